@@ -0,0 +1,20 @@
+-- Audit trail for destructive infrastructure operations
+-- Migration: 020_audit_events.sql
+
+CREATE TABLE IF NOT EXISTS audit_events (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    timestamp TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    user_id VARCHAR(255),
+    operation VARCHAR(100) NOT NULL,       -- delete, scale, restart, reset_offsets, ...
+    resource_type VARCHAR(100) NOT NULL,   -- Deployment, Pod, KafkaTopic, ...
+    resource_id VARCHAR(500) NOT NULL,
+    namespace VARCHAR(255),
+    cluster VARCHAR(255),
+    payload JSONB NOT NULL DEFAULT '{}',
+    ip_address VARCHAR(45)                 -- IPv4 or IPv6
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_events_timestamp ON audit_events(timestamp);
+CREATE INDEX IF NOT EXISTS idx_audit_events_user_id ON audit_events(user_id);
+CREATE INDEX IF NOT EXISTS idx_audit_events_operation ON audit_events(operation);
+CREATE INDEX IF NOT EXISTS idx_audit_events_resource ON audit_events(resource_type, resource_id);
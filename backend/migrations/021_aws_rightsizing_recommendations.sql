@@ -0,0 +1,20 @@
+-- Rightsizing recommendations pulled from AWS Cost Explorer
+-- Migration: 021_aws_rightsizing_recommendations.sql
+
+CREATE TABLE IF NOT EXISTS aws_rightsizing_recommendations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    account_id VARCHAR(20) NOT NULL,
+    resource_id VARCHAR(255) NOT NULL,
+    resource_type VARCHAR(100) NOT NULL,
+    current_instance_type VARCHAR(100) NOT NULL,
+    recommended_instance_type VARCHAR(100),    -- NULL for terminate recommendations
+    estimated_monthly_savings DECIMAL(15,4) NOT NULL DEFAULT 0.0,
+    modification_type VARCHAR(20) NOT NULL,    -- downsize, terminate, scale
+    confidence VARCHAR(20) NOT NULL,           -- high, medium, low
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_rightsizing_account ON aws_rightsizing_recommendations(account_id);
+CREATE INDEX IF NOT EXISTS idx_rightsizing_resource_type ON aws_rightsizing_recommendations(resource_type);
+CREATE INDEX IF NOT EXISTS idx_rightsizing_savings ON aws_rightsizing_recommendations(estimated_monthly_savings);
@@ -0,0 +1,14 @@
+-- Queue for long-running operations processed by a background worker
+-- Migration: 022_async_tasks.sql
+
+CREATE TABLE IF NOT EXISTS async_tasks (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    task_type VARCHAR(100) NOT NULL,       -- aws_sync, ...
+    status VARCHAR(20) NOT NULL DEFAULT 'queued', -- queued|running|completed|failed|cancelling|cancelled
+    payload JSONB NOT NULL DEFAULT '{}',
+    result JSONB,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_async_tasks_status_created ON async_tasks (status, created_at);
@@ -0,0 +1,14 @@
+-- Conversation history for the AI assistant so follow-up questions keep their context
+-- Migration: 023_llm_sessions.sql
+
+CREATE TABLE IF NOT EXISTS llm_sessions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id VARCHAR(255) NOT NULL,
+    provider_id UUID NOT NULL REFERENCES llm_providers(id) ON DELETE CASCADE,
+    context TEXT,
+    messages JSONB NOT NULL DEFAULT '[]',  -- [{role, content, timestamp}]
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_llm_sessions_user ON llm_sessions (user_id, updated_at DESC);
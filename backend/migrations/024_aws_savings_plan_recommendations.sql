@@ -0,0 +1,17 @@
+-- Savings Plans purchase recommendations pulled from AWS Cost Explorer
+-- Migration: 024_aws_savings_plan_recommendations.sql
+
+CREATE TABLE IF NOT EXISTS aws_savings_plan_recommendations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    account_id VARCHAR(20) NOT NULL,
+    payment_option VARCHAR(20) NOT NULL,       -- no_upfront, partial_upfront, all_upfront
+    lookback_period_days INTEGER NOT NULL,
+    hourly_commitment DECIMAL(15,4) NOT NULL DEFAULT 0.0,
+    estimated_monthly_savings DECIMAL(15,4) NOT NULL DEFAULT 0.0,
+    estimated_roi DECIMAL(15,4) NOT NULL DEFAULT 0.0,
+    on_demand_cost_equivalent DECIMAL(15,4) NOT NULL DEFAULT 0.0,
+    recommendations JSONB NOT NULL DEFAULT '[]',  -- per-account/family breakdown
+    created_at TIMESTAMPTZ DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_savings_plan_account_created ON aws_savings_plan_recommendations(account_id, created_at DESC);
@@ -0,0 +1,18 @@
+-- Alerts fired by Kubernetes event rules (OOMKilled, ImagePullBackOff, ...)
+-- Migration: 025_k8s_alert_firings.sql
+
+CREATE TABLE IF NOT EXISTS k8s_alert_firings (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    cluster_id UUID NOT NULL,
+    namespace VARCHAR(255) NOT NULL,
+    rule_name VARCHAR(255) NOT NULL,
+    event_reason VARCHAR(255) NOT NULL,
+    severity VARCHAR(20) NOT NULL,             -- critical, high, medium, low, info
+    involved_object VARCHAR(512) NOT NULL,     -- Kind/name
+    event_count INTEGER NOT NULL,
+    fired_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    resolved_at TIMESTAMPTZ                    -- NULL while the alert is still firing
+);
+
+CREATE INDEX IF NOT EXISTS idx_k8s_alert_firings_open ON k8s_alert_firings (cluster_id, rule_name, involved_object) WHERE resolved_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_k8s_alert_firings_fired_at ON k8s_alert_firings (cluster_id, fired_at DESC);
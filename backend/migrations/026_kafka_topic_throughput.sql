@@ -0,0 +1,15 @@
+-- Per-partition throughput samples taken by the Kafka topic monitor
+-- Migration: 026_kafka_topic_throughput.sql
+
+CREATE TABLE IF NOT EXISTS kafka_topic_throughput_samples (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    cluster_id VARCHAR(255) NOT NULL,          -- stored cluster id or configured cluster name
+    topic_name VARCHAR(255) NOT NULL,
+    partition INTEGER NOT NULL,
+    timestamp TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    messages_per_sec DOUBLE PRECISION NOT NULL,
+    bytes_per_sec_estimate DOUBLE PRECISION NOT NULL  -- messages_per_sec * sampled average message size
+);
+
+CREATE INDEX IF NOT EXISTS idx_kafka_topic_throughput_lookup ON kafka_topic_throughput_samples (cluster_id, topic_name, timestamp);
+CREATE INDEX IF NOT EXISTS idx_kafka_topic_throughput_timestamp ON kafka_topic_throughput_samples (timestamp);
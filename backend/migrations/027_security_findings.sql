@@ -0,0 +1,23 @@
+-- Vulnerability findings pulled from Amazon Inspector
+-- Migration: 027_security_findings.sql
+
+CREATE TABLE IF NOT EXISTS security_findings (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    finding_id VARCHAR(512) NOT NULL UNIQUE,      -- Inspector finding ARN
+    account_id VARCHAR(20) NOT NULL,
+    region VARCHAR(50) NOT NULL,
+    resource_arn VARCHAR(2048) NOT NULL,
+    title TEXT NOT NULL,
+    severity VARCHAR(20) NOT NULL,                -- CRITICAL, HIGH, MEDIUM, LOW, INFORMATIONAL, UNTRIAGED
+    description TEXT NOT NULL,
+    first_observed TIMESTAMPTZ NOT NULL,
+    last_observed TIMESTAMPTZ NOT NULL,
+    remediation_text TEXT,
+    package_name VARCHAR(255),
+    cvss_score DOUBLE PRECISION,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_security_findings_resource ON security_findings (resource_arn);
+CREATE INDEX IF NOT EXISTS idx_security_findings_account_severity ON security_findings (account_id, severity);
@@ -0,0 +1,5 @@
+-- Indexes backing the unified resource search (full text on name, containment on tags)
+-- Migration: 028_aws_resources_search.sql
+
+CREATE INDEX IF NOT EXISTS idx_aws_resources_name_fts ON aws_resources USING GIN (to_tsvector('simple', COALESCE(name, '')));
+CREATE INDEX IF NOT EXISTS idx_aws_resources_tags ON aws_resources USING GIN (tags jsonb_path_ops);
@@ -0,0 +1,4 @@
+-- Per-account override of the cost anomaly detection thresholds
+-- Migration: 029_aws_account_anomaly_detection.sql
+
+ALTER TABLE aws_accounts ADD COLUMN IF NOT EXISTS anomaly_detection_config JSONB;
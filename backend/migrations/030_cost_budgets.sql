@@ -0,0 +1,25 @@
+-- Budgets managed by BudgetService, optionally mirroring an AWS Budgets budget
+-- Migration: 030_cost_budgets.sql
+
+CREATE TABLE IF NOT EXISTS cost_budgets (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    account_id VARCHAR(20) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    budget_type TEXT NOT NULL,
+    budget_period TEXT NOT NULL,
+    amount DOUBLE PRECISION NOT NULL,
+    currency VARCHAR(10) NOT NULL,
+    start_date DATE NOT NULL,
+    end_date DATE,
+    alert_thresholds JSONB NOT NULL DEFAULT '[]',
+    tags JSONB NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE cost_budgets ADD COLUMN IF NOT EXISTS aws_budget_name VARCHAR(100);
+
+CREATE INDEX IF NOT EXISTS idx_cost_budgets_account ON cost_budgets(account_id);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_cost_budgets_aws_budget ON cost_budgets(account_id, aws_budget_name)
+    WHERE aws_budget_name IS NOT NULL;
@@ -0,0 +1,19 @@
+-- Non-compliant resources reported by AWS Config rule evaluations
+-- Migration: 031_config_violations.sql
+
+CREATE TABLE IF NOT EXISTS config_violations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    account_id VARCHAR(20) NOT NULL,
+    region VARCHAR(50) NOT NULL,
+    resource_type VARCHAR(255) NOT NULL,          -- e.g. AWS::EC2::SecurityGroup
+    resource_id VARCHAR(1024) NOT NULL,
+    rule_name VARCHAR(255) NOT NULL,
+    annotation TEXT NOT NULL,
+    evaluation_time TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_config_violations_unique
+    ON config_violations (account_id, region, resource_type, resource_id, rule_name);
+CREATE INDEX IF NOT EXISTS idx_config_violations_account ON config_violations (account_id, region);
@@ -0,0 +1,18 @@
+-- AWS Cost Explorer cost category definitions synced from the live account
+-- Migration: 032_aws_cost_categories.sql
+
+CREATE TABLE IF NOT EXISTS aws_cost_categories (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    account_id VARCHAR(20) NOT NULL,
+    category_arn VARCHAR(2048) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    effective_start VARCHAR(64),
+    number_of_rules INTEGER NOT NULL DEFAULT 0,
+    category_values JSONB NOT NULL DEFAULT '[]'::jsonb,  -- distinct values the rules assign
+    default_value VARCHAR(255),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_aws_cost_categories_arn ON aws_cost_categories (category_arn);
+CREATE INDEX IF NOT EXISTS idx_aws_cost_categories_account ON aws_cost_categories (account_id, name);
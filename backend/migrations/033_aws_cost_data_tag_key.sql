@@ -0,0 +1,6 @@
+-- Cost rows fetched grouped by a cost allocation tag. NULL for the regular per-service rows.
+-- Migration: 033_aws_cost_data_tag_key.sql
+
+ALTER TABLE aws_cost_data ADD COLUMN IF NOT EXISTS tag_key VARCHAR(128);
+
+CREATE INDEX IF NOT EXISTS idx_aws_cost_data_tag_key ON aws_cost_data (account_id, tag_key, usage_start);
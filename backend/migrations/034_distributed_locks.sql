@@ -0,0 +1,9 @@
+-- Database-backed locks so only one mayyam instance runs a given long operation at a time
+-- Migration: 034_distributed_locks.sql
+
+CREATE TABLE IF NOT EXISTS distributed_locks (
+    lock_key VARCHAR(512) PRIMARY KEY,
+    holder_id VARCHAR(64),
+    acquired_at TIMESTAMPTZ,                      -- NULL once released
+    expires_at TIMESTAMPTZ NOT NULL
+);
@@ -0,0 +1,9 @@
+-- Tie OIDC logins to the provider's stable (issuer, subject) pair instead of the email address
+-- Migration: 035_user_oidc_identity.sql
+
+ALTER TABLE users ADD COLUMN IF NOT EXISTS oidc_issuer TEXT;
+ALTER TABLE users ADD COLUMN IF NOT EXISTS oidc_subject TEXT;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_users_oidc_identity
+    ON users(oidc_issuer, oidc_subject)
+    WHERE oidc_subject IS NOT NULL;
@@ -0,0 +1,16 @@
+-- Topic config overrides to put back after a temporary change, e.g. the retention override
+-- a purge applies. Rows still unrestored at startup are re-applied.
+-- Migration: 036_kafka_topic_config_restores.sql
+
+CREATE TABLE IF NOT EXISTS kafka_topic_config_restores (
+    id UUID PRIMARY KEY,
+    cluster_id VARCHAR(255) NOT NULL,
+    topic_name VARCHAR(255) NOT NULL,
+    original_configs JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    restored_at TIMESTAMPTZ                       -- NULL until the overrides are back
+);
+
+CREATE INDEX IF NOT EXISTS idx_kafka_topic_config_restores_pending
+    ON kafka_topic_config_restores(cluster_id, topic_name)
+    WHERE restored_at IS NULL;
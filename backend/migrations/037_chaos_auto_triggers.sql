@@ -0,0 +1,13 @@
+-- Restart-driven chaos auto-triggers, kept across restarts. last_fired_at is checked and
+-- set in one statement before a trigger fires, so a restart cannot fire it again early.
+-- Migration: 037_chaos_auto_triggers.sql
+
+CREATE TABLE IF NOT EXISTS chaos_auto_triggers (
+    id UUID PRIMARY KEY,
+    cluster_id UUID NOT NULL,
+    namespace VARCHAR(255) NOT NULL,
+    restart_threshold INTEGER NOT NULL,
+    experiment_id UUID NOT NULL REFERENCES chaos_experiments(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_fired_at TIMESTAMPTZ                     -- NULL until the trigger first fires
+);
@@ -24,6 +24,13 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
         .route("/explain", web::post().to(explain_data))
         .route("/chat", web::post().to(ai::chat))
         .route("/chat/stream", web::post().to(ai::chat_stream))
+        .route("/sessions", web::post().to(ai::create_session))
+        .route("/sessions/{id}", web::get().to(ai::get_session))
+        .route("/sessions/{id}", web::delete().to(ai::delete_session))
+        .route(
+            "/sessions/{id}/messages",
+            web::post().to(ai::send_session_message),
+        )
         .route(
             "/analyze/rds/{id}/{workflow}",
             web::get().to(ai::analyze_rds_instance),
@@ -39,6 +46,10 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
         .route(
             "/analyze/postgres/{id}/{workflow}",
             web::get().to(ai::analyze_database_triage),
+        )
+        .route(
+            "/kubernetes/generate-manifest",
+            web::post().to(ai::generate_kubernetes_manifest),
         );
 
     cfg.service(scope);
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 
+use actix_web::cookie::{time::Duration as CookieDuration, Cookie, SameSite};
 use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -20,6 +21,7 @@ use std::sync::Arc;
 use crate::controllers::auth::AuthController;
 use crate::middleware::auth::Claims;
 use crate::models::user::{CreateUserDto, LoginUserDto};
+use crate::services::user::oidc::{OIDC_STATE_COOKIE, PENDING_LOGIN_TTL};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct LoginRequest {
@@ -34,6 +36,12 @@ pub struct RegisterRequest {
     pub password: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct OidcCallbackQuery {
+    pub code: String,
+    pub state: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TokenResponse {
     pub token: String,
@@ -55,7 +63,9 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     let scope = web::scope("/api/auth")
         .route("/login", web::post().to(login))
         .route("/register", web::post().to(register))
-        .route("/profile", web::get().to(get_profile));
+        .route("/profile", web::get().to(get_profile))
+        .route("/oidc/login", web::get().to(oidc_login))
+        .route("/oidc/callback", web::get().to(oidc_callback));
 
     cfg.service(scope);
 }
@@ -97,6 +107,52 @@ async fn register(
     }
 }
 
+async fn oidc_login(auth_controller: web::Data<Arc<AuthController>>) -> HttpResponse {
+    match auth_controller.oidc_login_url() {
+        Ok(redirect) => {
+            let cookie = Cookie::build(OIDC_STATE_COOKIE, redirect.state_cookie)
+                .path("/api/auth/oidc")
+                .http_only(true)
+                .secure(redirect.secure_cookie)
+                .same_site(SameSite::Lax)
+                .max_age(CookieDuration::seconds(PENDING_LOGIN_TTL.as_secs() as i64))
+                .finish();
+            HttpResponse::Found()
+                .insert_header((actix_web::http::header::LOCATION, redirect.url))
+                .cookie(cookie)
+                .finish()
+        }
+        Err(e) => HttpResponse::BadRequest().json(e.to_string()),
+    }
+}
+
+async fn oidc_callback(
+    req: HttpRequest,
+    query: web::Query<OidcCallbackQuery>,
+    auth_controller: web::Data<Arc<AuthController>>,
+) -> HttpResponse {
+    let state_cookie = req.cookie(OIDC_STATE_COOKIE);
+    let result = auth_controller
+        .oidc_callback(
+            &query.code,
+            &query.state,
+            state_cookie.as_ref().map(|c| c.value()),
+        )
+        .await;
+
+    // The state is single use, so drop the cookie whatever the outcome
+    let mut removal = Cookie::build(OIDC_STATE_COOKIE, "")
+        .path("/api/auth/oidc")
+        .finish();
+    removal.make_removal();
+    match result {
+        Ok(response) => HttpResponse::Ok().cookie(removal).json(response),
+        Err(e) => HttpResponse::Unauthorized()
+            .cookie(removal)
+            .json(e.to_string()),
+    }
+}
+
 async fn get_profile(
     req: HttpRequest,
     _auth_controller: web::Data<Arc<AuthController>>, // Prefix with underscore to ignore unused variable
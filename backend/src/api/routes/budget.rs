@@ -19,14 +19,26 @@ use std::sync::Arc;
 use crate::controllers::budget;
 use crate::repositories::cost_budget_repository::CostBudgetRepository;
 use crate::services::budget_service::BudgetService;
+use crate::services::AwsService;
 
 pub fn configure_routes(
     cfg: &mut web::ServiceConfig,
     cost_budget_repo: Arc<CostBudgetRepository>,
+    aws_service: Arc<AwsService>,
 ) {
-    let budget_service = Arc::new(BudgetService::new(cost_budget_repo.get_db().clone()));
+    let budget_service = Arc::new(
+        BudgetService::new(cost_budget_repo.get_db().clone()).with_aws_service(aws_service),
+    );
     let budget_service_data = web::Data::new(budget_service);
 
+    // Budgets in AWS Budgets, mirrored into the local budgets table
+    cfg.service(
+        web::scope("/api/budget/aws")
+            .app_data(budget_service_data.clone())
+            .route("/create", web::post().to(budget::create_aws_budget))
+            .route("/sync", web::post().to(budget::sync_aws_budgets)),
+    );
+
     cfg.service(
         web::scope("/api/budgets")
             .app_data(budget_service_data)
@@ -88,6 +88,13 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
                 "/audit/users/{user_id}",
                 web::get().to(chaos::get_user_activity),
             )
+            // Auto-trigger endpoints
+            .route("/auto-triggers", web::get().to(chaos::list_auto_triggers))
+            .route("/auto-triggers", web::post().to(chaos::create_auto_trigger))
+            .route(
+                "/auto-triggers/{id}",
+                web::delete().to(chaos::delete_auto_trigger),
+            )
             // Metrics endpoints
             .route("/metrics/stats", web::get().to(chaos::get_metrics_stats))
             .route(
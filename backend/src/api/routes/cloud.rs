@@ -22,7 +22,130 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     let cloud_scope = web::scope("/api/cloud")
         .route("/providers", web::get().to(cloud::list_providers))
         // Unified multi-cloud resources search
-        .route("/resources", web::get().to(cloud::search_cloud_resources));
+        .route("/resources", web::get().to(cloud::search_cloud_resources))
+        // VPC flow log analysis
+        .route(
+            "/aws/{account_id}/vpc/{vpc_id}/flow-logs/analyze",
+            web::get().to(cloud::analyze_vpc_flow_logs),
+        )
+        // Lambda performance analytics
+        .route(
+            "/aws/{account_id}/lambda/{function_name}/performance",
+            web::get().to(cloud::get_lambda_performance),
+        )
+        // CloudWatch resource dashboards
+        .route(
+            "/aws/{account_id}/cloudwatch/dashboards",
+            web::post().to(cloud::create_resource_dashboard),
+        )
+        .route(
+            "/aws/{account_id}/cloudwatch/dashboards/{dashboard_name}/snapshot",
+            web::get().to(cloud::get_dashboard_snapshot),
+        )
+        // RDS Performance Insights
+        .route(
+            "/aws/{account_id}/rds/{db_instance_id}/performance-insights",
+            web::get().to(cloud::get_rds_performance_insights),
+        )
+        // EBS snapshot lifecycle
+        .route(
+            "/aws/{account_id}/ebs/volumes/{volume_id}/snapshots",
+            web::get().to(cloud::list_ebs_volume_snapshots),
+        )
+        .route(
+            "/aws/{account_id}/ebs/volumes/{volume_id}/snapshots",
+            web::post().to(cloud::create_ebs_volume_snapshot),
+        )
+        .route(
+            "/aws/{account_id}/ebs/volumes/{volume_id}/snapshots/retention",
+            web::post().to(cloud::enforce_ebs_snapshot_retention),
+        )
+        .route(
+            "/aws/{account_id}/ebs/volumes/{volume_id}/snapshots/{snapshot_id}",
+            web::delete().to(cloud::delete_ebs_volume_snapshot),
+        )
+        .route(
+            "/aws/{account_id}/ebs/volumes/{volume_id}/snapshots/{snapshot_id}/copy",
+            web::post().to(cloud::copy_ebs_volume_snapshot),
+        )
+        // ECS task definition management
+        .route(
+            "/aws/{account_id}/ecs/task-definitions",
+            web::get().to(cloud::list_ecs_task_definition_families),
+        )
+        .route(
+            "/aws/{account_id}/ecs/task-definitions/describe",
+            web::get().to(cloud::describe_ecs_task_definition),
+        )
+        .route(
+            "/aws/{account_id}/ecs/task-definitions/{family}/revisions",
+            web::get().to(cloud::list_ecs_task_definition_revisions),
+        )
+        .route(
+            "/aws/{account_id}/ecs/task-definitions/{family}/deregister-old",
+            web::post().to(cloud::deregister_old_ecs_task_definitions),
+        )
+        // WAF IP sets and rule groups
+        .route(
+            "/aws/{account_id}/waf/ip-sets",
+            web::get().to(cloud::list_waf_ip_sets),
+        )
+        .route(
+            "/aws/{account_id}/waf/ip-sets/{ip_set_id}",
+            web::put().to(cloud::update_waf_ip_set),
+        )
+        .route(
+            "/aws/{account_id}/waf/rule-groups",
+            web::get().to(cloud::list_waf_rule_groups),
+        )
+        .route(
+            "/aws/{account_id}/waf/rule-groups/ip-block",
+            web::post().to(cloud::create_waf_ip_block_rule_group),
+        )
+        .route(
+            "/aws/{account_id}/waf/rule-groups/{rule_group_id}",
+            web::get().to(cloud::get_waf_rule_group),
+        )
+        .route(
+            "/aws/{account_id}/waf/block-ip",
+            web::post().to(cloud::block_waf_ip),
+        )
+        // Inspector vulnerability findings
+        .route(
+            "/aws/{account_id}/security/findings",
+            web::get().to(cloud::list_security_findings),
+        )
+        .route(
+            "/aws/{account_id}/security/findings/sync",
+            web::post().to(cloud::sync_security_findings),
+        )
+        // AWS Config rule compliance
+        .route(
+            "/aws/{account_id}/config/compliance",
+            web::get().to(cloud::check_config_compliance),
+        )
+        // Route53 records and health checks
+        .route(
+            "/aws/{account_id}/route53/hosted-zones/{hosted_zone_id}/records/sync",
+            web::post().to(cloud::sync_route53_records),
+        )
+        .route(
+            "/aws/{account_id}/route53/health-checks",
+            web::post().to(cloud::create_route53_health_check),
+        )
+        .route(
+            "/aws/{account_id}/route53/health-checks/{health_check_id}",
+            web::delete().to(cloud::delete_route53_health_check),
+        )
+        .route(
+            "/aws/{account_id}/route53/health-checks/{health_check_id}/status",
+            web::get().to(cloud::get_route53_health_check_status),
+        )
+        // Background resource sync
+        .route(
+            "/aws/{account_id}/sync",
+            web::post().to(cloud::enqueue_aws_account_sync),
+        );
 
     // AWS resource management (control plane)
     let aws_scope = web::scope("/api/aws")
@@ -50,6 +173,14 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             "/accounts/{account_id}/s3",
             web::get().to(cloud::list_s3_buckets),
         )
+        .route(
+            "/accounts/{account_id}/regions/{region}/s3/security-audit",
+            web::get().to(cloud::s3_audit_all_buckets),
+        )
+        .route(
+            "/accounts/{account_id}/regions/{region}/s3/{bucket_name}/security-audit",
+            web::get().to(cloud::s3_audit_bucket_security),
+        )
         // RDS instances
         .route(
             "/accounts/{account_id}/regions/{region}/rds",
@@ -60,6 +191,18 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             "/accounts/{account_id}/regions/{region}/dynamodb",
             web::get().to(cloud::list_dynamodb_tables),
         )
+        .route(
+            "/accounts/{account_id}/regions/{region}/dynamodb/{table_name}/on-demand",
+            web::post().to(cloud::dynamodb_switch_to_on_demand),
+        )
+        .route(
+            "/accounts/{account_id}/regions/{region}/dynamodb/{table_name}/provisioned",
+            web::post().to(cloud::dynamodb_switch_to_provisioned),
+        )
+        .route(
+            "/accounts/{account_id}/regions/{region}/dynamodb/{table_name}/throughput",
+            web::put().to(cloud::dynamodb_update_throughput),
+        )
         // VPC resources
         .route(
             "/accounts/{account_id}/regions/{region}/vpcs",
@@ -287,6 +430,14 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             "/accounts/{account_id}/regions/{region}/glue-databases",
             web::get().to(cloud::list_glue_databases),
         )
+        .route(
+            "/accounts/{account_id}/regions/{region}/glue-tables",
+            web::get().to(cloud::list_glue_tables),
+        )
+        .route(
+            "/accounts/{account_id}/regions/{region}/glue-databases/{database_name}/tables/{table_name}/schema",
+            web::get().to(cloud::get_glue_table_schema),
+        )
         // Batch 7: Edge & DR
         .route(
             "/accounts/{account_id}/regions/{region}/waf-web-acls",
@@ -324,6 +475,31 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
         .route(
             "/accounts/{account_id}/regions/{region}/kinesis-analytics-apps",
             web::get().to(cloud::list_kinesis_analytics_apps),
+        )
+        // Auto Scaling Groups
+        .route(
+            "/accounts/{account_id}/regions/{region}/autoscaling-groups",
+            web::get().to(cloud::list_autoscaling_groups),
+        )
+        .route(
+            "/accounts/{account_id}/regions/{region}/autoscaling-groups/{asg_name}/desired-capacity",
+            web::post().to(cloud::asg_set_desired_capacity),
+        )
+        .route(
+            "/accounts/{account_id}/regions/{region}/autoscaling-groups/{asg_name}/suspend-processes",
+            web::post().to(cloud::asg_suspend_processes),
+        )
+        .route(
+            "/accounts/{account_id}/regions/{region}/autoscaling-groups/{asg_name}/resume-processes",
+            web::post().to(cloud::asg_resume_processes),
+        )
+        .route(
+            "/accounts/{account_id}/regions/{region}/tags",
+            web::post().to(cloud::bulk_tag_resources),
+        )
+        .route(
+            "/accounts/{account_id}/regions/{region}/tags/remove",
+            web::post().to(cloud::bulk_untag_resources),
         );
 
     // AWS data plane operations
@@ -350,15 +526,35 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             "/profiles/{profile}/regions/{region}/dynamodb/{table}/query",
             web::post().to(cloud::dynamodb_query),
         )
+        .route(
+            "/profiles/{profile}/regions/{region}/dynamodb/{table}/scan",
+            web::post().to(cloud::dynamodb_scan),
+        )
         // SQS operations
         .route(
             "/profiles/{profile}/regions/{region}/sqs/send",
             web::post().to(cloud::sqs_send_message),
         )
+        .route(
+            "/profiles/{profile}/regions/{region}/sqs/send-fifo",
+            web::post().to(cloud::sqs_send_fifo_message),
+        )
+        .route(
+            "/profiles/{profile}/regions/{region}/sqs/attributes",
+            web::get().to(cloud::sqs_get_queue_attributes),
+        )
         .route(
             "/profiles/{profile}/regions/{region}/sqs/receive",
             web::post().to(cloud::sqs_receive_messages),
         )
+        .route(
+            "/profiles/{profile}/regions/{region}/sqs/dlq/stats",
+            web::get().to(cloud::sqs_dlq_stats),
+        )
+        .route(
+            "/profiles/{profile}/regions/{region}/sqs/dlq/requeue",
+            web::post().to(cloud::sqs_requeue_dlq_messages),
+        )
         // Kinesis operations
         .route(
             "/profiles/{profile}/regions/{region}/kinesis",
@@ -405,6 +601,26 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             "/profiles/{profile}/regions/{region}/kinesis/shards",
             web::post().to(cloud::kinesis_list_shards),
         )
+        .route(
+            "/profiles/{profile}/regions/{region}/kinesis/shards/split",
+            web::post().to(cloud::kinesis_split_shard),
+        )
+        .route(
+            "/profiles/{profile}/regions/{region}/kinesis/shards/merge",
+            web::post().to(cloud::kinesis_merge_shards),
+        )
+        .route(
+            "/profiles/{profile}/regions/{region}/kinesis/consumers",
+            web::post().to(cloud::kinesis_register_stream_consumer),
+        )
+        .route(
+            "/profiles/{profile}/regions/{region}/kinesis/consumers",
+            web::delete().to(cloud::kinesis_deregister_stream_consumer),
+        )
+        .route(
+            "/profiles/{profile}/regions/{region}/kinesis/consumers/list",
+            web::post().to(cloud::kinesis_list_stream_consumers),
+        )
         // Kinesis data plane operations
         .route(
             "/profiles/{profile}/regions/{region}/kinesis/records/put",
@@ -417,10 +633,19 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
         .route(
             "/profiles/{profile}/regions/{region}/kinesis/shard-iterator",
             web::post().to(cloud::kinesis_get_shard_iterator),
+        )
+        .route(
+            "/profiles/{profile}/regions/{region}/kinesis/consumers/subscribe",
+            web::post().to(cloud::kinesis_subscribe_to_shard),
         );
 
+    // Ranked search across every synced resource type
+    let resources_scope = web::scope("/api/resources")
+        .route("/search", web::get().to(cloud::unified_resource_search));
+
     // Register the scopes
     cfg.service(cloud_scope);
     cfg.service(aws_scope);
+    cfg.service(resources_scope);
     cfg.service(aws_data_scope);
 }
@@ -104,6 +104,49 @@ pub fn configure_routes(
             )
             .route("/health", web::get().to(health_check)),
     );
+
+    cfg.service(
+        web::scope("/api/cost")
+            .route("/chargeback", web::get().to(cost_analytics::get_chargeback_report))
+            .route("/tags/{tag_key}", web::get().to(cost_analytics::get_cost_by_tag))
+            .route("/untagged", web::get().to(cost_analytics::get_untagged_cost))
+            .route(
+                "/categories",
+                web::get().to(cost_analytics::list_aws_cost_categories),
+            )
+            .route(
+                "/categories",
+                web::post().to(cost_analytics::create_aws_cost_category),
+            )
+            .route(
+                "/categories/{arn}",
+                web::put().to(cost_analytics::update_aws_cost_category),
+            )
+            .route(
+                "/categories/{arn}",
+                web::delete().to(cost_analytics::delete_aws_cost_category),
+            )
+            .route(
+                "/rightsizing",
+                web::get().to(cost_analytics::get_rightsizing_recommendations),
+            )
+            .route(
+                "/rightsizing/refresh",
+                web::post().to(cost_analytics::refresh_rightsizing_recommendations),
+            )
+            .route(
+                "/savings-plans",
+                web::get().to(cost_analytics::get_savings_plan_recommendations),
+            )
+            .route(
+                "/savings-plans/refresh",
+                web::post().to(cost_analytics::refresh_savings_plan_recommendations),
+            )
+            .route(
+                "/optimization-suggestions",
+                web::post().to(cost_analytics::generate_optimization_suggestions),
+            ),
+    );
 }
 
 async fn health_check() -> HttpResponse {
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 
-use crate::controllers::database;
+use crate::controllers::{aurora_cluster, database, mysql_performance};
 use actix_web::{web, HttpResponse};
 use serde::{Deserialize, Serialize};
 
@@ -58,7 +58,34 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
                 web::resource("/{id}/table/{table_name}/details")
                     .route(web::get().to(get_table_details)),
             )
-            .service(web::resource("/{id}/monitoring").route(web::get().to(get_monitoring_data))),
+            .service(web::resource("/{id}/monitoring").route(web::get().to(get_monitoring_data)))
+            .service(
+                web::resource("/postgres/{name}/bloat")
+                    .route(web::get().to(database::get_table_bloat)),
+            )
+            .service(
+                web::resource("/postgres/{name}/index-bloat")
+                    .route(web::get().to(database::get_index_bloat)),
+            ),
+    );
+
+    cfg.service(
+        web::scope("/api/mysql-performance").service(
+            web::resource("/snapshots/compare")
+                .route(web::get().to(mysql_performance::compare_performance_snapshots)),
+        ),
+    );
+
+    cfg.service(
+        web::scope("/api/aurora")
+            .service(
+                web::resource("/replication-lag")
+                    .route(web::get().to(aurora_cluster::get_replication_lag)),
+            )
+            .service(
+                web::resource("/topology")
+                    .route(web::get().to(aurora_cluster::get_cluster_topology)),
+            ),
     );
 }
 
@@ -13,35 +13,92 @@
 // limitations under the License.
 
 
-use actix_web::{web, HttpResponse};
-use async_graphql::{EmptySubscription, Schema};
+use actix_web::{web, HttpRequest, HttpResponse};
+use async_graphql::{Context, EmptyMutation, Object, Schema, Subscription};
+use async_graphql_actix_web::{GraphQLRequest, GraphQLResponse, GraphQLSubscription};
+use futures::Stream;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tracing::warn;
 
-// We'll define our GraphQL schema later
-// For now, let's create a placeholder for the configuration
+use crate::controllers::kubernetes::get_cluster_config_by_id;
+use crate::services::kubernetes::pod_status_broadcaster::{PodStatusBroadcaster, PodStatusEvent};
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     let scope = web::scope("/api/graphql")
         .route("", web::post().to(graphql_handler))
+        .route("/ws", web::get().to(graphql_subscription_handler))
         .route("/playground", web::get().to(graphql_playground));
 
     cfg.service(scope);
 }
 
-// Placeholder for GraphQL schema and handlers
-// Will implement the actual schema later
+pub type AppSchema = Schema<Query, EmptyMutation, Subscription>;
 
-pub type AppSchema = Schema<Query, Mutation, EmptySubscription>;
+pub fn build_schema(
+    db: Arc<DatabaseConnection>,
+    pod_status_broadcaster: Arc<PodStatusBroadcaster>,
+) -> AppSchema {
+    Schema::build(Query, EmptyMutation, Subscription)
+        .data(db)
+        .data(pod_status_broadcaster)
+        .finish()
+}
 
-// Define basic Query and Mutation roots
 pub struct Query;
-pub struct Mutation;
 
-async fn graphql_handler() -> HttpResponse {
-    HttpResponse::Ok().json(serde_json::json!({
-        "data": {
-            "hello": "Hello from GraphQL API!"
-        }
-    }))
+#[Object]
+impl Query {
+    async fn hello(&self) -> &'static str {
+        "Hello from GraphQL API!"
+    }
+}
+
+pub struct Subscription;
+
+#[Subscription]
+impl Subscription {
+    /// Pod status transitions in a namespace, starting with the current status of every pod
+    /// when this is the first subscriber for the namespace.
+    async fn pod_status_changes(
+        &self,
+        ctx: &Context<'_>,
+        cluster_id: String,
+        namespace: String,
+    ) -> async_graphql::Result<impl Stream<Item = PodStatusEvent>> {
+        let db = ctx.data::<Arc<DatabaseConnection>>()?;
+        let broadcaster = ctx.data::<Arc<PodStatusBroadcaster>>()?;
+
+        let cluster_config = get_cluster_config_by_id(db.as_ref(), &cluster_id).await?;
+        let rx = broadcaster
+            .subscribe(&cluster_id, &cluster_config, &namespace)
+            .await?;
+
+        Ok(futures::stream::unfold(rx, |mut rx| async move {
+            loop {
+                match rx.recv().await {
+                    Ok(event) => return Some((event, rx)),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("Pod status subscriber lagged, skipped {} events", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        }))
+    }
+}
+
+async fn graphql_handler(schema: web::Data<AppSchema>, req: GraphQLRequest) -> GraphQLResponse {
+    schema.execute(req.into_inner()).await.into()
+}
+
+async fn graphql_subscription_handler(
+    schema: web::Data<AppSchema>,
+    req: HttpRequest,
+    payload: web::Payload,
+) -> actix_web::Result<HttpResponse> {
+    GraphQLSubscription::new(AppSchema::clone(&schema)).start(&req, payload)
 }
 
 async fn graphql_playground() -> HttpResponse {
@@ -95,6 +152,7 @@ async fn graphql_playground() -> HttpResponse {
   <script>window.addEventListener('load', function (event) {
       GraphQLPlayground.init(document.getElementById('root'), {
         endpoint: '/api/graphql',
+        subscriptionEndpoint: '/api/graphql/ws',
         settings: {
           'request.credentials': 'include',
         }
@@ -22,6 +22,10 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
         .route("/clusters", web::post().to(kafka::create_cluster))
         .route("/clusters/{id}", web::get().to(kafka::get_cluster))
         .route("/clusters/{id}/health", web::get().to(kafka::health_check))
+        .route(
+            "/clusters/{id}/certificates",
+            web::get().to(kafka::check_broker_certificates),
+        )
         .route("/metrics", web::get().to(kafka::get_metrics))
         .route(
             "/clusters/{id}/batch-produce",
@@ -49,6 +53,10 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             "/clusters/{id}/topics/{topic}/consume",
             web::post().to(kafka::consume_messages),
         )
+        .route(
+            "/clusters/{id}/topics/{topic}/search",
+            web::post().to(kafka::search_messages),
+        )
         .route(
             "/clusters/{id}/consumer-groups",
             web::get().to(kafka::list_consumer_groups),
@@ -57,10 +65,22 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             "/clusters/{id}/consumer-groups/{group}",
             web::get().to(kafka::get_consumer_group),
         )
+        .route(
+            "/clusters/{id}/consumer-groups/{group}/lag/{topic}",
+            web::get().to(kafka::get_consumer_group_lag),
+        )
         .route(
             "/clusters/{id}/consumer-groups/{group}/reset",
             web::post().to(kafka::reset_offsets),
         )
+        .route(
+            "/clusters/{id}/consumer-groups/{group}/topics/{topic}/initialize-offsets",
+            web::post().to(kafka::initialize_new_partition_offsets),
+        )
+        .route(
+            "/clusters/{id}/consumer-groups/{group}/clone",
+            web::post().to(kafka::clone_consumer_group),
+        )
         .route(
             "/clusters/{id}/topics/{topic}/config",
             web::put().to(kafka::update_topic_config),
@@ -74,6 +94,22 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             "/clusters/{id}/brokers",
             web::get().to(kafka::get_broker_status),
         )
+        .route(
+            "/clusters/{id}/topics/{topic}/throughput",
+            web::get().to(kafka::get_topic_throughput),
+        )
+        .route(
+            "/clusters/{id}/topics/{topic}/replication-lag",
+            web::get().to(kafka::get_replication_lag),
+        )
+        .route(
+            "/clusters/{id}/capacity-report",
+            web::get().to(kafka::get_capacity_report),
+        )
+        .route(
+            "/clusters/{id}/reassignment-plan",
+            web::post().to(kafka::generate_reassignment_plan),
+        )
         .route(
             "/clusters/{id}/backup",
             web::post().to(kafka::backup_topic_messages),
@@ -83,9 +119,53 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             web::post().to(kafka::restore_topic_messages),
         )
         .route("/migrate", web::post().to(kafka::migrate_topic_messages))
+        .route(
+            "/clusters/{id}/topics/{topic}/replay",
+            web::post().to(kafka::replay_messages),
+        )
+        .route(
+            "/clusters/{id}/topics/{topic}/transform",
+            web::post().to(kafka::transform_topic),
+        )
+        .route(
+            "/clusters/{id}/topics/{topic}/purge",
+            web::post().to(kafka::purge_messages),
+        )
         .route(
             "/clusters/{id}/drain",
             web::post().to(kafka::wait_for_queue_drain),
+        )
+        .route(
+            "/clusters/{id}/topics/{topic}/schema/compatibility",
+            web::post().to(kafka::check_schema_compatibility),
+        )
+        .route(
+            "/clusters/{id}/topics/{topic}/schema/compatibility-level",
+            web::get().to(kafka::get_schema_compatibility_level),
+        )
+        .route(
+            "/clusters/{id}/topics/{topic}/schema/compatibility-level",
+            web::put().to(kafka::set_schema_compatibility_level),
+        )
+        .route(
+            "/{id}/schema-registry/subjects",
+            web::get().to(kafka::list_schema_subjects),
+        )
+        .route(
+            "/{id}/schema-registry/subjects/{subject}",
+            web::delete().to(kafka::delete_schema_subject),
+        )
+        .route(
+            "/{id}/schema-registry/subjects/{subject}/versions",
+            web::get().to(kafka::list_schema_versions),
+        )
+        .route(
+            "/{id}/schema-registry/subjects/{subject}/versions/{version}",
+            web::get().to(kafka::get_schema_version),
+        )
+        .route(
+            "/{id}/schema-registry/subjects/{subject}/versions/{version}",
+            web::delete().to(kafka::delete_schema_version),
         );
 
     cfg.service(scope);
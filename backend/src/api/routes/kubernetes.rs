@@ -28,6 +28,10 @@ pub fn configure(cfg: &mut web::ServiceConfig, db: Arc<DatabaseConnection>) {
             "/test-db-connection",
             web::get().to(kube_controller::test_db_connection_controller),
         )
+        .route(
+            "/discover-contexts",
+            web::get().to(kube_controller::discover_contexts_controller),
+        )
         .route(
             "/clusters",
             web::get().to(kube_controller::list_clusters_controller),
@@ -52,6 +56,38 @@ pub fn configure(cfg: &mut web::ServiceConfig, db: Arc<DatabaseConnection>) {
             "/clusters/{cluster_id}/metrics",
             web::get().to(kube_controller::get_cluster_metrics_controller),
         )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace_name}/pods/{pod_name}/metrics",
+            web::get().to(kube_controller::get_pod_resource_usage_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace}/top-pods",
+            web::get().to(kube_controller::get_top_pods_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace_name}/pods/{pod_name}/scheduling-analysis",
+            web::get().to(kube_controller::analyze_pending_pod_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace_name}/pods/{pod_name}/node-affinity",
+            web::get().to(kube_controller::get_node_affinity_compliance_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/schedulable-nodes",
+            web::post().to(kube_controller::find_schedulable_nodes_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace}/canary-analysis",
+            web::post().to(kube_controller::analyze_canary_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/admission-simulation",
+            web::post().to(kube_controller::simulate_admission_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace}/admission-simulation",
+            web::post().to(kube_controller::simulate_admission_batch_controller),
+        )
         .route(
             "/clusters/{cluster_id}/namespaces",
             web::get().to(kube_controller::list_namespaces_controller),
@@ -76,11 +112,35 @@ pub fn configure(cfg: &mut web::ServiceConfig, db: Arc<DatabaseConnection>) {
             "/clusters/{cluster_id}/nodes/{node_name}",
             web::get().to(kube_controller::get_node_details_controller),
         )
+        .route(
+            "/clusters/{cluster_id}/nodes/{node_name}/labels",
+            web::put().to(kube_controller::add_node_label_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/nodes/{node_name}/labels/{key}",
+            web::delete().to(kube_controller::remove_node_label_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/nodes/{node_name}/taints",
+            web::post().to(kube_controller::add_node_taint_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/nodes/{node_name}/taints/{key}",
+            web::delete().to(kube_controller::remove_node_taint_controller),
+        )
         // Route for all deployments in a cluster (new)
         .route(
             "/clusters/{cluster_id}/deployments",
             web::get().to(kube_controller::list_all_deployments_controller),
         )
+        .route(
+            "/clusters/{cluster_id}/deployments/by-image",
+            web::get().to(kube_controller::find_deployments_by_image_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/deployments/version-matrix",
+            web::get().to(kube_controller::get_deployment_version_matrix_controller),
+        )
         // Route for deployments in a specific namespace
         .route(
             "/clusters/{cluster_id}/namespaces/{namespace_name}/deployments",
@@ -102,6 +162,10 @@ pub fn configure(cfg: &mut web::ServiceConfig, db: Arc<DatabaseConnection>) {
             "/clusters/{cluster_id}/namespaces/{namespace_name}/deployments/{deployment_name}/pods",
             web::get().to(kube_controller::get_pods_for_deployment_controller),
         )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace_name}/deployments/{deployment_name}/cost",
+            web::get().to(kube_controller::estimate_deployment_cost_controller),
+        )
         // Route for all stateful sets in a cluster
         .route(
             "/clusters/{cluster_id}/statefulsets",
@@ -134,6 +198,10 @@ pub fn configure(cfg: &mut web::ServiceConfig, db: Arc<DatabaseConnection>) {
             "/clusters/{cluster_id}/namespaces/{namespace_name}/services/{service_name}",
             web::get().to(kube_controller::get_service_details_controller),
         )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace_name}/loadbalancers",
+            web::get().to(kube_controller::list_loadbalancer_addresses_controller),
+        )
         // Route for all daemon sets in a cluster
         .route(
             "/clusters/{cluster_id}/daemonsets",
@@ -170,10 +238,22 @@ pub fn configure(cfg: &mut web::ServiceConfig, db: Arc<DatabaseConnection>) {
             "/clusters/{cluster_id}/persistentvolumes",
             web::get().to(kube_controller::list_pvs_controller),
         )
+        .route(
+            "/clusters/{cluster_id}/persistentvolumes/released",
+            web::get().to(kube_controller::list_released_pvs_controller),
+        )
         .route(
             "/clusters/{cluster_id}/persistentvolumes/{pv_name}",
             web::get().to(kube_controller::get_pv_details_controller),
         )
+        .route(
+            "/clusters/{cluster_id}/persistentvolumes/{pv_name}/reclaim-policy",
+            web::put().to(kube_controller::set_pv_reclaim_policy_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/persistentvolumes/{pv_name}/rebind",
+            web::post().to(kube_controller::rebind_pv_controller),
+        )
         .route(
             "/clusters/{cluster_id}/namespaces/{namespace_name}/pods",
             web::get().to(kube_controller::list_pods_controller),
@@ -205,6 +285,14 @@ pub fn configure(cfg: &mut web::ServiceConfig, db: Arc<DatabaseConnection>) {
         .route(
             "/clusters/{cluster_id}/namespaces/{namespace_name}/pods/{pod_name}/exec",
             web::post().to(kube_controller::exec_pod_command_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace_name}/pods/{pod_name}/ephemeralcontainers",
+            web::post().to(kube_controller::inject_ephemeral_container_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace_name}/pods/{pod_name}/portforward/{pod_port}",
+            web::get().to(crate::controllers::port_forward::pod_port_forward_controller),
         );
 
     // ConfigMaps
@@ -243,6 +331,37 @@ pub fn configure(cfg: &mut web::ServiceConfig, db: Arc<DatabaseConnection>) {
         .route(
             "/clusters/{cluster_id}/namespaces/{namespace}/secrets/{name}",
             web::delete().to(crate::controllers::secrets::delete_secret_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace}/secrets/{name}/rotate",
+            web::post().to(crate::controllers::secrets::rotate_secret_controller),
+        );
+
+    // Prometheus alert rules stored in ConfigMaps
+    let scope = scope
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace}/prometheus-rules",
+            web::get().to(crate::controllers::prometheus_rules::list_prometheus_rules_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace}/prometheus-rules/validate",
+            web::post()
+                .to(crate::controllers::prometheus_rules::validate_prometheus_rule_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace}/prometheus-rules/{name}",
+            web::put().to(crate::controllers::prometheus_rules::apply_prometheus_rule_controller),
+        );
+
+    // Event alerting
+    let scope = scope
+        .route(
+            "/clusters/{cluster_id}/alerts",
+            web::get().to(crate::controllers::alerting::list_alert_firings_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/alerts/evaluate",
+            web::post().to(crate::controllers::alerting::evaluate_alert_rules_controller),
         );
 
     // Jobs
@@ -251,6 +370,10 @@ pub fn configure(cfg: &mut web::ServiceConfig, db: Arc<DatabaseConnection>) {
             "/clusters/{cluster_id}/namespaces/{namespace}/jobs",
             web::get().to(crate::controllers::jobs::list_jobs_controller),
         )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace}/jobs/run",
+            web::post().to(crate::controllers::jobs::run_job_controller),
+        )
         .route(
             "/clusters/{cluster_id}/namespaces/{namespace}/jobs/{name}",
             web::get().to(crate::controllers::jobs::get_job_controller),
@@ -340,6 +463,10 @@ pub fn configure(cfg: &mut web::ServiceConfig, db: Arc<DatabaseConnection>) {
         .route(
             "/clusters/{cluster_id}/namespaces/{namespace}/endpoints/{name}",
             web::delete().to(crate::controllers::endpoints::delete_endpoints_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace}/endpoints/{name}/health",
+            web::get().to(crate::controllers::endpoints::health_check_endpoints_controller),
         );
 
     // NetworkPolicies
@@ -360,6 +487,20 @@ pub fn configure(cfg: &mut web::ServiceConfig, db: Arc<DatabaseConnection>) {
             "/clusters/{cluster_id}/namespaces/{namespace}/networkpolicies/{name}",
             web::delete()
                 .to(crate::controllers::network_policies::delete_network_policy_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace}/networkpolicies/isolation",
+            web::post()
+                .to(crate::controllers::network_policies::create_namespace_isolation_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace}/networkpolicies/default-deny",
+            web::post().to(crate::controllers::network_policies::create_default_deny_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/networkpolicies/unenforced-namespaces",
+            web::get()
+                .to(crate::controllers::network_policies::list_unenforced_namespaces_controller),
         );
 
     // HPA
@@ -415,8 +556,41 @@ pub fn configure(cfg: &mut web::ServiceConfig, db: Arc<DatabaseConnection>) {
         .route(
             "/clusters/{cluster_id}/namespaces/{namespace}/poddisruptionbudgets/{name}",
             web::delete().to(crate::controllers::pdb::delete_pdb_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace}/pdb-violations",
+            web::get().to(crate::controllers::pdb::detect_pdb_violations_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/nodes/{node_name}/drain-impact",
+            web::get().to(crate::controllers::pdb::simulate_node_drain_controller),
         );
 
+    // Orphaned resource garbage collection
+    let scope = scope
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace}/orphaned-resources",
+            web::get()
+                .to(crate::controllers::garbage_collection::find_orphaned_resources_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace}/orphaned-resources/cleanup",
+            web::post()
+                .to(crate::controllers::garbage_collection::cleanup_orphaned_resources_controller),
+        );
+
+    // Resource counts across all registered clusters
+    let scope = scope.route(
+        "/multi-cluster/summary",
+        web::get().to(crate::controllers::multi_cluster::get_multi_cluster_summary_controller),
+    );
+
+    // Resource dependency graph
+    let scope = scope.route(
+        "/clusters/{cluster_id}/namespaces/{namespace}/dependency-graph",
+        web::get().to(crate::controllers::dependency_graph::get_dependency_graph_controller),
+    );
+
     // ResourceQuota
     let scope = scope
         .route(
@@ -442,6 +616,10 @@ pub fn configure(cfg: &mut web::ServiceConfig, db: Arc<DatabaseConnection>) {
             "/clusters/{cluster_id}/namespaces/{namespace}/limitranges",
             web::get().to(crate::controllers::limit_ranges::list_limit_ranges_controller),
         )
+        .route(
+            "/clusters/{cluster_id}/namespaces/{namespace}/default-limits",
+            web::put().to(crate::controllers::limit_ranges::apply_default_limit_range_controller),
+        )
         .route(
             "/clusters/{cluster_id}/namespaces/{namespace}/limitranges/{name}",
             web::get().to(crate::controllers::limit_ranges::get_limit_range_controller),
@@ -543,6 +721,10 @@ pub fn configure(cfg: &mut web::ServiceConfig, db: Arc<DatabaseConnection>) {
         .route(
             "/clusters/{cluster_id}/clusterrolebindings/{name}",
             web::delete().to(crate::controllers::rbac::delete_cluster_role_binding_controller),
+        )
+        .route(
+            "/clusters/{cluster_id}/rbac/subject-permissions",
+            web::post().to(crate::controllers::rbac::subject_permissions_controller),
         );
 
     // AuthZ check
@@ -602,10 +784,26 @@ pub fn configure(cfg: &mut web::ServiceConfig, db: Arc<DatabaseConnection>) {
             "/clusters/{cluster_id}/crds/{name}",
             web::get().to(crate::controllers::crds::get_crd_controller),
         )
+        .route(
+            "/clusters/{cluster_id}/crds/{name}/resources",
+            web::get().to(crate::controllers::crds::list_crd_resources_controller),
+        )
         .route(
             "/clusters/{cluster_id}/customresources/{group}/{version}/{plural}",
             web::get().to(crate::controllers::crds::list_custom_resources_controller),
         );
 
+    // API deprecations ahead of upgrades
+    let scope = scope.route(
+        "/clusters/{cluster_id}/api-deprecations",
+        web::get().to(crate::controllers::cluster_version::get_api_deprecations_controller),
+    );
+
+    // Batch label and annotation updates
+    let scope = scope.route(
+        "/clusters/{cluster_id}/labels/apply",
+        web::post().to(crate::controllers::labels::apply_labels_controller),
+    );
+
     cfg.service(scope);
 }
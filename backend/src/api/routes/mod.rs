@@ -20,6 +20,7 @@ pub mod aws_analytics;
 pub mod budget;
 pub mod chaos;
 pub mod cloud;
+pub mod config;
 pub mod cost_analytics;
 pub mod data_source;
 pub mod database;
@@ -31,11 +32,13 @@ pub mod kubernetes_cluster_management; // New module
 pub mod llm_analytics;
 pub mod llm_provider;
 pub mod metrics;
+pub mod opensearch;
 pub mod prompt_template;
 pub mod query_fingerprint;
 pub mod query_template;
 pub mod slow_query;
 pub mod sync_run;
+pub mod tasks;
 pub mod unified_llm;
 
 use actix_web::web;
@@ -54,7 +57,10 @@ pub fn configure(cfg: &mut web::ServiceConfig, db: Arc<DatabaseConnection>, llm_
     kafka::configure(cfg); // Same for this
     kubernetes::configure(cfg, db.clone()); // Pass db to kubernetes::configure
     cloud::configure(cfg);
+    opensearch::configure(cfg);
     chaos::configure(cfg);
+    config::configure(cfg);
+    tasks::configure(cfg);
     ai::configure(cfg);
     graphql::configure(cfg);
     // Note: sync_run routes are registered in server.rs where controller is available
@@ -0,0 +1,44 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use actix_web::web;
+
+use crate::controllers::opensearch;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/opensearch")
+            .route(
+                "/clusters/{cluster}/indices/{index}/mapping",
+                web::get().to(opensearch::get_index_mapping),
+            )
+            .route(
+                "/clusters/{cluster}/indices/{index}/mapping",
+                web::put().to(opensearch::put_index_mapping),
+            )
+            .route(
+                "/clusters/{cluster}/indices/{index}/aliases",
+                web::post().to(opensearch::create_alias),
+            )
+            .route(
+                "/clusters/{cluster}/indices/{index}/reindex",
+                web::post().to(opensearch::reindex_with_mapping_change),
+            )
+            .route(
+                "/clusters/{cluster}/aliases/{alias}/rollover",
+                web::post().to(opensearch::rollover_alias),
+            ),
+    );
+}
@@ -30,6 +30,8 @@ pub fn configure(cfg: &mut web::ServiceConfig, controller: Arc<UnifiedLlmControl
             .route("/generate/quick", web::post().to(quick_generate))
             // List available providers
             .route("/providers", web::get().to(list_providers))
+            // Test completion against every provider
+            .route("/health", web::get().to(health_check))
             // Get provider capabilities
             .route(
                 "/providers/{provider}/capabilities",
@@ -65,6 +67,10 @@ async fn list_providers(controller: web::Data<Arc<UnifiedLlmController>>) -> Res
     controller.list_providers().await
 }
 
+async fn health_check(controller: web::Data<Arc<UnifiedLlmController>>) -> Result<HttpResponse> {
+    controller.health_check().await
+}
+
 async fn get_provider_capabilities(
     controller: web::Data<Arc<UnifiedLlmController>>,
     path: web::Path<String>,
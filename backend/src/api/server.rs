@@ -18,10 +18,12 @@ use actix_web::{middleware::Logger, web, App, HttpServer, HttpResponse, Responde
 use sea_orm::{ConnectionTrait, DbBackend, Statement};
 use std::error::Error;
 use std::sync::Arc;
-use tracing::info;
+use tracing::{error, info, warn};
 
 use crate::api::routes;
+use crate::config::reloader::{ConfigReloader, SharedConfig};
 use crate::config::Config;
+use crate::services::kubernetes::client::ClientFactory as KubernetesClientFactory;
 use crate::controllers::sync_run::SyncRunController;
 use crate::controllers::{
     auth::AuthController, aws_analytics::AwsAnalyticsController, data_source::DataSourceController,
@@ -30,12 +32,22 @@ use crate::controllers::{
     llm_provider::LlmProviderController, prompt_template::PromptTemplateController,
 };
 use crate::middleware::auth::AuthMiddleware;
+use crate::middleware::request_id::RequestIdMiddleware;
+use crate::services::user::oidc::OidcProvider;
 use crate::repositories::{
-    aws_account::AwsAccountRepository, aws_resource::AwsResourceRepository,
-    cloud_resource::CloudResourceRepository, cluster::ClusterRepository,
+    ai_analysis_repository::AIAnalysisRepository, alert_firing::AlertFiringRepository,
+    async_task::AsyncTaskRepository,
+    aws_account::AwsAccountRepository,
+    aws_resource::AwsResourceRepository, cloud_resource::CloudResourceRepository,
+    config_violation::ConfigViolationRepository,
+    distributed_lock::DistributedLock,
+    kafka_config_restore::KafkaConfigRestoreRepository,
+    cluster::ClusterRepository,
     cost_analytics::CostAnalyticsRepository, data_source::DataSourceRepository,
-    database::DatabaseRepository, llm_provider::LlmProviderRepository,
-    prompt_template::PromptTemplateRepository, user::UserRepository,
+    database::DatabaseRepository, kafka_topic_throughput::KafkaTopicThroughputRepository,
+    llm_provider::LlmProviderRepository, llm_session_repository::LlmSessionRepository,
+    prompt_template::PromptTemplateRepository, security_finding::SecurityFindingRepository,
+    user::UserRepository,
 };
 use crate::services::analytics::aws_analytics::aws_analytics::AwsAnalyticsService;
 use crate::services::aws::aws_control_plane::dynamodb_control_plane::DynamoDbControlPlane;
@@ -48,33 +60,55 @@ use crate::services::aws::aws_data_plane::kinesis_data_plane::KinesisDataPlane;
 use crate::services::aws::aws_data_plane::s3_data_plane::S3DataPlane;
 use crate::services::aws::aws_data_plane::sqs_data_plane::SqsDataPlane;
 use crate::services::{
-    aws::{AwsControlPlane, AwsCostService, AwsDataPlane, AwsService},
+    aws::{
+        AwsControlPlane, AwsCostService, AwsDataPlane, AwsService, SecretsManagerCredentialProvider,
+    },
     aws_account::AwsAccountService,
     aws_cost_analytics::AwsCostAnalyticsService,
+    cost_optimization_advisor::CostOptimizationAdvisor,
     data_collection::DataCollectionService,
     kafka::KafkaService,
+    kafka_schema_registry::SchemaRegistryService,
+    kafka_topic_monitor::KafkaTopicMonitor,
     llm::{LlmAnalyticsService, LlmIntegrationService},
     llm_provider::LlmProviderService,
+    notifications::{cost_anomaly::CostAnomalyNotifier, email::EmailNotifier},
+    opensearch::OpenSearchService,
+    task_queue::TaskQueue,
     user::UserService,
 };
 use crate::repositories::chaos_repository::ChaosRepository;
+use crate::repositories::audit_event::AuditEventRepository;
 use crate::repositories::chaos_audit_repository::ChaosAuditRepository;
+use crate::repositories::chaos_auto_trigger::ChaosAutoTriggerRepository;
+use crate::services::analytics::audit_event_emitter::AuditEventEmitter;
 use crate::repositories::chaos_metrics_repository::ChaosMetricsRepository;
+use crate::services::aurora_cluster_service::AuroraClusterService;
 use crate::services::chaos_service::ChaosService;
 use crate::services::chaos_audit_service::ChaosAuditService;
 use crate::services::chaos_metrics_service::ChaosMetricsService;
+use crate::services::chaos_scheduler::ChaosScheduler;
 
 // Import Kubernetes Services
 use crate::services::kubernetes::authz_service::AuthorizationService;
+use crate::services::kubernetes::alerting::AlertingService;
 use crate::services::kubernetes::cronjobs_service::CronJobsService;
 use crate::services::kubernetes::endpoints_service::EndpointsService;
+use crate::services::kubernetes::dependency_graph_service::DependencyGraphService;
+use crate::services::kubernetes::multi_cluster_aggregator::MultiClusterAggregator;
+use crate::services::kubernetes::garbage_collection_service::GarbageCollectionService;
+use crate::services::kubernetes::prometheus_rule_service::PrometheusRuleService;
+use crate::services::kubernetes::secret_rotation_service::SecretRotationService;
+use crate::services::kubernetes::workload_cost_estimator::WorkloadCostEstimator;
 use crate::services::kubernetes::hpa_service::HorizontalPodAutoscalerService;
 use crate::services::kubernetes::ingress_service::IngressService;
 use crate::services::kubernetes::jobs_service::JobsService;
 use crate::services::kubernetes::limit_ranges_service::LimitRangesService;
+use crate::services::kubernetes::manifest_generator_service::ManifestGeneratorService;
 use crate::services::kubernetes::metrics_service::MetricsService;
 use crate::services::kubernetes::network_policies_service::NetworkPoliciesService;
 use crate::services::kubernetes::nodes_ops_service::NodeOpsService;
+use crate::services::kubernetes::label_service::LabelService;
 use crate::services::kubernetes::pdb_service::PodDisruptionBudgetsService;
 use crate::services::kubernetes::rbac_service::RbacService;
 use crate::services::kubernetes::resource_quotas_service::ResourceQuotasService;
@@ -82,19 +116,30 @@ use crate::services::kubernetes::service_accounts_service::ServiceAccountsServic
 use crate::services::kubernetes::replica_sets_service::ReplicaSetsService;
 use crate::services::kubernetes::storage_classes_service::StorageClassesService;
 use crate::services::kubernetes::crds_service::CrdsService;
+use crate::services::kubernetes::cluster_version_service::ClusterVersionService;
+use crate::services::kubernetes::pod_status_broadcaster::PodStatusBroadcaster;
 use crate::services::kubernetes::{
+    admission_simulator::AdmissionWebhookSimulator,
+    affinity_analyzer::AffinityAnalyzer,
+    canary_analyzer::DeploymentCanaryAnalyzer,
     daemon_sets::DaemonSetsService,
     deployments_service::DeploymentsService,
     namespaces_service::NamespacesService,
     nodes_service::NodesService,
     persistent_volume_claims_service::PersistentVolumeClaimsService,
     persistent_volumes_service::PersistentVolumesService,
-    pod::PodService,                                         // Changed from pods
+    pod::PodService, // Changed from pods
+    scheduling_analyzer::SchedulingAnalyzer,
     services_service::ServicesService as K8sServicesService, // Alias to avoid conflict with general 'Service'
     stateful_sets_service::StatefulSetsService,
 };
 
-pub async fn run_server(host: String, port: u16, config: Config) -> Result<(), Box<dyn Error>> {
+pub async fn run_server(
+    host: String,
+    port: u16,
+    config: Config,
+    secret_provider: Option<Arc<SecretsManagerCredentialProvider>>,
+) -> Result<(), Box<dyn Error>> {
     let addr = format!("{}:{}", host, port);
 
     info!("Starting Mayyam server on http://{}", addr);
@@ -144,12 +189,34 @@ pub async fn run_server(host: String, port: u16, config: Config) -> Result<(), B
     let chaos_repo = Arc::new(ChaosRepository::new(db_connection.clone()));
     let chaos_audit_repo = Arc::new(ChaosAuditRepository::new(db_connection.clone()));
     let chaos_metrics_repo = Arc::new(ChaosMetricsRepository::new(db_connection.clone()));
+    let audit_event_repo = Arc::new(AuditEventRepository::new(db_connection.clone()));
+
+    // Keeps concurrent instances from running the same sync or topic copy at once
+    let distributed_lock = Arc::new(DistributedLock::new(db_connection.clone()));
+
+    // Audit trail for destructive operations (database table or Kafka topic)
+    let audit_emitter = Arc::new(AuditEventEmitter::from_config(
+        &config,
+        audit_event_repo.clone(),
+    )?);
 
     let llm_provider_service = Arc::new(LlmProviderService::new(llm_provider_repo.clone()));
 
     // Initialize services
     let user_service = Arc::new(UserService::new(user_repo.clone()));
-    let kafka_service = Arc::new(KafkaService::new(cluster_repo.clone()));
+    let kafka_service = Arc::new(
+        KafkaService::new(cluster_repo.clone())
+            .with_audit_emitter(audit_emitter.clone())
+            .with_distributed_lock(distributed_lock.clone())
+            .with_config_restores(Arc::new(KafkaConfigRestoreRepository::new(
+                db_connection.clone(),
+            ))),
+    );
+    kafka_service.start_config_restore(config.clone());
+    let schema_registry_service = Arc::new(
+        SchemaRegistryService::new(kafka_service.clone()).with_audit_emitter(audit_emitter.clone()),
+    );
+    let opensearch_service = Arc::new(OpenSearchService::new());
 
     // AWS services
     let aws_service = Arc::new(AwsService::new(
@@ -157,7 +224,17 @@ pub async fn run_server(host: String, port: u16, config: Config) -> Result<(), B
         cloud_resource_repo.clone(),
         config.clone(),
     ));
-    let aws_control_plane = Arc::new(AwsControlPlane::new(aws_service.clone()));
+    let aws_control_plane = Arc::new(
+        AwsControlPlane::new(aws_service.clone())
+            .with_security_finding_repo(Arc::new(SecurityFindingRepository::new(
+                db_connection.clone(),
+            )))
+            .with_config_violation_repo(Arc::new(ConfigViolationRepository::new(
+                db_connection.clone(),
+            )))
+            .with_distributed_lock(distributed_lock.clone())
+            .with_audit_emitter(audit_emitter.clone()),
+    );
     let aws_data_plane = Arc::new(AwsDataPlane::new(aws_service.clone()));
     let aws_cost_service = Arc::new(AwsCostService::new(aws_service.clone()));
     let cloudwatch_service = Arc::new(CloudWatchService::new(aws_service.clone()));
@@ -166,11 +243,17 @@ pub async fn run_server(host: String, port: u16, config: Config) -> Result<(), B
         aws_control_plane.clone(),
         sync_run_repo.clone(),
     ));
+    let task_queue = Arc::new(TaskQueue::new(
+        Arc::new(AsyncTaskRepository::new(db_connection.clone())),
+        aws_account_service.clone(),
+    ));
+    task_queue.start_worker();
 
     // Initialize LLM Integration service first (needed by AWS analytics)
     let llm_integration_service = Arc::new(LlmIntegrationService::new(
         llm_provider_repo.clone(),
         prompt_template_repo.clone(),
+        Arc::new(LlmSessionRepository::new(db_connection.clone())),
     ));
 
     // AWS Analytics service with real LLM integration
@@ -205,9 +288,23 @@ pub async fn run_server(host: String, port: u16, config: Config) -> Result<(), B
         prompt_template_repo.clone(),
     ));
 
+    // Cost anomaly alerts go to the configured webhook and, when SES is set up, by email
+    let mut cost_anomaly_notifier =
+        CostAnomalyNotifier::new(config.cost_anomaly_webhook_url.clone());
+    if let Some(ses) = &config.ses {
+        let email_notifier = Arc::new(
+            EmailNotifier::new(ses)
+                .await
+                .with_template_repo(prompt_template_repo.clone()),
+        );
+        cost_anomaly_notifier =
+            cost_anomaly_notifier.with_email(email_notifier, ses.alert_recipients.clone());
+    }
+    let cost_anomaly_notifier = Arc::new(cost_anomaly_notifier);
+
     // AWS Cost Analytics service
-    let aws_cost_analytics_service = {
-        Arc::new(AwsCostAnalyticsService::new(
+    let aws_cost_analytics_service = Arc::new(
+        AwsCostAnalyticsService::new(
             cost_analytics_repo.clone(),
             aws_account_repo.clone(),
             aws_resource_repo.clone(),
@@ -215,8 +312,20 @@ pub async fn run_server(host: String, port: u16, config: Config) -> Result<(), B
             llm_integration_service.clone(),
             llm_provider_repo.clone(),
             db_connection.clone(),
-        ))
-    };
+        )
+        .with_anomaly_notifier(cost_anomaly_notifier)
+        .with_anomaly_detection_config(config.cost_analytics.anomaly_detection.clone())
+        .with_cost_allocation_tags(config.cost_analytics.cost_allocation_tags.clone())
+        .with_audit_emitter(audit_emitter.clone()),
+    );
+    aws_cost_analytics_service.start_savings_plan_refresh();
+    aws_cost_analytics_service.start_tag_cost_summaries();
+    let cost_optimization_advisor = Arc::new(CostOptimizationAdvisor::new(
+        cost_analytics_repo.clone(),
+        llm_integration_service.clone(),
+        llm_provider_repo.clone(),
+        Arc::new(AIAnalysisRepository::new(db_connection.clone())),
+    ));
 
     // Chaos Engineering audit and metrics services
     let chaos_audit_service = Arc::new(ChaosAuditService::new(chaos_audit_repo.clone()));
@@ -230,41 +339,110 @@ pub async fn run_server(host: String, port: u16, config: Config) -> Result<(), B
         chaos_audit_service.clone(),
         chaos_metrics_service.clone(),
     ));
+    let aurora_cluster_service = Arc::new(AuroraClusterService::new(
+        aws_service.clone(),
+        aws_account_repo.clone(),
+    ));
+    let chaos_scheduler = Arc::new(
+        ChaosScheduler::new(chaos_service.clone(), chaos_audit_service.clone()).with_trigger_store(
+            Arc::new(ChaosAutoTriggerRepository::new(db_connection.clone())),
+        ),
+    );
+    chaos_scheduler.start_restore(cluster_repo.clone());
 
     // Initialize Kubernetes Services
-    let deployments_service = Arc::new(DeploymentsService::new());
-    let stateful_sets_service = Arc::new(StatefulSetsService::new());
-    let daemon_sets_service = Arc::new(DaemonSetsService::new());
-    let pod_service = Arc::new(PodService::new());
+    let deployments_service =
+        Arc::new(DeploymentsService::new().with_audit_emitter(audit_emitter.clone()));
+    let stateful_sets_service =
+        Arc::new(StatefulSetsService::new().with_audit_emitter(audit_emitter.clone()));
+    let daemon_sets_service =
+        Arc::new(DaemonSetsService::new().with_audit_emitter(audit_emitter.clone()));
+    let pod_service = Arc::new(PodService::new().with_audit_emitter(audit_emitter.clone()));
+    let canary_analyzer = Arc::new(DeploymentCanaryAnalyzer::new(pod_service.clone()));
+    let scheduling_analyzer = Arc::new(SchedulingAnalyzer::new(pod_service.clone()));
+    let admission_simulator = Arc::new(AdmissionWebhookSimulator::new());
+    let affinity_analyzer = Arc::new(AffinityAnalyzer::new());
     let k8s_services_service = Arc::new(K8sServicesService::new());
     let nodes_service = Arc::new(NodesService::new());
-    let namespaces_service = Arc::new(NamespacesService::new());
+    let namespaces_service =
+        Arc::new(NamespacesService::new().with_audit_emitter(audit_emitter.clone()));
     let persistent_volume_claims_service = Arc::new(PersistentVolumeClaimsService::new());
-    let persistent_volumes_service = Arc::new(PersistentVolumesService::new());
-    let configmaps_service =
-        Arc::new(crate::services::kubernetes::configmaps_service::ConfigMapsService::new());
-    let secrets_service =
-        Arc::new(crate::services::kubernetes::secrets_service::SecretsService::new());
+    let persistent_volumes_service =
+        Arc::new(PersistentVolumesService::new().with_audit_emitter(audit_emitter.clone()));
+    let configmaps_service = Arc::new(
+        crate::services::kubernetes::configmaps_service::ConfigMapsService::new()
+            .with_audit_emitter(audit_emitter.clone()),
+    );
+    let secrets_service = Arc::new(
+        crate::services::kubernetes::secrets_service::SecretsService::new()
+            .with_audit_emitter(audit_emitter.clone()),
+    );
     let metrics_service = Arc::new(MetricsService::new());
-    let jobs_service = Arc::new(JobsService::new());
-    let cronjobs_service = Arc::new(CronJobsService::new());
-    let ingress_service = Arc::new(IngressService::new());
-    let endpoints_service = Arc::new(EndpointsService::new());
-    let network_policies_service = Arc::new(NetworkPoliciesService::new());
-    let hpa_service = Arc::new(HorizontalPodAutoscalerService::new());
-    let pdb_service = Arc::new(PodDisruptionBudgetsService::new());
-    let resource_quotas_service = Arc::new(ResourceQuotasService::new());
-    let limit_ranges_service = Arc::new(LimitRangesService::new());
-    let service_accounts_service = Arc::new(ServiceAccountsService::new());
-    let rbac_service = Arc::new(RbacService::new());
+    let jobs_service = Arc::new(JobsService::new().with_audit_emitter(audit_emitter.clone()));
+    let cronjobs_service =
+        Arc::new(CronJobsService::new().with_audit_emitter(audit_emitter.clone()));
+    let alerting_service = Arc::new(AlertingService::new(Arc::new(
+        AlertFiringRepository::new(db_connection.clone()),
+    )));
+    alerting_service.start(cluster_repo.clone(), config.kubernetes.alerting.clone());
+    let ingress_service = Arc::new(IngressService::new().with_audit_emitter(audit_emitter.clone()));
+    let endpoints_service =
+        Arc::new(EndpointsService::new().with_audit_emitter(audit_emitter.clone()));
+    let network_policies_service =
+        Arc::new(NetworkPoliciesService::new().with_audit_emitter(audit_emitter.clone()));
+    let hpa_service =
+        Arc::new(HorizontalPodAutoscalerService::new().with_audit_emitter(audit_emitter.clone()));
+    let pdb_service =
+        Arc::new(PodDisruptionBudgetsService::new().with_audit_emitter(audit_emitter.clone()));
+    let resource_quotas_service =
+        Arc::new(ResourceQuotasService::new().with_audit_emitter(audit_emitter.clone()));
+    let limit_ranges_service =
+        Arc::new(LimitRangesService::new().with_audit_emitter(audit_emitter.clone()));
+    let service_accounts_service =
+        Arc::new(ServiceAccountsService::new().with_audit_emitter(audit_emitter.clone()));
+    let rbac_service = Arc::new(RbacService::new().with_audit_emitter(audit_emitter.clone()));
     let authorization_service = Arc::new(AuthorizationService::new());
     let node_ops_service = Arc::new(NodeOpsService::new());
+    let label_service = Arc::new(LabelService::new());
     let replica_sets_service = Arc::new(ReplicaSetsService);
     let storage_classes_service = Arc::new(StorageClassesService);
     let crds_service = Arc::new(CrdsService);
+    let dependency_graph_service = Arc::new(DependencyGraphService);
+    let multi_cluster_aggregator = Arc::new(MultiClusterAggregator::new(
+        pod_service.clone(),
+        deployments_service.clone(),
+        nodes_service.clone(),
+    ));
+    let garbage_collection_service =
+        Arc::new(GarbageCollectionService::new().with_audit_emitter(audit_emitter.clone()));
+    let secret_rotation_service =
+        Arc::new(SecretRotationService::new().with_audit_emitter(audit_emitter.clone()));
+    let prometheus_rule_service =
+        Arc::new(PrometheusRuleService::new().with_audit_emitter(audit_emitter.clone()));
+    let workload_cost_estimator = Arc::new(WorkloadCostEstimator::new());
+    let cluster_version_service = Arc::new(ClusterVersionService);
+    let manifest_generator_service =
+        Arc::new(ManifestGeneratorService::new(unified_llm_manager.clone()));
+    let graphql_schema = routes::graphql::build_schema(
+        db_connection.clone(),
+        Arc::new(PodStatusBroadcaster::new()),
+    );
+
+    // Config hot reload; a failed watcher only disables automatic reloads
+    let shared_config = SharedConfig::new(config.clone());
 
     // Initialize controllers
-    let auth_controller = Arc::new(AuthController::new(user_service.clone(), config.clone()));
+    let mut auth_controller = AuthController::new(user_service.clone(), shared_config.clone());
+    if config.auth.enable_oidc {
+        // An unreachable identity provider disables OIDC login instead of stopping the server
+        match OidcProvider::discover(&config.auth).await {
+            Ok(oidc_provider) => {
+                auth_controller = auth_controller.with_oidc_provider(Arc::new(oidc_provider));
+            }
+            Err(e) => error!("OIDC login disabled: {}", e),
+        }
+    }
+    let auth_controller = Arc::new(auth_controller);
     let aws_analytics_controller =
         Arc::new(AwsAnalyticsController::new(aws_analytics_service.clone()));
 
@@ -300,6 +478,45 @@ pub async fn run_server(host: String, port: u16, config: Config) -> Result<(), B
     let kinesis_data_plane = Arc::new(KinesisDataPlane::new(aws_service.clone()));
     let kinesis_control_plane = Arc::new(KinesisControlPlane::new(aws_service.clone()));
 
+    let mut config_reloader = ConfigReloader::new(shared_config.clone());
+    if let Some(provider) = secret_provider {
+        config_reloader = config_reloader.with_secret_provider(provider);
+    }
+    let config_reloader = Arc::new(config_reloader);
+    if let Err(e) = config_reloader.start_watching() {
+        warn!("Config file watching disabled: {}", e);
+    }
+    config_reloader.start_secret_refresh();
+    let kafka_topic_monitor = Arc::new(KafkaTopicMonitor::new(
+        kafka_service.clone(),
+        Arc::new(KafkaTopicThroughputRepository::new(db_connection.clone())),
+    ));
+    kafka_topic_monitor.start(shared_config.clone(), config.kafka.topic_monitor.clone());
+    let mut config_events = config_reloader.subscribe();
+    tokio::spawn(async move {
+        loop {
+            let event = match config_events.recv().await {
+                Ok(event) => event,
+                // Missed events still mean "something changed"; treat it as a full change
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {
+                    crate::config::reloader::ConfigChangedEvent {
+                        reloaded_at: chrono::Utc::now(),
+                        kafka_changed: true,
+                        kubernetes_changed: true,
+                    }
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            };
+            if event.kubernetes_changed {
+                KubernetesClientFactory::invalidate_cache();
+            }
+            // Kafka clients are built per request from the shared config, nothing to drop
+            if event.kafka_changed {
+                info!("Kafka cluster configuration changed");
+            }
+        }
+    });
+
     // Create and start the HTTP server
     HttpServer::new(move || {
         let cors = Cors::default()
@@ -314,11 +531,15 @@ pub async fn run_server(host: String, port: u16, config: Config) -> Result<(), B
         App::new()
             .wrap(cors)
             .wrap(Logger::default())
-            .wrap(AuthMiddleware::new(&config))
+            .wrap(AuthMiddleware::new(&shared_config))
+            // Outermost, so auth and handler spans nest under the request's correlation ID
+            .wrap(RequestIdMiddleware)
             // Global JSON config: limit large payloads (256KB)
             .app_data(web::JsonConfig::default().limit(256 * 1024))
             .app_data(web::Data::new(db_connection.clone())) // Now correctly Data<Arc<DatabaseConnection>>
             .app_data(web::Data::new(config.clone()))
+            .app_data(web::Data::new(shared_config.clone()))
+            .app_data(web::Data::new(config_reloader.clone()))
             // Repositories
             .app_data(web::Data::new(user_repo.clone()))
             .app_data(web::Data::new(database_repo.clone()))
@@ -326,6 +547,7 @@ pub async fn run_server(host: String, port: u16, config: Config) -> Result<(), B
             .app_data(web::Data::new(aws_resource_repo.clone()))
             .app_data(web::Data::new(cloud_resource_repo.clone()))
             .app_data(web::Data::new(aws_account_repo.clone()))
+            .app_data(web::Data::new(aurora_cluster_service.clone()))
             .app_data(web::Data::new(data_source_repo.clone()))
             .app_data(web::Data::new(llm_provider_repo.clone()))
             .app_data(web::Data::new(prompt_template_repo.clone()))
@@ -333,12 +555,16 @@ pub async fn run_server(host: String, port: u16, config: Config) -> Result<(), B
             // Services
             .app_data(web::Data::new(user_service.clone()))
             .app_data(web::Data::new(kafka_service.clone()))
+            .app_data(web::Data::new(schema_registry_service.clone()))
+            .app_data(web::Data::new(kafka_topic_monitor.clone()))
+            .app_data(web::Data::new(opensearch_service.clone()))
             .app_data(web::Data::new(aws_service.clone()))
             .app_data(web::Data::new(aws_control_plane.clone()))
             .app_data(web::Data::new(aws_data_plane.clone()))
             .app_data(web::Data::new(aws_cost_service.clone()))
             .app_data(web::Data::new(cloudwatch_service.clone()))
             .app_data(web::Data::new(aws_account_service.clone()))
+            .app_data(web::Data::new(task_queue.clone()))
             .app_data(web::Data::new(aws_analytics_service.clone()))
             .app_data(web::Data::new(llm_integration_service.clone()))
             .app_data(web::Data::new(llm_provider_service.clone()))
@@ -346,15 +572,21 @@ pub async fn run_server(host: String, port: u16, config: Config) -> Result<(), B
             .app_data(web::Data::new(llm_analytics_service.clone()))
             .app_data(web::Data::new(unified_llm_manager.clone()))
             .app_data(web::Data::new(aws_cost_analytics_service.clone()))
+            .app_data(web::Data::new(cost_optimization_advisor.clone()))
             // Chaos Engineering
             .app_data(web::Data::new(chaos_service.clone()))
             .app_data(web::Data::new(chaos_audit_service.clone()))
             .app_data(web::Data::new(chaos_metrics_service.clone()))
+            .app_data(web::Data::new(chaos_scheduler.clone()))
             // Kubernetes Services
             .app_data(web::Data::new(deployments_service.clone()))
             .app_data(web::Data::new(stateful_sets_service.clone()))
             .app_data(web::Data::new(daemon_sets_service.clone()))
             .app_data(web::Data::new(pod_service.clone()))
+            .app_data(web::Data::new(canary_analyzer.clone()))
+            .app_data(web::Data::new(scheduling_analyzer.clone()))
+            .app_data(web::Data::new(admission_simulator.clone()))
+            .app_data(web::Data::new(affinity_analyzer.clone()))
             .app_data(web::Data::new(k8s_services_service.clone()))
             .app_data(web::Data::new(nodes_service.clone()))
             .app_data(web::Data::new(namespaces_service.clone()))
@@ -365,17 +597,25 @@ pub async fn run_server(host: String, port: u16, config: Config) -> Result<(), B
             .app_data(web::Data::new(metrics_service.clone()))
             .app_data(web::Data::new(jobs_service.clone()))
             .app_data(web::Data::new(cronjobs_service.clone()))
+            .app_data(web::Data::new(alerting_service.clone()))
             .app_data(web::Data::new(ingress_service.clone()))
             .app_data(web::Data::new(endpoints_service.clone()))
             .app_data(web::Data::new(network_policies_service.clone()))
             .app_data(web::Data::new(hpa_service.clone()))
             .app_data(web::Data::new(pdb_service.clone()))
+            .app_data(web::Data::new(garbage_collection_service.clone()))
+            .app_data(web::Data::new(secret_rotation_service.clone()))
+            .app_data(web::Data::new(prometheus_rule_service.clone()))
+            .app_data(web::Data::new(workload_cost_estimator.clone()))
+            .app_data(web::Data::new(dependency_graph_service.clone()))
+            .app_data(web::Data::new(multi_cluster_aggregator.clone()))
             .app_data(web::Data::new(resource_quotas_service.clone()))
             .app_data(web::Data::new(limit_ranges_service.clone()))
             .app_data(web::Data::new(service_accounts_service.clone()))
             .app_data(web::Data::new(rbac_service.clone()))
             .app_data(web::Data::new(authorization_service.clone()))
             .app_data(web::Data::new(node_ops_service.clone()))
+            .app_data(web::Data::new(label_service.clone()))
             // Controllers
             .app_data(web::Data::new(auth_controller.clone()))
             .app_data(web::Data::new(aws_analytics_controller.clone()))
@@ -400,6 +640,9 @@ pub async fn run_server(host: String, port: u16, config: Config) -> Result<(), B
             .app_data(web::Data::new(replica_sets_service.clone()))
             .app_data(web::Data::new(storage_classes_service.clone()))
             .app_data(web::Data::new(crds_service.clone()))
+            .app_data(web::Data::new(cluster_version_service.clone()))
+            .app_data(web::Data::new(manifest_generator_service.clone()))
+            .app_data(web::Data::new(graphql_schema.clone()))
             // Middleware
             // Routes configuration - specify the order: analytics first, then general routes
             .configure(|cfg_param: &mut web::ServiceConfig| {
@@ -445,6 +688,7 @@ pub async fn run_server(host: String, port: u16, config: Config) -> Result<(), B
                 routes::budget::configure_routes(
                     cfg_param,
                     cost_budget_repo.clone(),
+                    aws_service.clone(),
                 );
 
                 info!("Registering other general routes");
@@ -16,6 +16,7 @@ use clap::Subcommand;
 use std::error::Error;
 
 use crate::config::Config;
+use crate::services::chaos_service::run_aurora_failover;
 
 #[derive(Subcommand)]
 pub enum ChaosCommands {
@@ -65,6 +66,33 @@ pub enum ChaosCommands {
         #[arg(short, long)]
         resource_id: String,
     },
+
+    /// Force an Aurora cluster failover and measure the time to a new writer
+    AuroraFailover {
+        /// Aurora DB cluster identifier
+        #[arg(short, long)]
+        cluster_id: String,
+
+        /// AWS region of the cluster
+        #[arg(short, long)]
+        region: String,
+
+        /// Reader instance to promote (Aurora picks one when omitted)
+        #[arg(long)]
+        target_instance: Option<String>,
+
+        /// AWS profile to use (default credential chain when omitted)
+        #[arg(long)]
+        profile: Option<String>,
+
+        /// Also wait for every instance in the cluster to become available
+        #[arg(long, default_value_t = false)]
+        wait_for_recovery: bool,
+
+        /// Seconds to wait before giving up
+        #[arg(long, default_value_t = 600)]
+        timeout_secs: u64,
+    },
 }
 
 pub async fn handle_command(command: ChaosCommands, config: &Config) -> Result<(), Box<dyn Error>> {
@@ -138,5 +166,52 @@ pub async fn handle_command(command: ChaosCommands, config: &Config) -> Result<(
             );
             Ok(())
         }
+
+        ChaosCommands::AuroraFailover {
+            cluster_id,
+            region,
+            target_instance,
+            profile,
+            wait_for_recovery,
+            timeout_secs,
+        } => {
+            println!("Failing over Aurora cluster {} in {}", cluster_id, region);
+            let mut loader = aws_config::defaults(aws_config::BehaviorVersion::latest())
+                .region(aws_types::region::Region::new(region));
+            if let Some(profile) = profile {
+                loader = loader.profile_name(profile);
+            }
+            let rds_client = aws_sdk_rds::Client::new(&loader.load().await);
+
+            let result = run_aurora_failover(
+                &rds_client,
+                &cluster_id,
+                target_instance.as_deref(),
+                wait_for_recovery,
+                timeout_secs,
+            )
+            .await?;
+
+            println!("  Previous writer: {}", result.previous_writer);
+            match &result.new_writer {
+                Some(writer) => println!(
+                    "  New writer:      {} (after {}s)",
+                    writer, result.failover_duration_secs
+                ),
+                None => println!(
+                    "  Writer did not change within {}s",
+                    result.failover_duration_secs
+                ),
+            }
+            println!(
+                "  Recovery:        {}",
+                if result.recovery_detected {
+                    "detected"
+                } else {
+                    "not detected"
+                }
+            );
+            Ok(())
+        }
     }
 }
@@ -15,8 +15,12 @@
 
 use clap::Subcommand;
 use std::error::Error;
+use std::sync::Arc;
 
-use crate::config::Config;
+use crate::config::{Config, GcpStorageConfig};
+use crate::repositories::cloud_resource::CloudResourceRepository;
+use crate::services::gcp::GcpStorageService;
+use crate::utils::database;
 
 #[derive(Subcommand)]
 pub enum CloudCommands {
@@ -34,6 +38,12 @@ pub enum CloudCommands {
         #[command(subcommand)]
         command: AzureCommands,
     },
+
+    /// GCP specific commands
+    Gcp {
+        #[command(subcommand)]
+        command: GcpCommands,
+    },
 }
 
 #[derive(Subcommand)]
@@ -308,6 +318,79 @@ pub enum AzureCommands {
     },
 }
 
+#[derive(Subcommand)]
+pub enum GcpCommands {
+    /// List Cloud Storage buckets in the configured project
+    Buckets {
+        /// Configured bucket whose project and credentials to use (defaults to the first)
+        #[arg(short, long)]
+        bucket: Option<String>,
+
+        /// Also store the buckets in the cloud resource inventory
+        #[arg(long)]
+        store: bool,
+    },
+
+    /// List objects in a configured bucket
+    Objects {
+        /// Configured bucket (defaults to the first)
+        #[arg(short, long)]
+        bucket: Option<String>,
+
+        /// Only list objects whose name starts with this prefix
+        #[arg(short, long)]
+        prefix: Option<String>,
+    },
+
+    /// Download an object
+    Get {
+        /// Configured bucket (defaults to the first)
+        #[arg(short, long)]
+        bucket: Option<String>,
+
+        /// Object name
+        #[arg(short, long)]
+        object: String,
+
+        /// File to write to (defaults to stdout)
+        #[arg(long)]
+        output: Option<String>,
+    },
+
+    /// Upload a file as an object
+    Upload {
+        /// Configured bucket (defaults to the first)
+        #[arg(short, long)]
+        bucket: Option<String>,
+
+        /// Object name
+        #[arg(short, long)]
+        object: String,
+
+        /// File to upload
+        #[arg(short, long)]
+        file: String,
+
+        /// Content type of the object
+        #[arg(long, default_value = "application/octet-stream")]
+        content_type: String,
+    },
+}
+
+fn gcp_storage_config<'a>(
+    config: &'a Config,
+    bucket: Option<&str>,
+) -> Result<&'a GcpStorageConfig, Box<dyn Error>> {
+    let found = match bucket {
+        Some(bucket) => config.cloud.gcp.iter().find(|c| c.bucket == bucket),
+        None => config.cloud.gcp.first(),
+    };
+    found.ok_or_else(|| match bucket {
+        Some(bucket) => format!("GCP bucket '{}' not found in configuration", bucket).into(),
+        None => "No GCP storage configuration found".into(),
+    })
+}
+
 pub async fn handle_command(command: CloudCommands, config: &Config) -> Result<(), Box<dyn Error>> {
     match command {
         CloudCommands::List => {
@@ -330,6 +413,13 @@ pub async fn handle_command(command: CloudCommands, config: &Config) -> Result<(
                 }
             }
 
+            if !config.cloud.gcp.is_empty() {
+                println!("GCP:");
+                for storage in &config.cloud.gcp {
+                    println!("  - gs://{} ({})", storage.bucket, storage.project_id);
+                }
+            }
+
             Ok(())
         }
 
@@ -674,5 +764,81 @@ pub async fn handle_command(command: CloudCommands, config: &Config) -> Result<(
                 }
             }
         }
+
+        CloudCommands::Gcp { command } => {
+            let service = GcpStorageService::new();
+            match command {
+                GcpCommands::Buckets { bucket, store } => {
+                    let gcp_config = gcp_storage_config(config, bucket.as_deref())?;
+                    let buckets = service.list_buckets(gcp_config).await?;
+                    println!("GCS buckets in project {}:", gcp_config.project_id);
+                    for bucket in &buckets {
+                        println!("  - {} ({})", bucket.resource_id, bucket.region);
+                    }
+
+                    if store {
+                        let db = database::connect(config).await?;
+                        let repo = CloudResourceRepository::new(Arc::new(db));
+                        let sync_id = service.store_buckets(&repo, &buckets).await?;
+                        println!("Stored {} buckets (sync {})", buckets.len(), sync_id);
+                    }
+                    Ok(())
+                }
+
+                GcpCommands::Objects { bucket, prefix } => {
+                    let gcp_config = gcp_storage_config(config, bucket.as_deref())?;
+                    let objects = service.list_objects(gcp_config, prefix.as_deref()).await?;
+                    println!("Objects in gs://{}:", gcp_config.bucket);
+                    for object in objects {
+                        println!(
+                            "  - {} ({} bytes, {})",
+                            object.name,
+                            object.size,
+                            object.content_type.as_deref().unwrap_or("unknown type")
+                        );
+                    }
+                    Ok(())
+                }
+
+                GcpCommands::Get {
+                    bucket,
+                    object,
+                    output,
+                } => {
+                    let gcp_config = gcp_storage_config(config, bucket.as_deref())?;
+                    let data = service.get_object(gcp_config, &object).await?;
+                    match output {
+                        Some(path) => {
+                            std::fs::write(&path, &data)?;
+                            println!("Wrote {} bytes to {}", data.len(), path);
+                        }
+                        None => {
+                            use std::io::Write;
+                            std::io::stdout().write_all(&data)?;
+                        }
+                    }
+                    Ok(())
+                }
+
+                GcpCommands::Upload {
+                    bucket,
+                    object,
+                    file,
+                    content_type,
+                } => {
+                    let gcp_config = gcp_storage_config(config, bucket.as_deref())?;
+                    let data = std::fs::read(&file)
+                        .map_err(|e| format!("Failed to read {}: {}", file, e))?;
+                    let uploaded = service
+                        .upload_object(gcp_config, &object, data, &content_type)
+                        .await?;
+                    println!(
+                        "Uploaded gs://{}/{} ({} bytes)",
+                        gcp_config.bucket, uploaded.name, uploaded.size
+                    );
+                    Ok(())
+                }
+            }
+        }
     }
 }
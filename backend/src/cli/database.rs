@@ -16,9 +16,14 @@
 use clap::Subcommand;
 use sea_orm_migration::prelude::*;
 use std::error::Error;
+use std::path::PathBuf;
 use tracing::{error, info};
 
 use crate::config::Config;
+use crate::utils::database::{connect_to_specific_mysql, connect_to_specific_postgres};
+use crate::utils::migration_runner::{
+    discover_migrations, MigrationRunner, MySqlMigrationRunner, PostgresMigrationRunner,
+};
 
 #[derive(Subcommand)]
 pub enum DbCommands {
@@ -29,15 +34,20 @@ pub enum DbCommands {
         name: String,
     },
 
-    /// Run database migrations
+    /// Run numbered SQL migrations (001_*.sql) against a configured database
     Migrate {
-        /// Migration direction (up or down)
-        #[arg(short, long, default_value = "up")]
-        direction: String,
-
-        /// Number of steps for down migration (optional)
+        /// Name of the PostgreSQL or MySQL connection from the configuration
         #[arg(short, long)]
-        steps: Option<u32>,
+        connection: String,
+
+        /// Directory containing the migration files
+        #[arg(short, long, default_value = "migrations")]
+        migrations_dir: PathBuf,
+
+        /// Run pending migrations in a transaction and roll it back; on MySQL,
+        /// where DDL commits implicitly, only list them
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
     },
 
     /// Print migration status
@@ -53,30 +63,49 @@ pub async fn handle_command(command: DbCommands, config: &Config) -> Result<(),
             Ok(())
         }
 
-        DbCommands::Migrate { direction, steps } => {
-            match direction.as_str() {
-                "up" => {
-                    info!("Running migrations UP");
-                    // TODO: Implement migration up logic
-                    println!("Migrations applied successfully");
-                    Ok(())
-                }
-                "down" => {
-                    let steps = steps.unwrap_or(1);
-                    info!("Running migrations DOWN {} step(s)", steps);
-                    // TODO: Implement migration down logic
-                    println!("Migrations reverted successfully");
-                    Ok(())
+        DbCommands::Migrate {
+            connection,
+            migrations_dir,
+            dry_run,
+        } => {
+            let migrations = discover_migrations(&migrations_dir)?;
+            info!(
+                "Found {} migration file(s) in {}",
+                migrations.len(),
+                migrations_dir.display()
+            );
+
+            let runner = build_migration_runner(&connection, config).await?;
+            let report = runner.run(&migrations, dry_run).await.map_err(|e| {
+                error!("Migration run failed: {}", e);
+                e
+            })?;
+
+            match report.last_applied_version {
+                Some(v) => println!("Last applied version before run: {}", v),
+                None => println!("No migrations applied previously"),
+            }
+            if report.applied.is_empty() {
+                println!("Database '{}' is up to date", connection);
+            } else {
+                for name in &report.applied {
+                    println!("  {}", name);
                 }
-                _ => {
-                    error!("Invalid migration direction: {}", direction);
-                    Err(format!(
-                        "Invalid migration direction: {}. Use 'up' or 'down'.",
-                        direction
-                    )
-                    .into())
+                if report.dry_run && !report.executed {
+                    println!(
+                        "Dry run: {} pending migration(s) listed, not executed",
+                        report.applied.len()
+                    );
+                } else if report.dry_run {
+                    println!(
+                        "Dry run: {} migration(s) executed and rolled back",
+                        report.applied.len()
+                    );
+                } else {
+                    println!("{} migration(s) applied successfully", report.applied.len());
                 }
             }
+            Ok(())
         }
 
         DbCommands::Status => {
@@ -89,3 +118,19 @@ pub async fn handle_command(command: DbCommands, config: &Config) -> Result<(),
         }
     }
 }
+
+// Resolve a named connection from the configuration, checking PostgreSQL first, then MySQL
+async fn build_migration_runner(
+    name: &str,
+    config: &Config,
+) -> Result<Box<dyn MigrationRunner>, Box<dyn Error>> {
+    if let Some(pg) = config.database.postgres.iter().find(|c| c.name == name) {
+        let db = connect_to_specific_postgres(pg).await?;
+        return Ok(Box::new(PostgresMigrationRunner::new(db)));
+    }
+    if let Some(mysql) = config.database.mysql.iter().find(|c| c.name == name) {
+        let db = connect_to_specific_mysql(mysql).await?;
+        return Ok(Box::new(MySqlMigrationRunner::new(db)));
+    }
+    Err(format!("No PostgreSQL or MySQL connection named '{}' in configuration", name).into())
+}
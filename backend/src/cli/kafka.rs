@@ -14,13 +14,21 @@
 
 
 use crate::config::Config;
+use crate::repositories::cluster::ClusterRepository;
+use crate::services::kafka::{KafkaService, TopicDefinition};
+use crate::services::kafka_benchmark::{BenchmarkConfig, KafkaBenchmarkService};
+use crate::utils::database;
 use clap::Subcommand;
 use rdkafka::admin::{AdminClient};
 use rdkafka::config::ClientConfig;
 use rdkafka::message::{Headers};
 use rdkafka::producer::{FutureProducer};
+use sea_orm::DatabaseConnection;
 use std::error::Error;
 use std::fs;
+use std::io::Read;
+use std::sync::Arc;
+use std::time::Duration;
 
 #[derive(Subcommand, Debug)]
 pub enum KafkaCommands {
@@ -101,6 +109,66 @@ pub enum KafkaCommands {
         #[arg(long)]
         from_beginning: bool,
     },
+
+    /// Measure produce and consume throughput against a topic
+    Benchmark {
+        /// Name of the Kafka cluster
+        #[arg(short, long)]
+        cluster: String,
+
+        /// Name of the topic
+        #[arg(short, long)]
+        topic: String,
+
+        /// Number of concurrent producers
+        #[arg(long, default_value_t = 1)]
+        producers: usize,
+
+        /// Number of concurrent consumers
+        #[arg(long, default_value_t = 1)]
+        consumers: usize,
+
+        /// Size of each message in bytes
+        #[arg(long, default_value_t = 1024)]
+        message_size: usize,
+
+        /// Benchmark duration in seconds
+        #[arg(long, default_value_t = 30)]
+        duration: u64,
+    },
+
+    /// Print a topic's partition layout and config overrides as JSON
+    ExportTopic {
+        /// Name (or stored cluster ID) of the Kafka cluster
+        #[arg(short, long)]
+        cluster: String,
+
+        /// Name of the topic
+        #[arg(short, long)]
+        topic: String,
+    },
+
+    /// Create a topic from a JSON definition read from stdin
+    ImportTopic {
+        /// Name (or stored cluster ID) of the Kafka cluster
+        #[arg(short, long)]
+        cluster: String,
+
+        /// Succeed without changes if the topic already exists
+        #[arg(long)]
+        exists_ok: bool,
+    },
+}
+
+// Clusters stored in the database need the app database; config-defined ones work without it
+async fn kafka_service(config: &Config) -> KafkaService {
+    let db = database::connect(config)
+        .await
+        .unwrap_or(DatabaseConnection::Disconnected);
+    KafkaService::new(Arc::new(ClusterRepository::new(
+        Arc::new(db),
+        config.clone(),
+    )))
 }
 
 pub async fn handle_command(command: KafkaCommands, config: &Config) -> Result<(), Box<dyn Error>> {
@@ -357,6 +425,82 @@ pub async fn handle_command(command: KafkaCommands, config: &Config) -> Result<(
             // For simplicity in this example, we'll just log that we would do this
             println!("In a real implementation, this would consume messages from the Kafka topic");
         }
+        KafkaCommands::Benchmark {
+            cluster,
+            topic,
+            producers,
+            consumers,
+            message_size,
+            duration,
+        } => {
+            let kafka_cluster = config
+                .kafka
+                .clusters
+                .iter()
+                .find(|c| c.name == cluster)
+                .ok_or_else(|| format!("Kafka cluster '{}' not found in configuration", cluster))?;
+
+            println!(
+                "Benchmarking topic '{}' in cluster '{}' for {}s ({} producers, {} consumers, {} byte messages)",
+                topic, cluster, duration, producers, consumers, message_size
+            );
+
+            let result = KafkaBenchmarkService::run(BenchmarkConfig {
+                cluster: kafka_cluster.clone(),
+                topic,
+                producers,
+                consumers,
+                message_size,
+                duration: Duration::from_secs(duration),
+            })
+            .await?;
+
+            println!();
+            println!("{:<24} {:>14} {:>12}", "", "msgs/sec", "MB/sec");
+            println!(
+                "{:<24} {:>14.1} {:>12.2}",
+                "Producer", result.producer_msgs_per_sec, result.producer_mb_per_sec
+            );
+            println!(
+                "{:<24} {:>14.1} {:>12.2}",
+                "Consumer", result.consumer_msgs_per_sec, result.consumer_mb_per_sec
+            );
+            println!("{:<24} {:>14.2}", "p99 produce latency (ms)", result.p99_produce_latency_ms);
+            println!("{:<24} {:>14}", "Errors", result.error_count);
+        }
+        KafkaCommands::ExportTopic { cluster, topic } => {
+            let definition = kafka_service(config)
+                .await
+                .export_topic_definition(&cluster, &topic, config)
+                .await?;
+            println!("{}", serde_json::to_string_pretty(&definition)?);
+        }
+        KafkaCommands::ImportTopic { cluster, exists_ok } => {
+            let mut input = String::new();
+            std::io::stdin().read_to_string(&mut input)?;
+            let definition: TopicDefinition = serde_json::from_str(&input)
+                .map_err(|e| format!("Invalid topic definition: {}", e))?;
+
+            let created = kafka_service(config)
+                .await
+                .import_topic_definition(&cluster, &definition, exists_ok, config)
+                .await?;
+            if created {
+                println!(
+                    "Created topic '{}' in cluster '{}' ({} partitions, replication factor {}, {} config overrides)",
+                    definition.name,
+                    cluster,
+                    definition.partition_count,
+                    definition.replication_factor,
+                    definition.configs.len()
+                );
+            } else {
+                println!(
+                    "Topic '{}' already exists in cluster '{}'",
+                    definition.name, cluster
+                );
+            }
+        }
     }
 
     Ok(())
@@ -14,9 +14,19 @@
 
 
 use clap::Subcommand;
+use k8s_openapi::api::core::v1::{ConfigMap, Secret};
 use std::error::Error;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
 
 use crate::config::Config;
+use crate::models::audit_event::AuditContext;
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::configmaps_service::{ConfigMapDiff, ConfigMapsService};
+use crate::services::kubernetes::nodes_service::{NodeTaint, NodesService, TaintEffect};
+use crate::services::kubernetes::prometheus_rule_service::PrometheusRuleService;
+use crate::services::kubernetes::rbac_service::RbacService;
+use crate::services::kubernetes::secrets_service::SecretsService;
 
 #[derive(Subcommand)]
 pub enum K8sCommands {
@@ -89,6 +99,127 @@ pub enum K8sCommands {
         #[arg(short, long, default_value = "default")]
         namespace: String,
     },
+
+    /// Apply a ConfigMap or Secret manifest, showing the changed keys first
+    Apply {
+        /// YAML manifest containing a single ConfigMap or Secret
+        #[arg(short, long)]
+        file: PathBuf,
+
+        /// Configured cluster to apply to
+        #[arg(short, long)]
+        cluster: String,
+
+        /// Namespace to use (defaults to the manifest's namespace, then "default")
+        #[arg(short, long)]
+        namespace: Option<String>,
+
+        /// Apply without asking for confirmation
+        #[arg(long, default_value_t = false)]
+        no_confirm: bool,
+    },
+
+    /// Add or update a label on a node
+    LabelNode {
+        /// Configured cluster
+        #[arg(short, long)]
+        cluster: String,
+
+        /// Node name
+        #[arg(long)]
+        node: String,
+
+        /// Label key
+        #[arg(short, long)]
+        key: String,
+
+        /// Label value
+        #[arg(short, long)]
+        value: String,
+    },
+
+    /// Remove a label from a node
+    UnlabelNode {
+        /// Configured cluster
+        #[arg(short, long)]
+        cluster: String,
+
+        /// Node name
+        #[arg(long)]
+        node: String,
+
+        /// Label key
+        #[arg(short, long)]
+        key: String,
+    },
+
+    /// Add a taint to a node, replacing one with the same key and effect
+    TaintNode {
+        /// Configured cluster
+        #[arg(short, long)]
+        cluster: String,
+
+        /// Node name
+        #[arg(long)]
+        node: String,
+
+        /// Taint key
+        #[arg(short, long)]
+        key: String,
+
+        /// Taint value
+        #[arg(short, long)]
+        value: Option<String>,
+
+        /// NoSchedule, PreferNoSchedule or NoExecute
+        #[arg(short, long)]
+        effect: TaintEffect,
+    },
+
+    /// Remove all taints with the given key from a node
+    UntaintNode {
+        /// Configured cluster
+        #[arg(short, long)]
+        cluster: String,
+
+        /// Node name
+        #[arg(long)]
+        node: String,
+
+        /// Taint key
+        #[arg(short, long)]
+        key: String,
+    },
+
+    /// Compare Roles, ClusterRoles and their bindings between two clusters
+    RbacDiff {
+        /// Configured cluster the changes are promoted from
+        #[arg(short, long)]
+        source: String,
+
+        /// Configured cluster the changes are promoted to
+        #[arg(short, long)]
+        target: String,
+
+        /// Only compare Roles and RoleBindings in this namespace
+        #[arg(short, long)]
+        namespace: Option<String>,
+    },
+
+    /// Validate a Prometheus rule file and store it in a labelled ConfigMap
+    ApplyAlertRule {
+        /// Prometheus rule file (YAML with a top-level `groups` list)
+        #[arg(short, long)]
+        file: PathBuf,
+
+        /// Configured cluster to apply to
+        #[arg(short, long)]
+        cluster: String,
+
+        /// Namespace to use
+        #[arg(short, long, default_value = "default")]
+        namespace: String,
+    },
 }
 
 pub async fn handle_command(command: K8sCommands, config: &Config) -> Result<(), Box<dyn Error>> {
@@ -181,5 +312,230 @@ pub async fn handle_command(command: K8sCommands, config: &Config) -> Result<(),
             );
             Ok(())
         }
+
+        K8sCommands::Apply {
+            file,
+            cluster,
+            namespace,
+            no_confirm,
+        } => {
+            let cluster_config = cluster_config_for(config, &cluster)?;
+            let manifest: serde_yaml::Value =
+                serde_yaml::from_str(&std::fs::read_to_string(&file)?)?;
+            let kind = manifest
+                .get("kind")
+                .and_then(|k| k.as_str())
+                .unwrap_or_default()
+                .to_string();
+            let manifest_namespace = manifest
+                .get("metadata")
+                .and_then(|m| m.get("namespace"))
+                .and_then(|n| n.as_str())
+                .map(str::to_string);
+            let namespace = namespace
+                .or(manifest_namespace)
+                .unwrap_or_else(|| "default".to_string());
+            let name = manifest
+                .get("metadata")
+                .and_then(|m| m.get("name"))
+                .and_then(|n| n.as_str())
+                .ok_or("Manifest must have metadata.name")?
+                .to_string();
+
+            match kind.as_str() {
+                "ConfigMap" => {
+                    let proposed: ConfigMap = serde_yaml::from_value(manifest)?;
+                    let service = ConfigMapsService::new();
+                    let diff = service
+                        .diff_configmap(&cluster_config, &namespace, &proposed)
+                        .await?;
+                    print_diff("ConfigMap", &namespace, &name, &diff);
+                    if !diff.has_changes() || !(no_confirm || confirm("Apply these changes?")?) {
+                        return Ok(());
+                    }
+                    service
+                        .apply(&cluster_config, &namespace, &proposed)
+                        .await?;
+                }
+                "Secret" => {
+                    let proposed: Secret = serde_yaml::from_value(manifest)?;
+                    let service = SecretsService::new();
+                    let diff = service
+                        .diff_secret(&cluster_config, &namespace, &proposed)
+                        .await?;
+                    print_diff("Secret", &namespace, &name, &diff);
+                    if !diff.has_changes() || !(no_confirm || confirm("Apply these changes?")?) {
+                        return Ok(());
+                    }
+                    service
+                        .apply(&cluster_config, &namespace, &proposed)
+                        .await?;
+                }
+                other => {
+                    return Err(format!(
+                        "Unsupported kind '{}', expected ConfigMap or Secret",
+                        other
+                    )
+                    .into())
+                }
+            }
+            println!("Applied {} {}/{}", kind, namespace, name);
+            Ok(())
+        }
+
+        K8sCommands::LabelNode {
+            cluster,
+            node,
+            key,
+            value,
+        } => {
+            let cluster_config = cluster_config_for(config, &cluster)?;
+            NodesService::new()
+                .add_node_label(&cluster_config, &node, &key, &value)
+                .await?;
+            println!("Labeled node {} with {}={}", node, key, value);
+            Ok(())
+        }
+
+        K8sCommands::UnlabelNode { cluster, node, key } => {
+            let cluster_config = cluster_config_for(config, &cluster)?;
+            NodesService::new()
+                .remove_node_label(&cluster_config, &node, &key)
+                .await?;
+            println!("Removed label {} from node {}", key, node);
+            Ok(())
+        }
+
+        K8sCommands::TaintNode {
+            cluster,
+            node,
+            key,
+            value,
+            effect,
+        } => {
+            let cluster_config = cluster_config_for(config, &cluster)?;
+            NodesService::new()
+                .add_node_taint(
+                    &cluster_config,
+                    &node,
+                    NodeTaint {
+                        key: key.clone(),
+                        value,
+                        effect,
+                    },
+                )
+                .await?;
+            println!("Tainted node {} with {}:{}", node, key, effect.as_str());
+            Ok(())
+        }
+
+        K8sCommands::UntaintNode { cluster, node, key } => {
+            let cluster_config = cluster_config_for(config, &cluster)?;
+            NodesService::new()
+                .remove_node_taint(&cluster_config, &node, &key)
+                .await?;
+            println!("Removed taint {} from node {}", key, node);
+            Ok(())
+        }
+
+        K8sCommands::RbacDiff {
+            source,
+            target,
+            namespace,
+        } => {
+            let source_config = cluster_config_for(config, &source)?;
+            let target_config = cluster_config_for(config, &target)?;
+            let diff = RbacService::new()
+                .diff_rbac(&source_config, &target_config, namespace)
+                .await?;
+            println!("RBAC changes from {} to {}:", source, target);
+            print!("{}", diff);
+            Ok(())
+        }
+
+        K8sCommands::ApplyAlertRule {
+            file,
+            cluster,
+            namespace,
+        } => {
+            let cluster_config = cluster_config_for(config, &cluster)?;
+            let rule = PrometheusRuleService::new()
+                .apply_alert_rule_from_file(
+                    &cluster_config,
+                    &namespace,
+                    &file,
+                    &AuditContext::new(None, None, Some(cluster.clone())),
+                )
+                .await?;
+            let rule_count: usize = rule.groups.iter().map(|g| g.rules.len()).sum();
+            println!(
+                "Applied {} rules in {} groups to ConfigMap {}/{} (key {})",
+                rule_count,
+                rule.groups.len(),
+                rule.namespace,
+                rule.configmap_name,
+                rule.data_key
+            );
+            Ok(())
+        }
+    }
+}
+
+fn cluster_config_for(
+    config: &Config,
+    cluster: &str,
+) -> Result<KubernetesClusterConfig, Box<dyn Error>> {
+    let cluster = config
+        .kubernetes
+        .clusters
+        .iter()
+        .find(|c| c.name == cluster)
+        .ok_or_else(|| format!("Kubernetes cluster '{}' is not configured", cluster))?;
+    Ok(KubernetesClusterConfig {
+        kube_config_path: cluster.config_path.clone(),
+        kube_context: Some(cluster.context.clone()),
+        api_server_url: cluster.api_url.clone(),
+        certificate_authority_data: cluster.ca_cert.clone(),
+        client_certificate_data: None,
+        client_key_data: None,
+        token: cluster.token.clone(),
+        use_in_cluster_config: false,
+    })
+}
+
+fn print_diff(kind: &str, namespace: &str, name: &str, diff: &ConfigMapDiff) {
+    if diff.is_new {
+        println!(
+            "{} {}/{} does not exist and will be created",
+            kind, namespace, name
+        );
+    } else if !diff.has_changes() {
+        println!("{} {}/{} is unchanged", kind, namespace, name);
+        return;
+    } else {
+        println!("{} {}/{} will be updated", kind, namespace, name);
     }
+    for key in &diff.added_keys {
+        println!("  + {}", key);
+    }
+    for key in &diff.modified_keys {
+        println!("  ~ {}", key);
+    }
+    if let Some(change) = &diff.type_change {
+        println!(
+            "  ~ type: {} -> {} (type is immutable, the apply will be rejected)",
+            change.from, change.to
+        );
+    }
+    if !diff.unchanged_keys.is_empty() {
+        println!("  ({} unchanged)", diff.unchanged_keys.len());
+    }
+}
+
+fn confirm(prompt: &str) -> Result<bool, Box<dyn Error>> {
+    print!("{} [y/N] ", prompt);
+    io::stdout().flush()?;
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
 }
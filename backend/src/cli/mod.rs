@@ -18,3 +18,4 @@ pub mod cloud;
 pub mod database;
 pub mod kafka;
 pub mod kubernetes;
+pub mod pubsub;
@@ -0,0 +1,232 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use crate::config::Config;
+use crate::services::kafka::{ConsumeOptions, KafkaMessage};
+use crate::services::message_broker::MessageBrokerAdapter;
+use crate::services::pubsub::GcpPubSubService;
+use clap::Subcommand;
+use std::error::Error;
+use std::fs;
+
+#[derive(Subcommand, Debug)]
+pub enum PubSubCommands {
+    /// List topics in a GCP project
+    ListTopics {
+        /// GCP project ID, as configured under `pubsub`
+        #[arg(short, long)]
+        project: String,
+    },
+
+    /// Create a new topic
+    CreateTopic {
+        /// GCP project ID, as configured under `pubsub`
+        #[arg(short, long)]
+        project: String,
+
+        /// Name of the topic to create
+        #[arg(short, long)]
+        topic: String,
+    },
+
+    /// List subscriptions in a GCP project
+    ListSubscriptions {
+        /// GCP project ID, as configured under `pubsub`
+        #[arg(short, long)]
+        project: String,
+    },
+
+    /// Create a pull subscription to a topic
+    CreateSubscription {
+        /// GCP project ID, as configured under `pubsub`
+        #[arg(short, long)]
+        project: String,
+
+        /// Name of the subscription to create
+        #[arg(short, long)]
+        subscription: String,
+
+        /// Name of the topic to subscribe to
+        #[arg(short, long)]
+        topic: String,
+
+        /// Seconds a pulled message may go unacknowledged before redelivery
+        #[arg(long, default_value_t = 10)]
+        ack_deadline: i32,
+    },
+
+    /// Publish a message to a topic
+    Publish {
+        /// GCP project ID, as configured under `pubsub`
+        #[arg(short, long)]
+        project: String,
+
+        /// Name of the topic
+        #[arg(short, long)]
+        topic: String,
+
+        /// Message to publish (or file path prefixed with @ to read from file)
+        #[arg(short, long)]
+        message: String,
+
+        /// Optional ordering key for the message
+        #[arg(short, long)]
+        key: Option<String>,
+
+        /// Message attributes as key=value, may be repeated
+        #[arg(short, long)]
+        attribute: Vec<String>,
+    },
+
+    /// Pull and acknowledge messages from a subscription
+    Consume {
+        /// GCP project ID, as configured under `pubsub`
+        #[arg(short, long)]
+        project: String,
+
+        /// Name of the subscription
+        #[arg(short, long)]
+        subscription: String,
+
+        /// Maximum number of messages to consume
+        #[arg(short, long, default_value_t = 10)]
+        limit: u64,
+
+        /// How long to wait for messages, in milliseconds
+        #[arg(long, default_value_t = 5000)]
+        timeout_ms: u64,
+    },
+}
+
+async fn connect(config: &Config, project: &str) -> Result<GcpPubSubService, Box<dyn Error>> {
+    let pubsub_config = config
+        .pubsub
+        .iter()
+        .find(|p| p.project_id == project)
+        .ok_or_else(|| format!("Pub/Sub project '{}' not found in configuration", project))?;
+    Ok(GcpPubSubService::new(pubsub_config).await?)
+}
+
+pub async fn handle_command(
+    command: PubSubCommands,
+    config: &Config,
+) -> Result<(), Box<dyn Error>> {
+    match command {
+        PubSubCommands::ListTopics { project } => {
+            let service = connect(config, &project).await?;
+            println!("Topics in GCP project {}:", project);
+            for topic in service.list_topics().await? {
+                println!("  - {}", topic);
+            }
+        }
+        PubSubCommands::CreateTopic { project, topic } => {
+            let service = connect(config, &project).await?;
+            service.create_topic(&topic).await?;
+            println!("Created topic '{}' in GCP project {}", topic, project);
+        }
+        PubSubCommands::ListSubscriptions { project } => {
+            let service = connect(config, &project).await?;
+            println!("Subscriptions in GCP project {}:", project);
+            for subscription in service.list_subscriptions().await? {
+                println!(
+                    "  - {} (topic: {}, ack deadline: {}s)",
+                    subscription.name, subscription.topic, subscription.ack_deadline_seconds
+                );
+            }
+        }
+        PubSubCommands::CreateSubscription {
+            project,
+            subscription,
+            topic,
+            ack_deadline,
+        } => {
+            let service = connect(config, &project).await?;
+            service
+                .create_subscription(&subscription, &topic, ack_deadline)
+                .await?;
+            println!(
+                "Created subscription '{}' to topic '{}' in GCP project {}",
+                subscription, topic, project
+            );
+        }
+        PubSubCommands::Publish {
+            project,
+            topic,
+            message,
+            key,
+            attribute,
+        } => {
+            let value = if let Some(file_path) = message.strip_prefix('@') {
+                fs::read_to_string(file_path)
+                    .map_err(|e| format!("Failed to read message from file {}: {}", file_path, e))?
+            } else {
+                message
+            };
+            let headers = attribute
+                .iter()
+                .map(|a| {
+                    a.split_once('=')
+                        .map(|(k, v)| (k.to_string(), v.to_string()))
+                        .ok_or_else(|| format!("Invalid attribute '{}', expected key=value", a))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let service = connect(config, &project).await?;
+            let message_id = service
+                .publish(
+                    &topic,
+                    &KafkaMessage {
+                        key,
+                        value,
+                        headers: Some(headers),
+                    },
+                )
+                .await?;
+            println!("Published message {} to topic '{}'", message_id, topic);
+        }
+        PubSubCommands::Consume {
+            project,
+            subscription,
+            limit,
+            timeout_ms,
+        } => {
+            let service = connect(config, &project).await?;
+            let options = ConsumeOptions {
+                group_id: subscription.clone(),
+                max_messages: Some(limit),
+                timeout_ms: Some(timeout_ms),
+                from_beginning: None,
+            };
+            let messages = service.consume(&subscription, &options).await?;
+            println!(
+                "Consumed {} messages from subscription '{}'",
+                messages.len(),
+                subscription
+            );
+            for message in messages {
+                println!(
+                    "[key: {}] {}",
+                    message.key.as_deref().unwrap_or("null"),
+                    message.value
+                );
+                for (name, value) in message.headers.unwrap_or_default() {
+                    println!("    {}: {}", name, value);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -18,10 +18,14 @@ use serde::{Deserialize, Serialize};
 use std::env;
 use std::error::Error;
 
+pub mod reloader;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     pub database: DatabaseConfig,
     pub kafka: KafkaConfig,
+    #[serde(default)]
+    pub pubsub: Vec<GcpPubSubConfig>,
     pub auth: AuthConfig,
     pub cloud: CloudConfig,
     pub ai: AIConfig,
@@ -29,6 +33,19 @@ pub struct Config {
     pub kubernetes: KubernetesConfig,
     #[serde(default)]
     pub sync: SyncConfig,
+    #[serde(default)]
+    pub audit: AuditConfig,
+    // OTLP endpoint for exporting trace spans, set via MAYYAM_OTEL_ENDPOINT.
+    // Spans are only written to the log outputs when unset.
+    #[serde(default)]
+    pub otel_endpoint: Option<String>,
+    // Alert emails are only sent when SES is configured
+    #[serde(default)]
+    pub ses: Option<SesConfig>,
+    #[serde(default)]
+    pub cost_anomaly_webhook_url: Option<String>,
+    #[serde(default)]
+    pub cost_analytics: CostAnalyticsConfig,
 }
 
 impl Default for Config {
@@ -36,16 +53,32 @@ impl Default for Config {
         Self {
             database: DatabaseConfig::default(),
             kafka: KafkaConfig::default(),
+            pubsub: vec![],
             auth: AuthConfig::default(),
             cloud: CloudConfig::default(),
             ai: AIConfig::default(),
             security: SecurityConfig::default(),
             kubernetes: KubernetesConfig::default(),
             sync: SyncConfig::default(),
+            audit: AuditConfig::default(),
+            otel_endpoint: None,
+            ses: None,
+            cost_anomaly_webhook_url: None,
+            cost_analytics: CostAnalyticsConfig::default(),
         }
     }
 }
 
+// Amazon SES sender for alert notifications. Credentials come from the default
+// AWS provider chain of the host, not from a registered AWS account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SesConfig {
+    pub source_email: String,
+    pub region: String,
+    #[serde(default)]
+    pub alert_recipients: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DatabaseConfig {
     pub postgres: Vec<PostgresConfig>,
@@ -84,6 +117,48 @@ pub struct MySQLConfig {
     pub username: String,
     pub password: String,
     pub database: String,
+    // One of disabled, required, verify_ca or verify_identity
+    pub ssl_mode: Option<String>,
+    pub ssl_ca: Option<String>,
+    pub ssl_cert: Option<String>,
+    pub ssl_key: Option<String>,
+}
+
+pub const MYSQL_SSL_MODES: &[&str] = &["disabled", "required", "verify_ca", "verify_identity"];
+
+impl MySQLConfig {
+    pub fn validate_ssl(&self) -> Result<(), String> {
+        let ssl_mode = match self.ssl_mode.as_deref() {
+            Some(mode) => mode,
+            None => return Ok(()),
+        };
+        if !MYSQL_SSL_MODES.contains(&ssl_mode) {
+            return Err(format!(
+                "MySQL '{}': unsupported ssl_mode '{}', expected one of {}",
+                self.name,
+                ssl_mode,
+                MYSQL_SSL_MODES.join(", ")
+            ));
+        }
+        if matches!(ssl_mode, "verify_ca" | "verify_identity") {
+            match self.ssl_ca.as_deref() {
+                Some(path) if std::path::Path::new(path).exists() => {}
+                Some(path) => {
+                    return Err(format!(
+                        "MySQL '{}': ssl_ca file '{}' does not exist",
+                        self.name, path
+                    ))
+                }
+                None => {
+                    return Err(format!(
+                        "MySQL '{}': ssl_mode '{}' requires ssl_ca",
+                        self.name, ssl_mode
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -103,14 +178,84 @@ pub struct OpenSearchConfig {
     pub password: String,
 }
 
+// GCP Pub/Sub project. Application default credentials are used when no
+// service account key file is given.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GcpPubSubConfig {
+    pub project_id: String,
+    pub credentials_file: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KafkaConfig {
     pub clusters: Vec<KafkaClusterConfig>,
+    #[serde(default)]
+    pub topic_monitor: KafkaTopicMonitorConfig,
+    #[serde(default)]
+    pub replication: KafkaReplicationConfig,
 }
 
 impl Default for KafkaConfig {
     fn default() -> Self {
-        Self { clusters: vec![] }
+        Self {
+            clusters: vec![],
+            topic_monitor: KafkaTopicMonitorConfig::default(),
+            replication: KafkaReplicationConfig::default(),
+        }
+    }
+}
+
+// Per-partition throughput sampling for every configured and stored Kafka cluster.
+// Samples older than `retention_days` are pruned after each round.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KafkaTopicMonitorConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_topic_monitor_interval_secs")]
+    pub interval_secs: u64,
+    #[serde(default = "default_topic_monitor_retention_days")]
+    pub retention_days: i64,
+}
+
+fn default_topic_monitor_interval_secs() -> u64 {
+    60
+}
+
+fn default_topic_monitor_retention_days() -> i64 {
+    7
+}
+
+impl Default for KafkaTopicMonitorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_secs: default_topic_monitor_interval_secs(),
+            retention_days: default_topic_monitor_retention_days(),
+        }
+    }
+}
+
+// Cross-cluster replication lag checks (MirrorMaker 2, Confluent Replicator, ...).
+// `target_topic_prefix` matches MM2's DefaultReplicationPolicy, which names mirrored
+// topics "<source alias>.<topic>"; leave it unset for identity replication.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KafkaReplicationConfig {
+    #[serde(default = "default_max_acceptable_replication_lag")]
+    pub max_acceptable_lag_messages: i64,
+    #[serde(default)]
+    pub target_topic_prefix: Option<String>,
+}
+
+fn default_max_acceptable_replication_lag() -> i64 {
+    1000
+}
+
+impl Default for KafkaReplicationConfig {
+    fn default() -> Self {
+        Self {
+            max_acceptable_lag_messages: default_max_acceptable_replication_lag(),
+            target_topic_prefix: None,
+        }
     }
 }
 
@@ -122,6 +267,8 @@ pub struct KafkaClusterConfig {
     pub sasl_password: Option<String>,
     pub sasl_mechanism: Option<String>,
     pub security_protocol: String,
+    #[serde(default)]
+    pub schema_registry_url: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -132,6 +279,16 @@ pub struct AuthConfig {
     pub enable_token_auth: bool,
     pub enable_saml: bool,
     pub saml_metadata_url: Option<String>,
+    #[serde(default)]
+    pub enable_oidc: bool,
+    #[serde(default)]
+    pub oidc_issuer_url: Option<String>,
+    #[serde(default)]
+    pub oidc_client_id: Option<String>,
+    #[serde(default)]
+    pub oidc_client_secret: Option<String>,
+    #[serde(default)]
+    pub oidc_redirect_uri: Option<String>,
     pub encryption_key: String,
 }
 
@@ -144,15 +301,58 @@ impl Default for AuthConfig {
             enable_token_auth: true,
             enable_saml: false,
             saml_metadata_url: None,
+            enable_oidc: false,
+            oidc_issuer_url: None,
+            oidc_client_id: None,
+            oidc_client_secret: None,
+            oidc_redirect_uri: None,
             encryption_key: "default-encryption-key-for-development-only".to_string(),
         }
     }
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CostAnalyticsConfig {
+    #[serde(default)]
+    pub anomaly_detection: AnomalyDetectionConfig,
+    // Cost allocation tag keys summarised weekly and used to find untagged spend
+    #[serde(default)]
+    pub cost_allocation_tags: Vec<String>,
+}
+
+// Sensitivity of the monthly cost anomaly detector. This is the global default; an AWS
+// account can override it through its own `anomaly_detection_config`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnomalyDetectionConfig {
+    pub z_score_threshold: f64,
+    pub percent_change_threshold: f64,
+    pub rolling_breakout_threshold: f64,
+    pub medium_severity_composite: f64,
+    pub high_severity_composite: f64,
+    // Services whose current and baseline cost are both below this get a dampened score
+    pub min_baseline_cost_for_low_filter: f64,
+}
+
+impl Default for AnomalyDetectionConfig {
+    fn default() -> Self {
+        Self {
+            z_score_threshold: 2.25,
+            percent_change_threshold: 35.0,
+            rolling_breakout_threshold: 2.0,
+            medium_severity_composite: 5.5,
+            high_severity_composite: 8.0,
+            min_baseline_cost_for_low_filter: 20.0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CloudConfig {
     pub aws: Vec<AwsConfig>,
     pub azure: Vec<AzureConfig>,
+    #[serde(default)]
+    pub gcp: Vec<GcpStorageConfig>,
 }
 
 impl Default for CloudConfig {
@@ -160,6 +360,7 @@ impl Default for CloudConfig {
         Self {
             aws: vec![],
             azure: vec![],
+            gcp: vec![],
         }
     }
 }
@@ -184,6 +385,15 @@ pub struct AzureConfig {
     pub use_managed_identity: bool,
 }
 
+// GCP Cloud Storage bucket. Like Pub/Sub, falls back to application default
+// credentials when no service account key file is given.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GcpStorageConfig {
+    pub project_id: String,
+    pub credentials_file: Option<String>,
+    pub bucket: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AIConfig {
     pub provider: String,
@@ -206,12 +416,25 @@ impl Default for AIConfig {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SecurityConfig {
     pub encryption_key: String,
+    // Config values of the form "sm://<secret arn or name>" are read from AWS Secrets
+    // Manager using the host's default credentials; resolved values are re-fetched after
+    // this interval.
+    #[serde(default = "default_secret_refresh_interval_secs")]
+    pub secret_refresh_interval_secs: u64,
+    #[serde(default)]
+    pub secrets_manager_region: Option<String>,
+}
+
+fn default_secret_refresh_interval_secs() -> u64 {
+    3600
 }
 
 impl Default for SecurityConfig {
     fn default() -> Self {
         Self {
             encryption_key: "default-encryption-key-for-development-only".to_string(),
+            secret_refresh_interval_secs: default_secret_refresh_interval_secs(),
+            secrets_manager_region: None,
         }
     }
 }
@@ -219,11 +442,45 @@ impl Default for SecurityConfig {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KubernetesConfig {
     pub clusters: Vec<KubernetesClusterConfig>,
+    #[serde(default)]
+    pub alerting: KubernetesAlertingConfig,
 }
 
 impl Default for KubernetesConfig {
     fn default() -> Self {
-        Self { clusters: vec![] }
+        Self {
+            clusters: vec![],
+            alerting: KubernetesAlertingConfig::default(),
+        }
+    }
+}
+
+// Event alert rules evaluated against every registered Kubernetes cluster.
+// An empty `namespaces` list evaluates events across all namespaces.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KubernetesAlertingConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_alerting_interval_secs")]
+    pub interval_secs: u64,
+    #[serde(default)]
+    pub namespaces: Vec<String>,
+    #[serde(default)]
+    pub rules: Vec<crate::services::kubernetes::alerting::AlertRule>,
+}
+
+fn default_alerting_interval_secs() -> u64 {
+    60
+}
+
+impl Default for KubernetesAlertingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_secs: default_alerting_interval_secs(),
+            namespaces: vec![],
+            rules: vec![],
+        }
     }
 }
 
@@ -245,6 +502,29 @@ impl Default for SyncConfig {
     }
 }
 
+// Where audit events for destructive operations are written. Events go to the
+// `audit_events` table unless `kafka_cluster` names a configured Kafka cluster.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditConfig {
+    #[serde(default)]
+    pub kafka_cluster: Option<String>,
+    #[serde(default = "default_audit_topic")]
+    pub kafka_topic: String,
+}
+
+fn default_audit_topic() -> String {
+    "mayyam.audit-events".to_string()
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            kafka_cluster: None,
+            kafka_topic: default_audit_topic(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KubernetesClusterConfig {
     pub name: String,
@@ -272,6 +552,18 @@ pub fn load_config() -> Result<Config, Box<dyn Error>> {
 
     let mut config: Config = config.try_deserialize()?;
 
+    // The "__" separator above would only map MAYYAM__OTEL_ENDPOINT, so the
+    // documented single-underscore name is read directly
+    if let Ok(endpoint) = env::var("MAYYAM_OTEL_ENDPOINT") {
+        if !endpoint.trim().is_empty() {
+            config.otel_endpoint = Some(endpoint);
+        }
+    }
+
+    for mysql in &config.database.mysql {
+        mysql.validate_ssl()?;
+    }
+
     // Ensure kubernetes configuration exists even if not in config file
     if config.kubernetes.clusters.is_empty() {
         println!("Warning: No Kubernetes clusters configured. Add them to your config file.");
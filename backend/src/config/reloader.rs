@@ -0,0 +1,203 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+// Hot reload for the config file. Handlers that need live values read a snapshot from
+// `SharedConfig`; services holding derived state subscribe to `ConfigChangedEvent`.
+//
+// Live after a reload or secret refresh: Kafka cluster settings, Kubernetes clients (the cache
+// is dropped), AI model defaults, the database/query-template handlers and the JWT secret used
+// to issue and verify tokens (rotating it invalidates tokens issued with the old one).
+// Settings consumed once while building the server still need a restart: bind address, the
+// app database pool (its open connections keep working, new ones use the startup password),
+// the audit sink's Kafka producer, the OIDC login state key and background task schedules.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use tokio::sync::{broadcast, mpsc};
+use tracing::{error, info, warn};
+
+use super::{load_config, Config};
+use crate::errors::AppError;
+use crate::services::aws::SecretsManagerCredentialProvider;
+
+// Editors often write a file in several steps; wait for them to settle before re-parsing
+const RELOAD_DEBOUNCE: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone)]
+pub struct SharedConfig(Arc<RwLock<Config>>);
+
+impl SharedConfig {
+    pub fn new(config: Config) -> Self {
+        Self(Arc::new(RwLock::new(config)))
+    }
+
+    pub fn snapshot(&self) -> Config {
+        self.0.read().unwrap().clone()
+    }
+
+    /// Read a single value without cloning the whole config, for per-request hot paths
+    pub fn read<T>(&self, f: impl FnOnce(&Config) -> T) -> T {
+        f(&self.0.read().unwrap())
+    }
+
+    fn replace(&self, config: Config) -> Config {
+        std::mem::replace(&mut *self.0.write().unwrap(), config)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigChangedEvent {
+    pub reloaded_at: DateTime<Utc>,
+    pub kafka_changed: bool,
+    pub kubernetes_changed: bool,
+}
+
+pub struct ConfigReloader {
+    config: SharedConfig,
+    events: broadcast::Sender<ConfigChangedEvent>,
+    watcher: Mutex<Option<RecommendedWatcher>>,
+    secret_provider: Option<Arc<SecretsManagerCredentialProvider>>,
+}
+
+impl ConfigReloader {
+    pub fn new(config: SharedConfig) -> Self {
+        let (events, _) = broadcast::channel(16);
+        Self {
+            config,
+            events,
+            watcher: Mutex::new(None),
+            secret_provider: None,
+        }
+    }
+
+    pub fn with_secret_provider(mut self, provider: Arc<SecretsManagerCredentialProvider>) -> Self {
+        self.secret_provider = Some(provider);
+        self
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<ConfigChangedEvent> {
+        self.events.subscribe()
+    }
+
+    /// Re-read the config sources and swap them in. A config that fails to parse, or whose
+    /// secrets cannot be resolved, leaves the current one in place.
+    pub async fn reload(&self) -> Result<ConfigChangedEvent, AppError> {
+        let mut new_config = load_config()
+            .map_err(|e| AppError::Config(format!("Failed to reload config: {}", e)))?;
+        if let Some(provider) = self.secret_provider.as_ref() {
+            provider.resolve_config(&mut new_config).await?;
+        }
+        let old_config = self.config.replace(new_config.clone());
+        let kafka_changed = section_changed(&old_config.kafka, &new_config.kafka);
+        let kubernetes_changed = section_changed(&old_config.kubernetes, &new_config.kubernetes);
+
+        let event = ConfigChangedEvent {
+            reloaded_at: Utc::now(),
+            kafka_changed,
+            kubernetes_changed,
+        };
+        info!(kafka_changed, kubernetes_changed, "Configuration reloaded");
+        // No subscribers is not an error
+        let _ = self.events.send(event.clone());
+        Ok(event)
+    }
+
+    /// Watch the directory holding the config file and reload when it changes.
+    pub fn start_watching(self: &Arc<Self>) -> Result<(), AppError> {
+        let config_path = PathBuf::from(
+            std::env::var("CONFIG_FILE").unwrap_or_else(|_| "config".to_string()),
+        );
+        let dir = match config_path.parent() {
+            Some(p) if !p.as_os_str().is_empty() => p.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        let base_name = config_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let (tx, mut rx) = mpsc::unbounded_channel::<()>();
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+            match res {
+                Ok(event) if event.paths.iter().any(|p| is_config_file(p, &base_name)) => {
+                    let _ = tx.send(());
+                }
+                Ok(_) => {}
+                Err(e) => warn!("Config file watcher error: {}", e),
+            }
+        })
+        .map_err(|e| AppError::Config(format!("Failed to create config watcher: {}", e)))?;
+        watcher
+            .watch(&dir, RecursiveMode::NonRecursive)
+            .map_err(|e| AppError::Config(format!("Failed to watch {}: {}", dir.display(), e)))?;
+        *self.watcher.lock().unwrap() = Some(watcher);
+
+        let reloader = Arc::clone(self);
+        tokio::spawn(async move {
+            while rx.recv().await.is_some() {
+                tokio::time::sleep(RELOAD_DEBOUNCE).await;
+                while rx.try_recv().is_ok() {}
+                if let Err(e) = reloader.reload().await {
+                    error!("{}", e);
+                }
+            }
+        });
+
+        info!("Watching {} for config changes", dir.display());
+        Ok(())
+    }
+
+    /// Periodically reload so rotated Secrets Manager values reach the shared config.
+    /// Does nothing when no secret provider is set or the refresh interval is 0.
+    pub fn start_secret_refresh(self: &Arc<Self>) {
+        let Some(interval) = self.secret_provider.as_ref().map(|p| p.refresh_interval()) else {
+            return;
+        };
+        if interval.is_zero() {
+            info!("Secret refresh disabled (secret_refresh_interval_secs is 0)");
+            return;
+        }
+        let reloader = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            // The first tick completes immediately and startup already resolved the secrets
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                if let Err(e) = reloader.reload().await {
+                    error!("Secret refresh failed: {}", e);
+                }
+            }
+        });
+    }
+}
+
+// `config` resolves "config" to config.yml, config.default.toml, ...
+fn is_config_file(path: &Path, base_name: &str) -> bool {
+    path.file_name()
+        .map(|n| n.to_string_lossy())
+        .map_or(false, |name| {
+            name == base_name || name.starts_with(&format!("{}.", base_name))
+        })
+}
+
+fn section_changed<T: Serialize>(old: &T, new: &T) -> bool {
+    serde_json::to_value(old).ok() != serde_json::to_value(new).ok()
+}
@@ -29,7 +29,9 @@ use crate::repositories::prompt_template::PromptTemplateRepository;
 use crate::services::analytics::mysql_analytics::MySqlAnalyticsService;
 use crate::services::analytics::postgres_analytics::postgres_analytics_service::PostgresAnalyticsService;
 use crate::utils::database::connect_to_dynamic_database;
-use crate::config::Config;
+use crate::config::reloader::SharedConfig;
+use crate::services::kubernetes::manifest_generator_service::ManifestGeneratorService;
+use sea_orm::DatabaseConnection;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ChatRequest {
@@ -127,7 +129,7 @@ pub struct DynamoDBAnalysisResponse {
 
 pub async fn chat(
     req: web::Json<ChatRequest>,
-    config: Option<web::Data<crate::config::Config>>,
+    config: Option<web::Data<SharedConfig>>,
     llm_integration_service: Option<web::Data<Arc<crate::services::llm::LlmIntegrationService>>>,
     llm_provider_repo: Option<
         web::Data<Arc<crate::repositories::llm_provider::LlmProviderRepository>>,
@@ -161,8 +163,9 @@ pub async fn chat(
     // Optional simple sanitization to avoid accidental HTML/script injection echoes
     let strip_html = Regex::new(r"<[^>]+>").ok();
     // Find provider by model name (or fallback to default)
-    let config =
-        config.ok_or_else(|| AppError::Internal("Missing Config in app state".to_string()))?;
+    let config = config
+        .ok_or_else(|| AppError::Internal("Missing Config in app state".to_string()))?
+        .snapshot();
     let llm_provider_repo = llm_provider_repo.ok_or_else(|| {
         AppError::Internal("Missing LlmProviderRepository in app state".to_string())
     })?;
@@ -243,7 +246,7 @@ pub async fn chat_stream(
         web::Data<Arc<crate::repositories::llm_provider::LlmProviderRepository>>,
     >,
     _claims: Option<web::ReqData<Claims>>,
-    config: Option<web::Data<crate::config::Config>>,
+    config: Option<web::Data<SharedConfig>>,
 ) -> Result<HttpResponse, AppError> {
     // Validation (same limits as non-streaming)
     const MAX_MESSAGE_LEN: usize = 4000;
@@ -271,8 +274,9 @@ pub async fn chat_stream(
     }
 
     // Only resolve dependencies after validation to keep tests simple
-    let config =
-        config.ok_or_else(|| AppError::Internal("Missing Config in app state".to_string()))?;
+    let config = config
+        .ok_or_else(|| AppError::Internal("Missing Config in app state".to_string()))?
+        .snapshot();
     let llm_provider_repo = llm_provider_repo.ok_or_else(|| {
         AppError::Internal("Missing LlmProviderRepository in app state".to_string())
     })?;
@@ -563,10 +567,11 @@ pub async fn troubleshoot(
 pub async fn analyze_rds_instance(
     path: web::Path<(String, String)>,
     claims: web::ReqData<Claims>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     llm_integration_service: web::Data<Arc<crate::services::llm::LlmIntegrationService>>,
     llm_provider_repo: web::Data<Arc<crate::repositories::llm_provider::LlmProviderRepository>>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let (instance_id, workflow) = path.into_inner();
 
     info!(
@@ -653,10 +658,11 @@ pub async fn analyze_rds_instance(
 pub async fn answer_rds_question(
     req: web::Json<RelatedQuestionRequest>,
     _claims: web::ReqData<Claims>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     llm_integration_service: web::Data<Arc<crate::services::llm::LlmIntegrationService>>,
     llm_provider_repo: web::Data<Arc<crate::repositories::llm_provider::LlmProviderRepository>>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     info!(
         "Answering question about RDS instance {}: {}",
         req.instance_id, req.question
@@ -706,10 +712,11 @@ pub async fn answer_rds_question(
 pub async fn analyze_dynamodb_table(
     path: web::Path<(String, String)>,
     _claims: web::ReqData<Claims>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     llm_integration_service: web::Data<Arc<crate::services::llm::LlmIntegrationService>>,
     llm_provider_repo: web::Data<Arc<crate::repositories::llm_provider::LlmProviderRepository>>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let (table_id, workflow) = path.into_inner();
 
     info!(
@@ -797,10 +804,11 @@ pub async fn analyze_dynamodb_table(
 pub async fn answer_dynamodb_question(
     req: web::Json<RelatedQuestionRequest>,
     _claims: web::ReqData<Claims>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     llm_integration_service: web::Data<Arc<crate::services::llm::LlmIntegrationService>>,
     llm_provider_repo: web::Data<Arc<crate::repositories::llm_provider::LlmProviderRepository>>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     info!(
         "Answering question about DynamoDB table {}: {}",
         req.instance_id, req.question
@@ -852,9 +860,10 @@ pub async fn analyze_database_triage(
     prompt_repo: web::Data<Arc<PromptTemplateRepository>>,
     llm_service: web::Data<Arc<crate::services::llm::LlmIntegrationService>>,
     llm_provider_repo: web::Data<Arc<crate::repositories::llm_provider::LlmProviderRepository>>,
-    config: web::Data<Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<HttpResponse, AppError> {
+    let config = config.snapshot();
     let (connection_id_str, workflow) = path.into_inner();
     
     let connection_id = uuid::Uuid::parse_str(&connection_id_str)
@@ -900,11 +909,11 @@ pub async fn analyze_database_triage(
     // 5. Get triage context (metrics)
     let metrics_json = match db_model.connection_type.as_str() {
         "mysql" => {
-            let analytics_service = MySqlAnalyticsService::new((**config).clone());
+            let analytics_service = MySqlAnalyticsService::new(config.clone());
             analytics_service.get_triage_context(&conn).await?
         }
         "postgres" => {
-            let analytics_service = PostgresAnalyticsService::new((**config).clone());
+            let analytics_service = PostgresAnalyticsService::new(config.clone());
             analytics_service.get_triage_context(&conn).await?
         }
         _ => unreachable!(),
@@ -942,6 +951,154 @@ pub async fn analyze_database_triage(
     }))
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GenerateManifestRequest {
+    pub description: String,
+    pub dry_apply: Option<bool>,
+    pub cluster_id: Option<String>,
+    pub namespace: Option<String>,
+}
+
+/// Generate a Kubernetes Deployment manifest from a natural language description
+pub async fn generate_kubernetes_manifest(
+    req: web::Json<GenerateManifestRequest>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    manifest_generator: web::Data<Arc<ManifestGeneratorService>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    let req = req.into_inner();
+    info!("Generating Kubernetes manifest (dry_apply: {:?})", req.dry_apply);
+
+    let mut manifest = manifest_generator
+        .generate_deployment(&req.description)
+        .await?;
+
+    if req.dry_apply.unwrap_or(false) {
+        let cluster_id = req.cluster_id.as_deref().ok_or_else(|| {
+            AppError::BadRequest("cluster_id is required when dry_apply is set".to_string())
+        })?;
+        let namespace = req.namespace.as_deref().unwrap_or("default");
+        let cluster_config =
+            crate::controllers::kubernetes::get_cluster_config_by_id(db.get_ref().as_ref(), cluster_id)
+                .await?;
+        if manifest.validation_passed {
+            manifest_generator
+                .dry_apply_deployment(&cluster_config, namespace, &mut manifest)
+                .await?;
+        } else {
+            manifest
+                .warnings
+                .push("Skipped server-side dry run because local validation failed".to_string());
+        }
+    }
+
+    Ok(HttpResponse::Ok().json(manifest))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateSessionRequest {
+    pub model: Option<String>,
+    pub context: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SessionMessageRequest {
+    pub message: String,
+}
+
+// Sessions hold another user's conversation, so only the owner may read or change them
+async fn load_owned_session(
+    llm_service: &crate::services::llm::LlmIntegrationService,
+    session_id: uuid::Uuid,
+    claims: &Claims,
+) -> Result<crate::models::llm_session::LlmSession, AppError> {
+    let session = llm_service.get_session(session_id).await?;
+    if session.user_id != claims.sub {
+        return Err(AppError::NotFound(format!(
+            "LLM session {} not found",
+            session_id
+        )));
+    }
+    Ok(session)
+}
+
+pub async fn create_session(
+    req: web::Json<CreateSessionRequest>,
+    config: web::Data<SharedConfig>,
+    llm_service: web::Data<Arc<crate::services::llm::LlmIntegrationService>>,
+    llm_provider_repo: web::Data<Arc<crate::repositories::llm_provider::LlmProviderRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    let config = config.snapshot();
+    let req = req.into_inner();
+    let model_name = req.model.unwrap_or_else(|| config.ai.model.clone());
+    let provider = llm_provider_repo
+        .find_by_model_name(&model_name)
+        .await?
+        .ok_or_else(|| {
+            AppError::NotFound(format!("LLM provider for model '{}' not found", model_name))
+        })?;
+
+    let session = llm_service
+        .create_session(&claims.sub, provider.id, req.context)
+        .await?;
+    Ok(HttpResponse::Created().json(session))
+}
+
+pub async fn get_session(
+    path: web::Path<uuid::Uuid>,
+    llm_service: web::Data<Arc<crate::services::llm::LlmIntegrationService>>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    let session = load_owned_session(&llm_service, path.into_inner(), &claims).await?;
+    Ok(HttpResponse::Ok().json(session))
+}
+
+pub async fn delete_session(
+    path: web::Path<uuid::Uuid>,
+    llm_service: web::Data<Arc<crate::services::llm::LlmIntegrationService>>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    let session = load_owned_session(&llm_service, path.into_inner(), &claims).await?;
+    llm_service.delete_session(session.id).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub async fn send_session_message(
+    path: web::Path<uuid::Uuid>,
+    req: web::Json<SessionMessageRequest>,
+    llm_service: web::Data<Arc<crate::services::llm::LlmIntegrationService>>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    const MAX_MESSAGE_LEN: usize = 4000;
+    let req = req.into_inner();
+    if req.message.trim().is_empty() {
+        return Err(AppError::BadRequest(
+            "Message must not be empty".to_string(),
+        ));
+    }
+    if req.message.len() > MAX_MESSAGE_LEN {
+        return Err(AppError::BadRequest(format!(
+            "Message too long (max {} chars)",
+            MAX_MESSAGE_LEN
+        )));
+    }
+
+    let session = load_owned_session(&llm_service, path.into_inner(), &claims).await?;
+    let response = llm_service
+        .chat_with_history(session.id, req.message)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "session_id": session.id,
+        "content": response.content,
+        "model": response.model,
+        "provider": response.provider,
+        "tokens_used": response.tokens_used,
+        "timestamp": response.timestamp,
+    })))
+}
+
 // Mock response content generators
 fn get_mock_memory_analysis() -> String {
     r#"}"#.to_string()
@@ -0,0 +1,90 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use actix_web::{web, HttpResponse, Responder};
+use sea_orm::DatabaseConnection;
+use serde::Deserialize;
+use std::sync::Arc;
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::controllers::kubernetes::get_cluster_config_by_id;
+use crate::errors::AppError;
+use crate::middleware::auth::Claims;
+use crate::services::kubernetes::alerting::{AlertRule, AlertingService};
+
+#[derive(Debug, Deserialize)]
+pub struct EvaluateAlertRulesRequest {
+    // Omit to evaluate events in all namespaces
+    pub namespace: Option<String>,
+    pub rules: Vec<AlertRule>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AlertFiringsQuery {
+    #[serde(default)]
+    pub open_only: bool,
+    pub limit: Option<u64>,
+}
+
+fn parse_cluster_id(cluster_id: &str) -> Result<Uuid, AppError> {
+    Uuid::parse_str(cluster_id)
+        .map_err(|_| AppError::BadRequest("Invalid cluster ID format".to_string()))
+}
+
+pub async fn evaluate_alert_rules_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<String>, // cluster_id
+    body: web::Json<EvaluateAlertRulesRequest>,
+    svc: web::Data<Arc<AlertingService>>,
+) -> Result<impl Responder, AppError> {
+    let cluster_id = path.into_inner();
+    let req = body.into_inner();
+    if req.rules.is_empty() {
+        return Err(AppError::BadRequest(
+            "At least one alert rule is required".to_string(),
+        ));
+    }
+    debug!(target: "mayyam::controllers::alerting", user_id = %claims.username, %cluster_id, rules = req.rules.len(), "Evaluate alert rules");
+    let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let fired = svc
+        .evaluate_rules(
+            parse_cluster_id(&cluster_id)?,
+            &cfg,
+            req.namespace.as_deref(),
+            &req.rules,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(fired))
+}
+
+pub async fn list_alert_firings_controller(
+    claims: web::ReqData<Claims>,
+    path: web::Path<String>, // cluster_id
+    query: web::Query<AlertFiringsQuery>,
+    svc: web::Data<Arc<AlertingService>>,
+) -> Result<impl Responder, AppError> {
+    let cluster_id = path.into_inner();
+    debug!(target: "mayyam::controllers::alerting", user_id = %claims.username, %cluster_id, "List alert firings");
+    let firings = svc
+        .list_firings(
+            parse_cluster_id(&cluster_id)?,
+            query.open_only,
+            query.limit.unwrap_or(100),
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(firings))
+}
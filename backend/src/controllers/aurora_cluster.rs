@@ -21,8 +21,9 @@ use uuid::Uuid;
 use crate::config::Config;
 use crate::errors::AppError;
 use crate::middleware::auth::Claims;
-use crate::models::aurora_cluster::{AuroraCluster, AuroraClusterDto};
+use crate::models::aurora_cluster::{AuroraCluster, AuroraClusterConfig, AuroraClusterDto};
 use crate::repositories::aurora_cluster_repository::AuroraClusterRepository;
+use crate::services::aurora_cluster_service::AuroraClusterService;
 use crate::services::slow_query_ingestion_service::SlowQueryIngestionService;
 use serde::{Deserialize, Serialize};
 
@@ -247,4 +248,22 @@ pub async fn get_cluster_stats(
         "fingerprint_count": fingerprint_count,
         "performance_snapshot_count": snapshot_count
     })))
-}
\ No newline at end of file
+}
+
+pub async fn get_replication_lag(
+    query: web::Query<AuroraClusterConfig>,
+    aurora_service: web::Data<Arc<AuroraClusterService>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let status = aurora_service.get_replication_lag(&query).await?;
+    Ok(HttpResponse::Ok().json(status))
+}
+
+pub async fn get_cluster_topology(
+    query: web::Query<AuroraClusterConfig>,
+    aurora_service: web::Data<Arc<AuroraClusterService>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let topology = aurora_service.get_cluster_topology(&query).await?;
+    Ok(HttpResponse::Ok().json(topology))
+}
@@ -18,25 +18,34 @@ use jsonwebtoken::{encode, EncodingKey, Header};
 use std::sync::Arc;
 use tracing::{error};
 
-use crate::config::Config;
+use crate::config::SharedConfig;
 use crate::errors::AppError;
 use crate::middleware::auth::Claims;
+use crate::models::user::Model as UserModel;
 use crate::models::user::{AuthTokenResponse, CreateUserDto, LoginUserDto, UserResponse};
+use crate::services::user::oidc::{OidcLoginRedirect, OidcProvider};
 use crate::services::user::UserService;
 
 pub struct AuthController {
     user_service: Arc<UserService>,
-    config: Config,
+    config: SharedConfig,
+    oidc_provider: Option<Arc<OidcProvider>>,
 }
 
 impl AuthController {
-    pub fn new(user_service: Arc<UserService>, config: Config) -> Self {
+    pub fn new(user_service: Arc<UserService>, config: SharedConfig) -> Self {
         Self {
             user_service,
             config,
+            oidc_provider: None,
         }
     }
 
+    pub fn with_oidc_provider(mut self, oidc_provider: Arc<OidcProvider>) -> Self {
+        self.oidc_provider = Some(oidc_provider);
+        self
+    }
+
     pub async fn login(&self, login_data: LoginUserDto) -> Result<AuthTokenResponse, AppError> {
         // Verify credentials using the service layer
         let user = match self.user_service.authenticate_user(&login_data).await? {
@@ -44,9 +53,39 @@ impl AuthController {
             None => return Err(AppError::Auth("Invalid username or password".to_string())),
         };
 
-        // Generate JWT token
+        self.issue_token(user)
+    }
+
+    pub fn oidc_login_url(&self) -> Result<OidcLoginRedirect, AppError> {
+        self.oidc()?.get_authorization_url()
+    }
+
+    pub async fn oidc_callback(
+        &self,
+        code: &str,
+        state: &str,
+        state_cookie: Option<&str>,
+    ) -> Result<AuthTokenResponse, AppError> {
+        let claims = self
+            .oidc()?
+            .exchange_code_for_token(code, state, state_cookie)
+            .await?;
+        let user = self.user_service.find_or_create_oidc_user(&claims).await?;
+        self.issue_token(user)
+    }
+
+    fn oidc(&self) -> Result<&OidcProvider, AppError> {
+        self.oidc_provider
+            .as_deref()
+            .ok_or_else(|| AppError::BadRequest("OIDC authentication is not enabled".to_string()))
+    }
+
+    fn issue_token(&self, user: UserModel) -> Result<AuthTokenResponse, AppError> {
+        let (jwt_secret, jwt_expiration) = self
+            .config
+            .read(|c| (c.auth.jwt_secret.clone(), c.auth.jwt_expiration));
         let now = Utc::now();
-        let expiration = now + Duration::seconds(self.config.auth.jwt_expiration as i64);
+        let expiration = now + Duration::seconds(jwt_expiration as i64);
 
         let claims = Claims {
             sub: user.id.to_string(),
@@ -60,7 +99,7 @@ impl AuthController {
         let token = encode(
             &Header::default(),
             &claims,
-            &EncodingKey::from_secret(self.config.auth.jwt_secret.as_bytes()),
+            &EncodingKey::from_secret(jwt_secret.as_bytes()),
         )
         .map_err(|e| {
             error!("Failed to generate JWT token: {}", e);
@@ -73,7 +112,7 @@ impl AuthController {
         Ok(AuthTokenResponse {
             token,
             token_type: "Bearer".to_string(),
-            expires_in: self.config.auth.jwt_expiration as i64,
+            expires_in: jwt_expiration as i64,
             user: user_response,
         })
     }
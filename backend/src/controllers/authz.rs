@@ -60,6 +60,7 @@ async fn get_cluster_config_by_id(
             certificate_authority_data: None,
             client_certificate_data: None,
             client_key_data: None,
+            use_in_cluster_config: false,
         })
     } else {
         serde_json::from_value(value)
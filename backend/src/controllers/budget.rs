@@ -15,9 +15,13 @@
 
 use actix_web::{web, HttpResponse, Result};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use uuid::Uuid;
+use crate::controllers::cloud::resolve_account_for_region;
+use crate::errors::AppError;
+use crate::repositories::aws_account::AwsAccountRepository;
 use crate::services::budget_service::BudgetService;
-use crate::models::cost_budget::{BudgetDto, BudgetStatus, BudgetAlert};
+use crate::models::cost_budget::{BudgetDefinition, BudgetDto, BudgetStatus, BudgetAlert};
 
 #[derive(Deserialize)]
 pub struct CreateBudgetRequest {
@@ -302,4 +306,47 @@ pub async fn get_budget_alerts(
         .collect();
 
     Ok(HttpResponse::Ok().json(responses))
-}
\ No newline at end of file
+}
+
+#[derive(Deserialize)]
+pub struct CreateAwsBudgetRequest {
+    pub account_id: String,
+    pub region: String,
+    #[serde(flatten)]
+    pub budget: BudgetDefinition,
+}
+
+pub async fn create_aws_budget(
+    service: web::Data<Arc<BudgetService>>,
+    aws_account_repo: web::Data<Arc<AwsAccountRepository>>,
+    req: web::Json<CreateAwsBudgetRequest>,
+) -> Result<HttpResponse, AppError> {
+    let req = req.into_inner();
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &req.account_id, &req.region).await?;
+
+    let result = service
+        .create_aws_budget(&aws_account_dto, &req.region, req.budget)
+        .await?;
+    Ok(HttpResponse::Created().json(result))
+}
+
+#[derive(Deserialize)]
+pub struct SyncAwsBudgetsRequest {
+    pub account_id: String,
+    pub region: String,
+}
+
+pub async fn sync_aws_budgets(
+    service: web::Data<Arc<BudgetService>>,
+    aws_account_repo: web::Data<Arc<AwsAccountRepository>>,
+    req: web::Json<SyncAwsBudgetsRequest>,
+) -> Result<HttpResponse, AppError> {
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &req.account_id, &req.region).await?;
+
+    let result = service
+        .sync_aws_budgets(&aws_account_dto, &req.region)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
@@ -13,11 +13,13 @@
 // limitations under the License.
 
 use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use sea_orm::DatabaseConnection;
 use serde::Deserialize;
 use std::sync::Arc;
 use tracing::info;
 use uuid::Uuid;
 
+use crate::controllers::kubernetes::get_cluster_config_by_id;
 use crate::errors::AppError;
 use crate::middleware::auth::Claims;
 use crate::models::chaos_experiment::{
@@ -25,6 +27,7 @@ use crate::models::chaos_experiment::{
     RunExperimentRequest,
 };
 use crate::models::chaos_template::{ChaosTemplateCreateDto, ChaosTemplateQuery, ChaosTemplateUpdateDto};
+use crate::services::chaos_scheduler::ChaosScheduler;
 use crate::services::chaos_service::ChaosService;
 
 // ============================================================================
@@ -374,3 +377,62 @@ pub async fn get_resource_type_metrics(
         .await?;
     Ok(HttpResponse::Ok().json(metrics))
 }
+
+// ============================================================================
+// Auto-trigger Endpoints
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct CreateAutoTriggerRequest {
+    pub cluster_id: Uuid,
+    pub namespace: String,
+    pub restart_threshold: u32,
+    pub experiment_id: Uuid,
+}
+
+/// Register a restart-driven auto-trigger for an experiment
+pub async fn create_auto_trigger(
+    db: web::Data<Arc<DatabaseConnection>>,
+    service: web::Data<Arc<ChaosService>>,
+    scheduler: web::Data<Arc<ChaosScheduler>>,
+    body: web::Json<CreateAutoTriggerRequest>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let body = body.into_inner();
+    info!(
+        "Creating chaos auto-trigger for experiment {} on {}/{}",
+        body.experiment_id, body.cluster_id, body.namespace
+    );
+
+    let cluster_config =
+        get_cluster_config_by_id(db.get_ref().as_ref(), &body.cluster_id.to_string()).await?;
+    let experiment = service.get_experiment(body.experiment_id).await?;
+    let trigger = scheduler
+        .watch_and_trigger(
+            body.cluster_id,
+            cluster_config,
+            &body.namespace,
+            body.restart_threshold,
+            experiment,
+        )
+        .await?;
+    Ok(HttpResponse::Created().json(trigger))
+}
+
+/// List registered auto-triggers
+pub async fn list_auto_triggers(
+    scheduler: web::Data<Arc<ChaosScheduler>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    Ok(HttpResponse::Ok().json(scheduler.list_triggers()))
+}
+
+/// Stop and remove an auto-trigger
+pub async fn delete_auto_trigger(
+    scheduler: web::Data<Arc<ChaosScheduler>>,
+    id: web::Path<Uuid>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    scheduler.remove_trigger(id.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
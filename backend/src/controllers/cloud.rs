@@ -15,38 +15,67 @@
 
 use crate::errors::AppError;
 use crate::middleware::auth::Claims;
-use crate::models::aws_resource::{AwsResourceQuery, AwsResourceType};
+use crate::models::aws_resource::{AwsResourceQuery, AwsResourceType, ResourceSearchQuery};
 use crate::models::cloud_resource::CloudResourceQuery;
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use chrono::Utc;
+use futures::StreamExt;
 use std::sync::Arc;
 use tracing::info;
 use uuid::Uuid;
 
 use crate::models::aws_account::AwsAccountDto;
+use crate::services::aws::aws_control_plane::dynamodb_control_plane::DynamoDbControlPlane;
+use crate::services::aws::aws_control_plane::route53_control_plane::ROUTE53_REGION;
+use crate::services::aws::aws_control_plane::s3_control_plane::S3ControlPlane;
+use crate::services::aws::aws_control_plane::waf_control_plane::DEFAULT_BLOCK_IP_SET_NAME;
 use crate::services::aws::aws_data_plane::cloudwatch::{
     CloudWatchLogs, CloudWatchLogsRequest, CloudWatchMetrics, CloudWatchMetricsRequest,
     CloudWatchService,
 };
 use crate::services::aws::aws_data_plane::cost_explorer::CostAndUsage;
-use crate::services::aws::aws_data_plane::dynamodb_data_plane::DynamoDBDataPlane;
+use crate::services::aws::aws_data_plane::dynamodb_data_plane::{
+    DynamoDBDataPlane, DEFAULT_SCAN_MAX_ITEMS,
+};
 use crate::services::aws::aws_data_plane::kinesis_data_plane::KinesisDataPlane;
 use crate::services::aws::aws_data_plane::sqs_data_plane::SqsDataPlane;
+use crate::services::aws::aws_data_plane::vpc_flow_log_analyzer::DEFAULT_MAX_FLOW_LOG_EVENTS;
+use crate::services::aws::aws_types::autoscaling::{
+    AsgProcessesRequest, AsgSetDesiredCapacityRequest,
+};
 use crate::services::aws::aws_types::dynamodb::{
-    DynamoDBGetItemRequest, DynamoDBPutItemRequest, DynamoDBQueryRequest,
+    DynamoDBGetItemRequest, DynamoDBPutItemRequest, DynamoDBQueryRequest, DynamoDBScanRequest,
+    DynamoDbCapacityRequest,
+};
+use crate::services::aws::aws_types::ebs::{
+    EbsCopySnapshotRequest, EbsCreateSnapshotRequest, EbsSnapshotRetentionRequest,
+};
+use crate::services::aws::aws_types::ecs::{
+    EcsDeregisterTaskDefinitionsRequest, EcsDescribeTaskDefinitionQuery, EcsTaskDefinitionQuery,
 };
 use crate::services::aws::aws_types::kinesis::{
-    KinesisCreateStreamRequest, KinesisDeleteStreamRequest, KinesisDescribeStreamRequest,
-    KinesisEnhancedMonitoringRequest, KinesisGetRecordsRequest, KinesisGetShardIteratorRequest,
-    KinesisListShardsRequest, KinesisListStreamsRequest, KinesisPutRecordRequest,
-    KinesisPutRecordsRequest, KinesisRetentionPeriodRequest, KinesisUpdateShardCountRequest,
+    KinesisCreateStreamRequest, KinesisDeleteStreamRequest, KinesisDescribeStreamConsumerRequest,
+    KinesisDescribeStreamRequest, KinesisEnhancedMonitoringRequest, KinesisGetRecordsRequest,
+    KinesisGetShardIteratorRequest, KinesisListShardsRequest, KinesisListStreamConsumersRequest,
+    KinesisListStreamsRequest, KinesisMergeShardsRequest, KinesisPutRecordRequest,
+    KinesisPutRecordsRequest, KinesisRetentionPeriodRequest, KinesisSplitShardRequest,
+    KinesisStreamConsumerRequest, KinesisSubscribeToShardRequest, KinesisUpdateShardCountRequest,
+};
+use crate::services::aws::aws_types::sqs::{
+    SqsFifoSendRequest, SqsReceiveMessageRequest, SqsRequeueRequest, SqsSendMessageRequest,
+};
+use crate::services::aws::aws_types::waf::{
+    WafBlockIpRequest, WafCreateIpBlockRuleGroupRequest, WafGetRuleGroupQuery, WafListQuery,
+    WafUpdateIpSetRequest,
 };
-use crate::services::aws::aws_types::sqs::{SqsReceiveMessageRequest, SqsSendMessageRequest};
 use crate::services::aws::{AwsControlPlane, AwsCostService, AwsDataPlane};
 // use crate::services::aws::aws_control_plane::kinesis_control_plane::KinesisControlPlane;
 use crate::services::aws::aws_data_plane::s3_data_plane::S3DataPlane;
 use crate::services::aws::aws_types::resource_sync::ResourceSyncRequest;
+use crate::services::aws::aws_types::route53::CreateHealthCheckRequest;
 use crate::services::aws::aws_types::s3::{S3GetObjectRequest, S3PutObjectRequest};
+use crate::services::aws::aws_types::tagging::{BulkTagRequest, BulkUntagRequest};
+use crate::services::task_queue::TaskQueue;
 use serde::Deserialize;
 
 // AWS Control Plane operations
@@ -535,6 +564,65 @@ pub async fn search_aws_resources(
     Ok(HttpResponse::Ok().json(resources))
 }
 
+#[derive(Deserialize)]
+pub struct ResourceSearchParams {
+    pub q: String,
+    // Comma separated
+    pub resource_types: Option<String>,
+    pub account_ids: Option<String>,
+    pub regions: Option<String>,
+    // Comma separated key=value pairs
+    pub tags: Option<String>,
+    pub page: Option<usize>,
+    pub page_size: Option<usize>,
+}
+
+fn split_csv(value: Option<String>) -> Option<Vec<String>> {
+    value.map(|v| {
+        v.split(',')
+            .map(|item| item.trim().to_string())
+            .filter(|item| !item.is_empty())
+            .collect()
+    })
+}
+
+// Ranked search across every synced AWS resource type
+pub async fn unified_resource_search(
+    query: web::Query<ResourceSearchParams>,
+    aws_repo: web::Data<Arc<crate::repositories::aws_resource::AwsResourceRepository>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let params = query.into_inner();
+    let tag_filter = split_csv(params.tags)
+        .map(|pairs| {
+            pairs
+                .iter()
+                .map(|pair| {
+                    pair.split_once('=')
+                        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+                        .ok_or_else(|| {
+                            AppError::BadRequest(format!(
+                                "Invalid tag filter '{}', expected key=value",
+                                pair
+                            ))
+                        })
+                })
+                .collect::<Result<_, _>>()
+        })
+        .transpose()?;
+    let search = ResourceSearchQuery {
+        q: params.q,
+        resource_types: split_csv(params.resource_types),
+        account_ids: split_csv(params.account_ids),
+        regions: split_csv(params.regions),
+        tag_filter,
+        page: params.page.unwrap_or(0),
+        page_size: params.page_size.unwrap_or(20),
+    };
+    let results = aws_repo.full_text_search(&search).await?;
+    Ok(HttpResponse::Ok().json(results))
+}
+
 // Generic Cloud resource search endpoint (multi-cloud)
 pub async fn search_cloud_resources(
     query: web::Query<CloudResourceQuery>,
@@ -684,6 +772,40 @@ pub async fn dynamodb_query(
     Ok(HttpResponse::Ok().json(response))
 }
 
+#[derive(Debug, Deserialize)]
+pub struct DynamoDbScanQuery {
+    // Follow LastEvaluatedKey across pages instead of returning a single page
+    #[serde(default)]
+    pub all: bool,
+    pub max_items: Option<usize>,
+}
+
+pub async fn dynamodb_scan(
+    path: web::Path<(String, String, String)>,
+    query: web::Query<DynamoDbScanQuery>,
+    req: web::Json<DynamoDBScanRequest>,
+    aws_data_plane: web::Data<Arc<DynamoDBDataPlane>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (profile, region, table) = path.into_inner();
+
+    // Override the table name in the path
+    let mut request = req.into_inner();
+    request.table_name = table;
+
+    let aws_account_dto = AwsAccountDto::new_with_profile(&profile, &region);
+    let response = if query.all {
+        let max_items = query.max_items.unwrap_or(DEFAULT_SCAN_MAX_ITEMS);
+        aws_data_plane
+            .scan_all(&aws_account_dto, &request, max_items)
+            .await?
+    } else {
+        aws_data_plane.scan(&aws_account_dto, &request).await?
+    };
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
 // SQS data plane operations
 pub async fn sqs_send_message(
     path: web::Path<(String, String)>,
@@ -699,6 +821,22 @@ pub async fn sqs_send_message(
     Ok(HttpResponse::Ok().json(response))
 }
 
+pub async fn sqs_send_fifo_message(
+    path: web::Path<(String, String)>,
+    req: web::Json<SqsFifoSendRequest>,
+    aws_data_plane: web::Data<Arc<SqsDataPlane>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (profile, region) = path.into_inner();
+
+    let aws_account_dto = AwsAccountDto::new_with_profile(&profile, &region);
+    let response = aws_data_plane
+        .send_fifo_message(&aws_account_dto, &req)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
 pub async fn sqs_receive_messages(
     path: web::Path<(String, String)>,
     req: web::Json<SqsReceiveMessageRequest>,
@@ -715,6 +853,83 @@ pub async fn sqs_receive_messages(
     Ok(HttpResponse::Ok().json(response))
 }
 
+#[derive(Deserialize)]
+pub struct SqsDlqStatsQuery {
+    pub queue_url: String,
+}
+
+pub async fn sqs_dlq_stats(
+    path: web::Path<(String, String)>,
+    query: web::Query<SqsDlqStatsQuery>,
+    aws_data_plane: web::Data<Arc<SqsDataPlane>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (profile, region) = path.into_inner();
+
+    let aws_account_dto = AwsAccountDto::new_with_profile(&profile, &region);
+    let stats = aws_data_plane
+        .get_dlq_stats(&aws_account_dto, &query.queue_url)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(stats))
+}
+
+#[derive(Deserialize)]
+pub struct SqsQueueAttributesQuery {
+    pub queue_url: String,
+    // Comma-separated attribute names; all attributes when omitted
+    pub attributes: Option<String>,
+}
+
+pub async fn sqs_get_queue_attributes(
+    path: web::Path<(String, String)>,
+    query: web::Query<SqsQueueAttributesQuery>,
+    aws_data_plane: web::Data<Arc<SqsDataPlane>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (profile, region) = path.into_inner();
+    let attributes = query
+        .attributes
+        .as_deref()
+        .map(|a| {
+            a.split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let aws_account_dto = AwsAccountDto::new_with_profile(&profile, &region);
+    let response = aws_data_plane
+        .get_queue_attributes(&aws_account_dto, &query.queue_url, attributes)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+pub async fn sqs_requeue_dlq_messages(
+    path: web::Path<(String, String)>,
+    req: web::Json<SqsRequeueRequest>,
+    aws_data_plane: web::Data<Arc<SqsDataPlane>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (profile, region) = path.into_inner();
+
+    let aws_account_dto = AwsAccountDto::new_with_profile(&profile, &region);
+    let result = aws_data_plane
+        .requeue_dlq_messages(
+            &aws_account_dto,
+            &req.dlq_url,
+            &req.source_queue_url,
+            req.max_messages,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(result))
+}
+
 // Kinesis data plane operations
 pub async fn kinesis_put_record(
     path: web::Path<(String, String)>,
@@ -831,6 +1046,36 @@ pub async fn kinesis_update_shard_count(
     Ok(HttpResponse::Ok().json(response))
 }
 
+pub async fn kinesis_split_shard(
+    path: web::Path<(String, String)>,
+    req: web::Json<KinesisSplitShardRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (profile, region) = path.into_inner();
+    let aws_account_dto = AwsAccountDto::new_with_profile(&profile, &region);
+    let response = aws_control_plane
+        .kinesis_split_shard(&aws_account_dto, &req)
+        .await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+pub async fn kinesis_merge_shards(
+    path: web::Path<(String, String)>,
+    req: web::Json<KinesisMergeShardsRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (profile, region) = path.into_inner();
+    let aws_account_dto = AwsAccountDto::new_with_profile(&profile, &region);
+    let response = aws_control_plane
+        .kinesis_merge_shards(&aws_account_dto, &req)
+        .await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
 pub async fn kinesis_increase_retention_period(
     path: web::Path<(String, String)>,
     req: web::Json<KinesisRetentionPeriodRequest>,
@@ -901,6 +1146,50 @@ pub async fn kinesis_list_shards(
     Ok(HttpResponse::Ok().json(response))
 }
 
+pub async fn kinesis_register_stream_consumer(
+    path: web::Path<(String, String)>,
+    req: web::Json<KinesisStreamConsumerRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (profile, region) = path.into_inner();
+    let aws_account_dto = AwsAccountDto::new_with_profile(&profile, &region);
+    let response = aws_control_plane
+        .kinesis_register_stream_consumer(&aws_account_dto, &req)
+        .await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+pub async fn kinesis_deregister_stream_consumer(
+    path: web::Path<(String, String)>,
+    req: web::Json<KinesisDescribeStreamConsumerRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (profile, region) = path.into_inner();
+    let aws_account_dto = AwsAccountDto::new_with_profile(&profile, &region);
+    aws_control_plane
+        .kinesis_deregister_stream_consumer(&aws_account_dto, &req)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub async fn kinesis_list_stream_consumers(
+    path: web::Path<(String, String)>,
+    req: web::Json<KinesisListStreamConsumersRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (profile, region) = path.into_inner();
+    let aws_account_dto = AwsAccountDto::new_with_profile(&profile, &region);
+    let response = aws_control_plane
+        .kinesis_list_stream_consumers(&aws_account_dto, &req.stream_arn)
+        .await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
 // Kinesis Data Plane Endpoints
 pub async fn kinesis_put_records(
     path: web::Path<(String, String)>,
@@ -917,6 +1206,28 @@ pub async fn kinesis_put_records(
     Ok(HttpResponse::Ok().json(response))
 }
 
+// Streams records as newline-delimited JSON until the subscription ends
+pub async fn kinesis_subscribe_to_shard(
+    path: web::Path<(String, String)>,
+    req: web::Json<KinesisSubscribeToShardRequest>,
+    aws_data_plane: web::Data<Arc<AwsDataPlane>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (profile, region) = path.into_inner();
+    let aws_account_dto = AwsAccountDto::new_with_profile(&profile, &region);
+    let records = aws_data_plane
+        .kinesis_subscribe_to_shard(&aws_account_dto, &req)
+        .await?;
+    let body = records.map(|record| {
+        let mut line = serde_json::to_vec(&record?)?;
+        line.push(b'\n');
+        Ok::<_, AppError>(web::Bytes::from(line))
+    });
+    Ok(HttpResponse::Ok()
+        .content_type("application/x-ndjson")
+        .streaming(body))
+}
+
 pub async fn kinesis_get_records(
     path: web::Path<(String, String)>,
     req: web::Json<KinesisGetRecordsRequest>,
@@ -1071,6 +1382,7 @@ pub async fn get_cloudwatch_logs(
         end_time,
         filter_pattern,
         limit: Some(1000), // Add a default limit
+        max_events: None,
     };
 
     let aws_account_dto = AwsAccountDto::new_with_profile(&profile, &region);
@@ -1619,6 +1931,38 @@ pub async fn list_glue_databases(
     Ok(HttpResponse::Ok().json(resources))
 }
 
+pub async fn list_glue_tables(
+    path: web::Path<(String, String)>,
+    query: web::Query<AwsResourceQuery>,
+    aws_repo: web::Data<Arc<crate::repositories::aws_resource::AwsResourceRepository>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (account_id, region) = path.into_inner();
+    let mut query_params = query.into_inner();
+    query_params.account_id = Some(account_id);
+    query_params.region = Some(region);
+    query_params.resource_type = Some(AwsResourceType::GlueTable.to_string());
+    let resources = aws_repo.search(&query_params).await?;
+    Ok(HttpResponse::Ok().json(resources))
+}
+
+// Live column and partition key definitions for a Glue table
+pub async fn get_glue_table_schema(
+    path: web::Path<(String, String, String, String)>,
+    aws_data_plane: web::Data<Arc<AwsDataPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (account_id, region, database_name, table_name) = path.into_inner();
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &region).await?;
+    let schema = aws_data_plane
+        .get_glue_table_schema(&aws_account_dto, &region, &database_name, &table_name)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(schema))
+}
+
 // Batch 7: Edge & DR
 pub async fn list_waf_web_acls(
     path: web::Path<(String, String)>,
@@ -1756,3 +2100,1084 @@ pub async fn list_kinesis_analytics_apps(
     let resources = aws_repo.search(&query_params).await?;
     Ok(HttpResponse::Ok().json(resources))
 }
+
+#[derive(Deserialize)]
+pub struct VpcFlowLogQuery {
+    pub region: String,
+    pub log_group: String,
+    pub start_time: Option<chrono::DateTime<Utc>>,
+    pub end_time: Option<chrono::DateTime<Utc>>,
+    pub max_events: Option<usize>,
+}
+
+// VPC flow log analysis; defaults to the last hour of traffic
+pub async fn analyze_vpc_flow_logs(
+    path: web::Path<(String, String)>,
+    query: web::Query<VpcFlowLogQuery>,
+    aws_data_plane: web::Data<Arc<AwsDataPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (account_id, vpc_id) = path.into_inner();
+    let q = query.into_inner();
+    let end_time = q.end_time.unwrap_or_else(Utc::now);
+    let start_time = q
+        .start_time
+        .unwrap_or_else(|| end_time - chrono::Duration::hours(1));
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &q.region).await?;
+    let summary = aws_data_plane
+        .analyze_vpc_flow_logs(
+            &aws_account_dto,
+            &q.log_group,
+            start_time,
+            end_time,
+            &vpc_id,
+            q.max_events.unwrap_or(DEFAULT_MAX_FLOW_LOG_EVENTS),
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(summary))
+}
+
+#[derive(Deserialize)]
+pub struct EbsSnapshotQuery {
+    pub region: String,
+    // Comma separated; defaults to the account's own snapshots
+    pub owner_ids: Option<String>,
+}
+
+pub async fn list_ebs_volume_snapshots(
+    path: web::Path<(String, String)>,
+    query: web::Query<EbsSnapshotQuery>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (account_id, volume_id) = path.into_inner();
+    let q = query.into_inner();
+    let owner_ids = q
+        .owner_ids
+        .map(|ids| {
+            ids.split(',')
+                .map(|id| id.trim().to_string())
+                .filter(|id| !id.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &q.region).await?;
+    let snapshots = aws_control_plane
+        .ebs_list_snapshots(&aws_account_dto, &q.region, Some(&volume_id), owner_ids)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(snapshots))
+}
+
+pub async fn create_ebs_volume_snapshot(
+    path: web::Path<(String, String)>,
+    req: web::Json<EbsCreateSnapshotRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (account_id, volume_id) = path.into_inner();
+    let req = req.into_inner();
+    info!(
+        "User {} creating snapshot of EBS volume {} in {}",
+        claims.username, volume_id, req.region
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &req.region).await?;
+    let snapshot = aws_control_plane
+        .ebs_create_snapshot(
+            &aws_account_dto,
+            &req.region,
+            &volume_id,
+            &req.description,
+            req.tags,
+        )
+        .await?;
+
+    Ok(HttpResponse::Created().json(snapshot))
+}
+
+pub async fn delete_ebs_volume_snapshot(
+    req: HttpRequest,
+    path: web::Path<(String, String, String)>,
+    query: web::Query<EbsSnapshotQuery>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (account_id, volume_id, snapshot_id) = path.into_inner();
+    info!(
+        "User {} deleting snapshot {} of EBS volume {}",
+        claims.username, snapshot_id, volume_id
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &query.region).await?;
+    aws_control_plane
+        .ebs_delete_snapshot(
+            &aws_account_dto,
+            &query.region,
+            &snapshot_id,
+            &claims.audit_context(&req, None),
+        )
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub async fn copy_ebs_volume_snapshot(
+    path: web::Path<(String, String, String)>,
+    req: web::Json<EbsCopySnapshotRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (account_id, volume_id, snapshot_id) = path.into_inner();
+    info!(
+        "User {} copying snapshot {} of EBS volume {} from {} to {}",
+        claims.username, snapshot_id, volume_id, req.source_region, req.target_region
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &req.target_region).await?;
+    let new_snapshot_id = aws_control_plane
+        .ebs_copy_snapshot(
+            &aws_account_dto,
+            &req.source_region,
+            &req.target_region,
+            &snapshot_id,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "source_snapshot_id": snapshot_id,
+        "snapshot_id": new_snapshot_id,
+        "region": req.target_region,
+    })))
+}
+
+pub async fn enforce_ebs_snapshot_retention(
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    body: web::Json<EbsSnapshotRetentionRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (account_id, volume_id) = path.into_inner();
+    info!(
+        "User {} enforcing retention of {} snapshots on EBS volume {}",
+        claims.username, body.keep_last_n, volume_id
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &body.region).await?;
+    let result = aws_control_plane
+        .ebs_enforce_snapshot_retention(
+            &aws_account_dto,
+            &body.region,
+            &volume_id,
+            body.keep_last_n,
+            &claims.audit_context(&req, None),
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(result))
+}
+
+pub async fn list_ecs_task_definition_families(
+    path: web::Path<String>,
+    query: web::Query<EcsTaskDefinitionQuery>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let account_id = path.into_inner();
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &query.region).await?;
+    let families = aws_control_plane
+        .ecs_list_task_definition_families(&aws_account_dto, &query.region)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(families))
+}
+
+pub async fn list_ecs_task_definition_revisions(
+    path: web::Path<(String, String)>,
+    query: web::Query<EcsTaskDefinitionQuery>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (account_id, family) = path.into_inner();
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &query.region).await?;
+    let revisions = aws_control_plane
+        .ecs_list_task_definition_revisions(&aws_account_dto, &query.region, &family)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(revisions))
+}
+
+// The task definition is taken from the query string since ARNs contain '/'
+pub async fn describe_ecs_task_definition(
+    path: web::Path<String>,
+    query: web::Query<EcsDescribeTaskDefinitionQuery>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let account_id = path.into_inner();
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &query.region).await?;
+    let detail = aws_control_plane
+        .ecs_describe_task_definition(&aws_account_dto, &query.region, &query.task_definition)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(detail))
+}
+
+pub async fn deregister_old_ecs_task_definitions(
+    path: web::Path<(String, String)>,
+    req: web::Json<EcsDeregisterTaskDefinitionsRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (account_id, family) = path.into_inner();
+    info!(
+        "User {} deregistering task definitions of family {} beyond the newest {}",
+        claims.username, family, req.keep_last_n
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &req.region).await?;
+    let result = aws_control_plane
+        .ecs_deregister_old_task_definitions(
+            &aws_account_dto,
+            &req.region,
+            &family,
+            req.keep_last_n,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(result))
+}
+
+pub async fn list_waf_ip_sets(
+    path: web::Path<String>,
+    query: web::Query<WafListQuery>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let account_id = path.into_inner();
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &query.region).await?;
+    let ip_sets = aws_control_plane
+        .waf_list_ip_sets(&aws_account_dto, &query.region, query.scope)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(ip_sets))
+}
+
+pub async fn update_waf_ip_set(
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    body: web::Json<WafUpdateIpSetRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (account_id, ip_set_id) = path.into_inner();
+    let body = body.into_inner();
+    info!(
+        "User {} replacing addresses of WAF IP set {} with {} entries",
+        claims.username,
+        body.name,
+        body.addresses.len()
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &body.region).await?;
+    let audit_ctx = claims.audit_context(&req, None);
+    let next_lock_token = aws_control_plane
+        .waf_update_ip_set(
+            &aws_account_dto,
+            &body.region,
+            body.scope,
+            &ip_set_id,
+            &body.name,
+            &body.lock_token,
+            body.addresses,
+            &audit_ctx,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "ip_set_id": ip_set_id,
+        "next_lock_token": next_lock_token,
+    })))
+}
+
+pub async fn list_waf_rule_groups(
+    path: web::Path<String>,
+    query: web::Query<WafListQuery>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let account_id = path.into_inner();
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &query.region).await?;
+    let rule_groups = aws_control_plane
+        .waf_list_rule_groups(&aws_account_dto, &query.region, query.scope)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(rule_groups))
+}
+
+pub async fn get_waf_rule_group(
+    path: web::Path<(String, String)>,
+    query: web::Query<WafGetRuleGroupQuery>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (account_id, rule_group_id) = path.into_inner();
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &query.region).await?;
+    let rule_group = aws_control_plane
+        .waf_get_rule_group(
+            &aws_account_dto,
+            &query.region,
+            query.scope,
+            &rule_group_id,
+            &query.name,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(rule_group))
+}
+
+pub async fn create_waf_ip_block_rule_group(
+    req: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<WafCreateIpBlockRuleGroupRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let account_id = path.into_inner();
+    let body = body.into_inner();
+    info!(
+        "User {} creating WAF IP block rule group {} with {} addresses",
+        claims.username,
+        body.name,
+        body.ip_addresses.len()
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &body.region).await?;
+    let audit_ctx = claims.audit_context(&req, None);
+    let result = aws_control_plane
+        .waf_create_ip_block_rule_group(
+            &aws_account_dto,
+            &body.region,
+            body.scope,
+            &body.name,
+            body.ip_addresses,
+            &audit_ctx,
+        )
+        .await?;
+
+    Ok(HttpResponse::Created().json(result))
+}
+
+// Emergency block: adds one address to the designated block list IP set
+pub async fn block_waf_ip(
+    req: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<WafBlockIpRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let account_id = path.into_inner();
+    let ip_set_name = body
+        .ip_set_name
+        .clone()
+        .unwrap_or_else(|| DEFAULT_BLOCK_IP_SET_NAME.to_string());
+    info!(
+        "User {} blocking {} via WAF IP set {}",
+        claims.username, body.ip_address, ip_set_name
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &body.region).await?;
+    let audit_ctx = claims.audit_context(&req, None);
+    let result = aws_control_plane
+        .waf_block_ip(
+            &aws_account_dto,
+            &body.region,
+            body.scope,
+            &ip_set_name,
+            &body.ip_address,
+            &audit_ctx,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(result))
+}
+
+#[derive(Deserialize)]
+pub struct SecurityFindingsQuery {
+    pub resource_arn: Option<String>,
+    pub severity: Option<String>,
+}
+
+// Stored Inspector findings for one resource (highest CVSS first) or the whole account
+pub async fn list_security_findings(
+    path: web::Path<String>,
+    query: web::Query<SecurityFindingsQuery>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let account_id = path.into_inner();
+    let q = query.into_inner();
+
+    let findings = match q.resource_arn {
+        Some(resource_arn) => {
+            aws_control_plane
+                .get_findings_for_resource(&resource_arn)
+                .await?
+        }
+        None => {
+            aws_control_plane
+                .get_findings_for_account(&account_id, q.severity.as_deref())
+                .await?
+        }
+    };
+
+    Ok(HttpResponse::Ok().json(findings))
+}
+
+#[derive(Deserialize)]
+pub struct SyncSecurityFindingsRequest {
+    pub region: String,
+    pub severities: Option<Vec<String>>,
+}
+
+pub async fn sync_security_findings(
+    path: web::Path<String>,
+    req: web::Json<SyncSecurityFindingsRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let account_id = path.into_inner();
+    let req = req.into_inner();
+    info!(
+        "User {} syncing Inspector findings for account {} in {}",
+        claims.username, account_id, req.region
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &req.region).await?;
+    let findings = aws_control_plane
+        .sync_inspector_findings(&aws_account_dto, &req.region, req.severities)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "account_id": account_id,
+        "region": req.region,
+        "synced": findings.len(),
+    })))
+}
+
+#[derive(Deserialize)]
+pub struct ConfigComplianceQuery {
+    pub region: String,
+    // AWS Config resource type, e.g. AWS::EC2::SecurityGroup
+    pub resource_type: Option<String>,
+}
+
+pub async fn check_config_compliance(
+    path: web::Path<String>,
+    query: web::Query<ConfigComplianceQuery>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let account_id = path.into_inner();
+    let q = query.into_inner();
+    info!(
+        "User {} checking Config compliance for account {} in {}",
+        claims.username, account_id, q.region
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &q.region).await?;
+    let report = aws_control_plane
+        .check_config_compliance(&aws_account_dto, &q.region, q.resource_type)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(report))
+}
+
+// Route53 is global, so these endpoints take no region
+pub async fn sync_route53_records(
+    path: web::Path<(String, String)>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (account_id, hosted_zone_id) = path.into_inner();
+    info!(
+        "User {} syncing Route53 records of hosted zone {} in account {}",
+        claims.username, hosted_zone_id, account_id
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, ROUTE53_REGION).await?;
+    let records = aws_control_plane
+        .sync_route53_records(&aws_account_dto, &hosted_zone_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(records))
+}
+
+pub async fn create_route53_health_check(
+    path: web::Path<String>,
+    req: web::Json<CreateHealthCheckRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let account_id = path.into_inner();
+    let req = req.into_inner();
+    info!(
+        "User {} creating Route53 health check for {}:{}",
+        claims.username, req.fqdn, req.port
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, ROUTE53_REGION).await?;
+    let result = aws_control_plane
+        .create_health_check(
+            &aws_account_dto,
+            &req.fqdn,
+            req.port,
+            &req.protocol,
+            req.path,
+            req.failure_threshold,
+        )
+        .await?;
+
+    Ok(HttpResponse::Created().json(result))
+}
+
+pub async fn delete_route53_health_check(
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (account_id, health_check_id) = path.into_inner();
+    info!(
+        "User {} deleting Route53 health check {}",
+        claims.username, health_check_id
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, ROUTE53_REGION).await?;
+    aws_control_plane
+        .delete_health_check(
+            &aws_account_dto,
+            &health_check_id,
+            &claims.audit_context(&req, None),
+        )
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub async fn get_route53_health_check_status(
+    path: web::Path<(String, String)>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (account_id, health_check_id) = path.into_inner();
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, ROUTE53_REGION).await?;
+    let status = aws_control_plane
+        .get_health_check_status(&aws_account_dto, &health_check_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(status))
+}
+
+#[derive(Deserialize)]
+pub struct LambdaPerformanceQuery {
+    pub region: String,
+    pub start_time: Option<chrono::DateTime<Utc>>,
+    pub end_time: Option<chrono::DateTime<Utc>>,
+}
+
+// Lambda performance analytics; defaults to the last 24 hours
+pub async fn get_lambda_performance(
+    path: web::Path<(String, String)>,
+    query: web::Query<LambdaPerformanceQuery>,
+    aws_data_plane: web::Data<Arc<AwsDataPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (account_id, function_name) = path.into_inner();
+    let q = query.into_inner();
+    let end_time = q.end_time.unwrap_or_else(Utc::now);
+    let start_time = q
+        .start_time
+        .unwrap_or_else(|| end_time - chrono::Duration::hours(24));
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &q.region).await?;
+    let report = aws_data_plane
+        .lambda_function_performance(
+            &aws_account_dto,
+            &q.region,
+            &function_name,
+            start_time,
+            end_time,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(report))
+}
+
+#[derive(Deserialize)]
+pub struct CreateResourceDashboardRequest {
+    pub region: String,
+    pub resource_id: String,
+    // EC2Instance, RdsInstance or ElasticacheCluster
+    pub resource_type: String,
+    pub dashboard_name: String,
+}
+
+pub async fn create_resource_dashboard(
+    path: web::Path<String>,
+    req: web::Json<CreateResourceDashboardRequest>,
+    aws_data_plane: web::Data<Arc<AwsDataPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let account_id = path.into_inner();
+    let req = req.into_inner();
+    info!(
+        "User {} creating CloudWatch dashboard {} for {} {}",
+        claims.username, req.dashboard_name, req.resource_type, req.resource_id
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &req.region).await?;
+    let result = aws_data_plane
+        .create_resource_dashboard(
+            &aws_account_dto,
+            &req.region,
+            &req.resource_id,
+            &req.resource_type,
+            &req.dashboard_name,
+        )
+        .await?;
+
+    Ok(HttpResponse::Created().json(result))
+}
+
+#[derive(Deserialize)]
+pub struct DashboardSnapshotQuery {
+    pub region: String,
+}
+
+pub async fn get_dashboard_snapshot(
+    path: web::Path<(String, String)>,
+    query: web::Query<DashboardSnapshotQuery>,
+    aws_data_plane: web::Data<Arc<AwsDataPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (account_id, dashboard_name) = path.into_inner();
+    let q = query.into_inner();
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &q.region).await?;
+    let snapshot = aws_data_plane
+        .get_dashboard_snapshot(&aws_account_dto, &q.region, &dashboard_name)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(snapshot))
+}
+
+#[derive(Deserialize)]
+pub struct RdsPerformanceInsightsQuery {
+    pub region: String,
+    pub start_time: Option<chrono::DateTime<Utc>>,
+    pub end_time: Option<chrono::DateTime<Utc>>,
+    // Comma-separated Performance Insights metric names
+    pub metrics: Option<String>,
+}
+
+// RDS Performance Insights top SQL; defaults to the last hour
+pub async fn get_rds_performance_insights(
+    path: web::Path<(String, String)>,
+    query: web::Query<RdsPerformanceInsightsQuery>,
+    aws_data_plane: web::Data<Arc<AwsDataPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (account_id, db_instance_id) = path.into_inner();
+    let q = query.into_inner();
+    let end_time = q.end_time.unwrap_or_else(Utc::now);
+    let start_time = q
+        .start_time
+        .unwrap_or_else(|| end_time - chrono::Duration::hours(1));
+    let metrics = q
+        .metrics
+        .map(|m| m.split(',').map(|s| s.trim().to_string()).collect())
+        .unwrap_or_default();
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &q.region).await?;
+    let insights = aws_data_plane
+        .rds_performance_insights(
+            &aws_account_dto,
+            &db_instance_id,
+            start_time,
+            end_time,
+            metrics,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(insights))
+}
+
+// Queue a full resource sync for the account and return immediately; poll /api/tasks/{id}
+pub async fn enqueue_aws_account_sync(
+    path: web::Path<String>,
+    task_queue: web::Data<Arc<TaskQueue>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let account_id = path.into_inner();
+    let account = aws_account_repo
+        .get_by_account_id(&account_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("AWS account {} not found", account_id)))?;
+    info!("User {} queued resource sync for account {}", claims.username, account_id);
+
+    let task = task_queue.enqueue_aws_sync(account.id).await?;
+    Ok(HttpResponse::Accepted().json(serde_json::json!({
+        "task_id": task.id,
+        "status": task.status,
+    })))
+}
+
+// Auto Scaling Groups
+pub async fn list_autoscaling_groups(
+    path: web::Path<(String, String)>,
+    query: web::Query<AwsResourceQuery>,
+    aws_repo: web::Data<Arc<crate::repositories::aws_resource::AwsResourceRepository>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (account_id, region) = path.into_inner();
+    let mut query_params = query.into_inner();
+    query_params.account_id = Some(account_id);
+    query_params.region = Some(region);
+    query_params.resource_type = Some(AwsResourceType::AutoScalingGroup.to_string());
+    let resources = aws_repo.search(&query_params).await?;
+    Ok(HttpResponse::Ok().json(resources))
+}
+
+pub(crate) async fn resolve_account_for_region(
+    aws_account_repo: &crate::repositories::aws_account::AwsAccountRepository,
+    account_id: &str,
+    region: &str,
+) -> Result<AwsAccountDto, AppError> {
+    let account = aws_account_repo
+        .get_by_account_id(account_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("AWS account {} not found", account_id)))?;
+    let mut aws_account_dto = AwsAccountDto::from(account);
+    aws_account_dto.default_region = region.to_string();
+    Ok(aws_account_dto)
+}
+
+pub async fn asg_set_desired_capacity(
+    path: web::Path<(String, String, String)>,
+    req: web::Json<AsgSetDesiredCapacityRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (account_id, region, asg_name) = path.into_inner();
+    info!(
+        "User {} setting desired capacity of ASG {} to {}",
+        claims.username, asg_name, req.desired_capacity
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &region).await?;
+    let response = aws_control_plane
+        .asg_set_desired_capacity(&aws_account_dto, &asg_name, &req)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+pub async fn asg_suspend_processes(
+    path: web::Path<(String, String, String)>,
+    req: web::Json<AsgProcessesRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (account_id, region, asg_name) = path.into_inner();
+    info!(
+        "User {} suspending processes {:?} on ASG {}",
+        claims.username, req.processes, asg_name
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &region).await?;
+    let response = aws_control_plane
+        .asg_suspend_processes(&aws_account_dto, &asg_name, &req)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+pub async fn asg_resume_processes(
+    path: web::Path<(String, String, String)>,
+    req: web::Json<AsgProcessesRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (account_id, region, asg_name) = path.into_inner();
+    info!(
+        "User {} resuming processes {:?} on ASG {}",
+        claims.username, req.processes, asg_name
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &region).await?;
+    let response = aws_control_plane
+        .asg_resume_processes(&aws_account_dto, &asg_name, &req)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+pub async fn bulk_tag_resources(
+    path: web::Path<(String, String)>,
+    req: web::Json<BulkTagRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (account_id, region) = path.into_inner();
+    let req = req.into_inner();
+    info!(
+        "User {} tagging {} resources in {} with keys {:?}",
+        claims.username,
+        req.resource_arns.len(),
+        region,
+        req.tags.keys().collect::<Vec<_>>()
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &region).await?;
+    let result = aws_control_plane
+        .bulk_tag_resources(&aws_account_dto, &region, req.resource_arns, req.tags)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(result))
+}
+
+pub async fn bulk_untag_resources(
+    path: web::Path<(String, String)>,
+    req: web::Json<BulkUntagRequest>,
+    aws_control_plane: web::Data<Arc<AwsControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (account_id, region) = path.into_inner();
+    let req = req.into_inner();
+    info!(
+        "User {} removing tag keys {:?} from {} resources in {}",
+        claims.username,
+        req.tag_keys,
+        req.resource_arns.len(),
+        region
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &region).await?;
+    let result = aws_control_plane
+        .bulk_untag_resources(&aws_account_dto, &region, req.resource_arns, req.tag_keys)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(result))
+}
+
+pub async fn dynamodb_switch_to_on_demand(
+    path: web::Path<(String, String, String)>,
+    dynamodb_control_plane: web::Data<Arc<DynamoDbControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (account_id, region, table_name) = path.into_inner();
+    info!(
+        "User {} switching DynamoDB table {} to on-demand",
+        claims.username, table_name
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &region).await?;
+    let response = dynamodb_control_plane
+        .switch_to_on_demand(&aws_account_dto, &region, &table_name)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+pub async fn dynamodb_switch_to_provisioned(
+    path: web::Path<(String, String, String)>,
+    req: web::Json<DynamoDbCapacityRequest>,
+    dynamodb_control_plane: web::Data<Arc<DynamoDbControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (account_id, region, table_name) = path.into_inner();
+    info!(
+        "User {} switching DynamoDB table {} to provisioned ({} RCU / {} WCU)",
+        claims.username, table_name, req.read_capacity, req.write_capacity
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &region).await?;
+    let response = dynamodb_control_plane
+        .switch_to_provisioned(
+            &aws_account_dto,
+            &region,
+            &table_name,
+            req.read_capacity,
+            req.write_capacity,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+pub async fn dynamodb_update_throughput(
+    path: web::Path<(String, String, String)>,
+    req: web::Json<DynamoDbCapacityRequest>,
+    dynamodb_control_plane: web::Data<Arc<DynamoDbControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (account_id, region, table_name) = path.into_inner();
+    info!(
+        "User {} updating DynamoDB table {} throughput to {} RCU / {} WCU",
+        claims.username, table_name, req.read_capacity, req.write_capacity
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &region).await?;
+    let response = dynamodb_control_plane
+        .update_provisioned_throughput(
+            &aws_account_dto,
+            &region,
+            &table_name,
+            req.read_capacity,
+            req.write_capacity,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+pub async fn s3_audit_bucket_security(
+    path: web::Path<(String, String, String)>,
+    s3_control_plane: web::Data<Arc<S3ControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (account_id, region, bucket_name) = path.into_inner();
+    info!(
+        "User {} auditing security of S3 bucket {}",
+        claims.username, bucket_name
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &region).await?;
+    let audit = s3_control_plane
+        .audit_bucket_security(&aws_account_dto, &region, &bucket_name)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(audit))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct S3SecurityAuditQuery {
+    pub risk_threshold: Option<u8>,
+}
+
+pub async fn s3_audit_all_buckets(
+    path: web::Path<(String, String)>,
+    query: web::Query<S3SecurityAuditQuery>,
+    s3_control_plane: web::Data<Arc<S3ControlPlane>>,
+    aws_account_repo: web::Data<Arc<crate::repositories::aws_account::AwsAccountRepository>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (account_id, region) = path.into_inner();
+    info!(
+        "User {} auditing security of S3 buckets in {} / {}",
+        claims.username, account_id, region
+    );
+
+    let aws_account_dto =
+        resolve_account_for_region(&aws_account_repo, &account_id, &region).await?;
+    let audits = s3_control_plane
+        .audit_all_buckets(&aws_account_dto, &region, query.risk_threshold.unwrap_or(0))
+        .await?;
+
+    Ok(HttpResponse::Ok().json(audits))
+}
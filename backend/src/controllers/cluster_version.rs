@@ -0,0 +1,37 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::controllers::kubernetes::get_cluster_config_by_id;
+use crate::errors::AppError;
+use crate::middleware::auth::Claims;
+use crate::services::kubernetes::cluster_version_service::ClusterVersionService;
+use actix_web::{web, HttpResponse, Responder};
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use tracing::debug;
+
+pub async fn get_api_deprecations_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<String>, // cluster_id
+    cluster_version_service: web::Data<Arc<ClusterVersionService>>,
+) -> Result<impl Responder, AppError> {
+    let cluster_id = path.into_inner();
+    debug!(target: "mayyam::controllers::cluster_version", user_id = %claims.username, %cluster_id, "Attempting to build API deprecation report");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let report = cluster_version_service
+        .get_api_deprecations(&cluster_config)
+        .await?;
+    Ok(HttpResponse::Ok().json(report))
+}
@@ -0,0 +1,34 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use actix_web::{web, HttpResponse, Responder};
+use std::sync::Arc;
+use tracing::info;
+
+use crate::config::reloader::ConfigReloader;
+use crate::errors::AppError;
+use crate::middleware::auth::Claims;
+
+/// Re-read the config file without restarting the server
+pub async fn reload_config(
+    reloader: web::Data<Arc<ConfigReloader>>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+
+    info!("Manual config reload requested by {}", claims.username);
+    let event = reloader.reload().await?;
+    Ok(HttpResponse::Ok().json(event))
+}
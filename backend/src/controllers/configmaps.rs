@@ -13,7 +13,7 @@
 // limitations under the License.
 
 
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait};
 use std::sync::Arc;
 use tracing::debug;
@@ -51,6 +51,7 @@ async fn get_cluster_config_by_id(
             certificate_authority_data: None,
             client_certificate_data: None,
             client_key_data: None,
+            use_in_cluster_config: false,
         })
     } else {
         serde_json::from_value(value)
@@ -69,7 +70,7 @@ pub async fn list_configmaps_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>, // (cluster_id, namespace)
-    query: web::Query<std::collections::HashMap<String, String>>, // labelSelector, fieldSelector, limit, continue
+    query: web::Query<std::collections::HashMap<String, String>>, // labelSelector, fieldSelector, page_size, continue_token
     svc: web::Data<Arc<ConfigMapsService>>,
 ) -> Result<impl Responder, AppError> {
     let (cluster_id, ns) = path.into_inner();
@@ -77,15 +78,22 @@ pub async fn list_configmaps_controller(
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
     let label_selector = query.get("labelSelector").cloned();
     let field_selector = query.get("fieldSelector").cloned();
-    let limit = query.get("limit").and_then(|s| s.parse::<u32>().ok());
-    let continue_token = query.get("continue").cloned();
+    // `limit` and `continue` are the older names for these parameters
+    let page_size = query
+        .get("page_size")
+        .or_else(|| query.get("limit"))
+        .and_then(|s| s.parse::<usize>().ok());
+    let continue_token = query
+        .get("continue_token")
+        .or_else(|| query.get("continue"))
+        .cloned();
     let list = svc
         .list(
             &cfg,
             &ns,
             label_selector,
             field_selector,
-            limit,
+            page_size,
             continue_token,
         )
         .await?;
@@ -124,6 +132,7 @@ pub async fn upsert_configmap_controller(
 
 pub async fn delete_configmap_controller(
     claims: web::ReqData<Claims>,
+    req: HttpRequest,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String, String)>, // (cluster_id, namespace, name)
     svc: web::Data<Arc<ConfigMapsService>>,
@@ -131,6 +140,7 @@ pub async fn delete_configmap_controller(
     let (cluster_id, ns, name) = path.into_inner();
     debug!(target: "mayyam::controllers::configmaps", user_id = %claims.username, %cluster_id, %ns, %name, "Delete ConfigMap");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    svc.delete(&cfg, &ns, &name).await?;
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
+    svc.delete(&cfg, &ns, &name, &audit_ctx).await?;
     Ok(HttpResponse::Ok().json(serde_json::json!({"deleted": true})))
 }
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 
-use actix_web::{web, HttpResponse, Result as ActixResult};
+use actix_web::{web, HttpRequest, HttpResponse, Result as ActixResult};
 use chrono::NaiveDate;
 use csv::Writer;
 use serde::{Deserialize, Serialize};
@@ -25,7 +25,12 @@ use crate::middleware::auth::Claims;
 use crate::repositories::aws_account::AwsAccountRepository;
 use crate::repositories::aws_resource::AwsResourceRepository;
 use crate::repositories::cost_analytics::CostAnalyticsRepository;
-use crate::services::aws_cost_analytics::{AwsCostAnalyticsService, CostAnalysisRequest};
+use crate::services::aws_cost_analytics::{
+    AwsCostAnalyticsService, ChargebackGroupBy, CostAnalysisRequest, PaymentOption,
+    SavingsPlanItem, SavingsPlanRecommendations,
+};
+use crate::services::cost_categories::AwsCostCategoryRule;
+use crate::services::cost_optimization_advisor::CostOptimizationAdvisor;
 
 // CSV export helper functions
 fn export_new_resources_csv(resources: &[serde_json::Value]) -> Result<String, AppError> {
@@ -331,6 +336,91 @@ pub struct CostByTagQuery {
     pub end_date: String,   // YYYY-MM-DD format
 }
 
+#[derive(Debug, Deserialize)]
+pub struct TagCostQuery {
+    pub account_id: String,
+    pub start: String, // YYYY-MM-DD format
+    pub end: String,   // YYYY-MM-DD format
+    pub granularity: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UntaggedCostQuery {
+    pub account_id: String,
+    pub start: String, // YYYY-MM-DD format
+    pub end: String,   // YYYY-MM-DD format
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChargebackQuery {
+    pub account_id: String,
+    pub tag: Option<String>,
+    // Synced AWS cost category name, used instead of a tag
+    pub cost_category: Option<String>,
+    pub start: String, // YYYY-MM-DD format
+    pub end: String,   // YYYY-MM-DD format
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CostCategoryQuery {
+    pub account_id: String,
+    pub region: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateCostCategoryRequest {
+    pub account_id: String,
+    pub region: Option<String>,
+    pub name: String,
+    pub rules: Vec<AwsCostCategoryRule>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateCostCategoryRequest {
+    pub account_id: String,
+    pub region: Option<String>,
+    pub rules: Vec<AwsCostCategoryRule>,
+    // Left out, the stored default value is kept
+    pub default_value: Option<String>,
+    #[serde(default)]
+    pub clear_default_value: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RightsizingQuery {
+    pub account_id: Option<String>,
+    pub resource_type: Option<String>,
+    pub min_monthly_savings: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RightsizingRefreshQuery {
+    pub account_id: String,
+    pub region: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SavingsPlanQuery {
+    pub account_id: String,
+    pub region: Option<String>,
+    pub payment_option: Option<PaymentOption>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SavingsPlanRefreshQuery {
+    pub account_id: String,
+    pub region: Option<String>,
+    pub lookback_period_days: Option<u32>,
+    pub payment_option: Option<PaymentOption>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OptimizationSuggestionsQuery {
+    pub account_id: String,
+    pub lookback_days: Option<u32>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct TopResourcesQuery {
     pub account_id: String,
@@ -1161,6 +1251,375 @@ pub async fn get_cost_by_allocation_tag(
     }
 }
 
+/// Chargeback report grouped by a cost allocation tag, as JSON or CSV
+pub async fn get_chargeback_report(
+    cost_service: web::Data<Arc<AwsCostAnalyticsService>>,
+    query: web::Query<ChargebackQuery>,
+    _claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    let group_by = match (&query.tag, &query.cost_category) {
+        (Some(tag), None) => ChargebackGroupBy::Tag(tag.clone()),
+        (None, Some(name)) => ChargebackGroupBy::CostCategory(name.clone()),
+        _ => {
+            return Err(AppError::BadRequest(
+                "Specify exactly one of tag or cost_category".to_string(),
+            ))
+        }
+    };
+    tracing::info!(
+        "Generating chargeback report by {:?} for account {}",
+        group_by,
+        query.account_id
+    );
+
+    let start_date = NaiveDate::parse_from_str(&query.start, "%Y-%m-%d")
+        .map_err(|e| AppError::BadRequest(format!("Invalid start format: {}", e)))?;
+    let end_date = NaiveDate::parse_from_str(&query.end, "%Y-%m-%d")
+        .map_err(|e| AppError::BadRequest(format!("Invalid end format: {}", e)))?;
+
+    let report = cost_service
+        .generate_chargeback_report(&query.account_id, &group_by, start_date, end_date)
+        .await?;
+
+    if query.format.as_deref() == Some("csv") {
+        return Ok(HttpResponse::Ok()
+            .content_type("text/csv")
+            .append_header((
+                "Content-Disposition",
+                format!(
+                    "attachment; filename=\"chargeback_{}_{}_{}.csv\"",
+                    report.tag_key, query.start, query.end
+                ),
+            ))
+            .body(report.to_csv()));
+    }
+
+    Ok(HttpResponse::Ok().json(report))
+}
+
+/// Cost per value of a cost allocation tag, fetched from Cost Explorer and stored
+pub async fn get_cost_by_tag(
+    cost_service: web::Data<Arc<AwsCostAnalyticsService>>,
+    path: web::Path<String>,
+    query: web::Query<TagCostQuery>,
+    _claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    let tag_key = path.into_inner();
+    tracing::info!(
+        "Getting cost by tag {} for account {}",
+        tag_key,
+        query.account_id
+    );
+
+    let start_date = NaiveDate::parse_from_str(&query.start, "%Y-%m-%d")
+        .map_err(|e| AppError::BadRequest(format!("Invalid start format: {}", e)))?;
+    let end_date = NaiveDate::parse_from_str(&query.end, "%Y-%m-%d")
+        .map_err(|e| AppError::BadRequest(format!("Invalid end format: {}", e)))?;
+
+    let breakdown = cost_service
+        .get_cost_by_tag(
+            &query.account_id,
+            &tag_key,
+            start_date,
+            end_date,
+            query.granularity.as_deref().unwrap_or("MONTHLY"),
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(breakdown))
+}
+
+/// Cost of resources missing any of the configured cost allocation tags
+pub async fn get_untagged_cost(
+    cost_service: web::Data<Arc<AwsCostAnalyticsService>>,
+    query: web::Query<UntaggedCostQuery>,
+    _claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    let start_date = NaiveDate::parse_from_str(&query.start, "%Y-%m-%d")
+        .map_err(|e| AppError::BadRequest(format!("Invalid start format: {}", e)))?;
+    let end_date = NaiveDate::parse_from_str(&query.end, "%Y-%m-%d")
+        .map_err(|e| AppError::BadRequest(format!("Invalid end format: {}", e)))?;
+
+    let untagged_cost = cost_service
+        .get_untagged_cost(&query.account_id, start_date, end_date)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "account_id": query.account_id,
+        "start": query.start,
+        "end": query.end,
+        "untagged_cost": untagged_cost,
+    })))
+}
+
+/// List the AWS cost categories of an account, syncing them for chargeback reports
+pub async fn list_aws_cost_categories(
+    cost_service: web::Data<Arc<AwsCostAnalyticsService>>,
+    query: web::Query<CostCategoryQuery>,
+    _claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    let categories = cost_service
+        .list_aws_cost_categories(&query.account_id, query.region.as_deref())
+        .await?;
+    Ok(HttpResponse::Ok().json(categories))
+}
+
+pub async fn create_aws_cost_category(
+    req: HttpRequest,
+    cost_service: web::Data<Arc<AwsCostAnalyticsService>>,
+    body: web::Json<CreateCostCategoryRequest>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    claims.require_admin()?;
+    let body = body.into_inner();
+    tracing::info!(
+        "Creating cost category '{}' with {} rules for account {}",
+        body.name,
+        body.rules.len(),
+        body.account_id
+    );
+
+    let category = cost_service
+        .create_aws_cost_category(
+            &body.account_id,
+            body.region.as_deref(),
+            &body.name,
+            body.rules,
+            &claims.audit_context(&req, None),
+        )
+        .await?;
+    Ok(HttpResponse::Created().json(category))
+}
+
+// The category ARN contains '/', so clients percent-encode it in the path
+pub async fn update_aws_cost_category(
+    req: HttpRequest,
+    cost_service: web::Data<Arc<AwsCostAnalyticsService>>,
+    path: web::Path<String>,
+    body: web::Json<UpdateCostCategoryRequest>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    claims.require_admin()?;
+    let category_arn = path.into_inner();
+    let body = body.into_inner();
+    tracing::info!(
+        "Updating cost category {} for account {}",
+        category_arn,
+        body.account_id
+    );
+    let default_value = match (body.default_value, body.clear_default_value) {
+        (Some(_), true) => {
+            return Err(AppError::BadRequest(
+                "Set either default_value or clear_default_value, not both".to_string(),
+            ))
+        }
+        (Some(value), false) => Some(Some(value)),
+        (None, true) => Some(None),
+        (None, false) => None,
+    };
+
+    let category = cost_service
+        .update_aws_cost_category(
+            &body.account_id,
+            body.region.as_deref(),
+            &category_arn,
+            body.rules,
+            default_value,
+            &claims.audit_context(&req, None),
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(category))
+}
+
+pub async fn delete_aws_cost_category(
+    req: HttpRequest,
+    cost_service: web::Data<Arc<AwsCostAnalyticsService>>,
+    path: web::Path<String>,
+    query: web::Query<CostCategoryQuery>,
+    claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    claims.require_admin()?;
+    let category_arn = path.into_inner();
+    tracing::info!(
+        "Deleting cost category {} for account {}",
+        category_arn,
+        query.account_id
+    );
+
+    cost_service
+        .delete_aws_cost_category(
+            &query.account_id,
+            query.region.as_deref(),
+            &category_arn,
+            &claims.audit_context(&req, None),
+        )
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// List stored rightsizing recommendations, filtered by resource type and minimum savings
+pub async fn get_rightsizing_recommendations(
+    repository: web::Data<Arc<CostAnalyticsRepository>>,
+    query: web::Query<RightsizingQuery>,
+    _claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    let query = query.into_inner();
+    let recommendations = repository
+        .get_rightsizing_recommendations(
+            query.account_id,
+            query.resource_type,
+            query.min_monthly_savings,
+        )
+        .await?;
+
+    let total_monthly_savings: f64 = recommendations
+        .iter()
+        .map(|r| r.estimated_monthly_savings.to_string().parse::<f64>().unwrap_or(0.0))
+        .sum();
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "recommendations": recommendations,
+        "count": recommendations.len(),
+        "total_monthly_savings": total_monthly_savings,
+    })))
+}
+
+/// Pull fresh rightsizing recommendations from Cost Explorer and store them
+pub async fn refresh_rightsizing_recommendations(
+    cost_service: web::Data<Arc<AwsCostAnalyticsService>>,
+    query: web::Query<RightsizingRefreshQuery>,
+    _claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    tracing::info!(
+        "Refreshing rightsizing recommendations for account {}",
+        query.account_id
+    );
+
+    let recommendations = cost_service
+        .get_rightsizing_recommendations(&query.account_id, query.region.as_deref())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "recommendations": recommendations,
+        "count": recommendations.len(),
+    })))
+}
+
+// Savings plan commitments are hourly; AWS bills 730 hours in an average month
+const HOURS_PER_MONTH: f64 = 730.0;
+
+/// Latest stored savings plan recommendation compared against current Reserved Instance spend
+pub async fn get_savings_plan_recommendations(
+    cost_service: web::Data<Arc<AwsCostAnalyticsService>>,
+    repository: web::Data<Arc<CostAnalyticsRepository>>,
+    query: web::Query<SavingsPlanQuery>,
+    _claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    let query = query.into_inner();
+    let stored = repository
+        .get_latest_savings_plan_recommendation(
+            &query.account_id,
+            query.payment_option.as_ref().map(|p| p.as_str()),
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::NotFound(format!(
+                "No savings plan recommendation stored for account {}",
+                query.account_id
+            ))
+        })?;
+
+    let to_f64 = |d: &sea_orm::prelude::Decimal| d.to_string().parse::<f64>().unwrap_or(0.0);
+    let recommendation = SavingsPlanRecommendations {
+        recommended_commitment_per_hour: to_f64(&stored.hourly_commitment),
+        estimated_monthly_savings: to_f64(&stored.estimated_monthly_savings),
+        estimated_roi: to_f64(&stored.estimated_roi),
+        on_demand_cost_equivalent: to_f64(&stored.on_demand_cost_equivalent),
+        recommendations: serde_json::from_value::<Vec<SavingsPlanItem>>(
+            stored.recommendations.clone(),
+        )
+        .unwrap_or_default(),
+    };
+
+    // The comparison is best effort; accounts without Cost Explorer RI access still get the recommendation
+    let reserved_instances = match cost_service
+        .get_reserved_instance_commitments(&query.account_id, query.region.as_deref())
+        .await
+    {
+        Ok(commitments) => Some(commitments),
+        Err(e) => {
+            tracing::warn!(
+                "Failed to load reserved instance commitments for account {}: {}",
+                query.account_id,
+                e
+            );
+            None
+        }
+    };
+
+    let recommended_monthly_commitment =
+        recommendation.recommended_commitment_per_hour * HOURS_PER_MONTH;
+    let reserved_monthly_cost = reserved_instances
+        .as_ref()
+        .map(|ri| ri.monthly_amortized_cost)
+        .unwrap_or(0.0);
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "account_id": stored.account_id,
+        "payment_option": stored.payment_option,
+        "lookback_period_days": stored.lookback_period_days,
+        "generated_at": stored.created_at,
+        "recommendation": recommendation,
+        "reserved_instances": reserved_instances,
+        "comparison": {
+            "recommended_monthly_commitment": recommended_monthly_commitment,
+            "reserved_instance_monthly_cost": reserved_monthly_cost,
+            "total_monthly_commitment": recommended_monthly_commitment + reserved_monthly_cost,
+        },
+    })))
+}
+
+/// Pull a fresh savings plan recommendation from Cost Explorer and store it
+pub async fn refresh_savings_plan_recommendations(
+    cost_service: web::Data<Arc<AwsCostAnalyticsService>>,
+    query: web::Query<SavingsPlanRefreshQuery>,
+    _claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    tracing::info!(
+        "Refreshing savings plan recommendations for account {}",
+        query.account_id
+    );
+
+    let recommendations = cost_service
+        .get_savings_plan_recommendations(
+            &query.account_id,
+            query.region.as_deref(),
+            query.lookback_period_days.unwrap_or(30),
+            query.payment_option.unwrap_or(PaymentOption::NoUpfront),
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(recommendations))
+}
+
+/// Ask the LLM for cost optimization suggestions based on recent spend and stored recommendations
+pub async fn generate_optimization_suggestions(
+    advisor: web::Data<Arc<CostOptimizationAdvisor>>,
+    query: web::Query<OptimizationSuggestionsQuery>,
+    _claims: web::ReqData<Claims>,
+) -> Result<HttpResponse, AppError> {
+    tracing::info!(
+        "Generating cost optimization suggestions for account {}",
+        query.account_id
+    );
+
+    let report = advisor
+        .generate_suggestions(&query.account_id, query.lookback_days.unwrap_or(30))
+        .await?;
+
+    Ok(HttpResponse::Ok().json(report))
+}
+
 /// Get top cost resources with metadata
 pub async fn get_top_cost_resources_with_metadata(
     cost_service: web::Data<Arc<AwsCostAnalyticsService>>,
@@ -16,6 +16,7 @@ use crate::errors::AppError;
 use crate::middleware::auth::Claims;
 use crate::controllers::kubernetes::get_cluster_config_by_id;
 use crate::services::kubernetes::crds_service::CrdsService;
+use crate::services::kubernetes::pagination::PaginationQuery;
 use actix_web::{web, HttpResponse, Responder};
 use sea_orm::DatabaseConnection;
 use std::sync::Arc;
@@ -26,12 +27,19 @@ pub async fn list_crds_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<String>, // cluster_id
+    pagination: web::Query<PaginationQuery>,
     crds_service: web::Data<Arc<CrdsService>>,
 ) -> Result<impl Responder, AppError> {
     let cluster_id = path.into_inner();
     debug!(target: "mayyam::controllers::crds", user_id = %claims.username, %cluster_id, "Attempting to list CRDs");
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    let crds = crds_service.list_crds(&cluster_config).await?;
+    let crds = crds_service
+        .list_crds(
+            &cluster_config,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?;
     Ok(HttpResponse::Ok().json(crds))
 }
 
@@ -53,10 +61,34 @@ pub struct CustomResourceQuery {
     pub namespace: Option<String>,
 }
 
+pub async fn list_crd_resources_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String)>, // (cluster_id, crd_name)
+    pagination: web::Query<PaginationQuery>,
+    query: web::Query<CustomResourceQuery>,
+    crds_service: web::Data<Arc<CrdsService>>,
+) -> Result<impl Responder, AppError> {
+    let (cluster_id, crd_name) = path.into_inner();
+    debug!(target: "mayyam::controllers::crds", user_id = %claims.username, %cluster_id, %crd_name, "Attempting to list resources of CRD");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let resources = crds_service
+        .list_crd_resources(
+            &cluster_config,
+            &crd_name,
+            query.into_inner().namespace,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(resources))
+}
+
 pub async fn list_custom_resources_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String, String, String)>, // (cluster_id, group, version, plural)
+    pagination: web::Query<PaginationQuery>,
     query: web::Query<CustomResourceQuery>,
     crds_service: web::Data<Arc<CrdsService>>,
 ) -> Result<impl Responder, AppError> {
@@ -76,6 +108,16 @@ pub async fn list_custom_resources_controller(
     );
     
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    let resources = crds_service.list_custom_resources(&cluster_config, &group, &version, &plural, ns_ref).await?;
+    let resources = crds_service
+        .list_custom_resources(
+            &cluster_config,
+            &group,
+            &version,
+            &plural,
+            ns_ref,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?;
     Ok(HttpResponse::Ok().json(resources))
 }
@@ -17,6 +17,7 @@ use actix_web::{web, HttpResponse, Responder};
 use sea_orm::DatabaseConnection;
 use std::sync::Arc;
 
+use crate::config::reloader::SharedConfig;
 use crate::config::Config;
 use crate::errors::AppError;
 use crate::middleware::auth::Claims;
@@ -30,10 +31,11 @@ use crate::utils::database::connect_to_dynamic_database;
 pub async fn execute_query(
     query_req: web::Json<DatabaseQueryRequest>,
     db_pool: web::Data<Arc<DatabaseConnection>>,
-    config: web::Data<Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
-    let db_repo = DatabaseRepository::new(db_pool.get_ref().clone(), config.get_ref().clone());
+    let config = config.snapshot();
+    let db_repo = DatabaseRepository::new(db_pool.get_ref().clone(), config.clone());
 
     // Get the database connection details
     let conn_id = uuid::Uuid::parse_str(&query_req.connection_id)
@@ -46,7 +48,7 @@ pub async fn execute_query(
     })?;
 
     // Execute the query with analysis if requested
-    let analytics = MySqlAnalyticsService::new(config.get_ref().clone());
+    let analytics = MySqlAnalyticsService::new(config.clone());
     let result = if query_req.explain.unwrap_or(false) {
         analytics
             .execute_query_with_explain(&conn_model, &query_req.query, query_req.params.as_ref())
@@ -63,10 +65,11 @@ pub async fn execute_query(
 pub async fn analyze_database(
     path: web::Path<String>,
     db_pool: web::Data<Arc<DatabaseConnection>>,
-    config: web::Data<Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
-    let db_repo = DatabaseRepository::new(db_pool.get_ref().clone(), config.get_ref().clone());
+    let config = config.snapshot();
+    let db_repo = DatabaseRepository::new(db_pool.get_ref().clone(), config.clone());
 
     // Get the database connection details to check if it exists
     let conn_id = uuid::Uuid::parse_str(&path.into_inner())
@@ -82,13 +85,13 @@ pub async fn analyze_database(
     let connection_type = conn_model.connection_type.to_lowercase();
     let analysis = match connection_type.as_str() {
         "mysql" => {
-            let analytics = MySqlAnalyticsService::new(config.get_ref().clone());
-            let dynamic_conn = connect_to_dynamic_database(&conn_model, config.get_ref()).await?;
+            let analytics = MySqlAnalyticsService::new(config.clone());
+            let dynamic_conn = connect_to_dynamic_database(&conn_model, &config).await?;
             analytics.analyze_database(&dynamic_conn).await
         }
         "postgres" => {
-            let analytics = PostgresAnalyticsService::new(config.get_ref().clone());
-            let dynamic_conn = connect_to_dynamic_database(&conn_model, config.get_ref()).await?;
+            let analytics = PostgresAnalyticsService::new(config.clone());
+            let dynamic_conn = connect_to_dynamic_database(&conn_model, &config).await?;
             analytics.analyze_database(&dynamic_conn).await
         }
         other => Err(AppError::BadRequest(format!(
@@ -100,12 +103,51 @@ pub async fn analyze_database(
     Ok(HttpResponse::Ok().json(analysis))
 }
 
+fn find_postgres_config<'a>(
+    config: &'a Config,
+    name: &str,
+) -> Result<&'a crate::config::PostgresConfig, AppError> {
+    config
+        .database
+        .postgres
+        .iter()
+        .find(|pg| pg.name == name)
+        .ok_or_else(|| AppError::NotFound(format!("PostgreSQL config not found: {}", name)))
+}
+
+pub async fn get_table_bloat(
+    path: web::Path<String>,
+    config: web::Data<SharedConfig>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
+    let pg_config = find_postgres_config(&config, &path.into_inner())?;
+    let analytics = PostgresAnalyticsService::new(config.clone());
+    let bloat = analytics.get_table_bloat(pg_config).await?;
+
+    Ok(HttpResponse::Ok().json(bloat))
+}
+
+pub async fn get_index_bloat(
+    path: web::Path<String>,
+    config: web::Data<SharedConfig>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
+    let pg_config = find_postgres_config(&config, &path.into_inner())?;
+    let analytics = PostgresAnalyticsService::new(config.clone());
+    let bloat = analytics.analyze_index_bloat(pg_config).await?;
+
+    Ok(HttpResponse::Ok().json(bloat))
+}
+
 pub async fn list_connections(
     db_pool: web::Data<Arc<DatabaseConnection>>,
-    config: web::Data<Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
-    let db_repo = DatabaseRepository::new(db_pool.get_ref().clone(), config.get_ref().clone());
+    let config = config.snapshot();
+    let db_repo = DatabaseRepository::new(db_pool.get_ref().clone(), config.clone());
     let connections = db_repo.find_all().await?;
 
     Ok(HttpResponse::Ok().json(connections))
@@ -114,10 +156,11 @@ pub async fn list_connections(
 pub async fn get_connection(
     path: web::Path<String>,
     db_pool: web::Data<Arc<DatabaseConnection>>,
-    config: web::Data<Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
-    let db_repo = DatabaseRepository::new(db_pool.get_ref().clone(), config.get_ref().clone());
+    let config = config.snapshot();
+    let db_repo = DatabaseRepository::new(db_pool.get_ref().clone(), config.clone());
 
     let conn_id = uuid::Uuid::parse_str(&path.into_inner())
         .map_err(|e| AppError::BadRequest(format!("Invalid UUID: {}", e)))?;
@@ -132,10 +175,11 @@ pub async fn get_connection(
 pub async fn create_connection(
     connection: web::Json<CreateDatabaseConnectionRequest>,
     db_pool: web::Data<Arc<DatabaseConnection>>,
-    config: web::Data<Config>,
+    config: web::Data<SharedConfig>,
     claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
-    let db_repo = DatabaseRepository::new(db_pool.get_ref().clone(), config.get_ref().clone());
+    let config = config.snapshot();
+    let db_repo = DatabaseRepository::new(db_pool.get_ref().clone(), config.clone());
 
     // Create the database connection
     let user_id = uuid::Uuid::parse_str(&claims.sub)
@@ -149,10 +193,11 @@ pub async fn update_connection(
     path: web::Path<String>,
     connection: web::Json<CreateDatabaseConnectionRequest>,
     db_pool: web::Data<Arc<DatabaseConnection>>,
-    config: web::Data<Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
-    let db_repo = DatabaseRepository::new(db_pool.get_ref().clone(), config.get_ref().clone());
+    let config = config.snapshot();
+    let db_repo = DatabaseRepository::new(db_pool.get_ref().clone(), config.clone());
 
     // Update the database connection
     let conn_id = uuid::Uuid::parse_str(&path.into_inner())
@@ -165,10 +210,11 @@ pub async fn update_connection(
 pub async fn delete_connection(
     path: web::Path<String>,
     db_pool: web::Data<Arc<DatabaseConnection>>,
-    config: web::Data<Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
-    let db_repo = DatabaseRepository::new(db_pool.get_ref().clone(), config.get_ref().clone());
+    let config = config.snapshot();
+    let db_repo = DatabaseRepository::new(db_pool.get_ref().clone(), config.clone());
 
     // Delete the database connection
     let conn_id = uuid::Uuid::parse_str(&path.into_inner())
@@ -181,11 +227,12 @@ pub async fn delete_connection(
 pub async fn test_connection(
     path: web::Path<String>,
     db_pool: web::Data<Arc<DatabaseConnection>>,
-    config: web::Data<Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
-    let db_service = DatabaseService::new(config.get_ref().clone());
-    let db_repo = DatabaseRepository::new(db_pool.get_ref().clone(), config.get_ref().clone());
+    let config = config.snapshot();
+    let db_service = DatabaseService::new(config.clone());
+    let db_repo = DatabaseRepository::new(db_pool.get_ref().clone(), config.clone());
 
     // Get the database connection details
     let conn_id = uuid::Uuid::parse_str(&path.into_inner())
@@ -204,11 +251,12 @@ pub async fn test_connection(
 pub async fn get_schema(
     path: web::Path<String>,
     db_pool: web::Data<Arc<DatabaseConnection>>,
-    config: web::Data<Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
-    let db_service = DatabaseService::new(config.get_ref().clone());
-    let db_repo = DatabaseRepository::new(db_pool.get_ref().clone(), config.get_ref().clone());
+    let config = config.snapshot();
+    let db_service = DatabaseService::new(config.clone());
+    let db_repo = DatabaseRepository::new(db_pool.get_ref().clone(), config.clone());
 
     // Get the database connection details
     let conn_id = uuid::Uuid::parse_str(&path.into_inner())
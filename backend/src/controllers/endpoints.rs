@@ -13,8 +13,9 @@
 // limitations under the License.
 
 
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use sea_orm::{DatabaseConnection, EntityTrait};
+use serde::Deserialize;
 use std::sync::Arc;
 use tracing::debug;
 use uuid::Uuid;
@@ -23,6 +24,7 @@ use crate::errors::AppError;
 use crate::middleware::auth::Claims;
 use crate::models::cluster::KubernetesClusterConfig;
 use crate::services::kubernetes::endpoints_service::EndpointsService;
+use crate::services::kubernetes::pagination::PaginationQuery;
 use k8s_openapi::api::core::v1::Endpoints;
 
 async fn get_cluster_config_by_id(
@@ -51,6 +53,7 @@ async fn get_cluster_config_by_id(
             certificate_authority_data: None,
             client_certificate_data: None,
             client_key_data: None,
+            use_in_cluster_config: false,
         })
     } else {
         serde_json::from_value(value)
@@ -62,12 +65,20 @@ pub async fn list_endpoints_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>,
+    pagination: web::Query<PaginationQuery>,
     svc: web::Data<Arc<EndpointsService>>,
 ) -> Result<impl Responder, AppError> {
     let (cluster_id, ns) = path.into_inner();
     debug!(target: "mayyam::controllers::endpoints", user_id = %claims.username, %cluster_id, %ns, "List Endpoints");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    let list = svc.list_endpoints(&cfg, &ns).await?;
+    let list = svc
+        .list_endpoints(
+            &cfg,
+            &ns,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?;
     Ok(HttpResponse::Ok().json(list))
 }
 
@@ -75,12 +86,20 @@ pub async fn list_endpoint_slices_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>,
+    pagination: web::Query<PaginationQuery>,
     svc: web::Data<Arc<EndpointsService>>,
 ) -> Result<impl Responder, AppError> {
     let (cluster_id, ns) = path.into_inner();
     debug!(target: "mayyam::controllers::endpoints", user_id = %claims.username, %cluster_id, %ns, "List EndpointSlices");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    let list = svc.list_endpoint_slices(&cfg, &ns).await?;
+    let list = svc
+        .list_endpoint_slices(
+            &cfg,
+            &ns,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?;
     Ok(HttpResponse::Ok().json(list))
 }
 
@@ -117,6 +136,7 @@ pub async fn upsert_endpoints_controller(
 
 pub async fn delete_endpoints_controller(
     claims: web::ReqData<Claims>,
+    req: HttpRequest,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String, String)>,
     svc: web::Data<Arc<EndpointsService>>,
@@ -124,6 +144,28 @@ pub async fn delete_endpoints_controller(
     let (cluster_id, ns, name) = path.into_inner();
     debug!(target: "mayyam::controllers::endpoints", user_id = %claims.username, %cluster_id, %ns, %name, "Delete Endpoints");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    svc.delete_endpoints(&cfg, &ns, &name).await?;
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
+    svc.delete_endpoints(&cfg, &ns, &name, &audit_ctx).await?;
     Ok(HttpResponse::Ok().json(serde_json::json!({"deleted": true})))
 }
+
+#[derive(Debug, Deserialize)]
+pub struct EndpointHealthQuery {
+    pub timeout_secs: Option<u64>,
+}
+
+pub async fn health_check_endpoints_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String, String)>,
+    query: web::Query<EndpointHealthQuery>,
+    svc: web::Data<Arc<EndpointsService>>,
+) -> Result<impl Responder, AppError> {
+    let (cluster_id, ns, name) = path.into_inner();
+    debug!(target: "mayyam::controllers::endpoints", user_id = %claims.username, %cluster_id, %ns, %name, "Health check Endpoints");
+    let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let report = svc
+        .health_check_endpoints(&cfg, &ns, &name, query.timeout_secs.unwrap_or(3))
+        .await?;
+    Ok(HttpResponse::Ok().json(report))
+}
@@ -0,0 +1,106 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use sea_orm::{DatabaseConnection, EntityTrait};
+use serde::Deserialize;
+use std::sync::Arc;
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::middleware::auth::Claims;
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::garbage_collection_service::{
+    GarbageCollectionService, OrphanedResourceReport,
+};
+
+async fn get_cluster_config_by_id(
+    db: &DatabaseConnection,
+    cluster_id_str: &str,
+) -> Result<KubernetesClusterConfig, AppError> {
+    let cluster_id = Uuid::parse_str(cluster_id_str)
+        .map_err(|_| AppError::BadRequest("Invalid cluster ID format".to_string()))?;
+    let cluster_model = crate::models::cluster::Entity::find_by_id(cluster_id)
+        .one(db)
+        .await
+        .map_err(AppError::Database)?
+        .ok_or_else(|| AppError::NotFound(format!("Cluster with ID {} not found", cluster_id)))?;
+    if cluster_model.cluster_type != "kubernetes" {
+        return Err(AppError::BadRequest(
+            "Cluster is not a Kubernetes cluster".into(),
+        ));
+    }
+    let value = cluster_model.config;
+    if value.is_null() {
+        Ok(KubernetesClusterConfig {
+            api_server_url: None,
+            token: None,
+            kube_config_path: None,
+            kube_context: None,
+            certificate_authority_data: None,
+            client_certificate_data: None,
+            client_key_data: None,
+            use_in_cluster_config: false,
+        })
+    } else {
+        serde_json::from_value(value)
+            .map_err(|e| AppError::Internal(format!("Failed to parse cluster config: {}", e)))
+    }
+}
+
+pub async fn find_orphaned_resources_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String)>,
+    svc: web::Data<Arc<GarbageCollectionService>>,
+) -> Result<impl Responder, AppError> {
+    let (cluster_id, ns) = path.into_inner();
+    debug!(target: "mayyam::controllers::garbage_collection", user_id = %claims.username, %cluster_id, %ns, "Find orphaned resources");
+    let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let report = svc.find_orphaned_resources(&cfg, &ns).await?;
+    Ok(HttpResponse::Ok().json(report))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CleanupQuery {
+    pub dry_run: Option<bool>,
+}
+
+// Takes the report returned by the GET endpoint; defaults to a dry run.
+pub async fn cleanup_orphaned_resources_controller(
+    claims: web::ReqData<Claims>,
+    req: HttpRequest,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String)>,
+    query: web::Query<CleanupQuery>,
+    body: web::Json<OrphanedResourceReport>,
+    svc: web::Data<Arc<GarbageCollectionService>>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (cluster_id, ns) = path.into_inner();
+    let report = body.into_inner();
+    if report.namespace != ns {
+        return Err(AppError::BadRequest(format!(
+            "Report namespace '{}' does not match path namespace '{}'",
+            report.namespace, ns
+        )));
+    }
+    let dry_run = query.dry_run.unwrap_or(true);
+    debug!(target: "mayyam::controllers::garbage_collection", user_id = %claims.username, %cluster_id, %ns, dry_run, "Clean up orphaned resources");
+    let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
+    let result = svc.cleanup(&cfg, &report, dry_run, &audit_ctx).await?;
+    Ok(HttpResponse::Ok().json(result))
+}
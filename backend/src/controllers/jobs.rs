@@ -13,7 +13,7 @@
 // limitations under the License.
 
 
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use sea_orm::{DatabaseConnection, EntityTrait};
 use std::sync::Arc;
 use tracing::debug;
@@ -23,7 +23,9 @@ use crate::errors::AppError;
 use crate::middleware::auth::Claims;
 use crate::models::cluster::KubernetesClusterConfig;
 use crate::services::kubernetes::jobs_service::JobsService;
+use crate::services::kubernetes::pagination::PaginationQuery;
 use k8s_openapi::api::batch::v1::Job;
+use serde::Deserialize;
 
 async fn get_cluster_config_by_id(
     db: &DatabaseConnection,
@@ -51,6 +53,7 @@ async fn get_cluster_config_by_id(
             certificate_authority_data: None,
             client_certificate_data: None,
             client_key_data: None,
+            use_in_cluster_config: false,
         })
     } else {
         serde_json::from_value(value)
@@ -62,12 +65,20 @@ pub async fn list_jobs_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>, // (cluster_id, namespace)
+    pagination: web::Query<PaginationQuery>,
     svc: web::Data<Arc<JobsService>>,
 ) -> Result<impl Responder, AppError> {
     let (cluster_id, ns) = path.into_inner();
     debug!(target: "mayyam::controllers::jobs", user_id = %claims.username, %cluster_id, %ns, "List Jobs");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    let list = svc.list(&cfg, &ns).await?;
+    let list = svc
+        .list(
+            &cfg,
+            &ns,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?;
     Ok(HttpResponse::Ok().json(list))
 }
 
@@ -105,6 +116,7 @@ pub async fn upsert_job_controller(
 
 pub async fn delete_job_controller(
     claims: web::ReqData<Claims>,
+    req: HttpRequest,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String, String)>, // (cluster_id, namespace, name)
     svc: web::Data<Arc<JobsService>>,
@@ -112,6 +124,36 @@ pub async fn delete_job_controller(
     let (cluster_id, ns, name) = path.into_inner();
     debug!(target: "mayyam::controllers::jobs", user_id = %claims.username, %cluster_id, %ns, %name, "Delete Job");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    svc.delete(&cfg, &ns, &name).await?;
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
+    svc.delete(&cfg, &ns, &name, &audit_ctx).await?;
     Ok(HttpResponse::Ok().json(serde_json::json!({"deleted": true})))
 }
+
+const DEFAULT_JOB_TIMEOUT_SECS: u64 = 600;
+
+#[derive(Debug, Deserialize)]
+pub struct RunJobRequest {
+    pub job: Job,
+    pub timeout_secs: Option<u64>,
+    #[serde(default)]
+    pub delete_on_completion: bool,
+}
+
+pub async fn run_job_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String)>, // (cluster_id, namespace)
+    body: web::Json<RunJobRequest>,
+    svc: web::Data<Arc<JobsService>>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (cluster_id, ns) = path.into_inner();
+    let req = body.into_inner();
+    let timeout_secs = req.timeout_secs.unwrap_or(DEFAULT_JOB_TIMEOUT_SECS);
+    debug!(target: "mayyam::controllers::jobs", user_id = %claims.username, %cluster_id, %ns, timeout_secs, "Run Job and wait");
+    let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let result = svc
+        .run_job_and_wait(&cfg, &ns, req.job, timeout_secs, req.delete_on_completion)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
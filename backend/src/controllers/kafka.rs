@@ -13,19 +13,26 @@
 // limitations under the License.
 
 
+use crate::config::reloader::SharedConfig;
 use crate::errors::AppError;
 use crate::middleware::auth::Claims;
 use crate::models::cluster;
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 use crate::services::kafka::{
     ClusterUpdateRequest, ConsumeOptions, KafkaMessage, KafkaService, KafkaTopic,
-    MessageBackupRequest, MessageMigrationRequest,
-    MessageRestoreRequest, OffsetReset, PartitionAdditionRequest,
+    MessageBackupRequest, MessageMigrationRequest, MessageReplayRequest,
+    MessageRestoreRequest, MessageSearchOptions, OffsetReset, OffsetStrategy, PartitionAdditionRequest,
     PartitionOffset, QueueDrainRequest, TopicConfigUpdateRequest,
+    TopicTransformRequest,
 };
+use crate::services::kafka_schema_registry::{
+    CompatibilityLevel, SchemaRegistryClient, SchemaRegistryService,
+};
+use crate::services::kafka_topic_monitor::KafkaTopicMonitor;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct KafkaClusterRequest {
@@ -35,6 +42,36 @@ pub struct KafkaClusterRequest {
     pub sasl_password: Option<String>,
     pub sasl_mechanism: Option<String>,
     pub security_protocol: Option<String>,
+    pub schema_registry_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InitializeOffsetsRequest {
+    pub offset_strategy: OffsetStrategy,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CloneConsumerGroupRequest {
+    pub target_group_id: String,
+    pub topics: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PurgeMessagesRequest {
+    pub max_age_ms: u64,
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SchemaCompatibilityRequest {
+    pub schema: String,
+    pub compatibility_level: CompatibilityLevel,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CompatibilityLevelRequest {
+    pub compatibility_level: CompatibilityLevel,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -62,6 +99,7 @@ pub struct ConsumeRequest {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct OffsetRequest {
+    pub topic: String,
     pub partitions: Vec<PartitionOffset>,
     pub to_earliest: Option<bool>,
     pub to_latest: Option<bool>,
@@ -80,9 +118,10 @@ pub struct BrokerStatus {
 pub async fn health_check(
     path: web::Path<String>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let cluster_id = path.into_inner();
 
     // Use the KafkaService to perform health check
@@ -91,6 +130,22 @@ pub async fn health_check(
     Ok(HttpResponse::Ok().json(health_status))
 }
 
+pub async fn check_broker_certificates(
+    path: web::Path<String>,
+    kafka_service: web::Data<Arc<KafkaService>>,
+    config: web::Data<SharedConfig>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
+    let cluster_id = path.into_inner();
+
+    let certificates = kafka_service
+        .check_broker_cert_expiry(&cluster_id, &config)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(certificates))
+}
+
 pub async fn get_metrics(
     kafka_service: web::Data<Arc<KafkaService>>,
     _claims: web::ReqData<Claims>,
@@ -110,9 +165,10 @@ pub async fn produce_batch(
     path: web::Path<String>,
     batch_req: web::Json<BatchProduceRequest>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let cluster_id = path.into_inner();
 
     // Convert request messages to service models
@@ -145,9 +201,10 @@ pub async fn produce_with_retry(
     path: web::Path<String>,
     retry_req: web::Json<RetryProduceRequest>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let cluster_id = path.into_inner();
 
     // Convert request to service model
@@ -175,7 +232,7 @@ pub async fn produce_with_retry(
 
 pub async fn list_clusters(
     kafka_service: web::Data<Arc<KafkaService>>,
-    _config: web::Data<crate::config::Config>,
+    _config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
     // Get clusters from the service
@@ -192,7 +249,7 @@ pub async fn list_clusters(
 pub async fn create_cluster(
     cluster: web::Json<KafkaClusterRequest>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    _config: web::Data<crate::config::Config>,
+    _config: web::Data<SharedConfig>,
     claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
     // Convert the request to the service model
@@ -206,6 +263,7 @@ pub async fn create_cluster(
             .security_protocol
             .clone()
             .unwrap_or_else(|| "PLAINTEXT".to_string()),
+        schema_registry_url: cluster.schema_registry_url.clone(),
     };
 
     // Create the cluster using the service
@@ -218,9 +276,10 @@ pub async fn create_cluster(
 
 pub async fn get_cluster(
     path: web::Path<String>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let cluster_id = path.into_inner();
 
     // In a real implementation, we'd look up by ID, but for now find by name
@@ -245,9 +304,10 @@ pub async fn get_cluster(
 pub async fn list_topics(
     path: web::Path<String>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let cluster_id = path.into_inner();
 
     // Use the KafkaService to list topics
@@ -260,9 +320,10 @@ pub async fn create_topic(
     path: web::Path<String>,
     topic_req: web::Json<TopicRequest>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let cluster_id = path.into_inner();
 
     // Convert request to service model
@@ -284,9 +345,10 @@ pub async fn create_topic(
 pub async fn get_topic(
     path: web::Path<(String, String)>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let (cluster_id, topic_name) = path.into_inner();
 
     // Use the KafkaService to get topic details
@@ -298,16 +360,19 @@ pub async fn get_topic(
 }
 
 pub async fn delete_topic(
+    req: HttpRequest,
     path: web::Path<(String, String)>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
-    _claims: web::ReqData<Claims>,
+    config: web::Data<SharedConfig>,
+    claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let (cluster_id, topic_name) = path.into_inner();
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
 
     // Use the KafkaService to delete the topic
     let response = kafka_service
-        .delete_topic(&cluster_id, &topic_name, &config)
+        .delete_topic(&cluster_id, &topic_name, &config, &audit_ctx)
         .await?;
 
     Ok(HttpResponse::Ok().json(response))
@@ -317,9 +382,10 @@ pub async fn produce_message(
     path: web::Path<(String, String)>,
     message: web::Json<MessageRequest>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let (cluster_id, topic_name) = path.into_inner();
 
     // Convert request to service model
@@ -341,9 +407,10 @@ pub async fn consume_messages(
     path: web::Path<(String, String)>,
     consume_req: web::Json<ConsumeRequest>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let (cluster_id, topic_name) = path.into_inner();
 
     // Convert request to service model
@@ -362,12 +429,28 @@ pub async fn consume_messages(
     Ok(HttpResponse::Ok().json(messages))
 }
 
+pub async fn search_messages(
+    path: web::Path<(String, String)>,
+    search_req: web::Json<MessageSearchOptions>,
+    kafka_service: web::Data<Arc<KafkaService>>,
+    config: web::Data<SharedConfig>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
+    let (cluster_id, topic_name) = path.into_inner();
+    let messages = kafka_service
+        .search_messages(&cluster_id, &topic_name, &search_req, &config)
+        .await?;
+    Ok(HttpResponse::Ok().json(messages))
+}
+
 pub async fn list_consumer_groups(
     path: web::Path<String>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let cluster_id = path.into_inner();
 
     // Use the KafkaService to list consumer groups
@@ -381,9 +464,10 @@ pub async fn list_consumer_groups(
 pub async fn get_consumer_group(
     path: web::Path<(String, String)>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let (cluster_id, group_id) = path.into_inner();
 
     // Use the KafkaService to get consumer group details
@@ -394,17 +478,37 @@ pub async fn get_consumer_group(
     Ok(HttpResponse::Ok().json(group_details))
 }
 
+pub async fn get_consumer_group_lag(
+    path: web::Path<(String, String, String)>,
+    kafka_service: web::Data<Arc<KafkaService>>,
+    config: web::Data<SharedConfig>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
+    let (cluster_id, group_id, topic_name) = path.into_inner();
+
+    let buckets = kafka_service
+        .get_consumer_group_lag_histogram(&cluster_id, &group_id, &topic_name, &config)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(buckets))
+}
+
 pub async fn reset_offsets(
+    req: HttpRequest,
     path: web::Path<(String, String)>,
     offset_req: web::Json<OffsetRequest>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
-    _claims: web::ReqData<Claims>,
+    config: web::Data<SharedConfig>,
+    claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let (cluster_id, group_id) = path.into_inner();
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
 
     // Convert request to service model
     let offset_reset = OffsetReset {
+        topic: offset_req.topic.clone(),
         partitions: offset_req.partitions.clone(),
         to_earliest: offset_req.to_earliest,
         to_latest: offset_req.to_latest,
@@ -413,20 +517,72 @@ pub async fn reset_offsets(
 
     // Use the KafkaService to reset offsets
     let response = kafka_service
-        .reset_offsets(&cluster_id, &group_id, &offset_reset, &config)
+        .reset_offsets(&cluster_id, &group_id, &offset_reset, &config, &audit_ctx)
         .await?;
 
     Ok(HttpResponse::Ok().json(response))
 }
 
+// Commit starting offsets on partitions the group has not consumed yet
+pub async fn initialize_new_partition_offsets(
+    path: web::Path<(String, String, String)>,
+    init_req: web::Json<InitializeOffsetsRequest>,
+    kafka_service: web::Data<Arc<KafkaService>>,
+    config: web::Data<SharedConfig>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let config = config.snapshot();
+    let (cluster_id, group_id, topic_name) = path.into_inner();
+
+    let result = kafka_service
+        .initialize_offsets_for_new_partitions(
+            &cluster_id,
+            &group_id,
+            &topic_name,
+            init_req.offset_strategy,
+            &config,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(result))
+}
+
+// Copy committed offsets of a consumer group to a new group
+pub async fn clone_consumer_group(
+    path: web::Path<(String, String)>,
+    clone_req: web::Json<CloneConsumerGroupRequest>,
+    kafka_service: web::Data<Arc<KafkaService>>,
+    config: web::Data<SharedConfig>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let config = config.snapshot();
+    let (cluster_id, group_id) = path.into_inner();
+    let clone_req = clone_req.into_inner();
+
+    let result = kafka_service
+        .clone_consumer_group(
+            &cluster_id,
+            &group_id,
+            &clone_req.target_group_id,
+            clone_req.topics,
+            &config,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(result))
+}
+
 // Update topic configuration
 pub async fn update_topic_config(
     path: web::Path<(String, String)>,
     config_req: web::Json<TopicConfigUpdateRequest>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let (cluster_id, topic_name) = path.into_inner();
     let validate_only = config_req.validate_only.unwrap_or(false);
 
@@ -449,9 +605,10 @@ pub async fn update_cluster(
     path: web::Path<String>,
     update_req: web::Json<ClusterUpdateRequest>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let cluster_id = path.into_inner();
 
     // Use the KafkaService to update cluster configuration
@@ -467,9 +624,10 @@ pub async fn add_topic_partitions(
     path: web::Path<(String, String)>,
     partition_req: web::Json<PartitionAdditionRequest>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let (cluster_id, topic_name) = path.into_inner();
     let validate_only = partition_req.validate_only.unwrap_or(false);
 
@@ -491,9 +649,10 @@ pub async fn add_topic_partitions(
 pub async fn get_broker_status(
     path: web::Path<String>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let cluster_id = path.into_inner();
 
     // Use the KafkaService to get broker status
@@ -510,6 +669,94 @@ pub async fn get_broker_status(
     Ok(HttpResponse::Ok().json(response))
 }
 
+pub async fn get_capacity_report(
+    path: web::Path<String>,
+    kafka_service: web::Data<Arc<KafkaService>>,
+    config: web::Data<SharedConfig>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
+    let cluster_id = path.into_inner();
+    let report = kafka_service
+        .generate_capacity_report(&cluster_id, &config)
+        .await?;
+    Ok(HttpResponse::Ok().json(report))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ThroughputHistoryQuery {
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub partition: Option<i32>,
+}
+
+// Defaults to the last hour when no time range is given
+pub async fn get_topic_throughput(
+    path: web::Path<(String, String)>,
+    query: web::Query<ThroughputHistoryQuery>,
+    monitor: web::Data<Arc<KafkaTopicMonitor>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let (cluster_id, topic_name) = path.into_inner();
+    let end_time = query.end_time.unwrap_or_else(Utc::now);
+    let start_time = query
+        .start_time
+        .unwrap_or_else(|| end_time - chrono::Duration::hours(1));
+    let samples = monitor
+        .get_topic_throughput_history(
+            &cluster_id,
+            &topic_name,
+            start_time,
+            end_time,
+            query.partition,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(samples))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReplicationLagQuery {
+    pub target_cluster_id: String,
+}
+
+// Path cluster is the replication source
+pub async fn get_replication_lag(
+    path: web::Path<(String, String)>,
+    query: web::Query<ReplicationLagQuery>,
+    kafka_service: web::Data<Arc<KafkaService>>,
+    config: web::Data<SharedConfig>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
+    let (cluster_id, topic_name) = path.into_inner();
+    let report = kafka_service
+        .get_replication_lag(&cluster_id, &query.target_cluster_id, &topic_name, &config)
+        .await?;
+    Ok(HttpResponse::Ok().json(report))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReassignmentPlanRequest {
+    pub topics: Option<Vec<String>>,
+    pub target_broker_ids: Vec<i32>,
+}
+
+pub async fn generate_reassignment_plan(
+    path: web::Path<String>,
+    req: web::Json<ReassignmentPlanRequest>,
+    kafka_service: web::Data<Arc<KafkaService>>,
+    config: web::Data<SharedConfig>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
+    let cluster_id = path.into_inner();
+    let req = req.into_inner();
+    let plan = kafka_service
+        .generate_reassignment_plan(&cluster_id, req.topics, req.target_broker_ids, &config)
+        .await?;
+    Ok(HttpResponse::Ok().json(plan))
+}
+
 // ===== BACKUP AND RESTORE CONTROLLERS =====
 
 // Backup messages from a topic
@@ -517,9 +764,10 @@ pub async fn backup_topic_messages(
     path: web::Path<String>,
     backup_req: web::Json<MessageBackupRequest>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let cluster_id = path.into_inner();
 
     let response = kafka_service
@@ -534,9 +782,10 @@ pub async fn restore_topic_messages(
     path: web::Path<String>,
     restore_req: web::Json<MessageRestoreRequest>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let cluster_id = path.into_inner();
 
     let response = kafka_service
@@ -550,9 +799,10 @@ pub async fn restore_topic_messages(
 pub async fn migrate_topic_messages(
     migration_req: web::Json<MessageMigrationRequest>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let response = kafka_service
         .migrate_topic_messages(&*migration_req, &config)
         .await?;
@@ -560,14 +810,78 @@ pub async fn migrate_topic_messages(
     Ok(HttpResponse::Ok().json(response))
 }
 
+// Copy a topic's messages through a transformation into another topic
+pub async fn transform_topic(
+    path: web::Path<(String, String)>,
+    transform_req: web::Json<TopicTransformRequest>,
+    kafka_service: web::Data<Arc<KafkaService>>,
+    config: web::Data<SharedConfig>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let config = config.snapshot();
+    let (cluster_id, topic_name) = path.into_inner();
+
+    let response = kafka_service
+        .transform_topic(&cluster_id, &topic_name, &transform_req, &config)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+// Replay a range of offsets from one partition
+pub async fn replay_messages(
+    path: web::Path<(String, String)>,
+    replay_req: web::Json<MessageReplayRequest>,
+    kafka_service: web::Data<Arc<KafkaService>>,
+    config: web::Data<SharedConfig>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let config = config.snapshot();
+    let (cluster_id, topic_name) = path.into_inner();
+
+    let response = kafka_service
+        .replay_messages(&cluster_id, &topic_name, &replay_req, &config)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+// Drop messages older than the given age
+pub async fn purge_messages(
+    path: web::Path<(String, String)>,
+    purge_req: web::Json<PurgeMessagesRequest>,
+    kafka_service: web::Data<Arc<KafkaService>>,
+    config: web::Data<SharedConfig>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let config = config.snapshot();
+    let (cluster_id, topic_name) = path.into_inner();
+
+    let result = kafka_service
+        .purge_messages_older_than(
+            &cluster_id,
+            &topic_name,
+            std::time::Duration::from_millis(purge_req.max_age_ms),
+            purge_req.dry_run,
+            &config,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(result))
+}
+
 // Wait for consumer group to drain all messages
 pub async fn wait_for_queue_drain(
     path: web::Path<String>,
     drain_req: web::Json<QueueDrainRequest>,
     kafka_service: web::Data<Arc<KafkaService>>,
-    config: web::Data<crate::config::Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let cluster_id = path.into_inner();
 
     let response = kafka_service
@@ -576,3 +890,174 @@ pub async fn wait_for_queue_drain(
 
     Ok(HttpResponse::Ok().json(response))
 }
+
+async fn schema_registry_for_cluster(
+    kafka_service: &KafkaService,
+    cluster_id: &str,
+    config: &crate::config::Config,
+) -> Result<SchemaRegistryClient, AppError> {
+    let cluster = kafka_service.get_cluster(cluster_id, config).await?;
+    let url = cluster.schema_registry_url.ok_or_else(|| {
+        AppError::BadRequest(format!(
+            "Kafka cluster {} has no schema registry configured",
+            cluster_id
+        ))
+    })?;
+    Ok(SchemaRegistryClient::new(&url))
+}
+
+// Check a candidate schema against the latest version registered for the topic
+pub async fn check_schema_compatibility(
+    path: web::Path<(String, String)>,
+    check_req: web::Json<SchemaCompatibilityRequest>,
+    kafka_service: web::Data<Arc<KafkaService>>,
+    config: web::Data<SharedConfig>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
+    let (cluster_id, topic_name) = path.into_inner();
+
+    let registry = schema_registry_for_cluster(&kafka_service, &cluster_id, &config).await?;
+    let result = registry
+        .check_compatibility(
+            &topic_name,
+            &check_req.schema,
+            check_req.compatibility_level,
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(result))
+}
+
+pub async fn get_schema_compatibility_level(
+    path: web::Path<(String, String)>,
+    kafka_service: web::Data<Arc<KafkaService>>,
+    config: web::Data<SharedConfig>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
+    let (cluster_id, topic_name) = path.into_inner();
+
+    let registry = schema_registry_for_cluster(&kafka_service, &cluster_id, &config).await?;
+    let level = registry.get_compatibility_level(&topic_name).await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "subject": SchemaRegistryClient::value_subject(&topic_name),
+        "compatibility_level": level
+    })))
+}
+
+pub async fn set_schema_compatibility_level(
+    path: web::Path<(String, String)>,
+    level_req: web::Json<CompatibilityLevelRequest>,
+    kafka_service: web::Data<Arc<KafkaService>>,
+    config: web::Data<SharedConfig>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let config = config.snapshot();
+    let (cluster_id, topic_name) = path.into_inner();
+
+    let registry = schema_registry_for_cluster(&kafka_service, &cluster_id, &config).await?;
+    let level = registry
+        .set_compatibility_level(&topic_name, level_req.compatibility_level)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "subject": SchemaRegistryClient::value_subject(&topic_name),
+        "compatibility_level": level
+    })))
+}
+
+pub async fn list_schema_subjects(
+    path: web::Path<String>,
+    registry_service: web::Data<Arc<SchemaRegistryService>>,
+    config: web::Data<SharedConfig>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
+    let cluster_id = path.into_inner();
+
+    let subjects = registry_service.list_subjects(&cluster_id, &config).await?;
+
+    Ok(HttpResponse::Ok().json(subjects))
+}
+
+pub async fn list_schema_versions(
+    path: web::Path<(String, String)>,
+    registry_service: web::Data<Arc<SchemaRegistryService>>,
+    config: web::Data<SharedConfig>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
+    let (cluster_id, subject) = path.into_inner();
+
+    let versions = registry_service
+        .list_versions(&cluster_id, &subject, &config)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "subject": subject,
+        "versions": versions
+    })))
+}
+
+pub async fn get_schema_version(
+    path: web::Path<(String, String, i32)>,
+    registry_service: web::Data<Arc<SchemaRegistryService>>,
+    config: web::Data<SharedConfig>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
+    let (cluster_id, subject, version) = path.into_inner();
+
+    let schema = registry_service
+        .get_schema_by_version(&cluster_id, &subject, version, &config)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(schema))
+}
+
+pub async fn delete_schema_version(
+    req: HttpRequest,
+    path: web::Path<(String, String, i32)>,
+    registry_service: web::Data<Arc<SchemaRegistryService>>,
+    config: web::Data<SharedConfig>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let config = config.snapshot();
+    let (cluster_id, subject, version) = path.into_inner();
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
+
+    let deleted = registry_service
+        .delete_subject_version(&cluster_id, &subject, version, &config, &audit_ctx)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "subject": subject,
+        "deleted_version": deleted
+    })))
+}
+
+pub async fn delete_schema_subject(
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    registry_service: web::Data<Arc<SchemaRegistryService>>,
+    config: web::Data<SharedConfig>,
+    claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let config = config.snapshot();
+    let (cluster_id, subject) = path.into_inner();
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
+
+    let deleted = registry_service
+        .delete_subject(&cluster_id, &subject, &config, &audit_ctx)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "subject": subject,
+        "deleted_versions": deleted
+    })))
+}
@@ -16,14 +16,21 @@
 use crate::errors::AppError;
 use crate::middleware::auth::Claims; // Assuming you have auth middleware
 use crate::models::cluster::{CreateKubernetesClusterRequest, KubernetesClusterConfig};
+use crate::services::kubernetes::admission_simulator::{AdmissionOperation, WebhookRef};
+use crate::services::kubernetes::client::discover_contexts_from_kubeconfig;
+use crate::services::kubernetes::limit_ranges_service::DefaultResourceLimits;
+use crate::services::kubernetes::metrics_service::ResourceSortKey;
+use crate::services::kubernetes::nodes_service::NodeTaint;
+use crate::services::kubernetes::persistent_volumes_service::ReclaimPolicy;
 use crate::services::kubernetes::prelude::*;
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
 use serde::Deserialize;
 use std::collections::BTreeMap;
 use std::sync::Arc;
 use tracing::{debug, info};
 use actix_web_lab::sse;
+use k8s_openapi::api::core::v1::PodSpec;
 use futures::StreamExt;
 use std::time::Duration;
 use uuid::Uuid;
@@ -64,6 +71,7 @@ pub async fn get_cluster_config_by_id(
             certificate_authority_data: None,
             client_certificate_data: None,
             client_key_data: None,
+            use_in_cluster_config: false,
         })
     } else {
         serde_json::from_value(config_value.clone()).map_err(|e| {
@@ -79,6 +87,32 @@ pub async fn get_cluster_config_by_id(
     }
 }
 
+#[derive(Deserialize)]
+pub struct DiscoverContextsQuery {
+    pub kubeconfig_path: Option<String>,
+}
+
+// Defaults to $KUBECONFIG (first entry) or ~/.kube/config on the server
+pub async fn discover_contexts_controller(
+    claims: web::ReqData<Claims>,
+    query: web::Query<DiscoverContextsQuery>,
+) -> Result<impl Responder, AppError> {
+    let path = query
+        .into_inner()
+        .kubeconfig_path
+        .filter(|p| !p.trim().is_empty())
+        .or_else(|| {
+            std::env::var("KUBECONFIG")
+                .ok()
+                .and_then(|v| std::env::split_paths(&v).next())
+                .map(|p| p.to_string_lossy().into_owned())
+        })
+        .unwrap_or_else(|| "~/.kube/config".to_string());
+    debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, kubeconfig_path = %path, "Discovering kubeconfig contexts");
+    let contexts = discover_contexts_from_kubeconfig(std::path::Path::new(&path))?;
+    Ok(HttpResponse::Ok().json(contexts))
+}
+
 // === Cluster Management Controllers ===
 pub async fn list_clusters_controller(
     claims: web::ReqData<Claims>,
@@ -110,6 +144,7 @@ pub async fn create_cluster_controller(
         client_certificate_data: new_cluster_info.client_certificate_data,
         client_key_data: new_cluster_info.client_key_data,
         token: new_cluster_info.token,
+        use_in_cluster_config: new_cluster_info.use_in_cluster_config,
     };
 
     let new_cluster = crate::models::cluster::ActiveModel {
@@ -185,6 +220,7 @@ pub async fn update_cluster_controller(
         client_certificate_data: update_data.client_certificate_data,
         client_key_data: update_data.client_key_data,
         token: update_data.token,
+        use_in_cluster_config: update_data.use_in_cluster_config,
     };
 
     active_cluster.name = Set(update_data.name);
@@ -231,6 +267,7 @@ pub async fn list_namespaces_controller(
     claims: web::ReqData<Claims>, // Changed _claims to claims to use it in log
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<String>, // cluster_id
+    pagination: web::Query<PaginationQuery>,
     namespaces_service: web::Data<Arc<NamespacesService>>,
 ) -> Result<impl Responder, AppError> {
     let original_cluster_id = path.into_inner();
@@ -240,8 +277,14 @@ pub async fn list_namespaces_controller(
         get_cluster_config_by_id(db.get_ref().as_ref(), &original_cluster_id).await?;
     debug!(target: "mayyam::controllers::kubernetes", cluster_id = %original_cluster_id, "Successfully retrieved cluster config for listing namespaces");
 
-    let namespaces = namespaces_service.list_namespaces(&cluster_config).await?;
-    debug!(target: "mayyam::controllers::kubernetes", cluster_id = %original_cluster_id, count = namespaces.len(), "Successfully listed namespaces");
+    let namespaces = namespaces_service
+        .list_namespaces(
+            &cluster_config,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?;
+    debug!(target: "mayyam::controllers::kubernetes", cluster_id = %original_cluster_id, count = namespaces.items.len(), "Successfully listed namespaces");
 
     Ok(HttpResponse::Ok().json(namespaces))
 }
@@ -270,14 +313,21 @@ pub async fn list_nodes_controller(
     claims: web::ReqData<Claims>, // Changed _claims to claims to use it in log
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<String>, // cluster_id
+    pagination: web::Query<PaginationQuery>,
     nodes_service: web::Data<Arc<NodesService>>,
 ) -> Result<impl Responder, AppError> {
     let cluster_id = path.into_inner();
     debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, "Attempting to list nodes");
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
     debug!(target: "mayyam::controllers::kubernetes", %cluster_id, "Successfully retrieved cluster config for listing nodes");
-    let nodes = nodes_service.list_nodes(&cluster_config).await?;
-    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, count = nodes.len(), "Successfully listed nodes");
+    let nodes = nodes_service
+        .list_nodes(
+            &cluster_config,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?;
+    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, count = nodes.items.len(), "Successfully listed nodes");
     Ok(HttpResponse::Ok().json(nodes))
 }
 
@@ -298,10 +348,83 @@ pub async fn get_node_details_controller(
     Ok(HttpResponse::Ok().json(node_details))
 }
 
+#[derive(Deserialize)]
+pub struct NodeLabelRequest {
+    pub key: String,
+    pub value: String,
+}
+
+pub async fn add_node_label_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String)>, // (cluster_id, node_name)
+    body: web::Json<NodeLabelRequest>,
+    nodes_service: web::Data<Arc<NodesService>>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (cluster_id, node_name) = path.into_inner();
+    debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, %node_name, key = %body.key, "Adding node label");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let node = nodes_service
+        .add_node_label(&cluster_config, &node_name, &body.key, &body.value)
+        .await?;
+    Ok(HttpResponse::Ok().json(node))
+}
+
+pub async fn remove_node_label_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String, String)>, // (cluster_id, node_name, key)
+    nodes_service: web::Data<Arc<NodesService>>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (cluster_id, node_name, key) = path.into_inner();
+    debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, %node_name, %key, "Removing node label");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let node = nodes_service
+        .remove_node_label(&cluster_config, &node_name, &key)
+        .await?;
+    Ok(HttpResponse::Ok().json(node))
+}
+
+pub async fn add_node_taint_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String)>, // (cluster_id, node_name)
+    body: web::Json<NodeTaint>,
+    nodes_service: web::Data<Arc<NodesService>>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (cluster_id, node_name) = path.into_inner();
+    debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, %node_name, key = %body.key, "Adding node taint");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let node = nodes_service
+        .add_node_taint(&cluster_config, &node_name, body.into_inner())
+        .await?;
+    Ok(HttpResponse::Ok().json(node))
+}
+
+pub async fn remove_node_taint_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String, String)>, // (cluster_id, node_name, key)
+    nodes_service: web::Data<Arc<NodesService>>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (cluster_id, node_name, key) = path.into_inner();
+    debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, %node_name, %key, "Removing node taint");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let node = nodes_service
+        .remove_node_taint(&cluster_config, &node_name, &key)
+        .await?;
+    Ok(HttpResponse::Ok().json(node))
+}
+
 pub async fn list_pods_controller(
     claims: web::ReqData<Claims>, // Changed _claims to claims to use it in log
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>, // (cluster_id, namespace_name)
+    pagination: web::Query<PaginationQuery>,
     pod_service: web::Data<Arc<PodService>>,
 ) -> Result<impl Responder, AppError> {
     let (cluster_id, namespace_name) = path.into_inner();
@@ -309,9 +432,14 @@ pub async fn list_pods_controller(
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
     debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, "Successfully retrieved cluster config for listing pods");
     let pods = pod_service
-        .list_pods(&cluster_config, &namespace_name)
+        .list_pods(
+            &cluster_config,
+            &namespace_name,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
         .await?;
-    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, count = pods.len(), "Successfully listed pods");
+    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, count = pods.items.len(), "Successfully listed pods");
     Ok(HttpResponse::Ok().json(pods))
 }
 
@@ -539,6 +667,203 @@ pub async fn get_cluster_metrics_controller(
     Ok(HttpResponse::Ok().json(overview))
 }
 
+pub async fn get_pod_resource_usage_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String, String)>,
+    metrics_service: web::Data<Arc<MetricsService>>,
+) -> Result<impl Responder, AppError> {
+    let (cluster_id, namespace, pod_name) = path.into_inner();
+    debug!(
+        target: "mayyam::controllers::kubernetes",
+        user_id = %claims.username,
+        %cluster_id,
+        %namespace,
+        %pod_name,
+        "Fetching pod resource usage"
+    );
+
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let usage = metrics_service
+        .get_pod_resource_usage(&cluster_config, &namespace, &pod_name)
+        .await?;
+    Ok(HttpResponse::Ok().json(usage))
+}
+
+#[derive(Deserialize)]
+pub struct TopPodsQuery {
+    pub sort_by: Option<ResourceSortKey>,
+    pub limit: Option<usize>,
+}
+
+pub async fn get_top_pods_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String)>,
+    query: web::Query<TopPodsQuery>,
+    metrics_service: web::Data<Arc<MetricsService>>,
+) -> Result<impl Responder, AppError> {
+    let (cluster_id, namespace) = path.into_inner();
+    let query = query.into_inner();
+    debug!(
+        target: "mayyam::controllers::kubernetes",
+        user_id = %claims.username,
+        %cluster_id,
+        %namespace,
+        "Fetching top pods"
+    );
+
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let pods = metrics_service
+        .get_top_pods(
+            &cluster_config,
+            &namespace,
+            query.sort_by.unwrap_or(ResourceSortKey::Cpu),
+            query.limit.unwrap_or(10),
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(pods))
+}
+
+#[derive(Deserialize)]
+pub struct CanaryAnalysisRequest {
+    pub baseline_selector: String,
+    pub canary_selector: String,
+    pub metric_window_secs: Option<u64>,
+}
+
+pub async fn analyze_canary_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String)>,
+    body: web::Json<CanaryAnalysisRequest>,
+    analyzer: web::Data<Arc<DeploymentCanaryAnalyzer>>,
+) -> Result<impl Responder, AppError> {
+    let (cluster_id, namespace) = path.into_inner();
+    let req = body.into_inner();
+    debug!(
+        target: "mayyam::controllers::kubernetes",
+        user_id = %claims.username,
+        %cluster_id,
+        %namespace,
+        baseline = %req.baseline_selector,
+        canary = %req.canary_selector,
+        "Analyzing canary deployment"
+    );
+
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let result = analyzer
+        .analyze(
+            &cluster_config,
+            &namespace,
+            &req.baseline_selector,
+            &req.canary_selector,
+            req.metric_window_secs.unwrap_or(600),
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+pub async fn analyze_pending_pod_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String, String)>, // (cluster_id, namespace_name, pod_name)
+    analyzer: web::Data<Arc<SchedulingAnalyzer>>,
+) -> Result<impl Responder, AppError> {
+    let (cluster_id, namespace, pod_name) = path.into_inner();
+    debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, %namespace, %pod_name, "Analyzing pending pod scheduling");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let analysis = analyzer
+        .analyze_pending_pod(&cluster_config, &namespace, &pod_name)
+        .await?;
+    Ok(HttpResponse::Ok().json(analysis))
+}
+
+pub async fn get_node_affinity_compliance_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String, String)>, // (cluster_id, namespace_name, pod_name)
+    analyzer: web::Data<Arc<AffinityAnalyzer>>,
+) -> Result<impl Responder, AppError> {
+    let (cluster_id, namespace, pod_name) = path.into_inner();
+    debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, %namespace, %pod_name, "Evaluating pod node affinity");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let report = analyzer
+        .get_node_affinity_compliance(&cluster_config, &namespace, &pod_name)
+        .await?;
+    Ok(HttpResponse::Ok().json(report))
+}
+
+pub async fn find_schedulable_nodes_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<String>,
+    body: web::Json<PodSpec>,
+    analyzer: web::Data<Arc<AffinityAnalyzer>>,
+) -> Result<impl Responder, AppError> {
+    let cluster_id = path.into_inner();
+    debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, "Finding schedulable nodes for pod spec");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let nodes = analyzer
+        .find_schedulable_nodes(&cluster_config, body.into_inner())
+        .await?;
+    Ok(HttpResponse::Ok().json(nodes))
+}
+
+#[derive(Deserialize)]
+pub struct AdmissionSimulationRequest {
+    pub webhook: WebhookRef,
+    pub resource: serde_json::Value,
+    pub operation: AdmissionOperation,
+}
+
+pub async fn simulate_admission_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<String>,
+    body: web::Json<AdmissionSimulationRequest>,
+    simulator: web::Data<Arc<AdmissionWebhookSimulator>>,
+) -> Result<impl Responder, AppError> {
+    let cluster_id = path.into_inner();
+    claims.require_admin()?;
+    let req = body.into_inner();
+    debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, webhook = %req.webhook, "Simulating admission webhook");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let result = simulator
+        .test_policy(&cluster_config, &req.webhook, req.resource, req.operation)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+#[derive(Deserialize)]
+pub struct AdmissionBatchSimulationRequest {
+    pub webhook: WebhookRef,
+    pub operation: Option<AdmissionOperation>,
+}
+
+pub async fn simulate_admission_batch_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String)>,
+    body: web::Json<AdmissionBatchSimulationRequest>,
+    simulator: web::Data<Arc<AdmissionWebhookSimulator>>,
+) -> Result<impl Responder, AppError> {
+    let (cluster_id, namespace) = path.into_inner();
+    claims.require_admin()?;
+    let req = body.into_inner();
+    debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, %namespace, webhook = %req.webhook, "Simulating admission webhook for namespace");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let result = simulator
+        .test_policy_batch(
+            &cluster_config,
+            &req.webhook,
+            &namespace,
+            req.operation.unwrap_or(AdmissionOperation::Create),
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
 #[derive(Deserialize)]
 pub struct ExecQuery {
     pub command: String,
@@ -574,10 +899,30 @@ pub async fn exec_pod_command_controller(
     Ok(HttpResponse::Ok().json(result))
 }
 
+pub async fn inject_ephemeral_container_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String, String)>, // (cluster_id, namespace_name, pod_name)
+    body: web::Json<crate::services::kubernetes::pod::EphemeralContainerSpec>,
+    pod_service: web::Data<Arc<PodService>>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (cluster_id, namespace_name, pod_name) = path.into_inner();
+    let spec = body.into_inner();
+    debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, %namespace_name, %pod_name, image = %spec.image, "Inject ephemeral container");
+
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let status = pod_service
+        .inject_ephemeral_container(&cluster_config, &namespace_name, &pod_name, spec)
+        .await?;
+    Ok(HttpResponse::Ok().json(status))
+}
+
 pub async fn list_services_controller(
     claims: web::ReqData<Claims>, // Changed _claims to claims to use it in log
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>, // (cluster_id, namespace_name)
+    pagination: web::Query<PaginationQuery>,
     services_service: web::Data<Arc<ServicesService>>,
 ) -> Result<impl Responder, AppError> {
     let (cluster_id, namespace_name) = path.into_inner();
@@ -585,9 +930,14 @@ pub async fn list_services_controller(
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
     debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, "Successfully retrieved cluster config for listing services");
     let services = services_service
-        .list_services(&cluster_config, &namespace_name)
+        .list_services(
+            &cluster_config,
+            &namespace_name,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
         .await?;
-    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, count = services.len(), "Successfully listed services");
+    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, count = services.items.len(), "Successfully listed services");
     Ok(HttpResponse::Ok().json(services))
 }
 
@@ -596,14 +946,22 @@ pub async fn list_all_services_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<String>, // cluster_id
+    pagination: web::Query<PaginationQuery>,
     services_service: web::Data<Arc<ServicesService>>,
 ) -> Result<impl Responder, AppError> {
     let cluster_id = path.into_inner();
     debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, "Attempting to list all services");
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
     debug!(target: "mayyam::controllers::kubernetes", %cluster_id, "Successfully retrieved cluster config for listing all services");
-    let services = services_service.list_services(&cluster_config, "").await?; // Empty string for all namespaces
-    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, count = services.len(), "Successfully listed all services");
+    let services = services_service
+        .list_services(
+            &cluster_config,
+            "",
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?; // Empty string for all namespaces
+    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, count = services.items.len(), "Successfully listed all services");
     Ok(HttpResponse::Ok().json(services))
 }
 
@@ -624,10 +982,47 @@ pub async fn get_service_details_controller(
     Ok(HttpResponse::Ok().json(service_details))
 }
 
+#[derive(Deserialize)]
+pub struct LoadBalancerQuery {
+    #[serde(default)]
+    pub check_dns: bool,
+}
+
+pub async fn list_loadbalancer_addresses_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String)>, // (cluster_id, namespace_name)
+    query: web::Query<LoadBalancerQuery>,
+    services_service: web::Data<Arc<ServicesService>>,
+) -> Result<impl Responder, AppError> {
+    let (cluster_id, namespace_name) = path.into_inner();
+    debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, %namespace_name, check_dns = query.check_dns, "Attempting to list load balancer addresses");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let load_balancers = services_service
+        .get_loadbalancer_addresses(&cluster_config, &namespace_name)
+        .await?;
+
+    let mut response = Vec::with_capacity(load_balancers.len());
+    for lb in load_balancers {
+        let dns_checks = if query.check_dns {
+            Some(ServicesService::check_dns_resolution(&lb).await)
+        } else {
+            None
+        };
+        response.push(serde_json::json!({
+            "load_balancer": lb,
+            "dns_checks": dns_checks,
+        }));
+    }
+    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, count = response.len(), "Successfully listed load balancer addresses");
+    Ok(HttpResponse::Ok().json(response))
+}
+
 pub async fn list_deployments_controller(
     claims: web::ReqData<Claims>, // Changed _claims to claims to use it in log
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>, // (cluster_id, namespace_name)
+    pagination: web::Query<PaginationQuery>,
     deployments_service: web::Data<Arc<DeploymentsService>>,
 ) -> Result<impl Responder, AppError> {
     let (cluster_id, namespace_name) = path.into_inner();
@@ -635,9 +1030,14 @@ pub async fn list_deployments_controller(
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
     debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, "Successfully retrieved cluster config for listing deployments");
     let deployments = deployments_service
-        .list_deployments(&cluster_config, &namespace_name)
+        .list_deployments(
+            &cluster_config,
+            &namespace_name,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
         .await?;
-    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, count = deployments.len(), "Successfully listed deployments");
+    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, count = deployments.items.len(), "Successfully listed deployments");
     Ok(HttpResponse::Ok().json(deployments))
 }
 
@@ -665,6 +1065,7 @@ pub struct ScaleDeploymentBody {
 
 pub async fn scale_deployment_controller(
     claims: web::ReqData<Claims>,
+    req: HttpRequest,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String, String)>, // (cluster_id, namespace_name, deployment_name)
     body: web::Json<ScaleDeploymentBody>,
@@ -674,8 +1075,15 @@ pub async fn scale_deployment_controller(
     let replicas = body.replicas;
     debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, %namespace_name, %deployment_name, replicas = replicas, "Scaling deployment");
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
     deployments_service
-        .scale_deployment(&cluster_config, &namespace_name, &deployment_name, replicas)
+        .scale_deployment(
+            &cluster_config,
+            &namespace_name,
+            &deployment_name,
+            replicas,
+            &audit_ctx,
+        )
         .await?;
     Ok(HttpResponse::Ok().json(serde_json::json!({
         "status": "scaled",
@@ -685,6 +1093,7 @@ pub async fn scale_deployment_controller(
 
 pub async fn restart_deployment_controller(
     claims: web::ReqData<Claims>,
+    req: HttpRequest,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String, String)>, // (cluster_id, namespace_name, deployment_name)
     deployments_service: web::Data<Arc<DeploymentsService>>,
@@ -692,8 +1101,9 @@ pub async fn restart_deployment_controller(
     let (cluster_id, namespace_name, deployment_name) = path.into_inner();
     debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, %namespace_name, %deployment_name, "Restarting deployment");
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
     deployments_service
-        .restart_deployment(&cluster_config, &namespace_name, &deployment_name)
+        .restart_deployment(&cluster_config, &namespace_name, &deployment_name, &audit_ctx)
         .await?;
     Ok(HttpResponse::Ok().json(serde_json::json!({
         "status": "restarted",
@@ -704,6 +1114,7 @@ pub async fn restart_deployment_controller(
 pub struct CreateNamespaceBody {
     pub name: String,
     pub labels: Option<BTreeMap<String, String>>,
+    pub default_limits: Option<DefaultResourceLimits>,
 }
 
 pub async fn create_namespace_controller(
@@ -716,14 +1127,21 @@ pub async fn create_namespace_controller(
     let cluster_id = path.into_inner();
     debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, ns = %body.name, "Creating namespace");
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let body = body.into_inner();
     let ns = namespaces_service
-        .create_namespace(&cluster_config, &body.name, body.labels.clone())
+        .create_namespace_with_defaults(
+            &cluster_config,
+            &body.name,
+            body.labels,
+            body.default_limits,
+        )
         .await?;
     Ok(HttpResponse::Ok().json(ns))
 }
 
 pub async fn delete_namespace_controller(
     claims: web::ReqData<Claims>,
+    req: HttpRequest,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>, // (cluster_id, namespace_name)
     namespaces_service: web::Data<Arc<NamespacesService>>,
@@ -731,8 +1149,9 @@ pub async fn delete_namespace_controller(
     let (cluster_id, namespace_name) = path.into_inner();
     debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, %namespace_name, "Deleting namespace");
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
     namespaces_service
-        .delete_namespace(&cluster_config, &namespace_name)
+        .delete_namespace(&cluster_config, &namespace_name, &audit_ctx)
         .await?;
     Ok(HttpResponse::Ok().json(serde_json::json!({
         "status": "deleted",
@@ -745,6 +1164,7 @@ pub async fn list_all_deployments_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<String>, // cluster_id
+    pagination: web::Query<PaginationQuery>,
     deployments_service: web::Data<Arc<DeploymentsService>>,
 ) -> Result<impl Responder, AppError> {
     let cluster_id = path.into_inner();
@@ -757,12 +1177,62 @@ pub async fn list_all_deployments_controller(
     debug!(target: "mayyam::controllers::kubernetes", %cluster_id, "Successfully retrieved cluster config for listing all deployments");
     // Pass None or an empty string for namespace to indicate all namespaces
     let deployments = deployments_service
-        .list_deployments(&cluster_config, "")
+        .list_deployments(
+            &cluster_config,
+            "",
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
         .await?;
-    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, count = deployments.len(), "Successfully listed all deployments");
+    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, count = deployments.items.len(), "Successfully listed all deployments");
     Ok(HttpResponse::Ok().json(deployments))
 }
 
+#[derive(Deserialize)]
+pub struct DeploymentsByImageQuery {
+    pub image: String,
+    pub tag: Option<String>,
+}
+
+pub async fn find_deployments_by_image_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<String>, // cluster_id
+    query: web::Query<DeploymentsByImageQuery>,
+    deployments_service: web::Data<Arc<DeploymentsService>>,
+) -> Result<impl Responder, AppError> {
+    let cluster_id = path.into_inner();
+    debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, image = %query.image, "Attempting to find deployments by image");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let deployments = deployments_service
+        .find_deployments_by_image(&cluster_config, &query.image, query.tag.as_deref())
+        .await?;
+    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, count = deployments.len(), "Successfully found deployments by image");
+    Ok(HttpResponse::Ok().json(deployments))
+}
+
+#[derive(Deserialize)]
+pub struct VersionMatrixQuery {
+    pub label_key: String,
+    pub label_value: String,
+}
+
+pub async fn get_deployment_version_matrix_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<String>, // cluster_id
+    query: web::Query<VersionMatrixQuery>,
+    deployments_service: web::Data<Arc<DeploymentsService>>,
+) -> Result<impl Responder, AppError> {
+    let cluster_id = path.into_inner();
+    debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, label_key = %query.label_key, label_value = %query.label_value, "Attempting to build deployment version matrix");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let matrix = deployments_service
+        .get_deployment_version_matrix(&cluster_config, &query.label_key, &query.label_value)
+        .await?;
+    Ok(HttpResponse::Ok().json(matrix))
+}
+
 pub async fn get_pods_for_deployment_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
@@ -781,10 +1251,40 @@ pub async fn get_pods_for_deployment_controller(
     Ok(HttpResponse::Ok().json(pods))
 }
 
+#[derive(Deserialize)]
+pub struct WorkloadCostQuery {
+    pub cost_per_cpu_hour: f64,
+    pub cost_per_gb_memory_hour: f64,
+}
+
+pub async fn estimate_deployment_cost_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String, String)>, // (cluster_id, namespace_name, deployment_name)
+    query: web::Query<WorkloadCostQuery>,
+    estimator: web::Data<Arc<WorkloadCostEstimator>>,
+) -> Result<impl Responder, AppError> {
+    let (cluster_id, namespace_name, deployment_name) = path.into_inner();
+    debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, %namespace_name, %deployment_name, "Attempting to estimate deployment cost");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+
+    let estimate = estimator
+        .estimate_deployment_cost(
+            &cluster_config,
+            &namespace_name,
+            &deployment_name,
+            query.cost_per_cpu_hour,
+            query.cost_per_gb_memory_hour,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(estimate))
+}
+
 pub async fn list_stateful_sets_controller(
     claims: web::ReqData<Claims>, // Changed _claims to claims to use it in log
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>, // (cluster_id, namespace_name)
+    pagination: web::Query<PaginationQuery>,
     stateful_sets_service: web::Data<Arc<StatefulSetsService>>,
 ) -> Result<impl Responder, AppError> {
     let (cluster_id, namespace_name) = path.into_inner();
@@ -792,9 +1292,14 @@ pub async fn list_stateful_sets_controller(
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
     debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, "Successfully retrieved cluster config for listing stateful sets");
     let stateful_sets = stateful_sets_service
-        .list_stateful_sets(&cluster_config, &namespace_name)
+        .list_stateful_sets(
+            &cluster_config,
+            &namespace_name,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
         .await?;
-    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, count = stateful_sets.len(), "Successfully listed stateful sets");
+    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, count = stateful_sets.items.len(), "Successfully listed stateful sets");
     Ok(HttpResponse::Ok().json(stateful_sets))
 }
 
@@ -836,6 +1341,7 @@ pub async fn list_daemon_sets_controller(
     claims: web::ReqData<Claims>, // Changed _claims to claims to use it in log
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>, // (cluster_id, namespace_name)
+    pagination: web::Query<PaginationQuery>,
     daemon_sets_service: web::Data<Arc<DaemonSetsService>>,
 ) -> Result<impl Responder, AppError> {
     let (cluster_id, namespace_name) = path.into_inner();
@@ -843,9 +1349,14 @@ pub async fn list_daemon_sets_controller(
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
     debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, "Successfully retrieved cluster config for listing daemon sets");
     let daemon_sets = daemon_sets_service
-        .list_daemon_sets(&cluster_config, &namespace_name)
+        .list_daemon_sets(
+            &cluster_config,
+            &namespace_name,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
         .await?;
-    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, count = daemon_sets.len(), "Successfully listed daemon sets");
+    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, count = daemon_sets.items.len(), "Successfully listed daemon sets");
     Ok(HttpResponse::Ok().json(daemon_sets))
 }
 
@@ -854,6 +1365,7 @@ pub async fn list_all_daemon_sets_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<String>, // cluster_id
+    pagination: web::Query<PaginationQuery>,
     daemon_sets_service: web::Data<Arc<DaemonSetsService>>,
 ) -> Result<impl Responder, AppError> {
     let cluster_id = path.into_inner();
@@ -861,9 +1373,14 @@ pub async fn list_all_daemon_sets_controller(
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
     debug!(target: "mayyam::controllers::kubernetes", %cluster_id, "Successfully retrieved cluster config for listing all daemon sets");
     let daemon_sets = daemon_sets_service
-        .list_daemon_sets(&cluster_config, "")
+        .list_daemon_sets(
+            &cluster_config,
+            "",
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
         .await?; // Empty string for all namespaces
-    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, count = daemon_sets.len(), "Successfully listed all daemon sets");
+    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, count = daemon_sets.items.len(), "Successfully listed all daemon sets");
     Ok(HttpResponse::Ok().json(daemon_sets))
 }
 
@@ -906,6 +1423,7 @@ pub async fn list_all_stateful_sets_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<String>, // cluster_id
+    pagination: web::Query<PaginationQuery>,
     stateful_sets_service: web::Data<Arc<StatefulSetsService>>,
 ) -> Result<impl Responder, AppError> {
     let cluster_id = path.into_inner();
@@ -913,9 +1431,14 @@ pub async fn list_all_stateful_sets_controller(
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
     debug!(target: "mayyam::controllers::kubernetes", %cluster_id, "Successfully retrieved cluster config for listing all stateful sets");
     let stateful_sets = stateful_sets_service
-        .list_stateful_sets(&cluster_config, "")
+        .list_stateful_sets(
+            &cluster_config,
+            "",
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
         .await?; // Empty string for all namespaces
-    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, count = stateful_sets.len(), "Successfully listed all stateful sets");
+    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, count = stateful_sets.items.len(), "Successfully listed all stateful sets");
     Ok(HttpResponse::Ok().json(stateful_sets))
 }
 
@@ -924,6 +1447,7 @@ pub async fn list_all_pvcs_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<String>, // cluster_id
+    pagination: web::Query<PaginationQuery>,
     pvc_service: web::Data<Arc<PersistentVolumeClaimsService>>,
 ) -> Result<impl Responder, AppError> {
     let cluster_id = path.into_inner();
@@ -931,9 +1455,14 @@ pub async fn list_all_pvcs_controller(
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
     debug!(target: "mayyam::controllers::kubernetes", %cluster_id, "Successfully retrieved cluster config for listing all PVCs");
     let pvcs = pvc_service
-        .list_persistent_volume_claims(&cluster_config, "")
+        .list_persistent_volume_claims(
+            &cluster_config,
+            "",
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
         .await?; // Empty string for all namespaces
-    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, count = pvcs.len(), "Successfully listed all PVCs");
+    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, count = pvcs.items.len(), "Successfully listed all PVCs");
     Ok(HttpResponse::Ok().json(pvcs))
 }
 
@@ -941,6 +1470,7 @@ pub async fn list_pvcs_controller(
     claims: web::ReqData<Claims>, // Changed _claims to claims to use it in log
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>, // (cluster_id, namespace_name)
+    pagination: web::Query<PaginationQuery>,
     pvc_service: web::Data<Arc<PersistentVolumeClaimsService>>,
 ) -> Result<impl Responder, AppError> {
     let (cluster_id, namespace_name) = path.into_inner();
@@ -948,9 +1478,14 @@ pub async fn list_pvcs_controller(
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
     debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, "Successfully retrieved cluster config for listing PVCs");
     let pvcs = pvc_service
-        .list_persistent_volume_claims(&cluster_config, &namespace_name)
+        .list_persistent_volume_claims(
+            &cluster_config,
+            &namespace_name,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
         .await?;
-    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, count = pvcs.len(), "Successfully listed PVCs");
+    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, %namespace_name, count = pvcs.items.len(), "Successfully listed PVCs");
     Ok(HttpResponse::Ok().json(pvcs))
 }
 
@@ -975,14 +1510,21 @@ pub async fn list_pvs_controller(
     claims: web::ReqData<Claims>, // Changed _claims to claims to use it in log
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<String>, // cluster_id
+    pagination: web::Query<PaginationQuery>,
     pv_service: web::Data<Arc<PersistentVolumesService>>,
 ) -> Result<impl Responder, AppError> {
     let cluster_id = path.into_inner();
     debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, "Attempting to list PVs");
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
     debug!(target: "mayyam::controllers::kubernetes", %cluster_id, "Successfully retrieved cluster config for listing PVs");
-    let pvs = pv_service.list_persistent_volumes(&cluster_config).await?;
-    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, count = pvs.len(), "Successfully listed PVs");
+    let pvs = pv_service
+        .list_persistent_volumes(
+            &cluster_config,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?;
+    debug!(target: "mayyam::controllers::kubernetes", %cluster_id, count = pvs.items.len(), "Successfully listed PVs");
     Ok(HttpResponse::Ok().json(pvs))
 }
 
@@ -1003,6 +1545,74 @@ pub async fn get_pv_details_controller(
     Ok(HttpResponse::Ok().json(pv_details))
 }
 
+#[derive(Deserialize)]
+pub struct SetReclaimPolicyRequest {
+    pub policy: ReclaimPolicy,
+}
+
+pub async fn set_pv_reclaim_policy_controller(
+    req: HttpRequest,
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String)>, // (cluster_id, pv_name)
+    body: web::Json<SetReclaimPolicyRequest>,
+    pv_service: web::Data<Arc<PersistentVolumesService>>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (cluster_id, pv_name) = path.into_inner();
+    debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, %pv_name, policy = body.policy.as_str(), "Setting PV reclaim policy");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
+    pv_service
+        .set_reclaim_policy(&cluster_config, &pv_name, body.policy, &audit_ctx)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub async fn list_released_pvs_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<String>, // cluster_id
+    pv_service: web::Data<Arc<PersistentVolumesService>>,
+) -> Result<impl Responder, AppError> {
+    let cluster_id = path.into_inner();
+    debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, "Listing released PVs");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let pvs = pv_service.list_released_pvs(&cluster_config).await?;
+    Ok(HttpResponse::Ok().json(pvs))
+}
+
+#[derive(Deserialize)]
+pub struct RebindPvRequest {
+    pub pvc_name: String,
+    pub namespace: String,
+}
+
+pub async fn rebind_pv_controller(
+    req: HttpRequest,
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String)>, // (cluster_id, pv_name)
+    body: web::Json<RebindPvRequest>,
+    pv_service: web::Data<Arc<PersistentVolumesService>>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (cluster_id, pv_name) = path.into_inner();
+    debug!(target: "mayyam::controllers::kubernetes", user_id = %claims.username, %cluster_id, %pv_name, pvc_name = %body.pvc_name, namespace = %body.namespace, "Rebinding PV");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
+    let pvc = pv_service
+        .rebind_pv_to_pvc(
+            &cluster_config,
+            &pv_name,
+            &body.pvc_name,
+            &body.namespace,
+            &audit_ctx,
+        )
+        .await?;
+    Ok(HttpResponse::Created().json(pvc))
+}
+
 pub async fn test_db_connection_controller(
     db: web::Data<Arc<DatabaseConnection>>,
 ) -> Result<impl Responder, AppError> {
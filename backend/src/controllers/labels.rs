@@ -0,0 +1,62 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use std::collections::BTreeMap;
+
+use actix_web::{web, HttpResponse, Responder};
+use sea_orm::DatabaseConnection;
+use serde::Deserialize;
+use std::sync::Arc;
+use tracing::debug;
+
+use crate::controllers::kubernetes::get_cluster_config_by_id;
+use crate::errors::AppError;
+use crate::middleware::auth::Claims;
+use crate::services::kubernetes::label_service::{LabelService, ResourceRef};
+
+#[derive(Deserialize)]
+pub struct ApplyLabelsRequest {
+    pub resources: Vec<ResourceRef>,
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
+    #[serde(default)]
+    pub annotations: BTreeMap<String, String>,
+    #[serde(default)]
+    pub overwrite: bool,
+}
+
+pub async fn apply_labels_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<String>, // cluster_id
+    body: web::Json<ApplyLabelsRequest>,
+    label_service: web::Data<Arc<LabelService>>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let cluster_id = path.into_inner();
+    let req = body.into_inner();
+    debug!(target: "mayyam::controllers::labels", user_id = %claims.username, %cluster_id, resources = req.resources.len(), "Applying labels to resources");
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let result = label_service
+        .apply_labels(
+            &cluster_config,
+            req.resources,
+            req.labels,
+            req.annotations,
+            req.overwrite,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use sea_orm::{DatabaseConnection, EntityTrait};
 use std::sync::Arc;
 use tracing::debug;
@@ -22,7 +22,10 @@ use uuid::Uuid;
 use crate::errors::AppError;
 use crate::middleware::auth::Claims;
 use crate::models::cluster::KubernetesClusterConfig;
-use crate::services::kubernetes::limit_ranges_service::LimitRangesService;
+use crate::services::kubernetes::limit_ranges_service::{
+    DefaultResourceLimits, LimitRangesService,
+};
+use crate::services::kubernetes::pagination::PaginationQuery;
 use k8s_openapi::api::core::v1::LimitRange;
 
 async fn get_cluster_config_by_id(
@@ -51,6 +54,7 @@ async fn get_cluster_config_by_id(
             certificate_authority_data: None,
             client_certificate_data: None,
             client_key_data: None,
+            use_in_cluster_config: false,
         })
     } else {
         serde_json::from_value(value)
@@ -62,12 +66,20 @@ pub async fn list_limit_ranges_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>,
+    pagination: web::Query<PaginationQuery>,
     svc: web::Data<Arc<LimitRangesService>>,
 ) -> Result<impl Responder, AppError> {
     let (cluster_id, ns) = path.into_inner();
     debug!(target: "mayyam::controllers::limit_ranges", user_id = %claims.username, %cluster_id, %ns, "List LimitRanges");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    let list = svc.list(&cfg, &ns).await?;
+    let list = svc
+        .list(
+            &cfg,
+            &ns,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?;
     Ok(HttpResponse::Ok().json(list))
 }
 
@@ -104,6 +116,7 @@ pub async fn upsert_limit_range_controller(
 
 pub async fn delete_limit_range_controller(
     claims: web::ReqData<Claims>,
+    req: HttpRequest,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String, String)>,
     svc: web::Data<Arc<LimitRangesService>>,
@@ -111,6 +124,24 @@ pub async fn delete_limit_range_controller(
     let (cluster_id, ns, name) = path.into_inner();
     debug!(target: "mayyam::controllers::limit_ranges", user_id = %claims.username, %cluster_id, %ns, %name, "Delete LimitRange");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    svc.delete(&cfg, &ns, &name).await?;
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
+    svc.delete(&cfg, &ns, &name, &audit_ctx).await?;
     Ok(HttpResponse::Ok().json(serde_json::json!({"deleted": true})))
 }
+
+pub async fn apply_default_limit_range_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String)>,
+    body: web::Json<DefaultResourceLimits>,
+    svc: web::Data<Arc<LimitRangesService>>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (cluster_id, ns) = path.into_inner();
+    debug!(target: "mayyam::controllers::limit_ranges", user_id = %claims.username, %cluster_id, %ns, "Apply default LimitRange");
+    let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let applied = svc
+        .apply_default_limit_range(&cfg, &ns, body.into_inner())
+        .await?;
+    Ok(HttpResponse::Ok().json(applied))
+}
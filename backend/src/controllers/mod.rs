@@ -14,11 +14,13 @@
 
 
 pub mod ai;
+pub mod alerting;
 pub mod auth;
 pub mod authz;
 pub mod replica_sets;
 pub mod storage_classes;
 pub mod crds;
+pub mod cluster_version;
 pub mod aurora_cluster;
 pub mod ai_analysis;
 pub mod aws_account;
@@ -26,27 +28,35 @@ pub mod aws_analytics;
 pub mod budget;
 pub mod chaos;
 pub mod cloud;
+pub mod config;
 pub mod configmaps;
 pub mod cost_analytics;
 pub mod cronjobs;
 pub mod data_source;
 pub mod database;
+pub mod dependency_graph;
 pub mod endpoints;
 pub mod explain_plan;
+pub mod garbage_collection;
 pub mod hpa;
 pub mod ingress;
 pub mod jobs;
 pub mod kafka;
 pub mod kubernetes;
 pub mod kubernetes_cluster_management;
+pub mod labels;
 pub mod limit_ranges;
 pub mod llm_analytics;
 pub mod llm_model;
 pub mod llm_provider;
+pub mod multi_cluster;
 pub mod mysql_performance;
 pub mod network_policies;
 pub mod node_ops;
+pub mod opensearch;
 pub mod pdb;
+pub mod port_forward;
+pub mod prometheus_rules;
 pub mod prompt_template;
 pub mod query_fingerprint;
 pub mod query_template;
@@ -56,5 +66,6 @@ pub mod secrets;
 pub mod service_accounts;
 pub mod slow_query;
 pub mod sync_run;
+pub mod tasks;
 pub mod unified_llm;
 pub mod table_analytics;
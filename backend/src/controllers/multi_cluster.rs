@@ -0,0 +1,55 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use actix_web::{web, HttpResponse, Responder};
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+use crate::errors::AppError;
+use crate::middleware::auth::Claims;
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::repositories::cluster::ClusterRepository;
+use crate::services::kubernetes::multi_cluster_aggregator::MultiClusterAggregator;
+
+pub async fn get_multi_cluster_summary_controller(
+    claims: web::ReqData<Claims>,
+    cluster_repo: web::Data<Arc<ClusterRepository>>,
+    svc: web::Data<Arc<MultiClusterAggregator>>,
+) -> Result<impl Responder, AppError> {
+    debug!(target: "mayyam::controllers::multi_cluster", user_id = %claims.username, "Aggregate resource counts across clusters");
+    let mut names = Vec::new();
+    let mut configs = Vec::new();
+    for cluster in cluster_repo.find_by_type("kubernetes").await? {
+        // Clusters registered without explicit settings fall back to the default kubeconfig
+        let value = if cluster.config.is_null() {
+            serde_json::json!({})
+        } else {
+            cluster.config
+        };
+        match serde_json::from_value::<KubernetesClusterConfig>(value) {
+            Ok(cfg) => {
+                names.push(cluster.name);
+                configs.push(cfg);
+            }
+            Err(e) => warn!("Skipping cluster {} in summary: {}", cluster.name, e),
+        }
+    }
+
+    let mut summary = svc.aggregate_resource_counts(configs).await?;
+    // The stored configs carry no display name, so label each summary with the cluster's
+    for (cluster, name) in summary.clusters.iter_mut().zip(names) {
+        cluster.cluster_name = name;
+    }
+    Ok(HttpResponse::Ok().json(summary))
+}
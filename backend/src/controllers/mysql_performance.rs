@@ -240,6 +240,33 @@ pub async fn detect_performance_anomalies(
     Ok(HttpResponse::Ok().json(response))
 }
 
+#[derive(Debug, Deserialize)]
+pub struct CompareSnapshotsQuery {
+    pub baseline: String,
+    pub target: String,
+    pub threshold_percent: Option<f64>,
+}
+
+pub async fn compare_performance_snapshots(
+    query: web::Query<CompareSnapshotsQuery>,
+    db_pool: web::Data<Arc<DatabaseConnection>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let baseline = Uuid::parse_str(&query.baseline).map_err(|e| AppError::BadRequest(format!("Invalid baseline UUID: {}", e)))?;
+    let target = Uuid::parse_str(&query.target).map_err(|e| AppError::BadRequest(format!("Invalid target UUID: {}", e)))?;
+
+    let performance_repo = MySQLPerformanceRepository::new(db_pool.get_ref().clone());
+    let cluster_repo = crate::repositories::aurora_cluster_repository::AuroraClusterRepository::new(db_pool.get_ref().clone());
+    let mut performance_service = MySQLPerformanceService::new(performance_repo, cluster_repo);
+    if let Some(threshold) = query.threshold_percent {
+        performance_service = performance_service.with_regression_threshold(threshold);
+    }
+
+    let diff = performance_service.compare_snapshots(baseline, target).await?;
+
+    Ok(HttpResponse::Ok().json(diff))
+}
+
 pub async fn get_performance_stats(
     query: web::Query<PerformanceFilter>,
     db_pool: web::Data<Arc<DatabaseConnection>>,
@@ -13,8 +13,9 @@
 // limitations under the License.
 
 
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use sea_orm::{DatabaseConnection, EntityTrait};
+use serde::Deserialize;
 use std::sync::Arc;
 use tracing::debug;
 use uuid::Uuid;
@@ -23,6 +24,7 @@ use crate::errors::AppError;
 use crate::middleware::auth::Claims;
 use crate::models::cluster::KubernetesClusterConfig;
 use crate::services::kubernetes::network_policies_service::NetworkPoliciesService;
+use crate::services::kubernetes::pagination::PaginationQuery;
 use k8s_openapi::api::networking::v1::NetworkPolicy;
 
 async fn get_cluster_config_by_id(
@@ -51,6 +53,7 @@ async fn get_cluster_config_by_id(
             certificate_authority_data: None,
             client_certificate_data: None,
             client_key_data: None,
+            use_in_cluster_config: false,
         })
     } else {
         serde_json::from_value(value)
@@ -62,12 +65,20 @@ pub async fn list_network_policies_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>,
+    pagination: web::Query<PaginationQuery>,
     svc: web::Data<Arc<NetworkPoliciesService>>,
 ) -> Result<impl Responder, AppError> {
     let (cluster_id, ns) = path.into_inner();
     debug!(target: "mayyam::controllers::network_policies", user_id = %claims.username, %cluster_id, %ns, "List NetworkPolicies");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    let list = svc.list(&cfg, &ns).await?;
+    let list = svc
+        .list(
+            &cfg,
+            &ns,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?;
     Ok(HttpResponse::Ok().json(list))
 }
 
@@ -104,6 +115,7 @@ pub async fn upsert_network_policy_controller(
 
 pub async fn delete_network_policy_controller(
     claims: web::ReqData<Claims>,
+    req: HttpRequest,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String, String)>,
     svc: web::Data<Arc<NetworkPoliciesService>>,
@@ -111,6 +123,64 @@ pub async fn delete_network_policy_controller(
     let (cluster_id, ns, name) = path.into_inner();
     debug!(target: "mayyam::controllers::network_policies", user_id = %claims.username, %cluster_id, %ns, %name, "Delete NetworkPolicy");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    svc.delete(&cfg, &ns, &name).await?;
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
+    svc.delete(&cfg, &ns, &name, &audit_ctx).await?;
     Ok(HttpResponse::Ok().json(serde_json::json!({"deleted": true})))
 }
+
+#[derive(Debug, Deserialize)]
+pub struct NamespaceIsolationRequest {
+    #[serde(default)]
+    pub allow_ingress_from_namespaces: Vec<String>,
+    #[serde(default)]
+    pub allow_egress_to_namespaces: Vec<String>,
+}
+
+pub async fn create_namespace_isolation_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String)>,
+    body: web::Json<NamespaceIsolationRequest>,
+    svc: web::Data<Arc<NetworkPoliciesService>>,
+) -> Result<impl Responder, AppError> {
+    let (cluster_id, ns) = path.into_inner();
+    let body = body.into_inner();
+    debug!(target: "mayyam::controllers::network_policies", user_id = %claims.username, %cluster_id, %ns, "Apply namespace isolation NetworkPolicy");
+    let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let policy = svc
+        .create_namespace_isolation_policy(
+            &cfg,
+            &ns,
+            body.allow_ingress_from_namespaces,
+            body.allow_egress_to_namespaces,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(policy))
+}
+
+pub async fn create_default_deny_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String)>,
+    svc: web::Data<Arc<NetworkPoliciesService>>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (cluster_id, ns) = path.into_inner();
+    debug!(target: "mayyam::controllers::network_policies", user_id = %claims.username, %cluster_id, %ns, "Apply default deny NetworkPolicy");
+    let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let policy = svc.create_default_deny_all(&cfg, &ns).await?;
+    Ok(HttpResponse::Ok().json(policy))
+}
+
+pub async fn list_unenforced_namespaces_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<String>,
+    svc: web::Data<Arc<NetworkPoliciesService>>,
+) -> Result<impl Responder, AppError> {
+    let cluster_id = path.into_inner();
+    debug!(target: "mayyam::controllers::network_policies", user_id = %claims.username, %cluster_id, "List namespaces without default deny");
+    let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let namespaces = svc.list_unenforced_namespaces(&cfg).await?;
+    Ok(HttpResponse::Ok().json(namespaces))
+}
@@ -0,0 +1,144 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use actix_web::{web, HttpResponse, Responder};
+use serde::Deserialize;
+use std::sync::Arc;
+use tracing::debug;
+
+use crate::config::reloader::SharedConfig;
+use crate::config::OpenSearchConfig;
+use crate::errors::AppError;
+use crate::middleware::auth::Claims;
+use crate::services::opensearch::OpenSearchService;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateAliasRequest {
+    pub alias: String,
+    pub filter: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RolloverRequest {
+    pub max_docs: Option<u64>,
+    pub max_size_bytes: Option<u64>,
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReindexRequest {
+    pub target_mapping: serde_json::Value,
+}
+
+fn find_cluster(config: &SharedConfig, cluster: &str) -> Result<OpenSearchConfig, AppError> {
+    config
+        .snapshot()
+        .database
+        .opensearch
+        .iter()
+        .find(|c| c.name == cluster)
+        .cloned()
+        .ok_or_else(|| AppError::NotFound(format!("OpenSearch cluster {} not found", cluster)))
+}
+
+pub async fn get_index_mapping(
+    claims: web::ReqData<Claims>,
+    path: web::Path<(String, String)>,
+    config: web::Data<SharedConfig>,
+    svc: web::Data<Arc<OpenSearchService>>,
+) -> Result<impl Responder, AppError> {
+    let (cluster, index) = path.into_inner();
+    debug!(target: "mayyam::controllers::opensearch", user_id = %claims.username, %cluster, %index, "Get index mapping");
+    let cluster_config = find_cluster(&config, &cluster)?;
+    let mapping = svc.get_index_mapping(&cluster_config, &index).await?;
+    Ok(HttpResponse::Ok().json(mapping))
+}
+
+pub async fn put_index_mapping(
+    claims: web::ReqData<Claims>,
+    path: web::Path<(String, String)>,
+    body: web::Json<serde_json::Value>,
+    config: web::Data<SharedConfig>,
+    svc: web::Data<Arc<OpenSearchService>>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (cluster, index) = path.into_inner();
+    debug!(target: "mayyam::controllers::opensearch", user_id = %claims.username, %cluster, %index, "Put index mapping");
+    let cluster_config = find_cluster(&config, &cluster)?;
+    let result = svc
+        .put_index_mapping(&cluster_config, &index, &body.into_inner())
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+pub async fn create_alias(
+    claims: web::ReqData<Claims>,
+    path: web::Path<(String, String)>,
+    body: web::Json<CreateAliasRequest>,
+    config: web::Data<SharedConfig>,
+    svc: web::Data<Arc<OpenSearchService>>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (cluster, index) = path.into_inner();
+    let req = body.into_inner();
+    debug!(target: "mayyam::controllers::opensearch", user_id = %claims.username, %cluster, %index, alias = %req.alias, "Create alias");
+    let cluster_config = find_cluster(&config, &cluster)?;
+    let result = svc
+        .create_alias(&cluster_config, &index, &req.alias, req.filter)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+pub async fn rollover_alias(
+    claims: web::ReqData<Claims>,
+    path: web::Path<(String, String)>,
+    body: web::Json<RolloverRequest>,
+    config: web::Data<SharedConfig>,
+    svc: web::Data<Arc<OpenSearchService>>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (cluster, alias) = path.into_inner();
+    let req = body.into_inner();
+    debug!(target: "mayyam::controllers::opensearch", user_id = %claims.username, %cluster, %alias, dry_run = req.dry_run, "Rollover alias");
+    let cluster_config = find_cluster(&config, &cluster)?;
+    let result = svc
+        .rollover_alias(
+            &cluster_config,
+            &alias,
+            req.max_docs,
+            req.max_size_bytes,
+            req.dry_run,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+pub async fn reindex_with_mapping_change(
+    claims: web::ReqData<Claims>,
+    path: web::Path<(String, String)>,
+    body: web::Json<ReindexRequest>,
+    config: web::Data<SharedConfig>,
+    svc: web::Data<Arc<OpenSearchService>>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (cluster, index) = path.into_inner();
+    debug!(target: "mayyam::controllers::opensearch", user_id = %claims.username, %cluster, %index, "Reindex with mapping change");
+    let cluster_config = find_cluster(&config, &cluster)?;
+    let result = svc
+        .reindex_with_mapping_change(&cluster_config, &index, body.into_inner().target_mapping)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use sea_orm::{DatabaseConnection, EntityTrait};
 use std::sync::Arc;
 use tracing::debug;
@@ -22,6 +22,7 @@ use uuid::Uuid;
 use crate::errors::AppError;
 use crate::middleware::auth::Claims;
 use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::pagination::PaginationQuery;
 use crate::services::kubernetes::pdb_service::PodDisruptionBudgetsService;
 use k8s_openapi::api::policy::v1::PodDisruptionBudget;
 
@@ -51,6 +52,7 @@ async fn get_cluster_config_by_id(
             certificate_authority_data: None,
             client_certificate_data: None,
             client_key_data: None,
+            use_in_cluster_config: false,
         })
     } else {
         serde_json::from_value(value)
@@ -62,12 +64,20 @@ pub async fn list_pdb_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>,
+    pagination: web::Query<PaginationQuery>,
     svc: web::Data<Arc<PodDisruptionBudgetsService>>,
 ) -> Result<impl Responder, AppError> {
     let (cluster_id, ns) = path.into_inner();
     debug!(target: "mayyam::controllers::pdb", user_id = %claims.username, %cluster_id, %ns, "List PDB");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    let list = svc.list(&cfg, &ns).await?;
+    let list = svc
+        .list(
+            &cfg,
+            &ns,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?;
     Ok(HttpResponse::Ok().json(list))
 }
 
@@ -104,6 +114,7 @@ pub async fn upsert_pdb_controller(
 
 pub async fn delete_pdb_controller(
     claims: web::ReqData<Claims>,
+    req: HttpRequest,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String, String)>,
     svc: web::Data<Arc<PodDisruptionBudgetsService>>,
@@ -111,6 +122,33 @@ pub async fn delete_pdb_controller(
     let (cluster_id, ns, name) = path.into_inner();
     debug!(target: "mayyam::controllers::pdb", user_id = %claims.username, %cluster_id, %ns, %name, "Delete PDB");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    svc.delete(&cfg, &ns, &name).await?;
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
+    svc.delete(&cfg, &ns, &name, &audit_ctx).await?;
     Ok(HttpResponse::Ok().json(serde_json::json!({"deleted": true})))
 }
+
+pub async fn detect_pdb_violations_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String)>,
+    svc: web::Data<Arc<PodDisruptionBudgetsService>>,
+) -> Result<impl Responder, AppError> {
+    let (cluster_id, ns) = path.into_inner();
+    debug!(target: "mayyam::controllers::pdb", user_id = %claims.username, %cluster_id, %ns, "Detect PDB violations");
+    let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let violations = svc.detect_pdb_violations(&cfg, &ns).await?;
+    Ok(HttpResponse::Ok().json(violations))
+}
+
+pub async fn simulate_node_drain_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String)>,
+    svc: web::Data<Arc<PodDisruptionBudgetsService>>,
+) -> Result<impl Responder, AppError> {
+    let (cluster_id, node_name) = path.into_inner();
+    debug!(target: "mayyam::controllers::pdb", user_id = %claims.username, %cluster_id, %node_name, "Simulate node drain PDB impact");
+    let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let impacts = svc.simulate_node_drain_pdb_impact(&cfg, &node_name).await?;
+    Ok(HttpResponse::Ok().json(impacts))
+}
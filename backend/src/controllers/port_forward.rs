@@ -0,0 +1,189 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use actix_web::{rt, web, HttpRequest, HttpResponse};
+use actix_ws::{Message, MessageStream, Session};
+use futures::StreamExt;
+use kube::api::Portforwarder;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tracing::{debug, info, warn};
+
+use crate::controllers::kubernetes::get_cluster_config_by_id;
+use crate::errors::AppError;
+use crate::middleware::auth::Claims;
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::prelude::*;
+
+const TUNNEL_READ_BUFFER_SIZE: usize = 16 * 1024;
+
+// Proxies raw TCP bytes between a client WebSocket and a kube port-forward tunnel.
+// Binary and text frames from the client are written to the pod port as-is; bytes read
+// from the pod port are sent back as binary frames.
+pub struct PodPortForwardHandler {
+    forwarder: Portforwarder,
+    pod_port: u16,
+    tunnel: String,
+}
+
+impl PodPortForwardHandler {
+    pub async fn connect(
+        pod_service: &PodService,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        pod_name: &str,
+        pod_port: u16,
+    ) -> Result<Self, AppError> {
+        let forwarder = pod_service
+            .port_forward(cluster_config, namespace, pod_name, pod_port)
+            .await?;
+        Ok(Self {
+            forwarder,
+            pod_port,
+            tunnel: format!("{}/{}:{}", namespace, pod_name, pod_port),
+        })
+    }
+
+    pub async fn proxy(
+        mut self,
+        mut session: Session,
+        msg_stream: MessageStream,
+    ) -> Result<(), AppError> {
+        let stream = match self.forwarder.take_stream(self.pod_port) {
+            Some(stream) => stream,
+            None => {
+                let _ = session.close(None).await;
+                self.forwarder.abort();
+                return Err(AppError::Kubernetes(format!(
+                    "Port-forward tunnel to {} has no stream",
+                    self.tunnel
+                )));
+            }
+        };
+
+        let result = Self::pump(&mut session, msg_stream, stream, &self.tunnel).await;
+
+        // Teardown runs on every exit path so neither side is left half-open
+        let _ = session.close(None).await;
+        self.forwarder.abort();
+        if let Err(e) = self.forwarder.join().await {
+            debug!(tunnel = %self.tunnel, "Port-forward tunnel closed with error: {}", e);
+        }
+        info!(tunnel = %self.tunnel, "Port-forward session closed");
+
+        result
+    }
+
+    async fn pump<S>(
+        session: &mut Session,
+        mut msg_stream: MessageStream,
+        stream: S,
+        tunnel: &str,
+    ) -> Result<(), AppError>
+    where
+        S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+    {
+        let (mut tunnel_rx, mut tunnel_tx) = tokio::io::split(stream);
+        let mut buf = vec![0u8; TUNNEL_READ_BUFFER_SIZE];
+
+        loop {
+            tokio::select! {
+                msg = msg_stream.next() => match msg {
+                    Some(Ok(Message::Binary(data))) => {
+                        tunnel_tx.write_all(&data).await.map_err(|e| {
+                            AppError::Kubernetes(format!("Failed writing to tunnel {}: {}", tunnel, e))
+                        })?;
+                    }
+                    Some(Ok(Message::Text(text))) => {
+                        tunnel_tx.write_all(text.as_bytes()).await.map_err(|e| {
+                            AppError::Kubernetes(format!("Failed writing to tunnel {}: {}", tunnel, e))
+                        })?;
+                    }
+                    Some(Ok(Message::Ping(bytes))) => {
+                        if session.pong(&bytes).await.is_err() {
+                            break;
+                        }
+                    }
+                    Some(Ok(Message::Close(reason))) => {
+                        debug!(%tunnel, ?reason, "Client closed port-forward WebSocket");
+                        break;
+                    }
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => {
+                        warn!(%tunnel, "Port-forward WebSocket protocol error: {}", e);
+                        break;
+                    }
+                    None => break,
+                },
+                read = tunnel_rx.read(&mut buf) => match read {
+                    Ok(0) => {
+                        debug!(%tunnel, "Pod closed the port-forward connection");
+                        break;
+                    }
+                    Ok(n) => {
+                        if session.binary(buf[..n].to_vec()).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        return Err(AppError::Kubernetes(format!(
+                            "Failed reading from tunnel {}: {}",
+                            tunnel, e
+                        )));
+                    }
+                },
+            }
+        }
+
+        let _ = tunnel_tx.shutdown().await;
+        Ok(())
+    }
+}
+
+pub async fn pod_port_forward_controller(
+    req: HttpRequest,
+    body: web::Payload,
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String, String, u16)>, // (cluster_id, namespace_name, pod_name, pod_port)
+    pod_service: web::Data<Arc<PodService>>,
+) -> Result<HttpResponse, AppError> {
+    let (cluster_id, namespace_name, pod_name, pod_port) = path.into_inner();
+    debug!(target: "mayyam::controllers::port_forward", user_id = %claims.username, %cluster_id, %namespace_name, %pod_name, pod_port, "Port-forward requested");
+
+    let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+
+    // Open the tunnel before upgrading so failures surface as a regular HTTP error
+    let handler = PodPortForwardHandler::connect(
+        pod_service.get_ref(),
+        &cluster_config,
+        &namespace_name,
+        &pod_name,
+        pod_port,
+    )
+    .await?;
+
+    let (response, session, msg_stream) = actix_ws::handle(&req, body)
+        .map_err(|e| AppError::BadRequest(format!("WebSocket upgrade failed: {}", e)))?;
+
+    rt::spawn(async move {
+        if let Err(e) = handler.proxy(session, msg_stream).await {
+            warn!(target: "mayyam::controllers::port_forward", "Port-forward proxy ended with error: {}", e);
+        }
+    });
+
+    Ok(response)
+}
@@ -17,7 +17,7 @@ use actix_web::{web, HttpResponse, Responder};
 use sea_orm::DatabaseConnection;
 use std::sync::Arc;
 
-use crate::config::Config;
+use crate::config::reloader::SharedConfig;
 use crate::errors::AppError;
 use crate::middleware::auth::Claims;
 use crate::models::query_template::{CreateQueryTemplateRequest, UpdateQueryTemplateRequest};
@@ -25,11 +25,11 @@ use crate::repositories::query_template::QueryTemplateRepository;
 
 pub async fn list_templates(
     db_pool: web::Data<Arc<DatabaseConnection>>,
-    config: web::Data<Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
-    let template_repo =
-        QueryTemplateRepository::new(db_pool.get_ref().clone(), config.get_ref().clone());
+    let config = config.snapshot();
+    let template_repo = QueryTemplateRepository::new(db_pool.get_ref().clone(), config);
     let templates = template_repo.find_all().await?;
 
     Ok(HttpResponse::Ok().json(templates))
@@ -37,11 +37,11 @@ pub async fn list_templates(
 
 pub async fn list_common_templates(
     db_pool: web::Data<Arc<DatabaseConnection>>,
-    config: web::Data<Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
-    let template_repo =
-        QueryTemplateRepository::new(db_pool.get_ref().clone(), config.get_ref().clone());
+    let config = config.snapshot();
+    let template_repo = QueryTemplateRepository::new(db_pool.get_ref().clone(), config);
     let templates = template_repo.find_common_templates().await?;
 
     Ok(HttpResponse::Ok().json(templates))
@@ -50,12 +50,12 @@ pub async fn list_common_templates(
 pub async fn list_templates_by_type(
     path: web::Path<String>,
     db_pool: web::Data<Arc<DatabaseConnection>>,
-    config: web::Data<Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
+    let config = config.snapshot();
     let connection_type = path.into_inner();
-    let template_repo =
-        QueryTemplateRepository::new(db_pool.get_ref().clone(), config.get_ref().clone());
+    let template_repo = QueryTemplateRepository::new(db_pool.get_ref().clone(), config);
 
     // Get the templates for this connection type including common templates
     let templates = template_repo
@@ -68,11 +68,11 @@ pub async fn list_templates_by_type(
 pub async fn get_template(
     path: web::Path<String>,
     db_pool: web::Data<Arc<DatabaseConnection>>,
-    config: web::Data<Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
-    let template_repo =
-        QueryTemplateRepository::new(db_pool.get_ref().clone(), config.get_ref().clone());
+    let config = config.snapshot();
+    let template_repo = QueryTemplateRepository::new(db_pool.get_ref().clone(), config);
 
     // Improved error handling for UUID parsing
     let id_str = path.into_inner();
@@ -98,11 +98,11 @@ pub async fn get_template(
 pub async fn create_template(
     template: web::Json<CreateQueryTemplateRequest>,
     db_pool: web::Data<Arc<DatabaseConnection>>,
-    config: web::Data<Config>,
+    config: web::Data<SharedConfig>,
     claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
-    let template_repo =
-        QueryTemplateRepository::new(db_pool.get_ref().clone(), config.get_ref().clone());
+    let config = config.snapshot();
+    let template_repo = QueryTemplateRepository::new(db_pool.get_ref().clone(), config);
 
     // Create the template
     let user_id = uuid::Uuid::parse_str(&claims.sub)
@@ -117,11 +117,11 @@ pub async fn update_template(
     path: web::Path<String>,
     template: web::Json<UpdateQueryTemplateRequest>,
     db_pool: web::Data<Arc<DatabaseConnection>>,
-    config: web::Data<Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
-    let template_repo =
-        QueryTemplateRepository::new(db_pool.get_ref().clone(), config.get_ref().clone());
+    let config = config.snapshot();
+    let template_repo = QueryTemplateRepository::new(db_pool.get_ref().clone(), config);
 
     // Improved error handling for UUID parsing
     let id_str = path.into_inner();
@@ -144,11 +144,11 @@ pub async fn update_template(
 pub async fn delete_template(
     path: web::Path<String>,
     db_pool: web::Data<Arc<DatabaseConnection>>,
-    config: web::Data<Config>,
+    config: web::Data<SharedConfig>,
     _claims: web::ReqData<Claims>,
 ) -> Result<impl Responder, AppError> {
-    let template_repo =
-        QueryTemplateRepository::new(db_pool.get_ref().clone(), config.get_ref().clone());
+    let config = config.snapshot();
+    let template_repo = QueryTemplateRepository::new(db_pool.get_ref().clone(), config);
 
     // Improved error handling for UUID parsing
     let id_str = path.into_inner();
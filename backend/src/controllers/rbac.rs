@@ -13,7 +13,7 @@
 // limitations under the License.
 
 
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use sea_orm::{DatabaseConnection, EntityTrait};
 use std::sync::Arc;
 use tracing::debug;
@@ -22,7 +22,8 @@ use uuid::Uuid;
 use crate::errors::AppError;
 use crate::middleware::auth::Claims;
 use crate::models::cluster::KubernetesClusterConfig;
-use crate::services::kubernetes::rbac_service::RbacService;
+use crate::services::kubernetes::pagination::PaginationQuery;
+use crate::services::kubernetes::rbac_service::{RbacService, RbacSubject};
 use k8s_openapi::api::rbac::v1::{ClusterRole, ClusterRoleBinding, Role, RoleBinding};
 
 async fn get_cluster_config_by_id(
@@ -51,6 +52,7 @@ async fn get_cluster_config_by_id(
             certificate_authority_data: None,
             client_certificate_data: None,
             client_key_data: None,
+            use_in_cluster_config: false,
         })
     } else {
         serde_json::from_value(value)
@@ -63,12 +65,20 @@ pub async fn list_roles_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>,
+    pagination: web::Query<PaginationQuery>,
     svc: web::Data<Arc<RbacService>>,
 ) -> Result<impl Responder, AppError> {
     let (cluster_id, ns) = path.into_inner();
     debug!(target: "mayyam::controllers::rbac", user_id = %claims.username, %cluster_id, %ns, "List Roles");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    let list = svc.list_roles(&cfg, &ns).await?;
+    let list = svc
+        .list_roles(
+            &cfg,
+            &ns,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?;
     Ok(HttpResponse::Ok().json(list))
 }
 
@@ -105,6 +115,7 @@ pub async fn upsert_role_controller(
 
 pub async fn delete_role_controller(
     claims: web::ReqData<Claims>,
+    req: HttpRequest,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String, String)>,
     svc: web::Data<Arc<RbacService>>,
@@ -112,7 +123,8 @@ pub async fn delete_role_controller(
     let (cluster_id, ns, name) = path.into_inner();
     debug!(target: "mayyam::controllers::rbac", user_id = %claims.username, %cluster_id, %ns, %name, "Delete Role");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    svc.delete_role(&cfg, &ns, &name).await?;
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
+    svc.delete_role(&cfg, &ns, &name, &audit_ctx).await?;
     Ok(HttpResponse::Ok().json(serde_json::json!({"deleted": true})))
 }
 
@@ -121,12 +133,20 @@ pub async fn list_role_bindings_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>,
+    pagination: web::Query<PaginationQuery>,
     svc: web::Data<Arc<RbacService>>,
 ) -> Result<impl Responder, AppError> {
     let (cluster_id, ns) = path.into_inner();
     debug!(target: "mayyam::controllers::rbac", user_id = %claims.username, %cluster_id, %ns, "List RoleBindings");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    let list = svc.list_role_bindings(&cfg, &ns).await?;
+    let list = svc
+        .list_role_bindings(
+            &cfg,
+            &ns,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?;
     Ok(HttpResponse::Ok().json(list))
 }
 
@@ -163,6 +183,7 @@ pub async fn upsert_role_binding_controller(
 
 pub async fn delete_role_binding_controller(
     claims: web::ReqData<Claims>,
+    req: HttpRequest,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String, String)>,
     svc: web::Data<Arc<RbacService>>,
@@ -170,7 +191,8 @@ pub async fn delete_role_binding_controller(
     let (cluster_id, ns, name) = path.into_inner();
     debug!(target: "mayyam::controllers::rbac", user_id = %claims.username, %cluster_id, %ns, %name, "Delete RoleBinding");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    svc.delete_role_binding(&cfg, &ns, &name).await?;
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
+    svc.delete_role_binding(&cfg, &ns, &name, &audit_ctx).await?;
     Ok(HttpResponse::Ok().json(serde_json::json!({"deleted": true})))
 }
 
@@ -179,12 +201,19 @@ pub async fn list_cluster_roles_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<String>,
+    pagination: web::Query<PaginationQuery>,
     svc: web::Data<Arc<RbacService>>,
 ) -> Result<impl Responder, AppError> {
     let cluster_id = path.into_inner();
     debug!(target: "mayyam::controllers::rbac", user_id = %claims.username, %cluster_id, "List ClusterRoles");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    let list = svc.list_cluster_roles(&cfg).await?;
+    let list = svc
+        .list_cluster_roles(
+            &cfg,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?;
     Ok(HttpResponse::Ok().json(list))
 }
 
@@ -221,6 +250,7 @@ pub async fn upsert_cluster_role_controller(
 
 pub async fn delete_cluster_role_controller(
     claims: web::ReqData<Claims>,
+    req: HttpRequest,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>,
     svc: web::Data<Arc<RbacService>>,
@@ -228,7 +258,8 @@ pub async fn delete_cluster_role_controller(
     let (cluster_id, name) = path.into_inner();
     debug!(target: "mayyam::controllers::rbac", user_id = %claims.username, %cluster_id, %name, "Delete ClusterRole");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    svc.delete_cluster_role(&cfg, &name).await?;
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
+    svc.delete_cluster_role(&cfg, &name, &audit_ctx).await?;
     Ok(HttpResponse::Ok().json(serde_json::json!({"deleted": true})))
 }
 
@@ -237,12 +268,19 @@ pub async fn list_cluster_role_bindings_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<String>,
+    pagination: web::Query<PaginationQuery>,
     svc: web::Data<Arc<RbacService>>,
 ) -> Result<impl Responder, AppError> {
     let cluster_id = path.into_inner();
     debug!(target: "mayyam::controllers::rbac", user_id = %claims.username, %cluster_id, "List ClusterRoleBindings");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    let list = svc.list_cluster_role_bindings(&cfg).await?;
+    let list = svc
+        .list_cluster_role_bindings(
+            &cfg,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?;
     Ok(HttpResponse::Ok().json(list))
 }
 
@@ -279,6 +317,7 @@ pub async fn upsert_cluster_role_binding_controller(
 
 pub async fn delete_cluster_role_binding_controller(
     claims: web::ReqData<Claims>,
+    req: HttpRequest,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>,
     svc: web::Data<Arc<RbacService>>,
@@ -286,6 +325,23 @@ pub async fn delete_cluster_role_binding_controller(
     let (cluster_id, name) = path.into_inner();
     debug!(target: "mayyam::controllers::rbac", user_id = %claims.username, %cluster_id, %name, "Delete ClusterRoleBinding");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    svc.delete_cluster_role_binding(&cfg, &name).await?;
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
+    svc.delete_cluster_role_binding(&cfg, &name, &audit_ctx).await?;
     Ok(HttpResponse::Ok().json(serde_json::json!({"deleted": true})))
 }
+
+// Subject permissions
+pub async fn subject_permissions_controller(
+    claims: web::ReqData<Claims>,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<String>,
+    body: web::Json<RbacSubject>,
+    svc: web::Data<Arc<RbacService>>,
+) -> Result<impl Responder, AppError> {
+    let cluster_id = path.into_inner();
+    let subject = body.into_inner();
+    debug!(target: "mayyam::controllers::rbac", user_id = %claims.username, %cluster_id, ?subject, "Get subject permissions");
+    let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let summary = svc.get_subject_permissions(&cfg, subject).await?;
+    Ok(HttpResponse::Ok().json(summary))
+}
@@ -15,6 +15,7 @@
 use crate::errors::AppError;
 use crate::middleware::auth::Claims;
 use crate::controllers::kubernetes::get_cluster_config_by_id;
+use crate::services::kubernetes::pagination::PaginationQuery;
 use crate::services::kubernetes::replica_sets_service::ReplicaSetsService;
 use actix_web::{web, HttpResponse, Responder};
 use sea_orm::DatabaseConnection;
@@ -25,12 +26,20 @@ pub async fn list_replica_sets_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>, // (cluster_id, namespace_name)
+    pagination: web::Query<PaginationQuery>,
     rs_service: web::Data<Arc<ReplicaSetsService>>,
 ) -> Result<impl Responder, AppError> {
     let (cluster_id, namespace_name) = path.into_inner();
     debug!(target: "mayyam::controllers::replica_sets", user_id = %claims.username, %cluster_id, %namespace_name, "Attempting to list ReplicaSets");
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    let replica_sets = rs_service.list_replica_sets(&cluster_config, &namespace_name).await?;
+    let replica_sets = rs_service
+        .list_replica_sets(
+            &cluster_config,
+            &namespace_name,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?;
     Ok(HttpResponse::Ok().json(replica_sets))
 }
 
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use sea_orm::{DatabaseConnection, EntityTrait};
 use std::sync::Arc;
 use tracing::debug;
@@ -22,6 +22,9 @@ use uuid::Uuid;
 use crate::errors::AppError;
 use crate::middleware::auth::Claims;
 use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::secret_rotation_service::{
+    RestartStrategy, SecretRotationService,
+};
 use crate::services::kubernetes::secrets_service::SecretsService;
 
 async fn get_cluster_config_by_id(
@@ -50,6 +53,7 @@ async fn get_cluster_config_by_id(
             certificate_authority_data: None,
             client_certificate_data: None,
             client_key_data: None,
+            use_in_cluster_config: false,
         })
     } else {
         serde_json::from_value(value)
@@ -69,7 +73,7 @@ pub async fn list_secrets_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String)>, // (cluster_id, namespace)
-    query: web::Query<std::collections::HashMap<String, String>>, // labelSelector, fieldSelector, limit, continue
+    query: web::Query<std::collections::HashMap<String, String>>, // labelSelector, fieldSelector, page_size, continue_token
     svc: web::Data<Arc<SecretsService>>,
 ) -> Result<impl Responder, AppError> {
     let (cluster_id, ns) = path.into_inner();
@@ -77,15 +81,22 @@ pub async fn list_secrets_controller(
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
     let label_selector = query.get("labelSelector").cloned();
     let field_selector = query.get("fieldSelector").cloned();
-    let limit = query.get("limit").and_then(|s| s.parse::<u32>().ok());
-    let continue_token = query.get("continue").cloned();
+    // `limit` and `continue` are the older names for these parameters
+    let page_size = query
+        .get("page_size")
+        .or_else(|| query.get("limit"))
+        .and_then(|s| s.parse::<usize>().ok());
+    let continue_token = query
+        .get("continue_token")
+        .or_else(|| query.get("continue"))
+        .cloned();
     let list = svc
         .list(
             &cfg,
             &ns,
             label_selector,
             field_selector,
-            limit,
+            page_size,
             continue_token,
         )
         .await?;
@@ -132,6 +143,7 @@ pub async fn upsert_secret_plaintext_controller(
 
 pub async fn delete_secret_controller(
     claims: web::ReqData<Claims>,
+    req: HttpRequest,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<(String, String, String)>, // (cluster_id, namespace, name)
     svc: web::Data<Arc<SecretsService>>,
@@ -139,6 +151,47 @@ pub async fn delete_secret_controller(
     let (cluster_id, ns, name) = path.into_inner();
     debug!(target: "mayyam::controllers::secrets", user_id = %claims.username, %cluster_id, %ns, %name, "Delete Secret");
     let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    svc.delete(&cfg, &ns, &name).await?;
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
+    svc.delete(&cfg, &ns, &name, &audit_ctx).await?;
     Ok(HttpResponse::Ok().json(serde_json::json!({"deleted": true})))
 }
+
+#[derive(serde::Deserialize)]
+pub struct RotateSecretRequest {
+    pub key: String,
+    // Plaintext; base64-encoded before it is stored, like the upsert endpoint
+    pub new_value: String,
+    // Defaults to a rolling restart
+    pub restart_strategy: Option<RestartStrategy>,
+}
+
+pub async fn rotate_secret_controller(
+    claims: web::ReqData<Claims>,
+    req: HttpRequest,
+    db: web::Data<Arc<DatabaseConnection>>,
+    path: web::Path<(String, String, String)>, // (cluster_id, namespace, name)
+    body: web::Json<RotateSecretRequest>,
+    svc: web::Data<Arc<SecretRotationService>>,
+) -> Result<impl Responder, AppError> {
+    claims.require_admin()?;
+    let (cluster_id, ns, name) = path.into_inner();
+    let body = body.into_inner();
+    let strategy = body
+        .restart_strategy
+        .unwrap_or(RestartStrategy::RollingRestart);
+    debug!(target: "mayyam::controllers::secrets", user_id = %claims.username, %cluster_id, %ns, %name, key = %body.key, ?strategy, "Rotate Secret");
+    let cfg = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
+    let audit_ctx = claims.audit_context(&req, Some(&cluster_id));
+    let result = svc
+        .rotate_secret(
+            &cfg,
+            &ns,
+            &name,
+            &body.key,
+            body.new_value.as_bytes(),
+            strategy,
+            &audit_ctx,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
@@ -15,6 +15,7 @@
 use crate::errors::AppError;
 use crate::middleware::auth::Claims;
 use crate::controllers::kubernetes::get_cluster_config_by_id;
+use crate::services::kubernetes::pagination::PaginationQuery;
 use crate::services::kubernetes::storage_classes_service::StorageClassesService;
 use actix_web::{web, HttpResponse, Responder};
 use sea_orm::DatabaseConnection;
@@ -25,12 +26,19 @@ pub async fn list_storage_classes_controller(
     claims: web::ReqData<Claims>,
     db: web::Data<Arc<DatabaseConnection>>,
     path: web::Path<String>, // cluster_id
+    pagination: web::Query<PaginationQuery>,
     sc_service: web::Data<Arc<StorageClassesService>>,
 ) -> Result<impl Responder, AppError> {
     let cluster_id = path.into_inner();
     debug!(target: "mayyam::controllers::storage_classes", user_id = %claims.username, %cluster_id, "Attempting to list StorageClasses");
     let cluster_config = get_cluster_config_by_id(db.get_ref().as_ref(), &cluster_id).await?;
-    let storage_classes = sc_service.list_storage_classes(&cluster_config).await?;
+    let storage_classes = sc_service
+        .list_storage_classes(
+            &cluster_config,
+            pagination.page_size,
+            pagination.continue_token.clone(),
+        )
+        .await?;
     Ok(HttpResponse::Ok().json(storage_classes))
 }
 
@@ -0,0 +1,40 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use actix_web::{web, HttpResponse, Responder};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::middleware::auth::Claims;
+use crate::services::task_queue::TaskQueue;
+
+pub async fn get_task(
+    path: web::Path<Uuid>,
+    task_queue: web::Data<Arc<TaskQueue>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let task = task_queue.get(path.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(task))
+}
+
+pub async fn cancel_task(
+    path: web::Path<Uuid>,
+    task_queue: web::Data<Arc<TaskQueue>>,
+    _claims: web::ReqData<Claims>,
+) -> Result<impl Responder, AppError> {
+    let task = task_queue.cancel(path.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(task))
+}
@@ -167,6 +167,12 @@ impl UnifiedLlmController {
         })))
     }
 
+    /// Run a test completion against every provider
+    pub async fn health_check(&self) -> Result<HttpResponse> {
+        let statuses = self.llm_manager.health_check_all_providers().await;
+        Ok(HttpResponse::Ok().json(statuses))
+    }
+
     /// Get provider capabilities
     pub async fn get_provider_capabilities(&self, path: web::Path<String>) -> Result<HttpResponse> {
         let provider_name = path.into_inner();
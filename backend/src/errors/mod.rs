@@ -24,6 +24,9 @@ pub enum AppError {
     #[error("Authentication error: {0}")]
     Auth(String),
 
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
     #[error("Database error: {0}")]
     Database(#[from] DbErr),
 
@@ -81,6 +84,7 @@ impl AppError {
     pub fn error_type(&self) -> &'static str {
         match self {
             AppError::Auth(_) => "AUTH_ERROR",
+            AppError::Forbidden(_) => "FORBIDDEN",
             AppError::Database(_) => "DATABASE_ERROR",
             AppError::Validation(_) => "VALIDATION_ERROR",
             AppError::NotFound(_) => "NOT_FOUND",
@@ -106,6 +110,7 @@ impl ResponseError for AppError {
     fn error_response(&self) -> HttpResponse {
         match self {
             AppError::Auth(_) => HttpResponse::Unauthorized().json(ErrorResponse::new(self)),
+            AppError::Forbidden(_) => HttpResponse::Forbidden().json(ErrorResponse::new(self)),
             AppError::Validation(_) | AppError::Config(_) | AppError::BadRequest(_) => {
                 HttpResponse::BadRequest().json(ErrorResponse::new(self))
             }
@@ -126,6 +131,7 @@ impl ErrorResponse {
     fn new(error: &AppError) -> Self {
         let error_type = match error {
             AppError::Auth(_) => "AUTH_ERROR",
+            AppError::Forbidden(_) => "FORBIDDEN",
             AppError::Database(_) => "DATABASE_ERROR",
             AppError::Validation(_) => "VALIDATION_ERROR",
             AppError::NotFound(_) => "NOT_FOUND",
@@ -14,8 +14,9 @@
 
 
 use clap::{Parser, Subcommand};
-use mayyam::{api, cli, config, utils};
+use mayyam::{api, cli, config, services, utils};
 use std::error::Error;
+use std::sync::Arc;
 
 #[derive(Parser)]
 #[command(name = "mayyam")]
@@ -50,6 +51,12 @@ enum Commands {
         command: cli::kafka::KafkaCommands,
     },
 
+    /// GCP Pub/Sub operations
+    Pubsub {
+        #[command(subcommand)]
+        command: cli::pubsub::PubSubCommands,
+    },
+
     /// Cloud provider operations
     Cloud {
         #[command(subcommand)]
@@ -71,11 +78,22 @@ enum Commands {
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
-    // Initialize logging
-    utils::logging::init_logger();
+    let mut config = config::load_config()?;
 
-    // Load configuration
-    let config = config::load_config()?;
+    // Initialize logging, exporting spans when an OTLP endpoint is configured. Done before
+    // secrets are resolved so their log lines are not lost.
+    utils::logging::init_logger(config.otel_endpoint.as_deref());
+
+    // Replace "sm://" values with secrets read from AWS Secrets Manager
+    let secret_provider =
+        if services::aws::SecretsManagerCredentialProvider::has_references(&config) {
+            let provider =
+                services::aws::SecretsManagerCredentialProvider::new(&config.security).await;
+            provider.resolve_config(&mut config).await?;
+            Some(Arc::new(provider))
+        } else {
+            None
+        };
 
     // Parse command line arguments
     let cli = Cli::parse();
@@ -83,7 +101,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     match cli.command {
         Commands::Server { port, host } => {
             // Start web server
-            api::server::run_server(host, port, config).await?;
+            api::server::run_server(host, port, config, secret_provider).await?;
         }
         Commands::Db { command } => {
             // Handle database commands
@@ -93,6 +111,10 @@ async fn main() -> Result<(), Box<dyn Error>> {
             // Handle Kafka commands
             cli::kafka::handle_command(command, &config).await?;
         }
+        Commands::Pubsub { command } => {
+            // Handle GCP Pub/Sub commands
+            cli::pubsub::handle_command(command, &config).await?;
+        }
         Commands::Cloud { command } => {
             // Handle cloud provider commands
             cli::cloud::handle_command(command, &config).await?;
@@ -107,5 +129,6 @@ async fn main() -> Result<(), Box<dyn Error>> {
         }
     }
 
+    utils::logging::shutdown_tracing();
     Ok(())
 }
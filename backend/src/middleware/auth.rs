@@ -16,7 +16,7 @@
 use actix_web::{
     dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
     error::Error,
-    HttpMessage,
+    HttpMessage, HttpRequest,
 };
 use chrono::{Duration, Utc};
 use futures_util::future::{ready, LocalBoxFuture, Ready};
@@ -24,8 +24,9 @@ use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header,
 use serde::{Deserialize, Serialize};
 use tracing::error;
 
-use crate::config::Config;
+use crate::config::SharedConfig;
 use crate::errors::AppError;
+use crate::models::audit_event::AuditContext;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Claims {
@@ -37,19 +38,44 @@ pub struct Claims {
     pub iat: i64,
 }
 
+impl Claims {
+    // Attribute an audited operation to this user and the caller's address
+    pub fn audit_context(&self, req: &HttpRequest, cluster: Option<&str>) -> AuditContext {
+        AuditContext::new(
+            Some(self.username.clone()),
+            req.connection_info().realip_remote_addr().map(String::from),
+            cluster.map(String::from),
+        )
+    }
+
+    // Authenticated but lacking the role is a 403, not a 401
+    pub fn require_admin(&self) -> Result<(), AppError> {
+        if self.roles.iter().any(|r| r == "admin") {
+            Ok(())
+        } else {
+            Err(AppError::Forbidden(
+                "Admin role required for this action".to_string(),
+            ))
+        }
+    }
+}
+
+// The JWT secret is read from the shared config on every request, so a rotated secret takes
+// effect without a restart
 pub struct AuthMiddleware {
-    jwt_secret: String,
+    config: SharedConfig,
     public_paths: Vec<String>,
 }
 
 impl AuthMiddleware {
-    pub fn new(config: &Config) -> Self {
+    pub fn new(config: &SharedConfig) -> Self {
         Self {
-            jwt_secret: config.auth.jwt_secret.clone(),
+            config: config.clone(),
             public_paths: vec![
                 "/health".to_string(),
                 "/api/auth/login".to_string(),
                 "/api/auth/register".to_string(),
+                "/api/auth/oidc/".to_string(),
             ],
         }
     }
@@ -70,7 +96,7 @@ where
     fn new_transform(&self, service: S) -> Self::Future {
         ready(Ok(AuthMiddlewareService {
             service,
-            jwt_secret: self.jwt_secret.clone(),
+            config: self.config.clone(),
             public_paths: self.public_paths.clone(),
         }))
     }
@@ -78,7 +104,7 @@ where
 
 pub struct AuthMiddlewareService<S> {
     service: S,
-    jwt_secret: String,
+    config: SharedConfig,
     public_paths: Vec<String>,
 }
 
@@ -124,19 +150,19 @@ where
                     let token = auth_str[7..].to_string(); // Remove "Bearer " prefix
 
                     // Validate JWT token
-                    let token_data = match decode::<Claims>(
-                        &token,
-                        &DecodingKey::from_secret(self.jwt_secret.as_bytes()),
-                        &Validation::default(),
-                    ) {
-                        Ok(data) => data,
-                        Err(err) => {
-                            error!("JWT validation error for path {}: {:?}", path, err);
-                            return Box::pin(async move {
-                                Err(AppError::Auth(format!("Invalid token: {}", err)).into())
-                            });
-                        }
-                    };
+                    let decoding_key = self
+                        .config
+                        .read(|c| DecodingKey::from_secret(c.auth.jwt_secret.as_bytes()));
+                    let token_data =
+                        match decode::<Claims>(&token, &decoding_key, &Validation::default()) {
+                            Ok(data) => data,
+                            Err(err) => {
+                                error!("JWT validation error for path {}: {:?}", path, err);
+                                return Box::pin(async move {
+                                    Err(AppError::Auth(format!("Invalid token: {}", err)).into())
+                                });
+                            }
+                        };
 
                     // Check token expiration
                     let now = Utc::now().timestamp();
@@ -0,0 +1,107 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use actix_web::{
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    error::Error,
+    http::header::{HeaderName, HeaderValue},
+    HttpMessage,
+};
+use futures_util::future::{ready, LocalBoxFuture, Ready};
+use tracing::Instrument;
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+// Longer caller-supplied IDs are replaced rather than copied into every span
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+// Correlation ID of the current request, available to handlers as
+// `web::ReqData<CorrelationId>`
+#[derive(Debug, Clone)]
+pub struct CorrelationId(pub String);
+
+// Runs each request inside an `http_request` span carrying a `correlation_id`
+// taken from the X-Request-ID header (or generated), so spans opened by
+// services during the request are tied to it. The ID is echoed back on the
+// response.
+pub struct RequestIdMiddleware;
+
+impl<S, B> Transform<S, ServiceRequest> for RequestIdMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = RequestIdMiddlewareService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequestIdMiddlewareService { service }))
+    }
+}
+
+pub struct RequestIdMiddlewareService<S> {
+    service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestIdMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let correlation_id = req
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::trim)
+            .filter(|value| !value.is_empty() && value.len() <= MAX_REQUEST_ID_LEN)
+            .map(String::from)
+            .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+        req.extensions_mut()
+            .insert(CorrelationId(correlation_id.clone()));
+
+        let span = tracing::info_span!(
+            "http_request",
+            correlation_id = %correlation_id,
+            method = %req.method(),
+            path = %req.path(),
+        );
+        let fut = span.in_scope(|| self.service.call(req));
+
+        Box::pin(
+            async move {
+                let mut res = fut.await?;
+                if let Ok(value) = HeaderValue::from_str(&correlation_id) {
+                    res.headers_mut()
+                        .insert(HeaderName::from_static(REQUEST_ID_HEADER), value);
+                }
+                Ok(res)
+            }
+            .instrument(span),
+        )
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "audit_events")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    #[sea_orm(column_type = "TimestampWithTimeZone")]
+    pub timestamp: DateTime<Utc>,
+    pub user_id: Option<String>,
+    pub operation: String,
+    pub resource_type: String,
+    pub resource_id: String,
+    pub namespace: Option<String>,
+    pub cluster: Option<String>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub payload: serde_json::Value,
+    pub ip_address: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditOperation;
+
+impl AuditOperation {
+    pub const DELETE: &'static str = "delete";
+    pub const SCALE: &'static str = "scale";
+    pub const RESTART: &'static str = "restart";
+    pub const DELETE_PODS: &'static str = "delete_pods";
+    pub const RESET_OFFSETS: &'static str = "reset_offsets";
+    pub const ROTATE: &'static str = "rotate";
+    pub const CREATE: &'static str = "create";
+    pub const UPDATE: &'static str = "update";
+    pub const BLOCK_IP: &'static str = "block_ip";
+}
+
+// Request-scoped information about who triggered an operation and where
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuditContext {
+    pub user_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub cluster: Option<String>,
+}
+
+impl AuditContext {
+    pub fn new(user_id: Option<String>, ip_address: Option<String>, cluster: Option<String>) -> Self {
+        Self {
+            user_id,
+            ip_address,
+            cluster,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub timestamp: DateTime<Utc>,
+    pub user_id: Option<String>,
+    pub operation: String,
+    pub resource_type: String,
+    pub resource_id: String,
+    pub namespace: Option<String>,
+    pub cluster: Option<String>,
+    pub payload: serde_json::Value,
+    pub ip_address: Option<String>,
+}
+
+impl AuditEvent {
+    pub fn new(
+        ctx: &AuditContext,
+        operation: &str,
+        resource_type: &str,
+        resource_id: &str,
+    ) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            user_id: ctx.user_id.clone(),
+            operation: operation.to_string(),
+            resource_type: resource_type.to_string(),
+            resource_id: resource_id.to_string(),
+            namespace: None,
+            cluster: ctx.cluster.clone(),
+            payload: serde_json::json!({}),
+            ip_address: ctx.ip_address.clone(),
+        }
+    }
+
+    pub fn with_namespace(mut self, namespace: &str) -> Self {
+        self.namespace = Some(namespace.to_string());
+        self
+    }
+
+    pub fn with_payload(mut self, payload: serde_json::Value) -> Self {
+        self.payload = payload;
+        self
+    }
+}
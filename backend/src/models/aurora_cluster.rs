@@ -84,4 +84,14 @@ impl From<Model> for AuroraClusterDto {
             last_event_timestamp: model.last_event_timestamp,
         }
     }
-}
\ No newline at end of file
+}
+// Target of an Aurora failover simulation. `account_id` selects the registered AWS
+// account whose credentials are used.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuroraClusterConfig {
+    pub cluster_identifier: String,
+    pub region: String,
+    pub account_id: String,
+    // Reader to promote; Aurora picks one when unset
+    pub target_instance: Option<String>,
+}
@@ -19,6 +19,8 @@ use sea_orm::ActiveValue::Set;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::config::AnomalyDetectionConfig;
+
 /// SeaORM entity definition for the AWS accounts table
 /// This maps directly to the database schema
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
@@ -52,6 +54,8 @@ pub struct Model {
     pub web_identity_token_file: Option<String>,
     #[sea_orm(nullable)]
     pub session_name: Option<String>,
+    #[sea_orm(nullable, column_type = "Json")]
+    pub anomaly_detection_config: Option<serde_json::Value>,
     #[sea_orm(nullable)]
     pub last_synced_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
@@ -85,6 +89,8 @@ pub struct DomainModel {
     pub sso_profile: Option<String>,
     pub web_identity_token_file: Option<String>,
     pub session_name: Option<String>,
+    // Overrides the global cost anomaly detection thresholds for this account
+    pub anomaly_detection_config: Option<AnomalyDetectionConfig>,
     pub last_synced_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -110,6 +116,8 @@ pub struct AwsAccountCreateDto {
     pub sso_profile: Option<String>,
     pub web_identity_token_file: Option<String>,
     pub session_name: Option<String>,
+    #[serde(default)]
+    pub anomaly_detection_config: Option<AnomalyDetectionConfig>,
 }
 
 /// DTO for updating an existing AWS account
@@ -131,6 +139,8 @@ pub struct AwsAccountUpdateDto {
     pub sso_profile: Option<String>,
     pub web_identity_token_file: Option<String>,
     pub session_name: Option<String>,
+    #[serde(default)]
+    pub anomaly_detection_config: Option<AnomalyDetectionConfig>,
 }
 
 /// DTO for returning account information (without sensitive data)
@@ -155,6 +165,7 @@ pub struct AwsAccountDto {
     pub sso_profile: Option<String>,
     pub web_identity_token_file: Option<String>,
     pub session_name: Option<String>,
+    pub anomaly_detection_config: Option<AnomalyDetectionConfig>,
     pub last_synced_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -191,6 +202,9 @@ impl From<Model> for DomainModel {
             sso_profile: entity.sso_profile,
             web_identity_token_file: entity.web_identity_token_file,
             session_name: entity.session_name,
+            anomaly_detection_config: entity
+                .anomaly_detection_config
+                .and_then(|c| serde_json::from_value(c).ok()),
             last_synced_at: entity.last_synced_at,
             created_at: entity.created_at,
             updated_at: entity.updated_at,
@@ -220,6 +234,7 @@ impl From<DomainModel> for AwsAccountDto {
             sso_profile: model.sso_profile,
             web_identity_token_file: model.web_identity_token_file,
             session_name: model.session_name,
+            anomaly_detection_config: model.anomaly_detection_config,
             last_synced_at: model.last_synced_at,
             created_at: model.created_at,
             updated_at: model.updated_at,
@@ -252,6 +267,9 @@ impl From<AwsAccountCreateDto> for ActiveModel {
             sso_profile: Set(dto.sso_profile),
             web_identity_token_file: Set(dto.web_identity_token_file),
             session_name: Set(dto.session_name),
+            anomaly_detection_config: Set(dto
+                .anomaly_detection_config
+                .and_then(|c| serde_json::to_value(c).ok())),
             last_synced_at: Set(None),
             created_at: Set(now),
             updated_at: Set(now),
@@ -294,6 +312,9 @@ impl From<(AwsAccountUpdateDto, Option<String>, Uuid)> for ActiveModel {
             sso_profile: Set(dto.sso_profile),
             web_identity_token_file: Set(dto.web_identity_token_file),
             session_name: Set(dto.session_name),
+            anomaly_detection_config: Set(dto
+                .anomaly_detection_config
+                .and_then(|c| serde_json::to_value(c).ok())),
             last_synced_at: sea_orm::ActiveValue::NotSet,
             created_at: sea_orm::ActiveValue::NotSet,
             updated_at: Set(now),
@@ -322,6 +343,7 @@ impl AwsAccountDto {
             sso_profile: None,
             web_identity_token_file: None,
             session_name: None,
+            anomaly_detection_config: None,
             last_synced_at: None,
             created_at: now,
             updated_at: now,
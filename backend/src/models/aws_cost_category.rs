@@ -0,0 +1,63 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "aws_cost_categories")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub account_id: String,
+    pub category_arn: String,
+    pub name: String,
+    pub effective_start: Option<String>,
+    pub number_of_rules: i32,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub category_values: serde_json::Value,
+    pub default_value: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AwsCostCategory {
+    pub category_arn: String,
+    pub name: String,
+    pub effective_start: Option<String>,
+    pub number_of_rules: i32,
+    pub values: Vec<String>,
+    pub default_value: Option<String>,
+}
+
+impl From<Model> for AwsCostCategory {
+    fn from(model: Model) -> Self {
+        Self {
+            category_arn: model.category_arn,
+            name: model.name,
+            effective_start: model.effective_start,
+            number_of_rules: model.number_of_rules,
+            values: serde_json::from_value(model.category_values).unwrap_or_default(),
+            default_value: model.default_value,
+        }
+    }
+}
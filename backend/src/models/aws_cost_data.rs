@@ -34,6 +34,8 @@ pub struct Model {
     pub usage_unit: Option<String>,
     pub currency: String,
     pub tags: Option<Json>,
+    // Set when the row comes from a breakdown by this cost allocation tag
+    pub tag_key: Option<String>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
@@ -60,6 +62,7 @@ pub struct CostDataModel {
     pub usage_unit: Option<String>,
     pub currency: String,
     pub tags: Option<serde_json::Value>,
+    pub tag_key: Option<String>,
 }
 
 impl From<Model> for CostDataModel {
@@ -81,6 +84,7 @@ impl From<Model> for CostDataModel {
             usage_unit: model.usage_unit,
             currency: model.currency,
             tags: model.tags.map(|j| j.into()),
+            tag_key: model.tag_key,
         }
     }
 }
@@ -16,6 +16,7 @@
 use chrono::{DateTime, Utc};
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
@@ -52,6 +53,7 @@ impl ActiveModelBehavior for ActiveModel {}
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum AwsResourceType {
     EC2Instance,
+    AutoScalingGroup,
     S3Bucket,
     RdsInstance,
     DynamoDbTable,
@@ -79,6 +81,7 @@ pub enum AwsResourceType {
     Nlb,
     Elb,
     CloudFrontDistribution,
+    Route53Record,
     ApiGatewayRestApi,
     ApiGatewayStage,
     ApiGatewayResource,
@@ -117,6 +120,7 @@ pub enum AwsResourceType {
     EmrCluster,
     AthenaWorkgroup,
     GlueDatabase,
+    GlueTable,
     KinesisAnalyticsApp,
     // Edge Computing Resources
     WafWebAcl,
@@ -131,6 +135,7 @@ impl ToString for AwsResourceType {
     fn to_string(&self) -> String {
         match self {
             AwsResourceType::EC2Instance => "EC2Instance".to_string(),
+            AwsResourceType::AutoScalingGroup => "AutoScalingGroup".to_string(),
             AwsResourceType::S3Bucket => "S3Bucket".to_string(),
             AwsResourceType::RdsInstance => "RdsInstance".to_string(),
             AwsResourceType::DynamoDbTable => "DynamoDbTable".to_string(),
@@ -158,6 +163,7 @@ impl ToString for AwsResourceType {
             AwsResourceType::Nlb => "Nlb".to_string(),
             AwsResourceType::Elb => "Elb".to_string(),
             AwsResourceType::CloudFrontDistribution => "CloudFrontDistribution".to_string(),
+            AwsResourceType::Route53Record => "Route53Record".to_string(),
             AwsResourceType::ApiGatewayRestApi => "ApiGatewayRestApi".to_string(),
             AwsResourceType::ApiGatewayStage => "ApiGatewayStage".to_string(),
             AwsResourceType::ApiGatewayResource => "ApiGatewayResource".to_string(),
@@ -196,6 +202,7 @@ impl ToString for AwsResourceType {
             AwsResourceType::EmrCluster => "EmrCluster".to_string(),
             AwsResourceType::AthenaWorkgroup => "AthenaWorkgroup".to_string(),
             AwsResourceType::GlueDatabase => "GlueDatabase".to_string(),
+            AwsResourceType::GlueTable => "GlueTable".to_string(),
             AwsResourceType::KinesisAnalyticsApp => "KinesisAnalyticsApp".to_string(),
             // Edge Computing Resources
             AwsResourceType::WafWebAcl => "WafWebAcl".to_string(),
@@ -212,6 +219,7 @@ impl From<&str> for AwsResourceType {
     fn from(s: &str) -> Self {
         match s {
             "EC2Instance" => AwsResourceType::EC2Instance,
+            "AutoScalingGroup" => AwsResourceType::AutoScalingGroup,
             "S3Bucket" => AwsResourceType::S3Bucket,
             "RdsInstance" => AwsResourceType::RdsInstance,
             "DynamoDbTable" => AwsResourceType::DynamoDbTable,
@@ -239,6 +247,7 @@ impl From<&str> for AwsResourceType {
             "Nlb" => AwsResourceType::Nlb,
             "Elb" => AwsResourceType::Elb,
             "CloudFrontDistribution" => AwsResourceType::CloudFrontDistribution,
+            "Route53Record" => AwsResourceType::Route53Record,
             "ApiGatewayRestApi" => AwsResourceType::ApiGatewayRestApi,
             "ApiGatewayStage" => AwsResourceType::ApiGatewayStage,
             "ApiGatewayResource" => AwsResourceType::ApiGatewayResource,
@@ -277,6 +286,7 @@ impl From<&str> for AwsResourceType {
             "EmrCluster" => AwsResourceType::EmrCluster,
             "AthenaWorkgroup" => AwsResourceType::AthenaWorkgroup,
             "GlueDatabase" => AwsResourceType::GlueDatabase,
+            "GlueTable" => AwsResourceType::GlueTable,
             "KinesisAnalyticsApp" => AwsResourceType::KinesisAnalyticsApp,
             // Edge Computing Resources
             "WafWebAcl" => AwsResourceType::WafWebAcl,
@@ -321,9 +331,29 @@ pub struct AwsResourceQuery {
     pub page_size: Option<u64>,
 }
 
+// Ranked search across every synced resource type. `q` is matched against the
+// name (full text), resource id, ARN and tag values; tag_filter must be contained in tags.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AwsResourcePage {
-    pub resources: Vec<Model>,
+pub struct ResourceSearchQuery {
+    pub q: String,
+    pub resource_types: Option<Vec<String>>,
+    pub account_ids: Option<Vec<String>>,
+    pub regions: Option<Vec<String>>,
+    pub tag_filter: Option<HashMap<String, String>>,
+    pub page: usize,
+    pub page_size: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoredAwsResource {
+    #[serde(flatten)]
+    pub resource: Model,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AwsResourcePage<T = Model> {
+    pub resources: Vec<T>,
     pub total: u64,
     pub page: u64,
     pub page_size: u64,
@@ -64,6 +64,7 @@ impl ChaosAuditAction {
     pub const RUN_FAILED: &'static str = "run_failed";
     pub const RUN_STOPPED: &'static str = "run_stopped";
     pub const RUN_TIMED_OUT: &'static str = "run_timed_out";
+    pub const AUTO_TRIGGERED: &'static str = "auto_triggered";
 
     pub const ROLLBACK_STARTED: &'static str = "rollback_started";
     pub const ROLLBACK_COMPLETED: &'static str = "rollback_completed";
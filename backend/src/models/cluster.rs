@@ -47,6 +47,21 @@ pub struct KafkaClusterConfig {
     pub sasl_password: Option<String>,
     pub sasl_mechanism: Option<String>,
     pub security_protocol: String,
+    #[serde(default)]
+    pub schema_registry_url: Option<String>,
+}
+
+impl From<&crate::config::KafkaClusterConfig> for KafkaClusterConfig {
+    fn from(c: &crate::config::KafkaClusterConfig) -> Self {
+        Self {
+            bootstrap_servers: c.bootstrap_servers.clone(),
+            sasl_username: c.sasl_username.clone(),
+            sasl_password: c.sasl_password.clone(),
+            sasl_mechanism: c.sasl_mechanism.clone(),
+            security_protocol: c.security_protocol.clone(),
+            schema_registry_url: c.schema_registry_url.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -57,6 +72,8 @@ pub struct CreateKafkaClusterRequest {
     pub sasl_password: Option<String>,
     pub sasl_mechanism: Option<String>,
     pub security_protocol: String,
+    #[serde(default)]
+    pub schema_registry_url: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -73,10 +90,25 @@ pub struct KubernetesClusterConfig {
     pub kube_config_path: Option<String>,
     pub kube_context: Option<String>,
     pub api_server_url: Option<String>,
+    #[serde(alias = "ca_cert_base64")]
     pub certificate_authority_data: Option<String>,
     pub client_certificate_data: Option<String>,
     pub client_key_data: Option<String>,
-    pub token: Option<String>,
+    pub token: Option<String>, // Bearer token, e.g. a service account token; needs api_server_url
+    // Use the pod's own service account (KUBERNETES_SERVICE_HOST + mounted token)
+    #[serde(default)]
+    pub use_in_cluster_config: bool,
+}
+
+impl KubernetesClusterConfig {
+    // The stored config does not carry the cluster's display name, so logs and
+    // trace spans identify the cluster by its API server or kubeconfig context.
+    pub fn trace_name(&self) -> &str {
+        self.api_server_url
+            .as_deref()
+            .or(self.kube_context.as_deref())
+            .unwrap_or("default")
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -85,10 +117,13 @@ pub struct CreateKubernetesClusterRequest {
     pub kube_config_path: Option<String>,
     pub kube_context: Option<String>,
     pub api_server_url: Option<String>,
+    #[serde(alias = "ca_cert_base64")]
     pub certificate_authority_data: Option<String>,
     pub client_certificate_data: Option<String>,
     pub client_key_data: Option<String>,
     pub token: Option<String>,
+    #[serde(default)]
+    pub use_in_cluster_config: bool,
 }
 
 #[derive(Debug, Deserialize, Serialize)] // Added Serialize for potential use in responses
@@ -97,10 +132,13 @@ pub struct UpdateKubernetesClusterRequest {
     pub kube_config_path: Option<String>,
     pub kube_context: Option<String>,
     pub api_server_url: Option<String>,
+    #[serde(alias = "ca_cert_base64")]
     pub certificate_authority_data: Option<String>,
     pub client_certificate_data: Option<String>,
     pub client_key_data: Option<String>,
     pub token: Option<String>,
+    #[serde(default)]
+    pub use_in_cluster_config: bool,
 }
 
 // Cloud provider models
@@ -0,0 +1,60 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "config_violations")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub account_id: String,
+    pub region: String,
+    pub resource_type: String,
+    pub resource_id: String,
+    pub rule_name: String,
+    pub annotation: String,
+    pub evaluation_time: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigViolation {
+    pub resource_type: String,
+    pub resource_id: String,
+    pub rule_name: String,
+    pub annotation: String,
+    pub evaluation_time: DateTime<Utc>,
+}
+
+impl From<Model> for ConfigViolation {
+    fn from(model: Model) -> Self {
+        Self {
+            resource_type: model.resource_type,
+            resource_id: model.resource_id,
+            rule_name: model.rule_name,
+            annotation: model.annotation,
+            evaluation_time: model.evaluation_time,
+        }
+    }
+}
@@ -39,6 +39,8 @@ pub struct Model {
     pub tags: Json, // JSON object for additional metadata
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    // Set when the budget mirrors one in AWS Budgets, which identifies budgets by name
+    pub aws_budget_name: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -151,8 +153,51 @@ impl BudgetDto {
             tags: Set(self.tags),
             created_at: Set(chrono::Utc::now().naive_utc()),
             updated_at: Set(chrono::Utc::now().naive_utc()),
+            aws_budget_name: Set(None),
         }
     }
 }
 
-pub type Budget = Model;
\ No newline at end of file
+pub type Budget = Model;
+
+// Budget created in AWS Budgets. For usage budgets `currency` is the usage unit, e.g. GB.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BudgetDefinition {
+    pub name: String,
+    pub budget_type: AwsBudgetType,
+    pub amount: f64,
+    pub currency: String,
+    pub time_unit: AwsBudgetTimeUnit,
+    #[serde(default)]
+    pub notification_emails: Vec<String>,
+    pub threshold_percent: f64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AwsBudgetType {
+    Cost,
+    Usage,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AwsBudgetTimeUnit {
+    Daily,
+    Monthly,
+    Quarterly,
+    Annually,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AwsBudgetResult {
+    pub budget_id: Uuid,
+    pub aws_account_id: String,
+    pub aws_budget_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AwsBudgetSyncResult {
+    pub created: usize,
+    pub updated: usize,
+}
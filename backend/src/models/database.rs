@@ -198,6 +198,28 @@ pub struct IndexStats {
     pub unused_since: Option<DateTime<Utc>>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct TableBloatInfo {
+    pub schema: String,
+    pub table_name: String,
+    pub live_bytes: u64,
+    pub dead_bytes: u64,
+    pub bloat_ratio: f64,
+    pub last_autovacuum: Option<DateTime<Utc>>,
+    pub vacuum_recommended: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct IndexBloatInfo {
+    pub schema: String,
+    pub table_name: String,
+    pub index_name: String,
+    pub index_bytes: u64,
+    pub estimated_bloat_bytes: u64,
+    pub bloat_ratio: f64,
+    pub reindex_recommended: bool,
+}
+
 #[derive(Debug, Serialize)]
 pub struct CostAnalysis {
     pub storage_cost: ResourceCost,
@@ -0,0 +1,59 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "kafka_topic_throughput_samples")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub cluster_id: String,
+    pub topic_name: String,
+    pub partition: i32,
+    pub timestamp: DateTime<Utc>,
+    pub messages_per_sec: f64,
+    pub bytes_per_sec_estimate: f64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KafkaTopicThroughputSample {
+    pub topic_name: String,
+    pub partition: i32,
+    pub cluster_id: String,
+    pub timestamp: DateTime<Utc>,
+    pub messages_per_sec: f64,
+    pub bytes_per_sec_estimate: f64,
+}
+
+impl From<Model> for KafkaTopicThroughputSample {
+    fn from(model: Model) -> Self {
+        Self {
+            topic_name: model.topic_name,
+            partition: model.partition,
+            cluster_id: model.cluster_id,
+            timestamp: model.timestamp,
+            messages_per_sec: model.messages_per_sec,
+            bytes_per_sec_estimate: model.bytes_per_sec_estimate,
+        }
+    }
+}
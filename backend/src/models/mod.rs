@@ -35,6 +35,8 @@ pub mod aws_cost_anomalies;
 pub mod aws_cost_data;
 pub mod aws_cost_insights;
 pub mod aws_monthly_cost_aggregates;
+pub mod aws_rightsizing_recommendations;
+pub mod aws_savings_plan_recommendations;
 pub mod cost_budget;
 
 // MySQL Performance Analysis models
@@ -54,6 +56,18 @@ pub mod chaos_audit_log;
 pub mod chaos_metrics;
 pub mod chaos_metrics_aggregates;
 
+// Audit trail for destructive operations
+pub mod audit_event;
+
+// Background task queue
+pub mod async_task;
+pub mod llm_session;
+pub mod alert_firing;
+pub mod kafka_topic_throughput;
+pub mod security_finding;
+pub mod config_violation;
+pub mod aws_cost_category;
+
 // Models module for data structures
 
 pub use analytics::{Insight, InsightSeverity, Recommendation, RecommendationPriority};
@@ -49,6 +49,9 @@ pub struct Model {
     pub temp_tables_disk: i64,
     pub temp_tables_memory: i64,
 
+    // Full table scans (Handler_read_rnd_next)
+    pub handler_read_rnd_next: i64,
+
     // Replication
     pub replication_lag: Option<f64>, // seconds
 
@@ -88,6 +91,7 @@ pub struct MySQLPerformanceSnapshotDto {
     pub innodb_flushes: i64,
     pub temp_tables_disk: i64,
     pub temp_tables_memory: i64,
+    pub handler_read_rnd_next: i64,
     pub replication_lag: Option<f64>,
     pub health_score: String,
     pub top_issues: Vec<String>,
@@ -113,6 +117,7 @@ impl MySQLPerformanceSnapshotDto {
             innodb_flushes: Set(self.innodb_flushes),
             temp_tables_disk: Set(self.temp_tables_disk),
             temp_tables_memory: Set(self.temp_tables_memory),
+            handler_read_rnd_next: Set(self.handler_read_rnd_next),
             replication_lag: Set(self.replication_lag),
             health_score: Set(self.health_score),
             top_issues: Set(serde_json::to_value(&self.top_issues).unwrap_or(serde_json::Value::Array(vec![]))),
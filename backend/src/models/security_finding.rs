@@ -0,0 +1,76 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "security_findings")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    #[sea_orm(unique)]
+    pub finding_id: String,
+    pub account_id: String,
+    pub region: String,
+    pub resource_arn: String,
+    pub title: String,
+    pub severity: String,
+    pub description: String,
+    pub first_observed: DateTime<Utc>,
+    pub last_observed: DateTime<Utc>,
+    pub remediation_text: Option<String>,
+    pub package_name: Option<String>,
+    pub cvss_score: Option<f64>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecurityFinding {
+    pub finding_id: String,
+    pub resource_arn: String,
+    pub title: String,
+    pub severity: String,
+    pub description: String,
+    pub first_observed: DateTime<Utc>,
+    pub last_observed: DateTime<Utc>,
+    pub remediation_text: Option<String>,
+    pub package_name: Option<String>,
+    pub cvss_score: Option<f64>,
+}
+
+impl From<Model> for SecurityFinding {
+    fn from(model: Model) -> Self {
+        Self {
+            finding_id: model.finding_id,
+            resource_arn: model.resource_arn,
+            title: model.title,
+            severity: model.severity,
+            description: model.description,
+            first_observed: model.first_observed,
+            last_observed: model.last_observed,
+            remediation_text: model.remediation_text,
+            package_name: model.package_name,
+            cvss_score: model.cvss_score,
+        }
+    }
+}
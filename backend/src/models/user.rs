@@ -39,6 +39,11 @@ pub struct Model {
     pub updated_at: DateTime<Utc>,
     #[sea_orm(column_type = "TimestampWithTimeZone", nullable)]
     pub last_login: Option<DateTime<Utc>>,
+    // Identity provider account this user is linked to, if any
+    #[serde(skip_serializing)]
+    pub oidc_issuer: Option<String>,
+    #[serde(skip_serializing)]
+    pub oidc_subject: Option<String>,
 
     // Adding permissions as transient field derived from roles
     #[sea_orm(ignore)]
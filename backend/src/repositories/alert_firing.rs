@@ -0,0 +1,87 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use chrono::Utc;
+use sea_orm::{prelude::*, ActiveValue::Set, QueryOrder, QuerySelect};
+use sea_query::Expr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::models::alert_firing::{self, ActiveModel, Entity as AlertFiringEntity, Model};
+
+#[derive(Debug)]
+pub struct AlertFiringRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl AlertFiringRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn create(&self, firing: ActiveModel) -> Result<Model, AppError> {
+        firing.insert(&*self.db).await.map_err(AppError::Database)
+    }
+
+    /// Alerts for a rule that have not been resolved yet, across all involved objects.
+    pub async fn find_open(
+        &self,
+        cluster_id: Uuid,
+        namespace: &str,
+        rule_name: &str,
+    ) -> Result<Vec<Model>, AppError> {
+        AlertFiringEntity::find()
+            .filter(alert_firing::Column::ClusterId.eq(cluster_id))
+            .filter(alert_firing::Column::Namespace.eq(namespace))
+            .filter(alert_firing::Column::RuleName.eq(rule_name))
+            .filter(alert_firing::Column::ResolvedAt.is_null())
+            .all(&*self.db)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    pub async fn resolve(&self, ids: Vec<Uuid>) -> Result<(), AppError> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+        AlertFiringEntity::update_many()
+            .col_expr(alert_firing::Column::ResolvedAt, Expr::value(Utc::now()))
+            .filter(alert_firing::Column::Id.is_in(ids))
+            .exec(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+        Ok(())
+    }
+
+    pub async fn list(
+        &self,
+        cluster_id: Uuid,
+        open_only: bool,
+        limit: u64,
+    ) -> Result<Vec<Model>, AppError> {
+        let mut query =
+            AlertFiringEntity::find().filter(alert_firing::Column::ClusterId.eq(cluster_id));
+        if open_only {
+            query = query.filter(alert_firing::Column::ResolvedAt.is_null());
+        }
+        query
+            .order_by_desc(alert_firing::Column::FiredAt)
+            .limit(limit)
+            .all(&*self.db)
+            .await
+            .map_err(AppError::Database)
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use chrono::Utc;
+use sea_orm::{prelude::*, ActiveValue::Set, DbBackend, Statement};
+use sea_query::Expr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::models::async_task::{
+    self, ActiveModel, Entity as AsyncTask, Model, STATUS_CANCELLED, STATUS_CANCELLING,
+    STATUS_FAILED, STATUS_QUEUED, STATUS_RUNNING,
+};
+
+#[derive(Debug)]
+pub struct AsyncTaskRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl AsyncTaskRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn create(
+        &self,
+        task_type: &str,
+        payload: serde_json::Value,
+    ) -> Result<Model, AppError> {
+        let now = Utc::now();
+        let model = ActiveModel {
+            id: Set(Uuid::new_v4()),
+            task_type: Set(task_type.to_string()),
+            status: Set(STATUS_QUEUED.to_string()),
+            payload: Set(payload),
+            result: Set(None),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+        model.insert(&*self.db).await.map_err(AppError::Database)
+    }
+
+    pub async fn get(&self, id: Uuid) -> Result<Option<Model>, AppError> {
+        AsyncTask::find_by_id(id)
+            .one(&*self.db)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    /// Atomically move the oldest queued task to running. SKIP LOCKED lets several
+    /// workers poll the same table without picking up the same task.
+    pub async fn claim_next(&self) -> Result<Option<Model>, AppError> {
+        let stmt = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"UPDATE async_tasks SET status = $1, updated_at = NOW()
+               WHERE id = (
+                   SELECT id FROM async_tasks WHERE status = $2
+                   ORDER BY created_at LIMIT 1 FOR UPDATE SKIP LOCKED
+               )
+               RETURNING *"#,
+            [STATUS_RUNNING.into(), STATUS_QUEUED.into()],
+        );
+        AsyncTask::find()
+            .from_raw_sql(stmt)
+            .one(&*self.db)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    pub async fn finish(
+        &self,
+        id: Uuid,
+        status: &str,
+        result: serde_json::Value,
+    ) -> Result<(), AppError> {
+        AsyncTask::update_many()
+            .col_expr(async_task::Column::Status, Expr::value(status))
+            .col_expr(async_task::Column::Result, Expr::value(result))
+            .col_expr(async_task::Column::UpdatedAt, Expr::value(Utc::now()))
+            .filter(async_task::Column::Id.eq(id))
+            .exec(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+        Ok(())
+    }
+
+    /// Queued tasks are cancelled immediately; running ones are flagged for the worker.
+    pub async fn request_cancel(&self, id: Uuid) -> Result<Option<Model>, AppError> {
+        let Some(task) = self.get(id).await? else {
+            return Ok(None);
+        };
+        let next_status = match task.status.as_str() {
+            STATUS_QUEUED => STATUS_CANCELLED,
+            STATUS_RUNNING => STATUS_CANCELLING,
+            _ => return Ok(Some(task)),
+        };
+        // Guard on the current status so a task that just finished isn't overwritten
+        AsyncTask::update_many()
+            .col_expr(async_task::Column::Status, Expr::value(next_status))
+            .col_expr(async_task::Column::UpdatedAt, Expr::value(Utc::now()))
+            .filter(async_task::Column::Id.eq(id))
+            .filter(async_task::Column::Status.eq(task.status.clone()))
+            .exec(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+        self.get(id).await
+    }
+
+    /// Renew the lease on a task this worker is running. `updated_at` doubles as the
+    /// heartbeat, so a task is only considered abandoned once it stops moving.
+    pub async fn heartbeat(&self, id: Uuid) -> Result<(), AppError> {
+        AsyncTask::update_many()
+            .col_expr(async_task::Column::UpdatedAt, Expr::value(Utc::now()))
+            .filter(async_task::Column::Id.eq(id))
+            .filter(async_task::Column::Status.is_in([STATUS_RUNNING, STATUS_CANCELLING]))
+            .exec(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+        Ok(())
+    }
+
+    /// Tasks whose worker stopped heartbeating for longer than `lease` can never complete,
+    /// so mark them failed. Tasks other instances are still running keep renewing their
+    /// lease and are left alone.
+    pub async fn fail_interrupted(&self, lease: chrono::Duration) -> Result<u64, AppError> {
+        let now = Utc::now();
+        let result = AsyncTask::update_many()
+            .col_expr(async_task::Column::Status, Expr::value(STATUS_FAILED))
+            .col_expr(
+                async_task::Column::Result,
+                Expr::value(serde_json::json!({ "error": "Worker lease expired" })),
+            )
+            .col_expr(async_task::Column::UpdatedAt, Expr::value(now))
+            .filter(async_task::Column::Status.is_in([STATUS_RUNNING, STATUS_CANCELLING]))
+            .filter(async_task::Column::UpdatedAt.lt(now - lease))
+            .exec(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+        Ok(result.rows_affected)
+    }
+}
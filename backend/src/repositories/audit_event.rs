@@ -0,0 +1,76 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect,
+};
+use std::sync::Arc;
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::models::audit_event::{self, ActiveModel, AuditEvent, Entity as AuditEventEntity, Model};
+
+#[derive(Debug)]
+pub struct AuditEventRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl AuditEventRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn create(&self, event: &AuditEvent) -> Result<Model, AppError> {
+        debug!(
+            "Recording audit event {} on {}/{}",
+            event.operation, event.resource_type, event.resource_id
+        );
+
+        let active = ActiveModel {
+            id: Set(Uuid::new_v4()),
+            timestamp: Set(event.timestamp),
+            user_id: Set(event.user_id.clone()),
+            operation: Set(event.operation.clone()),
+            resource_type: Set(event.resource_type.clone()),
+            resource_id: Set(event.resource_id.clone()),
+            namespace: Set(event.namespace.clone()),
+            cluster: Set(event.cluster.clone()),
+            payload: Set(event.payload.clone()),
+            ip_address: Set(event.ip_address.clone()),
+        };
+
+        active.insert(self.db.as_ref()).await.map_err(AppError::Database)
+    }
+
+    pub async fn list_recent(
+        &self,
+        resource_type: Option<&str>,
+        limit: u64,
+    ) -> Result<Vec<Model>, AppError> {
+        let mut query = AuditEventEntity::find();
+        if let Some(resource_type) = resource_type {
+            query = query.filter(audit_event::Column::ResourceType.eq(resource_type));
+        }
+
+        query
+            .order_by_desc(audit_event::Column::Timestamp)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await
+            .map_err(AppError::Database)
+    }
+}
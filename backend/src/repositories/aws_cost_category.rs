@@ -0,0 +1,111 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use sea_orm::{prelude::*, ActiveValue::Set};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::models::aws_cost_category::{
+    self, ActiveModel, AwsCostCategory, Entity as AwsCostCategoryEntity,
+};
+
+#[derive(Debug)]
+pub struct AwsCostCategoryRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl AwsCostCategoryRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // Inserts or refreshes a single category, keyed by its ARN
+    pub async fn upsert_category(
+        &self,
+        account_id: &str,
+        category: &AwsCostCategory,
+    ) -> Result<(), AppError> {
+        let now = Utc::now();
+        let existing = AwsCostCategoryEntity::find()
+            .filter(aws_cost_category::Column::CategoryArn.eq(category.category_arn.as_str()))
+            .one(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+
+        let mut model = match existing {
+            Some(existing) => existing.into(),
+            None => ActiveModel {
+                id: Set(Uuid::new_v4()),
+                category_arn: Set(category.category_arn.clone()),
+                created_at: Set(now),
+                ..Default::default()
+            },
+        };
+        model.account_id = Set(account_id.to_string());
+        model.name = Set(category.name.clone());
+        model.effective_start = Set(category.effective_start.clone());
+        model.number_of_rules = Set(category.number_of_rules);
+        model.category_values = Set(serde_json::json!(category.values));
+        model.default_value = Set(category.default_value.clone());
+        model.updated_at = Set(now);
+
+        model.save(&*self.db).await.map_err(AppError::Database)?;
+        Ok(())
+    }
+
+    // Makes the stored set for an account match the live definitions, dropping deleted ones
+    pub async fn replace_for_account(
+        &self,
+        account_id: &str,
+        categories: &[AwsCostCategory],
+    ) -> Result<(), AppError> {
+        for category in categories {
+            self.upsert_category(account_id, category).await?;
+        }
+
+        let live_arns: Vec<&str> = categories.iter().map(|c| c.category_arn.as_str()).collect();
+        AwsCostCategoryEntity::delete_many()
+            .filter(aws_cost_category::Column::AccountId.eq(account_id))
+            .filter(aws_cost_category::Column::CategoryArn.is_not_in(live_arns))
+            .exec(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+        Ok(())
+    }
+
+    pub async fn find_by_name(
+        &self,
+        account_id: &str,
+        name: &str,
+    ) -> Result<Option<AwsCostCategory>, AppError> {
+        let row = AwsCostCategoryEntity::find()
+            .filter(aws_cost_category::Column::AccountId.eq(account_id))
+            .filter(aws_cost_category::Column::Name.eq(name))
+            .one(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+        Ok(row.map(Into::into))
+    }
+
+    pub async fn delete_by_arn(&self, category_arn: &str) -> Result<(), AppError> {
+        AwsCostCategoryEntity::delete_many()
+            .filter(aws_cost_category::Column::CategoryArn.eq(category_arn))
+            .exec(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+        Ok(())
+    }
+}
@@ -15,9 +15,9 @@
 
 use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, EntityTrait,
-    IntoActiveModel, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
-    QueryTrait, Set,
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, DbBackend,
+    EntityTrait, FromQueryResult, IntoActiveModel, Order, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, QueryTrait, Set, Statement, Value,
 };
 use std::sync::Arc;
 use tracing::{info};
@@ -27,7 +27,7 @@ use crate::config::Config;
 use crate::errors::AppError;
 use crate::models::aws_resource::{
     self, ActiveModel, AwsResourceDto, AwsResourcePage, AwsResourceQuery, Entity as AwsResource,
-    Model,
+    Model, ResourceSearchQuery, ScoredAwsResource,
 };
 
 #[derive(Debug)]
@@ -230,6 +230,112 @@ impl AwsResourceRepository {
         })
     }
 
+    // Ranked search across all resource types. Full-text matches on the name score
+    // highest, then exact names, partial ARNs/resource ids and tag values.
+    pub async fn full_text_search(
+        &self,
+        query: &ResourceSearchQuery,
+    ) -> Result<AwsResourcePage<ScoredAwsResource>, AppError> {
+        let q = query.q.trim();
+        if q.is_empty() {
+            return Err(AppError::Validation(
+                "Search query must not be empty".to_string(),
+            ));
+        }
+        let page_size = query.page_size.clamp(1, 200);
+
+        // $1 is the raw query, $2 a LIKE pattern; filters append further placeholders
+        let mut values: Vec<Value> = vec![q.into(), format!("%{}%", escape_like(q)).into()];
+        let mut filters = vec![
+            "(to_tsvector('simple', COALESCE(name, '')) @@ plainto_tsquery('simple', $1) \
+             OR name ILIKE $2 OR arn ILIKE $2 OR resource_id ILIKE $2 OR tags::text ILIKE $2)"
+                .to_string(),
+        ];
+        for (column, allowed) in [
+            ("resource_type", &query.resource_types),
+            ("account_id", &query.account_ids),
+            ("region", &query.regions),
+        ] {
+            let Some(allowed) = allowed.as_ref().filter(|a| !a.is_empty()) else {
+                continue;
+            };
+            let placeholders: Vec<String> = allowed
+                .iter()
+                .map(|v| {
+                    values.push(v.clone().into());
+                    format!("${}", values.len())
+                })
+                .collect();
+            filters.push(format!("{} IN ({})", column, placeholders.join(", ")));
+        }
+        if let Some(tags) = query.tag_filter.as_ref().filter(|t| !t.is_empty()) {
+            values.push(serde_json::to_value(tags)?.into());
+            filters.push(format!("tags @> ${}", values.len()));
+        }
+        let where_clause = filters.join(" AND ");
+
+        let count_row = self
+            .db
+            .query_one(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                &format!(
+                    "SELECT COUNT(*) AS count FROM aws_resources WHERE {}",
+                    where_clause
+                ),
+                values.clone(),
+            ))
+            .await
+            .map_err(AppError::Database)?;
+        let total = match count_row {
+            Some(row) => row
+                .try_get::<i64>("", "count")
+                .map_err(AppError::Database)? as u64,
+            None => 0,
+        };
+
+        let sql = format!(
+            r#"SELECT *, (
+                   ts_rank(to_tsvector('simple', COALESCE(name, '')), plainto_tsquery('simple', $1))
+                   + CASE WHEN LOWER(name) = LOWER($1) THEN 1.0 ELSE 0.0 END
+                   + CASE WHEN arn ILIKE $2 OR resource_id ILIKE $2 THEN 0.5 ELSE 0.0 END
+                   + CASE WHEN tags::text ILIKE $2 THEN 0.25 ELSE 0.0 END
+               )::float8 AS score
+               FROM aws_resources
+               WHERE {}
+               ORDER BY score DESC, updated_at DESC
+               LIMIT {} OFFSET {}"#,
+            where_clause,
+            page_size,
+            query.page * page_size
+        );
+        let rows = self
+            .db
+            .query_all(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                &sql,
+                values,
+            ))
+            .await
+            .map_err(AppError::Database)?;
+
+        let total_pages = (total as f64 / page_size as f64).ceil() as u64;
+        let mut resources = Vec::with_capacity(rows.len());
+        for row in rows {
+            resources.push(ScoredAwsResource {
+                resource: Model::from_query_result(&row, "").map_err(AppError::Database)?,
+                score: row.try_get("", "score").map_err(AppError::Database)?,
+            });
+        }
+
+        Ok(AwsResourcePage {
+            resources,
+            total,
+            page: query.page as u64,
+            page_size: page_size as u64,
+            total_pages,
+        })
+    }
+
     // Delete a resource
     pub async fn delete(&self, id: Uuid) -> Result<(), AppError> {
         let res = AwsResource::delete_by_id(id)
@@ -276,6 +382,26 @@ impl AwsResourceRepository {
         Ok(updated_model)
     }
 
+    pub async fn update_tags(&self, id: Uuid, tags: serde_json::Value) -> Result<Model, AppError> {
+        let aws_resource = AwsResource::find_by_id(id)
+            .one(&*self.db)
+            .await
+            .map_err(|e| AppError::Database(e))?
+            .ok_or_else(|| AppError::NotFound(format!("AWS resource with ID {} not found", id)))?;
+
+        let mut active_model = aws_resource.into_active_model();
+        active_model.tags = Set(tags);
+        active_model.updated_at = Set(Utc::now());
+
+        let updated_model = active_model
+            .update(&*self.db)
+            .await
+            .map_err(|e| AppError::Database(e))?;
+
+        info!("AWS resource tags updated: {}", updated_model.id);
+        Ok(updated_model)
+    }
+
     // Find newly added resources within a time window
     pub async fn find_newly_added_resources(
         &self,
@@ -526,3 +652,11 @@ impl AwsResourceRepository {
         Ok(results)
     }
 }
+
+// Backslash is the default ILIKE escape character in Postgres
+fn escape_like(input: &str) -> String {
+    input
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
@@ -0,0 +1,131 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{DateTime, Utc};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, Statement};
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+
+#[derive(Debug, Clone)]
+pub struct StoredAutoTrigger {
+    pub id: Uuid,
+    pub cluster_id: Uuid,
+    pub namespace: String,
+    pub restart_threshold: u32,
+    pub experiment_id: Uuid,
+    pub last_fired_at: Option<DateTime<Utc>>,
+}
+
+/// Keeps chaos auto-triggers and the time each last fired, so registered triggers
+/// are restored after a restart and do not fire again within their cooldown.
+#[derive(Debug)]
+pub struct ChaosAutoTriggerRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl ChaosAutoTriggerRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn insert(
+        &self,
+        id: Uuid,
+        cluster_id: Uuid,
+        namespace: &str,
+        restart_threshold: u32,
+        experiment_id: Uuid,
+    ) -> Result<(), AppError> {
+        self.db
+            .execute(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO chaos_auto_triggers
+                   (id, cluster_id, namespace, restart_threshold, experiment_id)
+                   VALUES ($1, $2, $3, $4, $5)"#,
+                [
+                    id.into(),
+                    cluster_id.into(),
+                    namespace.into(),
+                    (restart_threshold as i32).into(),
+                    experiment_id.into(),
+                ],
+            ))
+            .await
+            .map_err(AppError::Database)?;
+        Ok(())
+    }
+
+    // Returns whether a stored trigger was removed
+    pub async fn delete(&self, id: Uuid) -> Result<bool, AppError> {
+        let result = self
+            .db
+            .execute(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "DELETE FROM chaos_auto_triggers WHERE id = $1",
+                [id.into()],
+            ))
+            .await
+            .map_err(AppError::Database)?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn find_all(&self) -> Result<Vec<StoredAutoTrigger>, AppError> {
+        let rows = self
+            .db
+            .query_all(Statement::from_string(
+                DbBackend::Postgres,
+                r#"SELECT id, cluster_id, namespace, restart_threshold, experiment_id, last_fired_at
+                   FROM chaos_auto_triggers
+                   ORDER BY created_at"#,
+            ))
+            .await
+            .map_err(AppError::Database)?;
+        rows.iter()
+            .map(|row| -> Result<StoredAutoTrigger, AppError> {
+                let restart_threshold: i32 = row.try_get("", "restart_threshold")?;
+                Ok(StoredAutoTrigger {
+                    id: row.try_get("", "id")?,
+                    cluster_id: row.try_get("", "cluster_id")?,
+                    namespace: row.try_get("", "namespace")?,
+                    restart_threshold: restart_threshold.max(0) as u32,
+                    experiment_id: row.try_get("", "experiment_id")?,
+                    last_fired_at: row.try_get("", "last_fired_at")?,
+                })
+            })
+            .collect()
+    }
+
+    // Check and record in one statement, so concurrent watches or instances fire once.
+    // Returns false while the trigger is still within `cooldown` of its last firing.
+    pub async fn claim_fire(&self, id: Uuid, cooldown: Duration) -> Result<bool, AppError> {
+        let row = self
+            .db
+            .query_one(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"UPDATE chaos_auto_triggers
+                   SET last_fired_at = NOW()
+                   WHERE id = $1
+                     AND (last_fired_at IS NULL
+                          OR last_fired_at <= NOW() - make_interval(secs => $2))
+                   RETURNING id"#,
+                [id.into(), (cooldown.as_secs() as f64).into()],
+            ))
+            .await
+            .map_err(AppError::Database)?;
+        Ok(row.is_some())
+    }
+}
@@ -100,6 +100,7 @@ impl ClusterRepository {
             "sasl_password": encrypted_password,
             "sasl_mechanism": request.sasl_mechanism,
             "security_protocol": request.security_protocol,
+            "schema_registry_url": request.schema_registry_url,
         });
 
         let now = Utc::now();
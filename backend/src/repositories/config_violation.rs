@@ -0,0 +1,75 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use sea_orm::{prelude::*, ActiveValue::Set};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::models::config_violation::{
+    self, ActiveModel, ConfigViolation, Entity as ConfigViolationEntity,
+};
+
+#[derive(Debug)]
+pub struct ConfigViolationRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl ConfigViolationRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // One row per resource and rule; a re-evaluation refreshes the annotation and time
+    pub async fn upsert_violations(
+        &self,
+        account_id: &str,
+        region: &str,
+        violations: &[ConfigViolation],
+    ) -> Result<(), AppError> {
+        let now = Utc::now();
+        for violation in violations {
+            let existing = ConfigViolationEntity::find()
+                .filter(config_violation::Column::AccountId.eq(account_id))
+                .filter(config_violation::Column::Region.eq(region))
+                .filter(config_violation::Column::ResourceType.eq(violation.resource_type.as_str()))
+                .filter(config_violation::Column::ResourceId.eq(violation.resource_id.as_str()))
+                .filter(config_violation::Column::RuleName.eq(violation.rule_name.as_str()))
+                .one(&*self.db)
+                .await
+                .map_err(AppError::Database)?;
+
+            let mut model = match existing {
+                Some(existing) => existing.into(),
+                None => ActiveModel {
+                    id: Set(Uuid::new_v4()),
+                    account_id: Set(account_id.to_string()),
+                    region: Set(region.to_string()),
+                    resource_type: Set(violation.resource_type.clone()),
+                    resource_id: Set(violation.resource_id.clone()),
+                    rule_name: Set(violation.rule_name.clone()),
+                    created_at: Set(now),
+                    ..Default::default()
+                },
+            };
+            model.annotation = Set(violation.annotation.clone());
+            model.evaluation_time = Set(violation.evaluation_time);
+            model.updated_at = Set(now);
+
+            model.save(&*self.db).await.map_err(AppError::Database)?;
+        }
+        Ok(())
+    }
+}
@@ -26,6 +26,14 @@ use crate::models::aws_cost_insights::{Entity as CostInsights, Model as CostInsi
 use crate::models::aws_monthly_cost_aggregates::{
     Entity as MonthlyCostAggregates, Model as MonthlyCostAggregateModel,
 };
+use crate::models::aws_rightsizing_recommendations::{
+    Column as RightsizingColumn, Entity as RightsizingRecommendations,
+    Model as RightsizingRecommendationModel,
+};
+use crate::models::aws_savings_plan_recommendations::{
+    Column as SavingsPlanColumn, Entity as SavingsPlanRecommendations,
+    Model as SavingsPlanRecommendationModel,
+};
 
 #[derive(Debug)]
 pub struct CostAnalyticsRepository {
@@ -60,7 +68,8 @@ impl CostAnalyticsRepository {
         let mut query = CostData::find()
             .filter(crate::models::aws_cost_data::Column::AccountId.eq(account_id))
             .filter(crate::models::aws_cost_data::Column::UsageStart.gte(start_date))
-            .filter(crate::models::aws_cost_data::Column::UsageEnd.lte(end_date));
+            .filter(crate::models::aws_cost_data::Column::UsageEnd.lte(end_date))
+            .filter(crate::models::aws_cost_data::Column::TagKey.is_null());
 
         if let Some(service) = service_name {
             query = query.filter(crate::models::aws_cost_data::Column::ServiceName.eq(service));
@@ -75,6 +84,68 @@ impl CostAnalyticsRepository {
         Ok(results)
     }
 
+    // Replaces the stored breakdown for one tag key over the window, so refetching a period
+    // does not double count it
+    pub async fn replace_tag_cost_data(
+        &self,
+        account_id: &str,
+        tag_key: &str,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        cost_data: Vec<crate::models::aws_cost_data::ActiveModel>,
+    ) -> Result<(), AppError> {
+        let txn = self.db.begin().await.map_err(AppError::Database)?;
+        CostData::delete_many()
+            .filter(crate::models::aws_cost_data::Column::AccountId.eq(account_id))
+            .filter(crate::models::aws_cost_data::Column::TagKey.eq(tag_key))
+            .filter(crate::models::aws_cost_data::Column::UsageStart.gte(start_date))
+            .filter(crate::models::aws_cost_data::Column::UsageEnd.lte(end_date))
+            .exec(&txn)
+            .await
+            .map_err(AppError::Database)?;
+        if !cost_data.is_empty() {
+            CostData::insert_many(cost_data)
+                .exec(&txn)
+                .await
+                .map_err(AppError::Database)?;
+        }
+        txn.commit().await.map_err(AppError::Database)?;
+
+        Ok(())
+    }
+
+    pub async fn get_tag_cost_data(
+        &self,
+        account_id: &str,
+        tag_key: &str,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Vec<CostDataModel>, AppError> {
+        CostData::find()
+            .filter(crate::models::aws_cost_data::Column::AccountId.eq(account_id))
+            .filter(crate::models::aws_cost_data::Column::TagKey.eq(tag_key))
+            .filter(crate::models::aws_cost_data::Column::UsageStart.gte(start_date))
+            .filter(crate::models::aws_cost_data::Column::UsageEnd.lte(end_date))
+            .order_by_asc(crate::models::aws_cost_data::Column::UsageStart)
+            .all(&*self.db)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    pub async fn get_latest_tag_cost_data(
+        &self,
+        account_id: &str,
+        tag_key: &str,
+    ) -> Result<Option<CostDataModel>, AppError> {
+        CostData::find()
+            .filter(crate::models::aws_cost_data::Column::AccountId.eq(account_id))
+            .filter(crate::models::aws_cost_data::Column::TagKey.eq(tag_key))
+            .order_by_desc(crate::models::aws_cost_data::Column::CreatedAt)
+            .one(&*self.db)
+            .await
+            .map_err(AppError::Database)
+    }
+
     // Monthly Aggregates operations
     pub async fn insert_monthly_aggregate(
         &self,
@@ -183,6 +254,94 @@ impl CostAnalyticsRepository {
         Ok(results)
     }
 
+    // Rightsizing recommendation operations
+    /// Replace stored recommendations for the given accounts with a fresh set.
+    pub async fn replace_rightsizing_recommendations(
+        &self,
+        account_ids: Vec<String>,
+        recommendations: Vec<crate::models::aws_rightsizing_recommendations::ActiveModel>,
+    ) -> Result<(), AppError> {
+        let txn = self.db.begin().await.map_err(AppError::Database)?;
+
+        RightsizingRecommendations::delete_many()
+            .filter(RightsizingColumn::AccountId.is_in(account_ids))
+            .exec(&txn)
+            .await
+            .map_err(AppError::Database)?;
+
+        if !recommendations.is_empty() {
+            RightsizingRecommendations::insert_many(recommendations)
+                .exec(&txn)
+                .await
+                .map_err(AppError::Database)?;
+        }
+
+        txn.commit().await.map_err(AppError::Database)?;
+        Ok(())
+    }
+
+    pub async fn get_rightsizing_recommendations(
+        &self,
+        account_id: Option<String>,
+        resource_type: Option<String>,
+        min_monthly_savings: Option<f64>,
+    ) -> Result<Vec<RightsizingRecommendationModel>, AppError> {
+        let mut query = RightsizingRecommendations::find();
+
+        if let Some(account) = account_id {
+            query = query.filter(RightsizingColumn::AccountId.eq(account));
+        }
+
+        if let Some(rtype) = resource_type {
+            query = query.filter(RightsizingColumn::ResourceType.eq(rtype));
+        }
+
+        if let Some(min_savings) = min_monthly_savings {
+            let min_savings =
+                sea_orm::prelude::Decimal::from_f64_retain(min_savings).unwrap_or_default();
+            query = query.filter(RightsizingColumn::EstimatedMonthlySavings.gte(min_savings));
+        }
+
+        let results = query
+            .order_by_desc(RightsizingColumn::EstimatedMonthlySavings)
+            .all(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+
+        Ok(results)
+    }
+
+    // Savings Plan recommendation operations
+    pub async fn insert_savings_plan_recommendation(
+        &self,
+        recommendation: crate::models::aws_savings_plan_recommendations::ActiveModel,
+    ) -> Result<SavingsPlanRecommendationModel, AppError> {
+        recommendation
+            .insert(&*self.db)
+            .await
+            .map_err(AppError::Database)
+    }
+
+    /// Most recent stored recommendation for an account, optionally for one payment option.
+    pub async fn get_latest_savings_plan_recommendation(
+        &self,
+        account_id: &str,
+        payment_option: Option<&str>,
+    ) -> Result<Option<SavingsPlanRecommendationModel>, AppError> {
+        let mut query =
+            SavingsPlanRecommendations::find().filter(SavingsPlanColumn::AccountId.eq(account_id));
+
+        if let Some(payment_option) = payment_option {
+            query = query.filter(SavingsPlanColumn::PaymentOption.eq(payment_option));
+        }
+
+        query
+            .order_by_desc(SavingsPlanColumn::CreatedAt)
+            .one(&*self.db)
+            .await
+            .map_err(AppError::Database)
+    }
+
     // Cost Insights operations
     pub async fn insert_cost_insight(
         &self,
@@ -313,7 +472,8 @@ impl CostAnalyticsRepository {
         let mut query = CostData::find()
             .filter(crate::models::aws_cost_data::Column::AccountId.eq(account_id))
             .filter(crate::models::aws_cost_data::Column::UsageStart.gte(start_date))
-            .filter(crate::models::aws_cost_data::Column::UsageEnd.lte(end_date));
+            .filter(crate::models::aws_cost_data::Column::UsageEnd.lte(end_date))
+            .filter(crate::models::aws_cost_data::Column::TagKey.is_null());
 
         // Apply optional filters
         if let Some(resource) = resource_id {
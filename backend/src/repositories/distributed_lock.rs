@@ -0,0 +1,163 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, Statement};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+
+/// Cross-instance mutual exclusion backed by the `distributed_locks` table. While a guard
+/// is held a background heartbeat keeps pushing the expiry out by another TTL, so an
+/// operation may outlive its TTL; a lock whose holder crashed becomes available again
+/// once the TTL has passed without a heartbeat.
+#[derive(Debug)]
+pub struct DistributedLock {
+    db: Arc<DatabaseConnection>,
+}
+
+impl DistributedLock {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Take the lock or fail with `AppError::Conflict` if another holder has it.
+    pub async fn acquire(&self, key: &str, ttl: Duration) -> Result<LockGuard, AppError> {
+        let holder_id = Uuid::new_v4().to_string();
+        // The conditional upsert only takes over a row that is released or expired
+        let row = self
+            .db
+            .query_one(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO distributed_locks (lock_key, holder_id, acquired_at, expires_at)
+                   VALUES ($1, $2, NOW(), NOW() + $3 * INTERVAL '1 second')
+                   ON CONFLICT (lock_key) DO UPDATE
+                   SET holder_id = EXCLUDED.holder_id,
+                       acquired_at = EXCLUDED.acquired_at,
+                       expires_at = EXCLUDED.expires_at
+                   WHERE distributed_locks.acquired_at IS NULL
+                      OR distributed_locks.expires_at < NOW()
+                   RETURNING lock_key"#,
+                [
+                    key.into(),
+                    holder_id.clone().into(),
+                    (ttl.as_secs().max(1) as i64).into(),
+                ],
+            ))
+            .await
+            .map_err(AppError::Database)?;
+
+        if row.is_none() {
+            return Err(AppError::Conflict(format!(
+                "Operation '{}' is already running on another instance",
+                key
+            )));
+        }
+        let heartbeat = spawn_heartbeat(self.db.clone(), key.to_string(), holder_id.clone(), ttl);
+        Ok(LockGuard {
+            db: self.db.clone(),
+            key: key.to_string(),
+            holder_id,
+            heartbeat: Some(heartbeat),
+        })
+    }
+}
+
+// Renews a few times per TTL so one slow or failed renewal does not lose the lock
+fn spawn_heartbeat(
+    db: Arc<DatabaseConnection>,
+    key: String,
+    holder_id: String,
+    ttl: Duration,
+) -> JoinHandle<()> {
+    let ttl_secs = ttl.as_secs().max(1) as i64;
+    let period = Duration::from_secs((ttl_secs as u64 / 3).max(1));
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(period);
+        // The first tick completes immediately and the lock was just taken
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            let result = db
+                .execute(Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"UPDATE distributed_locks
+                       SET expires_at = NOW() + $3 * INTERVAL '1 second'
+                       WHERE lock_key = $1 AND holder_id = $2 AND acquired_at IS NOT NULL"#,
+                    [
+                        key.clone().into(),
+                        holder_id.clone().into(),
+                        ttl_secs.into(),
+                    ],
+                ))
+                .await;
+            match result {
+                Ok(r) if r.rows_affected() == 0 => {
+                    warn!("Distributed lock {} was lost; stopping its heartbeat", key);
+                    return;
+                }
+                Ok(_) => {}
+                Err(e) => warn!("Failed to renew distributed lock {}: {}", key, e),
+            }
+        }
+    })
+}
+
+/// Holds a lock taken with [`DistributedLock::acquire`] and releases it when dropped.
+#[derive(Debug)]
+pub struct LockGuard {
+    db: Arc<DatabaseConnection>,
+    key: String,
+    holder_id: String,
+    heartbeat: Option<JoinHandle<()>>,
+}
+
+impl LockGuard {
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl Drop for LockGuard {
+    fn drop(&mut self) {
+        if let Some(heartbeat) = self.heartbeat.take() {
+            heartbeat.abort();
+        }
+        let db = self.db.clone();
+        let key = std::mem::take(&mut self.key);
+        let holder_id = std::mem::take(&mut self.holder_id);
+        // Outside a runtime the lock is left to expire at its TTL
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        handle.spawn(async move {
+            // Matching the holder keeps a guard whose lock already expired and was taken
+            // over from releasing the new holder's lock
+            let result = db
+                .execute(Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"UPDATE distributed_locks SET acquired_at = NULL
+                       WHERE lock_key = $1 AND holder_id = $2"#,
+                    [key.clone().into(), holder_id.into()],
+                ))
+                .await;
+            if let Err(e) = result {
+                warn!("Failed to release distributed lock {}: {}", key, e);
+            }
+        });
+    }
+}
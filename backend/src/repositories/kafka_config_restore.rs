@@ -0,0 +1,135 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, Statement};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+
+#[derive(Debug, Clone)]
+pub struct PendingConfigRestore {
+    pub id: Uuid,
+    pub cluster_id: String,
+    pub topic_name: String,
+    pub original_configs: HashMap<String, String>,
+}
+
+/// Remembers topic config overrides that a temporary change has to put back, so the
+/// restore survives a dropped request or a restart.
+#[derive(Debug)]
+pub struct KafkaConfigRestoreRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl KafkaConfigRestoreRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn record(
+        &self,
+        cluster_id: &str,
+        topic_name: &str,
+        original_configs: &HashMap<String, String>,
+    ) -> Result<Uuid, AppError> {
+        let id = Uuid::new_v4();
+        self.db
+            .execute(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO kafka_topic_config_restores
+                   (id, cluster_id, topic_name, original_configs)
+                   VALUES ($1, $2, $3, $4)"#,
+                [
+                    id.into(),
+                    cluster_id.into(),
+                    topic_name.into(),
+                    serde_json::to_value(original_configs)?.into(),
+                ],
+            ))
+            .await
+            .map_err(AppError::Database)?;
+        Ok(id)
+    }
+
+    pub async fn mark_restored(&self, id: Uuid) -> Result<(), AppError> {
+        self.db
+            .execute(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "UPDATE kafka_topic_config_restores SET restored_at = NOW() WHERE id = $1",
+                [id.into()],
+            ))
+            .await
+            .map_err(AppError::Database)?;
+        Ok(())
+    }
+
+    pub async fn find_pending(&self) -> Result<Vec<PendingConfigRestore>, AppError> {
+        self.query_pending(
+            r#"SELECT id, cluster_id, topic_name, original_configs
+               FROM kafka_topic_config_restores
+               WHERE restored_at IS NULL
+               ORDER BY created_at"#,
+            vec![],
+        )
+        .await
+    }
+
+    // The oldest pending row holds the overrides from before any temporary change
+    pub async fn find_pending_for_topic(
+        &self,
+        cluster_id: &str,
+        topic_name: &str,
+    ) -> Result<Option<PendingConfigRestore>, AppError> {
+        let rows = self
+            .query_pending(
+                r#"SELECT id, cluster_id, topic_name, original_configs
+                   FROM kafka_topic_config_restores
+                   WHERE restored_at IS NULL AND cluster_id = $1 AND topic_name = $2
+                   ORDER BY created_at
+                   LIMIT 1"#,
+                vec![cluster_id.into(), topic_name.into()],
+            )
+            .await?;
+        Ok(rows.into_iter().next())
+    }
+
+    async fn query_pending(
+        &self,
+        sql: &str,
+        values: Vec<sea_orm::Value>,
+    ) -> Result<Vec<PendingConfigRestore>, AppError> {
+        let rows = self
+            .db
+            .query_all(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                sql,
+                values,
+            ))
+            .await
+            .map_err(AppError::Database)?;
+        rows.iter()
+            .map(|row| -> Result<PendingConfigRestore, AppError> {
+                let configs: serde_json::Value = row.try_get("", "original_configs")?;
+                Ok(PendingConfigRestore {
+                    id: row.try_get("", "id")?,
+                    cluster_id: row.try_get("", "cluster_id")?,
+                    topic_name: row.try_get("", "topic_name")?,
+                    original_configs: serde_json::from_value(configs)?,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{DateTime, Utc};
+use sea_orm::{prelude::*, ActiveValue::Set, QueryOrder};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::models::kafka_topic_throughput::{
+    self, ActiveModel, Entity as ThroughputEntity, KafkaTopicThroughputSample,
+};
+
+#[derive(Debug)]
+pub struct KafkaTopicThroughputRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl KafkaTopicThroughputRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn insert_samples(
+        &self,
+        samples: &[KafkaTopicThroughputSample],
+    ) -> Result<(), AppError> {
+        if samples.is_empty() {
+            return Ok(());
+        }
+        let models = samples.iter().map(|s| ActiveModel {
+            id: Set(Uuid::new_v4()),
+            cluster_id: Set(s.cluster_id.clone()),
+            topic_name: Set(s.topic_name.clone()),
+            partition: Set(s.partition),
+            timestamp: Set(s.timestamp),
+            messages_per_sec: Set(s.messages_per_sec),
+            bytes_per_sec_estimate: Set(s.bytes_per_sec_estimate),
+        });
+        ThroughputEntity::insert_many(models)
+            .exec(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+        Ok(())
+    }
+
+    pub async fn find_history(
+        &self,
+        cluster_id: &str,
+        topic_name: &str,
+        partition: Option<i32>,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Vec<KafkaTopicThroughputSample>, AppError> {
+        let mut query = ThroughputEntity::find()
+            .filter(kafka_topic_throughput::Column::ClusterId.eq(cluster_id))
+            .filter(kafka_topic_throughput::Column::TopicName.eq(topic_name))
+            .filter(kafka_topic_throughput::Column::Timestamp.gte(start_time))
+            .filter(kafka_topic_throughput::Column::Timestamp.lte(end_time));
+        if let Some(partition) = partition {
+            query = query.filter(kafka_topic_throughput::Column::Partition.eq(partition));
+        }
+        let rows = query
+            .order_by_asc(kafka_topic_throughput::Column::Timestamp)
+            .order_by_asc(kafka_topic_throughput::Column::Partition)
+            .all(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    pub async fn delete_older_than(&self, cutoff: DateTime<Utc>) -> Result<u64, AppError> {
+        let result = ThroughputEntity::delete_many()
+            .filter(kafka_topic_throughput::Column::Timestamp.lt(cutoff))
+            .exec(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+        Ok(result.rows_affected)
+    }
+}
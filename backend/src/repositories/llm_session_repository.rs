@@ -0,0 +1,84 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use chrono::Utc;
+use sea_orm::{prelude::*, ActiveValue::Set};
+use sea_query::Expr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::models::llm_session::{
+    self, ActiveModel, Entity as LlmSessionEntity, LlmMessage, LlmSession,
+};
+
+#[derive(Debug)]
+pub struct LlmSessionRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl LlmSessionRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn create(
+        &self,
+        user_id: &str,
+        provider_id: Uuid,
+        context: Option<String>,
+    ) -> Result<LlmSession, AppError> {
+        let now = Utc::now();
+        let model = ActiveModel {
+            id: Set(Uuid::new_v4()),
+            user_id: Set(user_id.to_string()),
+            provider_id: Set(provider_id),
+            context: Set(context),
+            messages: Set(serde_json::json!([])),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+        let model = model.insert(&*self.db).await.map_err(AppError::Database)?;
+        Ok(model.into())
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> Result<Option<LlmSession>, AppError> {
+        let model = LlmSessionEntity::find_by_id(id)
+            .one(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+        Ok(model.map(LlmSession::from))
+    }
+
+    pub async fn save_messages(&self, id: Uuid, messages: &[LlmMessage]) -> Result<(), AppError> {
+        let messages = serde_json::to_value(messages)?;
+        LlmSessionEntity::update_many()
+            .col_expr(llm_session::Column::Messages, Expr::value(messages))
+            .col_expr(llm_session::Column::UpdatedAt, Expr::value(Utc::now()))
+            .filter(llm_session::Column::Id.eq(id))
+            .exec(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+        Ok(())
+    }
+
+    pub async fn delete(&self, id: Uuid) -> Result<bool, AppError> {
+        let result = LlmSessionEntity::delete_by_id(id)
+            .exec(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+        Ok(result.rows_affected > 0)
+    }
+}
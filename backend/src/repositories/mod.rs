@@ -36,3 +36,14 @@ pub mod user;
 pub mod chaos_repository;
 pub mod chaos_audit_repository;
 pub mod chaos_metrics_repository;
+pub mod audit_event;
+pub mod async_task;
+pub mod llm_session_repository;
+pub mod alert_firing;
+pub mod kafka_topic_throughput;
+pub mod security_finding;
+pub mod config_violation;
+pub mod aws_cost_category;
+pub mod distributed_lock;
+pub mod kafka_config_restore;
+pub mod chaos_auto_trigger;
@@ -0,0 +1,115 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use sea_orm::{prelude::*, ActiveValue::Set, QueryOrder};
+use std::cmp::Ordering;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::models::security_finding::{
+    self, ActiveModel, Entity as SecurityFindingEntity, SecurityFinding,
+};
+
+#[derive(Debug)]
+pub struct SecurityFindingRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl SecurityFindingRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // Inserts new findings and refreshes the ones already stored, keyed by finding id
+    pub async fn upsert_findings(
+        &self,
+        account_id: &str,
+        region: &str,
+        findings: &[SecurityFinding],
+    ) -> Result<(), AppError> {
+        let now = Utc::now();
+        for finding in findings {
+            let existing = SecurityFindingEntity::find()
+                .filter(security_finding::Column::FindingId.eq(finding.finding_id.as_str()))
+                .one(&*self.db)
+                .await
+                .map_err(AppError::Database)?;
+
+            let mut model = match existing {
+                Some(existing) => existing.into(),
+                None => ActiveModel {
+                    id: Set(Uuid::new_v4()),
+                    finding_id: Set(finding.finding_id.clone()),
+                    created_at: Set(now),
+                    ..Default::default()
+                },
+            };
+            model.account_id = Set(account_id.to_string());
+            model.region = Set(region.to_string());
+            model.resource_arn = Set(finding.resource_arn.clone());
+            model.title = Set(finding.title.clone());
+            model.severity = Set(finding.severity.clone());
+            model.description = Set(finding.description.clone());
+            model.first_observed = Set(finding.first_observed);
+            model.last_observed = Set(finding.last_observed);
+            model.remediation_text = Set(finding.remediation_text.clone());
+            model.package_name = Set(finding.package_name.clone());
+            model.cvss_score = Set(finding.cvss_score);
+            model.updated_at = Set(now);
+
+            model.save(&*self.db).await.map_err(AppError::Database)?;
+        }
+        Ok(())
+    }
+
+    // Highest CVSS first; findings without a score go last
+    pub async fn find_by_resource_arn(
+        &self,
+        resource_arn: &str,
+    ) -> Result<Vec<SecurityFinding>, AppError> {
+        let rows = SecurityFindingEntity::find()
+            .filter(security_finding::Column::ResourceArn.eq(resource_arn))
+            .all(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+        let mut findings: Vec<SecurityFinding> = rows.into_iter().map(Into::into).collect();
+        findings.sort_by(|a, b| match (a.cvss_score, b.cvss_score) {
+            (Some(a), Some(b)) => b.partial_cmp(&a).unwrap_or(Ordering::Equal),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+        Ok(findings)
+    }
+
+    pub async fn find_by_account(
+        &self,
+        account_id: &str,
+        severity: Option<&str>,
+    ) -> Result<Vec<SecurityFinding>, AppError> {
+        let mut query = SecurityFindingEntity::find()
+            .filter(security_finding::Column::AccountId.eq(account_id));
+        if let Some(severity) = severity {
+            query = query.filter(security_finding::Column::Severity.eq(severity));
+        }
+        let rows = query
+            .order_by_desc(security_finding::Column::LastObserved)
+            .all(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+}
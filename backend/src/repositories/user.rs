@@ -66,6 +66,21 @@ impl UserRepository {
         Ok(user)
     }
 
+    pub async fn find_by_oidc_identity(
+        &self,
+        issuer: &str,
+        subject: &str,
+    ) -> Result<Option<UserModel>, AppError> {
+        let user = User::find()
+            .filter(user::Column::OidcIssuer.eq(issuer))
+            .filter(user::Column::OidcSubject.eq(subject))
+            .one(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+
+        Ok(user)
+    }
+
     pub async fn create(&self, user_data: &CreateUserDto) -> Result<UserModel, AppError> {
         // Check if username or email already exists
         if let Some(_) = self.find_by_username(&user_data.username).await? {
@@ -99,6 +114,8 @@ impl UserRepository {
             created_at: Set(Utc::now()),
             updated_at: Set(Utc::now()),
             last_login: Set(None),
+            oidc_issuer: Set(None),
+            oidc_subject: Set(None),
         };
 
         let user = user.insert(&*self.db).await.map_err(AppError::Database)?;
@@ -221,6 +238,37 @@ impl UserRepository {
         Ok(count)
     }
 
+    // Links the user to its identity provider account. The roles for this login are
+    // returned as permissions only; the stored roles stay the locally granted ones.
+    pub async fn record_external_login(
+        &self,
+        id: Uuid,
+        issuer: &str,
+        subject: &str,
+        roles: &[String],
+    ) -> Result<UserModel, AppError> {
+        let user = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("User not found with ID: {}", id)))?;
+
+        let mut user_active: UserActiveModel = user.into();
+        user_active.oidc_issuer = Set(Some(issuer.to_string()));
+        user_active.oidc_subject = Set(Some(subject.to_string()));
+        user_active.last_login = Set(Some(Utc::now()));
+        user_active.updated_at = Set(Utc::now());
+
+        let updated_user = user_active
+            .update(&*self.db)
+            .await
+            .map_err(AppError::Database)?;
+
+        Ok(UserModel {
+            permissions: roles.to_vec(),
+            ..updated_user
+        })
+    }
+
     pub async fn delete(&self, id: Uuid) -> Result<(), AppError> {
         let user = self
             .find_by_id(id)
@@ -0,0 +1,120 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use tracing::{info, warn};
+
+use crate::config::Config;
+use crate::errors::AppError;
+use crate::models::audit_event::AuditEvent;
+use crate::models::cluster::KafkaClusterConfig;
+use crate::repositories::audit_event::AuditEventRepository;
+use crate::services::kafka::build_client_config;
+
+pub enum AuditSink {
+    Database(Arc<AuditEventRepository>),
+    Kafka {
+        producer: FutureProducer,
+        topic: String,
+    },
+}
+
+// Records destructive operations (deletes, scaling, restarts, offset resets).
+// Emission is best effort: a failing sink is logged and never fails the operation itself.
+pub struct AuditEventEmitter {
+    sink: AuditSink,
+}
+
+impl AuditEventEmitter {
+    pub fn new(sink: AuditSink) -> Self {
+        Self { sink }
+    }
+
+    pub fn from_config(config: &Config, repo: Arc<AuditEventRepository>) -> Result<Self, AppError> {
+        let cluster_name = match &config.audit.kafka_cluster {
+            Some(name) => name,
+            None => return Ok(Self::new(AuditSink::Database(repo))),
+        };
+
+        let cluster = config
+            .kafka
+            .clusters
+            .iter()
+            .find(|c| &c.name == cluster_name)
+            .ok_or_else(|| {
+                AppError::Config(format!(
+                    "Audit Kafka cluster '{}' is not configured",
+                    cluster_name
+                ))
+            })?;
+
+        // Same connection settings as every other client for this cluster
+        let mut client_config = build_client_config(&KafkaClusterConfig::from(cluster));
+        client_config.set("client.id", "mayyam-audit-producer");
+
+        let producer: FutureProducer = client_config.create()?;
+        info!(
+            "Audit events will be published to Kafka topic '{}' on cluster '{}'",
+            config.audit.kafka_topic, cluster_name
+        );
+
+        Ok(Self::new(AuditSink::Kafka {
+            producer,
+            topic: config.audit.kafka_topic.clone(),
+        }))
+    }
+
+    pub async fn emit(&self, event: AuditEvent) {
+        if let Err(e) = self.write(&event).await {
+            warn!(
+                operation = %event.operation,
+                resource_type = %event.resource_type,
+                resource_id = %event.resource_id,
+                "Failed to record audit event: {}",
+                e
+            );
+        }
+    }
+
+    async fn write(&self, event: &AuditEvent) -> Result<(), AppError> {
+        match &self.sink {
+            AuditSink::Database(repo) => {
+                repo.create(event).await?;
+            }
+            AuditSink::Kafka { producer, topic } => {
+                let payload = serde_json::to_string(event)?;
+                let key = format!("{}/{}", event.resource_type, event.resource_id);
+                producer
+                    .send(
+                        FutureRecord::to(topic).key(&key).payload(&payload),
+                        Duration::from_secs(10),
+                    )
+                    .await
+                    .map_err(|(e, _)| AppError::Kafka(e.to_string()))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Convenience for services whose emitter is optional
+pub async fn emit_if_configured(emitter: &Option<Arc<AuditEventEmitter>>, event: AuditEvent) {
+    if let Some(emitter) = emitter {
+        emitter.emit(event).await;
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 
+pub mod audit_event_emitter;
 pub mod aws_analytics;
 pub mod cloudwatch_analytics;
 pub mod mysql_analytics;
@@ -13,19 +13,31 @@
 // limitations under the License.
 
 
-use crate::config::Config;
+use crate::config::{Config, PostgresConfig};
 use crate::errors::AppError;
 use crate::models::database::{
     ComputeMetrics, CostAnalysis, CostRecommendation, DatabaseAnalysis, DatabaseIssue,
-    DatabaseQueryResponse, FrequentQuery, IndexStats, IssueCategory, IssueSeverity,
+    DatabaseQueryResponse, FrequentQuery, IndexBloatInfo, IndexStats, IssueCategory, IssueSeverity,
     PerformanceMetrics, QueryPlan, QueryPlanNode, QueryStatistics, ResourceCost, SlowQuery,
-    StorageMetrics, TableStats, TrendDirection,
+    StorageMetrics, TableBloatInfo, TableStats, TrendDirection,
 };
+use crate::utils::database::connect_to_specific_postgres;
 use crate::utils::database_ext::DatabaseConnectionExt;
 use chrono::{DateTime, Utc};
 use sea_orm::{DatabaseConnection, DbBackend, Statement};
 use std::collections::HashMap;
 
+// Thresholds used to flag tables for VACUUM and indexes for REINDEX
+const TABLE_BLOAT_RATIO_THRESHOLD: f64 = 0.2;
+const INDEX_BLOAT_RATIO_THRESHOLD: f64 = 0.3;
+const MIN_BLOAT_BYTES: u64 = 1024 * 1024;
+
+// Default btree fillfactor and per-page overhead (page header + btree special space)
+const BTREE_FILLFACTOR: f64 = 0.9;
+const BTREE_PAGE_OVERHEAD: f64 = 24.0 + 16.0;
+// Index tuple header plus line pointer
+const BTREE_TUPLE_OVERHEAD: f64 = 8.0 + 4.0;
+
 pub struct PostgresAnalyticsService {
     config: Config,
 }
@@ -1007,4 +1019,350 @@ impl PostgresAnalyticsService {
 
         Ok(response)
     }
+
+    pub async fn get_table_bloat(
+        &self,
+        config: &PostgresConfig,
+    ) -> Result<Vec<TableBloatInfo>, AppError> {
+        let conn = connect_to_specific_postgres(config).await?;
+
+        // pgstattuple gives measured numbers; fall back to the statistics
+        // collector estimate when it is missing or not permitted
+        let mut result = if self.has_pgstattuple(&conn).await? {
+            match self.get_table_bloat_pgstattuple(&conn).await {
+                Ok(result) => result,
+                Err(e) => {
+                    tracing::warn!(
+                        "pgstattuple query failed on {}, using estimates: {}",
+                        config.name,
+                        e
+                    );
+                    self.estimate_table_bloat(&conn).await?
+                }
+            }
+        } else {
+            self.estimate_table_bloat(&conn).await?
+        };
+
+        result.sort_by(|a, b| {
+            b.bloat_ratio
+                .partial_cmp(&a.bloat_ratio)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        Ok(result)
+    }
+
+    pub async fn analyze_index_bloat(
+        &self,
+        config: &PostgresConfig,
+    ) -> Result<Vec<IndexBloatInfo>, AppError> {
+        let conn = connect_to_specific_postgres(config).await?;
+
+        let mut result = if self.has_pgstattuple(&conn).await? {
+            match self.get_index_bloat_pgstatindex(&conn).await {
+                Ok(result) => result,
+                Err(e) => {
+                    tracing::warn!(
+                        "pgstatindex query failed on {}, using estimates: {}",
+                        config.name,
+                        e
+                    );
+                    self.estimate_index_bloat(&conn).await?
+                }
+            }
+        } else {
+            self.estimate_index_bloat(&conn).await?
+        };
+
+        result.sort_by(|a, b| {
+            b.bloat_ratio
+                .partial_cmp(&a.bloat_ratio)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        Ok(result)
+    }
+
+    async fn has_pgstattuple(&self, conn: &DatabaseConnection) -> Result<bool, AppError> {
+        let rows = sea_orm::ConnectionTrait::query_all(
+            conn,
+            Statement::from_string(
+                DbBackend::Postgres,
+                r#"
+            SELECT EXISTS (
+                SELECT 1 FROM pg_extension WHERE extname = 'pgstattuple'
+            ) as installed
+            "#,
+            ),
+        )
+        .await
+        .map_err(AppError::Database)?;
+
+        match rows.first() {
+            Some(row) => Ok(row.try_get::<bool>("", "installed")?),
+            None => Ok(false),
+        }
+    }
+
+    async fn get_table_bloat_pgstattuple(
+        &self,
+        conn: &DatabaseConnection,
+    ) -> Result<Vec<TableBloatInfo>, AppError> {
+        let rows = sea_orm::ConnectionTrait::query_all(
+            conn,
+            Statement::from_string(
+                DbBackend::Postgres,
+                r#"
+            SELECT
+                s.schemaname as schema,
+                s.relname as table_name,
+                t.approx_tuple_len::bigint as live_bytes,
+                (t.dead_tuple_len + t.approx_free_space)::bigint as dead_bytes,
+                s.last_autovacuum
+            FROM pg_stat_user_tables s
+            JOIN pg_class c ON c.oid = s.relid AND c.relkind = 'r'
+            CROSS JOIN LATERAL pgstattuple_approx(s.relid) t
+            "#,
+            ),
+        )
+        .await
+        .map_err(AppError::Database)?;
+
+        let mut result = Vec::with_capacity(rows.len());
+        for row in rows {
+            let live_bytes = row.try_get::<i64>("", "live_bytes")?.max(0) as u64;
+            let dead_bytes = row.try_get::<i64>("", "dead_bytes")?.max(0) as u64;
+            result.push(table_bloat_info(
+                row.try_get::<String>("", "schema")?,
+                row.try_get::<String>("", "table_name")?,
+                live_bytes,
+                dead_bytes,
+                row.try_get::<Option<DateTime<Utc>>>("", "last_autovacuum")?,
+            ));
+        }
+
+        Ok(result)
+    }
+
+    async fn estimate_table_bloat(
+        &self,
+        conn: &DatabaseConnection,
+    ) -> Result<Vec<TableBloatInfo>, AppError> {
+        let rows = sea_orm::ConnectionTrait::query_all(
+            conn,
+            Statement::from_string(
+                DbBackend::Postgres,
+                r#"
+            SELECT
+                s.schemaname as schema,
+                s.relname as table_name,
+                pg_table_size(s.relid)::bigint as table_bytes,
+                s.n_live_tup::bigint as live_tuples,
+                s.n_dead_tup::bigint as dead_tuples,
+                s.last_autovacuum
+            FROM pg_stat_user_tables s
+            "#,
+            ),
+        )
+        .await
+        .map_err(AppError::Database)?;
+
+        let mut result = Vec::with_capacity(rows.len());
+        for row in rows {
+            let table_bytes = row.try_get::<i64>("", "table_bytes")?.max(0) as u64;
+            let live_tuples = row.try_get::<i64>("", "live_tuples")?.max(0) as f64;
+            let dead_tuples = row.try_get::<i64>("", "dead_tuples")?.max(0) as f64;
+
+            // Split the on-disk size proportionally to live and dead tuple counts
+            let dead_bytes = if live_tuples + dead_tuples > 0.0 {
+                (table_bytes as f64 * dead_tuples / (live_tuples + dead_tuples)) as u64
+            } else {
+                0
+            };
+
+            result.push(table_bloat_info(
+                row.try_get::<String>("", "schema")?,
+                row.try_get::<String>("", "table_name")?,
+                table_bytes - dead_bytes,
+                dead_bytes,
+                row.try_get::<Option<DateTime<Utc>>>("", "last_autovacuum")?,
+            ));
+        }
+
+        Ok(result)
+    }
+
+    async fn get_index_bloat_pgstatindex(
+        &self,
+        conn: &DatabaseConnection,
+    ) -> Result<Vec<IndexBloatInfo>, AppError> {
+        let rows = sea_orm::ConnectionTrait::query_all(
+            conn,
+            Statement::from_string(
+                DbBackend::Postgres,
+                r#"
+            SELECT
+                n.nspname as schema,
+                t.relname as table_name,
+                ic.relname as index_name,
+                p.index_size::bigint as index_bytes,
+                CASE WHEN p.avg_leaf_density = 'NaN' THEN NULL
+                     ELSE p.avg_leaf_density END::float8 as avg_leaf_density
+            FROM pg_index i
+            JOIN pg_class ic ON ic.oid = i.indexrelid
+            JOIN pg_class t ON t.oid = i.indrelid
+            JOIN pg_namespace n ON n.oid = t.relnamespace
+            JOIN pg_am am ON am.oid = ic.relam
+            CROSS JOIN LATERAL pgstatindex(i.indexrelid::regclass) p
+            WHERE am.amname = 'btree'
+              AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+              AND n.nspname NOT LIKE 'pg_toast%'
+            "#,
+            ),
+        )
+        .await
+        .map_err(AppError::Database)?;
+
+        let mut result = Vec::with_capacity(rows.len());
+        for row in rows {
+            let index_bytes = row.try_get::<i64>("", "index_bytes")?.max(0) as u64;
+            // A freshly built btree packs leaves to the fillfactor, anything
+            // below that is reclaimable by REINDEX
+            let estimated_bloat_bytes = match row.try_get::<Option<f64>>("", "avg_leaf_density")? {
+                Some(density) => {
+                    let wasted = 1.0 - (density / 100.0) / BTREE_FILLFACTOR;
+                    (index_bytes as f64 * wasted.max(0.0)) as u64
+                }
+                None => 0,
+            };
+
+            result.push(index_bloat_info(
+                row.try_get::<String>("", "schema")?,
+                row.try_get::<String>("", "table_name")?,
+                row.try_get::<String>("", "index_name")?,
+                index_bytes,
+                estimated_bloat_bytes,
+            ));
+        }
+
+        Ok(result)
+    }
+
+    async fn estimate_index_bloat(
+        &self,
+        conn: &DatabaseConnection,
+    ) -> Result<Vec<IndexBloatInfo>, AppError> {
+        let rows = sea_orm::ConnectionTrait::query_all(
+            conn,
+            Statement::from_string(
+                DbBackend::Postgres,
+                r#"
+            SELECT
+                n.nspname as schema,
+                t.relname as table_name,
+                ic.relname as index_name,
+                pg_relation_size(ic.oid)::bigint as index_bytes,
+                GREATEST(ic.reltuples, 0)::float8 as index_tuples,
+                COALESCE((
+                    SELECT sum(st.avg_width)
+                    FROM pg_attribute a
+                    JOIN pg_stats st
+                      ON st.schemaname = n.nspname
+                     AND st.tablename = t.relname
+                     AND st.attname = a.attname
+                    WHERE a.attrelid = t.oid
+                      AND a.attnum = ANY(i.indkey)
+                ), 0)::float8 as key_width,
+                current_setting('block_size')::float8 as block_size
+            FROM pg_index i
+            JOIN pg_class ic ON ic.oid = i.indexrelid
+            JOIN pg_class t ON t.oid = i.indrelid
+            JOIN pg_namespace n ON n.oid = t.relnamespace
+            JOIN pg_am am ON am.oid = ic.relam
+            WHERE am.amname = 'btree'
+              AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+              AND n.nspname NOT LIKE 'pg_toast%'
+            "#,
+            ),
+        )
+        .await
+        .map_err(AppError::Database)?;
+
+        let mut result = Vec::with_capacity(rows.len());
+        for row in rows {
+            let index_bytes = row.try_get::<i64>("", "index_bytes")?.max(0) as u64;
+            let index_tuples = row.try_get::<f64>("", "index_tuples")?;
+            let key_width = row.try_get::<f64>("", "key_width")?;
+            let block_size = row.try_get::<f64>("", "block_size")?;
+
+            // Expected size of a freshly built index: leaf pages packed to the
+            // fillfactor plus the metapage
+            let tuple_bytes = key_width + BTREE_TUPLE_OVERHEAD;
+            let usable_bytes = (block_size - BTREE_PAGE_OVERHEAD) * BTREE_FILLFACTOR;
+            let expected_pages = (index_tuples * tuple_bytes / usable_bytes).ceil() + 1.0;
+            let expected_bytes = (expected_pages * block_size) as u64;
+
+            result.push(index_bloat_info(
+                row.try_get::<String>("", "schema")?,
+                row.try_get::<String>("", "table_name")?,
+                row.try_get::<String>("", "index_name")?,
+                index_bytes,
+                index_bytes.saturating_sub(expected_bytes),
+            ));
+        }
+
+        Ok(result)
+    }
+}
+
+fn table_bloat_info(
+    schema: String,
+    table_name: String,
+    live_bytes: u64,
+    dead_bytes: u64,
+    last_autovacuum: Option<DateTime<Utc>>,
+) -> TableBloatInfo {
+    let total = live_bytes + dead_bytes;
+    let bloat_ratio = if total > 0 {
+        dead_bytes as f64 / total as f64
+    } else {
+        0.0
+    };
+
+    TableBloatInfo {
+        schema,
+        table_name,
+        live_bytes,
+        dead_bytes,
+        bloat_ratio,
+        last_autovacuum,
+        vacuum_recommended: bloat_ratio >= TABLE_BLOAT_RATIO_THRESHOLD
+            && dead_bytes >= MIN_BLOAT_BYTES,
+    }
+}
+
+fn index_bloat_info(
+    schema: String,
+    table_name: String,
+    index_name: String,
+    index_bytes: u64,
+    estimated_bloat_bytes: u64,
+) -> IndexBloatInfo {
+    let bloat_ratio = if index_bytes > 0 {
+        estimated_bloat_bytes as f64 / index_bytes as f64
+    } else {
+        0.0
+    };
+
+    IndexBloatInfo {
+        schema,
+        table_name,
+        index_name,
+        index_bytes,
+        estimated_bloat_bytes,
+        bloat_ratio,
+        reindex_recommended: bloat_ratio >= INDEX_BLOAT_RATIO_THRESHOLD
+            && estimated_bloat_bytes >= MIN_BLOAT_BYTES,
+    }
 }
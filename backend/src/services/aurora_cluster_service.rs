@@ -0,0 +1,244 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aws_sdk_cloudwatch::types::{Dimension, Statistic};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::errors::AppError;
+use crate::models::aurora_cluster::AuroraClusterConfig;
+use crate::models::aws_account::AwsAccountDto;
+use crate::repositories::aws_account::AwsAccountRepository;
+use crate::services::aws::AwsService;
+
+// Readers further behind the writer than this are flagged as lagging
+const REPLICA_LAG_THRESHOLD_SECS: f64 = 1.0;
+// CloudWatch publishes replica lag every minute; look back far enough to find a datapoint
+const REPLICA_LAG_WINDOW_SECS: i64 = 300;
+const REPLICA_LAG_PERIOD_SECS: i32 = 60;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReaderLag {
+    pub instance_id: String,
+    pub endpoint: Option<String>,
+    // None when CloudWatch has no recent datapoint for the reader
+    pub lag_seconds: Option<f64>,
+    pub is_lagging_behind: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplicationStatus {
+    pub cluster_id: String,
+    pub readers: Vec<ReaderLag>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ClusterInstance {
+    pub instance_id: String,
+    // "writer" or "reader"
+    pub role: String,
+    pub endpoint: Option<String>,
+    pub availability_zone: Option<String>,
+    pub status: Option<String>,
+    pub promotion_tier: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ClusterTopology {
+    pub cluster_id: String,
+    pub status: Option<String>,
+    pub writer_endpoint: Option<String>,
+    pub reader_endpoint: Option<String>,
+    pub instances: Vec<ClusterInstance>,
+}
+
+pub struct AuroraClusterService {
+    aws_service: Arc<AwsService>,
+    aws_account_repo: Arc<AwsAccountRepository>,
+}
+
+impl AuroraClusterService {
+    pub fn new(aws_service: Arc<AwsService>, aws_account_repo: Arc<AwsAccountRepository>) -> Self {
+        Self {
+            aws_service,
+            aws_account_repo,
+        }
+    }
+
+    async fn account_for(
+        &self,
+        cluster_config: &AuroraClusterConfig,
+    ) -> Result<AwsAccountDto, AppError> {
+        let aws_account = self
+            .aws_account_repo
+            .get_by_account_id(&cluster_config.account_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "AWS account {} not found",
+                    cluster_config.account_id
+                ))
+            })?;
+        let mut aws_account_dto = AwsAccountDto::from(aws_account);
+        aws_account_dto.default_region = cluster_config.region.clone();
+        Ok(aws_account_dto)
+    }
+
+    /// Writer and reader instances of the cluster with their endpoints and placement.
+    pub async fn get_cluster_topology(
+        &self,
+        cluster_config: &AuroraClusterConfig,
+    ) -> Result<ClusterTopology, AppError> {
+        let aws_account_dto = self.account_for(cluster_config).await?;
+        let rds_client = self.aws_service.create_rds_client(&aws_account_dto).await?;
+        let cluster_id = &cluster_config.cluster_identifier;
+
+        let response = rds_client
+            .describe_db_clusters()
+            .db_cluster_identifier(cluster_id)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::CloudProvider(format!("Failed to describe Aurora cluster: {:?}", e))
+            })?;
+        let cluster = response.db_clusters().first().ok_or_else(|| {
+            AppError::NotFound(format!("Aurora cluster {} not found", cluster_id))
+        })?;
+
+        // Cluster members carry the roles; endpoints and AZs live on the instances
+        let cluster_filter = aws_sdk_rds::types::Filter::builder()
+            .name("db-cluster-id")
+            .values(cluster_id)
+            .build()
+            .map_err(|e| AppError::Internal(format!("Failed to build instance filter: {}", e)))?;
+        let instances = rds_client
+            .describe_db_instances()
+            .filters(cluster_filter)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::CloudProvider(format!("Failed to describe Aurora instances: {:?}", e))
+            })?;
+        let instances_by_id: HashMap<&str, &aws_sdk_rds::types::DbInstance> = instances
+            .db_instances()
+            .iter()
+            .filter_map(|i| Some((i.db_instance_identifier()?, i)))
+            .collect();
+
+        let instances = cluster
+            .db_cluster_members()
+            .iter()
+            .filter_map(|member| {
+                let instance_id = member.db_instance_identifier()?;
+                let instance = instances_by_id.get(instance_id);
+                let role = if member.is_cluster_writer() == Some(true) {
+                    "writer"
+                } else {
+                    "reader"
+                };
+                Some(ClusterInstance {
+                    instance_id: instance_id.to_string(),
+                    role: role.to_string(),
+                    endpoint: instance
+                        .and_then(|i| i.endpoint())
+                        .and_then(|e| e.address())
+                        .map(|a| a.to_string()),
+                    availability_zone: instance
+                        .and_then(|i| i.availability_zone())
+                        .map(|az| az.to_string()),
+                    status: instance
+                        .and_then(|i| i.db_instance_status())
+                        .map(|s| s.to_string()),
+                    promotion_tier: member.promotion_tier(),
+                })
+            })
+            .collect();
+
+        Ok(ClusterTopology {
+            cluster_id: cluster_id.clone(),
+            status: cluster.status().map(|s| s.to_string()),
+            writer_endpoint: cluster.endpoint().map(|e| e.to_string()),
+            reader_endpoint: cluster.reader_endpoint().map(|e| e.to_string()),
+            instances,
+        })
+    }
+
+    /// Latest replica lag of every reader in the cluster. AuroraBinlogReplicaLag only covers
+    /// binlog replicas in other clusters, so in-cluster readers are measured with
+    /// AuroraReplicaLag, which CloudWatch reports per instance in milliseconds.
+    pub async fn get_replication_lag(
+        &self,
+        cluster_config: &AuroraClusterConfig,
+    ) -> Result<ReplicationStatus, AppError> {
+        let topology = self.get_cluster_topology(cluster_config).await?;
+        let aws_account_dto = self.account_for(cluster_config).await?;
+        let client = self
+            .aws_service
+            .create_cloudwatch_client(&aws_account_dto)
+            .await?;
+        let now = chrono::Utc::now().timestamp();
+
+        let mut readers = Vec::new();
+        for instance in topology
+            .instances
+            .into_iter()
+            .filter(|i| i.role == "reader")
+        {
+            let response = client
+                .get_metric_statistics()
+                .namespace("AWS/RDS")
+                .metric_name("AuroraReplicaLag")
+                .dimensions(
+                    Dimension::builder()
+                        .name("DBInstanceIdentifier")
+                        .value(&instance.instance_id)
+                        .build(),
+                )
+                .start_time(aws_sdk_cloudwatch::primitives::DateTime::from_secs(
+                    now - REPLICA_LAG_WINDOW_SECS,
+                ))
+                .end_time(aws_sdk_cloudwatch::primitives::DateTime::from_secs(now))
+                .period(REPLICA_LAG_PERIOD_SECS)
+                .statistics(Statistic::Average)
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::ExternalService(format!(
+                        "Failed to get replica lag for {}: {}",
+                        instance.instance_id, e
+                    ))
+                })?;
+
+            let lag_seconds = response
+                .datapoints()
+                .iter()
+                .filter(|d| d.timestamp().is_some())
+                .max_by_key(|d| d.timestamp().map(|t| t.secs()))
+                .and_then(|d| d.average())
+                .map(|ms| ms / 1000.0);
+            readers.push(ReaderLag {
+                instance_id: instance.instance_id,
+                endpoint: instance.endpoint,
+                lag_seconds,
+                is_lagging_behind: lag_seconds.map_or(false, |l| l > REPLICA_LAG_THRESHOLD_SECS),
+            });
+        }
+
+        Ok(ReplicationStatus {
+            cluster_id: topology.cluster_id,
+            readers,
+        })
+    }
+}
@@ -58,6 +58,8 @@ use aws_sdk_storagegateway::Client as StorageGatewayClient;
 use aws_sdk_connect::Client as ConnectClient;
 use aws_sdk_appsync::Client as AppSyncClient;
 use aws_sdk_kinesisanalyticsv2::Client as KinesisAnalyticsClient;
+use aws_sdk_autoscaling::Client as AutoScalingClient;
+use aws_sdk_pi::Client as PiClient;
 
 use crate::models::aws_account::AwsAccountDto;
 use crate::{errors::AppError};
@@ -239,4 +241,12 @@ pub trait AwsClientFactory {
         &self,
         aws_account_dto: &AwsAccountDto,
     ) -> Result<KinesisAnalyticsClient, AppError>;
+    async fn create_autoscaling_client(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+    ) -> Result<AutoScalingClient, AppError>;
+    async fn create_pi_client(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+    ) -> Result<PiClient, AppError>;
 }
@@ -13,10 +13,21 @@
 // limitations under the License.
 
 
+use crate::config::{Config, SecurityConfig};
 use crate::errors::AppError;
 use crate::models::aws_account::AwsAccountDto;
 use aws_config::{BehaviorVersion, Region};
 use aws_credential_types::Credentials;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::info;
+
+// Config values with this prefix name a Secrets Manager secret (ARN or name). A "#field"
+// suffix selects one field of a JSON secret, e.g. "sm://prod/db#password".
+pub const SECRETS_MANAGER_PREFIX: &str = "sm://";
+// Field read from JSON secrets when no selector is given, matching the RDS secret layout
+const DEFAULT_SECRET_FIELD: &str = "password";
 
 pub struct AwsConfigService {}
 
@@ -65,3 +76,131 @@ impl AwsConfigService {
         return Ok(config);
     }
 }
+
+// Resolves "sm://" placeholders in the config from AWS Secrets Manager. Credentials come
+// from the default AWS provider chain of the host, not from a registered AWS account.
+pub struct SecretsManagerCredentialProvider {
+    client: aws_sdk_secretsmanager::Client,
+    refresh_interval: Duration,
+    cache: Mutex<HashMap<String, (String, Instant)>>,
+}
+
+impl SecretsManagerCredentialProvider {
+    pub async fn new(security: &SecurityConfig) -> Self {
+        let mut loader = aws_config::defaults(BehaviorVersion::latest());
+        if let Some(region) = security.secrets_manager_region.as_ref() {
+            loader = loader.region(Region::new(region.clone()));
+        }
+        let sdk_config = loader.load().await;
+        Self {
+            client: aws_sdk_secretsmanager::Client::new(&sdk_config),
+            refresh_interval: Duration::from_secs(security.secret_refresh_interval_secs),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn refresh_interval(&self) -> Duration {
+        self.refresh_interval
+    }
+
+    /// Whether any supported config field holds a secret reference
+    pub fn has_references(config: &Config) -> bool {
+        let is_ref = |v: &str| v.starts_with(SECRETS_MANAGER_PREFIX);
+        config.database.postgres.iter().any(|c| is_ref(&c.password))
+            || config.database.mysql.iter().any(|c| is_ref(&c.password))
+            || config
+                .kafka
+                .clusters
+                .iter()
+                .any(|c| c.sasl_password.as_deref().map_or(false, is_ref))
+            || is_ref(&config.auth.jwt_secret)
+    }
+
+    /// Replace secret references in database passwords, Kafka SASL passwords and the JWT secret
+    pub async fn resolve_config(&self, config: &mut Config) -> Result<(), AppError> {
+        for postgres in &mut config.database.postgres {
+            postgres.password = self.resolve(&postgres.password).await?;
+        }
+        for mysql in &mut config.database.mysql {
+            mysql.password = self.resolve(&mysql.password).await?;
+        }
+        for cluster in &mut config.kafka.clusters {
+            if let Some(password) = cluster.sasl_password.as_mut() {
+                *password = self.resolve(password).await?;
+            }
+        }
+        config.auth.jwt_secret = self.resolve(&config.auth.jwt_secret).await?;
+        Ok(())
+    }
+
+    /// Values without the "sm://" prefix are returned unchanged
+    pub async fn resolve(&self, value: &str) -> Result<String, AppError> {
+        let Some(reference) = value.strip_prefix(SECRETS_MANAGER_PREFIX) else {
+            return Ok(value.to_string());
+        };
+        let (secret_id, field) = match reference.split_once('#') {
+            Some((id, field)) => (id, Some(field)),
+            None => (reference, None),
+        };
+        let secret = self.fetch(secret_id).await?;
+        select_secret_field(secret_id, &secret, field)
+    }
+
+    async fn fetch(&self, secret_id: &str) -> Result<String, AppError> {
+        if let Some((cached, fetched_at)) = self.cache.lock().unwrap().get(secret_id) {
+            if fetched_at.elapsed() < self.refresh_interval {
+                return Ok(cached.clone());
+            }
+        }
+
+        let response = self
+            .client
+            .get_secret_value()
+            .secret_id(secret_id)
+            .send()
+            .await
+            .map_err(|e| AppError::Config(format!("Failed to read secret {}: {}", secret_id, e)))?;
+        let secret = response
+            .secret_string()
+            .ok_or_else(|| AppError::Config(format!("Secret {} has no string value", secret_id)))?
+            .to_string();
+
+        info!("Resolved config secret {} from Secrets Manager", secret_id);
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(secret_id.to_string(), (secret.clone(), Instant::now()));
+        Ok(secret)
+    }
+}
+
+// Plain-text secrets are used as is. JSON objects yield the selected field, or "password"
+// when no field is selected; a selector on a non-JSON secret is an error.
+fn select_secret_field(
+    secret_id: &str,
+    secret: &str,
+    field: Option<&str>,
+) -> Result<String, AppError> {
+    let parsed = serde_json::from_str::<serde_json::Value>(secret)
+        .ok()
+        .filter(|v| v.is_object());
+    let Some(object) = parsed else {
+        return match field {
+            Some(field) => Err(AppError::Config(format!(
+                "Secret {} is not a JSON object, cannot select field '{}'",
+                secret_id, field
+            ))),
+            None => Ok(secret.to_string()),
+        };
+    };
+
+    let name = field.unwrap_or(DEFAULT_SECRET_FIELD);
+    match object.get(name) {
+        Some(serde_json::Value::String(s)) => Ok(s.clone()),
+        Some(v) if !v.is_null() => Ok(v.to_string()),
+        _ => Err(AppError::Config(format!(
+            "Secret {} has no field '{}'",
+            secret_id, name
+        ))),
+    }
+}
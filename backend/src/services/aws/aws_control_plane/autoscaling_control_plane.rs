@@ -0,0 +1,261 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use crate::errors::AppError;
+use crate::models::aws_account::AwsAccountDto;
+use crate::models::aws_resource::{AwsResourceDto, AwsResourceType, Model as AwsResourceModel};
+use crate::services::aws::aws_types::autoscaling::AsgScalingActionResponse;
+use crate::services::aws::client_factory::AwsClientFactory;
+use crate::services::AwsService;
+use serde_json::json;
+use std::sync::Arc;
+use tracing::{debug, error, info};
+use uuid::Uuid;
+
+pub struct AutoScalingControlPlane {
+    aws_service: Arc<AwsService>,
+}
+
+impl AutoScalingControlPlane {
+    pub fn new(aws_service: Arc<AwsService>) -> Self {
+        Self { aws_service }
+    }
+
+    pub async fn sync_autoscaling_groups(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        sync_id: Uuid,
+    ) -> Result<Vec<AwsResourceModel>, AppError> {
+        debug!(
+            "Syncing Auto Scaling Groups for account: {} with sync_id: {}",
+            &aws_account_dto.account_id, sync_id
+        );
+
+        let client = self.aws_service.create_autoscaling_client(aws_account_dto).await?;
+        let mut resources: Vec<AwsResourceModel> = Vec::new();
+
+        let mut next_token = None;
+
+        loop {
+            let mut request = client.describe_auto_scaling_groups();
+            if let Some(t) = next_token {
+                request = request.next_token(t);
+            }
+
+            let response = match request.send().await {
+                Ok(res) => res,
+                Err(e) => {
+                    error!("Failed to describe Auto Scaling Groups: {}", e);
+                    break;
+                }
+            };
+
+            for asg in response.auto_scaling_groups() {
+                let name = asg.auto_scaling_group_name().unwrap_or_default();
+                let arn = asg.auto_scaling_group_arn().unwrap_or_default();
+
+                let mut tags_map = serde_json::Map::new();
+                for tag in asg.tags() {
+                    if let (Some(key), Some(value)) = (tag.key(), tag.value()) {
+                        tags_map.insert(key.to_string(), json!(value));
+                    }
+                }
+
+                let launch_template = asg.launch_template().map(|lt| {
+                    json!({
+                        "launch_template_id": lt.launch_template_id(),
+                        "launch_template_name": lt.launch_template_name(),
+                        "version": lt.version(),
+                    })
+                });
+
+                let instances: Vec<serde_json::Value> = asg
+                    .instances()
+                    .iter()
+                    .map(|i| {
+                        json!({
+                            "instance_id": i.instance_id(),
+                            "instance_type": i.instance_type(),
+                            "availability_zone": i.availability_zone(),
+                            "lifecycle_state": i.lifecycle_state().map(|s| s.as_str()),
+                            "health_status": i.health_status(),
+                        })
+                    })
+                    .collect();
+
+                let suspended_processes: Vec<&str> = asg
+                    .suspended_processes()
+                    .iter()
+                    .filter_map(|p| p.process_name())
+                    .collect();
+
+                let resource_data = json!({
+                    "auto_scaling_group_name": name,
+                    "desired_capacity": asg.desired_capacity(),
+                    "min_size": asg.min_size(),
+                    "max_size": asg.max_size(),
+                    "default_cooldown": asg.default_cooldown(),
+                    "launch_template": launch_template,
+                    "launch_configuration_name": asg.launch_configuration_name(),
+                    "availability_zones": asg.availability_zones(),
+                    "health_check_type": asg.health_check_type(),
+                    "status": asg.status(),
+                    "suspended_processes": suspended_processes,
+                    "instances": instances,
+                });
+
+                let dto = AwsResourceDto {
+                    id: None,
+                    sync_id: Some(sync_id),
+                    account_id: aws_account_dto.account_id.clone(),
+                    profile: aws_account_dto.profile.clone(),
+                    region: aws_account_dto.default_region.clone(),
+                    resource_type: AwsResourceType::AutoScalingGroup.to_string(),
+                    resource_id: name.to_string(),
+                    arn: arn.to_string(),
+                    name: Some(name.to_string()),
+                    tags: serde_json::Value::Object(tags_map),
+                    resource_data,
+                };
+
+                resources.push(dto.into());
+            }
+
+            next_token = response.next_token().map(String::from);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        debug!(
+            "Successfully synced {} Auto Scaling Groups for account: {} with sync_id: {}",
+            resources.len(),
+            &aws_account_dto.account_id,
+            sync_id
+        );
+
+        Ok(resources)
+    }
+
+    pub async fn set_asg_desired_capacity(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        asg_name: &str,
+        desired: i32,
+        honor_cooldown: bool,
+    ) -> Result<AsgScalingActionResponse, AppError> {
+        if desired < 0 {
+            return Err(AppError::Validation(
+                "Desired capacity must not be negative".to_string(),
+            ));
+        }
+
+        let client = self.aws_service.create_autoscaling_client(aws_account_dto).await?;
+
+        client
+            .set_desired_capacity()
+            .auto_scaling_group_name(asg_name)
+            .desired_capacity(desired)
+            .honor_cooldown(honor_cooldown)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to set desired capacity for Auto Scaling Group {}: {}",
+                    asg_name, e
+                ))
+            })?;
+
+        info!(
+            "Set desired capacity of Auto Scaling Group {} to {} (honor_cooldown: {})",
+            asg_name, desired, honor_cooldown
+        );
+
+        Ok(AsgScalingActionResponse {
+            auto_scaling_group_name: asg_name.to_string(),
+            action: "set_desired_capacity".to_string(),
+            desired_capacity: Some(desired),
+            processes: Vec::new(),
+        })
+    }
+
+    // An empty process list suspends every scaling process, matching the AWS API semantics
+    pub async fn suspend_asg_processes(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        asg_name: &str,
+        processes: Vec<String>,
+    ) -> Result<AsgScalingActionResponse, AppError> {
+        let client = self.aws_service.create_autoscaling_client(aws_account_dto).await?;
+
+        let mut request = client.suspend_processes().auto_scaling_group_name(asg_name);
+        if !processes.is_empty() {
+            request = request.set_scaling_processes(Some(processes.clone()));
+        }
+
+        request.send().await.map_err(|e| {
+            AppError::ExternalService(format!(
+                "Failed to suspend processes for Auto Scaling Group {}: {}",
+                asg_name, e
+            ))
+        })?;
+
+        info!(
+            "Suspended processes {:?} on Auto Scaling Group {}",
+            processes, asg_name
+        );
+
+        Ok(AsgScalingActionResponse {
+            auto_scaling_group_name: asg_name.to_string(),
+            action: "suspend_processes".to_string(),
+            desired_capacity: None,
+            processes,
+        })
+    }
+
+    // An empty process list resumes every suspended process
+    pub async fn resume_asg_processes(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        asg_name: &str,
+        processes: Vec<String>,
+    ) -> Result<AsgScalingActionResponse, AppError> {
+        let client = self.aws_service.create_autoscaling_client(aws_account_dto).await?;
+
+        let mut request = client.resume_processes().auto_scaling_group_name(asg_name);
+        if !processes.is_empty() {
+            request = request.set_scaling_processes(Some(processes.clone()));
+        }
+
+        request.send().await.map_err(|e| {
+            AppError::ExternalService(format!(
+                "Failed to resume processes for Auto Scaling Group {}: {}",
+                asg_name, e
+            ))
+        })?;
+
+        info!(
+            "Resumed processes {:?} on Auto Scaling Group {}",
+            processes, asg_name
+        );
+
+        Ok(AsgScalingActionResponse {
+            auto_scaling_group_name: asg_name.to_string(),
+            action: "resume_processes".to_string(),
+            desired_capacity: None,
+            processes,
+        })
+    }
+}
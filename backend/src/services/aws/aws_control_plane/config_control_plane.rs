@@ -15,13 +15,26 @@
 use crate::errors::AppError;
 use crate::models::aws_account::AwsAccountDto;
 use crate::models::aws_resource::{AwsResourceDto, AwsResourceType, Model as AwsResourceModel};
+use crate::models::config_violation::ConfigViolation;
 use crate::services::aws::client_factory::AwsClientFactory;
 use crate::services::AwsService;
+use aws_sdk_config::types::{ComplianceType, EvaluationResult};
+use aws_sdk_config::Client as ConfigClient;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::sync::Arc;
-use tracing::debug;
+use tracing::{debug, info};
 use uuid::Uuid;
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComplianceReport {
+    pub compliant_resources: u32,
+    pub non_compliant_resources: u32,
+    pub insufficient_data_resources: u32,
+    pub violations: Vec<ConfigViolation>,
+}
+
 pub struct ConfigControlPlane {
     aws_service: Arc<AwsService>,
 }
@@ -60,4 +73,139 @@ impl ConfigControlPlane {
 
         Ok(resources)
     }
+    async fn client(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+    ) -> Result<ConfigClient, AppError> {
+        let sdk_config = self.aws_service.get_aws_sdk_config(aws_account_dto).await?;
+        let config = aws_sdk_config::config::Builder::from(&sdk_config)
+            .region(aws_types::region::Region::new(region.to_string()))
+            .build();
+        Ok(ConfigClient::from_conf(config))
+    }
+
+    // Counts resources by compliance state and collects the failing rule evaluations
+    // of every non-compliant resource
+    pub async fn check_compliance(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        resource_type: Option<String>,
+    ) -> Result<ComplianceReport, AppError> {
+        let client = self.client(aws_account_dto, region).await?;
+
+        let mut report = ComplianceReport {
+            compliant_resources: 0,
+            non_compliant_resources: 0,
+            insufficient_data_resources: 0,
+            violations: Vec::new(),
+        };
+        let mut non_compliant: Vec<(String, String)> = Vec::new();
+
+        let mut next_token: Option<String> = None;
+        loop {
+            let response = client
+                .describe_compliance_by_resource()
+                .set_resource_type(resource_type.clone())
+                .limit(100)
+                .set_next_token(next_token.take())
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::CloudProvider(format!("Failed to describe Config compliance: {}", e))
+                })?;
+
+            for resource in response.compliance_by_resources() {
+                match resource.compliance().and_then(|c| c.compliance_type()) {
+                    Some(ComplianceType::Compliant) => report.compliant_resources += 1,
+                    Some(ComplianceType::NonCompliant) => {
+                        report.non_compliant_resources += 1;
+                        if let (Some(resource_type), Some(resource_id)) =
+                            (resource.resource_type(), resource.resource_id())
+                        {
+                            non_compliant
+                                .push((resource_type.to_string(), resource_id.to_string()));
+                        }
+                    }
+                    Some(ComplianceType::InsufficientData) => {
+                        report.insufficient_data_resources += 1
+                    }
+                    _ => {}
+                }
+            }
+
+            match response.next_token() {
+                Some(token) if !token.is_empty() => next_token = Some(token.to_string()),
+                _ => break,
+            }
+        }
+
+        for (resource_type, resource_id) in non_compliant {
+            let mut next_token: Option<String> = None;
+            loop {
+                let response = client
+                    .get_compliance_details_by_resource()
+                    .resource_type(&resource_type)
+                    .resource_id(&resource_id)
+                    .compliance_types(ComplianceType::NonCompliant)
+                    .set_next_token(next_token.take())
+                    .send()
+                    .await
+                    .map_err(|e| {
+                        AppError::CloudProvider(format!(
+                            "Failed to get Config compliance details for {}: {}",
+                            resource_id, e
+                        ))
+                    })?;
+
+                report.violations.extend(
+                    response
+                        .evaluation_results()
+                        .iter()
+                        .map(|result| to_violation(&resource_type, &resource_id, result)),
+                );
+
+                match response.next_token() {
+                    Some(token) if !token.is_empty() => next_token = Some(token.to_string()),
+                    _ => break,
+                }
+            }
+        }
+
+        info!(
+            "Config compliance for account {} in {}: {} compliant, {} non-compliant, {} violations",
+            aws_account_dto.account_id,
+            region,
+            report.compliant_resources,
+            report.non_compliant_resources,
+            report.violations.len()
+        );
+        Ok(report)
+    }
+}
+
+fn to_violation(
+    resource_type: &str,
+    resource_id: &str,
+    result: &EvaluationResult,
+) -> ConfigViolation {
+    let rule_name = result
+        .evaluation_result_identifier()
+        .and_then(|id| id.evaluation_result_qualifier())
+        .and_then(|q| q.config_rule_name())
+        .unwrap_or_default();
+    let evaluation_time = result
+        .result_recorded_time()
+        .or_else(|| result.config_rule_invoked_time())
+        .map(|dt| DateTime::from_timestamp(dt.secs(), dt.subsec_nanos()).unwrap_or_default())
+        .unwrap_or_else(Utc::now);
+
+    ConfigViolation {
+        resource_type: resource_type.to_string(),
+        resource_id: resource_id.to_string(),
+        rule_name: rule_name.to_string(),
+        annotation: result.annotation().unwrap_or_default().to_string(),
+        evaluation_time,
+    }
 }
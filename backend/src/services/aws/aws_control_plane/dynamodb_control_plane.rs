@@ -17,16 +17,29 @@ use crate::errors::AppError;
 use crate::models::aws_account::AwsAccountDto;
 use crate::models::aws_resource::{AwsResourceDto, Model as AwsResourceModel};
 use crate::services::aws::aws_types::dynamodb::{
-    DynamoDbAttributeDefinition, DynamoDbKeySchema, DynamoDbProvisionedThroughput,
-    DynamoDbTableInfo,
+    CapacityChangeResult, DynamoDbAttributeDefinition, DynamoDbKeySchema,
+    DynamoDbProvisionedThroughput, DynamoDbTableInfo,
 };
 use crate::services::aws::client_factory::AwsClientFactory;
 use crate::services::AwsService;
+use aws_sdk_cloudwatch::types::{Dimension, Statistic};
+use aws_sdk_dynamodb::types::{
+    BillingMode, GlobalSecondaryIndexUpdate, ProvisionedThroughput, TableDescription, TableStatus,
+    UpdateGlobalSecondaryIndexAction,
+};
+use aws_sdk_dynamodb::Client as DynamoDbClient;
 use serde_json::json;
 use std::sync::Arc;
-use tracing::{debug, error, info};
+use std::time::{Duration, Instant};
+use tracing::{debug, error, info, trace, warn};
 use uuid::Uuid;
 
+const CAPACITY_CHANGE_TIMEOUT: Duration = Duration::from_secs(600);
+const TABLE_STATUS_POLL_INTERVAL: Duration = Duration::from_secs(5);
+// Window and granularity used to find the consumed capacity peak
+const PEAK_USAGE_WINDOW_SECS: i64 = 24 * 60 * 60;
+const PEAK_USAGE_PERIOD_SECS: i32 = 60;
+
 // Control plane implementation for DynamoDB
 pub struct DynamoDbControlPlane {
     aws_service: Arc<AwsService>,
@@ -569,4 +582,354 @@ impl DynamoDbControlPlane {
             attribute_definitions: vec![],
         })
     }
+
+    pub async fn switch_to_on_demand(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        table_name: &str,
+    ) -> Result<CapacityChangeResult, AppError> {
+        let mut account = aws_account_dto.clone();
+        account.default_region = region.to_string();
+        let client = self.aws_service.create_dynamodb_client(&account).await?;
+        let started = Instant::now();
+
+        let table = Self::describe(&client, table_name).await?;
+        let previous_mode = Self::billing_mode(&table);
+        if previous_mode == BillingMode::PayPerRequest {
+            return Err(AppError::BadRequest(format!(
+                "Table {} is already in on-demand mode",
+                table_name
+            )));
+        }
+
+        client
+            .update_table()
+            .table_name(table_name)
+            .billing_mode(BillingMode::PayPerRequest)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to switch table {} to on-demand: {}",
+                    table_name,
+                    e.into_service_error()
+                ))
+            })?;
+        info!(
+            "Switching DynamoDB table {} to on-demand capacity",
+            table_name
+        );
+
+        Self::wait_for_table_active(&client, table_name, CAPACITY_CHANGE_TIMEOUT).await?;
+
+        Ok(CapacityChangeResult {
+            table_name: table_name.to_string(),
+            previous_mode: previous_mode.as_str().to_string(),
+            new_mode: BillingMode::PayPerRequest.as_str().to_string(),
+            duration_ms: started.elapsed().as_millis() as u64,
+            warnings: Vec::new(),
+        })
+    }
+
+    pub async fn switch_to_provisioned(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        table_name: &str,
+        read_capacity: i64,
+        write_capacity: i64,
+    ) -> Result<CapacityChangeResult, AppError> {
+        validate_capacity(read_capacity, write_capacity)?;
+
+        let mut account = aws_account_dto.clone();
+        account.default_region = region.to_string();
+        let client = self.aws_service.create_dynamodb_client(&account).await?;
+        let started = Instant::now();
+
+        let table = Self::describe(&client, table_name).await?;
+        let previous_mode = Self::billing_mode(&table);
+        if previous_mode == BillingMode::Provisioned {
+            return Err(AppError::BadRequest(format!(
+                "Table {} is already in provisioned mode, update its throughput instead",
+                table_name
+            )));
+        }
+
+        let warnings = self
+            .check_peak_usage(&account, table_name, read_capacity, write_capacity)
+            .await;
+        let throughput = build_throughput(read_capacity, write_capacity)?;
+
+        // Every global secondary index needs its own throughput when leaving on-demand
+        let mut index_updates = Vec::new();
+        for index in table.global_secondary_indexes() {
+            let Some(index_name) = index.index_name() else {
+                continue;
+            };
+            let action = UpdateGlobalSecondaryIndexAction::builder()
+                .index_name(index_name)
+                .provisioned_throughput(throughput.clone())
+                .build()
+                .map_err(|e| {
+                    AppError::ExternalService(format!(
+                        "Failed to build index update for {}: {}",
+                        index_name, e
+                    ))
+                })?;
+            index_updates.push(GlobalSecondaryIndexUpdate::builder().update(action).build());
+        }
+
+        let mut request = client
+            .update_table()
+            .table_name(table_name)
+            .billing_mode(BillingMode::Provisioned)
+            .provisioned_throughput(throughput);
+        if !index_updates.is_empty() {
+            request = request.set_global_secondary_index_updates(Some(index_updates));
+        }
+        request.send().await.map_err(|e| {
+            AppError::ExternalService(format!(
+                "Failed to switch table {} to provisioned: {}",
+                table_name,
+                e.into_service_error()
+            ))
+        })?;
+        info!(
+            "Switching DynamoDB table {} to provisioned capacity ({} RCU / {} WCU)",
+            table_name, read_capacity, write_capacity
+        );
+
+        Self::wait_for_table_active(&client, table_name, CAPACITY_CHANGE_TIMEOUT).await?;
+
+        Ok(CapacityChangeResult {
+            table_name: table_name.to_string(),
+            previous_mode: previous_mode.as_str().to_string(),
+            new_mode: BillingMode::Provisioned.as_str().to_string(),
+            duration_ms: started.elapsed().as_millis() as u64,
+            warnings,
+        })
+    }
+
+    pub async fn update_provisioned_throughput(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        table_name: &str,
+        read_capacity: i64,
+        write_capacity: i64,
+    ) -> Result<CapacityChangeResult, AppError> {
+        validate_capacity(read_capacity, write_capacity)?;
+
+        let mut account = aws_account_dto.clone();
+        account.default_region = region.to_string();
+        let client = self.aws_service.create_dynamodb_client(&account).await?;
+        let started = Instant::now();
+
+        let table = Self::describe(&client, table_name).await?;
+        let mode = Self::billing_mode(&table);
+        if mode == BillingMode::PayPerRequest {
+            return Err(AppError::BadRequest(format!(
+                "Table {} is in on-demand mode, switch it to provisioned first",
+                table_name
+            )));
+        }
+
+        let warnings = self
+            .check_peak_usage(&account, table_name, read_capacity, write_capacity)
+            .await;
+
+        client
+            .update_table()
+            .table_name(table_name)
+            .provisioned_throughput(build_throughput(read_capacity, write_capacity)?)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to update throughput of table {}: {}",
+                    table_name,
+                    e.into_service_error()
+                ))
+            })?;
+        info!(
+            "Updating DynamoDB table {} throughput to {} RCU / {} WCU",
+            table_name, read_capacity, write_capacity
+        );
+
+        Self::wait_for_table_active(&client, table_name, CAPACITY_CHANGE_TIMEOUT).await?;
+
+        Ok(CapacityChangeResult {
+            table_name: table_name.to_string(),
+            previous_mode: mode.as_str().to_string(),
+            new_mode: mode.as_str().to_string(),
+            duration_ms: started.elapsed().as_millis() as u64,
+            warnings,
+        })
+    }
+
+    async fn describe(
+        client: &DynamoDbClient,
+        table_name: &str,
+    ) -> Result<TableDescription, AppError> {
+        let response = client
+            .describe_table()
+            .table_name(table_name)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to describe DynamoDB table {}: {}",
+                    table_name, e
+                ))
+            })?;
+
+        response.table().cloned().ok_or_else(|| {
+            AppError::ExternalService(format!("No table details returned for {}", table_name))
+        })
+    }
+
+    // Tables created before on-demand existed carry no billing mode summary
+    fn billing_mode(table: &TableDescription) -> BillingMode {
+        table
+            .billing_mode_summary()
+            .and_then(|s| s.billing_mode())
+            .cloned()
+            .unwrap_or(BillingMode::Provisioned)
+    }
+
+    // Capacity changes leave the table UPDATING until they are applied
+    pub async fn wait_for_table_active(
+        client: &DynamoDbClient,
+        table_name: &str,
+        timeout: Duration,
+    ) -> Result<(), AppError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let table = Self::describe(client, table_name).await?;
+            let status = table.table_status().cloned();
+            if status == Some(TableStatus::Active) {
+                return Ok(());
+            }
+            trace!("Table {} status is {:?}, waiting", table_name, status);
+
+            if Instant::now() >= deadline {
+                return Err(AppError::ExternalService(format!(
+                    "Timed out after {}s waiting for table {} to become ACTIVE",
+                    timeout.as_secs(),
+                    table_name
+                )));
+            }
+            tokio::time::sleep(TABLE_STATUS_POLL_INTERVAL).await;
+        }
+    }
+
+    // Compares the requested capacity against the consumed peak of the last day.
+    // Lookup failures only skip the check, the change itself is never blocked.
+    async fn check_peak_usage(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        table_name: &str,
+        read_capacity: i64,
+        write_capacity: i64,
+    ) -> Vec<String> {
+        let mut warnings = Vec::new();
+        let checks = [
+            ("ConsumedReadCapacityUnits", "read", read_capacity),
+            ("ConsumedWriteCapacityUnits", "write", write_capacity),
+        ];
+
+        for (metric_name, label, requested) in checks {
+            match self
+                .peak_consumed_per_second(aws_account_dto, table_name, metric_name)
+                .await
+            {
+                Ok(Some(peak)) if (requested as f64) < peak => {
+                    let message = format!(
+                        "Requested {} capacity {} is below the peak consumption of {:.1} units/s over the last 24h",
+                        label, requested, peak
+                    );
+                    warn!("Table {}: {}", table_name, message);
+                    warnings.push(message);
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    warn!(
+                        "Could not determine peak {} usage of table {}: {}",
+                        label, table_name, e
+                    );
+                }
+            }
+        }
+
+        warnings
+    }
+
+    async fn peak_consumed_per_second(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        table_name: &str,
+        metric_name: &str,
+    ) -> Result<Option<f64>, AppError> {
+        let client = self
+            .aws_service
+            .create_cloudwatch_client(aws_account_dto)
+            .await?;
+        let now = chrono::Utc::now().timestamp();
+
+        let response = client
+            .get_metric_statistics()
+            .namespace("AWS/DynamoDB")
+            .metric_name(metric_name)
+            .dimensions(
+                Dimension::builder()
+                    .name("TableName")
+                    .value(table_name)
+                    .build(),
+            )
+            .start_time(aws_sdk_cloudwatch::primitives::DateTime::from_secs(
+                now - PEAK_USAGE_WINDOW_SECS,
+            ))
+            .end_time(aws_sdk_cloudwatch::primitives::DateTime::from_secs(now))
+            .period(PEAK_USAGE_PERIOD_SECS)
+            .statistics(Statistic::Sum)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to get {} for table {}: {}",
+                    metric_name, table_name, e
+                ))
+            })?;
+
+        // Consumed capacity is reported as a sum per period
+        Ok(response
+            .datapoints()
+            .iter()
+            .filter_map(|d| d.sum())
+            .map(|sum| sum / PEAK_USAGE_PERIOD_SECS as f64)
+            .reduce(f64::max))
+    }
+}
+
+fn validate_capacity(read_capacity: i64, write_capacity: i64) -> Result<(), AppError> {
+    if read_capacity < 1 || write_capacity < 1 {
+        return Err(AppError::Validation(
+            "read_capacity and write_capacity must be at least 1".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn build_throughput(
+    read_capacity: i64,
+    write_capacity: i64,
+) -> Result<ProvisionedThroughput, AppError> {
+    ProvisionedThroughput::builder()
+        .read_capacity_units(read_capacity)
+        .write_capacity_units(write_capacity)
+        .build()
+        .map_err(|e| {
+            AppError::ExternalService(format!("Failed to build provisioned throughput: {}", e))
+        })
 }
@@ -16,10 +16,12 @@
 use crate::errors::AppError;
 use crate::models::aws_account::AwsAccountDto;
 use crate::models::aws_resource::{AwsResourceDto, Model as AwsResourceModel, AwsResourceType};
+use crate::services::aws::aws_types::ebs::{EbsSnapshotInfo, EbsSnapshotRetentionResult};
 use crate::services::aws::client_factory::AwsClientFactory;
 use crate::services::aws::service::AwsService;
-use aws_sdk_ec2::types::{Volume, Snapshot};
+use aws_sdk_ec2::types::{Filter, ResourceType, Snapshot, Tag, TagSpecification, Volume};
 use chrono::Utc;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{debug, error, info};
 use uuid::Uuid;
@@ -132,6 +134,219 @@ impl EbsControlPlane {
         Ok(all_resources.into_iter().map(|r| r.into()).collect())
     }
 
+    async fn client_for_region(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+    ) -> Result<aws_sdk_ec2::Client, AppError> {
+        let mut account = aws_account_dto.clone();
+        account.default_region = region.to_string();
+        self.aws_service.create_ec2_client(&account).await
+    }
+
+    pub async fn create_snapshot(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        volume_id: &str,
+        description: &str,
+        tags: HashMap<String, String>,
+    ) -> Result<EbsSnapshotInfo, AppError> {
+        let client = self.client_for_region(aws_account_dto, region).await?;
+
+        let mut request = client.create_snapshot().volume_id(volume_id);
+        if !description.is_empty() {
+            request = request.description(description);
+        }
+        if !tags.is_empty() {
+            let tags = tags
+                .iter()
+                .map(|(key, value)| Tag::builder().key(key).value(value).build())
+                .collect();
+            request = request.tag_specifications(
+                TagSpecification::builder()
+                    .resource_type(ResourceType::Snapshot)
+                    .set_tags(Some(tags))
+                    .build(),
+            );
+        }
+
+        let response = request.send().await.map_err(|e| {
+            AppError::CloudProvider(format!(
+                "Failed to create snapshot of volume {}: {}",
+                volume_id, e
+            ))
+        })?;
+        let snapshot_id = response.snapshot_id().unwrap_or_default().to_string();
+        info!(
+            "Created snapshot {} of EBS volume {}",
+            snapshot_id, volume_id
+        );
+
+        Ok(EbsSnapshotInfo {
+            snapshot_id,
+            volume_id: response.volume_id().map(String::from),
+            description: response.description().map(String::from),
+            state: response.state().map(|s| s.as_str().to_string()),
+            progress: response.progress().map(String::from),
+            volume_size: response.volume_size(),
+            encrypted: response.encrypted(),
+            owner_id: response.owner_id().map(String::from),
+            start_time: response.start_time().map(|dt| dt.to_string()),
+            tags,
+        })
+    }
+
+    // Newest first. `owner_ids` defaults to the account's own snapshots.
+    pub async fn list_snapshots(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        volume_id: Option<&str>,
+        owner_ids: Vec<String>,
+    ) -> Result<Vec<EbsSnapshotInfo>, AppError> {
+        let client = self.client_for_region(aws_account_dto, region).await?;
+        let owner_ids = if owner_ids.is_empty() {
+            vec!["self".to_string()]
+        } else {
+            owner_ids
+        };
+
+        let mut snapshots: Vec<Snapshot> = Vec::new();
+        let mut next_token = None;
+        loop {
+            let mut request = client
+                .describe_snapshots()
+                .set_owner_ids(Some(owner_ids.clone()))
+                .set_next_token(next_token);
+            if let Some(volume_id) = volume_id {
+                request = request.filters(
+                    Filter::builder()
+                        .name("volume-id")
+                        .values(volume_id)
+                        .build(),
+                );
+            }
+
+            let response = request
+                .send()
+                .await
+                .map_err(|e| AppError::CloudProvider(format!("Failed to list snapshots: {}", e)))?;
+            snapshots.extend(response.snapshots().iter().cloned());
+
+            next_token = response.next_token().map(String::from);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        snapshots.sort_by_key(|s| std::cmp::Reverse(s.start_time().map(|t| t.secs())));
+        Ok(snapshots.iter().map(to_snapshot_info).collect())
+    }
+
+    pub async fn delete_snapshot(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        snapshot_id: &str,
+    ) -> Result<(), AppError> {
+        let client = self.client_for_region(aws_account_dto, region).await?;
+        client
+            .delete_snapshot()
+            .snapshot_id(snapshot_id)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::CloudProvider(format!("Failed to delete snapshot {}: {}", snapshot_id, e))
+            })?;
+
+        info!("Deleted EBS snapshot {} in {}", snapshot_id, region);
+        Ok(())
+    }
+
+    // Copies a snapshot into another region and returns the new snapshot's ID.
+    // The copy is issued from the target region, as CopySnapshot requires.
+    pub async fn copy_snapshot(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        source_region: &str,
+        target_region: &str,
+        snapshot_id: &str,
+    ) -> Result<String, AppError> {
+        let client = self
+            .client_for_region(aws_account_dto, target_region)
+            .await?;
+        let response = client
+            .copy_snapshot()
+            .source_region(source_region)
+            .source_snapshot_id(snapshot_id)
+            .description(format!("Copy of {} from {}", snapshot_id, source_region))
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::CloudProvider(format!(
+                    "Failed to copy snapshot {} from {} to {}: {}",
+                    snapshot_id, source_region, target_region, e
+                ))
+            })?;
+
+        let new_snapshot_id = response.snapshot_id().map(String::from).ok_or_else(|| {
+            AppError::CloudProvider("CopySnapshot returned no snapshot ID".to_string())
+        })?;
+        info!(
+            "Copied EBS snapshot {} from {} to {} as {}",
+            snapshot_id, source_region, target_region, new_snapshot_id
+        );
+        Ok(new_snapshot_id)
+    }
+
+    // Deletes the volume's own snapshots beyond the newest `keep_last_n`. A failed
+    // deletion is reported and doesn't stop the remaining ones.
+    pub async fn enforce_snapshot_retention(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        volume_id: &str,
+        keep_last_n: usize,
+    ) -> Result<EbsSnapshotRetentionResult, AppError> {
+        if keep_last_n == 0 {
+            return Err(AppError::Validation(
+                "keep_last_n must be at least 1".to_string(),
+            ));
+        }
+
+        let snapshots = self
+            .list_snapshots(aws_account_dto, region, Some(volume_id), Vec::new())
+            .await?;
+
+        let mut result = EbsSnapshotRetentionResult::default();
+        for (index, snapshot) in snapshots.into_iter().enumerate() {
+            if index < keep_last_n {
+                result.kept.push(snapshot.snapshot_id);
+                continue;
+            }
+            match self
+                .delete_snapshot(aws_account_dto, region, &snapshot.snapshot_id)
+                .await
+            {
+                Ok(()) => result.deleted.push(snapshot.snapshot_id),
+                Err(e) => {
+                    error!("Retention failed to delete {}: {}", snapshot.snapshot_id, e);
+                    result.failed.push((snapshot.snapshot_id, e.to_string()));
+                }
+            }
+        }
+
+        info!(
+            "Snapshot retention for volume {}: kept {}, deleted {}, failed {}",
+            volume_id,
+            result.kept.len(),
+            result.deleted.len(),
+            result.failed.len()
+        );
+        Ok(result)
+    }
+
     /// Create EBS volume resource from AWS SDK model
     async fn create_volume_resource(
         &self,
@@ -244,4 +459,23 @@ impl EbsControlPlane {
             resource_data,
         })
     }
-}
\ No newline at end of file
+}
+
+fn to_snapshot_info(snapshot: &Snapshot) -> EbsSnapshotInfo {
+    EbsSnapshotInfo {
+        snapshot_id: snapshot.snapshot_id().unwrap_or_default().to_string(),
+        volume_id: snapshot.volume_id().map(String::from),
+        description: snapshot.description().map(String::from),
+        state: snapshot.state().map(|s| s.as_str().to_string()),
+        progress: snapshot.progress().map(String::from),
+        volume_size: snapshot.volume_size(),
+        encrypted: snapshot.encrypted(),
+        owner_id: snapshot.owner_id().map(String::from),
+        start_time: snapshot.start_time().map(|dt| dt.to_string()),
+        tags: snapshot
+            .tags()
+            .iter()
+            .filter_map(|tag| Some((tag.key()?.to_string(), tag.value()?.to_string())))
+            .collect(),
+    }
+}
@@ -15,13 +15,21 @@
 use crate::errors::AppError;
 use crate::models::aws_account::AwsAccountDto;
 use crate::models::aws_resource::{AwsResourceDto, AwsResourceType, Model as AwsResourceModel};
+use crate::services::aws::aws_types::ecs::{
+    DeregisterResult, EcsContainerDefinitionSummary, EcsTaskDefinitionDetail,
+};
 use crate::services::aws::client_factory::AwsClientFactory;
 use crate::services::AwsService;
+use aws_sdk_ecs::types::{SortOrder, TaskDefinitionFamilyStatus, TaskDefinitionStatus};
 use serde_json::json;
+use std::collections::HashSet;
 use std::sync::Arc;
-use tracing::debug;
+use tracing::{debug, error, info};
 use uuid::Uuid;
 
+// DescribeServices accepts at most 10 services per call
+const DESCRIBE_SERVICES_BATCH: usize = 10;
+
 pub struct EcsControlPlane {
     aws_service: Arc<AwsService>,
 }
@@ -347,4 +355,283 @@ impl EcsControlPlane {
 
         Ok(resources)
     }
+
+    async fn client_for_region(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+    ) -> Result<aws_sdk_ecs::Client, AppError> {
+        let mut account = aws_account_dto.clone();
+        account.default_region = region.to_string();
+        self.aws_service.create_ecs_client(&account).await
+    }
+
+    pub async fn list_task_definition_families(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+    ) -> Result<Vec<String>, AppError> {
+        let client = self.client_for_region(aws_account_dto, region).await?;
+
+        let mut families = Vec::new();
+        let mut next_token = None;
+        loop {
+            let response = client
+                .list_task_definition_families()
+                .status(TaskDefinitionFamilyStatus::Active)
+                .set_next_token(next_token)
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::CloudProvider(format!(
+                        "Failed to list task definition families: {}",
+                        e
+                    ))
+                })?;
+            families.extend(response.families().iter().cloned());
+
+            next_token = response.next_token().map(String::from);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(families)
+    }
+
+    // Active revisions of the family, newest first
+    pub async fn list_task_definition_revisions(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        family: &str,
+    ) -> Result<Vec<String>, AppError> {
+        let client = self.client_for_region(aws_account_dto, region).await?;
+
+        let mut revisions = Vec::new();
+        let mut next_token = None;
+        loop {
+            let response = client
+                .list_task_definitions()
+                .family_prefix(family)
+                .status(TaskDefinitionStatus::Active)
+                .sort(SortOrder::Desc)
+                .set_next_token(next_token)
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::CloudProvider(format!(
+                        "Failed to list revisions of task definition family {}: {}",
+                        family, e
+                    ))
+                })?;
+            // The prefix also matches longer family names, e.g. "web" matches "web-worker"
+            revisions.extend(
+                response
+                    .task_definition_arns()
+                    .iter()
+                    .filter(|arn| task_definition_family(arn) == family)
+                    .cloned(),
+            );
+
+            next_token = response.next_token().map(String::from);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(revisions)
+    }
+
+    pub async fn describe_task_definition(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        task_definition_arn: &str,
+    ) -> Result<EcsTaskDefinitionDetail, AppError> {
+        let client = self.client_for_region(aws_account_dto, region).await?;
+        let response = client
+            .describe_task_definition()
+            .task_definition(task_definition_arn)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::CloudProvider(format!(
+                    "Failed to describe task definition {}: {}",
+                    task_definition_arn, e
+                ))
+            })?;
+        let task_definition = response.task_definition().ok_or_else(|| {
+            AppError::NotFound(format!("Task definition {} not found", task_definition_arn))
+        })?;
+
+        Ok(EcsTaskDefinitionDetail {
+            task_definition_arn: task_definition
+                .task_definition_arn()
+                .unwrap_or(task_definition_arn)
+                .to_string(),
+            family: task_definition.family().map(String::from),
+            revision: task_definition.revision(),
+            status: task_definition.status().map(|s| s.as_str().to_string()),
+            cpu: task_definition.cpu().map(String::from),
+            memory: task_definition.memory().map(String::from),
+            network_mode: task_definition
+                .network_mode()
+                .map(|m| m.as_str().to_string()),
+            requires_compatibilities: task_definition
+                .requires_compatibilities()
+                .iter()
+                .map(|c| c.as_str().to_string())
+                .collect(),
+            execution_role_arn: task_definition.execution_role_arn().map(String::from),
+            task_role_arn: task_definition.task_role_arn().map(String::from),
+            registered_at: task_definition.registered_at().map(|d| d.to_string()),
+            containers: task_definition
+                .container_definitions()
+                .iter()
+                .map(|c| EcsContainerDefinitionSummary {
+                    name: c.name().map(String::from),
+                    image: c.image().map(String::from),
+                    cpu: c.cpu(),
+                    memory: c.memory(),
+                    essential: c.essential(),
+                })
+                .collect(),
+        })
+    }
+
+    // Deregisters revisions beyond the newest `keep_last_n`, skipping any revision a
+    // service still runs, including the old side of an in-progress deployment. A failed
+    // deregistration is reported and doesn't stop the remaining ones.
+    pub async fn deregister_old_task_definitions(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        family: &str,
+        keep_last_n: usize,
+    ) -> Result<DeregisterResult, AppError> {
+        if keep_last_n == 0 {
+            return Err(AppError::Validation(
+                "keep_last_n must be at least 1".to_string(),
+            ));
+        }
+
+        let revisions = self
+            .list_task_definition_revisions(aws_account_dto, region, family)
+            .await?;
+        let client = self.client_for_region(aws_account_dto, region).await?;
+        let in_use = task_definitions_in_use(&client).await?;
+
+        let mut result = DeregisterResult::default();
+        for (index, arn) in revisions.into_iter().enumerate() {
+            if index < keep_last_n {
+                result.kept.push(arn);
+                continue;
+            }
+            if in_use.contains(&arn) {
+                result.in_use.push(arn);
+                continue;
+            }
+            match client
+                .deregister_task_definition()
+                .task_definition(&arn)
+                .send()
+                .await
+            {
+                Ok(_) => result.deregistered.push(arn),
+                Err(e) => {
+                    error!("Failed to deregister task definition {}: {}", arn, e);
+                    result.failed.push((arn, e.to_string()));
+                }
+            }
+        }
+
+        info!(
+            "Task definition cleanup for family {}: kept {}, in use {}, deregistered {}, failed {}",
+            family,
+            result.kept.len(),
+            result.in_use.len(),
+            result.deregistered.len(),
+            result.failed.len()
+        );
+        Ok(result)
+    }
+}
+
+// Task definitions referenced by any service in the region, from the service itself and
+// from each of its deployments
+async fn task_definitions_in_use(
+    client: &aws_sdk_ecs::Client,
+) -> Result<HashSet<String>, AppError> {
+    let mut cluster_arns = Vec::new();
+    let mut next_token = None;
+    loop {
+        let response = client
+            .list_clusters()
+            .set_next_token(next_token)
+            .send()
+            .await
+            .map_err(|e| AppError::CloudProvider(format!("Failed to list ECS clusters: {}", e)))?;
+        cluster_arns.extend(response.cluster_arns().iter().cloned());
+        next_token = response.next_token().map(String::from);
+        if next_token.is_none() {
+            break;
+        }
+    }
+
+    let mut in_use = HashSet::new();
+    for cluster_arn in &cluster_arns {
+        let mut service_arns = Vec::new();
+        let mut next_token = None;
+        loop {
+            let response = client
+                .list_services()
+                .cluster(cluster_arn)
+                .set_next_token(next_token)
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::CloudProvider(format!(
+                        "Failed to list ECS services for cluster {}: {}",
+                        cluster_arn, e
+                    ))
+                })?;
+            service_arns.extend(response.service_arns().iter().cloned());
+            next_token = response.next_token().map(String::from);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        for batch in service_arns.chunks(DESCRIBE_SERVICES_BATCH) {
+            let response = client
+                .describe_services()
+                .cluster(cluster_arn)
+                .set_services(Some(batch.to_vec()))
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::CloudProvider(format!("Failed to describe ECS services: {}", e))
+                })?;
+            for service in response.services() {
+                in_use.extend(service.task_definition().map(String::from));
+                in_use.extend(
+                    service
+                        .deployments()
+                        .iter()
+                        .filter_map(|d| d.task_definition().map(String::from)),
+                );
+            }
+        }
+    }
+
+    Ok(in_use)
+}
+
+// "arn:aws:ecs:us-east-1:123456789012:task-definition/web:42" -> "web"
+fn task_definition_family(arn: &str) -> &str {
+    let name = arn.rsplit('/').next().unwrap_or(arn);
+    name.rsplit_once(':')
+        .map(|(family, _)| family)
+        .unwrap_or(name)
 }
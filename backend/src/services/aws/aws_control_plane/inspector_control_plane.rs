@@ -0,0 +1,142 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::AppError;
+use crate::models::aws_account::AwsAccountDto;
+use crate::models::security_finding::SecurityFinding;
+use crate::services::AwsService;
+use aws_sdk_inspector2::types::{FilterCriteria, Finding, StringComparison, StringFilter};
+use aws_sdk_inspector2::Client as InspectorClient;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tracing::info;
+
+pub struct InspectorControlPlane {
+    aws_service: Arc<AwsService>,
+}
+
+impl InspectorControlPlane {
+    pub fn new(aws_service: Arc<AwsService>) -> Self {
+        Self { aws_service }
+    }
+
+    async fn client(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+    ) -> Result<InspectorClient, AppError> {
+        let sdk_config = self.aws_service.get_aws_sdk_config(aws_account_dto).await?;
+        let config = aws_sdk_inspector2::config::Builder::from(&sdk_config)
+            .region(aws_types::region::Region::new(region.to_string()))
+            .build();
+        Ok(InspectorClient::from_conf(config))
+    }
+
+    // Active findings, optionally limited to the given severities (CRITICAL, HIGH, ...)
+    pub async fn list_findings(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        filter_severity: Option<Vec<String>>,
+    ) -> Result<Vec<SecurityFinding>, AppError> {
+        let client = self.client(aws_account_dto, region).await?;
+
+        let mut criteria = FilterCriteria::builder().finding_status(equals_filter("ACTIVE")?);
+        for severity in filter_severity.unwrap_or_default() {
+            criteria = criteria.severity(equals_filter(&severity.to_uppercase())?);
+        }
+        let criteria = criteria.build();
+
+        let mut findings = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let response = client
+                .list_findings()
+                .filter_criteria(criteria.clone())
+                .max_results(100)
+                .set_next_token(next_token.take())
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::CloudProvider(format!("Failed to list Inspector findings: {}", e))
+                })?;
+
+            findings.extend(response.findings().iter().flat_map(to_security_findings));
+
+            match response.next_token() {
+                Some(token) if !token.is_empty() => next_token = Some(token.to_string()),
+                _ => break,
+            }
+        }
+
+        info!(
+            "Fetched {} Inspector findings for account {} in {}",
+            findings.len(),
+            aws_account_dto.account_id,
+            region
+        );
+        Ok(findings)
+    }
+}
+
+fn equals_filter(value: &str) -> Result<StringFilter, AppError> {
+    StringFilter::builder()
+        .comparison(StringComparison::Equals)
+        .value(value)
+        .build()
+        .map_err(|e| AppError::Internal(format!("Invalid Inspector filter: {}", e)))
+}
+
+// A finding can cover several resources; store one row per resource
+fn to_security_findings(finding: &Finding) -> Vec<SecurityFinding> {
+    let package_details = finding.package_vulnerability_details();
+    let package_name = package_details
+        .and_then(|d| d.vulnerable_packages().first())
+        .map(|p| p.name().to_string());
+    let cvss_score = finding.inspector_score().or_else(|| {
+        package_details
+            .and_then(|d| d.cvss().first())
+            .map(|c| c.base_score())
+    });
+    let remediation_text = finding
+        .remediation()
+        .and_then(|r| r.recommendation())
+        .and_then(|r| r.text())
+        .map(String::from);
+
+    finding
+        .resources()
+        .iter()
+        .map(|resource| SecurityFinding {
+            finding_id: if finding.resources().len() > 1 {
+                format!("{}#{}", finding.finding_arn(), resource.id())
+            } else {
+                finding.finding_arn().to_string()
+            },
+            resource_arn: resource.id().to_string(),
+            title: finding.title().unwrap_or_default().to_string(),
+            severity: finding.severity().as_str().to_string(),
+            description: finding.description().to_string(),
+            first_observed: to_chrono(finding.first_observed_at()),
+            last_observed: to_chrono(finding.last_observed_at()),
+            remediation_text: remediation_text.clone(),
+            package_name: package_name.clone(),
+            cvss_score,
+        })
+        .collect()
+}
+
+fn to_chrono(dt: &aws_smithy_types::DateTime) -> DateTime<Utc> {
+    DateTime::from_timestamp(dt.secs(), dt.subsec_nanos()).unwrap_or_default()
+}
@@ -13,9 +13,10 @@
 // limitations under the License.
 
 
-use aws_sdk_kinesis::types::StreamDescription;
+use aws_sdk_kinesis::types::{StreamDescription, StreamStatus};
 use aws_sdk_kinesis::Client as KinesisClient;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tracing::{debug, error, info, trace, warn};
 use uuid::Uuid;
 
@@ -25,6 +26,7 @@ use crate::models::aws_auth::AccountAuthInfo;
 use crate::models::aws_resource::{AwsResourceDto, Model as AwsResourceModel};
 use crate::services::aws::aws_types::kinesis::{
     KinesisAddTagsRequest,
+    KinesisConsumerSummary,
     KinesisCreateStreamRequest,
     KinesisDeleteStreamRequest,
     KinesisDescribeStreamConsumerRequest,
@@ -68,11 +70,16 @@ use crate::services::aws::aws_types::kinesis::{
     KinesisTagsResponse,
     KinesisUpdateShardCountRequest,
     KinesisUpdateStreamModeRequest,
+    ShardOperationResult,
 };
 use crate::services::aws::client_factory::AwsClientFactory;
 use crate::services::AwsService;
 use serde_json::json;
 
+// Resharding usually settles within a minute, but large streams can take several
+const RESHARD_TIMEOUT: Duration = Duration::from_secs(300);
+const STREAM_STATUS_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
 pub struct KinesisControlPlane {
     aws_service: Arc<AwsService>,
 }
@@ -867,4 +874,277 @@ impl KinesisControlPlane {
             stream_creation_timestamp: None, // Not provided in ListShards response
         })
     }
+
+    pub async fn split_shard(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        request: &KinesisSplitShardRequest,
+    ) -> Result<ShardOperationResult, AppError> {
+        let client = self
+            .aws_service
+            .create_kinesis_client(aws_account_dto)
+            .await?;
+        let started = Instant::now();
+
+        client
+            .split_shard()
+            .stream_name(&request.stream_name)
+            .shard_to_split(&request.shard_to_split)
+            .new_starting_hash_key(&request.new_starting_hash_key)
+            .send()
+            .await
+            .map_err(|e| AppError::ExternalService(format!("Failed to split shard: {}", e)))?;
+
+        info!(
+            "Splitting shard {} of stream {} at hash key {}",
+            request.shard_to_split, request.stream_name, request.new_starting_hash_key
+        );
+
+        Self::wait_for_stream_active(&client, &request.stream_name, RESHARD_TIMEOUT).await?;
+        let new_shard_ids =
+            Self::child_shard_ids(&client, &request.stream_name, &[&request.shard_to_split])
+                .await?;
+
+        Ok(ShardOperationResult {
+            stream_name: request.stream_name.clone(),
+            new_shard_ids,
+            duration_ms: started.elapsed().as_millis() as u64,
+        })
+    }
+
+    pub async fn merge_shards(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        request: &KinesisMergeShardsRequest,
+    ) -> Result<ShardOperationResult, AppError> {
+        let client = self
+            .aws_service
+            .create_kinesis_client(aws_account_dto)
+            .await?;
+        let started = Instant::now();
+
+        client
+            .merge_shards()
+            .stream_name(&request.stream_name)
+            .shard_to_merge(&request.shard_to_merge)
+            .adjacent_shard_to_merge(&request.adjacent_shard_to_merge)
+            .send()
+            .await
+            .map_err(|e| AppError::ExternalService(format!("Failed to merge shards: {}", e)))?;
+
+        info!(
+            "Merging shards {} and {} of stream {}",
+            request.shard_to_merge, request.adjacent_shard_to_merge, request.stream_name
+        );
+
+        Self::wait_for_stream_active(&client, &request.stream_name, RESHARD_TIMEOUT).await?;
+        let new_shard_ids = Self::child_shard_ids(
+            &client,
+            &request.stream_name,
+            &[&request.shard_to_merge, &request.adjacent_shard_to_merge],
+        )
+        .await?;
+
+        Ok(ShardOperationResult {
+            stream_name: request.stream_name.clone(),
+            new_shard_ids,
+            duration_ms: started.elapsed().as_millis() as u64,
+        })
+    }
+
+    // Each registered consumer gets its own 2MB/s per shard through SubscribeToShard
+    pub async fn register_stream_consumer(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        request: &KinesisStreamConsumerRequest,
+    ) -> Result<KinesisStreamConsumerResponse, AppError> {
+        let client = self
+            .aws_service
+            .create_kinesis_client(aws_account_dto)
+            .await?;
+
+        let response = client
+            .register_stream_consumer()
+            .stream_arn(&request.stream_arn)
+            .consumer_name(&request.consumer_name)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!("Failed to register stream consumer: {}", e))
+            })?;
+        let consumer = response.consumer().ok_or_else(|| {
+            AppError::ExternalService("RegisterStreamConsumer returned no consumer".to_string())
+        })?;
+
+        info!(
+            "Registered consumer {} on stream {}",
+            request.consumer_name, request.stream_arn
+        );
+        Ok(KinesisStreamConsumerResponse {
+            consumer_name: consumer.consumer_name().to_string(),
+            consumer_arn: consumer.consumer_arn().to_string(),
+            consumer_status: consumer.consumer_status().as_str().to_string(),
+            consumer_creation_timestamp: format_timestamp(consumer.consumer_creation_timestamp()),
+            stream_arn: request.stream_arn.clone(),
+        })
+    }
+
+    // Accepts either the consumer ARN or the stream ARN plus consumer name
+    pub async fn deregister_stream_consumer(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        request: &KinesisDescribeStreamConsumerRequest,
+    ) -> Result<(), AppError> {
+        if request.consumer_arn.is_none()
+            && (request.stream_arn.is_none() || request.consumer_name.is_none())
+        {
+            return Err(AppError::BadRequest(
+                "consumer_arn, or stream_arn and consumer_name, are required".to_string(),
+            ));
+        }
+        let client = self
+            .aws_service
+            .create_kinesis_client(aws_account_dto)
+            .await?;
+
+        client
+            .deregister_stream_consumer()
+            .set_consumer_arn(request.consumer_arn.clone())
+            .set_stream_arn(request.stream_arn.clone())
+            .set_consumer_name(request.consumer_name.clone())
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!("Failed to deregister stream consumer: {}", e))
+            })?;
+
+        info!("Deregistering stream consumer {:?}", request);
+        Ok(())
+    }
+
+    pub async fn list_stream_consumers(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        stream_arn: &str,
+    ) -> Result<KinesisListStreamConsumersResponse, AppError> {
+        let client = self
+            .aws_service
+            .create_kinesis_client(aws_account_dto)
+            .await?;
+
+        let mut consumers = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let response = client
+                .list_stream_consumers()
+                .stream_arn(stream_arn)
+                .set_next_token(next_token.take())
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::ExternalService(format!("Failed to list stream consumers: {}", e))
+                })?;
+
+            consumers.extend(response.consumers().iter().map(|c| KinesisConsumerSummary {
+                consumer_name: c.consumer_name().to_string(),
+                consumer_arn: c.consumer_arn().to_string(),
+                consumer_status: c.consumer_status().as_str().to_string(),
+                consumer_creation_timestamp: format_timestamp(c.consumer_creation_timestamp()),
+            }));
+
+            next_token = response.next_token().map(|s| s.to_string());
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(KinesisListStreamConsumersResponse {
+            consumers,
+            next_token: None,
+            stream_arn: stream_arn.to_string(),
+        })
+    }
+
+    // Resharding leaves the stream UPDATING until the child shards are open
+    pub async fn wait_for_stream_active(
+        client: &KinesisClient,
+        stream_name: &str,
+        timeout: Duration,
+    ) -> Result<(), AppError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let response = client
+                .describe_stream_summary()
+                .stream_name(stream_name)
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::ExternalService(format!(
+                        "Failed to describe stream summary: {}",
+                        e
+                    ))
+                })?;
+
+            let status = response
+                .stream_description_summary()
+                .map(|s| s.stream_status().clone());
+            if status == Some(StreamStatus::Active) {
+                return Ok(());
+            }
+            trace!("Stream {} status is {:?}, waiting", stream_name, status);
+
+            if Instant::now() >= deadline {
+                return Err(AppError::ExternalService(format!(
+                    "Timed out after {}s waiting for stream {} to become ACTIVE",
+                    timeout.as_secs(),
+                    stream_name
+                )));
+            }
+            tokio::time::sleep(STREAM_STATUS_POLL_INTERVAL).await;
+        }
+    }
+
+    async fn child_shard_ids(
+        client: &KinesisClient,
+        stream_name: &str,
+        parents: &[&str],
+    ) -> Result<Vec<String>, AppError> {
+        let mut shard_ids = Vec::new();
+        let mut next_token: Option<String> = None;
+
+        loop {
+            // ListShards rejects a stream name alongside a pagination token
+            let request = match &next_token {
+                Some(token) => client.list_shards().next_token(token),
+                None => client.list_shards().stream_name(stream_name),
+            };
+            let response = request
+                .send()
+                .await
+                .map_err(|e| AppError::ExternalService(format!("Failed to list shards: {}", e)))?;
+
+            for shard in response.shards() {
+                let is_child = shard
+                    .parent_shard_id()
+                    .into_iter()
+                    .chain(shard.adjacent_parent_shard_id())
+                    .any(|parent| parents.contains(&parent));
+                if is_child {
+                    shard_ids.push(shard.shard_id().to_string());
+                }
+            }
+
+            next_token = response.next_token().map(|s| s.to_string());
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(shard_ids)
+    }
+}
+
+fn format_timestamp(ts: &aws_smithy_types::DateTime) -> String {
+    ts.fmt(aws_smithy_types::date_time::Format::DateTime)
+        .unwrap_or_else(|_| ts.secs().to_string())
 }
@@ -14,6 +14,7 @@
 
 
 pub mod api_gateway_control_plane;
+pub mod autoscaling_control_plane;
 pub mod cloudfront_control_plane;
 pub mod dynamodb_control_plane;
 pub mod ebs_control_plane;
@@ -62,3 +63,6 @@ pub mod storagegateway_control_plane;
 pub mod connect_control_plane;
 pub mod appsync_control_plane;
 pub mod kinesisanalytics_control_plane;
+pub mod tagging_control_plane;
+pub mod inspector_control_plane;
+pub mod route53_control_plane;
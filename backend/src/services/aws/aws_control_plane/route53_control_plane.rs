@@ -0,0 +1,374 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::AppError;
+use crate::models::aws_account::AwsAccountDto;
+use crate::models::aws_resource::{AwsResourceDto, AwsResourceType, Model as AwsResourceModel};
+use crate::services::aws::aws_types::route53::{HealthCheckResult, HealthCheckStatus};
+use crate::services::AwsService;
+use aws_sdk_route53::types::{HealthCheckConfig, HealthCheckType, ResourceRecordSet, RrType};
+use aws_sdk_route53::Client as Route53Client;
+use serde_json::json;
+use std::sync::Arc;
+use tracing::{debug, info};
+use uuid::Uuid;
+
+// Route53 is a global service; its API is served from us-east-1
+pub const ROUTE53_REGION: &str = "us-east-1";
+
+// Route53 reports an endpoint healthy when more than 18% of checkers do
+const HEALTHY_CHECKER_PERCENT: usize = 18;
+
+pub struct Route53ControlPlane {
+    aws_service: Arc<AwsService>,
+}
+
+impl Route53ControlPlane {
+    pub fn new(aws_service: Arc<AwsService>) -> Self {
+        Self { aws_service }
+    }
+
+    async fn client(&self, aws_account_dto: &AwsAccountDto) -> Result<Route53Client, AppError> {
+        let sdk_config = self.aws_service.get_aws_sdk_config(aws_account_dto).await?;
+        let config = aws_sdk_route53::config::Builder::from(&sdk_config)
+            .region(aws_types::region::Region::new(ROUTE53_REGION))
+            .build();
+        Ok(Route53Client::from_conf(config))
+    }
+
+    // One Route53Record resource per record set, for one hosted zone or all of them
+    pub async fn sync_record_sets(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        hosted_zone_id: Option<&str>,
+        sync_id: Uuid,
+    ) -> Result<Vec<AwsResourceModel>, AppError> {
+        debug!(
+            "Syncing Route53 records for account: {} with sync_id: {}",
+            &aws_account_dto.account_id, sync_id
+        );
+        let client = self.client(aws_account_dto).await?;
+
+        let zones = match hosted_zone_id {
+            Some(id) => {
+                let response = client
+                    .get_hosted_zone()
+                    .id(normalize_zone_id(id))
+                    .send()
+                    .await
+                    .map_err(|e| {
+                        AppError::CloudProvider(format!("Failed to get hosted zone {}: {}", id, e))
+                    })?;
+                let zone = response
+                    .hosted_zone()
+                    .ok_or_else(|| AppError::NotFound(format!("Hosted zone {} not found", id)))?;
+                vec![(normalize_zone_id(zone.id()), zone.name().to_string())]
+            }
+            None => list_hosted_zones(&client).await?,
+        };
+
+        let mut resources = Vec::new();
+        for (zone_id, zone_name) in zones {
+            let mut start: Option<(String, RrType, Option<String>)> = None;
+            loop {
+                let mut request = client.list_resource_record_sets().hosted_zone_id(&zone_id);
+                if let Some((name, record_type, identifier)) = start.take() {
+                    request = request
+                        .start_record_name(name)
+                        .start_record_type(record_type)
+                        .set_start_record_identifier(identifier);
+                }
+                let response = request.send().await.map_err(|e| {
+                    AppError::CloudProvider(format!(
+                        "Failed to list record sets for hosted zone {}: {}",
+                        zone_id, e
+                    ))
+                })?;
+
+                for record in response.resource_record_sets() {
+                    let dto =
+                        record_to_resource(aws_account_dto, &zone_id, &zone_name, record, sync_id);
+                    resources.push(dto.into());
+                }
+
+                if !response.is_truncated() {
+                    break;
+                }
+                match (response.next_record_name(), response.next_record_type()) {
+                    (Some(name), Some(record_type)) => {
+                        start = Some((
+                            name.to_string(),
+                            record_type.clone(),
+                            response.next_record_identifier().map(String::from),
+                        ))
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        debug!(
+            "Successfully synced {} Route53 records for account: {} with sync_id: {}",
+            resources.len(),
+            &aws_account_dto.account_id,
+            sync_id
+        );
+        Ok(resources)
+    }
+
+    pub async fn create_health_check(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        fqdn: &str,
+        port: i32,
+        protocol: &str,
+        path: Option<String>,
+        failure_threshold: u8,
+    ) -> Result<HealthCheckResult, AppError> {
+        let protocol = protocol.to_uppercase();
+        if !["HTTP", "HTTPS", "HTTP_STR_MATCH", "HTTPS_STR_MATCH", "TCP"]
+            .contains(&protocol.as_str())
+        {
+            return Err(AppError::Validation(format!(
+                "Unsupported health check protocol {}",
+                protocol
+            )));
+        }
+        if protocol == "TCP" && path.is_some() {
+            return Err(AppError::Validation(
+                "TCP health checks do not take a path".to_string(),
+            ));
+        }
+        if !(1..=10).contains(&failure_threshold) {
+            return Err(AppError::Validation(
+                "failure_threshold must be between 1 and 10".to_string(),
+            ));
+        }
+        if !(1..=65535).contains(&port) {
+            return Err(AppError::Validation(format!("Invalid port {}", port)));
+        }
+
+        let config = HealthCheckConfig::builder()
+            .fully_qualified_domain_name(fqdn)
+            .port(port)
+            .r#type(HealthCheckType::from(protocol.as_str()))
+            .set_resource_path(path.clone())
+            .failure_threshold(failure_threshold as i32)
+            .build()
+            .map_err(|e| AppError::Validation(format!("Invalid health check: {}", e)))?;
+
+        // Makes retries of the same request idempotent on the AWS side
+        let caller_reference = Uuid::new_v4().to_string();
+        let client = self.client(aws_account_dto).await?;
+        let response = client
+            .create_health_check()
+            .caller_reference(&caller_reference)
+            .health_check_config(config)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::CloudProvider(format!(
+                    "Failed to create health check for {}: {}",
+                    fqdn, e
+                ))
+            })?;
+        let health_check_id = response
+            .health_check()
+            .map(|h| h.id().to_string())
+            .ok_or_else(|| {
+                AppError::CloudProvider("Route53 did not return the new health check".to_string())
+            })?;
+
+        info!(
+            "Created Route53 health check {} for {}:{} in account {}",
+            health_check_id, fqdn, port, aws_account_dto.account_id
+        );
+        Ok(HealthCheckResult {
+            health_check_id,
+            caller_reference,
+            fqdn: fqdn.to_string(),
+            port,
+            protocol,
+            path,
+            failure_threshold,
+        })
+    }
+
+    pub async fn delete_health_check(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        health_check_id: &str,
+    ) -> Result<(), AppError> {
+        let client = self.client(aws_account_dto).await?;
+        client
+            .delete_health_check()
+            .health_check_id(health_check_id)
+            .send()
+            .await
+            .map_err(|e| {
+                let service_error = e.into_service_error();
+                if service_error.is_no_such_health_check() {
+                    AppError::NotFound(format!("Health check {} not found", health_check_id))
+                } else {
+                    AppError::CloudProvider(format!(
+                        "Failed to delete health check {}: {}",
+                        health_check_id, service_error
+                    ))
+                }
+            })?;
+        info!(
+            "Deleted Route53 health check {} in account {}",
+            health_check_id, aws_account_dto.account_id
+        );
+        Ok(())
+    }
+
+    pub async fn get_health_check_status(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        health_check_id: &str,
+    ) -> Result<HealthCheckStatus, AppError> {
+        let client = self.client(aws_account_dto).await?;
+        let response = client
+            .get_health_check_status()
+            .health_check_id(health_check_id)
+            .send()
+            .await
+            .map_err(|e| {
+                let service_error = e.into_service_error();
+                if service_error.is_no_such_health_check() {
+                    AppError::NotFound(format!("Health check {} not found", health_check_id))
+                } else {
+                    AppError::CloudProvider(format!(
+                        "Failed to get status of health check {}: {}",
+                        health_check_id, service_error
+                    ))
+                }
+            })?;
+
+        let observations = response.health_check_observations();
+        let healthy = observations
+            .iter()
+            .filter(|o| {
+                o.status_report()
+                    .and_then(|r| r.status())
+                    .map_or(false, |s| s.starts_with("Success"))
+            })
+            .count();
+        let status = if observations.is_empty() {
+            "Unknown"
+        } else if healthy * 100 > observations.len() * HEALTHY_CHECKER_PERCENT {
+            "Healthy"
+        } else {
+            "Unhealthy"
+        };
+
+        Ok(HealthCheckStatus {
+            health_check_id: health_check_id.to_string(),
+            status: status.to_string(),
+            checker_regions: observations
+                .iter()
+                .filter_map(|o| o.region().map(|r| r.as_str().to_string()))
+                .collect(),
+        })
+    }
+}
+
+async fn list_hosted_zones(client: &Route53Client) -> Result<Vec<(String, String)>, AppError> {
+    let mut zones = Vec::new();
+    let mut marker: Option<String> = None;
+    loop {
+        let response = client
+            .list_hosted_zones()
+            .set_marker(marker)
+            .send()
+            .await
+            .map_err(|e| AppError::CloudProvider(format!("Failed to list hosted zones: {}", e)))?;
+
+        zones.extend(
+            response
+                .hosted_zones()
+                .iter()
+                .map(|z| (normalize_zone_id(z.id()), z.name().to_string())),
+        );
+
+        marker = response.next_marker().map(String::from);
+        if !response.is_truncated() || marker.is_none() {
+            return Ok(zones);
+        }
+    }
+}
+
+// The API returns ids as "/hostedzone/Z123" but accepts either form
+fn normalize_zone_id(id: &str) -> String {
+    id.trim_start_matches("/hostedzone/").to_string()
+}
+
+fn record_to_resource(
+    aws_account_dto: &AwsAccountDto,
+    zone_id: &str,
+    zone_name: &str,
+    record: &ResourceRecordSet,
+    sync_id: Uuid,
+) -> AwsResourceDto {
+    let record_type = record.r#type().as_str();
+    let resource_data = json!({
+        "hosted_zone_id": zone_id,
+        "hosted_zone_name": zone_name,
+        "name": record.name(),
+        "type": record_type,
+        "ttl": record.ttl(),
+        "values": record
+            .resource_records()
+            .iter()
+            .map(|r| r.value())
+            .collect::<Vec<_>>(),
+        "alias_target": record.alias_target().map(|a| json!({
+            "dns_name": a.dns_name(),
+            "hosted_zone_id": a.hosted_zone_id(),
+            "evaluate_target_health": a.evaluate_target_health(),
+        })),
+        "set_identifier": record.set_identifier(),
+        "weight": record.weight(),
+        "region": record.region().map(|r| r.as_str()),
+        "failover": record.failover().map(|f| f.as_str()),
+        "health_check_id": record.health_check_id(),
+    });
+
+    // Routing policies allow several record sets with the same name and type
+    let resource_id = match record.set_identifier() {
+        Some(identifier) => format!(
+            "{}/{}/{}/{}",
+            zone_id,
+            record.name(),
+            record_type,
+            identifier
+        ),
+        None => format!("{}/{}/{}", zone_id, record.name(), record_type),
+    };
+    AwsResourceDto {
+        id: None,
+        sync_id: Some(sync_id),
+        account_id: aws_account_dto.account_id.clone(),
+        profile: aws_account_dto.profile.clone(),
+        region: ROUTE53_REGION.to_string(),
+        resource_type: AwsResourceType::Route53Record.to_string(),
+        arn: format!("arn:aws:route53:::hostedzone/{}", resource_id),
+        resource_id,
+        name: Some(record.name().to_string()),
+        // Record sets are not taggable; tags live on the hosted zone
+        tags: json!({}),
+        resource_data,
+    }
+}
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 
+use aws_sdk_s3::error::ProvideErrorMetadata;
+use aws_sdk_s3::types::{BucketVersioningStatus, Type as GranteeType};
 use aws_sdk_s3::Client as S3Client;
 use std::sync::Arc;
 use uuid::Uuid;
@@ -21,10 +23,14 @@ use crate::errors::AppError;
 use crate::models::aws_account::AwsAccountDto;
 use crate::models::aws_auth::AccountAuthInfo;
 use crate::models::aws_resource::{AwsResourceDto, Model as AwsResourceModel};
+use crate::services::aws::aws_types::s3::{BucketSecurityAudit, PolicyStatement};
 use crate::services::aws::client_factory::AwsClientFactory;
 use crate::services::AwsService;
 use serde_json::json;
-use tracing::{debug, error, info, trace};
+use tracing::{debug, error, info, trace, warn};
+
+const ALL_USERS_URI: &str = "http://acs.amazonaws.com/groups/global/AllUsers";
+const AUTHENTICATED_USERS_URI: &str = "http://acs.amazonaws.com/groups/global/AuthenticatedUsers";
 
 // Control plane implementation for S3
 pub struct S3ControlPlane {
@@ -203,4 +209,284 @@ impl S3ControlPlane {
 
         Ok(buckets.into_iter().map(|b| b.into()).collect())
     }
+
+    pub async fn audit_bucket_security(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        bucket_name: &str,
+    ) -> Result<BucketSecurityAudit, AppError> {
+        let mut account = aws_account_dto.clone();
+        account.default_region = region.to_string();
+        let client = self.aws_service.create_s3_client(&account).await?;
+
+        Self::audit_bucket(&client, bucket_name).await
+    }
+
+    // Audits every bucket located in the region and keeps those scoring above
+    // the threshold. Buckets that cannot be audited are logged and skipped.
+    pub async fn audit_all_buckets(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        risk_threshold: u8,
+    ) -> Result<Vec<BucketSecurityAudit>, AppError> {
+        let mut account = aws_account_dto.clone();
+        account.default_region = region.to_string();
+        let client = self.aws_service.create_s3_client(&account).await?;
+
+        let response = client.list_buckets().send().await.map_err(|e| {
+            AppError::ExternalService(format!(
+                "Failed to list S3 buckets: {}",
+                e.into_service_error()
+            ))
+        })?;
+
+        let mut audits = Vec::new();
+        for bucket in response.buckets() {
+            let Some(bucket_name) = bucket.name() else {
+                continue;
+            };
+
+            let location = client
+                .get_bucket_location()
+                .bucket(bucket_name)
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::ExternalService(format!(
+                        "Failed to get bucket location for {}: {}",
+                        bucket_name, e
+                    ))
+                })?;
+            // us-east-1 buckets report no location constraint, "EU" is the legacy eu-west-1
+            let bucket_region = match location.location_constraint().map(|c| c.as_str()) {
+                None | Some("") => "us-east-1",
+                Some("EU") => "eu-west-1",
+                Some(other) => other,
+            };
+            if bucket_region != region {
+                continue;
+            }
+
+            match Self::audit_bucket(&client, bucket_name).await {
+                Ok(audit) if audit.risk_score > risk_threshold => audits.push(audit),
+                Ok(_) => {}
+                Err(e) => warn!("Skipping security audit of bucket {}: {}", bucket_name, e),
+            }
+        }
+
+        audits.sort_by(|a, b| b.risk_score.cmp(&a.risk_score));
+        Ok(audits)
+    }
+
+    async fn audit_bucket(
+        client: &S3Client,
+        bucket_name: &str,
+    ) -> Result<BucketSecurityAudit, AppError> {
+        let mut findings = Vec::new();
+
+        // Each of these reports a missing configuration as a service error code
+        let policy_statements = match client.get_bucket_policy().bucket(bucket_name).send().await {
+            Ok(resp) => match resp.policy() {
+                Some(policy) => parse_policy_statements(policy)?,
+                None => Vec::new(),
+            },
+            Err(e) if e.code() == Some("NoSuchBucketPolicy") => Vec::new(),
+            Err(e) => {
+                return Err(AppError::ExternalService(format!(
+                    "Failed to get bucket policy for {}: {}",
+                    bucket_name,
+                    e.into_service_error()
+                )))
+            }
+        };
+
+        let acl = client
+            .get_bucket_acl()
+            .bucket(bucket_name)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to get bucket ACL for {}: {}",
+                    bucket_name,
+                    e.into_service_error()
+                ))
+            })?;
+        let mut public_acl_grants = Vec::new();
+        for grant in acl.grants() {
+            let Some(grantee) = grant.grantee() else {
+                continue;
+            };
+            if grantee.r#type() != &GranteeType::Group {
+                continue;
+            }
+            let group = match grantee.uri() {
+                Some(ALL_USERS_URI) => "AllUsers",
+                Some(AUTHENTICATED_USERS_URI) => "AuthenticatedUsers",
+                _ => continue,
+            };
+            let permission = grant.permission().map(|p| p.as_str()).unwrap_or("UNKNOWN");
+            public_acl_grants.push(format!("{}:{}", group, permission));
+        }
+
+        let encryption_type = match client
+            .get_bucket_encryption()
+            .bucket(bucket_name)
+            .send()
+            .await
+        {
+            Ok(resp) => resp
+                .server_side_encryption_configuration()
+                .and_then(|c| c.rules().first())
+                .and_then(|r| r.apply_server_side_encryption_by_default())
+                .map(|d| d.sse_algorithm().as_str().to_string()),
+            Err(e) if e.code() == Some("ServerSideEncryptionConfigurationNotFoundError") => None,
+            Err(e) => {
+                return Err(AppError::ExternalService(format!(
+                    "Failed to get bucket encryption for {}: {}",
+                    bucket_name,
+                    e.into_service_error()
+                )))
+            }
+        };
+
+        let public_access_blocked = match client
+            .get_public_access_block()
+            .bucket(bucket_name)
+            .send()
+            .await
+        {
+            Ok(resp) => resp
+                .public_access_block_configuration()
+                .map(|c| {
+                    c.block_public_acls().unwrap_or(false)
+                        && c.ignore_public_acls().unwrap_or(false)
+                        && c.block_public_policy().unwrap_or(false)
+                        && c.restrict_public_buckets().unwrap_or(false)
+                })
+                .unwrap_or(false),
+            Err(e) if e.code() == Some("NoSuchPublicAccessBlockConfiguration") => false,
+            Err(e) => {
+                return Err(AppError::ExternalService(format!(
+                    "Failed to get public access block for {}: {}",
+                    bucket_name,
+                    e.into_service_error()
+                )))
+            }
+        };
+
+        let versioning = client
+            .get_bucket_versioning()
+            .bucket(bucket_name)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to get versioning for {}: {}",
+                    bucket_name, e
+                ))
+            })?;
+        let versioning_enabled = versioning.status() == Some(&BucketVersioningStatus::Enabled);
+
+        let has_public_policy = policy_statements.iter().any(|s| s.is_public);
+        let mut risk_score: u32 = 0;
+        if has_public_policy {
+            risk_score += 40;
+            findings.push("Bucket policy grants access to anonymous principals".to_string());
+        }
+        if public_acl_grants.iter().any(|g| g.starts_with("AllUsers")) {
+            risk_score += 30;
+            findings.push("ACL grants access to all users".to_string());
+        } else if !public_acl_grants.is_empty() {
+            risk_score += 15;
+            findings.push("ACL grants access to any authenticated AWS user".to_string());
+        }
+        if !public_access_blocked {
+            risk_score += 10;
+            findings.push("Public access block is not fully enabled".to_string());
+        }
+        if encryption_type.is_none() {
+            risk_score += 15;
+            findings.push("Default encryption is not configured".to_string());
+        }
+        if !versioning_enabled {
+            risk_score += 5;
+            findings.push("Versioning is not enabled".to_string());
+        }
+
+        Ok(BucketSecurityAudit {
+            bucket_name: bucket_name.to_string(),
+            has_public_policy,
+            public_acl_grants,
+            encryption_type,
+            versioning_enabled,
+            public_access_blocked,
+            policy_statements,
+            risk_score: risk_score.min(100) as u8,
+            findings,
+        })
+    }
+}
+
+fn parse_policy_statements(policy: &str) -> Result<Vec<PolicyStatement>, AppError> {
+    let document: serde_json::Value = serde_json::from_str(policy)?;
+    // Statement, Action and Resource may each be a single value or a list
+    let as_list = |value: Option<&serde_json::Value>| -> Vec<serde_json::Value> {
+        match value {
+            Some(serde_json::Value::Array(items)) => items.clone(),
+            Some(item) => vec![item.clone()],
+            None => Vec::new(),
+        }
+    };
+    let as_strings = |value: Option<&serde_json::Value>| -> Vec<String> {
+        as_list(value)
+            .iter()
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect()
+    };
+
+    Ok(as_list(document.get("Statement"))
+        .iter()
+        .map(|statement| {
+            let effect = statement
+                .get("Effect")
+                .and_then(|e| e.as_str())
+                .unwrap_or("Deny")
+                .to_string();
+            let principal = statement
+                .get("Principal")
+                .cloned()
+                .unwrap_or(serde_json::Value::Null);
+            let has_condition = statement.get("Condition").is_some();
+            let is_public =
+                effect == "Allow" && is_wildcard_principal(&principal) && !has_condition;
+
+            PolicyStatement {
+                sid: statement
+                    .get("Sid")
+                    .and_then(|s| s.as_str())
+                    .map(str::to_string),
+                effect,
+                principal,
+                actions: as_strings(statement.get("Action")),
+                resources: as_strings(statement.get("Resource")),
+                has_condition,
+                is_public,
+            }
+        })
+        .collect())
+}
+
+fn is_wildcard_principal(principal: &serde_json::Value) -> bool {
+    match principal {
+        serde_json::Value::String(p) => p == "*",
+        serde_json::Value::Object(map) => map.get("AWS").map_or(false, |aws| match aws {
+            serde_json::Value::String(p) => p == "*",
+            serde_json::Value::Array(items) => items.iter().any(|p| p.as_str() == Some("*")),
+            _ => false,
+        }),
+        _ => false,
+    }
 }
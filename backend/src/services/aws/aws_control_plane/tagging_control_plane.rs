@@ -0,0 +1,202 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::AppError;
+use crate::models::aws_account::AwsAccountDto;
+use crate::services::aws::aws_types::tagging::BulkTagResult;
+use crate::services::AwsService;
+use aws_sdk_resourcegroupstagging::types::FailureInfo;
+use aws_sdk_resourcegroupstagging::Client as TaggingClient;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+// TagResources and UntagResources accept at most 20 ARNs per call
+const MAX_ARNS_PER_CALL: usize = 20;
+
+pub struct TaggingControlPlane {
+    aws_service: Arc<AwsService>,
+}
+
+impl TaggingControlPlane {
+    pub fn new(aws_service: Arc<AwsService>) -> Self {
+        Self { aws_service }
+    }
+
+    async fn client(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+    ) -> Result<TaggingClient, AppError> {
+        let sdk_config = self.aws_service.get_aws_sdk_config(aws_account_dto).await?;
+        let config = aws_sdk_resourcegroupstagging::config::Builder::from(&sdk_config)
+            .region(aws_types::region::Region::new(region.to_string()))
+            .build();
+        Ok(TaggingClient::from_conf(config))
+    }
+
+    pub async fn bulk_tag_resources(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        resource_arns: Vec<String>,
+        tags: HashMap<String, String>,
+    ) -> Result<BulkTagResult, AppError> {
+        if resource_arns.is_empty() || tags.is_empty() {
+            return Err(AppError::Validation(
+                "At least one resource ARN and one tag are required".to_string(),
+            ));
+        }
+
+        let client = self.client(aws_account_dto, region).await?;
+        let mut result = BulkTagResult::default();
+        for batch in resource_arns.chunks(MAX_ARNS_PER_CALL) {
+            let response = client
+                .tag_resources()
+                .set_resource_arn_list(Some(batch.to_vec()))
+                .set_tags(Some(tags.clone()))
+                .send()
+                .await;
+            match response {
+                Ok(output) => {
+                    collect_batch_result(&mut result, batch, output.failed_resources_map())
+                }
+                Err(e) => fail_batch(&mut result, batch, &e.to_string()),
+            }
+        }
+
+        info!(
+            "Tagged {} of {} resources in account {} ({})",
+            result.succeeded.len(),
+            resource_arns.len(),
+            aws_account_dto.account_id,
+            region
+        );
+
+        for arn in &result.succeeded {
+            self.sync_local_tags(arn, |local| {
+                for (key, value) in &tags {
+                    local.insert(key.clone(), json!(value));
+                }
+            })
+            .await;
+        }
+        Ok(result)
+    }
+
+    pub async fn bulk_untag_resources(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        resource_arns: Vec<String>,
+        tag_keys: Vec<String>,
+    ) -> Result<BulkTagResult, AppError> {
+        if resource_arns.is_empty() || tag_keys.is_empty() {
+            return Err(AppError::Validation(
+                "At least one resource ARN and one tag key are required".to_string(),
+            ));
+        }
+
+        let client = self.client(aws_account_dto, region).await?;
+        let mut result = BulkTagResult::default();
+        for batch in resource_arns.chunks(MAX_ARNS_PER_CALL) {
+            let response = client
+                .untag_resources()
+                .set_resource_arn_list(Some(batch.to_vec()))
+                .set_tag_keys(Some(tag_keys.clone()))
+                .send()
+                .await;
+            match response {
+                Ok(output) => {
+                    collect_batch_result(&mut result, batch, output.failed_resources_map())
+                }
+                Err(e) => fail_batch(&mut result, batch, &e.to_string()),
+            }
+        }
+
+        info!(
+            "Untagged {} of {} resources in account {} ({})",
+            result.succeeded.len(),
+            resource_arns.len(),
+            aws_account_dto.account_id,
+            region
+        );
+
+        for arn in &result.succeeded {
+            self.sync_local_tags(arn, |local| {
+                for key in &tag_keys {
+                    local.remove(key);
+                }
+            })
+            .await;
+        }
+        Ok(result)
+    }
+
+    // Mirrors a tag change onto the synced resource record, if there is one. The
+    // change already happened in AWS, so a failure here only leaves the local copy
+    // stale until the next resource sync.
+    async fn sync_local_tags<F>(&self, arn: &str, apply: F)
+    where
+        F: FnOnce(&mut serde_json::Map<String, serde_json::Value>),
+    {
+        let repo = &self.aws_service.aws_resource_repo;
+        let resource = match repo.find_by_arn(arn).await {
+            Ok(Some(resource)) => resource,
+            Ok(None) => return,
+            Err(e) => {
+                warn!("Failed to look up resource {} for tag sync: {}", arn, e);
+                return;
+            }
+        };
+        let mut tags = resource.tags.as_object().cloned().unwrap_or_default();
+        apply(&mut tags);
+        if let Err(e) = repo
+            .update_tags(resource.id, serde_json::Value::Object(tags))
+            .await
+        {
+            warn!("Failed to update local tags for {}: {}", arn, e);
+        }
+    }
+}
+
+fn collect_batch_result(
+    result: &mut BulkTagResult,
+    batch: &[String],
+    failures: Option<&HashMap<String, FailureInfo>>,
+) {
+    for arn in batch {
+        match failures.and_then(|f| f.get(arn)) {
+            Some(info) => result.failed.push((
+                arn.clone(),
+                info.error_message()
+                    .unwrap_or("Unknown tagging error")
+                    .to_string(),
+            )),
+            None => result.succeeded.push(arn.clone()),
+        }
+    }
+}
+
+fn fail_batch(result: &mut BulkTagResult, batch: &[String], error: &str) {
+    warn!(
+        "Tagging request for {} resources failed: {}",
+        batch.len(),
+        error
+    );
+    result
+        .failed
+        .extend(batch.iter().map(|arn| (arn.clone(), error.to_string())));
+}
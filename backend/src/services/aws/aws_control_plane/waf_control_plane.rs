@@ -15,13 +15,30 @@
 use crate::errors::AppError;
 use crate::models::aws_account::AwsAccountDto;
 use crate::models::aws_resource::{AwsResourceDto, AwsResourceType, Model as AwsResourceModel};
+use crate::services::aws::aws_types::waf::{
+    RuleGroupResult, WafBlockIpResult, WafIpSetDetail, WafIpSetSummary, WafRuleGroupDetail,
+    WafRuleGroupSummary, WafRuleSummary, WafScope,
+};
 use crate::services::aws::client_factory::AwsClientFactory;
 use crate::services::AwsService;
+use aws_sdk_wafv2::types::{
+    BlockAction, IpAddressVersion, IpSetReferenceStatement, Rule, RuleAction, Statement,
+    VisibilityConfig,
+};
 use serde_json::json;
+use std::net::IpAddr;
 use std::sync::Arc;
-use tracing::debug;
+use tracing::{debug, info, warn};
 use uuid::Uuid;
 
+// Rule group capacity can't change after creation; one IP set reference uses 1 WCU,
+// so this leaves room for a few more rules to be added by hand
+const IP_BLOCK_RULE_GROUP_CAPACITY: i64 = 10;
+// Another writer changed the IP set between our read and update
+const IP_SET_UPDATE_ATTEMPTS: usize = 3;
+// IP set created alongside a rule group named "mayyam-emergency-block"
+pub const DEFAULT_BLOCK_IP_SET_NAME: &str = "mayyam-emergency-block-ips";
+
 pub struct WafControlPlane {
     aws_service: Arc<AwsService>,
 }
@@ -60,4 +77,552 @@ impl WafControlPlane {
 
         Ok(resources)
     }
+
+    // CloudFront-scoped resources only exist in us-east-1
+    async fn client_for_scope(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        scope: WafScope,
+    ) -> Result<aws_sdk_wafv2::Client, AppError> {
+        let mut account = aws_account_dto.clone();
+        account.default_region = match scope {
+            WafScope::Regional => region.to_string(),
+            WafScope::Cloudfront => "us-east-1".to_string(),
+        };
+        self.aws_service.create_waf_client(&account).await
+    }
+
+    pub async fn list_ip_sets(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        scope: WafScope,
+    ) -> Result<Vec<WafIpSetSummary>, AppError> {
+        let client = self
+            .client_for_scope(aws_account_dto, region, scope)
+            .await?;
+
+        let mut ip_sets = Vec::new();
+        let mut next_marker = None;
+        loop {
+            let response = client
+                .list_ip_sets()
+                .scope(scope.to_sdk())
+                .set_next_marker(next_marker)
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::CloudProvider(format!("Failed to list WAF IP sets: {}", e))
+                })?;
+            ip_sets.extend(response.ip_sets().iter().map(|s| WafIpSetSummary {
+                id: s.id().unwrap_or_default().to_string(),
+                name: s.name().unwrap_or_default().to_string(),
+                arn: s.arn().map(String::from),
+                description: s.description().map(String::from),
+                lock_token: s.lock_token().map(String::from),
+            }));
+
+            // WAF keeps returning a marker on the last page, so stop on an empty one
+            next_marker = response.next_marker().map(String::from);
+            if next_marker.is_none() || response.ip_sets().is_empty() {
+                break;
+            }
+        }
+
+        Ok(ip_sets)
+    }
+
+    pub async fn get_ip_set(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        scope: WafScope,
+        ip_set_id: &str,
+        ip_set_name: &str,
+    ) -> Result<WafIpSetDetail, AppError> {
+        let client = self
+            .client_for_scope(aws_account_dto, region, scope)
+            .await?;
+        let response = client
+            .get_ip_set()
+            .scope(scope.to_sdk())
+            .id(ip_set_id)
+            .name(ip_set_name)
+            .send()
+            .await
+            .map_err(|e| AppError::CloudProvider(format!("Failed to get WAF IP set: {}", e)))?;
+        let ip_set = response
+            .ip_set()
+            .ok_or_else(|| AppError::NotFound(format!("WAF IP set {} not found", ip_set_name)))?;
+
+        Ok(WafIpSetDetail {
+            id: ip_set.id().to_string(),
+            name: ip_set.name().to_string(),
+            arn: ip_set.arn().to_string(),
+            ip_address_version: ip_set.ip_address_version().as_str().to_string(),
+            addresses: ip_set.addresses().to_vec(),
+            lock_token: response.lock_token().map(String::from),
+        })
+    }
+
+    // Replaces the IP set's addresses. Returns the lock token for the next update.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_ip_set(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        scope: WafScope,
+        ip_set_id: &str,
+        ip_set_name: &str,
+        ip_set_lock_token: &str,
+        addresses: Vec<String>,
+    ) -> Result<String, AppError> {
+        let (addresses, _) = ip_set_addresses(&addresses)?;
+
+        let client = self
+            .client_for_scope(aws_account_dto, region, scope)
+            .await?;
+        let response = client
+            .update_ip_set()
+            .scope(scope.to_sdk())
+            .id(ip_set_id)
+            .name(ip_set_name)
+            .lock_token(ip_set_lock_token)
+            .set_addresses(Some(addresses))
+            .send()
+            .await
+            .map_err(|e| {
+                if e.as_service_error()
+                    .map_or(false, |se| se.is_waf_optimistic_lock_exception())
+                {
+                    AppError::Conflict(format!(
+                        "WAF IP set {} changed since it was read; fetch it again and retry",
+                        ip_set_name
+                    ))
+                } else {
+                    AppError::CloudProvider(format!("Failed to update WAF IP set: {}", e))
+                }
+            })?;
+
+        info!("Updated WAF IP set {} ({})", ip_set_name, ip_set_id);
+        Ok(response.next_lock_token().unwrap_or_default().to_string())
+    }
+
+    pub async fn list_rule_groups(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        scope: WafScope,
+    ) -> Result<Vec<WafRuleGroupSummary>, AppError> {
+        let client = self
+            .client_for_scope(aws_account_dto, region, scope)
+            .await?;
+
+        let mut rule_groups = Vec::new();
+        let mut next_marker = None;
+        loop {
+            let response = client
+                .list_rule_groups()
+                .scope(scope.to_sdk())
+                .set_next_marker(next_marker)
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::CloudProvider(format!("Failed to list WAF rule groups: {}", e))
+                })?;
+            rule_groups.extend(response.rule_groups().iter().map(|g| WafRuleGroupSummary {
+                id: g.id().unwrap_or_default().to_string(),
+                name: g.name().unwrap_or_default().to_string(),
+                arn: g.arn().map(String::from),
+                description: g.description().map(String::from),
+                lock_token: g.lock_token().map(String::from),
+            }));
+
+            next_marker = response.next_marker().map(String::from);
+            if next_marker.is_none() || response.rule_groups().is_empty() {
+                break;
+            }
+        }
+
+        Ok(rule_groups)
+    }
+
+    pub async fn get_rule_group(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        scope: WafScope,
+        rule_group_id: &str,
+        rule_group_name: &str,
+    ) -> Result<WafRuleGroupDetail, AppError> {
+        let client = self
+            .client_for_scope(aws_account_dto, region, scope)
+            .await?;
+        let response = client
+            .get_rule_group()
+            .scope(scope.to_sdk())
+            .id(rule_group_id)
+            .name(rule_group_name)
+            .send()
+            .await
+            .map_err(|e| AppError::CloudProvider(format!("Failed to get WAF rule group: {}", e)))?;
+        let group = response.rule_group().ok_or_else(|| {
+            AppError::NotFound(format!("WAF rule group {} not found", rule_group_name))
+        })?;
+
+        Ok(WafRuleGroupDetail {
+            id: group.id().to_string(),
+            name: group.name().to_string(),
+            arn: group.arn().to_string(),
+            capacity: group.capacity(),
+            description: group.description().map(String::from),
+            rules: group
+                .rules()
+                .iter()
+                .map(|r| WafRuleSummary {
+                    name: r.name().to_string(),
+                    priority: r.priority(),
+                    action: r.action().and_then(rule_action_name).map(String::from),
+                })
+                .collect(),
+            lock_token: response.lock_token().map(String::from),
+        })
+    }
+
+    // Creates an IP set named `<name>-ips` and a rule group with a single rule
+    // blocking it. The rule group still has to be added to a web ACL to take effect.
+    pub async fn create_ip_block_rule_group(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        scope: WafScope,
+        name: &str,
+        ip_addresses: Vec<String>,
+    ) -> Result<RuleGroupResult, AppError> {
+        let (addresses, version) = ip_set_addresses(&ip_addresses)?;
+
+        let client = self
+            .client_for_scope(aws_account_dto, region, scope)
+            .await?;
+        let ip_set_name = format!("{}-ips", name);
+        let ip_set = client
+            .create_ip_set()
+            .scope(scope.to_sdk())
+            .name(&ip_set_name)
+            .description(format!("Addresses blocked by rule group {}", name))
+            .ip_address_version(version.unwrap_or(IpAddressVersion::Ipv4))
+            .set_addresses(Some(addresses))
+            .send()
+            .await
+            .map_err(|e| AppError::CloudProvider(format!("Failed to create WAF IP set: {}", e)))?;
+        let ip_set = ip_set.summary().ok_or_else(|| {
+            AppError::CloudProvider("WAF did not return the created IP set".to_string())
+        })?;
+        let ip_set_id = ip_set.id().unwrap_or_default().to_string();
+        let ip_set_arn = ip_set.arn().unwrap_or_default().to_string();
+        let ip_set_lock_token = ip_set.lock_token().unwrap_or_default().to_string();
+
+        let created: Result<(String, String), AppError> = async {
+            let metric_name = metric_name_for(name);
+            let visibility = |metric: &str| {
+                VisibilityConfig::builder()
+                    .sampled_requests_enabled(true)
+                    .cloud_watch_metrics_enabled(true)
+                    .metric_name(metric)
+                    .build()
+                    .map_err(|e| {
+                        AppError::Internal(format!("Failed to build WAF visibility config: {}", e))
+                    })
+            };
+            let statement = Statement::builder()
+                .ip_set_reference_statement(
+                    IpSetReferenceStatement::builder()
+                        .arn(&ip_set_arn)
+                        .build()
+                        .map_err(|e| {
+                            AppError::Internal(format!("Failed to build IP set statement: {}", e))
+                        })?,
+                )
+                .build();
+            let rule = Rule::builder()
+                .name(format!("{}-block", name))
+                .priority(0)
+                .statement(statement)
+                .action(
+                    RuleAction::builder()
+                        .block(BlockAction::builder().build())
+                        .build(),
+                )
+                .visibility_config(visibility(&format!("{}-block", metric_name))?)
+                .build()
+                .map_err(|e| AppError::Internal(format!("Failed to build WAF rule: {}", e)))?;
+
+            let rule_group = client
+                .create_rule_group()
+                .scope(scope.to_sdk())
+                .name(name)
+                .capacity(IP_BLOCK_RULE_GROUP_CAPACITY)
+                .rules(rule)
+                .visibility_config(visibility(&metric_name)?)
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::CloudProvider(format!("Failed to create WAF rule group: {}", e))
+                })?;
+            let rule_group = rule_group.summary().ok_or_else(|| {
+                AppError::CloudProvider("WAF did not return the created rule group".to_string())
+            })?;
+            Ok((
+                rule_group.id().unwrap_or_default().to_string(),
+                rule_group.arn().unwrap_or_default().to_string(),
+            ))
+        }
+        .await;
+        let (rule_group_id, rule_group_arn) = match created {
+            Ok(created) => created,
+            Err(e) => {
+                // Nothing references the IP set without its rule group
+                if let Err(cleanup) = client
+                    .delete_ip_set()
+                    .scope(scope.to_sdk())
+                    .id(&ip_set_id)
+                    .name(&ip_set_name)
+                    .lock_token(&ip_set_lock_token)
+                    .send()
+                    .await
+                {
+                    warn!(
+                        "Failed to delete WAF IP set {} after the rule group was not created: {}",
+                        ip_set_name, cleanup
+                    );
+                }
+                return Err(e);
+            }
+        };
+
+        info!(
+            "Created WAF IP block rule group {} with {} addresses",
+            name,
+            ip_addresses.len()
+        );
+        Ok(RuleGroupResult {
+            rule_group_id,
+            rule_group_name: name.to_string(),
+            rule_group_arn,
+            ip_set_id,
+            ip_set_name,
+            ip_set_arn,
+        })
+    }
+
+    // Adds one address to an existing IP set, re-reading and retrying when
+    // another writer updates the set in between
+    pub async fn block_ip(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        scope: WafScope,
+        ip_set_name: &str,
+        ip_address: &str,
+    ) -> Result<WafBlockIpResult, AppError> {
+        let (cidr, _) = to_cidr(ip_address)?;
+        let summary = self
+            .list_ip_sets(aws_account_dto, region, scope)
+            .await?
+            .into_iter()
+            .find(|s| s.name == ip_set_name)
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "WAF IP set {} not found; create it with an IP block rule group first",
+                    ip_set_name
+                ))
+            })?;
+
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let ip_set = self
+                .get_ip_set(aws_account_dto, region, scope, &summary.id, ip_set_name)
+                .await?;
+            if ip_set.addresses.contains(&cidr) {
+                return Ok(WafBlockIpResult {
+                    ip_set_id: ip_set.id,
+                    ip_set_name: ip_set.name,
+                    address: cidr,
+                    already_blocked: true,
+                    address_count: ip_set.addresses.len(),
+                });
+            }
+
+            let mut addresses = ip_set.addresses.clone();
+            addresses.push(cidr.clone());
+            let address_count = addresses.len();
+            let lock_token = ip_set.lock_token.unwrap_or_default();
+            match self
+                .update_ip_set(
+                    aws_account_dto,
+                    region,
+                    scope,
+                    &ip_set.id,
+                    ip_set_name,
+                    &lock_token,
+                    addresses,
+                )
+                .await
+            {
+                Ok(_) => {
+                    return Ok(WafBlockIpResult {
+                        ip_set_id: ip_set.id,
+                        ip_set_name: ip_set.name,
+                        address: cidr,
+                        already_blocked: false,
+                        address_count,
+                    })
+                }
+                Err(AppError::Conflict(msg)) if attempt < IP_SET_UPDATE_ATTEMPTS => {
+                    warn!("Retrying block of {} in {}: {}", cidr, ip_set_name, msg);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+fn rule_action_name(action: &RuleAction) -> Option<&'static str> {
+    if action.block().is_some() {
+        Some("block")
+    } else if action.allow().is_some() {
+        Some("allow")
+    } else if action.count().is_some() {
+        Some("count")
+    } else if action.captcha().is_some() {
+        Some("captcha")
+    } else if action.challenge().is_some() {
+        Some("challenge")
+    } else {
+        None
+    }
+}
+
+// WAF IP sets only take CIDR blocks, so bare addresses become /32 or /128
+fn to_cidr(address: &str) -> Result<(String, IpAddressVersion), AppError> {
+    let address = address.trim();
+    let (ip, prefix) = match address.split_once('/') {
+        Some((ip, prefix)) => (ip, Some(prefix)),
+        None => (address, None),
+    };
+    let ip: IpAddr = ip
+        .parse()
+        .map_err(|_| AppError::Validation(format!("Invalid IP address: {}", address)))?;
+    let (max_prefix, version) = match ip {
+        IpAddr::V4(_) => (32, IpAddressVersion::Ipv4),
+        IpAddr::V6(_) => (128, IpAddressVersion::Ipv6),
+    };
+    let prefix = match prefix {
+        Some(p) => p
+            .parse::<u8>()
+            .ok()
+            .filter(|p| *p <= max_prefix)
+            .ok_or_else(|| AppError::Validation(format!("Invalid CIDR prefix: {}", address)))?,
+        None => max_prefix,
+    };
+    Ok((format!("{}/{}", ip, prefix), version))
+}
+
+// Normalises addresses for one IP set, which holds either IPv4 or IPv6 blocks.
+// The version is None for an empty list.
+pub(crate) fn ip_set_addresses(
+    addresses: &[String],
+) -> Result<(Vec<String>, Option<IpAddressVersion>), AppError> {
+    let mut cidrs = Vec::with_capacity(addresses.len());
+    let mut version = None;
+    for address in addresses {
+        let (cidr, v) = to_cidr(address)?;
+        if version.as_ref().map_or(false, |existing| existing != &v) {
+            return Err(AppError::Validation(
+                "A WAF IP set holds either IPv4 or IPv6 addresses, not both".to_string(),
+            ));
+        }
+        version = Some(v);
+        cidrs.push(cidr);
+    }
+    Ok((cidrs, version))
+}
+
+// CloudWatch metric names allow letters, digits, '-' and '_'
+fn metric_name_for(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_cidr_adds_host_prefix_to_bare_addresses() {
+        let (cidr, version) = to_cidr("203.0.113.7").unwrap();
+        assert_eq!(cidr, "203.0.113.7/32");
+        assert_eq!(version, IpAddressVersion::Ipv4);
+
+        let (cidr, version) = to_cidr(" 2001:db8::1 ").unwrap();
+        assert_eq!(cidr, "2001:db8::1/128");
+        assert_eq!(version, IpAddressVersion::Ipv6);
+    }
+
+    #[test]
+    fn test_to_cidr_keeps_valid_prefixes() {
+        assert_eq!(to_cidr("10.0.0.0/8").unwrap().0, "10.0.0.0/8");
+        assert_eq!(to_cidr("2001:db8::/32").unwrap().0, "2001:db8::/32");
+    }
+
+    #[test]
+    fn test_to_cidr_rejects_invalid_input() {
+        for address in [
+            "10.0.0.0/33",
+            "2001:db8::/129",
+            "10.0.0.0/",
+            "10.0.0.0/-1",
+            "10.0.0.0/abc",
+            "300.1.1.1",
+            "not-an-ip",
+        ] {
+            assert!(
+                matches!(to_cidr(address), Err(AppError::Validation(_))),
+                "{} should be rejected",
+                address
+            );
+        }
+    }
+
+    #[test]
+    fn test_ip_set_addresses_rejects_mixed_families() {
+        let addresses = vec!["203.0.113.7".to_string(), "2001:db8::1".to_string()];
+        assert!(matches!(
+            ip_set_addresses(&addresses),
+            Err(AppError::Validation(_))
+        ));
+
+        let addresses = vec!["203.0.113.7".to_string(), "198.51.100.0/24".to_string()];
+        let (cidrs, version) = ip_set_addresses(&addresses).unwrap();
+        assert_eq!(cidrs, vec!["203.0.113.7/32", "198.51.100.0/24"]);
+        assert_eq!(version, Some(IpAddressVersion::Ipv4));
+
+        assert_eq!(ip_set_addresses(&[]).unwrap(), (Vec::new(), None));
+    }
+
+    #[test]
+    fn test_metric_name_for_replaces_unsupported_characters() {
+        assert_eq!(metric_name_for("emergency-block_1"), "emergency-block_1");
+        assert_eq!(metric_name_for("team a/blocks.v2"), "team_a_blocks_v2");
+        assert_eq!(metric_name_for("blocé"), "bloc_");
+    }
 }
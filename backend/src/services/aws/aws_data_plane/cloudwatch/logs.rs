@@ -131,6 +131,7 @@ impl CloudWatchLogs for CloudWatchService {
 
         let mut events = Vec::new();
         let mut token: Option<String> = None;
+        let mut truncated = false;
 
         loop {
             let mut current_req = builder.clone();
@@ -170,6 +171,14 @@ impl CloudWatchLogs for CloudWatchService {
                 events.push(event_data);
             }
 
+            if let Some(max_events) = request.max_events {
+                if events.len() >= max_events {
+                    truncated = events.len() > max_events || response.next_token().is_some();
+                    events.truncate(max_events);
+                    break;
+                }
+            }
+
             match response.next_token() {
                 Some(next_token) => token = Some(next_token.to_string()),
                 None => break,
@@ -178,6 +187,7 @@ impl CloudWatchLogs for CloudWatchService {
 
         Ok(json!({
             "events": events,
+            "truncated": truncated,
             "logGroupName": request.log_group_name,
             "filterPattern": request.filter_pattern,
             "startTime": request.start_time,
@@ -34,6 +34,9 @@ pub struct CloudWatchLogsRequest {
     pub end_time: DateTime<Utc>,
     pub filter_pattern: Option<String>,
     pub limit: Option<i32>,
+    // Stop following next_token once this many events were read
+    #[serde(default)]
+    pub max_events: Option<usize>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
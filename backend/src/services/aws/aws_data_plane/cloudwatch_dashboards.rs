@@ -0,0 +1,276 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+use crate::errors::AppError;
+use crate::models::aws_account::AwsAccountDto;
+use crate::services::aws::aws_data_plane::cloudwatch::CloudWatchService;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DashboardResult {
+    pub dashboard_arn: String,
+    pub dashboard_url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DashboardWidgetSnapshot {
+    pub title: Option<String>,
+    // PNG rendered by GetMetricWidgetImage
+    pub image_base64: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DashboardSnapshot {
+    pub dashboard_name: String,
+    pub dashboard_arn: Option<String>,
+    pub body: Value,
+    pub widgets: Vec<DashboardWidgetSnapshot>,
+    pub captured_at: DateTime<Utc>,
+}
+
+// (title, namespace, metric name, statistic)
+type WidgetSpec = (&'static str, &'static str, &'static str, &'static str);
+
+const EC2_WIDGETS: &[WidgetSpec] = &[
+    ("CPU utilization", "AWS/EC2", "CPUUtilization", "Average"),
+    // Only published when the CloudWatch agent runs on the instance
+    (
+        "Memory utilization",
+        "CWAgent",
+        "mem_used_percent",
+        "Average",
+    ),
+];
+
+const RDS_WIDGETS: &[WidgetSpec] = &[
+    ("Connections", "AWS/RDS", "DatabaseConnections", "Average"),
+    ("Read IOPS", "AWS/RDS", "ReadIOPS", "Average"),
+    ("Write IOPS", "AWS/RDS", "WriteIOPS", "Average"),
+    ("CPU utilization", "AWS/RDS", "CPUUtilization", "Average"),
+];
+
+const ELASTICACHE_WIDGETS: &[WidgetSpec] = &[
+    ("Cache hits", "AWS/ElastiCache", "CacheHits", "Sum"),
+    ("Cache misses", "AWS/ElastiCache", "CacheMisses", "Sum"),
+    (
+        "CPU utilization",
+        "AWS/ElastiCache",
+        "CPUUtilization",
+        "Average",
+    ),
+];
+
+const WIDGET_WIDTH: u32 = 12;
+const WIDGET_HEIGHT: u32 = 6;
+
+pub struct CloudWatchDashboardService {
+    cloudwatch_service: Arc<CloudWatchService>,
+}
+
+impl CloudWatchDashboardService {
+    pub fn new(cloudwatch_service: Arc<CloudWatchService>) -> Self {
+        Self { cloudwatch_service }
+    }
+
+    /// Create or replace a dashboard with the standard widgets for one EC2 instance,
+    /// RDS instance or ElastiCache cluster.
+    pub async fn create_resource_dashboard(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        resource_id: &str,
+        resource_type: &str,
+        dashboard_name: &str,
+    ) -> Result<DashboardResult, AppError> {
+        let widgets = match resource_type {
+            "EC2Instance" => EC2_WIDGETS,
+            "RdsInstance" => RDS_WIDGETS,
+            "ElasticacheCluster" => ELASTICACHE_WIDGETS,
+            _ => {
+                return Err(AppError::BadRequest(format!(
+                    "Dashboards are not supported for resource type {}",
+                    resource_type
+                )))
+            }
+        };
+        let dimension = self
+            .cloudwatch_service
+            .create_dimensions_for_resource(resource_type, resource_id)
+            .into_iter()
+            .next()
+            .and_then(|d| d.name().map(|n| n.to_string()))
+            .ok_or_else(|| {
+                AppError::Internal(format!("No metric dimension for {}", resource_type))
+            })?;
+        let body = dashboard_body(widgets, &dimension, resource_id, region);
+
+        let mut account = aws_account_dto.clone();
+        account.default_region = region.to_string();
+        let client = self
+            .cloudwatch_service
+            .create_cloudwatch_client(&account)
+            .await?;
+
+        debug!(
+            "Creating CloudWatch dashboard {} for {} {}",
+            dashboard_name, resource_type, resource_id
+        );
+        let response = client
+            .put_dashboard()
+            .dashboard_name(dashboard_name)
+            .dashboard_body(body.to_string())
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to create CloudWatch dashboard {}: {}",
+                    dashboard_name, e
+                ))
+            })?;
+        for message in response.dashboard_validation_messages() {
+            warn!(
+                "CloudWatch dashboard {} validation: {}",
+                dashboard_name,
+                message.message().unwrap_or_default()
+            );
+        }
+
+        // PutDashboard does not return the ARN
+        let dashboard = client
+            .get_dashboard()
+            .dashboard_name(dashboard_name)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to get CloudWatch dashboard {}: {}",
+                    dashboard_name, e
+                ))
+            })?;
+
+        Ok(DashboardResult {
+            dashboard_arn: dashboard.dashboard_arn().unwrap_or_default().to_string(),
+            dashboard_url: dashboard_url(region, dashboard_name),
+        })
+    }
+
+    /// Fetch a dashboard's definition and render each of its metric widgets as an image.
+    pub async fn get_dashboard_snapshot(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        dashboard_name: &str,
+    ) -> Result<DashboardSnapshot, AppError> {
+        let mut account = aws_account_dto.clone();
+        account.default_region = region.to_string();
+        let client = self
+            .cloudwatch_service
+            .create_cloudwatch_client(&account)
+            .await?;
+
+        let dashboard = client
+            .get_dashboard()
+            .dashboard_name(dashboard_name)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to get CloudWatch dashboard {}: {}",
+                    dashboard_name, e
+                ))
+            })?;
+        let body: Value = serde_json::from_str(dashboard.dashboard_body().unwrap_or("{}"))?;
+
+        let mut widgets = Vec::new();
+        for widget in body["widgets"].as_array().into_iter().flatten() {
+            if widget["type"] != "metric" {
+                continue;
+            }
+            let properties = &widget["properties"];
+            let image = client
+                .get_metric_widget_image()
+                .metric_widget(properties.to_string())
+                .output_format("png")
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::ExternalService(format!(
+                        "Failed to render widget of dashboard {}: {}",
+                        dashboard_name, e
+                    ))
+                })?;
+            widgets.push(DashboardWidgetSnapshot {
+                title: properties["title"].as_str().map(|s| s.to_string()),
+                image_base64: image
+                    .metric_widget_image()
+                    .map(|blob| BASE64.encode(blob.as_ref()))
+                    .unwrap_or_default(),
+            });
+        }
+
+        Ok(DashboardSnapshot {
+            dashboard_name: dashboard_name.to_string(),
+            dashboard_arn: dashboard.dashboard_arn().map(|s| s.to_string()),
+            body,
+            widgets,
+            captured_at: Utc::now(),
+        })
+    }
+}
+
+// Two widgets per row, each showing one metric of the resource
+fn dashboard_body(
+    widgets: &[WidgetSpec],
+    dimension: &str,
+    resource_id: &str,
+    region: &str,
+) -> Value {
+    let widgets: Vec<Value> = widgets
+        .iter()
+        .enumerate()
+        .map(|(i, (title, namespace, metric_name, stat))| {
+            json!({
+                "type": "metric",
+                "x": (i as u32 % 2) * WIDGET_WIDTH,
+                "y": (i as u32 / 2) * WIDGET_HEIGHT,
+                "width": WIDGET_WIDTH,
+                "height": WIDGET_HEIGHT,
+                "properties": {
+                    "title": title,
+                    "metrics": [[namespace, metric_name, dimension, resource_id]],
+                    "stat": stat,
+                    "period": 300,
+                    "region": region,
+                    "view": "timeSeries",
+                },
+            })
+        })
+        .collect();
+    json!({ "widgets": widgets })
+}
+
+fn dashboard_url(region: &str, dashboard_name: &str) -> String {
+    format!(
+        "https://{region}.console.aws.amazon.com/cloudwatch/home?region={region}#dashboards:name={}",
+        dashboard_name,
+        region = region
+    )
+}
@@ -16,13 +16,22 @@
 use crate::errors::AppError;
 use crate::models::aws_account::AwsAccountDto;
 use crate::services::aws::aws_types::dynamodb::{
-    DynamoDBGetItemRequest, DynamoDBPutItemRequest, DynamoDBQueryRequest,
+    DynamoDBGetItemRequest, DynamoDBPutItemRequest, DynamoDBQueryRequest, DynamoDBScanRequest,
 };
 use crate::services::aws::client_factory::AwsClientFactory;
 use crate::services::AwsService;
+use aws_sdk_dynamodb::primitives::Blob;
+use aws_sdk_dynamodb::types::{AttributeValue, ReturnConsumedCapacity};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use serde_json::json;
+use std::collections::HashMap;
 use std::sync::Arc;
 
+// Upper bound for scan_all when the caller does not give one
+pub const DEFAULT_SCAN_MAX_ITEMS: usize = 1000;
+// Everything scan_all collects is held in memory and returned in one response
+pub const MAX_SCAN_ITEMS: usize = 10_000;
+
 // Data plane implementation for DynamoDB
 pub struct DynamoDBDataPlane {
     aws_service: Arc<AwsService>,
@@ -110,4 +119,240 @@ impl DynamoDBDataPlane {
 
         Ok(response)
     }
+
+    pub async fn scan(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        request: &DynamoDBScanRequest,
+    ) -> Result<serde_json::Value, AppError> {
+        let client = self
+            .aws_service
+            .create_dynamodb_client(aws_account_dto)
+            .await?;
+        let page = scan_page(
+            &client,
+            request,
+            request.limit,
+            request.exclusive_start_key.as_ref(),
+        )
+        .await?;
+
+        Ok(json!({
+            "Items": page.items,
+            "Count": page.count,
+            "ScannedCount": page.scanned_count,
+            "ConsumedCapacity": {
+                "TableName": request.table_name,
+                "CapacityUnits": page.capacity_units
+            },
+            "LastEvaluatedKey": page.last_evaluated_key
+        }))
+    }
+
+    // Follows LastEvaluatedKey until the table is exhausted or max_items are collected.
+    // Each page is limited to the remaining item budget, so a returned LastEvaluatedKey
+    // resumes exactly where this scan stopped.
+    pub async fn scan_all(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        request: &DynamoDBScanRequest,
+        max_items: usize,
+    ) -> Result<serde_json::Value, AppError> {
+        if max_items == 0 || max_items > MAX_SCAN_ITEMS {
+            return Err(AppError::BadRequest(format!(
+                "max_items must be between 1 and {}",
+                MAX_SCAN_ITEMS
+            )));
+        }
+        if request.limit.map_or(false, |limit| limit < 1) {
+            return Err(AppError::BadRequest("limit must be at least 1".to_string()));
+        }
+
+        let client = self
+            .aws_service
+            .create_dynamodb_client(aws_account_dto)
+            .await?;
+
+        let mut items = Vec::new();
+        let mut scanned_count: i64 = 0;
+        let mut capacity_units = 0.0;
+        let mut start_key = request.exclusive_start_key.clone();
+        loop {
+            let remaining = max_items.saturating_sub(items.len());
+            let page_limit = i32::try_from(remaining)
+                .unwrap_or(i32::MAX)
+                .min(request.limit.unwrap_or(i32::MAX));
+            let page = scan_page(&client, request, Some(page_limit), start_key.as_ref()).await?;
+
+            items.extend(page.items);
+            scanned_count += page.scanned_count as i64;
+            capacity_units += page.capacity_units;
+            start_key = page.last_evaluated_key;
+            if start_key.is_none() || items.len() >= max_items {
+                break;
+            }
+        }
+
+        Ok(json!({
+            "Items": items,
+            "Count": items.len(),
+            "ScannedCount": scanned_count,
+            "ConsumedCapacity": {
+                "TableName": request.table_name,
+                "CapacityUnits": capacity_units
+            },
+            "LastEvaluatedKey": start_key
+        }))
+    }
+}
+
+struct ScanPage {
+    items: Vec<serde_json::Value>,
+    count: i32,
+    scanned_count: i32,
+    capacity_units: f64,
+    last_evaluated_key: Option<serde_json::Value>,
+}
+
+async fn scan_page(
+    client: &aws_sdk_dynamodb::Client,
+    request: &DynamoDBScanRequest,
+    limit: Option<i32>,
+    exclusive_start_key: Option<&serde_json::Value>,
+) -> Result<ScanPage, AppError> {
+    let names = request
+        .expression_attribute_names
+        .as_ref()
+        .map(|names| {
+            serde_json::from_value::<HashMap<String, String>>(names.clone()).map_err(|e| {
+                AppError::BadRequest(format!("Invalid expression_attribute_names: {}", e))
+            })
+        })
+        .transpose()?;
+    let values = request
+        .expression_attribute_values
+        .as_ref()
+        .map(json_to_item)
+        .transpose()?;
+    let start_key = exclusive_start_key.map(json_to_item).transpose()?;
+
+    let response = client
+        .scan()
+        .table_name(&request.table_name)
+        .set_filter_expression(request.filter_expression.clone())
+        .set_projection_expression(request.projection_expression.clone())
+        .set_expression_attribute_names(names)
+        .set_expression_attribute_values(values)
+        .set_limit(limit)
+        .set_segment(request.segment)
+        .set_total_segments(request.total_segments)
+        .set_exclusive_start_key(start_key)
+        .return_consumed_capacity(ReturnConsumedCapacity::Total)
+        .send()
+        .await
+        .map_err(|e| {
+            AppError::ExternalService(format!("Failed to scan {}: {}", request.table_name, e))
+        })?;
+
+    Ok(ScanPage {
+        items: response.items().iter().map(item_to_json).collect(),
+        count: response.count(),
+        scanned_count: response.scanned_count(),
+        capacity_units: response
+            .consumed_capacity()
+            .and_then(|c| c.capacity_units())
+            .unwrap_or(0.0),
+        last_evaluated_key: response.last_evaluated_key().map(item_to_json),
+    })
+}
+
+// Items use the DynamoDB JSON wire format, e.g. {"id": {"S": "a"}, "count": {"N": "1"}}
+fn json_to_item(value: &serde_json::Value) -> Result<HashMap<String, AttributeValue>, AppError> {
+    let map = value
+        .as_object()
+        .ok_or_else(|| AppError::BadRequest("Expected a JSON object of attributes".to_string()))?;
+    map.iter()
+        .map(|(k, v)| Ok((k.clone(), json_to_attribute_value(v)?)))
+        .collect()
+}
+
+fn json_to_attribute_value(value: &serde_json::Value) -> Result<AttributeValue, AppError> {
+    let invalid = || AppError::BadRequest(format!("Invalid DynamoDB attribute value: {}", value));
+    let (kind, inner) = value
+        .as_object()
+        .filter(|m| m.len() == 1)
+        .and_then(|m| m.iter().next())
+        .ok_or_else(invalid)?;
+    let strings = |v: &serde_json::Value| -> Result<Vec<String>, AppError> {
+        v.as_array()
+            .ok_or_else(invalid)?
+            .iter()
+            .map(|s| s.as_str().map(String::from).ok_or_else(invalid))
+            .collect()
+    };
+    let blob = |v: &serde_json::Value| -> Result<Blob, AppError> {
+        let encoded = v.as_str().ok_or_else(invalid)?;
+        BASE64.decode(encoded).map(Blob::new).map_err(|_| invalid())
+    };
+
+    Ok(match kind.as_str() {
+        "S" => AttributeValue::S(inner.as_str().ok_or_else(invalid)?.to_string()),
+        // Numbers travel as strings to keep their precision
+        "N" => AttributeValue::N(match inner {
+            serde_json::Value::String(n) => n.clone(),
+            serde_json::Value::Number(n) => n.to_string(),
+            _ => return Err(invalid()),
+        }),
+        "BOOL" => AttributeValue::Bool(inner.as_bool().ok_or_else(invalid)?),
+        "NULL" => AttributeValue::Null(true),
+        "B" => AttributeValue::B(blob(inner)?),
+        "SS" => AttributeValue::Ss(strings(inner)?),
+        "NS" => AttributeValue::Ns(strings(inner)?),
+        "BS" => AttributeValue::Bs(
+            inner
+                .as_array()
+                .ok_or_else(invalid)?
+                .iter()
+                .map(blob)
+                .collect::<Result<_, _>>()?,
+        ),
+        "L" => AttributeValue::L(
+            inner
+                .as_array()
+                .ok_or_else(invalid)?
+                .iter()
+                .map(json_to_attribute_value)
+                .collect::<Result<_, _>>()?,
+        ),
+        "M" => AttributeValue::M(json_to_item(inner)?),
+        _ => return Err(invalid()),
+    })
+}
+
+fn item_to_json(item: &HashMap<String, AttributeValue>) -> serde_json::Value {
+    serde_json::Value::Object(
+        item.iter()
+            .map(|(k, v)| (k.clone(), attribute_value_to_json(v)))
+            .collect(),
+    )
+}
+
+fn attribute_value_to_json(value: &AttributeValue) -> serde_json::Value {
+    match value {
+        AttributeValue::S(s) => json!({ "S": s }),
+        AttributeValue::N(n) => json!({ "N": n }),
+        AttributeValue::Bool(b) => json!({ "BOOL": b }),
+        AttributeValue::Null(_) => json!({ "NULL": true }),
+        AttributeValue::B(b) => json!({ "B": BASE64.encode(b.as_ref()) }),
+        AttributeValue::Ss(ss) => json!({ "SS": ss }),
+        AttributeValue::Ns(ns) => json!({ "NS": ns }),
+        AttributeValue::Bs(bs) => json!({
+            "BS": bs.iter().map(|b| BASE64.encode(b.as_ref())).collect::<Vec<_>>()
+        }),
+        AttributeValue::L(list) => json!({
+            "L": list.iter().map(attribute_value_to_json).collect::<Vec<_>>()
+        }),
+        AttributeValue::M(map) => json!({ "M": item_to_json(map) }),
+        _ => serde_json::Value::Null,
+    }
 }
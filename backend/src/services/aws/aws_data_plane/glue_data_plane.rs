@@ -0,0 +1,246 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aws_sdk_glue::types::{Column, SerDeInfo, Table};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::models::aws_account::AwsAccountDto;
+use crate::models::aws_resource::{AwsResourceDto, AwsResourceType, Model as AwsResourceModel};
+use crate::services::aws::aws_types::glue::{GlueColumn, GlueSerdeInfo, GlueTableSchema};
+use crate::services::aws::client_factory::AwsClientFactory;
+use crate::services::AwsService;
+
+pub struct GlueDataPlane {
+    aws_service: Arc<AwsService>,
+}
+
+impl GlueDataPlane {
+    pub fn new(aws_service: Arc<AwsService>) -> Self {
+        Self { aws_service }
+    }
+
+    async fn client(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+    ) -> Result<aws_sdk_glue::Client, AppError> {
+        let mut account = aws_account_dto.clone();
+        account.default_region = region.to_string();
+        self.aws_service.create_glue_client(&account).await
+    }
+
+    // Walks every database in the region's Data Catalog and returns one
+    // GlueTable resource per table. The sync orchestrator persists them.
+    pub async fn sync_glue_catalogs(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        sync_id: Uuid,
+    ) -> Result<Vec<AwsResourceModel>, AppError> {
+        debug!(
+            "Syncing Glue tables for account: {} in {} with sync_id: {}",
+            &aws_account_dto.account_id, region, sync_id
+        );
+
+        let client = self.client(aws_account_dto, region).await?;
+        let mut resources: Vec<AwsResourceModel> = Vec::new();
+
+        for database_name in list_database_names(&client).await? {
+            let mut next_token: Option<String> = None;
+            loop {
+                let response = client
+                    .get_tables()
+                    .database_name(&database_name)
+                    .set_next_token(next_token)
+                    .send()
+                    .await
+                    .map_err(|e| {
+                        AppError::ExternalService(format!(
+                            "Failed to list Glue tables in database {}: {}",
+                            database_name, e
+                        ))
+                    })?;
+
+                for table in response.table_list() {
+                    let dto =
+                        table_to_resource(aws_account_dto, region, &database_name, table, sync_id);
+                    resources.push(dto.into());
+                }
+
+                next_token = response.next_token().map(String::from);
+                if next_token.is_none() {
+                    break;
+                }
+            }
+        }
+
+        debug!(
+            "Successfully synced {} Glue tables for account: {} with sync_id: {}",
+            resources.len(),
+            &aws_account_dto.account_id,
+            sync_id
+        );
+
+        Ok(resources)
+    }
+
+    pub async fn get_table_schema(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        database_name: &str,
+        table_name: &str,
+    ) -> Result<GlueTableSchema, AppError> {
+        let client = self.client(aws_account_dto, region).await?;
+        let response = client
+            .get_table()
+            .database_name(database_name)
+            .name(table_name)
+            .send()
+            .await
+            .map_err(|e| {
+                let service_error = e.into_service_error();
+                if service_error.is_entity_not_found_exception() {
+                    AppError::NotFound(format!(
+                        "Glue table {}.{} not found",
+                        database_name, table_name
+                    ))
+                } else {
+                    AppError::ExternalService(format!(
+                        "Failed to get Glue table {}.{}: {}",
+                        database_name, table_name, service_error
+                    ))
+                }
+            })?;
+
+        let table = response.table().ok_or_else(|| {
+            AppError::NotFound(format!(
+                "Glue table {}.{} not found",
+                database_name, table_name
+            ))
+        })?;
+        let storage = table.storage_descriptor();
+
+        Ok(GlueTableSchema {
+            database_name: database_name.to_string(),
+            table_name: table.name().to_string(),
+            table_type: table.table_type().map(String::from),
+            location: storage.and_then(|s| s.location()).map(String::from),
+            input_format: storage.and_then(|s| s.input_format()).map(String::from),
+            output_format: storage.and_then(|s| s.output_format()).map(String::from),
+            serde_info: storage.and_then(|s| s.serde_info()).map(to_glue_serde_info),
+            columns: storage
+                .map(|s| s.columns().iter().map(to_glue_column).collect())
+                .unwrap_or_default(),
+            partition_keys: table.partition_keys().iter().map(to_glue_column).collect(),
+            created_at: table.create_time().map(|t| t.to_string()),
+            updated_at: table.update_time().map(|t| t.to_string()),
+        })
+    }
+}
+
+async fn list_database_names(client: &aws_sdk_glue::Client) -> Result<Vec<String>, AppError> {
+    let mut names = Vec::new();
+    let mut next_token: Option<String> = None;
+    loop {
+        let response = client
+            .get_databases()
+            .set_next_token(next_token)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!("Failed to list Glue databases: {}", e))
+            })?;
+
+        names.extend(
+            response
+                .database_list()
+                .iter()
+                .map(|db| db.name().to_string()),
+        );
+
+        next_token = response.next_token().map(String::from);
+        if next_token.is_none() {
+            return Ok(names);
+        }
+    }
+}
+
+fn table_to_resource(
+    aws_account_dto: &AwsAccountDto,
+    region: &str,
+    database_name: &str,
+    table: &Table,
+    sync_id: Uuid,
+) -> AwsResourceDto {
+    let table_name = table.name();
+    let storage = table.storage_descriptor();
+    let partition_keys: Vec<GlueColumn> =
+        table.partition_keys().iter().map(to_glue_column).collect();
+
+    let resource_data = json!({
+        "database_name": database_name,
+        "table_name": table_name,
+        "table_type": table.table_type(),
+        "owner": table.owner(),
+        "location": storage.and_then(|s| s.location()),
+        "input_format": storage.and_then(|s| s.input_format()),
+        "output_format": storage.and_then(|s| s.output_format()),
+        "serde_info": storage.and_then(|s| s.serde_info()).map(to_glue_serde_info),
+        "partition_keys": partition_keys,
+        "column_count": storage.map(|s| s.columns().len()).unwrap_or(0),
+        "created_at": table.create_time().map(|t| t.to_string()),
+        "updated_at": table.update_time().map(|t| t.to_string()),
+    });
+
+    let resource_id = format!("{}/{}", database_name, table_name);
+    AwsResourceDto {
+        id: None,
+        sync_id: Some(sync_id),
+        account_id: aws_account_dto.account_id.clone(),
+        profile: aws_account_dto.profile.clone(),
+        region: region.to_string(),
+        resource_type: AwsResourceType::GlueTable.to_string(),
+        arn: format!(
+            "arn:aws:glue:{}:{}:table/{}",
+            region, aws_account_dto.account_id, resource_id
+        ),
+        resource_id,
+        name: Some(table_name.to_string()),
+        // Glue tables carry table parameters rather than resource tags
+        tags: json!({}),
+        resource_data,
+    }
+}
+
+fn to_glue_column(column: &Column) -> GlueColumn {
+    GlueColumn {
+        name: column.name().to_string(),
+        data_type: column.r#type().map(String::from),
+        comment: column.comment().map(String::from),
+        parameters: column.parameters().cloned().unwrap_or_default(),
+    }
+}
+
+fn to_glue_serde_info(serde_info: &SerDeInfo) -> GlueSerdeInfo {
+    GlueSerdeInfo {
+        name: serde_info.name().map(String::from),
+        serialization_library: serde_info.serialization_library().map(String::from),
+        parameters: serde_info.parameters().cloned().unwrap_or_default(),
+    }
+}
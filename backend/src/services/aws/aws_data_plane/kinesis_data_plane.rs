@@ -21,14 +21,19 @@ use crate::services::aws::aws_types::cloud_watch::{
 use crate::services::aws::aws_types::kinesis::{
     KinesisGetRecordsRequest, KinesisGetRecordsResponse, KinesisGetShardIteratorRequest,
     KinesisGetShardIteratorResponse, KinesisPutRecordRequest, KinesisPutRecordsRequest,
-    KinesisPutRecordsResponse,
+    KinesisPutRecordsResponse, KinesisRecord, KinesisSubscribeToShardRequest,
 };
 use crate::services::aws::client_factory::AwsClientFactory;
 use crate::services::AwsService;
 use aws_sdk_kinesis::primitives::Blob;
-use aws_sdk_kinesis::types::PutRecordsRequestEntry;
+use aws_sdk_kinesis::types::{
+    PutRecordsRequestEntry, Record, ShardIteratorType, StartingPosition,
+    SubscribeToShardEventStream,
+};
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use futures::stream::{self, BoxStream, StreamExt};
 use serde_json::json;
+use std::collections::VecDeque;
 use std::sync::Arc;
 
 // Data plane implementation for Kinesis
@@ -164,26 +169,8 @@ impl KinesisDataPlane {
             AppError::ExternalService(format!("Failed to get records from Kinesis stream: {}", e))
         })?;
 
-        // Convert records
-        let mut result_records = Vec::new();
-
-        for record in response.records() {
-            let data = record.data();
-
-            result_records.push(crate::services::aws::aws_types::kinesis::KinesisRecord {
-                sequence_number: record.sequence_number().to_string(),
-                data: BASE64.encode(data.as_ref()),
-                partition_key: record.partition_key().to_string(),
-                approximate_arrival_timestamp: record
-                    .approximate_arrival_timestamp()
-                    .map(|ts| ts.secs().to_string())
-                    .unwrap_or_else(|| "0".to_string()),
-                encryption_type: record.encryption_type().map(|et| et.as_str().to_string()),
-            });
-        }
-
         Ok(KinesisGetRecordsResponse {
-            records: result_records,
+            records: response.records().iter().map(to_kinesis_record).collect(),
             next_shard_iterator: response.next_shard_iterator().map(|s| s.to_string()),
             millis_behind_latest: Some(response.millis_behind_latest().unwrap_or(0)),
             child_shards: None, // TODO: Convert child shards if present
@@ -200,21 +187,7 @@ impl KinesisDataPlane {
             .create_kinesis_client(aws_account_dto)
             .await?;
 
-        let shard_iterator_type = match request.shard_iterator_type.as_str() {
-            "TRIM_HORIZON" => aws_sdk_kinesis::types::ShardIteratorType::TrimHorizon,
-            "LATEST" => aws_sdk_kinesis::types::ShardIteratorType::Latest,
-            "AT_SEQUENCE_NUMBER" => aws_sdk_kinesis::types::ShardIteratorType::AtSequenceNumber,
-            "AFTER_SEQUENCE_NUMBER" => {
-                aws_sdk_kinesis::types::ShardIteratorType::AfterSequenceNumber
-            }
-            "AT_TIMESTAMP" => aws_sdk_kinesis::types::ShardIteratorType::AtTimestamp,
-            _ => {
-                return Err(AppError::BadRequest(format!(
-                    "Invalid shard iterator type: {}",
-                    request.shard_iterator_type
-                )))
-            }
-        };
+        let shard_iterator_type = parse_shard_iterator_type(&request.shard_iterator_type)?;
 
         let stream_name = request
             .stream_name
@@ -254,4 +227,96 @@ impl KinesisDataPlane {
     // - list_shards
     // - merge_shards
     // - split_shard
+
+    // Enhanced fan-out read over HTTP/2. Records are yielded as they are pushed; the
+    // stream ends when AWS closes the subscription (after 5 minutes) or on error.
+    pub async fn subscribe_to_shard(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        request: &KinesisSubscribeToShardRequest,
+    ) -> Result<BoxStream<'static, Result<KinesisRecord, AppError>>, AppError> {
+        let mut position = StartingPosition::builder()
+            .r#type(parse_shard_iterator_type(&request.starting_position)?)
+            .set_sequence_number(request.starting_sequence_number.clone());
+        if let Some(timestamp_str) = &request.timestamp {
+            let timestamp_secs = timestamp_str.parse::<i64>().map_err(|_| {
+                AppError::BadRequest(format!("Invalid timestamp: {}", timestamp_str))
+            })?;
+            position = position.timestamp(aws_sdk_kinesis::primitives::DateTime::from_secs(
+                timestamp_secs,
+            ));
+        }
+        let position = position
+            .build()
+            .map_err(|e| AppError::BadRequest(format!("Invalid starting position: {}", e)))?;
+
+        let client = self
+            .aws_service
+            .create_kinesis_client(aws_account_dto)
+            .await?;
+        let output = client
+            .subscribe_to_shard()
+            .consumer_arn(&request.consumer_arn)
+            .shard_id(&request.shard_id)
+            .starting_position(position)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!("Failed to subscribe to shard: {}", e))
+            })?;
+
+        let records = stream::unfold(
+            (Some(output.event_stream), VecDeque::new()),
+            |(mut receiver, mut pending)| async move {
+                loop {
+                    if let Some(record) = pending.pop_front() {
+                        return Some((Ok(record), (receiver, pending)));
+                    }
+                    let events = receiver.as_mut()?;
+                    match events.recv().await {
+                        Ok(Some(SubscribeToShardEventStream::SubscribeToShardEvent(event))) => {
+                            pending.extend(event.records().iter().map(to_kinesis_record));
+                        }
+                        Ok(Some(_)) => {}
+                        Ok(None) => return None,
+                        Err(e) => {
+                            let err = AppError::ExternalService(format!(
+                                "Shard subscription failed: {}",
+                                e
+                            ));
+                            return Some((Err(err), (None, pending)));
+                        }
+                    }
+                }
+            },
+        );
+        Ok(records.boxed())
+    }
+}
+
+fn parse_shard_iterator_type(value: &str) -> Result<ShardIteratorType, AppError> {
+    match value {
+        "TRIM_HORIZON" => Ok(ShardIteratorType::TrimHorizon),
+        "LATEST" => Ok(ShardIteratorType::Latest),
+        "AT_SEQUENCE_NUMBER" => Ok(ShardIteratorType::AtSequenceNumber),
+        "AFTER_SEQUENCE_NUMBER" => Ok(ShardIteratorType::AfterSequenceNumber),
+        "AT_TIMESTAMP" => Ok(ShardIteratorType::AtTimestamp),
+        _ => Err(AppError::BadRequest(format!(
+            "Invalid shard iterator type: {}",
+            value
+        ))),
+    }
+}
+
+fn to_kinesis_record(record: &Record) -> KinesisRecord {
+    KinesisRecord {
+        sequence_number: record.sequence_number().to_string(),
+        data: BASE64.encode(record.data().as_ref()),
+        partition_key: record.partition_key().to_string(),
+        approximate_arrival_timestamp: record
+            .approximate_arrival_timestamp()
+            .map(|ts| ts.secs().to_string())
+            .unwrap_or_else(|| "0".to_string()),
+        encryption_type: record.encryption_type().map(|et| et.as_str().to_string()),
+    }
 }
@@ -0,0 +1,191 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use aws_sdk_cloudwatch::types::{Dimension, Metric, MetricDataQuery, MetricStat};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::debug;
+
+use crate::errors::AppError;
+use crate::models::aws_account::AwsAccountDto;
+use crate::services::aws::aws_data_plane::cloudwatch::{to_aws_datetime, CloudWatchService};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LambdaPerformanceReport {
+    pub avg_duration_ms: f64,
+    pub p95_duration_ms: f64,
+    pub p99_duration_ms: f64,
+    pub cold_start_rate: f64,
+    pub error_rate: f64,
+    pub throttle_rate: f64,
+    pub invocation_count: u64,
+    pub max_concurrent_executions: f64,
+}
+
+// (query id, namespace, metric name, statistic, dimension name)
+const PERFORMANCE_QUERIES: &[(&str, &str, &str, &str, &str)] = &[
+    ("invocations", "AWS/Lambda", "Invocations", "Sum", "FunctionName"),
+    ("errors", "AWS/Lambda", "Errors", "Sum", "FunctionName"),
+    ("throttles", "AWS/Lambda", "Throttles", "Sum", "FunctionName"),
+    ("duration_avg", "AWS/Lambda", "Duration", "Average", "FunctionName"),
+    ("duration_p95", "AWS/Lambda", "Duration", "p95", "FunctionName"),
+    ("duration_p99", "AWS/Lambda", "Duration", "p99", "FunctionName"),
+    ("concurrency", "AWS/Lambda", "ConcurrentExecutions", "Maximum", "FunctionName"),
+    // Init duration is only published as a metric when Lambda Insights is enabled;
+    // without it no cold starts are reported.
+    ("cold_starts", "LambdaInsights", "init_duration", "SampleCount", "function_name"),
+];
+
+pub struct LambdaAnalytics {
+    cloudwatch_service: Arc<CloudWatchService>,
+}
+
+impl LambdaAnalytics {
+    pub fn new(cloudwatch_service: Arc<CloudWatchService>) -> Self {
+        Self { cloudwatch_service }
+    }
+
+    pub async fn get_function_performance(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        function_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<LambdaPerformanceReport, AppError> {
+        if start_time >= end_time {
+            return Err(AppError::BadRequest(
+                "start_time must be before end_time".to_string(),
+            ));
+        }
+
+        let mut account = aws_account_dto.clone();
+        account.default_region = region.to_string();
+        let client = self.cloudwatch_service.create_cloudwatch_client(&account).await?;
+
+        let period = aggregate_period((end_time - start_time).num_seconds());
+        let queries: Vec<MetricDataQuery> = PERFORMANCE_QUERIES
+            .iter()
+            .map(|(id, namespace, metric_name, stat, dimension)| {
+                let metric = Metric::builder()
+                    .namespace(*namespace)
+                    .metric_name(*metric_name)
+                    .dimensions(
+                        Dimension::builder()
+                            .name(*dimension)
+                            .value(function_name)
+                            .build(),
+                    )
+                    .build();
+                MetricDataQuery::builder()
+                    .id(*id)
+                    .metric_stat(
+                        MetricStat::builder()
+                            .metric(metric)
+                            .period(period)
+                            .stat(*stat)
+                            .build(),
+                    )
+                    .return_data(true)
+                    .build()
+            })
+            .collect();
+
+        let response = client
+            .get_metric_data()
+            .start_time(to_aws_datetime(&start_time))
+            .end_time(to_aws_datetime(&end_time))
+            .set_metric_data_queries(Some(queries))
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to get CloudWatch metrics for Lambda function {}: {}",
+                    function_name, e
+                ))
+            })?;
+
+        let mut values: HashMap<String, Vec<f64>> = HashMap::new();
+        for result in response.metric_data_results() {
+            if let Some(id) = result.id() {
+                values.insert(id.to_string(), result.values().to_vec());
+            }
+        }
+
+        debug!(
+            "Fetched {} Lambda metric series for {} over {}s periods",
+            values.len(),
+            function_name,
+            period
+        );
+
+        Ok(build_performance_report(&values))
+    }
+}
+
+// One period spanning the whole range so each statistic comes back as a single value.
+// CloudWatch requires multiples of 60s, and of an hour for data older than 63 days.
+fn aggregate_period(range_secs: i64) -> i32 {
+    let round_to = if range_secs > 3600 { 3600 } else { 60 };
+    let rounded = ((range_secs.max(1) + round_to - 1) / round_to) * round_to;
+    rounded.min(i32::MAX as i64) as i32
+}
+
+// The range can still straddle two period boundaries: counts are summed, while averages
+// are averaged and percentiles/maximums keep the worst value.
+pub fn build_performance_report(values: &HashMap<String, Vec<f64>>) -> LambdaPerformanceReport {
+    let sum = |id: &str| values.get(id).map(|v| v.iter().sum()).unwrap_or(0.0);
+    let max = |id: &str| {
+        values
+            .get(id)
+            .and_then(|v| v.iter().copied().reduce(f64::max))
+            .unwrap_or(0.0)
+    };
+    let mean = |id: &str| match values.get(id) {
+        Some(v) if !v.is_empty() => v.iter().sum::<f64>() / v.len() as f64,
+        _ => 0.0,
+    };
+
+    let invocations: f64 = sum("invocations");
+    let rate = |count: f64| {
+        if invocations > 0.0 {
+            count / invocations
+        } else {
+            0.0
+        }
+    };
+
+    LambdaPerformanceReport {
+        avg_duration_ms: mean("duration_avg"),
+        p95_duration_ms: max("duration_p95"),
+        p99_duration_ms: max("duration_p99"),
+        cold_start_rate: rate(sum("cold_starts")),
+        error_rate: rate(sum("errors")),
+        // Throttled requests are not counted as invocations
+        throttle_rate: {
+            let throttles = sum("throttles");
+            let attempts = invocations + throttles;
+            if attempts > 0.0 {
+                throttles / attempts
+            } else {
+                0.0
+            }
+        },
+        invocation_count: invocations as u64,
+        max_concurrent_executions: max("concurrency"),
+    }
+}
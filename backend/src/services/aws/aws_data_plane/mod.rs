@@ -13,16 +13,20 @@
 // limitations under the License.
 
 
+pub mod cloudwatch_dashboards;
 pub mod dynamodb_data_plane;
 pub mod ec2_data_plane;
 pub mod elasticache_data_plane;
+pub mod glue_data_plane;
 pub mod kinesis_data_plane;
+pub mod lambda_analytics;
 pub mod lambda_data_plane;
 pub mod opensearch_data_plane;
 pub mod rds_data_plane;
 pub mod s3_data_plane;
 pub mod sns_data_plane;
 pub mod sqs_data_plane;
+pub mod vpc_flow_log_analyzer;
 
 pub mod cloudwatch;
 pub mod cost_explorer;
@@ -18,11 +18,23 @@ use crate::models::aws_account::AwsAccountDto;
 use crate::services::aws::aws_types::cloud_watch::{
     CloudWatchMetricsRequest, CloudWatchMetricsResult,
 };
+use crate::services::aws::aws_types::rds::{RdsPerformanceInsights, SqlPerformance};
 use crate::services::aws::client_factory::AwsClientFactory;
 use crate::services::AwsService;
+use aws_sdk_pi::primitives::DateTime as PiDateTime;
+use aws_sdk_pi::types::{DimensionGroup, MetricQuery, ServiceType};
+use chrono::{DateTime, Utc};
 use std::sync::Arc;
 use uuid;
 
+const DB_LOAD_METRIC: &str = "db.load.avg";
+const DEFAULT_SQL_METRICS: [&str; 2] = [
+    "db.sql.stats.calls_per_sec.avg",
+    "db.sql.stats.avg_latency_per_call.avg",
+];
+const TOP_SQL_LIMIT: i32 = 10;
+const SQL_TEXT_MAX_LEN: usize = 200;
+
 // Data plane implementation for RDS
 pub struct RdsDataPlane {
     aws_service: Arc<AwsService>,
@@ -52,6 +64,179 @@ impl RdsDataPlane {
         })
     }
 
+    /// Top SQL by database load from Performance Insights. `metrics` overrides the
+    /// per-statement metrics; names containing "calls_per_sec" and "latency" are mapped onto
+    /// `calls_per_sec` and `avg_latency_ms`.
+    pub async fn get_performance_insights(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        db_instance_id: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        metrics: Vec<String>,
+    ) -> Result<RdsPerformanceInsights, AppError> {
+        if end_time <= start_time {
+            return Err(AppError::BadRequest(
+                "end_time must be after start_time".to_string(),
+            ));
+        }
+
+        let resource_id = self
+            .resolve_dbi_resource_id(aws_account_dto, db_instance_id)
+            .await?;
+        let client = self.aws_service.create_pi_client(aws_account_dto).await?;
+
+        let period_seconds = pi_period_seconds((end_time - start_time).num_seconds());
+        let start = PiDateTime::from_secs(start_time.timestamp());
+        let end = PiDateTime::from_secs(end_time.timestamp());
+
+        let load_query = MetricQuery::builder()
+            .metric(DB_LOAD_METRIC)
+            .build()
+            .map_err(|e| AppError::Internal(format!("Failed to build metric query: {}", e)))?;
+        let load_response = client
+            .get_resource_metrics()
+            .service_type(ServiceType::Rds)
+            .identifier(&resource_id)
+            .start_time(start)
+            .end_time(end)
+            .period_in_seconds(period_seconds as i32)
+            .metric_queries(load_query)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to get Performance Insights metrics for {}: {}",
+                    db_instance_id, e
+                ))
+            })?;
+
+        let load_values: Vec<f64> = load_response
+            .metric_list()
+            .iter()
+            .flat_map(|m| m.data_points())
+            .map(|dp| dp.value())
+            .collect();
+        let db_load_avg = if load_values.is_empty() {
+            0.0
+        } else {
+            load_values.iter().sum::<f64>() / load_values.len() as f64
+        };
+
+        let sql_metrics = if metrics.is_empty() {
+            DEFAULT_SQL_METRICS.iter().map(|m| m.to_string()).collect()
+        } else {
+            metrics
+        };
+        let calls_metric = sql_metrics.iter().find(|m| m.contains("calls_per_sec"));
+        let latency_metric = sql_metrics.iter().find(|m| m.contains("latency"));
+
+        let group = DimensionGroup::builder()
+            .group("db.sql")
+            .dimensions("db.sql.id")
+            .dimensions("db.sql.statement")
+            .limit(TOP_SQL_LIMIT)
+            .build()
+            .map_err(|e| AppError::Internal(format!("Failed to build dimension group: {}", e)))?;
+
+        let keys_response = client
+            .describe_dimension_keys()
+            .service_type(ServiceType::Rds)
+            .identifier(&resource_id)
+            .start_time(start)
+            .end_time(end)
+            .period_in_seconds(period_seconds as i32)
+            .metric(DB_LOAD_METRIC)
+            .group_by(group)
+            .set_additional_metrics(Some(sql_metrics.clone()))
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to describe Performance Insights SQL for {}: {}",
+                    db_instance_id, e
+                ))
+            })?;
+
+        let top_sql = keys_response
+            .keys()
+            .iter()
+            .map(|key| {
+                let dimensions = key.dimensions();
+                let metric_value = |name: Option<&String>| {
+                    name.and_then(|n| key.additional_metrics().and_then(|m| m.get(n)))
+                        .copied()
+                        .unwrap_or(0.0)
+                };
+                let sql_text = dimensions
+                    .and_then(|d| d.get("db.sql.statement"))
+                    .map(|s| s.as_str())
+                    .unwrap_or_default();
+                let load = key.total().unwrap_or(0.0);
+
+                SqlPerformance {
+                    sql_id: dimensions
+                        .and_then(|d| d.get("db.sql.id"))
+                        .cloned()
+                        .unwrap_or_default(),
+                    sql_text_truncated: sql_text.chars().take(SQL_TEXT_MAX_LEN).collect(),
+                    calls_per_sec: metric_value(calls_metric),
+                    avg_latency_ms: metric_value(latency_metric),
+                    pct_of_total_load: if db_load_avg > 0.0 {
+                        load / db_load_avg * 100.0
+                    } else {
+                        0.0
+                    },
+                }
+            })
+            .collect();
+
+        Ok(RdsPerformanceInsights {
+            top_sql,
+            db_load_avg,
+            period_seconds,
+        })
+    }
+
+    // Performance Insights is keyed by the DbiResourceId (db-XXXX), not the instance name
+    async fn resolve_dbi_resource_id(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        db_instance_id: &str,
+    ) -> Result<String, AppError> {
+        let client = self.aws_service.create_rds_client(aws_account_dto).await?;
+        let response = client
+            .describe_db_instances()
+            .db_instance_identifier(db_instance_id)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to describe RDS instance {}: {}",
+                    db_instance_id, e
+                ))
+            })?;
+
+        let instance = response.db_instances().first().ok_or_else(|| {
+            AppError::NotFound(format!("RDS instance {} not found", db_instance_id))
+        })?;
+        if !instance.performance_insights_enabled().unwrap_or(false) {
+            return Err(AppError::BadRequest(format!(
+                "Performance Insights is not enabled on {}",
+                db_instance_id
+            )));
+        }
+        instance
+            .dbi_resource_id()
+            .map(|id| id.to_string())
+            .ok_or_else(|| {
+                AppError::ExternalService(format!(
+                    "RDS instance {} has no resource id",
+                    db_instance_id
+                ))
+            })
+    }
+
     // Additional RDS-specific data plane operations would go here
     // For example:
     // - Create snapshot
@@ -59,3 +244,14 @@ impl RdsDataPlane {
     // - Modify instance
     // - Start/stop instance
 }
+
+// Performance Insights only accepts these periods; pick the finest that keeps the
+// number of data points reasonable for the requested range
+fn pi_period_seconds(range_seconds: i64) -> i64 {
+    match range_seconds {
+        r if r <= 3600 => 60,
+        r if r <= 6 * 3600 => 300,
+        r if r <= 7 * 86400 => 3600,
+        _ => 86400,
+    }
+}
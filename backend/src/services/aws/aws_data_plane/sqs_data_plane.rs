@@ -18,13 +18,27 @@ use crate::models::aws_account::AwsAccountDto;
 use crate::services::aws::aws_types::cloud_watch::{
     CloudWatchMetricsRequest, CloudWatchMetricsResult,
 };
-use crate::services::aws::aws_types::sqs::{SqsReceiveMessageRequest, SqsSendMessageRequest};
+use crate::services::aws::aws_types::sqs::{
+    DlqStats, RequeueResult, SqsFifoSendRequest, SqsReceiveMessageRequest, SqsSendMessageRequest,
+};
 use crate::services::aws::client_factory::AwsClientFactory;
 use crate::services::AwsService;
+use aws_sdk_sqs::types::{
+    DeleteMessageBatchRequestEntry, MessageSystemAttributeName, QueueAttributeName,
+    SendMessageBatchRequestEntry,
+};
+use aws_sdk_sqs::Client as SqsClient;
 use serde_json::json;
+use std::collections::HashMap;
 use std::sync::Arc;
+use tracing::{info, warn};
 use uuid;
 
+// SQS caps receive, send and delete batches at 10 messages
+const SQS_BATCH_SIZE: u32 = 10;
+// Long enough to re-send a batch before the messages reappear in the DLQ
+const REQUEUE_VISIBILITY_TIMEOUT_SECS: i32 = 60;
+
 // Data plane implementation for SQS
 pub struct SqsDataPlane {
     aws_service: Arc<AwsService>,
@@ -40,15 +54,107 @@ impl SqsDataPlane {
         aws_account_dto: &AwsAccountDto,
         request: &SqsSendMessageRequest,
     ) -> Result<serde_json::Value, AppError> {
+        validate_queue_url(&request.queue_url, false)?;
         let client = self.aws_service.create_sqs_client(aws_account_dto).await?;
 
-        // In a real implementation, this would call send_message
-        let response = json!({
-            "MessageId": format!("msg-{}", chrono::Utc::now().timestamp()),
-            "MD5OfMessageBody": "d41d8cd98f00b204e9800998ecf8427e"
-        });
+        let response = client
+            .send_message()
+            .queue_url(&request.queue_url)
+            .message_body(&request.message_body)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to send message to {}: {}",
+                    request.queue_url, e
+                ))
+            })?;
 
-        Ok(response)
+        Ok(json!({
+            "MessageId": response.message_id(),
+            "MD5OfMessageBody": response.md5_of_message_body(),
+        }))
+    }
+
+    pub async fn send_fifo_message(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        request: &SqsFifoSendRequest,
+    ) -> Result<serde_json::Value, AppError> {
+        validate_queue_url(&request.queue_url, true)?;
+        if request.message_group_id.is_empty() {
+            return Err(AppError::BadRequest(
+                "message_group_id is required for FIFO queues".to_string(),
+            ));
+        }
+        // FIFO queues only support a queue-level delay
+        if request.delay_seconds.map_or(false, |d| d != 0) {
+            return Err(AppError::BadRequest(
+                "FIFO queues do not support per-message delay_seconds".to_string(),
+            ));
+        }
+        let client = self.aws_service.create_sqs_client(aws_account_dto).await?;
+
+        let response = client
+            .send_message()
+            .queue_url(&request.queue_url)
+            .message_body(&request.message_body)
+            .message_group_id(&request.message_group_id)
+            .set_message_deduplication_id(request.message_deduplication_id.clone())
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to send message to {}: {}",
+                    request.queue_url, e
+                ))
+            })?;
+
+        Ok(json!({
+            "MessageId": response.message_id(),
+            "MD5OfMessageBody": response.md5_of_message_body(),
+            "SequenceNumber": response.sequence_number(),
+        }))
+    }
+
+    /// Fetch the named queue attributes, or all of them when `attributes` is empty.
+    pub async fn get_queue_attributes(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        queue_url: &str,
+        attributes: Vec<String>,
+    ) -> Result<HashMap<String, String>, AppError> {
+        let client = self.aws_service.create_sqs_client(aws_account_dto).await?;
+        let attribute_names = if attributes.is_empty() {
+            vec![QueueAttributeName::All]
+        } else {
+            attributes
+                .iter()
+                .map(|a| QueueAttributeName::from(a.as_str()))
+                .collect()
+        };
+
+        let response = client
+            .get_queue_attributes()
+            .queue_url(queue_url)
+            .set_attribute_names(Some(attribute_names))
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to get queue attributes for {}: {}",
+                    queue_url, e
+                ))
+            })?;
+
+        Ok(response
+            .attributes()
+            .map(|a| {
+                a.iter()
+                    .map(|(name, value)| (name.as_str().to_string(), value.clone()))
+                    .collect()
+            })
+            .unwrap_or_default())
     }
 
     pub async fn receive_messages(
@@ -73,6 +179,224 @@ impl SqsDataPlane {
         Ok(response)
     }
 
+    pub async fn get_dlq_stats(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        queue_url: &str,
+    ) -> Result<DlqStats, AppError> {
+        let client = self.aws_service.create_sqs_client(aws_account_dto).await?;
+        let (visible, not_visible) = Self::queue_depth(&client, queue_url).await?;
+
+        // A DLQ doesn't reference its sources; the source queues' redrive policies point at it
+        let mut source_queue_urls = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let response = client
+                .list_dead_letter_source_queues()
+                .queue_url(queue_url)
+                .set_next_token(next_token.take())
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::ExternalService(format!(
+                        "Failed to list dead-letter source queues for {}: {}",
+                        queue_url, e
+                    ))
+                })?;
+            source_queue_urls.extend(response.queue_urls().iter().cloned());
+            next_token = response.next_token().map(|s| s.to_string());
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(DlqStats {
+            queue_url: queue_url.to_string(),
+            approximate_number_of_messages: visible,
+            approximate_number_of_messages_not_visible: not_visible,
+            source_queue_urls,
+        })
+    }
+
+    /// Move messages from a DLQ back to its source queue. Messages are only deleted from
+    /// the DLQ once the source queue accepted them; failed ones reappear after the
+    /// visibility timeout.
+    pub async fn requeue_dlq_messages(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        dlq_url: &str,
+        source_queue_url: &str,
+        max_messages: Option<u32>,
+    ) -> Result<RequeueResult, AppError> {
+        if dlq_url == source_queue_url {
+            return Err(AppError::BadRequest(
+                "DLQ and source queue must be different".to_string(),
+            ));
+        }
+
+        let client = self.aws_service.create_sqs_client(aws_account_dto).await?;
+        let is_fifo = source_queue_url.ends_with(".fifo");
+        let mut requeued = 0u32;
+        let mut failed = 0u32;
+
+        loop {
+            let remaining_budget = max_messages.map(|m| m.saturating_sub(requeued + failed));
+            if remaining_budget == Some(0) {
+                break;
+            }
+            let batch_size = remaining_budget.map_or(SQS_BATCH_SIZE, |r| r.min(SQS_BATCH_SIZE));
+
+            let received = client
+                .receive_message()
+                .queue_url(dlq_url)
+                .max_number_of_messages(batch_size as i32)
+                .visibility_timeout(REQUEUE_VISIBILITY_TIMEOUT_SECS)
+                .wait_time_seconds(1)
+                .message_attribute_names("All")
+                .attribute_names(QueueAttributeName::All)
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::ExternalService(format!(
+                        "Failed to receive messages from {}: {}",
+                        dlq_url, e
+                    ))
+                })?;
+
+            let messages = received.messages();
+            if messages.is_empty() {
+                break;
+            }
+
+            let mut receipt_handles = HashMap::new();
+            let mut entries = Vec::with_capacity(messages.len());
+            for (i, msg) in messages.iter().enumerate() {
+                let id = i.to_string();
+                let mut entry = SendMessageBatchRequestEntry::builder()
+                    .id(&id)
+                    .message_body(msg.body().unwrap_or_default())
+                    .set_message_attributes(msg.message_attributes().cloned());
+                if is_fifo {
+                    let group_id = msg
+                        .attributes()
+                        .and_then(|a| a.get(&MessageSystemAttributeName::MessageGroupId))
+                        .cloned();
+                    entry = entry
+                        .set_message_group_id(group_id)
+                        .set_message_deduplication_id(msg.message_id().map(|s| s.to_string()));
+                }
+                let entry = entry.build().map_err(|e| {
+                    AppError::Internal(format!("Failed to build requeue entry: {}", e))
+                })?;
+                entries.push(entry);
+                if let Some(handle) = msg.receipt_handle() {
+                    receipt_handles.insert(id, handle.to_string());
+                }
+            }
+
+            let sent = client
+                .send_message_batch()
+                .queue_url(source_queue_url)
+                .set_entries(Some(entries))
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::ExternalService(format!(
+                        "Failed to send messages to {}: {}",
+                        source_queue_url, e
+                    ))
+                })?;
+
+            for failure in sent.failed() {
+                warn!(
+                    "Failed to requeue message {} to {}: {}",
+                    failure.id(),
+                    source_queue_url,
+                    failure.message().unwrap_or_default()
+                );
+            }
+            failed += sent.failed().len() as u32;
+
+            let deletes = sent
+                .successful()
+                .iter()
+                .filter_map(|s| {
+                    let handle = receipt_handles.get(s.id())?;
+                    DeleteMessageBatchRequestEntry::builder()
+                        .id(s.id())
+                        .receipt_handle(handle)
+                        .build()
+                        .ok()
+                })
+                .collect::<Vec<_>>();
+            requeued += deletes.len() as u32;
+
+            if !deletes.is_empty() {
+                let deleted = client
+                    .delete_message_batch()
+                    .queue_url(dlq_url)
+                    .set_entries(Some(deletes))
+                    .send()
+                    .await
+                    .map_err(|e| {
+                        AppError::ExternalService(format!(
+                            "Failed to delete requeued messages from {}: {}",
+                            dlq_url, e
+                        ))
+                    })?;
+                // Already in the source queue, so they will be delivered twice
+                for failure in deleted.failed() {
+                    warn!(
+                        "Requeued message {} could not be removed from {}: {}",
+                        failure.id(),
+                        dlq_url,
+                        failure.message().unwrap_or_default()
+                    );
+                }
+            }
+        }
+
+        let (remaining_in_dlq, _) = Self::queue_depth(&client, dlq_url).await?;
+        info!(
+            "Requeued {} messages from {} to {} ({} failed)",
+            requeued, dlq_url, source_queue_url, failed
+        );
+
+        Ok(RequeueResult {
+            requeued,
+            failed,
+            remaining_in_dlq,
+        })
+    }
+
+    async fn queue_depth(client: &SqsClient, queue_url: &str) -> Result<(u32, u32), AppError> {
+        let response = client
+            .get_queue_attributes()
+            .queue_url(queue_url)
+            .attribute_names(QueueAttributeName::ApproximateNumberOfMessages)
+            .attribute_names(QueueAttributeName::ApproximateNumberOfMessagesNotVisible)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to get queue attributes for {}: {}",
+                    queue_url, e
+                ))
+            })?;
+
+        let attribute = |name: QueueAttributeName| {
+            response
+                .attributes()
+                .and_then(|a| a.get(&name))
+                .and_then(|v| v.parse::<u32>().ok())
+                .unwrap_or(0)
+        };
+        Ok((
+            attribute(QueueAttributeName::ApproximateNumberOfMessages),
+            attribute(QueueAttributeName::ApproximateNumberOfMessagesNotVisible),
+        ))
+    }
+
     pub async fn get_queue_metrics(
         &self,
         aws_account_dto: &AwsAccountDto,
@@ -92,3 +416,24 @@ impl SqsDataPlane {
         })
     }
 }
+
+// FIFO queue names always end in ".fifo", so the URL alone tells the queue type
+fn validate_queue_url(queue_url: &str, fifo: bool) -> Result<(), AppError> {
+    if !queue_url.starts_with("https://") {
+        return Err(AppError::BadRequest(format!(
+            "Invalid SQS queue URL: {}",
+            queue_url
+        )));
+    }
+    match (queue_url.ends_with(".fifo"), fifo) {
+        (true, false) => Err(AppError::BadRequest(format!(
+            "{} is a FIFO queue; use the FIFO send endpoint with a message_group_id",
+            queue_url
+        ))),
+        (false, true) => Err(AppError::BadRequest(format!(
+            "{} is not a FIFO queue",
+            queue_url
+        ))),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,222 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::debug;
+
+use crate::errors::AppError;
+use crate::models::aws_account::AwsAccountDto;
+use crate::services::aws::aws_data_plane::cloudwatch::{
+    CloudWatchLogs, CloudWatchLogsRequest, CloudWatchService,
+};
+
+const TOP_N: usize = 10;
+pub const DEFAULT_MAX_FLOW_LOG_EVENTS: usize = 100_000;
+pub const MAX_FLOW_LOG_EVENTS_LIMIT: usize = 1_000_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VpcTrafficSummary {
+    pub top_source_ips: Vec<(String, u64)>,
+    pub top_destination_ports: Vec<(u16, u64)>,
+    pub rejected_connection_count: u64,
+    pub accepted_bytes: u64,
+    pub rejected_bytes: u64,
+    pub protocol_breakdown: HashMap<String, u64>,
+    // Parsed records attributed to the VPC; unparseable events and other VPCs are not counted
+    pub records_matched: usize,
+    // The time range held more than max_events events; only the first ones were analyzed
+    pub truncated: bool,
+}
+
+// One record in the default (version 2) VPC flow log format:
+// version account-id interface-id srcaddr dstaddr srcport dstport protocol packets bytes start end action log-status
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlowLogRecord {
+    pub interface_id: String,
+    pub src_addr: String,
+    pub dst_addr: String,
+    pub dst_port: Option<u16>,
+    pub protocol: String,
+    pub bytes: u64,
+    pub accepted: bool,
+    pub vpc_id: Option<String>,
+}
+
+pub fn parse_flow_log_record(line: &str) -> Option<FlowLogRecord> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    if fields.len() < 14 || fields[0] == "version" {
+        return None;
+    }
+    // NODATA / SKIPDATA records carry "-" in every traffic field
+    if fields[13] != "OK" {
+        return None;
+    }
+
+    let accepted = match fields[12] {
+        "ACCEPT" => true,
+        "REJECT" => false,
+        _ => return None,
+    };
+
+    // Custom formats that append vpc-id expose it as an extra field
+    let vpc_id = fields[14..]
+        .iter()
+        .find(|f| f.starts_with("vpc-"))
+        .map(|f| f.to_string());
+
+    Some(FlowLogRecord {
+        interface_id: fields[2].to_string(),
+        src_addr: fields[3].to_string(),
+        dst_addr: fields[4].to_string(),
+        dst_port: fields[6].parse().ok(),
+        protocol: protocol_name(fields[7]),
+        bytes: fields[9].parse().unwrap_or(0),
+        accepted,
+        vpc_id,
+    })
+}
+
+fn protocol_name(number: &str) -> String {
+    match number {
+        "1" => "ICMP".to_string(),
+        "6" => "TCP".to_string(),
+        "17" => "UDP".to_string(),
+        "58" => "ICMPv6".to_string(),
+        other => other.to_string(),
+    }
+}
+
+// Records without a vpc-id field cannot be attributed and are assumed to belong to the
+// VPC whose log group is being analyzed.
+pub fn summarize_flow_logs(records: &[FlowLogRecord], vpc_id: &str) -> VpcTrafficSummary {
+    let mut source_counts: HashMap<String, u64> = HashMap::new();
+    let mut port_counts: HashMap<u16, u64> = HashMap::new();
+    let mut protocol_breakdown: HashMap<String, u64> = HashMap::new();
+    let mut rejected_connection_count = 0;
+    let mut accepted_bytes = 0;
+    let mut rejected_bytes = 0;
+    let mut records_matched = 0;
+
+    for record in records {
+        if let Some(record_vpc) = &record.vpc_id {
+            if record_vpc != vpc_id {
+                continue;
+            }
+        }
+
+        records_matched += 1;
+        *source_counts.entry(record.src_addr.clone()).or_insert(0) += 1;
+        if let Some(port) = record.dst_port {
+            *port_counts.entry(port).or_insert(0) += 1;
+        }
+        *protocol_breakdown.entry(record.protocol.clone()).or_insert(0) += 1;
+
+        if record.accepted {
+            accepted_bytes += record.bytes;
+        } else {
+            rejected_connection_count += 1;
+            rejected_bytes += record.bytes;
+        }
+    }
+
+    VpcTrafficSummary {
+        top_source_ips: top_n(source_counts),
+        top_destination_ports: top_n(port_counts),
+        rejected_connection_count,
+        accepted_bytes,
+        rejected_bytes,
+        protocol_breakdown,
+        records_matched,
+        truncated: false,
+    }
+}
+
+fn top_n<K: Ord>(counts: HashMap<K, u64>) -> Vec<(K, u64)> {
+    let mut entries: Vec<(K, u64)> = counts.into_iter().collect();
+    entries.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    entries.truncate(TOP_N);
+    entries
+}
+
+pub struct VpcFlowLogAnalyzer {
+    cloudwatch_service: Arc<CloudWatchService>,
+}
+
+impl VpcFlowLogAnalyzer {
+    pub fn new(cloudwatch_service: Arc<CloudWatchService>) -> Self {
+        Self { cloudwatch_service }
+    }
+
+    pub async fn analyze(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        log_group: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        vpc_id: &str,
+        max_events: usize,
+    ) -> Result<VpcTrafficSummary, AppError> {
+        if start_time >= end_time {
+            return Err(AppError::BadRequest(
+                "start_time must be before end_time".to_string(),
+            ));
+        }
+        if max_events == 0 || max_events > MAX_FLOW_LOG_EVENTS_LIMIT {
+            return Err(AppError::BadRequest(format!(
+                "max_events must be between 1 and {}",
+                MAX_FLOW_LOG_EVENTS_LIMIT
+            )));
+        }
+
+        let request = CloudWatchLogsRequest {
+            log_group_name: log_group.to_string(),
+            start_time,
+            end_time,
+            filter_pattern: None,
+            limit: None,
+            max_events: Some(max_events),
+        };
+
+        let logs = self
+            .cloudwatch_service
+            .get_filtered_logs(aws_account_dto, &request)
+            .await?;
+
+        let records: Vec<FlowLogRecord> = logs["events"]
+            .as_array()
+            .map(|events| {
+                events
+                    .iter()
+                    .filter_map(|e| e["message"].as_str())
+                    .filter_map(parse_flow_log_record)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        debug!(
+            "Parsed {} flow log records from log group {} for VPC {}",
+            records.len(),
+            log_group,
+            vpc_id
+        );
+
+        let mut summary = summarize_flow_logs(&records, vpc_id);
+        summary.truncated = logs["truncated"].as_bool().unwrap_or(false);
+        Ok(summary)
+    }
+}
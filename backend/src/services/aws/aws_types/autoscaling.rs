@@ -0,0 +1,37 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use serde::{Deserialize, Serialize};
+
+// Auto Scaling Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AsgSetDesiredCapacityRequest {
+    pub desired_capacity: i32,
+    #[serde(default)]
+    pub honor_cooldown: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AsgProcessesRequest {
+    pub processes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AsgScalingActionResponse {
+    pub auto_scaling_group_name: String,
+    pub action: String,
+    pub desired_capacity: Option<i32>,
+    pub processes: Vec<String>,
+}
@@ -30,6 +30,22 @@ pub struct DynamoDbProvisionedThroughput {
     pub write_capacity_units: i64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DynamoDbCapacityRequest {
+    pub read_capacity: i64,
+    pub write_capacity: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapacityChangeResult {
+    pub table_name: String,
+    pub previous_mode: String,
+    pub new_mode: String,
+    pub duration_ms: u64,
+    // Capacity below recently consumed peaks is applied but reported here
+    pub warnings: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DynamoDbKeySchema {
     pub attribute_name: String,
@@ -97,6 +113,10 @@ pub struct DynamoDBScanRequest {
     pub filter_expression: Option<String>,
     pub expression_attribute_names: Option<serde_json::Value>,
     pub expression_attribute_values: Option<serde_json::Value>,
+    pub projection_expression: Option<String>,
     pub limit: Option<i32>,
+    // Parallel scan: this worker's segment out of total_segments
+    pub segment: Option<i32>,
+    pub total_segments: Option<i32>,
     pub exclusive_start_key: Option<serde_json::Value>,
 }
@@ -0,0 +1,60 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// EBS Snapshot Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EbsSnapshotInfo {
+    pub snapshot_id: String,
+    pub volume_id: Option<String>,
+    pub description: Option<String>,
+    pub state: Option<String>,
+    pub progress: Option<String>,
+    pub volume_size: Option<i32>,
+    pub encrypted: Option<bool>,
+    pub owner_id: Option<String>,
+    pub start_time: Option<String>,
+    pub tags: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EbsCreateSnapshotRequest {
+    pub region: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub tags: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EbsCopySnapshotRequest {
+    pub source_region: String,
+    pub target_region: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EbsSnapshotRetentionRequest {
+    pub region: String,
+    pub keep_last_n: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EbsSnapshotRetentionResult {
+    pub kept: Vec<String>,
+    pub deleted: Vec<String>,
+    // (snapshot ID, error message)
+    pub failed: Vec<(String, String)>,
+}
@@ -0,0 +1,69 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+
+// ECS task definition types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EcsContainerDefinitionSummary {
+    pub name: Option<String>,
+    pub image: Option<String>,
+    pub cpu: i32,
+    pub memory: Option<i32>,
+    pub essential: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EcsTaskDefinitionDetail {
+    pub task_definition_arn: String,
+    pub family: Option<String>,
+    pub revision: i32,
+    pub status: Option<String>,
+    pub cpu: Option<String>,
+    pub memory: Option<String>,
+    pub network_mode: Option<String>,
+    pub requires_compatibilities: Vec<String>,
+    pub execution_role_arn: Option<String>,
+    pub task_role_arn: Option<String>,
+    pub registered_at: Option<String>,
+    pub containers: Vec<EcsContainerDefinitionSummary>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EcsTaskDefinitionQuery {
+    pub region: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EcsDescribeTaskDefinitionQuery {
+    pub region: String,
+    // Full ARN or family:revision
+    pub task_definition: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EcsDeregisterTaskDefinitionsRequest {
+    pub region: String,
+    pub keep_last_n: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeregisterResult {
+    pub kept: Vec<String>,
+    // Older revisions still referenced by a service or one of its deployments
+    pub in_use: Vec<String>,
+    pub deregistered: Vec<String>,
+    // (task definition ARN, error message)
+    pub failed: Vec<(String, String)>,
+}
@@ -0,0 +1,47 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// Glue Data Catalog Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlueColumn {
+    pub name: String,
+    pub data_type: Option<String>,
+    pub comment: Option<String>,
+    pub parameters: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlueSerdeInfo {
+    pub name: Option<String>,
+    pub serialization_library: Option<String>,
+    pub parameters: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlueTableSchema {
+    pub database_name: String,
+    pub table_name: String,
+    pub table_type: Option<String>,
+    pub location: Option<String>,
+    pub input_format: Option<String>,
+    pub output_format: Option<String>,
+    pub serde_info: Option<GlueSerdeInfo>,
+    pub columns: Vec<GlueColumn>,
+    pub partition_keys: Vec<GlueColumn>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
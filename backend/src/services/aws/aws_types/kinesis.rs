@@ -133,6 +133,13 @@ pub struct KinesisSplitShardRequest {
     pub new_starting_hash_key: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShardOperationResult {
+    pub stream_name: String,
+    pub new_shard_ids: Vec<String>,
+    pub duration_ms: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KinesisResourcePolicyRequest {
     pub resource_arn: String,
@@ -152,6 +159,21 @@ pub struct KinesisDescribeStreamConsumerRequest {
     pub consumer_arn: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KinesisListStreamConsumersRequest {
+    pub stream_arn: String,
+}
+
+// Enhanced fan-out read; subscriptions end after 5 minutes and must be renewed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KinesisSubscribeToShardRequest {
+    pub consumer_arn: String,
+    pub shard_id: String,
+    pub starting_position: String, // TRIM_HORIZON, LATEST, AT_SEQUENCE_NUMBER, AFTER_SEQUENCE_NUMBER, AT_TIMESTAMP
+    pub starting_sequence_number: Option<String>,
+    pub timestamp: Option<String>, // Epoch seconds
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KinesisListShardsRequest {
     pub stream_name: Option<String>,
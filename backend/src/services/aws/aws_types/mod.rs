@@ -13,15 +13,22 @@
 // limitations under the License.
 
 
+pub mod autoscaling;
 pub mod cloud_watch;
 pub mod dynamodb;
+pub mod ebs;
+pub mod ecs;
 pub mod ec2;
 pub mod elasticache;
+pub mod glue;
 pub mod kinesis;
 pub mod lambda;
 pub mod opensearch;
 pub mod rds;
 pub mod resource_sync;
+pub mod route53;
 pub mod s3;
 pub mod sns;
 pub mod sqs;
+pub mod tagging;
+pub mod waf;
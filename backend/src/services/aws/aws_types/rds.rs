@@ -34,3 +34,19 @@ pub struct RdsEndpoint {
     pub port: i32,
     pub hosted_zone_id: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SqlPerformance {
+    pub sql_id: String,
+    pub sql_text_truncated: String,
+    pub calls_per_sec: f64,
+    pub avg_latency_ms: f64,
+    pub pct_of_total_load: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RdsPerformanceInsights {
+    pub top_sql: Vec<SqlPerformance>,
+    pub db_load_avg: f64,
+    pub period_seconds: i64,
+}
@@ -0,0 +1,44 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateHealthCheckRequest {
+    pub fqdn: String,
+    pub port: i32,
+    // HTTP, HTTPS, HTTP_STR_MATCH, HTTPS_STR_MATCH or TCP
+    pub protocol: String,
+    pub path: Option<String>,
+    pub failure_threshold: u8,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthCheckResult {
+    pub health_check_id: String,
+    pub caller_reference: String,
+    pub fqdn: String,
+    pub port: i32,
+    pub protocol: String,
+    pub path: Option<String>,
+    pub failure_threshold: u8,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthCheckStatus {
+    pub health_check_id: String,
+    // Healthy, Unhealthy or Unknown when no checker has reported yet
+    pub status: String,
+    pub checker_regions: Vec<String>,
+}
@@ -37,3 +37,29 @@ pub struct S3PutObjectRequest {
     pub content_type: Option<String>,
     pub body: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyStatement {
+    pub sid: Option<String>,
+    pub effect: String,
+    pub principal: serde_json::Value,
+    pub actions: Vec<String>,
+    pub resources: Vec<String>,
+    pub has_condition: bool,
+    // Allows anonymous access: wildcard principal without a condition
+    pub is_public: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BucketSecurityAudit {
+    pub bucket_name: String,
+    pub has_public_policy: bool,
+    pub public_acl_grants: Vec<String>,
+    pub encryption_type: Option<String>,
+    pub versioning_enabled: bool,
+    pub public_access_blocked: bool,
+    pub policy_statements: Vec<PolicyStatement>,
+    // 0 (no findings) to 100 (publicly exposed and unprotected)
+    pub risk_score: u8,
+    pub findings: Vec<String>,
+}
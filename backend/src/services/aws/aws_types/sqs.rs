@@ -21,6 +21,16 @@ pub struct SqsSendMessageRequest {
     pub message_body: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SqsFifoSendRequest {
+    pub queue_url: String,
+    pub message_body: String,
+    pub message_group_id: String,
+    // Required unless the queue has content-based deduplication enabled
+    pub message_deduplication_id: Option<String>,
+    pub delay_seconds: Option<i32>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SqsReceiveMessageRequest {
     pub queue_url: String,
@@ -28,3 +38,25 @@ pub struct SqsReceiveMessageRequest {
     pub visibility_timeout: Option<i32>,
     pub wait_time_seconds: Option<i32>,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DlqStats {
+    pub queue_url: String,
+    pub approximate_number_of_messages: u32,
+    pub approximate_number_of_messages_not_visible: u32,
+    pub source_queue_urls: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SqsRequeueRequest {
+    pub dlq_url: String,
+    pub source_queue_url: String,
+    pub max_messages: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequeueResult {
+    pub requeued: u32,
+    pub failed: u32,
+    pub remaining_in_dlq: u32,
+}
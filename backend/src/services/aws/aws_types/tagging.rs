@@ -0,0 +1,36 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// Resource Groups Tagging Types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkTagRequest {
+    pub resource_arns: Vec<String>,
+    pub tags: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkUntagRequest {
+    pub resource_arns: Vec<String>,
+    pub tag_keys: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BulkTagResult {
+    pub succeeded: Vec<String>,
+    // (resource ARN, error message)
+    pub failed: Vec<(String, String)>,
+}
@@ -0,0 +1,146 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+
+// WAF IP set and rule group types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WafScope {
+    #[default]
+    Regional,
+    // Web ACLs attached to CloudFront distributions; always managed from us-east-1
+    Cloudfront,
+}
+
+impl WafScope {
+    pub fn to_sdk(self) -> aws_sdk_wafv2::types::Scope {
+        match self {
+            WafScope::Regional => aws_sdk_wafv2::types::Scope::Regional,
+            WafScope::Cloudfront => aws_sdk_wafv2::types::Scope::Cloudfront,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WafIpSetSummary {
+    pub id: String,
+    pub name: String,
+    pub arn: Option<String>,
+    pub description: Option<String>,
+    pub lock_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WafIpSetDetail {
+    pub id: String,
+    pub name: String,
+    pub arn: String,
+    pub ip_address_version: String,
+    pub addresses: Vec<String>,
+    pub lock_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WafRuleGroupSummary {
+    pub id: String,
+    pub name: String,
+    pub arn: Option<String>,
+    pub description: Option<String>,
+    pub lock_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WafRuleSummary {
+    pub name: String,
+    pub priority: i32,
+    // block, allow, count, captcha or challenge; None when the rule defers to its statement
+    pub action: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WafRuleGroupDetail {
+    pub id: String,
+    pub name: String,
+    pub arn: String,
+    pub capacity: i64,
+    pub description: Option<String>,
+    pub rules: Vec<WafRuleSummary>,
+    pub lock_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleGroupResult {
+    pub rule_group_id: String,
+    pub rule_group_name: String,
+    pub rule_group_arn: String,
+    pub ip_set_id: String,
+    pub ip_set_name: String,
+    pub ip_set_arn: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WafBlockIpResult {
+    pub ip_set_id: String,
+    pub ip_set_name: String,
+    pub address: String,
+    // The address was already in the IP set, so nothing was changed
+    pub already_blocked: bool,
+    pub address_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WafListQuery {
+    pub region: String,
+    #[serde(default)]
+    pub scope: WafScope,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WafGetRuleGroupQuery {
+    pub region: String,
+    #[serde(default)]
+    pub scope: WafScope,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WafUpdateIpSetRequest {
+    pub region: String,
+    #[serde(default)]
+    pub scope: WafScope,
+    pub name: String,
+    pub lock_token: String,
+    // Replaces the whole address list, in CIDR notation
+    pub addresses: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WafCreateIpBlockRuleGroupRequest {
+    pub region: String,
+    #[serde(default)]
+    pub scope: WafScope,
+    pub name: String,
+    pub ip_addresses: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WafBlockIpRequest {
+    pub region: String,
+    #[serde(default)]
+    pub scope: WafScope,
+    // Defaults to the IP set created with the emergency block rule group
+    pub ip_set_name: Option<String>,
+    pub ip_address: String,
+}
@@ -15,18 +15,26 @@
 
 use crate::errors::AppError;
 use crate::models::aws_account::AwsAccountDto;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
 use crate::models::aws_resource::{AwsResourceDto, AwsResourceType, Model as AwsResourceModel};
 use crate::models::cloud_resource::CloudResourceDto;
+use crate::models::security_finding::SecurityFinding;
+use crate::repositories::config_violation::ConfigViolationRepository;
+use crate::repositories::distributed_lock::{DistributedLock, LockGuard};
+use crate::repositories::security_finding::SecurityFindingRepository;
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
 use crate::services::aws::AwsService;
 use aws_sdk_kinesis::types::StreamDescription;
 use chrono::Utc;
 use serde_json::{json, Value};
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{debug, error, info};
 use uuid::Uuid;
 
 // Import control planes from their respective modules
 use crate::services::aws::aws_control_plane::api_gateway_control_plane::ApiGatewayControlPlane;
+use crate::services::aws::aws_control_plane::autoscaling_control_plane::AutoScalingControlPlane;
 use crate::services::aws::aws_control_plane::cloudfront_control_plane::CloudFrontControlPlane;
 use crate::services::aws::aws_control_plane::dynamodb_control_plane::DynamoDbControlPlane;
 use crate::services::aws::aws_control_plane::ec2_control_plane::Ec2ControlPlane;
@@ -34,20 +42,25 @@ use crate::services::aws::aws_control_plane::ebs_control_plane::EbsControlPlane;
 use crate::services::aws::aws_control_plane::efs_control_plane::EfsControlPlane;
 use crate::services::aws::aws_control_plane::elasticache_control_plane::ElasticacheControlPlane;
 use crate::services::aws::aws_control_plane::iam_control_plane::IamControlPlane;
+use crate::services::aws::aws_control_plane::inspector_control_plane::InspectorControlPlane;
 use crate::services::aws::aws_control_plane::kinesis_control_plane::KinesisControlPlane;
 use crate::services::aws::aws_control_plane::lambda_control_plane::LambdaControlPlane;
 use crate::services::aws::aws_control_plane::load_balancer_control_plane::LoadBalancerControlPlane;
 use crate::services::aws::aws_control_plane::rds_control_plane::RdsControlPlane;
+use crate::services::aws::aws_control_plane::route53_control_plane::Route53ControlPlane;
 use crate::services::aws::aws_control_plane::s3_control_plane::S3ControlPlane;
 use crate::services::aws::aws_control_plane::sns_control_plane::SnsControlPlane;
 use crate::services::aws::aws_control_plane::sqs_control_plane::SqsControlPlane;
+use crate::services::aws::aws_control_plane::tagging_control_plane::TaggingControlPlane;
 use crate::services::aws::aws_control_plane::opensearch_control_plane::OpenSearchControlPlane;
 use crate::services::aws::aws_control_plane::vpc_control_plane::VpcControlPlane;
 // Batch 2: Security & Compliance
 use crate::services::aws::aws_control_plane::kms_control_plane::KmsControlPlane;
 use crate::services::aws::aws_control_plane::acm_control_plane::AcmControlPlane;
 use crate::services::aws::aws_control_plane::cloudtrail_control_plane::CloudTrailControlPlane;
-use crate::services::aws::aws_control_plane::config_control_plane::ConfigControlPlane;
+use crate::services::aws::aws_control_plane::config_control_plane::{
+    ComplianceReport, ConfigControlPlane,
+};
 // Batch 3: Containers & Serverless
 use crate::services::aws::aws_control_plane::ecs_control_plane::EcsControlPlane;
 use crate::services::aws::aws_control_plane::eks_control_plane::EksControlPlane;
@@ -66,7 +79,7 @@ use crate::services::aws::aws_control_plane::emr_control_plane::EmrControlPlane;
 use crate::services::aws::aws_control_plane::athena_control_plane::AthenaControlPlane;
 use crate::services::aws::aws_control_plane::glue_control_plane::GlueControlPlane;
 // Batch 7: Edge & DR
-use crate::services::aws::aws_control_plane::waf_control_plane::WafControlPlane;
+use crate::services::aws::aws_control_plane::waf_control_plane::{ip_set_addresses, WafControlPlane};
 use crate::services::aws::aws_control_plane::globalaccelerator_control_plane::GlobalAcceleratorControlPlane;
 use crate::services::aws::aws_control_plane::backup_control_plane::BackupControlPlane;
 // Final Review Additions
@@ -75,10 +88,19 @@ use crate::services::aws::aws_control_plane::storagegateway_control_plane::Stora
 use crate::services::aws::aws_control_plane::connect_control_plane::ConnectControlPlane;
 use crate::services::aws::aws_control_plane::appsync_control_plane::AppSyncControlPlane;
 use crate::services::aws::aws_control_plane::kinesisanalytics_control_plane::KinesisAnalyticsControlPlane;
+use crate::services::aws::aws_data_plane::glue_data_plane::GlueDataPlane;
 
+use crate::services::aws::aws_types::ebs::{EbsSnapshotInfo, EbsSnapshotRetentionResult};
+use crate::services::aws::aws_types::ecs::{DeregisterResult, EcsTaskDefinitionDetail};
 use crate::services::aws::aws_types::resource_sync::{
     ResourceSyncRequest, ResourceSyncResponse, ResourceTypeSyncSummary,
 };
+use crate::services::aws::aws_types::route53::{HealthCheckResult, HealthCheckStatus};
+use crate::services::aws::aws_types::tagging::BulkTagResult;
+use crate::services::aws::aws_types::waf::{
+    RuleGroupResult, WafBlockIpResult, WafIpSetSummary, WafRuleGroupDetail, WafRuleGroupSummary,
+    WafScope,
+};
 
 // Helper function to convert StreamDescription to JSON
 fn stream_description_to_json(stream_desc: &StreamDescription) -> Value {
@@ -114,8 +136,15 @@ pub trait AwsControlPlaneTrait: Send + Sync {
 
 pub struct AwsControlPlane {
     aws_service: Arc<AwsService>,
+    security_finding_repo: Option<Arc<SecurityFindingRepository>>,
+    config_violation_repo: Option<Arc<ConfigViolationRepository>>,
+    distributed_lock: Option<Arc<DistributedLock>>,
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
 }
 
+// The lock is renewed while the sync runs; a crashed holder's lock frees up after this
+const SYNC_LOCK_TTL: Duration = Duration::from_secs(10 * 60);
+
 #[async_trait::async_trait]
 impl AwsControlPlaneTrait for AwsControlPlane {
     async fn list_all_regions(
@@ -135,7 +164,53 @@ impl AwsControlPlaneTrait for AwsControlPlane {
 
 impl AwsControlPlane {
     pub fn new(aws_service: Arc<AwsService>) -> Self {
-        Self { aws_service }
+        Self {
+            aws_service,
+            security_finding_repo: None,
+            config_violation_repo: None,
+            distributed_lock: None,
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_security_finding_repo(mut self, repo: Arc<SecurityFindingRepository>) -> Self {
+        self.security_finding_repo = Some(repo);
+        self
+    }
+
+    fn security_finding_repo(&self) -> Result<&Arc<SecurityFindingRepository>, AppError> {
+        self.security_finding_repo.as_ref().ok_or_else(|| {
+            AppError::Config("Security finding storage is not configured".to_string())
+        })
+    }
+
+    pub fn with_config_violation_repo(mut self, repo: Arc<ConfigViolationRepository>) -> Self {
+        self.config_violation_repo = Some(repo);
+        self
+    }
+
+    fn config_violation_repo(&self) -> Result<&Arc<ConfigViolationRepository>, AppError> {
+        self.config_violation_repo.as_ref().ok_or_else(|| {
+            AppError::Config("Config violation storage is not configured".to_string())
+        })
+    }
+
+    pub fn with_distributed_lock(mut self, lock: Arc<DistributedLock>) -> Self {
+        self.distributed_lock = Some(lock);
+        self
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
+    }
+
+    // Without a configured lock (e.g. in the CLI) operations run unguarded
+    async fn lock(&self, key: &str, ttl: Duration) -> Result<Option<LockGuard>, AppError> {
+        match &self.distributed_lock {
+            Some(lock) => lock.acquire(key, ttl).await.map(Some),
+            None => Ok(None),
+        }
     }
 
     // Helper to expose region enumeration to callers without exposing inner service
@@ -638,12 +713,585 @@ impl AwsControlPlane {
         Ok(serde_json::to_value(response)?)
     }
 
+    pub async fn kinesis_split_shard(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        request: &crate::services::aws::aws_types::kinesis::KinesisSplitShardRequest,
+    ) -> Result<serde_json::Value, AppError> {
+        let kinesis = KinesisControlPlane::new(self.aws_service.clone());
+        let response = kinesis.split_shard(&aws_account_dto, request).await?;
+        Ok(serde_json::to_value(response)?)
+    }
+
+    pub async fn kinesis_merge_shards(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        request: &crate::services::aws::aws_types::kinesis::KinesisMergeShardsRequest,
+    ) -> Result<serde_json::Value, AppError> {
+        let kinesis = KinesisControlPlane::new(self.aws_service.clone());
+        let response = kinesis.merge_shards(&aws_account_dto, request).await?;
+        Ok(serde_json::to_value(response)?)
+    }
+
+    pub async fn kinesis_register_stream_consumer(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        request: &crate::services::aws::aws_types::kinesis::KinesisStreamConsumerRequest,
+    ) -> Result<serde_json::Value, AppError> {
+        let kinesis = KinesisControlPlane::new(self.aws_service.clone());
+        let response = kinesis
+            .register_stream_consumer(aws_account_dto, request)
+            .await?;
+        Ok(serde_json::to_value(response)?)
+    }
+
+    pub async fn kinesis_deregister_stream_consumer(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        request: &crate::services::aws::aws_types::kinesis::KinesisDescribeStreamConsumerRequest,
+    ) -> Result<(), AppError> {
+        let kinesis = KinesisControlPlane::new(self.aws_service.clone());
+        kinesis
+            .deregister_stream_consumer(aws_account_dto, request)
+            .await
+    }
+
+    pub async fn kinesis_list_stream_consumers(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        stream_arn: &str,
+    ) -> Result<serde_json::Value, AppError> {
+        let kinesis = KinesisControlPlane::new(self.aws_service.clone());
+        let response = kinesis
+            .list_stream_consumers(aws_account_dto, stream_arn)
+            .await?;
+        Ok(serde_json::to_value(response)?)
+    }
+
+    // Auto Scaling control plane operations
+    pub async fn asg_set_desired_capacity(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        asg_name: &str,
+        request: &crate::services::aws::aws_types::autoscaling::AsgSetDesiredCapacityRequest,
+    ) -> Result<serde_json::Value, AppError> {
+        let autoscaling = AutoScalingControlPlane::new(self.aws_service.clone());
+        let response = autoscaling
+            .set_asg_desired_capacity(
+                aws_account_dto,
+                asg_name,
+                request.desired_capacity,
+                request.honor_cooldown,
+            )
+            .await?;
+        Ok(serde_json::to_value(response)?)
+    }
+
+    pub async fn asg_suspend_processes(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        asg_name: &str,
+        request: &crate::services::aws::aws_types::autoscaling::AsgProcessesRequest,
+    ) -> Result<serde_json::Value, AppError> {
+        let autoscaling = AutoScalingControlPlane::new(self.aws_service.clone());
+        let response = autoscaling
+            .suspend_asg_processes(aws_account_dto, asg_name, request.processes.clone())
+            .await?;
+        Ok(serde_json::to_value(response)?)
+    }
+
+    pub async fn asg_resume_processes(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        asg_name: &str,
+        request: &crate::services::aws::aws_types::autoscaling::AsgProcessesRequest,
+    ) -> Result<serde_json::Value, AppError> {
+        let autoscaling = AutoScalingControlPlane::new(self.aws_service.clone());
+        let response = autoscaling
+            .resume_asg_processes(aws_account_dto, asg_name, request.processes.clone())
+            .await?;
+        Ok(serde_json::to_value(response)?)
+    }
+
+    pub async fn bulk_tag_resources(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        resource_arns: Vec<String>,
+        tags: std::collections::HashMap<String, String>,
+    ) -> Result<BulkTagResult, AppError> {
+        let tagging = TaggingControlPlane::new(self.aws_service.clone());
+        tagging
+            .bulk_tag_resources(aws_account_dto, region, resource_arns, tags)
+            .await
+    }
+
+    pub async fn bulk_untag_resources(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        resource_arns: Vec<String>,
+        tag_keys: Vec<String>,
+    ) -> Result<BulkTagResult, AppError> {
+        let tagging = TaggingControlPlane::new(self.aws_service.clone());
+        tagging
+            .bulk_untag_resources(aws_account_dto, region, resource_arns, tag_keys)
+            .await
+    }
+
+    // EBS snapshot lifecycle operations
+    pub async fn ebs_create_snapshot(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        volume_id: &str,
+        description: &str,
+        tags: std::collections::HashMap<String, String>,
+    ) -> Result<EbsSnapshotInfo, AppError> {
+        let ebs = EbsControlPlane::new(self.aws_service.clone());
+        ebs.create_snapshot(aws_account_dto, region, volume_id, description, tags)
+            .await
+    }
+
+    pub async fn ebs_list_snapshots(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        volume_id: Option<&str>,
+        owner_ids: Vec<String>,
+    ) -> Result<Vec<EbsSnapshotInfo>, AppError> {
+        let ebs = EbsControlPlane::new(self.aws_service.clone());
+        ebs.list_snapshots(aws_account_dto, region, volume_id, owner_ids)
+            .await
+    }
+
+    pub async fn ebs_delete_snapshot(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        snapshot_id: &str,
+        audit_ctx: &AuditContext,
+    ) -> Result<(), AppError> {
+        let ebs = EbsControlPlane::new(self.aws_service.clone());
+        ebs.delete_snapshot(aws_account_dto, region, snapshot_id)
+            .await?;
+
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(
+                audit_ctx,
+                AuditOperation::DELETE,
+                "EbsSnapshot",
+                snapshot_id,
+            )
+            .with_payload(json!({
+                "account_id": aws_account_dto.account_id,
+                "region": region,
+            })),
+        )
+        .await;
+        Ok(())
+    }
+
+    pub async fn ebs_copy_snapshot(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        source_region: &str,
+        target_region: &str,
+        snapshot_id: &str,
+    ) -> Result<String, AppError> {
+        let ebs = EbsControlPlane::new(self.aws_service.clone());
+        ebs.copy_snapshot(aws_account_dto, source_region, target_region, snapshot_id)
+            .await
+    }
+
+    pub async fn ebs_enforce_snapshot_retention(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        volume_id: &str,
+        keep_last_n: usize,
+        audit_ctx: &AuditContext,
+    ) -> Result<EbsSnapshotRetentionResult, AppError> {
+        let ebs = EbsControlPlane::new(self.aws_service.clone());
+        let result = ebs
+            .enforce_snapshot_retention(aws_account_dto, region, volume_id, keep_last_n)
+            .await?;
+
+        for snapshot_id in &result.deleted {
+            emit_if_configured(
+                &self.audit_emitter,
+                AuditEvent::new(
+                    audit_ctx,
+                    AuditOperation::DELETE,
+                    "EbsSnapshot",
+                    snapshot_id,
+                )
+                .with_payload(json!({
+                    "account_id": aws_account_dto.account_id,
+                    "region": region,
+                    "volume_id": volume_id,
+                    "keep_last_n": keep_last_n,
+                })),
+            )
+            .await;
+        }
+        Ok(result)
+    }
+
+    // ECS task definition management
+    pub async fn ecs_list_task_definition_families(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+    ) -> Result<Vec<String>, AppError> {
+        let ecs = EcsControlPlane::new(self.aws_service.clone());
+        ecs.list_task_definition_families(aws_account_dto, region)
+            .await
+    }
+
+    pub async fn ecs_list_task_definition_revisions(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        family: &str,
+    ) -> Result<Vec<String>, AppError> {
+        let ecs = EcsControlPlane::new(self.aws_service.clone());
+        ecs.list_task_definition_revisions(aws_account_dto, region, family)
+            .await
+    }
+
+    pub async fn ecs_describe_task_definition(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        task_definition_arn: &str,
+    ) -> Result<EcsTaskDefinitionDetail, AppError> {
+        let ecs = EcsControlPlane::new(self.aws_service.clone());
+        ecs.describe_task_definition(aws_account_dto, region, task_definition_arn)
+            .await
+    }
+
+    pub async fn ecs_deregister_old_task_definitions(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        family: &str,
+        keep_last_n: usize,
+    ) -> Result<DeregisterResult, AppError> {
+        let ecs = EcsControlPlane::new(self.aws_service.clone());
+        ecs.deregister_old_task_definitions(aws_account_dto, region, family, keep_last_n)
+            .await
+    }
+
+    // WAF IP set and rule group management. Changes are written to the audit trail.
+    pub async fn waf_list_ip_sets(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        scope: WafScope,
+    ) -> Result<Vec<WafIpSetSummary>, AppError> {
+        let waf = WafControlPlane::new(self.aws_service.clone());
+        waf.list_ip_sets(aws_account_dto, region, scope).await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn waf_update_ip_set(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        scope: WafScope,
+        ip_set_id: &str,
+        ip_set_name: &str,
+        ip_set_lock_token: &str,
+        addresses: Vec<String>,
+        audit_ctx: &AuditContext,
+    ) -> Result<String, AppError> {
+        let waf = WafControlPlane::new(self.aws_service.clone());
+        // Read before the update for the audit trail. The lock token makes the update
+        // fail if the set changed after this read.
+        let previous = waf
+            .get_ip_set(aws_account_dto, region, scope, ip_set_id, ip_set_name)
+            .await?;
+        let (addresses, _) = ip_set_addresses(&addresses)?;
+        let added: Vec<&String> = addresses
+            .iter()
+            .filter(|a| !previous.addresses.contains(a))
+            .collect();
+        let removed: Vec<&String> = previous
+            .addresses
+            .iter()
+            .filter(|a| !addresses.contains(a))
+            .collect();
+        let audit_payload = json!({
+            "account_id": aws_account_dto.account_id,
+            "region": region,
+            "scope": scope,
+            "name": ip_set_name,
+            "previous_addresses": previous.addresses,
+            "addresses": addresses,
+            "added": added,
+            "removed": removed,
+        });
+        let next_lock_token = waf
+            .update_ip_set(
+                aws_account_dto,
+                region,
+                scope,
+                ip_set_id,
+                ip_set_name,
+                ip_set_lock_token,
+                addresses,
+            )
+            .await?;
+
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::UPDATE, "WafIpSet", ip_set_id)
+                .with_payload(audit_payload),
+        )
+        .await;
+        Ok(next_lock_token)
+    }
+
+    pub async fn waf_list_rule_groups(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        scope: WafScope,
+    ) -> Result<Vec<WafRuleGroupSummary>, AppError> {
+        let waf = WafControlPlane::new(self.aws_service.clone());
+        waf.list_rule_groups(aws_account_dto, region, scope).await
+    }
+
+    pub async fn waf_get_rule_group(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        scope: WafScope,
+        rule_group_id: &str,
+        rule_group_name: &str,
+    ) -> Result<WafRuleGroupDetail, AppError> {
+        let waf = WafControlPlane::new(self.aws_service.clone());
+        waf.get_rule_group(
+            aws_account_dto,
+            region,
+            scope,
+            rule_group_id,
+            rule_group_name,
+        )
+        .await
+    }
+
+    pub async fn waf_create_ip_block_rule_group(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        scope: WafScope,
+        name: &str,
+        ip_addresses: Vec<String>,
+        audit_ctx: &AuditContext,
+    ) -> Result<RuleGroupResult, AppError> {
+        let waf = WafControlPlane::new(self.aws_service.clone());
+        let result = waf
+            .create_ip_block_rule_group(aws_account_dto, region, scope, name, ip_addresses.clone())
+            .await?;
+
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(
+                audit_ctx,
+                AuditOperation::CREATE,
+                "WafRuleGroup",
+                &result.rule_group_id,
+            )
+            .with_payload(json!({
+                "account_id": aws_account_dto.account_id,
+                "region": region,
+                "scope": scope,
+                "name": name,
+                "ip_set_id": result.ip_set_id,
+                "addresses": ip_addresses,
+            })),
+        )
+        .await;
+        Ok(result)
+    }
+
+    pub async fn waf_block_ip(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        scope: WafScope,
+        ip_set_name: &str,
+        ip_address: &str,
+        audit_ctx: &AuditContext,
+    ) -> Result<WafBlockIpResult, AppError> {
+        let waf = WafControlPlane::new(self.aws_service.clone());
+        let result = waf
+            .block_ip(aws_account_dto, region, scope, ip_set_name, ip_address)
+            .await?;
+
+        if !result.already_blocked {
+            emit_if_configured(
+                &self.audit_emitter,
+                AuditEvent::new(
+                    audit_ctx,
+                    AuditOperation::BLOCK_IP,
+                    "WafIpSet",
+                    &result.ip_set_id,
+                )
+                .with_payload(json!({
+                    "account_id": aws_account_dto.account_id,
+                    "region": region,
+                    "scope": scope,
+                    "name": result.ip_set_name,
+                    "address": result.address,
+                })),
+            )
+            .await;
+        }
+        Ok(result)
+    }
+
+    // Pull Inspector findings for one region and store them for later lookups
+    pub async fn sync_inspector_findings(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        filter_severity: Option<Vec<String>>,
+    ) -> Result<Vec<SecurityFinding>, AppError> {
+        let repo = self.security_finding_repo()?;
+        let inspector = InspectorControlPlane::new(self.aws_service.clone());
+        let findings = inspector
+            .list_findings(aws_account_dto, region, filter_severity)
+            .await?;
+        repo.upsert_findings(&aws_account_dto.account_id, region, &findings)
+            .await?;
+        Ok(findings)
+    }
+
+    pub async fn get_findings_for_resource(
+        &self,
+        resource_arn: &str,
+    ) -> Result<Vec<SecurityFinding>, AppError> {
+        self.security_finding_repo()?
+            .find_by_resource_arn(resource_arn)
+            .await
+    }
+
+    pub async fn get_findings_for_account(
+        &self,
+        account_id: &str,
+        severity: Option<&str>,
+    ) -> Result<Vec<SecurityFinding>, AppError> {
+        self.security_finding_repo()?
+            .find_by_account(account_id, severity)
+            .await
+    }
+
+    // Evaluate AWS Config rule compliance for one region and store the violations
+    pub async fn check_config_compliance(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        resource_type: Option<String>,
+    ) -> Result<ComplianceReport, AppError> {
+        let repo = self.config_violation_repo()?;
+        let config = ConfigControlPlane::new(self.aws_service.clone());
+        let report = config
+            .check_compliance(aws_account_dto, region, resource_type)
+            .await?;
+        repo.upsert_violations(&aws_account_dto.account_id, region, &report.violations)
+            .await?;
+        Ok(report)
+    }
+
+    // Sync the record sets of one hosted zone outside of a full account sync
+    pub async fn sync_route53_records(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        hosted_zone_id: &str,
+    ) -> Result<Vec<AwsResourceModel>, AppError> {
+        let sync_id = Uuid::new_v4();
+        let route53 = Route53ControlPlane::new(self.aws_service.clone());
+        let resources = route53
+            .sync_record_sets(aws_account_dto, Some(hosted_zone_id), sync_id)
+            .await?;
+        self.persist_resources(&resources, sync_id).await;
+        Ok(resources)
+    }
+
+    pub async fn create_health_check(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        fqdn: &str,
+        port: i32,
+        protocol: &str,
+        path: Option<String>,
+        failure_threshold: u8,
+    ) -> Result<HealthCheckResult, AppError> {
+        let route53 = Route53ControlPlane::new(self.aws_service.clone());
+        route53
+            .create_health_check(
+                aws_account_dto,
+                fqdn,
+                port,
+                protocol,
+                path,
+                failure_threshold,
+            )
+            .await
+    }
+
+    pub async fn delete_health_check(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        health_check_id: &str,
+        audit_ctx: &AuditContext,
+    ) -> Result<(), AppError> {
+        let route53 = Route53ControlPlane::new(self.aws_service.clone());
+        route53
+            .delete_health_check(aws_account_dto, health_check_id)
+            .await?;
+
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(
+                audit_ctx,
+                AuditOperation::DELETE,
+                "Route53HealthCheck",
+                health_check_id,
+            )
+            .with_payload(json!({ "account_id": aws_account_dto.account_id })),
+        )
+        .await;
+        Ok(())
+    }
+
+    pub async fn get_health_check_status(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        health_check_id: &str,
+    ) -> Result<HealthCheckStatus, AppError> {
+        let route53 = Route53ControlPlane::new(self.aws_service.clone());
+        route53
+            .get_health_check_status(aws_account_dto, health_check_id)
+            .await
+    }
+
     // Sync all resources for an account and region
     pub async fn sync_resources(
         &self,
         request: &ResourceSyncRequest,
     ) -> Result<ResourceSyncResponse, AppError> {
         let region = &request.region;
+        let _lock = self
+            .lock(
+                &format!("aws_sync:{}:{}", request.account_id, region),
+                SYNC_LOCK_TTL,
+            )
+            .await?;
         // Build a rich AwsAccountDto carrying account_id, auth and region from the request
         let aws_account = AwsAccountDto {
             id: Uuid::new_v4(),
@@ -666,6 +1314,7 @@ impl AwsControlPlane {
             sso_profile: None,
             web_identity_token_file: None,
             session_name: None,
+            anomaly_detection_config: None,
             last_synced_at: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
@@ -685,6 +1334,7 @@ impl AwsControlPlane {
             Some(types) => types.clone(),
             None => vec![
                 AwsResourceType::EC2Instance.to_string(),
+                AwsResourceType::AutoScalingGroup.to_string(),
                 AwsResourceType::S3Bucket.to_string(),
                 AwsResourceType::RdsInstance.to_string(),
                 AwsResourceType::DynamoDbTable.to_string(),
@@ -706,6 +1356,7 @@ impl AwsControlPlane {
                 AwsResourceType::Nlb.to_string(),
                 AwsResourceType::Elb.to_string(),
                 AwsResourceType::CloudFrontDistribution.to_string(),
+                AwsResourceType::Route53Record.to_string(),
                 AwsResourceType::ApiGatewayRestApi.to_string(),
                 AwsResourceType::ApiGatewayStage.to_string(),
                 AwsResourceType::ApiGatewayResource.to_string(),
@@ -737,6 +1388,7 @@ impl AwsControlPlane {
                 AwsResourceType::EmrCluster.to_string(),
                 AwsResourceType::AthenaWorkgroup.to_string(),
                 AwsResourceType::GlueDatabase.to_string(),
+                AwsResourceType::GlueTable.to_string(),
                 // Batch 7: Edge & DR
                 AwsResourceType::WafWebAcl.to_string(),
                 AwsResourceType::GlobalAccelerator.to_string(),
@@ -761,6 +1413,11 @@ impl AwsControlPlane {
                     self.sync_ec2_resources(aws_account_dto, request.sync_id)
                         .await
                 }
+                "AutoScalingGroup" => {
+                    let cp = AutoScalingControlPlane::new(self.aws_service.clone());
+                    cp.sync_autoscaling_groups(aws_account_dto, request.sync_id)
+                        .await
+                }
                 "S3Bucket" => self.sync_s3_buckets(aws_account_dto, request.sync_id).await,
                 "RdsInstance" => {
                     self.sync_rds_resources(aws_account_dto, request.sync_id)
@@ -812,6 +1469,11 @@ impl AwsControlPlane {
                     self.sync_cloudfront_resources(aws_account_dto, request.sync_id)
                         .await
                 }
+                "Route53Record" => {
+                    let cp = Route53ControlPlane::new(self.aws_service.clone());
+                    cp.sync_record_sets(aws_account_dto, None, request.sync_id)
+                        .await
+                }
                 "ApiGatewayRestApi" | "ApiGatewayStage" | "ApiGatewayResource" | "ApiGatewayMethod" => {
                     self.sync_api_gateway_resources(aws_account_dto, request.sync_id)
                         .await
@@ -913,6 +1575,15 @@ impl AwsControlPlane {
                     let cp = GlueControlPlane::new(self.aws_service.clone());
                     cp.sync_databases(aws_account_dto, request.sync_id).await
                 }
+                "GlueTable" => {
+                    let dp = GlueDataPlane::new(self.aws_service.clone());
+                    dp.sync_glue_catalogs(
+                        aws_account_dto,
+                        &aws_account_dto.default_region,
+                        request.sync_id,
+                    )
+                    .await
+                }
                 // Batch 7: Edge & DR
                 "WafWebAcl" => {
                     let cp = WafControlPlane::new(self.aws_service.clone());
@@ -951,50 +1622,7 @@ impl AwsControlPlane {
 
             match result {
                 Ok(resources) => {
-                    // Save resources to the database
-                    for resource in &resources {
-                        // Convert to DTO for persistence
-                        let resource_dto = AwsResourceDto {
-                            id: Some(resource.id),
-                            sync_id: Some(request.sync_id),
-                            account_id: resource.account_id.clone(),
-                            profile: resource.profile.clone(),
-                            region: resource.region.clone(),
-                            resource_type: resource.resource_type.clone(),
-                            resource_id: resource.resource_id.clone(),
-                            arn: resource.arn.clone(),
-                            name: resource.name.clone(),
-                            tags: resource.tags.clone(),
-                            resource_data: resource.resource_data.clone(),
-                        };
-
-                        // Always insert a new row per sync; schema prevents dupes per (sync_id, arn)
-                        let _ = self
-                            .aws_service
-                            .aws_resource_repo
-                            .create(&resource_dto)
-                            .await;
-
-                        // Dual-write to cloud_resources (unified table)
-                        let cloud_dto = CloudResourceDto {
-                            id: None,
-                            sync_id: request.sync_id,
-                            provider: "aws".to_string(),
-                            account_id: resource.account_id.clone(),
-                            region: resource.region.clone(),
-                            resource_type: resource.resource_type.clone(),
-                            resource_id: resource.resource_id.clone(),
-                            arn_or_uri: Some(resource.arn.clone()),
-                            name: resource.name.clone(),
-                            tags: resource.tags.clone(),
-                            resource_data: resource.resource_data.clone(),
-                        };
-                        let _ = self
-                            .aws_service
-                            .cloud_resource_repo
-                            .create(&cloud_dto)
-                            .await;
-                    }
+                    self.persist_resources(&resources, request.sync_id).await;
 
                     summary.push(ResourceTypeSyncSummary {
                         resource_type: resource_type.clone(),
@@ -1035,4 +1663,51 @@ impl AwsControlPlane {
             sync_time: Utc::now().to_rfc3339(),
         })
     }
+
+    // Stores synced resources, dual-writing them to the unified cloud_resources table
+    async fn persist_resources(&self, resources: &[AwsResourceModel], sync_id: Uuid) {
+        for resource in resources {
+            // Convert to DTO for persistence
+            let resource_dto = AwsResourceDto {
+                id: Some(resource.id),
+                sync_id: Some(sync_id),
+                account_id: resource.account_id.clone(),
+                profile: resource.profile.clone(),
+                region: resource.region.clone(),
+                resource_type: resource.resource_type.clone(),
+                resource_id: resource.resource_id.clone(),
+                arn: resource.arn.clone(),
+                name: resource.name.clone(),
+                tags: resource.tags.clone(),
+                resource_data: resource.resource_data.clone(),
+            };
+
+            // Always insert a new row per sync; schema prevents dupes per (sync_id, arn)
+            let _ = self
+                .aws_service
+                .aws_resource_repo
+                .create(&resource_dto)
+                .await;
+
+            // Dual-write to cloud_resources (unified table)
+            let cloud_dto = CloudResourceDto {
+                id: None,
+                sync_id,
+                provider: "aws".to_string(),
+                account_id: resource.account_id.clone(),
+                region: resource.region.clone(),
+                resource_type: resource.resource_type.clone(),
+                resource_id: resource.resource_id.clone(),
+                arn_or_uri: Some(resource.arn.clone()),
+                name: resource.name.clone(),
+                tags: resource.tags.clone(),
+                resource_data: resource.resource_data.clone(),
+            };
+            let _ = self
+                .aws_service
+                .cloud_resource_repo
+                .create(&cloud_dto)
+                .await;
+        }
+    }
 }
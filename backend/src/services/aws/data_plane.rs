@@ -17,15 +17,32 @@ use super::client_factory::AwsClientFactory;
 use crate::api::routes::aws_account;
 use crate::errors::AppError;
 use crate::models::aws_account::AwsAccountDto;
+use crate::models::aws_resource::Model as AwsResourceModel;
+use crate::services::aws::aws_data_plane::cloudwatch::CloudWatchService;
+use crate::services::aws::aws_data_plane::cloudwatch_dashboards::{
+    CloudWatchDashboardService, DashboardResult, DashboardSnapshot,
+};
+use crate::services::aws::aws_data_plane::glue_data_plane::GlueDataPlane;
 use crate::services::aws::aws_data_plane::kinesis_data_plane::KinesisDataPlane;
+use crate::services::aws::aws_data_plane::rds_data_plane::RdsDataPlane;
+use crate::services::aws::aws_data_plane::lambda_analytics::{
+    LambdaAnalytics, LambdaPerformanceReport,
+};
+use crate::services::aws::aws_data_plane::vpc_flow_log_analyzer::{
+    VpcFlowLogAnalyzer, VpcTrafficSummary,
+};
 use crate::services::aws::aws_types::cloud_watch::{
     CloudWatchMetricsRequest, CloudWatchMetricsResult,
 };
+use crate::services::aws::aws_types::glue::GlueTableSchema;
+use crate::services::aws::aws_types::rds::RdsPerformanceInsights;
 use crate::services::aws::aws_types::kinesis::{
     KinesisGetRecordsRequest, KinesisGetRecordsResponse, KinesisGetShardIteratorRequest,
     KinesisGetShardIteratorResponse, KinesisPutRecordsRequest, KinesisPutRecordsResponse,
+    KinesisRecord, KinesisSubscribeToShardRequest,
 };
 use crate::services::aws::AwsService;
+use futures::stream::BoxStream;
 use chrono::Utc;
 use std::sync::Arc;
 use uuid::Uuid;
@@ -112,4 +129,127 @@ impl AwsDataPlane {
             .get_shard_iterator(aws_account_dto, request)
             .await
     }
+
+    pub async fn kinesis_subscribe_to_shard(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        request: &KinesisSubscribeToShardRequest,
+    ) -> Result<BoxStream<'static, Result<KinesisRecord, AppError>>, AppError> {
+        let kinesis_data_plane = KinesisDataPlane::new(self.aws_service.clone());
+        kinesis_data_plane
+            .subscribe_to_shard(aws_account_dto, request)
+            .await
+    }
+
+    // VPC flow log analysis over CloudWatch Logs
+    pub async fn analyze_vpc_flow_logs(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        log_group: &str,
+        start_time: chrono::DateTime<Utc>,
+        end_time: chrono::DateTime<Utc>,
+        vpc_id: &str,
+        max_events: usize,
+    ) -> Result<VpcTrafficSummary, AppError> {
+        let cloudwatch_service = Arc::new(CloudWatchService::new(self.aws_service.clone()));
+        let analyzer = VpcFlowLogAnalyzer::new(cloudwatch_service);
+        analyzer
+            .analyze(
+                aws_account_dto,
+                log_group,
+                start_time,
+                end_time,
+                vpc_id,
+                max_events,
+            )
+            .await
+    }
+
+    // Lambda duration, error, throttle and cold start metrics from CloudWatch
+    pub async fn lambda_function_performance(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        function_name: &str,
+        start_time: chrono::DateTime<Utc>,
+        end_time: chrono::DateTime<Utc>,
+    ) -> Result<LambdaPerformanceReport, AppError> {
+        let cloudwatch_service = Arc::new(CloudWatchService::new(self.aws_service.clone()));
+        let analytics = LambdaAnalytics::new(cloudwatch_service);
+        analytics
+            .get_function_performance(aws_account_dto, region, function_name, start_time, end_time)
+            .await
+    }
+
+    // Per-resource CloudWatch dashboards
+    pub async fn create_resource_dashboard(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        resource_id: &str,
+        resource_type: &str,
+        dashboard_name: &str,
+    ) -> Result<DashboardResult, AppError> {
+        let cloudwatch_service = Arc::new(CloudWatchService::new(self.aws_service.clone()));
+        let dashboards = CloudWatchDashboardService::new(cloudwatch_service);
+        dashboards
+            .create_resource_dashboard(
+                aws_account_dto,
+                region,
+                resource_id,
+                resource_type,
+                dashboard_name,
+            )
+            .await
+    }
+
+    pub async fn get_dashboard_snapshot(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        dashboard_name: &str,
+    ) -> Result<DashboardSnapshot, AppError> {
+        let cloudwatch_service = Arc::new(CloudWatchService::new(self.aws_service.clone()));
+        let dashboards = CloudWatchDashboardService::new(cloudwatch_service);
+        dashboards
+            .get_dashboard_snapshot(aws_account_dto, region, dashboard_name)
+            .await
+    }
+
+    pub async fn rds_performance_insights(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        db_instance_id: &str,
+        start_time: chrono::DateTime<Utc>,
+        end_time: chrono::DateTime<Utc>,
+        metrics: Vec<String>,
+    ) -> Result<RdsPerformanceInsights, AppError> {
+        let rds = RdsDataPlane::new(self.aws_service.clone());
+        rds.get_performance_insights(aws_account_dto, db_instance_id, start_time, end_time, metrics)
+            .await
+    }
+
+    // Glue Data Catalog operations
+    pub async fn sync_glue_catalogs(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        sync_id: Uuid,
+    ) -> Result<Vec<AwsResourceModel>, AppError> {
+        let glue = GlueDataPlane::new(self.aws_service.clone());
+        glue.sync_glue_catalogs(aws_account_dto, region, sync_id)
+            .await
+    }
+
+    pub async fn get_glue_table_schema(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        database_name: &str,
+        table_name: &str,
+    ) -> Result<GlueTableSchema, AppError> {
+        let glue = GlueDataPlane::new(self.aws_service.clone());
+        glue.get_table_schema(aws_account_dto, region, database_name, table_name)
+            .await
+    }
 }
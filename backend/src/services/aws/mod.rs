@@ -25,6 +25,7 @@ pub use aws_data_plane::cost_explorer::AwsCostService;
 pub use control_plane::{AwsControlPlane, AwsControlPlaneTrait};
 pub use data_plane::AwsDataPlane;
 pub use service::AwsService;
+pub use aws_config_service::SecretsManagerCredentialProvider;
 mod aws_config_service;
 pub mod aws_control_plane;
 pub mod aws_data_plane;
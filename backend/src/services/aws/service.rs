@@ -689,4 +689,20 @@ impl AwsClientFactory for AwsService {
         let config = self.get_aws_sdk_config(aws_account_dto).await?;
         Ok(aws_sdk_kinesisanalyticsv2::Client::new(&config))
     }
+
+    async fn create_autoscaling_client(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+    ) -> Result<aws_sdk_autoscaling::Client, AppError> {
+        let config = self.get_aws_sdk_config(aws_account_dto).await?;
+        Ok(aws_sdk_autoscaling::Client::new(&config))
+    }
+
+    async fn create_pi_client(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+    ) -> Result<aws_sdk_pi::Client, AppError> {
+        let config = self.get_aws_sdk_config(aws_account_dto).await?;
+        Ok(aws_sdk_pi::Client::new(&config))
+    }
 }
@@ -324,6 +324,7 @@ impl AwsAccountService {
                 sso_profile: account.sso_profile.clone(),
                 web_identity_token_file: account.web_identity_token_file.clone(),
                 session_name: account.session_name.clone(),
+                anomaly_detection_config: account.anomaly_detection_config.clone(),
                 last_synced_at: account.last_synced_at,
                 created_at: account.created_at,
                 updated_at: account.updated_at,
@@ -17,29 +17,37 @@ use aws_config::SdkConfig;
 use aws_sdk_costexplorer::{
     operation::get_cost_and_usage::GetCostAndUsageInput, types::*, Client as CostExplorerClient,
 };
+use aws_sdk_costexplorer::types::RightsizingRecommendation as CeRightsizingRecommendation;
 use chrono::{Datelike, Duration, NaiveDate, Utc};
 use sea_orm::{DatabaseConnection, prelude::Decimal, ActiveValue};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::config::AnomalyDetectionConfig;
 use crate::errors::AppError;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
 use crate::models::{
     aws_cost_anomalies::ActiveModel as CostAnomalyActiveModel,
     aws_cost_data::ActiveModel as CostDataActiveModel,
     aws_cost_insights::ActiveModel as CostInsightActiveModel,
     aws_monthly_cost_aggregates::ActiveModel as MonthlyCostAggregateActiveModel,
+    aws_rightsizing_recommendations::ActiveModel as RightsizingActiveModel,
+    aws_savings_plan_recommendations::ActiveModel as SavingsPlanActiveModel,
 };
 use crate::repositories::aws_account::AwsAccountRepository;
 use crate::repositories::aws_resource::AwsResourceRepository;
 use crate::repositories::cost_analytics::CostAnalyticsRepository;
 use crate::repositories::llm_provider::LlmProviderRepository;
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
 use crate::services::aws::AwsService;
 use crate::services::llm::LlmIntegrationService;
+use crate::services::notifications::cost_anomaly::CostAnomalyNotifier;
 use crate::services::resource_cost_enrichment::ResourceCostEnrichmentService;
-use crate::services::cost_categories::CostCategoriesService;
+use crate::models::aws_cost_category::AwsCostCategory;
+use crate::services::cost_categories::{AwsCostCategoryRule, CostCategoriesService};
 
 #[derive(Debug, Clone)]
 pub struct CostMetrics {
@@ -86,6 +94,11 @@ pub struct CostAnomaly {
     pub detection_methods: Option<Vec<String>>,
 }
 
+const SAVINGS_PLAN_CHECK_INTERVAL: std::time::Duration =
+    std::time::Duration::from_secs(24 * 60 * 60);
+const SAVINGS_PLAN_REFRESH_DAYS: i64 = 30;
+const TAG_COST_SUMMARY_DAYS: i64 = 7;
+
 #[derive(Debug, Clone)]
 pub struct CostAnalysisRequest {
     pub account_id: String,
@@ -95,6 +108,182 @@ pub struct CostAnalysisRequest {
     pub granularity: String, // "DAILY", "MONTHLY"
 }
 
+// Chargeback can group by a raw cost allocation tag or by a synced AWS cost category
+#[derive(Debug, Clone)]
+pub enum ChargebackGroupBy {
+    Tag(String),
+    CostCategory(String),
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChargebackPeriod {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChargebackEntry {
+    pub tag_value: String,
+    pub total_cost: f64,
+    pub service_breakdown: HashMap<String, f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChargebackReport {
+    pub tag_key: String,
+    pub period: ChargebackPeriod,
+    pub entries: Vec<ChargebackEntry>,
+}
+
+impl ChargebackReport {
+    /// One row per tag value and service, followed by a TOTAL row for each tag value.
+    pub fn to_csv(&self) -> String {
+        let mut wtr = csv::Writer::from_writer(vec![]);
+        let _ = wtr.write_record([
+            "Tag Key",
+            "Tag Value",
+            "Service",
+            "Cost",
+            "Period Start",
+            "Period End",
+        ]);
+
+        let start = self.period.start_date.format("%Y-%m-%d").to_string();
+        let end = self.period.end_date.format("%Y-%m-%d").to_string();
+        for entry in &self.entries {
+            let mut services: Vec<(&String, &f64)> = entry.service_breakdown.iter().collect();
+            services.sort_by(|a, b| b.1.partial_cmp(a.1).unwrap_or(std::cmp::Ordering::Equal));
+            for (service, cost) in services {
+                let _ = wtr.write_record([
+                    self.tag_key.as_str(),
+                    entry.tag_value.as_str(),
+                    service.as_str(),
+                    &format!("{:.2}", cost),
+                    &start,
+                    &end,
+                ]);
+            }
+            let _ = wtr.write_record([
+                self.tag_key.as_str(),
+                entry.tag_value.as_str(),
+                "TOTAL",
+                &format!("{:.2}", entry.total_cost),
+                &start,
+                &end,
+            ]);
+        }
+
+        // Writing into an in-memory buffer cannot fail
+        String::from_utf8(wtr.into_inner().unwrap_or_default()).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TagCostEntry {
+    pub tag_value: String,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub unblended_cost: f64,
+    pub blended_cost: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TagCostBreakdown {
+    pub tag_key: String,
+    pub entries: Vec<TagCostEntry>,
+    // Cost of resources without a value for the tag, over the whole range
+    pub untagged_cost: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ModificationType {
+    // Smaller instance within the same family
+    Downsize,
+    // Instance is idle and can be removed
+    Terminate,
+    // Move to a different instance family
+    Scale,
+}
+
+impl ModificationType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModificationType::Downsize => "downsize",
+            ModificationType::Terminate => "terminate",
+            ModificationType::Scale => "scale",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RightsizingRecommendation {
+    pub account_id: String,
+    pub resource_id: String,
+    pub resource_type: String,
+    pub current_instance_type: String,
+    pub recommended_instance_type: Option<String>,
+    pub estimated_monthly_savings: f64,
+    pub modification_type: ModificationType,
+    pub confidence: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentOption {
+    NoUpfront,
+    PartialUpfront,
+    AllUpfront,
+}
+
+impl PaymentOption {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PaymentOption::NoUpfront => "no_upfront",
+            PaymentOption::PartialUpfront => "partial_upfront",
+            PaymentOption::AllUpfront => "all_upfront",
+        }
+    }
+
+    fn to_sdk(self) -> aws_sdk_costexplorer::types::PaymentOption {
+        match self {
+            PaymentOption::NoUpfront => aws_sdk_costexplorer::types::PaymentOption::NoUpfront,
+            PaymentOption::PartialUpfront => {
+                aws_sdk_costexplorer::types::PaymentOption::PartialUpfront
+            }
+            PaymentOption::AllUpfront => aws_sdk_costexplorer::types::PaymentOption::AllUpfront,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavingsPlanItem {
+    pub account_id: Option<String>,
+    pub region: Option<String>,
+    pub instance_family: Option<String>,
+    pub hourly_commitment: f64,
+    pub estimated_monthly_savings: f64,
+    pub estimated_roi: f64,
+    pub estimated_savings_percentage: f64,
+    pub on_demand_cost_equivalent: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavingsPlanRecommendations {
+    pub recommended_commitment_per_hour: f64,
+    pub estimated_monthly_savings: f64,
+    pub estimated_roi: f64,
+    pub on_demand_cost_equivalent: f64,
+    pub recommendations: Vec<SavingsPlanItem>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReservedInstanceCommitments {
+    pub monthly_amortized_cost: f64,
+    pub utilization_percentage: f64,
+    pub net_savings: f64,
+}
+
 #[derive(Debug, Clone)]
 struct AdvancedAnomalyMetrics {
     is_anomaly: bool,
@@ -143,7 +332,11 @@ impl Default for AdvancedAnomalyMetrics {
 }
 
 impl AdvancedAnomalyMetrics {
-    fn from_history(history: &[(NaiveDate, f64)], current_cost: f64) -> Self {
+    fn from_history(
+        history: &[(NaiveDate, f64)],
+        current_cost: f64,
+        config: &AnomalyDetectionConfig,
+    ) -> Self {
         let mut metrics = AdvancedAnomalyMetrics::default();
         metrics.data_points = history.len();
 
@@ -206,15 +399,15 @@ impl AdvancedAnomalyMetrics {
 
         let mut detection_methods = Vec::new();
 
-        if metrics.z_score.abs() >= 2.25 {
+        if metrics.z_score.abs() >= config.z_score_threshold {
             detection_methods.push("z-score".to_string());
         }
 
-        if metrics.percent_change.abs() >= 35.0 {
+        if metrics.percent_change.abs() >= config.percent_change_threshold {
             detection_methods.push("percent-change".to_string());
         }
 
-        if metrics.change_point_score >= 2.0 {
+        if metrics.change_point_score >= config.rolling_breakout_threshold {
             detection_methods.push("rolling-breakout".to_string());
         }
 
@@ -249,7 +442,9 @@ impl AdvancedAnomalyMetrics {
             + (0.2 * change_component)
             + (0.1 * slope_component_norm);
 
-        if current_cost < 20.0 && metrics.baseline_mean < 20.0 {
+        if current_cost < config.min_baseline_cost_for_low_filter
+            && metrics.baseline_mean < config.min_baseline_cost_for_low_filter
+        {
             composite *= 0.6;
         }
 
@@ -268,9 +463,9 @@ impl AdvancedAnomalyMetrics {
             "drop".to_string()
         };
 
-        metrics.severity = if metrics.composite_score >= 8.0 {
+        metrics.severity = if metrics.composite_score >= config.high_severity_composite {
             "high".to_string()
-        } else if metrics.composite_score >= 5.5 {
+        } else if metrics.composite_score >= config.medium_severity_composite {
             "medium".to_string()
         } else if metrics.composite_score >= 4.0 {
             "guarded".to_string()
@@ -402,6 +597,10 @@ pub struct AwsCostAnalyticsService {
     llm_provider_repo: Arc<LlmProviderRepository>,
     resource_cost_enrichment: ResourceCostEnrichmentService,
     cost_categories: CostCategoriesService,
+    anomaly_notifier: Option<Arc<CostAnomalyNotifier>>,
+    anomaly_detection: AnomalyDetectionConfig,
+    cost_allocation_tags: Vec<String>,
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
 }
 
 impl AwsCostAnalyticsService {
@@ -434,9 +633,33 @@ impl AwsCostAnalyticsService {
             llm_provider_repo,
             resource_cost_enrichment,
             cost_categories,
+            anomaly_notifier: None,
+            anomaly_detection: AnomalyDetectionConfig::default(),
+            cost_allocation_tags: Vec::new(),
+            audit_emitter: None,
         }
     }
 
+    pub fn with_anomaly_notifier(mut self, notifier: Arc<CostAnomalyNotifier>) -> Self {
+        self.anomaly_notifier = Some(notifier);
+        self
+    }
+
+    pub fn with_anomaly_detection_config(mut self, config: AnomalyDetectionConfig) -> Self {
+        self.anomaly_detection = config;
+        self
+    }
+
+    pub fn with_cost_allocation_tags(mut self, tags: Vec<String>) -> Self {
+        self.cost_allocation_tags = tags;
+        self
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
+    }
+
     /// Fetch real-time cost data from AWS Cost Explorer API
     pub async fn fetch_cost_data(
         &self,
@@ -641,6 +864,7 @@ impl AwsCostAnalyticsService {
 
                                     tags_json
                                 })),
+                                tag_key: ActiveValue::Set(None),
                                 created_at: ActiveValue::Set(Utc::now().into()),
                                 updated_at: ActiveValue::Set(Utc::now().into()),
                             };
@@ -838,6 +1062,9 @@ impl AwsCostAnalyticsService {
                 };
 
                 let saved_anomaly = self.repository.insert_cost_anomaly(anomaly).await?;
+                if let Some(notifier) = &self.anomaly_notifier {
+                    notifier.notify(&saved_anomaly).await;
+                }
 
                 // Generate LLM insight for the anomaly
                 self.generate_anomaly_insight(&saved_anomaly, &saved_aggregate)
@@ -855,6 +1082,7 @@ impl AwsCostAnalyticsService {
         service_costs: &HashMap<String, f64>,
     ) -> Result<Vec<CostAnomaly>, AppError> {
         let mut anomalies = Vec::new();
+        let detection_config = self.anomaly_detection_config_for(account_id).await?;
 
         // Get historical data for comparison
         let historical_aggregates = self
@@ -882,7 +1110,7 @@ impl AwsCostAnalyticsService {
                 .cloned()
                 .unwrap_or_default();
 
-            let metrics = self.evaluate_advanced_anomaly(&history, current_cost);
+            let metrics = self.evaluate_advanced_anomaly(&history, current_cost, &detection_config);
 
             if !metrics.is_anomaly {
                 continue;
@@ -937,8 +1165,20 @@ impl AwsCostAnalyticsService {
         &self,
         history: &[(NaiveDate, f64)],
         current_cost: f64,
+        config: &AnomalyDetectionConfig,
     ) -> AdvancedAnomalyMetrics {
-        AdvancedAnomalyMetrics::from_history(history, current_cost)
+        AdvancedAnomalyMetrics::from_history(history, current_cost, config)
+    }
+
+    /// Thresholds stored on the AWS account, falling back to the configured defaults
+    async fn anomaly_detection_config_for(
+        &self,
+        account_id: &str,
+    ) -> Result<AnomalyDetectionConfig, AppError> {
+        let account = self.aws_account_repo.get_by_account_id(account_id).await?;
+        Ok(account
+            .and_then(|a| a.anomaly_detection_config)
+            .unwrap_or_else(|| self.anomaly_detection.clone()))
     }
 
     /// Generate LLM-powered insights for cost anomalies
@@ -1361,6 +1601,879 @@ Respond in JSON format with the following structure:
         }))
     }
 
+    /// Build a chargeback report from Cost Explorer, grouped by a cost allocation tag.
+    /// Resources without the tag are reported under "untagged".
+    pub async fn generate_chargeback_report(
+        &self,
+        account_id: &str,
+        group_by: &ChargebackGroupBy,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<ChargebackReport, AppError> {
+        let (group_type, group_key, unassigned_label) = match group_by {
+            ChargebackGroupBy::Tag(tag_key) => (GroupDefinitionType::Tag, tag_key, "untagged"),
+            ChargebackGroupBy::CostCategory(name) => {
+                (GroupDefinitionType::CostCategory, name, "uncategorized")
+            }
+        };
+        if group_key.trim().is_empty() {
+            return Err(AppError::BadRequest(
+                "Chargeback grouping key must not be empty".to_string(),
+            ));
+        }
+        if start_date >= end_date {
+            return Err(AppError::BadRequest(
+                "start date must be before end date".to_string(),
+            ));
+        }
+        if let ChargebackGroupBy::CostCategory(name) = group_by {
+            if self
+                .cost_categories
+                .find_synced_category(account_id, name)
+                .await?
+                .is_none()
+            {
+                return Err(AppError::NotFound(format!(
+                    "Cost category {} has not been synced for account {}",
+                    name, account_id
+                )));
+            }
+        }
+
+        let aws_account = self
+            .aws_account_repo
+            .get_by_account_id(account_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("AWS account {} not found", account_id)))?;
+        let aws_account_dto = crate::models::aws_account::AwsAccountDto::from(aws_account);
+        let aws_config = self
+            .aws_service
+            .get_aws_sdk_config(&aws_account_dto)
+            .await?;
+        let cost_explorer_client = CostExplorerClient::new(&aws_config);
+
+        let time_period = DateInterval::builder()
+            .start(start_date.format("%Y-%m-%d").to_string())
+            .end(end_date.format("%Y-%m-%d").to_string())
+            .build()
+            .map_err(|e| AppError::CloudProvider(format!("Failed to build time period: {}", e)))?;
+
+        let mut totals: HashMap<String, ChargebackEntry> = HashMap::new();
+        let mut next_page_token: Option<String> = None;
+
+        loop {
+            let response = cost_explorer_client
+                .get_cost_and_usage()
+                .time_period(time_period.clone())
+                .granularity(Granularity::Monthly)
+                .metrics("UnblendedCost")
+                .group_by(
+                    GroupDefinition::builder()
+                        .r#type(group_type.clone())
+                        .key(group_key.as_str())
+                        .build(),
+                )
+                .group_by(
+                    GroupDefinition::builder()
+                        .r#type(GroupDefinitionType::Dimension)
+                        .key("SERVICE")
+                        .build(),
+                )
+                .set_next_page_token(next_page_token.clone())
+                .send()
+                .await
+                .map_err(|e| AppError::CloudProvider(format!("Cost Explorer API error: {}", e)))?;
+
+            for time_result in response.results_by_time() {
+                for group in time_result.groups() {
+                    let keys = group.keys();
+                    // Keys come back as "<key>$<value>", with an empty value when unassigned
+                    let tag_value = keys
+                        .first()
+                        .map(|k| k.split_once('$').map(|(_, v)| v).unwrap_or(k.as_str()))
+                        .filter(|v| !v.is_empty())
+                        .unwrap_or(unassigned_label)
+                        .to_string();
+                    let service_name = keys.get(1).cloned().unwrap_or_default();
+                    let cost = group
+                        .metrics()
+                        .and_then(|m| m.get("UnblendedCost"))
+                        .and_then(|m| m.amount.as_ref())
+                        .and_then(|a| a.parse::<f64>().ok())
+                        .unwrap_or(0.0);
+
+                    let entry = totals
+                        .entry(tag_value.clone())
+                        .or_insert_with(|| ChargebackEntry {
+                            tag_value,
+                            total_cost: 0.0,
+                            service_breakdown: HashMap::new(),
+                        });
+                    entry.total_cost += cost;
+                    *entry.service_breakdown.entry(service_name).or_insert(0.0) += cost;
+                }
+            }
+
+            next_page_token = response.next_page_token().map(String::from);
+            if next_page_token.is_none() {
+                break;
+            }
+        }
+
+        let mut entries: Vec<ChargebackEntry> = totals.into_values().collect();
+        entries.sort_by(|a, b| {
+            b.total_cost
+                .partial_cmp(&a.total_cost)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        Ok(ChargebackReport {
+            tag_key: group_key.to_string(),
+            period: ChargebackPeriod {
+                start_date,
+                end_date,
+            },
+            entries,
+        })
+    }
+
+    /// Cost per value of a cost allocation tag and period. The rows are stored in
+    /// `aws_cost_data` under the tag key, replacing any earlier fetch of the same range.
+    pub async fn get_cost_by_tag(
+        &self,
+        account_id: &str,
+        tag_key: &str,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        granularity: &str,
+    ) -> Result<TagCostBreakdown, AppError> {
+        if tag_key.trim().is_empty() {
+            return Err(AppError::BadRequest(
+                "Tag key must not be empty".to_string(),
+            ));
+        }
+        if start_date >= end_date {
+            return Err(AppError::BadRequest(
+                "start date must be before end date".to_string(),
+            ));
+        }
+        let granularity = match granularity.to_ascii_uppercase().as_str() {
+            "DAILY" => Granularity::Daily,
+            "MONTHLY" => Granularity::Monthly,
+            other => {
+                return Err(AppError::BadRequest(format!(
+                    "Unsupported granularity {}, expected DAILY or MONTHLY",
+                    other
+                )))
+            }
+        };
+
+        let cost_explorer_client = self.cost_explorer_client(account_id, None).await?;
+        let time_period = DateInterval::builder()
+            .start(start_date.format("%Y-%m-%d").to_string())
+            .end(end_date.format("%Y-%m-%d").to_string())
+            .build()
+            .map_err(|e| AppError::CloudProvider(format!("Failed to build time period: {}", e)))?;
+
+        let mut entries: HashMap<(String, NaiveDate), TagCostEntry> = HashMap::new();
+        let mut untagged_cost = 0.0;
+        let mut rows = Vec::new();
+        let mut next_page_token: Option<String> = None;
+
+        loop {
+            let response = cost_explorer_client
+                .get_cost_and_usage()
+                .time_period(time_period.clone())
+                .granularity(granularity.clone())
+                .metrics("UnblendedCost")
+                .metrics("BlendedCost")
+                .group_by(
+                    GroupDefinition::builder()
+                        .r#type(GroupDefinitionType::Tag)
+                        .key(tag_key)
+                        .build(),
+                )
+                .group_by(
+                    GroupDefinition::builder()
+                        .r#type(GroupDefinitionType::Dimension)
+                        .key("SERVICE")
+                        .build(),
+                )
+                .set_next_page_token(next_page_token.clone())
+                .send()
+                .await
+                .map_err(|e| AppError::CloudProvider(format!("Cost Explorer API error: {}", e)))?;
+
+            for time_result in response.results_by_time() {
+                let Some(period) = time_result.time_period() else {
+                    continue;
+                };
+                let period_start = NaiveDate::parse_from_str(&period.start, "%Y-%m-%d")
+                    .map_err(|e| AppError::Validation(format!("Invalid date format: {}", e)))?;
+                let period_end = NaiveDate::parse_from_str(&period.end, "%Y-%m-%d")
+                    .map_err(|e| AppError::Validation(format!("Invalid date format: {}", e)))?;
+
+                for group in time_result.groups() {
+                    let keys = group.keys();
+                    // Keys come back as "<key>$<value>", with an empty value when untagged
+                    let tag_value = keys
+                        .first()
+                        .map(|k| k.split_once('$').map(|(_, v)| v).unwrap_or(k.as_str()))
+                        .unwrap_or_default()
+                        .to_string();
+                    let service_name = keys.get(1).cloned().unwrap_or_default();
+                    let metric = |name: &str| {
+                        group
+                            .metrics()
+                            .and_then(|m| m.get(name))
+                            .and_then(|m| m.amount.as_ref())
+                            .and_then(|a| a.parse::<f64>().ok())
+                            .unwrap_or(0.0)
+                    };
+                    let unblended_cost = metric("UnblendedCost");
+                    let blended_cost = metric("BlendedCost");
+
+                    rows.push(CostDataActiveModel {
+                        id: ActiveValue::Set(Uuid::new_v4()),
+                        account_id: ActiveValue::Set(account_id.to_string()),
+                        service_name: ActiveValue::Set(service_name),
+                        usage_type: ActiveValue::Set(None),
+                        operation: ActiveValue::Set(None),
+                        region: ActiveValue::Set(None),
+                        usage_start: ActiveValue::Set(period_start),
+                        usage_end: ActiveValue::Set(period_end),
+                        unblended_cost: ActiveValue::Set(
+                            Decimal::from_f64_retain(unblended_cost).unwrap_or_default(),
+                        ),
+                        blended_cost: ActiveValue::Set(
+                            Decimal::from_f64_retain(blended_cost).unwrap_or_default(),
+                        ),
+                        usage_amount: ActiveValue::Set(None),
+                        usage_unit: ActiveValue::Set(None),
+                        currency: ActiveValue::Set("USD".to_string()),
+                        tags: ActiveValue::Set(Some(serde_json::json!({ tag_key: tag_value }))),
+                        tag_key: ActiveValue::Set(Some(tag_key.to_string())),
+                        created_at: ActiveValue::Set(Utc::now().into()),
+                        updated_at: ActiveValue::Set(Utc::now().into()),
+                    });
+
+                    if tag_value.is_empty() {
+                        untagged_cost += unblended_cost;
+                        continue;
+                    }
+                    let entry = entries
+                        .entry((tag_value.clone(), period_start))
+                        .or_insert_with(|| TagCostEntry {
+                            tag_value,
+                            period_start,
+                            period_end,
+                            unblended_cost: 0.0,
+                            blended_cost: 0.0,
+                        });
+                    entry.unblended_cost += unblended_cost;
+                    entry.blended_cost += blended_cost;
+                }
+            }
+
+            next_page_token = response.next_page_token().map(String::from);
+            if next_page_token.is_none() {
+                break;
+            }
+        }
+
+        self.repository
+            .replace_tag_cost_data(account_id, tag_key, start_date, end_date, rows)
+            .await?;
+
+        let mut entries: Vec<TagCostEntry> = entries.into_values().collect();
+        entries.sort_by(|a, b| {
+            a.period_start.cmp(&b.period_start).then(
+                b.unblended_cost
+                    .partial_cmp(&a.unblended_cost)
+                    .unwrap_or(std::cmp::Ordering::Equal),
+            )
+        });
+
+        Ok(TagCostBreakdown {
+            tag_key: tag_key.to_string(),
+            entries,
+            untagged_cost,
+        })
+    }
+
+    /// Cost of resources missing a value for any of the configured cost allocation tags
+    pub async fn get_untagged_cost(
+        &self,
+        account_id: &str,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<f64, AppError> {
+        if self.cost_allocation_tags.is_empty() {
+            return Err(AppError::BadRequest(
+                "No cost allocation tags are configured".to_string(),
+            ));
+        }
+        if start_date >= end_date {
+            return Err(AppError::BadRequest(
+                "start date must be before end date".to_string(),
+            ));
+        }
+
+        let mut missing_tag: Vec<Expression> = self
+            .cost_allocation_tags
+            .iter()
+            .map(|key| {
+                Expression::builder()
+                    .tags(
+                        TagValues::builder()
+                            .key(key)
+                            .match_options(MatchOption::Absent)
+                            .build(),
+                    )
+                    .build()
+            })
+            .collect();
+        // Cost Explorer rejects an Or with a single operand
+        let filter = if missing_tag.len() == 1 {
+            missing_tag.remove(0)
+        } else {
+            Expression::builder().set_or(Some(missing_tag)).build()
+        };
+
+        let cost_explorer_client = self.cost_explorer_client(account_id, None).await?;
+        let time_period = DateInterval::builder()
+            .start(start_date.format("%Y-%m-%d").to_string())
+            .end(end_date.format("%Y-%m-%d").to_string())
+            .build()
+            .map_err(|e| AppError::CloudProvider(format!("Failed to build time period: {}", e)))?;
+
+        let mut untagged_cost = 0.0;
+        let mut next_page_token: Option<String> = None;
+        loop {
+            let response = cost_explorer_client
+                .get_cost_and_usage()
+                .time_period(time_period.clone())
+                .granularity(Granularity::Monthly)
+                .metrics("UnblendedCost")
+                .filter(filter.clone())
+                .set_next_page_token(next_page_token.clone())
+                .send()
+                .await
+                .map_err(|e| AppError::CloudProvider(format!("Cost Explorer API error: {}", e)))?;
+
+            untagged_cost += response
+                .results_by_time()
+                .iter()
+                .filter_map(|r| r.total()?.get("UnblendedCost")?.amount.as_deref())
+                .filter_map(|a| a.parse::<f64>().ok())
+                .sum::<f64>();
+
+            next_page_token = response.next_page_token().map(String::from);
+            if next_page_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(untagged_cost)
+    }
+
+    /// Store a weekly breakdown for each configured cost allocation tag. Like the savings
+    /// plan refresh, the check runs daily against the stored rows so restarts don't repeat it.
+    pub fn start_tag_cost_summaries(self: &Arc<Self>) {
+        if self.cost_allocation_tags.is_empty() {
+            return;
+        }
+        let service = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(SAVINGS_PLAN_CHECK_INTERVAL);
+            loop {
+                interval.tick().await;
+                let accounts = match service.aws_account_repo.get_all().await {
+                    Ok(accounts) => accounts,
+                    Err(e) => {
+                        tracing::error!(
+                            "Failed to list AWS accounts for tag cost summaries: {}",
+                            e
+                        );
+                        continue;
+                    }
+                };
+                let end_date = Utc::now().date_naive();
+                let start_date = end_date - Duration::days(TAG_COST_SUMMARY_DAYS);
+                for account in &accounts {
+                    for tag_key in &service.cost_allocation_tags {
+                        let latest = service
+                            .repository
+                            .get_latest_tag_cost_data(&account.account_id, tag_key)
+                            .await;
+                        let due = match latest {
+                            Ok(Some(latest)) => {
+                                Utc::now() - latest.created_at.with_timezone(&Utc)
+                                    >= Duration::days(TAG_COST_SUMMARY_DAYS)
+                            }
+                            Ok(None) => true,
+                            Err(e) => {
+                                tracing::error!(
+                                    "Failed to load tag cost history for account {}: {}",
+                                    account.account_id,
+                                    e
+                                );
+                                false
+                            }
+                        };
+                        if !due {
+                            continue;
+                        }
+                        match service
+                            .get_cost_by_tag(
+                                &account.account_id,
+                                tag_key,
+                                start_date,
+                                end_date,
+                                "DAILY",
+                            )
+                            .await
+                        {
+                            Ok(breakdown) => tracing::info!(
+                                "Weekly cost for tag {} in account {}: {:.2} tagged, {:.2} untagged",
+                                tag_key,
+                                account.account_id,
+                                breakdown
+                                    .entries
+                                    .iter()
+                                    .map(|e| e.unblended_cost)
+                                    .sum::<f64>(),
+                                breakdown.untagged_cost
+                            ),
+                            Err(e) => tracing::warn!(
+                                "Tag cost summary failed for tag {} in account {}: {}",
+                                tag_key,
+                                account.account_id,
+                                e
+                            ),
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    /// Fetch EC2 rightsizing recommendations from Cost Explorer and store them.
+    pub async fn get_rightsizing_recommendations(
+        &self,
+        account_id: &str,
+        region: Option<&str>,
+    ) -> Result<Vec<RightsizingRecommendation>, AppError> {
+        let cost_explorer_client = self.cost_explorer_client(account_id, region).await?;
+
+        // Cross-family targets let Cost Explorer suggest moves to other instance families too
+        let configuration = RightsizingRecommendationConfiguration::builder()
+            .recommendation_target(RecommendationTarget::CrossInstanceFamily)
+            .benefits_considered(true)
+            .build()
+            .map_err(|e| {
+                AppError::CloudProvider(format!("Failed to build rightsizing configuration: {}", e))
+            })?;
+
+        let mut recommendations = Vec::new();
+        let mut next_page_token: Option<String> = None;
+        loop {
+            let response = cost_explorer_client
+                .get_rightsizing_recommendation()
+                .service("AmazonEC2")
+                .configuration(configuration.clone())
+                .set_next_page_token(next_page_token.clone())
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::CloudProvider(format!(
+                        "Failed to get rightsizing recommendations: {}",
+                        e
+                    ))
+                })?;
+
+            recommendations.extend(
+                response
+                    .rightsizing_recommendations()
+                    .iter()
+                    .filter_map(|rec| parse_rightsizing_recommendation(rec, account_id)),
+            );
+
+            next_page_token = response.next_page_token().map(|t| t.to_string());
+            if next_page_token.is_none() {
+                break;
+            }
+        }
+
+        // A payer account also receives recommendations for its linked accounts
+        let mut account_ids: Vec<String> =
+            recommendations.iter().map(|r| r.account_id.clone()).collect();
+        account_ids.push(account_id.to_string());
+        account_ids.sort();
+        account_ids.dedup();
+
+        let now = Utc::now();
+        let rows = recommendations
+            .iter()
+            .map(|r| RightsizingActiveModel {
+                id: ActiveValue::Set(Uuid::new_v4()),
+                account_id: ActiveValue::Set(r.account_id.clone()),
+                resource_id: ActiveValue::Set(r.resource_id.clone()),
+                resource_type: ActiveValue::Set(r.resource_type.clone()),
+                current_instance_type: ActiveValue::Set(r.current_instance_type.clone()),
+                recommended_instance_type: ActiveValue::Set(r.recommended_instance_type.clone()),
+                estimated_monthly_savings: ActiveValue::Set(
+                    Decimal::from_f64_retain(r.estimated_monthly_savings).unwrap_or_default(),
+                ),
+                modification_type: ActiveValue::Set(r.modification_type.as_str().to_string()),
+                confidence: ActiveValue::Set(r.confidence.clone()),
+                created_at: ActiveValue::Set(now.into()),
+                updated_at: ActiveValue::Set(now.into()),
+            })
+            .collect();
+        self.repository
+            .replace_rightsizing_recommendations(account_ids, rows)
+            .await?;
+
+        tracing::info!(
+            "Stored {} rightsizing recommendations for account {}",
+            recommendations.len(),
+            account_id
+        );
+        Ok(recommendations)
+    }
+
+    /// Ask Cost Explorer for a Compute Savings Plan commitment covering recent usage and store it.
+    pub async fn get_savings_plan_recommendations(
+        &self,
+        account_id: &str,
+        region: Option<&str>,
+        lookback_period_days: u32,
+        payment_option: PaymentOption,
+    ) -> Result<SavingsPlanRecommendations, AppError> {
+        let cost_explorer_client = self.cost_explorer_client(account_id, region).await?;
+
+        // Cost Explorer only supports three lookback windows; use the closest one that covers the request
+        let (lookback, lookback_days) = match lookback_period_days {
+            0..=7 => (LookbackPeriodInDays::SevenDays, 7),
+            8..=30 => (LookbackPeriodInDays::ThirtyDays, 30),
+            _ => (LookbackPeriodInDays::SixtyDays, 60),
+        };
+
+        let response = cost_explorer_client
+            .get_savings_plans_purchase_recommendation()
+            .savings_plans_type(SupportedSavingsPlansType::ComputeSp)
+            .term_in_years(TermInYears::OneYear)
+            .payment_option(payment_option.to_sdk())
+            .lookback_period_in_days(lookback)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::CloudProvider(format!(
+                    "Failed to get savings plan recommendations: {}",
+                    e
+                ))
+            })?;
+
+        let recommendation = response.savings_plans_purchase_recommendation();
+        let items: Vec<SavingsPlanItem> = recommendation
+            .map(|r| r.savings_plans_purchase_recommendation_details())
+            .unwrap_or_default()
+            .iter()
+            .map(|detail| {
+                let plan = detail.savings_plans_details();
+                SavingsPlanItem {
+                    account_id: detail.account_id().map(|s| s.to_string()),
+                    region: plan.and_then(|p| p.region()).map(|s| s.to_string()),
+                    instance_family: plan
+                        .and_then(|p| p.instance_family())
+                        .map(|s| s.to_string()),
+                    hourly_commitment: parse_amount(detail.hourly_commitment_to_purchase()),
+                    estimated_monthly_savings: parse_amount(
+                        detail.estimated_monthly_savings_amount(),
+                    ),
+                    estimated_roi: parse_amount(detail.estimated_roi()),
+                    estimated_savings_percentage: parse_amount(
+                        detail.estimated_savings_percentage(),
+                    ),
+                    on_demand_cost_equivalent: parse_amount(detail.estimated_on_demand_cost()),
+                }
+            })
+            .collect();
+
+        let summary =
+            recommendation.and_then(|r| r.savings_plans_purchase_recommendation_summary());
+        let recommendations = SavingsPlanRecommendations {
+            recommended_commitment_per_hour: parse_amount(
+                summary.and_then(|s| s.hourly_commitment_to_purchase()),
+            ),
+            estimated_monthly_savings: parse_amount(
+                summary.and_then(|s| s.estimated_monthly_savings_amount()),
+            ),
+            estimated_roi: parse_amount(summary.and_then(|s| s.estimated_roi())),
+            on_demand_cost_equivalent: items.iter().map(|i| i.on_demand_cost_equivalent).sum(),
+            recommendations: items,
+        };
+
+        let to_decimal = |v: f64| Decimal::from_f64_retain(v).unwrap_or_default();
+        let row = SavingsPlanActiveModel {
+            id: ActiveValue::Set(Uuid::new_v4()),
+            account_id: ActiveValue::Set(account_id.to_string()),
+            payment_option: ActiveValue::Set(payment_option.as_str().to_string()),
+            lookback_period_days: ActiveValue::Set(lookback_days),
+            hourly_commitment: ActiveValue::Set(to_decimal(
+                recommendations.recommended_commitment_per_hour,
+            )),
+            estimated_monthly_savings: ActiveValue::Set(to_decimal(
+                recommendations.estimated_monthly_savings,
+            )),
+            estimated_roi: ActiveValue::Set(to_decimal(recommendations.estimated_roi)),
+            on_demand_cost_equivalent: ActiveValue::Set(to_decimal(
+                recommendations.on_demand_cost_equivalent,
+            )),
+            recommendations: ActiveValue::Set(serde_json::to_value(
+                &recommendations.recommendations,
+            )?),
+            created_at: ActiveValue::Set(Utc::now().into()),
+        };
+        self.repository
+            .insert_savings_plan_recommendation(row)
+            .await?;
+
+        tracing::info!(
+            "Stored savings plan recommendation for account {}: ${:.2}/hour commitment",
+            account_id,
+            recommendations.recommended_commitment_per_hour
+        );
+        Ok(recommendations)
+    }
+
+    /// Reserved Instance spend and utilization over the last 30 days, for comparison
+    /// against a recommended Savings Plan commitment.
+    pub async fn get_reserved_instance_commitments(
+        &self,
+        account_id: &str,
+        region: Option<&str>,
+    ) -> Result<ReservedInstanceCommitments, AppError> {
+        let cost_explorer_client = self.cost_explorer_client(account_id, region).await?;
+
+        let end_date = Utc::now().date_naive();
+        let start_date = end_date - Duration::days(30);
+        let time_period = DateInterval::builder()
+            .start(start_date.format("%Y-%m-%d").to_string())
+            .end(end_date.format("%Y-%m-%d").to_string())
+            .build()
+            .map_err(|e| AppError::CloudProvider(format!("Failed to build time period: {}", e)))?;
+
+        let response = cost_explorer_client
+            .get_reservation_utilization()
+            .time_period(time_period)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::CloudProvider(format!("Failed to get reservation utilization: {}", e))
+            })?;
+
+        let total = response.total();
+        Ok(ReservedInstanceCommitments {
+            monthly_amortized_cost: parse_amount(total.and_then(|t| t.total_amortized_fee())),
+            utilization_percentage: parse_amount(total.and_then(|t| t.utilization_percentage())),
+            net_savings: parse_amount(total.and_then(|t| t.net_ri_savings())),
+        })
+    }
+
+    /// Refresh savings plan recommendations for every account once a month. The check runs
+    /// daily against the stored history so restarts don't delay or repeat the refresh.
+    pub fn start_savings_plan_refresh(self: &Arc<Self>) {
+        let service = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(SAVINGS_PLAN_CHECK_INTERVAL);
+            loop {
+                interval.tick().await;
+                let accounts = match service.aws_account_repo.get_all().await {
+                    Ok(accounts) => accounts,
+                    Err(e) => {
+                        tracing::error!(
+                            "Failed to list AWS accounts for savings plan refresh: {}",
+                            e
+                        );
+                        continue;
+                    }
+                };
+                for account in accounts {
+                    let latest = service
+                        .repository
+                        .get_latest_savings_plan_recommendation(
+                            &account.account_id,
+                            Some(PaymentOption::NoUpfront.as_str()),
+                        )
+                        .await;
+                    let due = match latest {
+                        Ok(Some(latest)) => {
+                            Utc::now() - latest.created_at.with_timezone(&Utc)
+                                >= Duration::days(SAVINGS_PLAN_REFRESH_DAYS)
+                        }
+                        Ok(None) => true,
+                        Err(e) => {
+                            tracing::error!(
+                                "Failed to load savings plan history for account {}: {}",
+                                account.account_id,
+                                e
+                            );
+                            false
+                        }
+                    };
+                    if !due {
+                        continue;
+                    }
+                    if let Err(e) = service
+                        .get_savings_plan_recommendations(
+                            &account.account_id,
+                            None,
+                            30,
+                            PaymentOption::NoUpfront,
+                        )
+                        .await
+                    {
+                        tracing::warn!(
+                            "Savings plan refresh failed for account {}: {}",
+                            account.account_id,
+                            e
+                        );
+                    }
+                }
+            }
+        });
+    }
+
+    /// List the live AWS cost categories for an account, refreshing the local copy
+    pub async fn list_aws_cost_categories(
+        &self,
+        account_id: &str,
+        region: Option<&str>,
+    ) -> Result<Vec<AwsCostCategory>, AppError> {
+        let cost_explorer_client = self.cost_explorer_client(account_id, region).await?;
+        self.cost_categories
+            .list_categories(&cost_explorer_client, account_id)
+            .await
+    }
+
+    pub async fn create_aws_cost_category(
+        &self,
+        account_id: &str,
+        region: Option<&str>,
+        name: &str,
+        rules: Vec<AwsCostCategoryRule>,
+        audit_ctx: &AuditContext,
+    ) -> Result<AwsCostCategory, AppError> {
+        let cost_explorer_client = self.cost_explorer_client(account_id, region).await?;
+        let category = self
+            .cost_categories
+            .create_category(&cost_explorer_client, account_id, name, rules)
+            .await?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(
+                audit_ctx,
+                AuditOperation::CREATE,
+                "CostCategory",
+                &category.category_arn,
+            )
+            .with_payload(serde_json::json!({
+                "account_id": account_id,
+                "name": category.name,
+                "rules": category.number_of_rules,
+            })),
+        )
+        .await;
+        Ok(category)
+    }
+
+    pub async fn update_aws_cost_category(
+        &self,
+        account_id: &str,
+        region: Option<&str>,
+        category_arn: &str,
+        rules: Vec<AwsCostCategoryRule>,
+        default_value: Option<Option<String>>,
+        audit_ctx: &AuditContext,
+    ) -> Result<AwsCostCategory, AppError> {
+        let cost_explorer_client = self.cost_explorer_client(account_id, region).await?;
+        let category = self
+            .cost_categories
+            .update_category(
+                &cost_explorer_client,
+                account_id,
+                category_arn,
+                rules,
+                default_value,
+            )
+            .await?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(
+                audit_ctx,
+                AuditOperation::UPDATE,
+                "CostCategory",
+                category_arn,
+            )
+            .with_payload(serde_json::json!({
+                "account_id": account_id,
+                "name": category.name,
+                "rules": category.number_of_rules,
+                "default_value": category.default_value,
+            })),
+        )
+        .await;
+        Ok(category)
+    }
+
+    pub async fn delete_aws_cost_category(
+        &self,
+        account_id: &str,
+        region: Option<&str>,
+        category_arn: &str,
+        audit_ctx: &AuditContext,
+    ) -> Result<(), AppError> {
+        let cost_explorer_client = self.cost_explorer_client(account_id, region).await?;
+        self.cost_categories
+            .delete_category(&cost_explorer_client, category_arn)
+            .await?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(
+                audit_ctx,
+                AuditOperation::DELETE,
+                "CostCategory",
+                category_arn,
+            )
+            .with_payload(serde_json::json!({ "account_id": account_id })),
+        )
+        .await;
+        Ok(())
+    }
+
+    async fn cost_explorer_client(
+        &self,
+        account_id: &str,
+        region: Option<&str>,
+    ) -> Result<CostExplorerClient, AppError> {
+        let aws_account = self
+            .aws_account_repo
+            .get_by_account_id(account_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("AWS account {} not found", account_id)))?;
+        let mut aws_account_dto = crate::models::aws_account::AwsAccountDto::from(aws_account);
+        if let Some(region) = region {
+            aws_account_dto.default_region = region.to_string();
+        }
+        let aws_config = self
+            .aws_service
+            .get_aws_sdk_config(&aws_account_dto)
+            .await?;
+        Ok(CostExplorerClient::new(&aws_config))
+    }
+
     /// Get enriched cost data with resource metadata
     pub async fn get_enriched_cost_data(
         &self,
@@ -1427,3 +2540,93 @@ Respond in JSON format with the following structure:
             .await
     }
 }
+
+// Cost Explorer reports amounts and percentages as strings
+fn parse_amount(value: Option<&str>) -> f64 {
+    value.and_then(|v| v.parse().ok()).unwrap_or(0.0)
+}
+
+fn parse_rightsizing_recommendation(
+    rec: &CeRightsizingRecommendation,
+    default_account_id: &str,
+) -> Option<RightsizingRecommendation> {
+    let current = rec.current_instance()?;
+    let resource_id = current.resource_id()?.to_string();
+    let current_instance_type = current
+        .resource_details()
+        .and_then(|d| d.ec2_resource_details())
+        .and_then(|d| d.instance_type())
+        .unwrap_or_default()
+        .to_string();
+    let account_id = rec.account_id().unwrap_or(default_account_id).to_string();
+
+    let (modification_type, recommended_instance_type, savings, max_cpu) =
+        match rec.rightsizing_type()? {
+            RightsizingType::Terminate => {
+                let savings = rec
+                    .terminate_recommendation_detail()
+                    .and_then(|d| d.estimated_monthly_savings());
+                (ModificationType::Terminate, None, savings, None)
+            }
+            RightsizingType::Modify => {
+                let targets = rec.modify_recommendation_detail()?.target_instances();
+                let target = targets
+                    .iter()
+                    .find(|t| t.default_target_instance())
+                    .or_else(|| targets.first())?;
+                let target_type = target
+                    .resource_details()
+                    .and_then(|d| d.ec2_resource_details())
+                    .and_then(|d| d.instance_type())
+                    .map(|t| t.to_string());
+                let same_family = target_type
+                    .as_deref()
+                    .map(|t| instance_family(t) == instance_family(&current_instance_type))
+                    .unwrap_or(false);
+                let max_cpu = target
+                    .expected_resource_utilization()
+                    .and_then(|u| u.ec2_resource_utilization())
+                    .and_then(|u| u.max_cpu_utilization_percentage())
+                    .and_then(|v| v.parse::<f64>().ok());
+                let modification_type = if same_family {
+                    ModificationType::Downsize
+                } else {
+                    ModificationType::Scale
+                };
+                (modification_type, target_type, target.estimated_monthly_savings(), max_cpu)
+            }
+            _ => return None,
+        };
+
+    Some(RightsizingRecommendation {
+        account_id,
+        resource_id,
+        resource_type: "EC2Instance".to_string(),
+        current_instance_type,
+        recommended_instance_type,
+        estimated_monthly_savings: savings.and_then(|s| s.parse().ok()).unwrap_or(0.0),
+        modification_type,
+        confidence: rightsizing_confidence(modification_type, max_cpu).to_string(),
+    })
+}
+
+// "m5.xlarge" -> "m5"
+fn instance_family(instance_type: &str) -> &str {
+    instance_type.split('.').next().unwrap_or(instance_type)
+}
+
+// Cost Explorer gives no confidence score, so derive one from the expected CPU headroom
+// on the recommended instance. Terminate recommendations are only issued for idle instances.
+fn rightsizing_confidence(
+    modification_type: ModificationType,
+    max_cpu: Option<f64>,
+) -> &'static str {
+    if modification_type == ModificationType::Terminate {
+        return "high";
+    }
+    match max_cpu {
+        Some(cpu) if cpu < 50.0 => "high",
+        Some(cpu) if cpu < 80.0 => "medium",
+        _ => "low",
+    }
+}
@@ -22,11 +22,13 @@ use crate::services::aws::aws_data_plane::kinesis_data_plane::KinesisDataPlane;
 use crate::services::aws::aws_data_plane::s3_data_plane::S3DataPlane;
 use crate::services::aws::aws_data_plane::sqs_data_plane::SqsDataPlane;
 use crate::services::aws::aws_types::dynamodb::{
-    DynamoDBGetItemRequest, DynamoDBPutItemRequest, DynamoDBQueryRequest,
+    DynamoDBGetItemRequest, DynamoDBPutItemRequest, DynamoDBQueryRequest, DynamoDBScanRequest,
 };
 use crate::services::aws::aws_types::kinesis::KinesisPutRecordRequest;
 use crate::services::aws::aws_types::s3::{S3GetObjectRequest, S3PutObjectRequest};
-use crate::services::aws::aws_types::sqs::{SqsReceiveMessageRequest, SqsSendMessageRequest};
+use crate::services::aws::aws_types::sqs::{
+    SqsFifoSendRequest, SqsReceiveMessageRequest, SqsSendMessageRequest,
+};
 use crate::services::aws::AwsService;
 
 // Helper struct for AWS data plane operations
@@ -89,6 +91,25 @@ impl AwsDataPlane {
         self.dynamodb.query(aws_account_dto, request).await
     }
 
+    pub async fn dynamodb_scan(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        request: &DynamoDBScanRequest,
+    ) -> Result<serde_json::Value, AppError> {
+        self.dynamodb.scan(aws_account_dto, request).await
+    }
+
+    pub async fn dynamodb_scan_all(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        request: &DynamoDBScanRequest,
+        max_items: usize,
+    ) -> Result<serde_json::Value, AppError> {
+        self.dynamodb
+            .scan_all(aws_account_dto, request, max_items)
+            .await
+    }
+
     // SQS operations
     pub async fn sqs_send_message(
         &self,
@@ -98,6 +119,25 @@ impl AwsDataPlane {
         self.sqs.send_message(aws_account_dto, request).await
     }
 
+    pub async fn sqs_send_fifo_message(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        request: &SqsFifoSendRequest,
+    ) -> Result<serde_json::Value, AppError> {
+        self.sqs.send_fifo_message(aws_account_dto, request).await
+    }
+
+    pub async fn sqs_get_queue_attributes(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        queue_url: &str,
+        attributes: Vec<String>,
+    ) -> Result<std::collections::HashMap<String, String>, AppError> {
+        self.sqs
+            .get_queue_attributes(aws_account_dto, queue_url, attributes)
+            .await
+    }
+
     pub async fn sqs_receive_messages(
         &self,
         aws_account_dto: &AwsAccountDto,
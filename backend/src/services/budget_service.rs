@@ -13,17 +13,35 @@
 // limitations under the License.
 
 
-use crate::models::cost_budget::{Budget, BudgetDto, BudgetType, BudgetStatus, BudgetAlert};
+use crate::errors::AppError;
+use crate::models::aws_account::AwsAccountDto;
+use crate::models::cost_budget::{
+    AwsBudgetResult, AwsBudgetSyncResult, AwsBudgetTimeUnit, AwsBudgetType, Budget, BudgetAlert,
+    BudgetAlertThreshold, BudgetDefinition, BudgetDto, BudgetStatus, BudgetType,
+};
 use crate::repositories::cost_budget_repository::CostBudgetRepository;
+use crate::services::AwsService;
+use aws_sdk_budgets::types::{
+    Budget as AwsBudget, BudgetType as AwsSdkBudgetType, ComparisonOperator, Notification,
+    NotificationType, NotificationWithSubscribers, Spend, Subscriber, SubscriptionType,
+    ThresholdType, TimeUnit,
+};
+use aws_sdk_budgets::Client as BudgetsClient;
 use sea_orm::{DatabaseConnection, ActiveModelTrait, Set};
 use chrono::{Utc, NaiveDate};
 use bigdecimal::ToPrimitive;
+use std::sync::Arc;
+use tracing::info;
 use uuid::Uuid;
 
+// AWS Budgets accepts at most 10 email subscribers per notification
+const MAX_BUDGET_NOTIFICATION_EMAILS: usize = 10;
+
 #[derive(Clone)]
 pub struct BudgetService {
     db: DatabaseConnection,
     repository: CostBudgetRepository,
+    aws_service: Option<Arc<AwsService>>,
 }
 
 impl BudgetService {
@@ -31,9 +49,15 @@ impl BudgetService {
         Self {
             repository: CostBudgetRepository::new(db.clone()),
             db,
+            aws_service: None,
         }
     }
 
+    pub fn with_aws_service(mut self, aws_service: Arc<AwsService>) -> Self {
+        self.aws_service = Some(aws_service);
+        self
+    }
+
     /// Create a new budget
     pub async fn create_budget(&self, dto: BudgetDto) -> Result<Budget, String> {
         // Validate budget data
@@ -118,6 +142,236 @@ impl BudgetService {
         Ok(alerts)
     }
 
+    /// Create a budget in AWS Budgets and record it locally
+    pub async fn create_aws_budget(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+        budget: BudgetDefinition,
+    ) -> Result<AwsBudgetResult, AppError> {
+        if budget.name.trim().is_empty() {
+            return Err(AppError::BadRequest(
+                "Budget name cannot be empty".to_string(),
+            ));
+        }
+        if budget.amount <= 0.0 {
+            return Err(AppError::BadRequest(
+                "Budget amount must be greater than 0".to_string(),
+            ));
+        }
+        if budget.threshold_percent <= 0.0 {
+            return Err(AppError::BadRequest(
+                "Threshold percent must be greater than 0".to_string(),
+            ));
+        }
+        if budget.notification_emails.len() > MAX_BUDGET_NOTIFICATION_EMAILS {
+            return Err(AppError::BadRequest(format!(
+                "At most {} notification emails are supported",
+                MAX_BUDGET_NOTIFICATION_EMAILS
+            )));
+        }
+
+        let client = self.budgets_client(aws_account_dto, region).await?;
+        let aws_budget = AwsBudget::builder()
+            .budget_name(&budget.name)
+            .budget_type(aws_budget_type(budget.budget_type))
+            .time_unit(aws_time_unit(budget.time_unit))
+            .budget_limit(
+                Spend::builder()
+                    .amount(budget.amount.to_string())
+                    .unit(&budget.currency)
+                    .build()?,
+            )
+            .build()?;
+
+        let mut request = client
+            .create_budget()
+            .account_id(&aws_account_dto.account_id)
+            .budget(aws_budget);
+        if !budget.notification_emails.is_empty() {
+            let notification = Notification::builder()
+                .notification_type(NotificationType::Actual)
+                .comparison_operator(ComparisonOperator::GreaterThan)
+                .threshold(budget.threshold_percent)
+                .threshold_type(ThresholdType::Percentage)
+                .build()?;
+            let subscribers = budget
+                .notification_emails
+                .iter()
+                .map(|email| {
+                    Subscriber::builder()
+                        .subscription_type(SubscriptionType::Email)
+                        .address(email)
+                        .build()
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            request = request.notifications_with_subscribers(
+                NotificationWithSubscribers::builder()
+                    .notification(notification)
+                    .set_subscribers(Some(subscribers))
+                    .build()?,
+            );
+        }
+        request.send().await.map_err(|e| {
+            AppError::CloudProvider(format!(
+                "Failed to create AWS budget {}: {}",
+                budget.name, e
+            ))
+        })?;
+        info!(
+            "Created AWS budget {} in account {}",
+            budget.name, aws_account_dto.account_id
+        );
+
+        let thresholds = vec![BudgetAlertThreshold {
+            percentage: budget.threshold_percent,
+            alert_type: "warning".to_string(),
+        }];
+        let now = Utc::now().naive_utc();
+        let local_budget = crate::models::cost_budget::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            account_id: Set(aws_account_dto.account_id.clone()),
+            name: Set(budget.name.clone()),
+            description: Set(None),
+            budget_type: Set(BudgetType::Overall.to_string()),
+            budget_period: Set(budget_period(&aws_time_unit(budget.time_unit)).to_string()),
+            amount: Set(budget.amount),
+            currency: Set(budget.currency.clone()),
+            start_date: Set(now.date()),
+            end_date: Set(None),
+            alert_thresholds: Set(serde_json::to_value(&thresholds)?),
+            tags: Set(aws_budget_tags(&aws_budget_type(budget.budget_type))),
+            created_at: Set(now),
+            updated_at: Set(now),
+            aws_budget_name: Set(Some(budget.name.clone())),
+        }
+        .insert(&self.db)
+        .await?;
+
+        Ok(AwsBudgetResult {
+            budget_id: local_budget.id,
+            aws_account_id: aws_account_dto.account_id.clone(),
+            aws_budget_name: budget.name,
+        })
+    }
+
+    /// Mirror the account's AWS Budgets into the local budgets table. Budgets
+    /// deleted in AWS are left in place.
+    pub async fn sync_aws_budgets(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+    ) -> Result<AwsBudgetSyncResult, AppError> {
+        let client = self.budgets_client(aws_account_dto, region).await?;
+        let account_id = &aws_account_dto.account_id;
+
+        let mut aws_budgets = Vec::new();
+        let mut next_token = None;
+        loop {
+            let output = client
+                .describe_budgets()
+                .account_id(account_id)
+                .max_results(100)
+                .set_next_token(next_token)
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::CloudProvider(format!("Failed to describe AWS budgets: {}", e))
+                })?;
+            aws_budgets.extend(output.budgets().iter().cloned());
+            next_token = output.next_token().map(|t| t.to_string());
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        let existing = self.repository.find_by_account_id(account_id).await?;
+        let mut result = AwsBudgetSyncResult {
+            created: 0,
+            updated: 0,
+        };
+        let now = Utc::now().naive_utc();
+        for aws_budget in aws_budgets {
+            let name = aws_budget.budget_name().to_string();
+            let (amount, currency) = aws_budget
+                .budget_limit()
+                .map(|limit| {
+                    (
+                        limit.amount().parse::<f64>().unwrap_or(0.0),
+                        limit.unit().to_string(),
+                    )
+                })
+                .unwrap_or((0.0, "USD".to_string()));
+            let period = budget_period(aws_budget.time_unit()).to_string();
+
+            match existing
+                .iter()
+                .find(|b| b.aws_budget_name.as_deref() == Some(name.as_str()))
+            {
+                Some(local_budget) => {
+                    let mut active_model: crate::models::cost_budget::ActiveModel =
+                        local_budget.clone().into();
+                    active_model.amount = Set(amount);
+                    active_model.currency = Set(currency);
+                    active_model.budget_period = Set(period);
+                    active_model.updated_at = Set(now);
+                    active_model.update(&self.db).await?;
+                    result.updated += 1;
+                }
+                None => {
+                    let start_date = aws_budget
+                        .time_period()
+                        .and_then(|p| p.start())
+                        .and_then(|start| {
+                            chrono::NaiveDateTime::from_timestamp_opt(start.secs(), 0)
+                        })
+                        .map(|start| start.date())
+                        .unwrap_or_else(|| now.date());
+                    crate::models::cost_budget::ActiveModel {
+                        id: Set(Uuid::new_v4()),
+                        account_id: Set(account_id.clone()),
+                        name: Set(name.clone()),
+                        description: Set(None),
+                        budget_type: Set(BudgetType::Overall.to_string()),
+                        budget_period: Set(period),
+                        amount: Set(amount),
+                        currency: Set(currency),
+                        start_date: Set(start_date),
+                        end_date: Set(None),
+                        alert_thresholds: Set(serde_json::json!([])),
+                        tags: Set(aws_budget_tags(aws_budget.budget_type())),
+                        created_at: Set(now),
+                        updated_at: Set(now),
+                        aws_budget_name: Set(Some(name)),
+                    }
+                    .insert(&self.db)
+                    .await?;
+                    result.created += 1;
+                }
+            }
+        }
+
+        info!(
+            "Synced AWS budgets for account {}: {} created, {} updated",
+            account_id, result.created, result.updated
+        );
+        Ok(result)
+    }
+
+    async fn budgets_client(
+        &self,
+        aws_account_dto: &AwsAccountDto,
+        region: &str,
+    ) -> Result<BudgetsClient, AppError> {
+        let aws_service = self.aws_service.as_ref().ok_or_else(|| {
+            AppError::Config("AWS budgets require the AWS service to be configured".to_string())
+        })?;
+        let mut account = aws_account_dto.clone();
+        account.default_region = region.to_string();
+        let sdk_config = aws_service.get_aws_sdk_config(&account).await?;
+        Ok(BudgetsClient::new(&sdk_config))
+    }
+
     /// Calculate current spending for a budget
     async fn calculate_current_spending(&self, budget: &Budget) -> Result<f64, String> {
         use crate::repositories::cost_analytics::CostAnalyticsRepository;
@@ -403,4 +657,34 @@ impl BudgetService {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+fn aws_budget_type(budget_type: AwsBudgetType) -> AwsSdkBudgetType {
+    match budget_type {
+        AwsBudgetType::Cost => AwsSdkBudgetType::Cost,
+        AwsBudgetType::Usage => AwsSdkBudgetType::Usage,
+    }
+}
+
+fn aws_time_unit(time_unit: AwsBudgetTimeUnit) -> TimeUnit {
+    match time_unit {
+        AwsBudgetTimeUnit::Daily => TimeUnit::Daily,
+        AwsBudgetTimeUnit::Monthly => TimeUnit::Monthly,
+        AwsBudgetTimeUnit::Quarterly => TimeUnit::Quarterly,
+        AwsBudgetTimeUnit::Annually => TimeUnit::Annually,
+    }
+}
+
+// Local budget periods have no daily period, so daily AWS budgets are stored as custom
+fn budget_period(time_unit: &TimeUnit) -> &'static str {
+    match time_unit {
+        TimeUnit::Monthly => "monthly",
+        TimeUnit::Quarterly => "quarterly",
+        TimeUnit::Annually => "yearly",
+        _ => "custom",
+    }
+}
+
+fn aws_budget_tags(budget_type: &AwsSdkBudgetType) -> serde_json::Value {
+    serde_json::json!({ "aws_budget_type": budget_type.as_str() })
+}
@@ -0,0 +1,444 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures::StreamExt;
+use k8s_openapi::api::core::v1::Pod;
+use kube::api::{WatchEvent, WatchParams};
+use kube::{Api, ResourceExt};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::models::chaos_audit_log::ChaosAuditAction;
+use crate::models::chaos_experiment::{Model as ChaosExperiment, RunExperimentRequest};
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::repositories::chaos_auto_trigger::{ChaosAutoTriggerRepository, StoredAutoTrigger};
+use crate::repositories::cluster::ClusterRepository;
+use crate::services::chaos_audit_service::ChaosAuditService;
+use crate::services::chaos_service::ChaosService;
+use crate::services::kubernetes::client::ClientFactory;
+
+const RESTART_WINDOW: Duration = Duration::from_secs(5 * 60);
+const WATCH_TIMEOUT_SECS: u32 = 290;
+const AUTO_TRIGGER_ACTOR: &str = "auto-trigger";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoTriggerInfo {
+    pub id: Uuid,
+    pub cluster_id: Uuid,
+    pub namespace: String,
+    pub restart_threshold: u32,
+    pub experiment_id: Uuid,
+}
+
+struct AutoTrigger {
+    info: AutoTriggerInfo,
+    handle: JoinHandle<()>,
+}
+
+// Restart timestamps per pod, pruned to the sliding window on every update
+#[derive(Default)]
+struct RestartTracker {
+    last_counts: HashMap<String, i32>,
+    restarts: HashMap<String, VecDeque<Instant>>,
+}
+
+impl RestartTracker {
+    // Returns the number of restarts seen for the pod within the window
+    fn observe(&mut self, pod_name: &str, restart_count: i32, now: Instant) -> usize {
+        let previous = self.last_counts.insert(pod_name.to_string(), restart_count);
+        let window = self.restarts.entry(pod_name.to_string()).or_default();
+
+        // The first sighting only establishes a baseline; earlier restarts are history
+        if let Some(previous) = previous {
+            for _ in previous..restart_count {
+                window.push_back(now);
+            }
+        }
+        while let Some(oldest) = window.front() {
+            if now.duration_since(*oldest) > RESTART_WINDOW {
+                window.pop_front();
+            } else {
+                break;
+            }
+        }
+        window.len()
+    }
+
+    fn forget(&mut self, pod_name: &str) {
+        self.last_counts.remove(pod_name);
+        self.restarts.remove(pod_name);
+    }
+}
+
+pub struct ChaosScheduler {
+    chaos_service: Arc<ChaosService>,
+    audit_service: Arc<ChaosAuditService>,
+    triggers: Mutex<HashMap<Uuid, AutoTrigger>>,
+    // Pods with an auto-triggered experiment in flight, keyed by "namespace/pod"
+    active_pods: Arc<Mutex<HashSet<String>>>,
+    // Without a store, triggers live only in memory and are lost on restart
+    trigger_store: Option<Arc<ChaosAutoTriggerRepository>>,
+}
+
+impl ChaosScheduler {
+    pub fn new(chaos_service: Arc<ChaosService>, audit_service: Arc<ChaosAuditService>) -> Self {
+        Self {
+            chaos_service,
+            audit_service,
+            triggers: Mutex::new(HashMap::new()),
+            active_pods: Arc::new(Mutex::new(HashSet::new())),
+            trigger_store: None,
+        }
+    }
+
+    pub fn with_trigger_store(mut self, store: Arc<ChaosAutoTriggerRepository>) -> Self {
+        self.trigger_store = Some(store);
+        self
+    }
+
+    /// Resume the watches of triggers registered before a restart.
+    pub fn start_restore(self: &Arc<Self>, clusters: Arc<ClusterRepository>) {
+        let scheduler = Arc::clone(self);
+        tokio::spawn(async move {
+            let Some(store) = scheduler.trigger_store.clone() else {
+                return;
+            };
+            let stored = match store.find_all().await {
+                Ok(stored) => stored,
+                Err(e) => {
+                    warn!("Failed to load chaos auto-triggers: {}", e);
+                    return;
+                }
+            };
+            for trigger in stored {
+                if let Err(e) = scheduler.restore_trigger(&clusters, &trigger).await {
+                    warn!(
+                        trigger_id = %trigger.id,
+                        "Failed to restore chaos auto-trigger: {}", e
+                    );
+                }
+            }
+        });
+    }
+
+    async fn restore_trigger(
+        self: &Arc<Self>,
+        clusters: &ClusterRepository,
+        trigger: &StoredAutoTrigger,
+    ) -> Result<(), AppError> {
+        let cluster = clusters
+            .find_by_id(trigger.cluster_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!("Cluster {} not found", trigger.cluster_id))
+            })?;
+        let cluster_config = parse_cluster_config(&cluster.config)?;
+        let experiment = self
+            .chaos_service
+            .get_experiment(trigger.experiment_id)
+            .await?;
+        let client = ClientFactory::get_client(&cluster_config).await?;
+        let info = AutoTriggerInfo {
+            id: trigger.id,
+            cluster_id: trigger.cluster_id,
+            namespace: trigger.namespace.clone(),
+            restart_threshold: trigger.restart_threshold,
+            experiment_id: trigger.experiment_id,
+        };
+        self.spawn_watch(
+            info,
+            Api::namespaced(client, &trigger.namespace),
+            experiment,
+        );
+        Ok(())
+    }
+
+    /// Watch pods in `namespace` and run `experiment` whenever a pod restarts more than
+    /// `restart_threshold` times within five minutes. Returns the id of the registered trigger.
+    pub async fn watch_and_trigger(
+        self: &Arc<Self>,
+        cluster_id: Uuid,
+        cluster_config: KubernetesClusterConfig,
+        namespace: &str,
+        restart_threshold: u32,
+        experiment: ChaosExperiment,
+    ) -> Result<AutoTriggerInfo, AppError> {
+        if restart_threshold == 0 {
+            return Err(AppError::Validation(
+                "restart_threshold must be greater than zero".to_string(),
+            ));
+        }
+
+        let client = ClientFactory::get_client(&cluster_config).await?;
+        let api: Api<Pod> = Api::namespaced(client, namespace);
+
+        let info = AutoTriggerInfo {
+            id: Uuid::new_v4(),
+            cluster_id,
+            namespace: namespace.to_string(),
+            restart_threshold,
+            experiment_id: experiment.id,
+        };
+        if let Some(store) = &self.trigger_store {
+            store
+                .insert(
+                    info.id,
+                    cluster_id,
+                    namespace,
+                    restart_threshold,
+                    experiment.id,
+                )
+                .await?;
+        }
+
+        self.spawn_watch(info.clone(), api, experiment);
+        Ok(info)
+    }
+
+    fn spawn_watch(
+        self: &Arc<Self>,
+        info: AutoTriggerInfo,
+        api: Api<Pod>,
+        experiment: ChaosExperiment,
+    ) {
+        let scheduler = Arc::clone(self);
+        let watch_info = info.clone();
+        let handle = tokio::spawn(async move {
+            scheduler.run_watch(watch_info, api, experiment).await;
+        });
+
+        info!(
+            trigger_id = %info.id,
+            namespace = %info.namespace,
+            restart_threshold = info.restart_threshold,
+            "Registered chaos auto-trigger"
+        );
+        self.triggers
+            .lock()
+            .unwrap()
+            .insert(info.id, AutoTrigger { info, handle });
+    }
+
+    pub fn list_triggers(&self) -> Vec<AutoTriggerInfo> {
+        self.triggers
+            .lock()
+            .unwrap()
+            .values()
+            .map(|t| t.info.clone())
+            .collect()
+    }
+
+    // A stored trigger whose watch could not be restored can still be removed
+    pub async fn remove_trigger(&self, id: Uuid) -> Result<(), AppError> {
+        let trigger = self.triggers.lock().unwrap().remove(&id);
+        let stored = match &self.trigger_store {
+            Some(store) => store.delete(id).await?,
+            None => false,
+        };
+        match trigger {
+            Some(trigger) => trigger.handle.abort(),
+            None if !stored => {
+                return Err(AppError::NotFound(format!("Auto-trigger {} not found", id)))
+            }
+            None => {}
+        }
+        info!(trigger_id = %id, "Removed chaos auto-trigger");
+        Ok(())
+    }
+
+    async fn run_watch(
+        self: Arc<Self>,
+        info: AutoTriggerInfo,
+        api: Api<Pod>,
+        experiment: ChaosExperiment,
+    ) {
+        let namespace = info.namespace.clone();
+        let mut tracker = RestartTracker::default();
+        let mut resource_version = "0".to_string();
+
+        loop {
+            let wp = WatchParams::default().timeout(WATCH_TIMEOUT_SECS);
+            let stream = match api.watch(&wp, &resource_version).await {
+                Ok(stream) => stream,
+                Err(e) => {
+                    warn!(%namespace, "Failed to start pod watch for auto-trigger: {}", e);
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    continue;
+                }
+            };
+            futures::pin_mut!(stream);
+
+            while let Some(event) = stream.next().await {
+                let event = match event {
+                    Ok(event) => event,
+                    Err(e) => {
+                        warn!(%namespace, "Auto-trigger pod watch stream error: {}", e);
+                        break;
+                    }
+                };
+
+                match event {
+                    WatchEvent::Added(pod) | WatchEvent::Modified(pod) => {
+                        if let Some(rv) = pod.resource_version() {
+                            resource_version = rv;
+                        }
+                        let pod_name = pod.name_any();
+                        let restarts =
+                            tracker.observe(&pod_name, total_restart_count(&pod), Instant::now());
+                        if restarts > info.restart_threshold as usize {
+                            self.trigger(&info, &experiment, &pod_name, restarts).await;
+                        }
+                    }
+                    WatchEvent::Deleted(pod) => {
+                        if let Some(rv) = pod.resource_version() {
+                            resource_version = rv;
+                        }
+                        tracker.forget(&pod.name_any());
+                    }
+                    WatchEvent::Bookmark(bookmark) => {
+                        resource_version = bookmark.metadata.resource_version;
+                    }
+                    WatchEvent::Error(e) => {
+                        if e.code == 410 {
+                            resource_version = "0".to_string();
+                        }
+                        debug!(
+                            %namespace,
+                            "Auto-trigger pod watch returned error: {}", e.message
+                        );
+                        break;
+                    }
+                }
+            }
+
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+    }
+
+    async fn trigger(
+        &self,
+        info: &AutoTriggerInfo,
+        experiment: &ChaosExperiment,
+        pod_name: &str,
+        restarts: usize,
+    ) {
+        let pod_key = format!("{}/{}", info.namespace, pod_name);
+        if !self.active_pods.lock().unwrap().insert(pod_key.clone()) {
+            debug!(pod = %pod_key, "Auto-triggered experiment already running for pod");
+            return;
+        }
+
+        // The last firing is persisted, so a restarted watch does not fire again early
+        if let Some(store) = &self.trigger_store {
+            let claimed = match store.claim_fire(info.id, RESTART_WINDOW).await {
+                Ok(claimed) => claimed,
+                Err(e) => {
+                    warn!(trigger_id = %info.id, "Failed to record auto-trigger firing: {}", e);
+                    false
+                }
+            };
+            if !claimed {
+                debug!(
+                    trigger_id = %info.id,
+                    pod = %pod_key,
+                    "Auto-trigger fired within the restart window, skipping"
+                );
+                self.active_pods.lock().unwrap().remove(&pod_key);
+                return;
+            }
+        }
+
+        let details = json!({
+            "trigger_id": info.id,
+            "namespace": info.namespace,
+            "pod_name": pod_name,
+            "restarts_in_window": restarts,
+            "restart_threshold": info.restart_threshold,
+            "window_seconds": RESTART_WINDOW.as_secs(),
+        });
+        let _ = self
+            .audit_service
+            .log_action(
+                ChaosAuditAction::AUTO_TRIGGERED,
+                None,
+                Some(AUTO_TRIGGER_ACTOR.to_string()),
+                Some(experiment.id),
+                None,
+                Some(pod_key.clone()),
+                None,
+                None,
+                None,
+                None,
+                Some(details),
+                None,
+                None,
+            )
+            .await;
+
+        info!(
+            pod = %pod_key,
+            experiment_id = %experiment.id,
+            restarts,
+            "Restart threshold exceeded, triggering chaos experiment"
+        );
+
+        // run_experiment blocks until the run finishes, so keep the pod marked until then
+        let chaos_service = self.chaos_service.clone();
+        let active_pods = self.active_pods.clone();
+        let experiment_id = experiment.id;
+        tokio::spawn(async move {
+            let request = RunExperimentRequest {
+                triggered_by: Some(AUTO_TRIGGER_ACTOR.to_string()),
+                parameter_overrides: None,
+                user_id: None,
+                ip_address: None,
+                user_agent: None,
+            };
+            if let Err(e) = chaos_service.run_experiment(experiment_id, request).await {
+                error!(
+                    pod = %pod_key,
+                    %experiment_id,
+                    "Auto-triggered experiment failed: {}", e
+                );
+            }
+            active_pods.lock().unwrap().remove(&pod_key);
+        });
+    }
+}
+
+// Clusters registered without explicit settings fall back to the default kubeconfig
+fn parse_cluster_config(value: &serde_json::Value) -> Result<KubernetesClusterConfig, AppError> {
+    if value.is_null() {
+        Ok(serde_json::from_value(json!({}))?)
+    } else {
+        Ok(serde_json::from_value(value.clone())?)
+    }
+}
+
+fn total_restart_count(pod: &Pod) -> i32 {
+    pod.status
+        .as_ref()
+        .and_then(|s| s.container_statuses.as_ref())
+        .map(|statuses| statuses.iter().map(|c| c.restart_count).sum())
+        .unwrap_or(0)
+}
@@ -13,12 +13,18 @@
 // limitations under the License.
 
 use chrono::Utc;
+use k8s_openapi::api::apps::v1::StatefulSet;
+use k8s_openapi::api::core::v1::Pod;
+use kube::api::{DeleteParams, ListParams, Patch, PatchParams};
+use kube::{Api, ResourceExt};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
 use crate::errors::AppError;
+use crate::models::aurora_cluster::AuroraClusterConfig;
+use crate::models::cluster::KubernetesClusterConfig;
 use crate::models::aws_account::AwsAccountDto;
 use crate::services::aws::client_factory::AwsClientFactory;
 use crate::models::chaos_experiment::{
@@ -37,6 +43,7 @@ use crate::repositories::chaos_repository::ChaosRepository;
 use crate::services::aws::AwsService;
 use crate::services::chaos_audit_service::ChaosAuditService;
 use crate::services::chaos_metrics_service::ChaosMetricsService;
+use crate::services::kubernetes::client::ClientFactory;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChaosExperimentSummary {
@@ -46,6 +53,31 @@ pub struct ChaosExperimentSummary {
     pub recent_runs: Vec<RunModel>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailoverResult {
+    pub cluster_id: String,
+    pub previous_writer: String,
+    pub new_writer: Option<String>,
+    pub failover_duration_secs: u64,
+    pub recovery_detected: bool,
+}
+
+const FAILOVER_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RollingUpgradeSimulation {
+    pub failed_pod: String,
+    pub time_to_failure_secs: u64,
+    pub time_to_recovery_secs: u64,
+    pub other_pods_affected: Vec<String>,
+    /// Explains when the pod that broke differs from the requested ordinal
+    pub note: Option<String>,
+}
+
+const ROLLING_UPGRADE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+const ROLLING_UPGRADE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(600);
+const ROLLING_UPGRADE_INVALID_IMAGE: &str = "mayyam.invalid/chaos-rolling-upgrade:does-not-exist";
+
 #[derive(Debug)]
 pub struct ChaosService {
     chaos_repo: Arc<ChaosRepository>,
@@ -771,6 +803,228 @@ impl ChaosService {
             .await
     }
 
+    /// Force an Aurora cluster failover and measure how long it takes for a new writer
+    /// to be promoted.
+    pub async fn simulate_aurora_failover(
+        &self,
+        cluster_config: &AuroraClusterConfig,
+        wait_for_recovery: bool,
+        timeout_secs: u64,
+    ) -> Result<FailoverResult, AppError> {
+        let aws_account = self
+            .aws_account_repo
+            .get_by_account_id(&cluster_config.account_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "AWS account {} not found",
+                    cluster_config.account_id
+                ))
+            })?;
+        let mut aws_account_dto = AwsAccountDto::from(aws_account);
+        aws_account_dto.default_region = cluster_config.region.clone();
+        let rds_client = self.aws_service.create_rds_client(&aws_account_dto).await?;
+
+        run_aurora_failover(
+            &rds_client,
+            &cluster_config.cluster_identifier,
+            cluster_config.target_instance.as_deref(),
+            wait_for_recovery,
+            timeout_secs,
+        )
+        .await
+    }
+
+    /// Break a StatefulSet rollout at `fail_at_ordinal` and measure how long it takes to fail
+    /// and to recover once the original image is restored.
+    ///
+    /// The rollout partition is set to `fail_at_ordinal`, so lower ordinals keep the old
+    /// revision. StatefulSets update from the highest ordinal down, so pods above
+    /// `fail_at_ordinal` are rolled first and show up in `other_pods_affected`. Under the
+    /// default `OrderedReady` policy the rollout halts at the first broken pod, so the highest
+    /// ordinal is watched instead and the result carries a note saying so.
+    ///
+    /// The original image is restored on every path once the broken image has been applied.
+    pub async fn simulate_rolling_upgrade_failure(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        stateful_set_name: &str,
+        fail_at_ordinal: i32,
+    ) -> Result<RollingUpgradeSimulation, AppError> {
+        let client = ClientFactory::get_client(cluster_config).await?;
+        let sts_api: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+        let pod_api: Api<Pod> = Api::namespaced(client, namespace);
+
+        let sts = sts_api.get(stateful_set_name).await.map_err(|e| {
+            AppError::ExternalService(format!(
+                "Failed to get stateful set '{}' in namespace '{}': {}",
+                stateful_set_name, namespace, e
+            ))
+        })?;
+        let spec = sts.spec.as_ref().ok_or_else(|| {
+            AppError::BadRequest(format!("StatefulSet {} has no spec", stateful_set_name))
+        })?;
+
+        let replicas = spec.replicas.unwrap_or(1);
+        if fail_at_ordinal < 0 || fail_at_ordinal >= replicas {
+            return Err(AppError::Validation(format!(
+                "fail_at_ordinal must be between 0 and {}",
+                replicas - 1
+            )));
+        }
+        let update_strategy = spec.update_strategy.as_ref();
+        if update_strategy.and_then(|s| s.type_.as_deref()) == Some("OnDelete") {
+            return Err(AppError::BadRequest(format!(
+                "StatefulSet {} uses the OnDelete update strategy and will not roll out",
+                stateful_set_name
+            )));
+        }
+        let original_partition = update_strategy
+            .and_then(|s| s.rolling_update.as_ref())
+            .and_then(|r| r.partition);
+        let container = spec
+            .template
+            .spec
+            .as_ref()
+            .and_then(|p| p.containers.first())
+            .ok_or_else(|| {
+                AppError::BadRequest(format!(
+                    "StatefulSet {} has no containers",
+                    stateful_set_name
+                ))
+            })?;
+        let container_name = container.name.clone();
+        let original_image = container.image.clone().ok_or_else(|| {
+            AppError::BadRequest(format!(
+                "Container {} in StatefulSet {} has no image",
+                container_name, stateful_set_name
+            ))
+        })?;
+
+        let ordered_ready = spec
+            .pod_management_policy
+            .as_deref()
+            .unwrap_or("OrderedReady")
+            == "OrderedReady";
+        let requested_pod = format!("{}-{}", stateful_set_name, fail_at_ordinal);
+        let (failed_pod, note) = if ordered_ready && fail_at_ordinal < replicas - 1 {
+            let first_rolled = format!("{}-{}", stateful_set_name, replicas - 1);
+            let note = format!(
+                "StatefulSet uses OrderedReady, so the rollout stops at {} and never reaches {}; \
+                 measured the failure of {} instead",
+                first_rolled, requested_pod, first_rolled
+            );
+            warn!("{}", note);
+            (first_rolled, Some(note))
+        } else {
+            (requested_pod, None)
+        };
+        info!(
+            "Breaking rollout of StatefulSet {}/{} at pod {}",
+            namespace, stateful_set_name, failed_pod
+        );
+        patch_stateful_set_image(
+            &sts_api,
+            stateful_set_name,
+            &container_name,
+            ROLLING_UPGRADE_INVALID_IMAGE,
+            Some(fail_at_ordinal),
+        )
+        .await?;
+
+        let started = std::time::Instant::now();
+        // Kept free of early returns past the patch so the restore below always runs
+        let observed: Result<(bool, Vec<Pod>), AppError> = async {
+            let mut failure_detected = false;
+            while started.elapsed() < ROLLING_UPGRADE_TIMEOUT {
+                tokio::time::sleep(ROLLING_UPGRADE_POLL_INTERVAL).await;
+                if let Some(pod) = pod_api.get_opt(&failed_pod).await.map_err(|e| {
+                    AppError::ExternalService(format!("Failed to get pod {}: {}", failed_pod, e))
+                })? {
+                    if pod_is_failing(&pod) {
+                        failure_detected = true;
+                        break;
+                    }
+                }
+            }
+            // Snapshot which other pods of the StatefulSet were unhealthy at the point of failure.
+            let pods = stateful_set_pods(&pod_api, stateful_set_name).await?;
+            Ok((failure_detected, pods))
+        }
+        .await;
+        let time_to_failure_secs = started.elapsed().as_secs();
+
+        info!(
+            "Restoring image {} on StatefulSet {}/{}",
+            original_image, namespace, stateful_set_name
+        );
+        patch_stateful_set_image(
+            &sts_api,
+            stateful_set_name,
+            &container_name,
+            &original_image,
+            original_partition,
+        )
+        .await?;
+
+        let (failure_detected, pods) = observed?;
+        let other_pods_affected: Vec<String> = pods
+            .iter()
+            .filter(|p| p.name_any() != failed_pod && (pod_is_failing(p) || !pod_is_ready(p)))
+            .map(|p| p.name_any())
+            .collect();
+        if !failure_detected {
+            return Err(AppError::ExternalService(format!(
+                "Pod {} did not fail within {}s; the original image has been restored",
+                failed_pod,
+                ROLLING_UPGRADE_TIMEOUT.as_secs()
+            )));
+        }
+
+        // Reverting the template does not replace pods stuck on the broken revision, so they
+        // have to be deleted for the controller to recreate them.
+        let recovery_started = std::time::Instant::now();
+        for pod in pods.iter().filter(|p| pod_is_failing(p)) {
+            let name = pod.name_any();
+            pod_api
+                .delete(&name, &DeleteParams::default())
+                .await
+                .map_err(|e| {
+                    AppError::ExternalService(format!("Failed to delete pod {}: {}", name, e))
+                })?;
+        }
+
+        let mut recovered = false;
+        while recovery_started.elapsed() < ROLLING_UPGRADE_TIMEOUT {
+            tokio::time::sleep(ROLLING_UPGRADE_POLL_INTERVAL).await;
+            let pods = stateful_set_pods(&pod_api, stateful_set_name).await?;
+            if pods.len() as i32 >= replicas
+                && pods.iter().all(|p| pod_is_ready(p) && !pod_is_failing(p))
+            {
+                recovered = true;
+                break;
+            }
+        }
+        let time_to_recovery_secs = recovery_started.elapsed().as_secs();
+        if !recovered {
+            warn!(
+                "StatefulSet {}/{} did not recover within {}s",
+                namespace,
+                stateful_set_name,
+                ROLLING_UPGRADE_TIMEOUT.as_secs()
+            );
+        }
+
+        Ok(RollingUpgradeSimulation {
+            failed_pod,
+            time_to_failure_secs,
+            time_to_recovery_secs,
+            other_pods_affected,
+            note,
+        })
+    }
+
     // ========================================================================
     // Private: Chaos Execution Engine
     // ========================================================================
@@ -1990,3 +2244,217 @@ impl ChaosResultData {
         }
     }
 }
+
+/// Trigger `FailoverDBCluster` and poll every 5 seconds until the writer changes or the
+/// timeout elapses. With `wait_for_recovery`, recovery also requires every instance in the
+/// cluster to be available again. Usable without a database, e.g. from the CLI.
+pub async fn run_aurora_failover(
+    rds_client: &aws_sdk_rds::Client,
+    cluster_identifier: &str,
+    target_instance: Option<&str>,
+    wait_for_recovery: bool,
+    timeout_secs: u64,
+) -> Result<FailoverResult, AppError> {
+    let cluster_id = cluster_identifier.to_string();
+    let previous_writer = aurora_writer(rds_client, &cluster_id)
+        .await?
+        .ok_or_else(|| {
+            AppError::BadRequest(format!(
+                "Aurora cluster {} has no writer instance",
+                cluster_id
+            ))
+        })?;
+
+    info!(
+        "Initiating Aurora failover for {} (current writer: {})",
+        cluster_id, previous_writer
+    );
+    rds_client
+        .failover_db_cluster()
+        .db_cluster_identifier(&cluster_id)
+        .set_target_db_instance_identifier(target_instance.map(|t| t.to_string()))
+        .send()
+        .await
+        .map_err(|e| {
+            AppError::CloudProvider(format!("Failed to initiate Aurora failover: {:?}", e))
+        })?;
+
+    let started = std::time::Instant::now();
+    let deadline = started + std::time::Duration::from_secs(timeout_secs);
+    let mut new_writer = None;
+    let mut failover_duration_secs = 0;
+    let mut recovery_detected = false;
+
+    while std::time::Instant::now() < deadline {
+        tokio::time::sleep(FAILOVER_POLL_INTERVAL).await;
+
+        if new_writer.is_none() {
+            match aurora_writer(rds_client, &cluster_id).await? {
+                Some(writer) if writer != previous_writer => {
+                    failover_duration_secs = started.elapsed().as_secs();
+                    info!(
+                        "Aurora cluster {} promoted {} after {}s",
+                        cluster_id, writer, failover_duration_secs
+                    );
+                    new_writer = Some(writer);
+                }
+                _ => continue,
+            }
+        }
+
+        if !wait_for_recovery || aurora_instances_available(rds_client, &cluster_id).await? {
+            recovery_detected = true;
+            break;
+        }
+    }
+
+    if new_writer.is_none() {
+        failover_duration_secs = started.elapsed().as_secs();
+        warn!(
+            "Aurora cluster {} writer did not change within {}s",
+            cluster_id, timeout_secs
+        );
+    }
+
+    Ok(FailoverResult {
+        cluster_id,
+        previous_writer,
+        new_writer,
+        failover_duration_secs,
+        recovery_detected,
+    })
+}
+
+async fn aurora_writer(
+    rds_client: &aws_sdk_rds::Client,
+    cluster_id: &str,
+) -> Result<Option<String>, AppError> {
+    let response = rds_client
+        .describe_db_clusters()
+        .db_cluster_identifier(cluster_id)
+        .send()
+        .await
+        .map_err(|e| {
+            AppError::CloudProvider(format!("Failed to describe Aurora cluster: {:?}", e))
+        })?;
+
+    let cluster = response
+        .db_clusters()
+        .first()
+        .ok_or_else(|| AppError::NotFound(format!("Aurora cluster {} not found", cluster_id)))?;
+    Ok(cluster
+        .db_cluster_members()
+        .iter()
+        .find(|m| m.is_cluster_writer() == Some(true))
+        .and_then(|m| m.db_instance_identifier())
+        .map(|id| id.to_string()))
+}
+
+async fn aurora_instances_available(
+    rds_client: &aws_sdk_rds::Client,
+    cluster_id: &str,
+) -> Result<bool, AppError> {
+    let response = rds_client
+        .describe_db_clusters()
+        .db_cluster_identifier(cluster_id)
+        .send()
+        .await
+        .map_err(|e| {
+            AppError::CloudProvider(format!("Failed to describe Aurora cluster: {:?}", e))
+        })?;
+    let members: Vec<String> = response
+        .db_clusters()
+        .iter()
+        .flat_map(|c| c.db_cluster_members())
+        .filter_map(|m| m.db_instance_identifier())
+        .map(|id| id.to_string())
+        .collect();
+
+    for instance_id in &members {
+        let response = rds_client
+            .describe_db_instances()
+            .db_instance_identifier(instance_id)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::CloudProvider(format!("Failed to describe Aurora instance: {:?}", e))
+            })?;
+        let available = response
+            .db_instances()
+            .iter()
+            .all(|i| i.db_instance_status() == Some("available"));
+        if !available {
+            return Ok(false);
+        }
+    }
+    Ok(!members.is_empty())
+}
+
+async fn patch_stateful_set_image(
+    api: &Api<StatefulSet>,
+    name: &str,
+    container_name: &str,
+    image: &str,
+    partition: Option<i32>,
+) -> Result<(), AppError> {
+    let patch = serde_json::json!({
+        "spec": {
+            "updateStrategy": {
+                "type": "RollingUpdate",
+                "rollingUpdate": { "partition": partition }
+            },
+            "template": {
+                "spec": {
+                    "containers": [{ "name": container_name, "image": image }]
+                }
+            }
+        }
+    });
+    api.patch(name, &PatchParams::default(), &Patch::Strategic(&patch))
+        .await
+        .map_err(|e| {
+            AppError::ExternalService(format!("Failed to patch stateful set {}: {}", name, e))
+        })?;
+    Ok(())
+}
+
+async fn stateful_set_pods(api: &Api<Pod>, stateful_set_name: &str) -> Result<Vec<Pod>, AppError> {
+    let pods = api
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| AppError::ExternalService(format!("Failed to list pods: {}", e)))?;
+    Ok(pods
+        .into_iter()
+        .filter(|p| {
+            p.owner_references()
+                .iter()
+                .any(|o| o.kind == "StatefulSet" && o.name == stateful_set_name)
+        })
+        .collect())
+}
+
+// A bad image surfaces as ErrImagePull/ImagePullBackOff rather than CrashLoopBackOff, so
+// all three count as a failed rollout.
+fn pod_is_failing(pod: &Pod) -> bool {
+    pod.status
+        .as_ref()
+        .and_then(|s| s.container_statuses.as_ref())
+        .into_iter()
+        .flatten()
+        .filter_map(|cs| cs.state.as_ref()?.waiting.as_ref()?.reason.as_deref())
+        .any(|reason| {
+            matches!(
+                reason,
+                "CrashLoopBackOff" | "ErrImagePull" | "ImagePullBackOff"
+            )
+        })
+}
+
+fn pod_is_ready(pod: &Pod) -> bool {
+    pod.status
+        .as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .into_iter()
+        .flatten()
+        .any(|c| c.type_ == "Ready" && c.status == "True")
+}
@@ -14,8 +14,12 @@
 
 
 use crate::errors::AppError;
+use crate::models::aws_cost_category::AwsCostCategory;
 use crate::models::aws_cost_data::CostDataModel;
+use crate::repositories::aws_cost_category::AwsCostCategoryRepository;
 use crate::repositories::cost_analytics::CostAnalyticsRepository;
+use aws_sdk_costexplorer::types as ce;
+use aws_sdk_costexplorer::Client as CostExplorerClient;
 use bigdecimal::ToPrimitive;
 use chrono::NaiveDate;
 use sea_orm::DatabaseConnection;
@@ -68,10 +72,112 @@ pub struct CostCategoryResult {
     pub cost_breakdown: HashMap<String, f64>,
 }
 
+/// Rule of an AWS-managed cost category; AWS assigns the value of the first matching rule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AwsCostCategoryRule {
+    pub value: String,
+    pub expression: CostCategoryExpression,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CostCategoryExpression {
+    Tags { key: String, values: Vec<String> },
+    Accounts { account_ids: Vec<String> },
+    // Cost category rules match service codes such as "AmazonEC2", not display names
+    Services { service_codes: Vec<String> },
+}
+
+impl CostCategoryExpression {
+    fn to_sdk_expression(&self) -> ce::Expression {
+        let dimension = |key: ce::Dimension, values: &[String]| {
+            ce::DimensionValues::builder()
+                .key(key)
+                .set_values(Some(values.to_vec()))
+                .match_options(ce::MatchOption::Equals)
+                .build()
+        };
+        match self {
+            CostCategoryExpression::Tags { key, values } => ce::Expression::builder()
+                .tags(
+                    ce::TagValues::builder()
+                        .key(key)
+                        .set_values(Some(values.clone()))
+                        .match_options(ce::MatchOption::Equals)
+                        .build(),
+                )
+                .build(),
+            CostCategoryExpression::Accounts { account_ids } => ce::Expression::builder()
+                .dimensions(dimension(ce::Dimension::LinkedAccount, account_ids))
+                .build(),
+            CostCategoryExpression::Services { service_codes } => ce::Expression::builder()
+                .dimensions(dimension(ce::Dimension::ServiceCode, service_codes))
+                .build(),
+        }
+    }
+}
+
+impl AwsCostCategoryRule {
+    fn to_sdk_rule(&self) -> ce::CostCategoryRule {
+        ce::CostCategoryRule::builder()
+            .value(&self.value)
+            .rule(self.expression.to_sdk_expression())
+            .r#type(ce::CostCategoryRuleType::Regular)
+            .build()
+    }
+}
+
+fn validate_rules(rules: &[AwsCostCategoryRule]) -> Result<(), AppError> {
+    if rules.is_empty() {
+        return Err(AppError::BadRequest(
+            "A cost category needs at least one rule".to_string(),
+        ));
+    }
+    if rules.iter().any(|r| r.value.trim().is_empty()) {
+        return Err(AppError::BadRequest(
+            "Cost category rule values must not be empty".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+async fn describe_category(
+    client: &CostExplorerClient,
+    category_arn: &str,
+) -> Result<ce::CostCategory, AppError> {
+    let described = client
+        .describe_cost_category_definition()
+        .cost_category_arn(category_arn)
+        .send()
+        .await
+        .map_err(|e| {
+            AppError::CloudProvider(format!(
+                "Failed to describe cost category {}: {}",
+                category_arn, e
+            ))
+        })?;
+    described
+        .cost_category()
+        .cloned()
+        .ok_or_else(|| AppError::NotFound(format!("Cost category {} not found", category_arn)))
+}
+
+// Distinct rule values in rule order
+fn rule_values<'a>(values: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let mut distinct: Vec<String> = Vec::new();
+    for value in values {
+        if !distinct.iter().any(|v| v == value) {
+            distinct.push(value.to_string());
+        }
+    }
+    distinct
+}
+
 #[derive(Debug)]
 pub struct CostCategoriesService {
     db: Arc<DatabaseConnection>,
     cost_repo: Arc<CostAnalyticsRepository>,
+    category_repo: AwsCostCategoryRepository,
 }
 
 impl CostCategoriesService {
@@ -79,7 +185,182 @@ impl CostCategoriesService {
         db: Arc<DatabaseConnection>,
         cost_repo: Arc<CostAnalyticsRepository>,
     ) -> Self {
-        Self { db, cost_repo }
+        let category_repo = AwsCostCategoryRepository::new(db.clone());
+        Self {
+            db,
+            cost_repo,
+            category_repo,
+        }
+    }
+
+    /// Create a cost category definition in AWS Cost Explorer and store it locally
+    pub async fn create_category(
+        &self,
+        client: &CostExplorerClient,
+        account_id: &str,
+        name: &str,
+        rules: Vec<AwsCostCategoryRule>,
+    ) -> Result<AwsCostCategory, AppError> {
+        if name.trim().is_empty() {
+            return Err(AppError::BadRequest(
+                "Cost category name must not be empty".to_string(),
+            ));
+        }
+        validate_rules(&rules)?;
+
+        let response = client
+            .create_cost_category_definition()
+            .name(name)
+            .rule_version(ce::CostCategoryRuleVersion::CostCategoryExpressionV1)
+            .set_rules(Some(
+                rules.iter().map(AwsCostCategoryRule::to_sdk_rule).collect(),
+            ))
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::CloudProvider(format!("Failed to create cost category {}: {}", name, e))
+            })?;
+
+        let category = AwsCostCategory {
+            category_arn: response.cost_category_arn().unwrap_or_default().to_string(),
+            name: name.to_string(),
+            effective_start: response.effective_start().map(String::from),
+            number_of_rules: rules.len() as i32,
+            values: rule_values(rules.iter().map(|r| r.value.as_str())),
+            default_value: None,
+        };
+        self.category_repo
+            .upsert_category(account_id, &category)
+            .await?;
+        Ok(category)
+    }
+
+    /// Replace the rules of an existing cost category definition. `default_value` is
+    /// None to keep the stored default, Some(None) to clear it.
+    pub async fn update_category(
+        &self,
+        client: &CostExplorerClient,
+        account_id: &str,
+        category_arn: &str,
+        rules: Vec<AwsCostCategoryRule>,
+        default_value: Option<Option<String>>,
+    ) -> Result<AwsCostCategory, AppError> {
+        validate_rules(&rules)?;
+
+        let default_value = match default_value {
+            Some(value) => value,
+            // The update clears the default when it is left out, so resend the current one
+            None => describe_category(client, category_arn)
+                .await?
+                .default_value()
+                .map(String::from),
+        };
+
+        client
+            .update_cost_category_definition()
+            .cost_category_arn(category_arn)
+            .rule_version(ce::CostCategoryRuleVersion::CostCategoryExpressionV1)
+            .set_rules(Some(
+                rules.iter().map(AwsCostCategoryRule::to_sdk_rule).collect(),
+            ))
+            .set_default_value(default_value)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::CloudProvider(format!(
+                    "Failed to update cost category {}: {}",
+                    category_arn, e
+                ))
+            })?;
+
+        // The update response only carries the ARN, so read back the definition for the name
+        let definition = describe_category(client, category_arn).await?;
+
+        let category = AwsCostCategory {
+            category_arn: definition.cost_category_arn().to_string(),
+            name: definition.name().to_string(),
+            effective_start: Some(definition.effective_start().to_string()),
+            number_of_rules: definition.rules().len() as i32,
+            values: rule_values(definition.rules().iter().filter_map(|r| r.value())),
+            default_value: definition.default_value().map(String::from),
+        };
+        self.category_repo
+            .upsert_category(account_id, &category)
+            .await?;
+        Ok(category)
+    }
+
+    pub async fn delete_category(
+        &self,
+        client: &CostExplorerClient,
+        category_arn: &str,
+    ) -> Result<(), AppError> {
+        client
+            .delete_cost_category_definition()
+            .cost_category_arn(category_arn)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::CloudProvider(format!(
+                    "Failed to delete cost category {}: {}",
+                    category_arn, e
+                ))
+            })?;
+        self.category_repo.delete_by_arn(category_arn).await
+    }
+
+    /// List the live cost categories and sync them to the local database
+    pub async fn list_categories(
+        &self,
+        client: &CostExplorerClient,
+        account_id: &str,
+    ) -> Result<Vec<AwsCostCategory>, AppError> {
+        let mut categories = Vec::new();
+        let mut next_token: Option<String> = None;
+
+        loop {
+            let response = client
+                .list_cost_category_definitions()
+                .set_next_token(next_token.clone())
+                .send()
+                .await
+                .map_err(|e| {
+                    AppError::CloudProvider(format!("Failed to list cost categories: {}", e))
+                })?;
+
+            for reference in response.cost_category_references() {
+                let Some(category_arn) = reference.cost_category_arn() else {
+                    continue;
+                };
+                categories.push(AwsCostCategory {
+                    category_arn: category_arn.to_string(),
+                    name: reference.name().unwrap_or_default().to_string(),
+                    effective_start: reference.effective_start().map(String::from),
+                    number_of_rules: reference.number_of_rules(),
+                    values: reference.values().to_vec(),
+                    default_value: reference.default_value().map(String::from),
+                });
+            }
+
+            next_token = response.next_token().map(String::from);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        self.category_repo
+            .replace_for_account(account_id, &categories)
+            .await?;
+        Ok(categories)
+    }
+
+    /// Look up a category from the last sync
+    pub async fn find_synced_category(
+        &self,
+        account_id: &str,
+        name: &str,
+    ) -> Result<Option<AwsCostCategory>, AppError> {
+        self.category_repo.find_by_name(account_id, name).await
     }
 
     /// Create predefined cost categories for common use cases
@@ -0,0 +1,338 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{Duration, Utc};
+use sea_orm::prelude::Decimal;
+use sea_orm::Set;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::repositories::ai_analysis_repository::AIAnalysisRepository;
+use crate::repositories::cost_analytics::CostAnalyticsRepository;
+use crate::repositories::llm_provider::LlmProviderRepository;
+use crate::services::llm::{LlmIntegrationService, LlmRequest};
+
+const ANALYSIS_TYPE: &str = "cost_optimization";
+// Keeps the prompt within a reasonable size for accounts with many services
+const MAX_SERVICES_IN_PROMPT: usize = 25;
+const MAX_RIGHTSIZING_IN_PROMPT: usize = 25;
+const MAX_ANOMALIES_IN_PROMPT: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EffortLevel {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptimizationSuggestion {
+    pub service: String,
+    pub action: String,
+    pub estimated_monthly_savings: f64,
+    pub implementation_effort: EffortLevel,
+    pub explanation: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostOptimizationReport {
+    pub account_id: String,
+    pub lookback_days: u32,
+    pub total_potential_savings: f64,
+    pub suggestions: Vec<OptimizationSuggestion>,
+    pub generated_at: chrono::DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+struct ServiceCost {
+    service: String,
+    cost: f64,
+}
+
+#[derive(Debug, Serialize)]
+struct CostContext {
+    account_id: String,
+    lookback_days: u32,
+    total_cost: f64,
+    cost_by_service: Vec<ServiceCost>,
+    rightsizing_recommendations: Vec<serde_json::Value>,
+    anomalies: Vec<serde_json::Value>,
+}
+
+// Shape the model is asked to answer with
+#[derive(Debug, Deserialize)]
+struct LlmSuggestions {
+    #[serde(default)]
+    suggestions: Vec<OptimizationSuggestion>,
+}
+
+pub struct CostOptimizationAdvisor {
+    repository: Arc<CostAnalyticsRepository>,
+    llm_service: Arc<LlmIntegrationService>,
+    llm_provider_repo: Arc<LlmProviderRepository>,
+    ai_analysis_repo: Arc<AIAnalysisRepository>,
+}
+
+impl CostOptimizationAdvisor {
+    pub fn new(
+        repository: Arc<CostAnalyticsRepository>,
+        llm_service: Arc<LlmIntegrationService>,
+        llm_provider_repo: Arc<LlmProviderRepository>,
+        ai_analysis_repo: Arc<AIAnalysisRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            llm_service,
+            llm_provider_repo,
+            ai_analysis_repo,
+        }
+    }
+
+    /// Ask the LLM for cost saving actions based on recent spend, rightsizing and anomalies.
+    pub async fn generate_suggestions(
+        &self,
+        account_id: &str,
+        lookback_days: u32,
+    ) -> Result<CostOptimizationReport, AppError> {
+        if lookback_days == 0 {
+            return Err(AppError::Validation(
+                "lookback_days must be greater than zero".to_string(),
+            ));
+        }
+
+        let context = self.build_context(account_id, lookback_days).await?;
+
+        let providers = self.llm_provider_repo.find_active().await?;
+        let provider = providers
+            .first()
+            .ok_or_else(|| AppError::Config("No active LLM provider configured".to_string()))?;
+
+        let prompt = build_prompt(&context)?;
+        let llm_request = LlmRequest {
+            prompt: prompt.clone(),
+            system_prompt: Some("You are a cloud cost optimization expert. Respond only with JSON matching the requested schema.".to_string()),
+            temperature: Some(0.2),
+            max_tokens: Some(2000),
+            variables: None,
+        };
+
+        let response = self
+            .llm_service
+            .generate_response(provider.id, llm_request)
+            .await?;
+
+        let suggestions = parse_suggestions(&response.content)?;
+        let report = CostOptimizationReport {
+            account_id: account_id.to_string(),
+            lookback_days,
+            // Summed here rather than taken from the model so the total always matches the list
+            total_potential_savings: suggestions
+                .iter()
+                .map(|s| s.estimated_monthly_savings)
+                .sum(),
+            suggestions,
+            generated_at: Utc::now(),
+        };
+
+        let active_model = crate::models::ai_analysis::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            // Cost analyses are not tied to a database cluster
+            cluster_id: Set(Uuid::nil()),
+            fingerprint_id: Set(None),
+            slow_query_id: Set(None),
+            ai_provider: Set(response.provider),
+            ai_model: Set(response.model),
+            analysis_type: Set(ANALYSIS_TYPE.to_string()),
+            input_data: Set(serde_json::to_value(&context).unwrap_or(serde_json::Value::Null)),
+            analysis_result: Set(serde_json::to_string(&report).unwrap_or_default()),
+            confidence_score: Set(None),
+            suggested_indexes: Set(serde_json::Value::Array(vec![])),
+            suggested_rewrites: Set(serde_json::Value::Array(vec![])),
+            root_causes: Set(serde_json::Value::Array(vec![])),
+            created_at: Set(Utc::now().naive_utc()),
+        };
+        self.ai_analysis_repo
+            .create_from_active_model(active_model)
+            .await
+            .map_err(AppError::Internal)?;
+
+        tracing::info!(
+            "Generated {} cost optimization suggestions for account {} (${:.2}/month)",
+            report.suggestions.len(),
+            account_id,
+            report.total_potential_savings
+        );
+        Ok(report)
+    }
+
+    async fn build_context(
+        &self,
+        account_id: &str,
+        lookback_days: u32,
+    ) -> Result<CostContext, AppError> {
+        let end_date = Utc::now().naive_utc().date();
+        let start_date = end_date - Duration::days(lookback_days as i64);
+
+        let cost_data = self
+            .repository
+            .get_cost_data_by_date_range(account_id, start_date, end_date, None)
+            .await?;
+        let mut by_service: HashMap<String, f64> = HashMap::new();
+        for row in &cost_data {
+            *by_service.entry(row.service_name.clone()).or_default() +=
+                decimal_to_f64(row.unblended_cost);
+        }
+        let total_cost = by_service.values().sum();
+        let mut cost_by_service: Vec<ServiceCost> = by_service
+            .into_iter()
+            .map(|(service, cost)| ServiceCost { service, cost })
+            .collect();
+        cost_by_service.sort_by(|a, b| b.cost.total_cmp(&a.cost));
+        cost_by_service.truncate(MAX_SERVICES_IN_PROMPT);
+
+        let rightsizing_recommendations = self
+            .repository
+            .get_rightsizing_recommendations(Some(account_id.to_string()), None, None)
+            .await?
+            .into_iter()
+            .take(MAX_RIGHTSIZING_IN_PROMPT)
+            .map(|r| {
+                serde_json::json!({
+                    "resource_id": r.resource_id,
+                    "resource_type": r.resource_type,
+                    "current_instance_type": r.current_instance_type,
+                    "recommended_instance_type": r.recommended_instance_type,
+                    "modification_type": r.modification_type,
+                    "estimated_monthly_savings": decimal_to_f64(r.estimated_monthly_savings),
+                })
+            })
+            .collect();
+
+        let anomalies = self
+            .repository
+            .get_cost_anomalies_by_account(account_id, None, None)
+            .await?
+            .into_iter()
+            .filter(|a| a.detected_date >= start_date)
+            .take(MAX_ANOMALIES_IN_PROMPT)
+            .map(|a| {
+                serde_json::json!({
+                    "service": a.service_name,
+                    "detected_date": a.detected_date,
+                    "severity": a.severity,
+                    "actual_cost": decimal_to_f64(a.actual_cost),
+                    "baseline_cost": a.baseline_cost.map(decimal_to_f64),
+                    "description": a.description,
+                })
+            })
+            .collect();
+
+        Ok(CostContext {
+            account_id: account_id.to_string(),
+            lookback_days,
+            total_cost,
+            cost_by_service,
+            rightsizing_recommendations,
+            anomalies,
+        })
+    }
+}
+
+fn decimal_to_f64(value: Decimal) -> f64 {
+    value.to_string().parse::<f64>().unwrap_or(0.0)
+}
+
+fn build_prompt(context: &CostContext) -> Result<String, AppError> {
+    let context_json = serde_json::to_string_pretty(context)
+        .map_err(|e| AppError::Internal(format!("Failed to serialize cost context: {}", e)))?;
+
+    Ok(format!(
+        r#"Review the AWS spend for account {} over the last {} days and suggest concrete cost optimizations.
+
+Cost context (costs in USD):
+{}
+
+Respond with JSON only, in this format:
+{{
+    "suggestions": [
+        {{
+            "service": "AWS service name",
+            "action": "Specific action to take",
+            "estimated_monthly_savings": 0.0,
+            "implementation_effort": "low|medium|high",
+            "explanation": "Why this saves money, referencing the data above"
+        }}
+    ]
+}}
+"#,
+        context.account_id, context.lookback_days, context_json
+    ))
+}
+
+// Models often wrap JSON in markdown fences or add a sentence around it, so only the
+// outermost object is parsed
+fn parse_suggestions(content: &str) -> Result<Vec<OptimizationSuggestion>, AppError> {
+    let start = content.find('{');
+    let end = content.rfind('}');
+    let json = match (start, end) {
+        (Some(start), Some(end)) if start < end => &content[start..=end],
+        _ => {
+            return Err(AppError::ExternalService(
+                "LLM response did not contain a JSON object".to_string(),
+            ))
+        }
+    };
+
+    let parsed: LlmSuggestions = serde_json::from_str(json).map_err(|e| {
+        AppError::ExternalService(format!("Failed to parse LLM cost suggestions: {}", e))
+    })?;
+
+    Ok(parsed
+        .suggestions
+        .into_iter()
+        .map(|mut s| {
+            s.estimated_monthly_savings = s.estimated_monthly_savings.max(0.0);
+            s
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_suggestions_strips_markdown_fence() {
+        let content = r#"Here you go:
+```json
+{"suggestions": [{"service": "Amazon EC2", "action": "Downsize m5.2xlarge to m5.xlarge",
+"estimated_monthly_savings": 120.5, "implementation_effort": "low",
+"explanation": "CPU stays under 10%"}]}
+```"#;
+
+        let suggestions = parse_suggestions(content).unwrap();
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].implementation_effort, EffortLevel::Low);
+        assert_eq!(suggestions[0].estimated_monthly_savings, 120.5);
+    }
+
+    #[test]
+    fn parse_suggestions_rejects_non_json() {
+        assert!(parse_suggestions("No suggestions today").is_err());
+    }
+}
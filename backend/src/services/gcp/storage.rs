@@ -0,0 +1,272 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{DateTime, Utc};
+use google_cloud_storage::client::google_cloud_auth::credentials::CredentialsFile;
+use google_cloud_storage::client::{Client, ClientConfig};
+use google_cloud_storage::http::buckets::list::ListBucketsRequest;
+use google_cloud_storage::http::buckets::Bucket;
+use google_cloud_storage::http::objects::download::Range;
+use google_cloud_storage::http::objects::get::GetObjectRequest;
+use google_cloud_storage::http::objects::list::ListObjectsRequest;
+use google_cloud_storage::http::objects::upload::{Media, UploadObjectRequest, UploadType};
+use google_cloud_storage::http::objects::Object;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::config::GcpStorageConfig;
+use crate::errors::AppError;
+use crate::models::aws_resource::AwsResourceDto;
+use crate::models::cloud_resource::CloudResourceDto;
+use crate::repositories::cloud_resource::CloudResourceRepository;
+
+pub const GCS_BUCKET_RESOURCE_TYPE: &str = "GCSBucket";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GcsObject {
+    pub name: String,
+    pub size: i64,
+    pub content_type: Option<String>,
+    pub updated: Option<DateTime<Utc>>,
+}
+
+// Clients are built per call since every config may carry its own credentials
+#[derive(Default)]
+pub struct GcpStorageService;
+
+impl GcpStorageService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    async fn client(config: &GcpStorageConfig) -> Result<Client, AppError> {
+        let client_config = match &config.credentials_file {
+            Some(path) => {
+                let credentials =
+                    CredentialsFile::new_from_file(path.clone())
+                        .await
+                        .map_err(|e| {
+                            AppError::Config(format!(
+                                "Failed to read GCP credentials file {}: {}",
+                                path, e
+                            ))
+                        })?;
+                ClientConfig::default().with_credentials(credentials).await
+            }
+            None => ClientConfig::default().with_auth().await,
+        }
+        .map_err(|e| AppError::Auth(format!("Failed to authenticate with GCP: {}", e)))?;
+
+        Ok(Client::new(client_config))
+    }
+
+    // Buckets of the config's project, shaped like AWS resources so they can share the
+    // cloud resource inventory
+    pub async fn list_buckets(
+        &self,
+        config: &GcpStorageConfig,
+    ) -> Result<Vec<AwsResourceDto>, AppError> {
+        let client = Self::client(config).await?;
+
+        let mut buckets = Vec::new();
+        let mut page_token: Option<String> = None;
+        loop {
+            let response = client
+                .list_buckets(&ListBucketsRequest {
+                    project: config.project_id.clone(),
+                    page_token: page_token.take(),
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| {
+                    AppError::CloudProvider(format!("Failed to list GCS buckets: {}", e))
+                })?;
+
+            buckets.extend(
+                response
+                    .items
+                    .iter()
+                    .map(|bucket| bucket_resource(&config.project_id, bucket)),
+            );
+
+            match response.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => break,
+            }
+        }
+
+        Ok(buckets)
+    }
+
+    pub async fn list_objects(
+        &self,
+        config: &GcpStorageConfig,
+        prefix: Option<&str>,
+    ) -> Result<Vec<GcsObject>, AppError> {
+        let client = Self::client(config).await?;
+
+        let mut objects = Vec::new();
+        let mut page_token: Option<String> = None;
+        loop {
+            let response = client
+                .list_objects(&ListObjectsRequest {
+                    bucket: config.bucket.clone(),
+                    prefix: prefix.map(String::from),
+                    page_token: page_token.take(),
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| {
+                    AppError::CloudProvider(format!(
+                        "Failed to list objects in gs://{}: {}",
+                        config.bucket, e
+                    ))
+                })?;
+
+            objects.extend(response.items.unwrap_or_default().iter().map(to_gcs_object));
+
+            match response.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => break,
+            }
+        }
+
+        Ok(objects)
+    }
+
+    pub async fn get_object(
+        &self,
+        config: &GcpStorageConfig,
+        object_name: &str,
+    ) -> Result<Vec<u8>, AppError> {
+        let client = Self::client(config).await?;
+        client
+            .download_object(
+                &GetObjectRequest {
+                    bucket: config.bucket.clone(),
+                    object: object_name.to_string(),
+                    ..Default::default()
+                },
+                &Range::default(),
+            )
+            .await
+            .map_err(|e| {
+                AppError::CloudProvider(format!(
+                    "Failed to download gs://{}/{}: {}",
+                    config.bucket, object_name, e
+                ))
+            })
+    }
+
+    pub async fn upload_object(
+        &self,
+        config: &GcpStorageConfig,
+        object_name: &str,
+        data: Vec<u8>,
+        content_type: &str,
+    ) -> Result<GcsObject, AppError> {
+        let client = Self::client(config).await?;
+
+        let mut media = Media::new(object_name.to_string());
+        media.content_type = content_type.to_string().into();
+        let object = client
+            .upload_object(
+                &UploadObjectRequest {
+                    bucket: config.bucket.clone(),
+                    ..Default::default()
+                },
+                data,
+                &UploadType::Simple(media),
+            )
+            .await
+            .map_err(|e| {
+                AppError::CloudProvider(format!(
+                    "Failed to upload gs://{}/{}: {}",
+                    config.bucket, object_name, e
+                ))
+            })?;
+
+        Ok(to_gcs_object(&object))
+    }
+
+    // Writes the buckets to the unified cloud resource table under one sync id
+    pub async fn store_buckets(
+        &self,
+        cloud_resource_repo: &CloudResourceRepository,
+        buckets: &[AwsResourceDto],
+    ) -> Result<Uuid, AppError> {
+        let sync_id = Uuid::new_v4();
+        for bucket in buckets {
+            cloud_resource_repo
+                .create(&CloudResourceDto {
+                    id: None,
+                    sync_id,
+                    provider: "gcp".to_string(),
+                    account_id: bucket.account_id.clone(),
+                    region: bucket.region.clone(),
+                    resource_type: bucket.resource_type.clone(),
+                    resource_id: bucket.resource_id.clone(),
+                    arn_or_uri: Some(bucket.arn.clone()),
+                    name: bucket.name.clone(),
+                    tags: bucket.tags.clone(),
+                    resource_data: bucket.resource_data.clone(),
+                })
+                .await?;
+        }
+
+        info!(
+            "Stored {} GCS buckets with sync_id {}",
+            buckets.len(),
+            sync_id
+        );
+        Ok(sync_id)
+    }
+}
+
+// The project stands in for the account and the gs:// URI for the ARN
+fn bucket_resource(project_id: &str, bucket: &Bucket) -> AwsResourceDto {
+    let created: Option<DateTime<Utc>> = bucket
+        .time_created
+        .and_then(|dt| DateTime::from_timestamp(dt.unix_timestamp(), dt.nanosecond()));
+    AwsResourceDto {
+        id: None,
+        sync_id: None,
+        account_id: project_id.to_string(),
+        profile: None,
+        region: bucket.location.to_lowercase(),
+        resource_type: GCS_BUCKET_RESOURCE_TYPE.to_string(),
+        resource_id: bucket.name.clone(),
+        arn: format!("gs://{}", bucket.name),
+        name: Some(bucket.name.clone()),
+        tags: json!(bucket.labels.clone().unwrap_or_default()),
+        resource_data: json!({
+            "location": bucket.location,
+            "location_type": bucket.location_type,
+            "created": created,
+        }),
+    }
+}
+
+fn to_gcs_object(object: &Object) -> GcsObject {
+    GcsObject {
+        name: object.name.clone(),
+        size: object.size,
+        content_type: object.content_type.clone(),
+        updated: object
+            .updated
+            .and_then(|dt| DateTime::from_timestamp(dt.unix_timestamp(), dt.nanosecond())),
+    }
+}
@@ -15,11 +15,22 @@
 
 use crate::errors::AppError;
 use crate::models::cluster::CreateKafkaClusterRequest;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
 use crate::models::cluster::KafkaClusterConfig;
 use crate::repositories::cluster::ClusterRepository;
-use rdkafka::admin::{AdminClient, AdminOptions, NewTopic, TopicReplication};
+use crate::repositories::distributed_lock::{DistributedLock, LockGuard};
+use crate::repositories::kafka_config_restore::{
+    KafkaConfigRestoreRepository, PendingConfigRestore,
+};
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
+use crate::services::kafka_transform::{MessageTransformPipeline, RawMessage};
+use rdkafka::admin::{
+    AdminClient, AdminOptions, AlterConfig, ConfigResource, ConfigSource, NewTopic,
+    OwnedResourceSpecifier, ResourceSpecifier, TopicReplication,
+};
+use rdkafka::client::DefaultClientContext;
 use rdkafka::config::ClientConfig;
-use rdkafka::consumer::{CommitMode, Consumer, StreamConsumer};
+use rdkafka::consumer::{BaseConsumer, CommitMode, Consumer, StreamConsumer};
 use rdkafka::message::{Header, Headers, Message, OwnedHeaders};
 use rdkafka::producer::{FutureProducer, FutureRecord, Producer};
 use rdkafka::topic_partition_list::{Offset, TopicPartitionList};
@@ -71,6 +82,22 @@ lazy_static! {
     ).unwrap();
 }
 
+// Capacity report tuning
+const DEFAULT_MESSAGE_SIZE_BYTES: f64 = 1024.0;
+const CAPACITY_SAMPLE_MESSAGES: i64 = 20;
+const RETENTION_WARNING_RATIO: f64 = 0.8;
+const MAX_PARTITIONS_PER_BROKER: usize = 4000;
+// Upper bound on messages read by a single search, so large topics cannot hold a request open
+const SEARCH_MAX_SCANNED_MESSAGES: usize = 100_000;
+const SEARCH_YIELD_EVERY: usize = 500;
+// How long a purge waits for the broker to delete expired segments or for a compacted
+// topic to be scanned
+const PURGE_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const PURGE_MAX_WAIT: Duration = Duration::from_secs(300);
+// Broker certificates expiring sooner than this are flagged by the health check
+const CERT_EXPIRY_WARNING_DAYS: i64 = 30;
+const TLS_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
 // ===== FILESYSTEM STORAGE STRUCTURES =====
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -428,6 +455,26 @@ pub struct ConsumeOptions {
     pub from_beginning: Option<bool>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageSearchOptions {
+    pub partition: Option<i32>,
+    pub start_offset: Option<i64>,
+    pub key_regex: Option<String>,
+    pub value_regex: Option<String>,
+    #[serde(default = "default_search_max_results")]
+    pub max_results: usize,
+    #[serde(default = "default_search_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+fn default_search_max_results() -> usize {
+    50
+}
+
+fn default_search_timeout_ms() -> u64 {
+    10_000
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConsumerGroup {
     pub group_id: String,
@@ -459,14 +506,82 @@ pub struct ConsumerGroupOffset {
     pub lag: i64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartitionLagBucket {
+    pub partition: i32,
+    pub lag: i64,
+    pub consumer_id: Option<String>,
+    pub is_caught_up: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OffsetReset {
+    pub topic: String,
     pub partitions: Vec<PartitionOffset>,
     pub to_earliest: Option<bool>,
     pub to_latest: Option<bool>,
     pub to_offset: Option<i64>,
 }
 
+// Where a consumer group starts on partitions it has never committed to
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OffsetStrategy {
+    Earliest,
+    Latest,
+    Timestamp(i64), // Milliseconds since epoch
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewPartitionOffsetResult {
+    pub initialized_partitions: Vec<i32>,
+    pub skipped_partitions: Vec<i32>, // Already had a committed offset
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloneResult {
+    pub topics_cloned: Vec<String>,
+    pub partitions_cloned: u32,
+    pub skipped_topics: Vec<String>, // Requested topics the source group never committed to
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicDefinition {
+    pub name: String,
+    pub partition_count: i32,
+    pub replication_factor: i16,
+    pub configs: HashMap<String, String>, // Topic-level overrides only, not broker defaults
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PurgeResult {
+    pub estimated_messages_purged: u64,
+    pub partitions_affected: Vec<i32>,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrokerCertExpiry {
+    pub broker_host: String,
+    pub broker_port: u16,
+    pub common_name: String,
+    pub not_after: chrono::DateTime<chrono::Utc>,
+    pub days_until_expiry: i64,
+    pub is_expired: bool,
+    // Whether the chain verifies against the system trust store for this broker's host name
+    pub is_trusted: bool,
+    pub trust_error: Option<String>,
+}
+
+// Offsets below `cutoff` hold messages older than the purge age
+#[derive(Debug, Clone, Copy)]
+struct ExpiredRange {
+    partition: i32,
+    low: i64,
+    cutoff: i64,
+    high: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PartitionOffset {
     pub partition: i32,
@@ -531,6 +646,25 @@ pub struct MessageTransformation {
     pub key_prefix: Option<String>,
     pub header_additions: Option<Vec<(String, String)>>,
     pub value_transformation: Option<String>, // Could be a script or template
+    pub jq_filter: Option<String>,            // Applied to the JSON value
+    pub header_removals: Option<Vec<String>>,
+    pub value_schema_coercion: Option<String>, // JSON schema the value is coerced to
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TopicTransformRequest {
+    pub target_topic: String,         // Must differ from the source topic
+    pub partitions: Option<Vec<i32>>, // None means all partitions
+    pub transform: MessageTransformation,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TopicTransformResponse {
+    pub topic: String,
+    pub target_topic: String,
+    pub messages_transformed: u64,
+    pub messages_failed: u64,
+    pub partitions_transformed: Vec<i32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -551,6 +685,43 @@ pub enum MigrationStatus {
     Failed(String),
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MessageReplayRequest {
+    pub target_topic: Option<String>, // None means replay onto the source topic
+    pub partition: i32,
+    pub start_offset: i64,
+    pub end_offset: i64, // Exclusive
+    pub rate_limit_per_sec: Option<u32>,
+    #[serde(default)]
+    pub allow_self_replay: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReplayResult {
+    pub messages_replayed: u64,
+    pub duration_ms: u64,
+    pub target_topic: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartitionReplicationLag {
+    pub partition: i32,
+    pub source_latest: i64,
+    pub target_latest: i64, // 0 when the partition is missing on the target
+    pub lag: i64,
+    pub exceeds_threshold: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplicationLagReport {
+    pub topic: String,
+    pub target_topic: String,
+    pub partitions: Vec<PartitionReplicationLag>,
+    pub total_lag: i64,
+    pub is_healthy: bool,
+    pub last_checked: chrono::DateTime<chrono::Utc>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct QueueDrainRequest {
     pub topics: Vec<String>,
@@ -595,6 +766,68 @@ pub struct PartitionOffsets {
     pub latest: i64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartitionWatermarks {
+    pub topic: String,
+    pub partition: i32,
+    pub offsets: PartitionOffsets,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KafkaCapacityReport {
+    pub broker_count: usize,
+    pub total_topics: usize,
+    pub total_partitions: usize,
+    pub estimated_messages_per_second: f64,
+    pub estimated_disk_usage_bytes: u64,
+    pub topics_near_retention: Vec<String>,
+    pub recommendations: Vec<String>,
+}
+
+// Per-topic figures gathered while building a capacity report
+#[derive(Debug, Clone)]
+pub struct TopicCapacity {
+    pub name: String,
+    pub partitions: usize,
+    pub replication_factor: usize,
+    pub messages_last_hour: i64,
+    pub estimated_disk_usage_bytes: u64,
+    pub retention_bytes: Option<i64>,
+    pub largest_partition_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartitionReassignment {
+    pub topic: String,
+    pub partition: i32,
+    pub current_replicas: Vec<i32>,
+    pub new_replicas: Vec<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReassignmentPlan {
+    pub reassignments: Vec<PartitionReassignment>,
+    pub estimated_data_move_bytes: u64,
+    // librdkafka has no AlterPartitionReassignments binding, so plans are applied with
+    // kafka-reassign-partitions.sh --execute using this file
+    pub reassignment_json: serde_json::Value,
+}
+
+// Same layout kafka-reassign-partitions.sh accepts with --reassignment-json-file
+fn to_reassignment_json(reassignments: &[PartitionReassignment]) -> serde_json::Value {
+    serde_json::json!({
+        "version": 1,
+        "partitions": reassignments
+            .iter()
+            .map(|r| serde_json::json!({
+                "topic": r.topic,
+                "partition": r.partition,
+                "replicas": r.new_replicas,
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KafkaMetrics {
     pub messages_produced: u64,
@@ -631,8 +864,14 @@ pub struct KafkaMetrics {
 pub struct KafkaService {
     cluster_repository: Arc<ClusterRepository>,
     metrics: Arc<Mutex<KafkaMetrics>>,
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+    distributed_lock: Option<Arc<DistributedLock>>,
+    config_restores: Option<Arc<KafkaConfigRestoreRepository>>,
 }
 
+// The lock is renewed while a copy runs; a crashed holder's lock frees up after this
+const TOPIC_COPY_LOCK_TTL: std::time::Duration = std::time::Duration::from_secs(10 * 60);
+
 impl KafkaService {
     pub fn new(cluster_repository: Arc<ClusterRepository>) -> Self {
         Self {
@@ -667,6 +906,80 @@ impl KafkaService {
                 drain_success_rate: 0.0,
                 avg_drain_duration_ms: 0.0,
             })),
+            audit_emitter: None,
+            distributed_lock: None,
+            config_restores: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
+    }
+
+    pub fn with_distributed_lock(mut self, lock: Arc<DistributedLock>) -> Self {
+        self.distributed_lock = Some(lock);
+        self
+    }
+
+    pub fn with_config_restores(mut self, repo: Arc<KafkaConfigRestoreRepository>) -> Self {
+        self.config_restores = Some(repo);
+        self
+    }
+
+    /// Re-apply topic overrides that an interrupted purge left pending, e.g. after a restart.
+    pub fn start_config_restore(self: &Arc<Self>, config: crate::config::Config) {
+        let service = Arc::clone(self);
+        tokio::spawn(async move {
+            let Some(repo) = service.config_restores.clone() else {
+                return;
+            };
+            let pending = match repo.find_pending().await {
+                Ok(pending) => pending,
+                Err(e) => {
+                    warn!("Failed to load pending Kafka topic config restores: {}", e);
+                    return;
+                }
+            };
+            for restore in pending {
+                if let Err(e) = service.restore_topic_config(&repo, &restore, &config).await {
+                    warn!(
+                        "Failed to restore config of topic {} in cluster {}: {}",
+                        restore.topic_name, restore.cluster_id, e
+                    );
+                }
+            }
+        });
+    }
+
+    async fn restore_topic_config(
+        &self,
+        repo: &KafkaConfigRestoreRepository,
+        restore: &PendingConfigRestore,
+        config: &crate::config::Config,
+    ) -> Result<(), AppError> {
+        // A purge still running on another instance holds the lock and restores on its own
+        let _guard = self
+            .lock(&purge_lock_key(&restore.cluster_id, &restore.topic_name))
+            .await?;
+        let cluster = self.get_cluster(&restore.cluster_id, config).await?;
+        let admin: AdminClient<_> = self.build_client_config(&cluster).create().map_err(|e| {
+            AppError::ExternalService(format!("Failed to create Kafka admin client: {}", e))
+        })?;
+        alter_topic_config(&admin, &restore.topic_name, &restore.original_configs).await?;
+        repo.mark_restored(restore.id).await?;
+        info!(
+            "Restored config overrides of topic {} in cluster {}",
+            restore.topic_name, restore.cluster_id
+        );
+        Ok(())
+    }
+
+    // Without a configured lock (e.g. in the CLI) operations run unguarded
+    async fn lock(&self, key: &str) -> Result<Option<LockGuard>, AppError> {
+        match &self.distributed_lock {
+            Some(lock) => lock.acquire(key, TOPIC_COPY_LOCK_TTL).await.map(Some),
+            None => Ok(None),
         }
     }
 
@@ -825,13 +1138,7 @@ impl KafkaService {
             .clusters
             .iter()
             .find(|c| c.name == id)
-            .map(|c| KafkaClusterConfig {
-                bootstrap_servers: c.bootstrap_servers.clone(),
-                sasl_username: c.sasl_username.clone(),
-                sasl_password: c.sasl_password.clone(),
-                sasl_mechanism: c.sasl_mechanism.clone(),
-                security_protocol: c.security_protocol.clone(),
-            })
+            .map(KafkaClusterConfig::from)
             .ok_or_else(|| AppError::NotFound(format!("Kafka cluster with ID {} not found", id)))
     }
 
@@ -894,39 +1201,7 @@ impl KafkaService {
 
     // Build Kafka client configuration
     fn build_client_config(&self, cluster: &KafkaClusterConfig) -> ClientConfig {
-        let mut client_config = ClientConfig::new();
-
-        // Set bootstrap servers (allow environment variable override for Docker/container environments)
-        let bootstrap_servers = if let Ok(env_brokers) = std::env::var("KAFKA_BROKERS") {
-            env_brokers
-        } else {
-            cluster.bootstrap_servers.join(",")
-        };
-        client_config.set("bootstrap.servers", &bootstrap_servers);
-
-        // Set security settings if present
-        if let (Some(username), Some(password)) = (&cluster.sasl_username, &cluster.sasl_password) {
-            client_config.set("sasl.username", username);
-            client_config.set("sasl.password", password);
-
-            if let Some(mechanism) = &cluster.sasl_mechanism {
-                client_config.set("sasl.mechanism", mechanism);
-            }
-
-            client_config.set("security.protocol", &cluster.security_protocol);
-        } else {
-            client_config.set("security.protocol", &cluster.security_protocol);
-        }
-
-        // Common settings for resilience in a web context
-        client_config.set("request.timeout.ms", "10000");
-        client_config.set("message.timeout.ms", "15000");
-        client_config.set("socket.timeout.ms", "10000");
-        client_config.set("metadata.request.timeout.ms", "10000");
-        client_config.set("message.send.max.retries", "3");
-        client_config.set("retry.backoff.ms", "500");
-
-        client_config
+        build_client_config(cluster)
     }
 
     // Health check for Kafka cluster connectivity
@@ -965,15 +1240,83 @@ impl KafkaService {
             })
             .collect::<Vec<_>>();
 
+        // Expired or untrusted certificates break clients, so they fail the check outright
+        let mut warnings = Vec::new();
+        let mut errors = Vec::new();
+        if uses_tls(&cluster) {
+            let addresses = broker_addresses(&metadata);
+            for cert in probe_broker_certs(cluster_id, &addresses).await {
+                if cert.is_expired {
+                    errors.push(format!(
+                        "Certificate for broker {}:{} ({}) expired on {}",
+                        cert.broker_host, cert.broker_port, cert.common_name, cert.not_after
+                    ));
+                } else if let Some(trust_error) = &cert.trust_error {
+                    errors.push(format!(
+                        "Certificate for broker {}:{} ({}) is not trusted: {}",
+                        cert.broker_host, cert.broker_port, cert.common_name, trust_error
+                    ));
+                } else if cert.days_until_expiry <= CERT_EXPIRY_WARNING_DAYS {
+                    warnings.push(format!(
+                        "Certificate for broker {}:{} ({}) expires in {} days",
+                        cert.broker_host,
+                        cert.broker_port,
+                        cert.common_name,
+                        cert.days_until_expiry
+                    ));
+                }
+            }
+        }
+
+        let status = if errors.is_empty() {
+            "healthy"
+        } else {
+            "unhealthy"
+        };
         Ok(serde_json::json!({
-            "status": "healthy",
+            "status": status,
             "cluster_id": cluster_id,
             "brokers": brokers,
             "topics_count": metadata.topics().len(),
+            "errors": errors,
+            "warnings": warnings,
             "timestamp": chrono::Utc::now().timestamp_millis()
         }))
     }
 
+    // Reads the certificate each broker presents on its TLS listener. Brokers that cannot
+    // be reached are logged and left out rather than failing the whole check.
+    pub async fn check_broker_cert_expiry(
+        &self,
+        cluster_id: &str,
+        config: &crate::config::Config,
+    ) -> Result<Vec<BrokerCertExpiry>, AppError> {
+        let cluster = self.get_cluster(cluster_id, config).await?;
+        if !uses_tls(&cluster) {
+            return Err(AppError::BadRequest(format!(
+                "Cluster {} uses {} and has no broker certificates to check",
+                cluster_id, cluster.security_protocol
+            )));
+        }
+
+        let mut client_config = self.build_client_config(&cluster);
+        client_config.set("client.id", "mayyam-cert-check");
+        let producer: FutureProducer = client_config.create().map_err(|e| {
+            AppError::ExternalService(format!("Failed to connect to Kafka cluster: {}", e))
+        })?;
+        let metadata = producer
+            .client()
+            .fetch_metadata(None, Duration::from_secs(10))
+            .map_err(|e| {
+                KAFKA_OPERATION_ERRORS
+                    .with_label_values(&[cluster_id, "cert_check"])
+                    .inc();
+                AppError::ExternalService(format!("Failed to fetch cluster metadata: {:?}", e))
+            })?;
+
+        Ok(probe_broker_certs(cluster_id, &broker_addresses(&metadata)).await)
+    }
+
     // List topics in a cluster
     #[tracing::instrument(skip(self, config), fields(cluster_id = %cluster_id))]
     pub async fn list_topics(
@@ -1129,6 +1472,7 @@ impl KafkaService {
         cluster_id: &str,
         topic_name: &str,
         config: &crate::config::Config,
+        audit_ctx: &AuditContext,
     ) -> Result<serde_json::Value, AppError> {
         let cluster = self.get_cluster(cluster_id, config).await?;
         let client_config = self.build_client_config(&cluster);
@@ -1138,8 +1482,31 @@ impl KafkaService {
             AppError::ExternalService(format!("Failed to create Kafka admin client: {}", e))
         })?;
 
-        // In a real implementation, delete the topic
-        // This is a placeholder implementation
+        let opts = AdminOptions::new().operation_timeout(Some(Duration::from_secs(10)));
+        let results = admin.delete_topics(&[topic_name], &opts).await.map_err(|e| {
+            KAFKA_OPERATION_ERRORS
+                .with_label_values(&[cluster_id, "delete_topic"])
+                .inc();
+            AppError::ExternalService(format!("Failed to execute delete topics request: {}", e))
+        })?;
+
+        if let Some(Err((topic, err))) = results.first() {
+            KAFKA_OPERATION_ERRORS
+                .with_label_values(&[cluster_id, "delete_topic"])
+                .inc();
+            return Err(AppError::ExternalService(format!(
+                "Failed to delete topic {}: {:?}",
+                topic, err
+            )));
+        }
+
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::DELETE, "KafkaTopic", topic_name)
+                .with_payload(serde_json::json!({ "cluster_id": cluster_id })),
+        )
+        .await;
+
         let response = serde_json::json!({
             "message": format!("Topic {} deleted successfully", topic_name)
         });
@@ -1324,6 +1691,160 @@ impl KafkaService {
         Ok(messages)
     }
 
+    // Reads the topic from start_offset (earliest by default) up to the high watermark
+    // seen when the search starts, and returns messages whose key and value match the
+    // given patterns. Nothing is committed; each search uses a throwaway group id.
+    pub async fn search_messages(
+        &self,
+        cluster_id: &str,
+        topic_name: &str,
+        search_options: &MessageSearchOptions,
+        config: &crate::config::Config,
+    ) -> Result<Vec<serde_json::Value>, AppError> {
+        if search_options.max_results == 0 {
+            return Err(AppError::Validation(
+                "max_results must be greater than zero".to_string(),
+            ));
+        }
+        let key_regex = compile_search_regex("key_regex", search_options.key_regex.as_deref())?;
+        let value_regex =
+            compile_search_regex("value_regex", search_options.value_regex.as_deref())?;
+
+        let cluster = self.get_cluster(cluster_id, config).await?;
+        let mut client_config = self.build_client_config(&cluster);
+        client_config.set("client.id", "mayyam-message-search");
+        client_config.set(
+            "group.id",
+            &format!("mayyam-message-search-{}", Uuid::new_v4()),
+        );
+        client_config.set("enable.auto.commit", "false");
+        let consumer: StreamConsumer = client_config.create().map_err(|e| {
+            AppError::ExternalService(format!("Failed to create Kafka consumer: {}", e))
+        })?;
+
+        let timeout = Duration::from_secs(10);
+        let metadata = consumer
+            .fetch_metadata(Some(topic_name), timeout)
+            .map_err(|e| AppError::Kafka(format!("Failed to fetch topic metadata: {}", e)))?;
+        let topic = metadata
+            .topics()
+            .iter()
+            .find(|t| t.name() == topic_name && t.error().is_none() && !t.partitions().is_empty())
+            .ok_or_else(|| AppError::NotFound(format!("Topic {} not found", topic_name)))?;
+        let partitions: Vec<i32> = match search_options.partition {
+            Some(partition) if topic.partitions().iter().any(|p| p.id() == partition) => {
+                vec![partition]
+            }
+            Some(partition) => {
+                return Err(AppError::BadRequest(format!(
+                    "Topic {} has no partition {}",
+                    topic_name, partition
+                )))
+            }
+            None => topic.partitions().iter().map(|p| p.id()).collect(),
+        };
+
+        let mut tpl = TopicPartitionList::new();
+        let mut end_offsets = HashMap::new();
+        for partition in partitions {
+            let (low, high) = consumer
+                .fetch_watermarks(topic_name, partition, timeout)
+                .map_err(|e| {
+                    AppError::Kafka(format!(
+                        "Failed to fetch watermarks for {}/{}: {}",
+                        topic_name, partition, e
+                    ))
+                })?;
+            let start = search_options
+                .start_offset
+                .map_or(low, |offset| offset.clamp(low, high));
+            if start < high {
+                tpl.add_partition_offset(topic_name, partition, Offset::Offset(start))
+                    .map_err(|e| AppError::Kafka(format!("Failed to seek consumer: {}", e)))?;
+                end_offsets.insert(partition, high);
+            }
+        }
+        if end_offsets.is_empty() {
+            return Ok(Vec::new());
+        }
+        consumer
+            .assign(&tpl)
+            .map_err(|e| AppError::Kafka(format!("Failed to assign partitions: {}", e)))?;
+
+        let deadline = Instant::now() + Duration::from_millis(search_options.timeout_ms);
+        let mut results = Vec::new();
+        let mut scanned = 0usize;
+        while !end_offsets.is_empty()
+            && results.len() < search_options.max_results
+            && scanned < SEARCH_MAX_SCANNED_MESSAGES
+        {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+            let message = match tokio::time::timeout(remaining, consumer.recv()).await {
+                Ok(Ok(message)) => message,
+                Ok(Err(e)) => {
+                    KAFKA_OPERATION_ERRORS
+                        .with_label_values(&[cluster_id, "search"])
+                        .inc();
+                    return Err(AppError::Kafka(format!("Failed to read messages: {}", e)));
+                }
+                Err(_) => break,
+            };
+
+            let partition = message.partition();
+            let end = match end_offsets.get(&partition) {
+                Some(&end) => end,
+                None => continue,
+            };
+            if message.offset() + 1 >= end {
+                end_offsets.remove(&partition);
+            }
+            if message.offset() >= end {
+                continue;
+            }
+            scanned += 1;
+
+            let key = message
+                .key()
+                .map(|k| String::from_utf8_lossy(k).to_string());
+            let value = message
+                .payload()
+                .map(|p| String::from_utf8_lossy(p).to_string())
+                .unwrap_or_default();
+            let key_matches = key_regex
+                .as_ref()
+                .map_or(true, |re| key.as_deref().map_or(false, |k| re.is_match(k)));
+            let value_matches = value_regex.as_ref().map_or(true, |re| re.is_match(&value));
+            if key_matches && value_matches {
+                results.push(serde_json::json!({
+                    "partition": partition,
+                    "offset": message.offset(),
+                    "timestamp": message.timestamp().to_millis(),
+                    "key": key,
+                    "value": value,
+                }));
+            }
+
+            // Give other tasks on the runtime a turn during long scans
+            if scanned % SEARCH_YIELD_EVERY == 0 {
+                tokio::task::yield_now().await;
+            }
+        }
+
+        KAFKA_MESSAGES_CONSUMED
+            .with_label_values(&[cluster_id, topic_name])
+            .inc_by(scanned as u64);
+        if scanned >= SEARCH_MAX_SCANNED_MESSAGES {
+            warn!(
+                "Search on {}/{} stopped after scanning {} messages",
+                cluster_id, topic_name, scanned
+            );
+        }
+        Ok(results)
+    }
+
     // List consumer groups
     pub async fn list_consumer_groups(
         &self,
@@ -1362,81 +1883,984 @@ impl KafkaService {
         group_id: &str,
         config: &crate::config::Config,
     ) -> Result<serde_json::Value, AppError> {
-        let cluster = self.get_cluster(cluster_id, config).await?;
-        let client_config = self.build_client_config(&cluster);
-
-        // In a real implementation, use the admin client to get consumer group details
-        // This is a placeholder implementation
-        let group_details = serde_json::json!({
-            "group_id": group_id,
-            "is_simple": false,
-            "state": "Stable",
-            "members": [
-                {
-                    "id": "consumer-1-uuid",
-                    "client_id": "consumer-1",
-                    "client_host": "consumer-host-1.example.com",
-                    "assignments": [
-                        {"topic": "example-topic-1", "partition": 0},
-                        {"topic": "example-topic-1", "partition": 1}
-                    ]
-                }
-            ],
-            "offsets": [
-                {
-                    "topic": "example-topic-1",
-                    "partition": 0,
-                    "offset": 1000,
-                    "lag": 0
-                },
-                {
-                    "topic": "example-topic-1",
-                    "partition": 1,
-                    "offset": 850,
-                    "lag": 0
-                }
-            ]
-        });
-
-        Ok(group_details)
+        let group = self
+            .describe_consumer_group(cluster_id, group_id, config)
+            .await?;
+        Ok(serde_json::to_value(group)?)
     }
 
-    // Reset consumer group offsets
-    pub async fn reset_offsets(
+    /// Describe a consumer group using the live member assignments reported by the
+    /// group coordinator, together with committed offsets and lag for assigned topics.
+    pub async fn describe_consumer_group(
         &self,
         cluster_id: &str,
         group_id: &str,
-        offset_req: &OffsetReset,
         config: &crate::config::Config,
-    ) -> Result<serde_json::Value, AppError> {
+    ) -> Result<ConsumerGroup, AppError> {
         let cluster = self.get_cluster(cluster_id, config).await?;
-        let client_config = self.build_client_config(&cluster);
+        let consumer = self.create_group_consumer(&cluster, group_id)?;
+        let (state, is_simple, members) =
+            Self::load_group_members(&consumer, cluster_id, group_id)?;
 
-        // In a real implementation, use the admin client to reset consumer group offsets
-        // This is a placeholder implementation
-        let response = serde_json::json!({
-            "message": format!("Consumer group {} offsets reset successfully", group_id)
-        });
+        let mut topics: Vec<String> = members
+            .iter()
+            .flat_map(|m| m.assignments.iter().map(|a| a.topic.clone()))
+            .collect();
+        topics.sort();
+        topics.dedup();
 
-        Ok(response)
+        let offsets = Self::fetch_committed_offsets(&consumer, &topics)?;
+
+        Ok(ConsumerGroup {
+            group_id: group_id.to_string(),
+            is_simple,
+            state,
+            members,
+            offsets,
+        })
     }
 
-    // Batch message production for better throughput
-    pub async fn produce_batch(
+    /// Per-partition lag for one topic of a consumer group, sorted by lag descending so the
+    /// consumers that are falling behind surface first.
+    pub async fn get_consumer_group_lag_histogram(
         &self,
         cluster_id: &str,
+        group_id: &str,
         topic_name: &str,
-        messages: Vec<KafkaMessage>,
         config: &crate::config::Config,
-    ) -> Result<serde_json::Value, AppError> {
-        let start_time = Instant::now();
+    ) -> Result<Vec<PartitionLagBucket>, AppError> {
         let cluster = self.get_cluster(cluster_id, config).await?;
-        let mut client_config = self.build_client_config(&cluster);
-        client_config.set("client.id", "mayyam-batch-producer");
+        let consumer = self.create_group_consumer(&cluster, group_id)?;
+        let (_, _, members) = Self::load_group_members(&consumer, cluster_id, group_id)?;
 
-        let producer: FutureProducer = client_config.create().map_err(|e| {
-            AppError::ExternalService(format!("Failed to create Kafka producer: {}", e))
-        })?;
+        let owners: HashMap<i32, String> = members
+            .iter()
+            .flat_map(|m| {
+                m.assignments
+                    .iter()
+                    .filter(|a| a.topic == topic_name)
+                    .map(move |a| (a.partition, m.id.clone()))
+            })
+            .collect();
+
+        let offsets = Self::fetch_committed_offsets(&consumer, &[topic_name.to_string()])?;
+
+        let mut buckets: Vec<PartitionLagBucket> = offsets
+            .into_iter()
+            .map(|o| PartitionLagBucket {
+                partition: o.partition,
+                lag: o.lag,
+                consumer_id: owners.get(&o.partition).cloned(),
+                is_caught_up: o.lag == 0,
+            })
+            .collect();
+        buckets.sort_by(|a, b| b.lag.cmp(&a.lag).then(a.partition.cmp(&b.partition)));
+
+        Ok(buckets)
+    }
+
+    // Create a non-subscribing consumer bound to a group, used to inspect its state
+    fn create_group_consumer(
+        &self,
+        cluster: &KafkaClusterConfig,
+        group_id: &str,
+    ) -> Result<BaseConsumer, AppError> {
+        let mut client_config = self.build_client_config(cluster);
+        client_config.set("group.id", group_id);
+        client_config.set("client.id", "mayyam-group-inspector");
+        client_config.set("enable.auto.commit", "false");
+
+        client_config.create().map_err(|e| {
+            AppError::ExternalService(format!("Failed to create Kafka consumer: {}", e))
+        })
+    }
+
+    // Returns (state, is_simple, members) for the group as reported by its coordinator
+    fn load_group_members(
+        consumer: &BaseConsumer,
+        cluster_id: &str,
+        group_id: &str,
+    ) -> Result<(String, bool, Vec<ConsumerGroupMember>), AppError> {
+        let timeout = Duration::from_secs(10);
+        let group_list = consumer
+            .fetch_group_list(Some(group_id), timeout)
+            .map_err(|e| {
+                KAFKA_OPERATION_ERRORS
+                    .with_label_values(&[cluster_id, "describe_group"])
+                    .inc();
+                AppError::Kafka(format!(
+                    "Failed to describe consumer group '{}': {}",
+                    group_id, e
+                ))
+            })?;
+
+        let group = group_list
+            .groups()
+            .iter()
+            .find(|g| g.name() == group_id)
+            .ok_or_else(|| {
+                AppError::NotFound(format!("Consumer group '{}' not found", group_id))
+            })?;
+
+        let members = group
+            .members()
+            .iter()
+            .map(|m| ConsumerGroupMember {
+                id: m.id().to_string(),
+                client_id: m.client_id().to_string(),
+                client_host: m.client_host().to_string(),
+                assignments: m
+                    .assignment()
+                    .map(decode_member_assignment)
+                    .unwrap_or_default(),
+            })
+            .collect();
+
+        // Groups that only commit offsets (no group management) report an empty protocol type
+        Ok((
+            group.state().to_string(),
+            group.protocol_type().is_empty(),
+            members,
+        ))
+    }
+
+    // Committed offset and lag for every partition of the given topics
+    fn fetch_committed_offsets(
+        consumer: &BaseConsumer,
+        topics: &[String],
+    ) -> Result<Vec<ConsumerGroupOffset>, AppError> {
+        let timeout = Duration::from_secs(10);
+        let mut tpl = TopicPartitionList::new();
+
+        for topic in topics {
+            let metadata = consumer
+                .fetch_metadata(Some(topic), timeout)
+                .map_err(|e| {
+                    AppError::Kafka(format!(
+                        "Failed to fetch metadata for topic '{}': {}",
+                        topic, e
+                    ))
+                })?;
+            for t in metadata.topics() {
+                for p in t.partitions() {
+                    tpl.add_partition(t.name(), p.id());
+                }
+            }
+        }
+
+        if tpl.count() == 0 {
+            return Ok(Vec::new());
+        }
+
+        let committed = consumer.committed_offsets(tpl, timeout).map_err(|e| {
+            AppError::Kafka(format!("Failed to fetch committed offsets: {}", e))
+        })?;
+
+        let mut offsets = Vec::new();
+        for elem in committed.elements() {
+            let (low, high) = consumer
+                .fetch_watermarks(elem.topic(), elem.partition(), timeout)
+                .map_err(|e| {
+                    AppError::Kafka(format!(
+                        "Failed to fetch watermarks for {}/{}: {}",
+                        elem.topic(),
+                        elem.partition(),
+                        e
+                    ))
+                })?;
+
+            let (offset, lag) = match elem.offset() {
+                Offset::Offset(committed) => (committed, (high - committed).max(0)),
+                // Nothing committed yet: the whole retained log is outstanding
+                _ => (-1, (high - low).max(0)),
+            };
+
+            offsets.push(ConsumerGroupOffset {
+                topic: elem.topic().to_string(),
+                partition: elem.partition(),
+                offset,
+                lag,
+            });
+        }
+
+        Ok(offsets)
+    }
+
+    // Reset consumer group offsets on the listed partitions of one topic. `to_earliest` and
+    // `to_latest` win over explicit offsets; otherwise each partition's own offset is used,
+    // falling back to `to_offset`. Offsets outside the partition's watermarks are rejected.
+    pub async fn reset_offsets(
+        &self,
+        cluster_id: &str,
+        group_id: &str,
+        offset_req: &OffsetReset,
+        config: &crate::config::Config,
+        audit_ctx: &AuditContext,
+    ) -> Result<serde_json::Value, AppError> {
+        if offset_req.partitions.is_empty() {
+            return Err(AppError::Validation(
+                "At least one partition is required".to_string(),
+            ));
+        }
+        let timeout = Duration::from_secs(10);
+        let topic = offset_req.topic.as_str();
+        let cluster = self.get_cluster(cluster_id, config).await?;
+        let consumer = self.create_group_consumer(&cluster, group_id)?;
+
+        // The coordinator rejects commits from outside the generation of an active group
+        let (state, is_simple, _) = Self::load_group_members(&consumer, cluster_id, group_id)?;
+        if !is_simple && state != "Empty" {
+            return Err(AppError::Conflict(format!(
+                "Consumer group '{}' is {}; stop its consumers before resetting offsets",
+                group_id, state
+            )));
+        }
+
+        let mut tpl = TopicPartitionList::new();
+        let mut applied = Vec::with_capacity(offset_req.partitions.len());
+        for requested in &offset_req.partitions {
+            let partition = requested.partition;
+            let (low, high) = consumer
+                .fetch_watermarks(topic, partition, timeout)
+                .map_err(|e| {
+                    AppError::Kafka(format!(
+                        "Failed to fetch watermarks for {}/{}: {}",
+                        topic, partition, e
+                    ))
+                })?;
+            let offset = if offset_req.to_earliest == Some(true) {
+                low
+            } else if offset_req.to_latest == Some(true) {
+                high
+            } else {
+                let offset = requested.offset.or(offset_req.to_offset).ok_or_else(|| {
+                    AppError::Validation(format!(
+                        "No target offset given for partition {}",
+                        partition
+                    ))
+                })?;
+                if offset < low || offset > high {
+                    return Err(AppError::Validation(format!(
+                        "Offset {} for {}/{} is outside [{}, {}]",
+                        offset, topic, partition, low, high
+                    )));
+                }
+                offset
+            };
+            tpl.add_partition_offset(topic, partition, Offset::Offset(offset))
+                .map_err(|e| AppError::Kafka(e.to_string()))?;
+            applied.push(PartitionOffset {
+                partition,
+                offset: Some(offset),
+            });
+        }
+
+        consumer.commit(&tpl, CommitMode::Sync).map_err(|e| {
+            KAFKA_OPERATION_ERRORS
+                .with_label_values(&[cluster_id, "commit_offsets"])
+                .inc();
+            AppError::Kafka(format!(
+                "Failed to commit offsets for consumer group '{}': {}",
+                group_id, e
+            ))
+        })?;
+
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(
+                audit_ctx,
+                AuditOperation::RESET_OFFSETS,
+                "KafkaConsumerGroup",
+                group_id,
+            )
+            .with_payload(serde_json::json!({
+                "cluster_id": cluster_id,
+                "topic": topic,
+                "offsets": applied,
+            })),
+        )
+        .await;
+
+        info!(
+            "Reset offsets for consumer group {} on {}: {:?}",
+            group_id, topic, applied
+        );
+
+        Ok(serde_json::json!({
+            "message": format!("Consumer group {} offsets reset successfully", group_id),
+            "topic": topic,
+            "offsets": applied,
+        }))
+    }
+
+    // Commit starting offsets for partitions of a topic the group has never committed to,
+    // typically the ones added by a partition expansion.
+    pub async fn initialize_offsets_for_new_partitions(
+        &self,
+        cluster_id: &str,
+        group_id: &str,
+        topic_name: &str,
+        offset_strategy: OffsetStrategy,
+        config: &crate::config::Config,
+    ) -> Result<NewPartitionOffsetResult, AppError> {
+        let timeout = Duration::from_secs(10);
+        let cluster = self.get_cluster(cluster_id, config).await?;
+        let consumer = self.create_group_consumer(&cluster, group_id)?;
+
+        // The coordinator rejects commits from outside the generation of an active group
+        let (state, is_simple, _) = Self::load_group_members(&consumer, cluster_id, group_id)?;
+        if !is_simple && state != "Empty" {
+            return Err(AppError::Conflict(format!(
+                "Consumer group '{}' is {}; stop its consumers before initializing offsets",
+                group_id, state
+            )));
+        }
+
+        let offsets = Self::fetch_committed_offsets(&consumer, &[topic_name.to_string()])?;
+        if offsets.is_empty() {
+            return Err(AppError::NotFound(format!(
+                "Topic '{}' not found",
+                topic_name
+            )));
+        }
+
+        let (committed, missing): (Vec<_>, Vec<_>) =
+            offsets.into_iter().partition(|o| o.offset >= 0);
+        if committed.is_empty() {
+            return Err(AppError::Validation(format!(
+                "Consumer group '{}' has no committed offsets for topic '{}'",
+                group_id, topic_name
+            )));
+        }
+
+        let mut skipped_partitions: Vec<i32> = committed.iter().map(|o| o.partition).collect();
+        skipped_partitions.sort();
+        let mut initialized_partitions: Vec<i32> = missing.iter().map(|o| o.partition).collect();
+        initialized_partitions.sort();
+
+        if initialized_partitions.is_empty() {
+            return Ok(NewPartitionOffsetResult {
+                initialized_partitions,
+                skipped_partitions,
+            });
+        }
+
+        let mut by_time = HashMap::new();
+        if let OffsetStrategy::Timestamp(ts) = offset_strategy {
+            let mut lookup = TopicPartitionList::new();
+            for &partition in &initialized_partitions {
+                lookup
+                    .add_partition_offset(topic_name, partition, Offset::Offset(ts))
+                    .map_err(|e| AppError::Kafka(e.to_string()))?;
+            }
+            let found = consumer.offsets_for_times(lookup, timeout).map_err(|e| {
+                AppError::Kafka(format!(
+                    "Failed to look up offsets for topic '{}': {}",
+                    topic_name, e
+                ))
+            })?;
+            for elem in found.elements() {
+                if let Offset::Offset(offset) = elem.offset() {
+                    by_time.insert(elem.partition(), offset);
+                }
+            }
+        }
+
+        let mut tpl = TopicPartitionList::new();
+        for &partition in &initialized_partitions {
+            let (low, high) = consumer
+                .fetch_watermarks(topic_name, partition, timeout)
+                .map_err(|e| {
+                    AppError::Kafka(format!(
+                        "Failed to fetch watermarks for {}/{}: {}",
+                        topic_name, partition, e
+                    ))
+                })?;
+            let offset = match offset_strategy {
+                OffsetStrategy::Earliest => low,
+                OffsetStrategy::Latest => high,
+                // No message at or after the timestamp yet: start at the end
+                OffsetStrategy::Timestamp(_) => by_time.get(&partition).copied().unwrap_or(high),
+            };
+            tpl.add_partition_offset(topic_name, partition, Offset::Offset(offset))
+                .map_err(|e| AppError::Kafka(e.to_string()))?;
+        }
+
+        consumer.commit(&tpl, CommitMode::Sync).map_err(|e| {
+            KAFKA_OPERATION_ERRORS
+                .with_label_values(&[cluster_id, "commit_offsets"])
+                .inc();
+            AppError::Kafka(format!(
+                "Failed to commit offsets for consumer group '{}': {}",
+                group_id, e
+            ))
+        })?;
+
+        info!(
+            "Initialized offsets for consumer group {} on {} partitions {:?} using {:?}",
+            group_id, topic_name, initialized_partitions, offset_strategy
+        );
+
+        Ok(NewPartitionOffsetResult {
+            initialized_partitions,
+            skipped_partitions,
+        })
+    }
+
+    // Copy the committed offsets of one consumer group to another, so a new deployment
+    // picks up where the old group left off. Without a topic filter every topic the
+    // source group has committed to is copied.
+    pub async fn clone_consumer_group(
+        &self,
+        cluster_id: &str,
+        source_group_id: &str,
+        target_group_id: &str,
+        topic_filter: Option<Vec<String>>,
+        config: &crate::config::Config,
+    ) -> Result<CloneResult, AppError> {
+        if source_group_id == target_group_id {
+            return Err(AppError::BadRequest(
+                "Source and target consumer groups must differ".to_string(),
+            ));
+        }
+
+        let cluster = self.get_cluster(cluster_id, config).await?;
+        let source = self.create_group_consumer(&cluster, source_group_id)?;
+        let target = self.create_group_consumer(&cluster, target_group_id)?;
+
+        // Commits from outside the group would be rejected or overwritten by its members
+        match Self::load_group_members(&target, cluster_id, target_group_id) {
+            Ok((state, _, members)) if !members.is_empty() => {
+                return Err(AppError::Conflict(format!(
+                    "Consumer group '{}' is {} with {} active members; stop its consumers before cloning",
+                    target_group_id,
+                    state,
+                    members.len()
+                )));
+            }
+            Ok(_) | Err(AppError::NotFound(_)) => {}
+            Err(e) => return Err(e),
+        }
+
+        let topics = match &topic_filter {
+            Some(topics) => topics.clone(),
+            None => {
+                let metadata = source
+                    .fetch_metadata(None, Duration::from_secs(10))
+                    .map_err(|e| AppError::Kafka(format!("Failed to fetch metadata: {}", e)))?;
+                metadata
+                    .topics()
+                    .iter()
+                    .map(|t| t.name().to_string())
+                    .filter(|name| !name.starts_with("__"))
+                    .collect()
+            }
+        };
+
+        let committed: Vec<ConsumerGroupOffset> = Self::fetch_committed_offsets(&source, &topics)?
+            .into_iter()
+            .filter(|o| o.offset >= 0)
+            .collect();
+
+        let mut tpl = TopicPartitionList::new();
+        for o in &committed {
+            tpl.add_partition_offset(&o.topic, o.partition, Offset::Offset(o.offset))
+                .map_err(|e| AppError::Kafka(e.to_string()))?;
+        }
+
+        let mut topics_cloned: Vec<String> = committed.iter().map(|o| o.topic.clone()).collect();
+        topics_cloned.sort();
+        topics_cloned.dedup();
+        let mut skipped_topics: Vec<String> = match &topic_filter {
+            Some(topics) => topics
+                .iter()
+                .filter(|t| !topics_cloned.contains(t))
+                .cloned()
+                .collect(),
+            None => Vec::new(),
+        };
+        skipped_topics.sort();
+
+        if tpl.count() > 0 {
+            target.commit(&tpl, CommitMode::Sync).map_err(|e| {
+                KAFKA_OPERATION_ERRORS
+                    .with_label_values(&[cluster_id, "commit_offsets"])
+                    .inc();
+                AppError::Kafka(format!(
+                    "Failed to commit offsets for consumer group '{}': {}",
+                    target_group_id, e
+                ))
+            })?;
+        }
+
+        info!(
+            "Cloned {} partition offsets of consumer group {} to {} ({} topics)",
+            committed.len(),
+            source_group_id,
+            target_group_id,
+            topics_cloned.len()
+        );
+
+        Ok(CloneResult {
+            topics_cloned,
+            partitions_cloned: committed.len() as u32,
+            skipped_topics,
+        })
+    }
+
+    // Partition layout and topic-level config overrides, enough to recreate the topic elsewhere
+    pub async fn export_topic_definition(
+        &self,
+        cluster_id: &str,
+        topic_name: &str,
+        config: &crate::config::Config,
+    ) -> Result<TopicDefinition, AppError> {
+        let cluster = self.get_cluster(cluster_id, config).await?;
+        let client_config = self.build_client_config(&cluster);
+        let admin: AdminClient<_> = client_config.create().map_err(|e| {
+            AppError::ExternalService(format!("Failed to create Kafka admin client: {}", e))
+        })?;
+
+        let timeout = Duration::from_secs(30);
+        let metadata = admin
+            .inner()
+            .fetch_metadata(Some(topic_name), timeout)
+            .map_err(|e| {
+                KAFKA_OPERATION_ERRORS
+                    .with_label_values(&[cluster_id, "export_topic"])
+                    .inc();
+                AppError::ExternalService(format!("Failed to fetch topic metadata: {}", e))
+            })?;
+        let topic = metadata
+            .topics()
+            .iter()
+            .find(|t| t.name() == topic_name && t.error().is_none())
+            .ok_or_else(|| AppError::NotFound(format!("Topic {} not found", topic_name)))?;
+        let replication_factor = topic
+            .partitions()
+            .first()
+            .map(|p| p.replicas().len())
+            .unwrap_or(1) as i16;
+
+        let resource = describe_topic_config(&admin, topic_name).await?;
+        let configs = topic_overrides(&resource);
+
+        Ok(TopicDefinition {
+            name: topic_name.to_string(),
+            partition_count: topic.partitions().len() as i32,
+            replication_factor,
+            configs,
+        })
+    }
+
+    // Returns false when the topic already existed and exists_ok allowed skipping it
+    pub async fn import_topic_definition(
+        &self,
+        cluster_id: &str,
+        definition: &TopicDefinition,
+        exists_ok: bool,
+        config: &crate::config::Config,
+    ) -> Result<bool, AppError> {
+        if definition.partition_count <= 0 || definition.replication_factor <= 0 {
+            return Err(AppError::BadRequest(format!(
+                "Topic {} needs a positive partition count and replication factor",
+                definition.name
+            )));
+        }
+
+        let cluster = self.get_cluster(cluster_id, config).await?;
+        let client_config = self.build_client_config(&cluster);
+        let admin: AdminClient<_> = client_config.create().map_err(|e| {
+            AppError::ExternalService(format!("Failed to create Kafka admin client: {}", e))
+        })?;
+
+        let mut new_topic = NewTopic::new(
+            &definition.name,
+            definition.partition_count,
+            TopicReplication::Fixed(definition.replication_factor as i32),
+        );
+        for (key, value) in &definition.configs {
+            new_topic = new_topic.set(key, value);
+        }
+
+        let opts = AdminOptions::new().operation_timeout(Some(Duration::from_secs(10)));
+        let results = admin
+            .create_topics(vec![&new_topic], &opts)
+            .await
+            .map_err(|e| {
+                KAFKA_OPERATION_ERRORS
+                    .with_label_values(&[cluster_id, "import_topic"])
+                    .inc();
+                AppError::ExternalService(format!("Failed to execute create topics request: {}", e))
+            })?;
+
+        if let Some(Err((topic, err))) = results.first() {
+            if *err != rdkafka::types::RDKafkaErrorCode::TopicAlreadyExists {
+                KAFKA_OPERATION_ERRORS
+                    .with_label_values(&[cluster_id, "import_topic"])
+                    .inc();
+                return Err(AppError::ExternalService(format!(
+                    "Failed to create topic {}: {:?}",
+                    topic, err
+                )));
+            }
+            if !exists_ok {
+                return Err(AppError::Conflict(format!(
+                    "Topic {} already exists",
+                    topic
+                )));
+            }
+            info!(
+                "Topic {} already exists on cluster {}, skipping import",
+                topic, cluster_id
+            );
+            return Ok(false);
+        }
+
+        info!(
+            "Imported topic {} into cluster {} with {} config overrides",
+            definition.name,
+            cluster_id,
+            definition.configs.len()
+        );
+        Ok(true)
+    }
+
+    // Removes messages older than max_age. Topics with the delete policy get a temporary
+    // retention.ms override that is reverted once the broker has dropped the expired
+    // segments; compact-only topics get a tombstone for every key whose latest record is
+    // older than the cutoff. With dry_run only the estimate is computed. Purges of the
+    // same topic are serialized by the distributed lock.
+    pub async fn purge_messages_older_than(
+        &self,
+        cluster_id: &str,
+        topic_name: &str,
+        max_age: Duration,
+        dry_run: bool,
+        config: &crate::config::Config,
+    ) -> Result<PurgeResult, AppError> {
+        if max_age.is_zero() {
+            return Err(AppError::BadRequest(
+                "max_age must be greater than zero".to_string(),
+            ));
+        }
+        let started = Instant::now();
+
+        let cluster = self.get_cluster(cluster_id, config).await?;
+        let mut client_config = self.build_client_config(&cluster);
+        client_config.set("client.id", "mayyam-purge");
+        client_config.set("group.id", &format!("mayyam-purge-{}", Uuid::new_v4()));
+        client_config.set("enable.auto.commit", "false");
+        let consumer: StreamConsumer = client_config.create().map_err(|e| {
+            AppError::ExternalService(format!("Failed to create Kafka consumer: {}", e))
+        })?;
+
+        let timeout = Duration::from_secs(10);
+        let metadata = consumer
+            .fetch_metadata(Some(topic_name), timeout)
+            .map_err(|e| AppError::Kafka(format!("Failed to fetch topic metadata: {}", e)))?;
+        let topic = metadata
+            .topics()
+            .iter()
+            .find(|t| t.name() == topic_name && t.error().is_none() && !t.partitions().is_empty())
+            .ok_or_else(|| AppError::NotFound(format!("Topic {} not found", topic_name)))?;
+
+        let cutoff_ms = chrono::Utc::now().timestamp_millis() - max_age.as_millis() as i64;
+        let mut lookup = TopicPartitionList::new();
+        for p in topic.partitions() {
+            lookup
+                .add_partition_offset(topic_name, p.id(), Offset::Offset(cutoff_ms))
+                .map_err(|e| AppError::Kafka(e.to_string()))?;
+        }
+        let found = consumer.offsets_for_times(lookup, timeout).map_err(|e| {
+            AppError::Kafka(format!(
+                "Failed to look up offsets for topic '{}': {}",
+                topic_name, e
+            ))
+        })?;
+
+        let mut expired = Vec::new();
+        for p in topic.partitions() {
+            let (low, high) = consumer
+                .fetch_watermarks(topic_name, p.id(), timeout)
+                .map_err(|e| {
+                    AppError::Kafka(format!(
+                        "Failed to fetch watermarks for {}/{}: {}",
+                        topic_name,
+                        p.id(),
+                        e
+                    ))
+                })?;
+            let cutoff = match found.find_partition(topic_name, p.id()).map(|e| e.offset()) {
+                Some(Offset::Offset(offset)) => offset.clamp(low, high),
+                // No message at or after the cutoff time: the whole partition is expired
+                _ => high,
+            };
+            if cutoff > low {
+                expired.push(ExpiredRange {
+                    partition: p.id(),
+                    low,
+                    cutoff,
+                    high,
+                });
+            }
+        }
+
+        let partitions_affected: Vec<i32> = expired.iter().map(|r| r.partition).collect();
+        let estimate: u64 = expired.iter().map(|r| (r.cutoff - r.low) as u64).sum();
+        if dry_run || expired.is_empty() {
+            return Ok(PurgeResult {
+                estimated_messages_purged: estimate,
+                partitions_affected,
+                duration_ms: started.elapsed().as_millis() as u64,
+            });
+        }
+
+        let guard = self.lock(&purge_lock_key(cluster_id, topic_name)).await?;
+        let admin: AdminClient<_> = self.build_client_config(&cluster).create().map_err(|e| {
+            AppError::ExternalService(format!("Failed to create Kafka admin client: {}", e))
+        })?;
+        let resource = describe_topic_config(&admin, topic_name).await?;
+        let cleanup_policy = resource
+            .get("cleanup.policy")
+            .and_then(|entry| entry.value.clone())
+            .unwrap_or_else(|| "delete".to_string());
+
+        let purged = if cleanup_policy.split(',').any(|p| p.trim() == "delete") {
+            self.purge_with_retention_override(
+                cluster_id,
+                topic_name,
+                max_age,
+                topic_overrides(&resource),
+                admin,
+                consumer,
+                expired,
+                guard,
+            )
+            .await?
+        } else {
+            self.tombstone_expired_keys(&cluster, &consumer, topic_name, &expired)
+                .await?
+        };
+
+        info!(
+            "Purged about {} messages older than {}ms from {}/{} ({} policy)",
+            purged,
+            max_age.as_millis(),
+            cluster_id,
+            topic_name,
+            cleanup_policy
+        );
+        Ok(PurgeResult {
+            estimated_messages_purged: purged,
+            partitions_affected,
+            duration_ms: started.elapsed().as_millis() as u64,
+        })
+    }
+
+    // The override and its restore run in a detached task that owns the purge lock, so a
+    // dropped request cannot leave the topic on the short retention. The original
+    // overrides are persisted first and re-applied at startup if the process dies.
+    #[allow(clippy::too_many_arguments)]
+    async fn purge_with_retention_override(
+        &self,
+        cluster_id: &str,
+        topic_name: &str,
+        max_age: Duration,
+        current_overrides: HashMap<String, String>,
+        admin: AdminClient<DefaultClientContext>,
+        consumer: StreamConsumer,
+        expired: Vec<ExpiredRange>,
+        guard: Option<LockGuard>,
+    ) -> Result<u64, AppError> {
+        // An interrupted earlier purge may have left its shortened retention in place
+        let pending = match &self.config_restores {
+            Some(repo) => repo.find_pending_for_topic(cluster_id, topic_name).await?,
+            None => None,
+        };
+        let original = match &pending {
+            Some(restore) => restore.original_configs.clone(),
+            None => current_overrides,
+        };
+        let restore_id = match (&self.config_restores, &pending) {
+            (Some(_), Some(restore)) => Some(restore.id),
+            (Some(repo), None) => Some(repo.record(cluster_id, topic_name, &original).await?),
+            (None, _) => None,
+        };
+        let mut purge_configs = original.clone();
+        purge_configs.insert("retention.ms".to_string(), max_age.as_millis().to_string());
+
+        let restores = self.config_restores.clone();
+        let topic = topic_name.to_string();
+        let task = tokio::spawn(async move {
+            let _guard = guard;
+            alter_topic_config(&admin, &topic, &purge_configs).await?;
+            let purged = Self::wait_for_retention_cleanup(&consumer, &topic, &expired).await;
+            // Restore the original overrides even if the broker has not caught up yet
+            alter_topic_config(&admin, &topic, &original).await?;
+            if let (Some(repo), Some(id)) = (restores, restore_id) {
+                if let Err(e) = repo.mark_restored(id).await {
+                    warn!(
+                        "Failed to mark config of topic {} as restored: {}",
+                        topic, e
+                    );
+                }
+            }
+            purged
+        });
+        task.await
+            .map_err(|e| AppError::Internal(format!("Purge task failed: {}", e)))?
+    }
+
+    // Polls low watermarks until every expired range is gone or PURGE_MAX_WAIT passes.
+    // Retention only drops whole closed segments, so some expired messages may remain.
+    async fn wait_for_retention_cleanup(
+        consumer: &StreamConsumer,
+        topic_name: &str,
+        expired: &[ExpiredRange],
+    ) -> Result<u64, AppError> {
+        let timeout = Duration::from_secs(10);
+        let deadline = Instant::now() + PURGE_MAX_WAIT;
+        loop {
+            tokio::time::sleep(PURGE_POLL_INTERVAL).await;
+
+            let mut purged = 0u64;
+            let mut done = true;
+            for range in expired {
+                let (low, _) = consumer
+                    .fetch_watermarks(topic_name, range.partition, timeout)
+                    .map_err(|e| {
+                        AppError::Kafka(format!(
+                            "Failed to fetch watermarks for {}/{}: {}",
+                            topic_name, range.partition, e
+                        ))
+                    })?;
+                purged += (low.min(range.cutoff) - range.low).max(0) as u64;
+                done &= low >= range.cutoff;
+            }
+
+            if done {
+                return Ok(purged);
+            }
+            if Instant::now() >= deadline {
+                warn!(
+                    "Retention cleanup of {} incomplete after {}s; expired messages remain in open segments",
+                    topic_name,
+                    PURGE_MAX_WAIT.as_secs()
+                );
+                return Ok(purged);
+            }
+        }
+    }
+
+    // Reads each expired partition to its end and tombstones keys last written before the
+    // cutoff. Partitions that cannot be read to the end in time are left untouched, since
+    // a newer record for a key may not have been seen yet.
+    async fn tombstone_expired_keys(
+        &self,
+        cluster: &KafkaClusterConfig,
+        consumer: &StreamConsumer,
+        topic_name: &str,
+        expired: &[ExpiredRange],
+    ) -> Result<u64, AppError> {
+        let mut tpl = TopicPartitionList::new();
+        let mut end_offsets = HashMap::new();
+        for range in expired {
+            tpl.add_partition_offset(topic_name, range.partition, Offset::Offset(range.low))
+                .map_err(|e| AppError::Kafka(format!("Failed to seek consumer: {}", e)))?;
+            end_offsets.insert(range.partition, range.high);
+        }
+        consumer
+            .assign(&tpl)
+            .map_err(|e| AppError::Kafka(format!("Failed to assign partitions: {}", e)))?;
+
+        // Latest offset per key and whether that record is already a tombstone
+        let mut latest: HashMap<(i32, Vec<u8>), (i64, bool)> = HashMap::new();
+        let mut completed = Vec::new();
+        let deadline = Instant::now() + PURGE_MAX_WAIT;
+        while !end_offsets.is_empty() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+            let message = match tokio::time::timeout(remaining, consumer.recv()).await {
+                Ok(Ok(message)) => message,
+                Ok(Err(e)) => {
+                    return Err(AppError::Kafka(format!("Failed to read messages: {}", e)))
+                }
+                Err(_) => break,
+            };
+
+            let partition = message.partition();
+            let end = match end_offsets.get(&partition) {
+                Some(&end) => end,
+                None => continue,
+            };
+            if message.offset() + 1 >= end {
+                end_offsets.remove(&partition);
+                completed.push(partition);
+            }
+            if message.offset() >= end {
+                continue;
+            }
+            if let Some(key) = message.key() {
+                latest.insert(
+                    (partition, key.to_vec()),
+                    (message.offset(), message.payload().is_none()),
+                );
+            }
+        }
+        if !end_offsets.is_empty() {
+            warn!(
+                "Skipping tombstones for {}: partitions {:?} not fully read within {}s",
+                topic_name,
+                end_offsets.keys().collect::<Vec<_>>(),
+                PURGE_MAX_WAIT.as_secs()
+            );
+        }
+
+        let cutoffs: HashMap<i32, i64> = expired.iter().map(|r| (r.partition, r.cutoff)).collect();
+        let mut client_config = self.build_client_config(cluster);
+        client_config.set("client.id", "mayyam-purge");
+        let producer: FutureProducer = client_config.create().map_err(|e| {
+            AppError::ExternalService(format!("Failed to create Kafka producer: {}", e))
+        })?;
+
+        let mut tombstones = 0u64;
+        for ((partition, key), (offset, is_tombstone)) in latest {
+            if is_tombstone || !completed.contains(&partition) || offset >= cutoffs[&partition] {
+                continue;
+            }
+            let record = FutureRecord::<[u8], [u8]>::to(topic_name)
+                .key(key.as_slice())
+                .partition(partition);
+            producer
+                .send(record, Duration::from_secs(10))
+                .await
+                .map_err(|(e, _)| {
+                    AppError::ExternalService(format!("Failed to send tombstone: {}", e))
+                })?;
+            tombstones += 1;
+        }
+        Ok(tombstones)
+    }
+
+    // Batch message production for better throughput
+    pub async fn produce_batch(
+        &self,
+        cluster_id: &str,
+        topic_name: &str,
+        messages: Vec<KafkaMessage>,
+        config: &crate::config::Config,
+    ) -> Result<serde_json::Value, AppError> {
+        let start_time = Instant::now();
+        let cluster = self.get_cluster(cluster_id, config).await?;
+        let mut client_config = self.build_client_config(&cluster);
+        client_config.set("client.id", "mayyam-batch-producer");
+
+        let producer: FutureProducer = client_config.create().map_err(|e| {
+            AppError::ExternalService(format!("Failed to create Kafka producer: {}", e))
+        })?;
 
         let mut send_futures = Vec::new();
         let mut total_size = 0;
@@ -1765,21 +3189,530 @@ impl KafkaService {
                 AppError::ExternalService(format!("Failed to fetch cluster metadata: {:?}", e))
             })?;
 
-        let brokers = metadata
-            .brokers()
-            .iter()
-            .map(|broker| {
-                serde_json::json!({
-                    "id": broker.id(),
-                    "host": broker.host(),
-                    "port": broker.port(),
-                    "is_controller": false, // Would need additional API call to determine
-                    "rack": null
-                })
-            })
-            .collect::<Vec<_>>();
+        let brokers = metadata
+            .brokers()
+            .iter()
+            .map(|broker| {
+                serde_json::json!({
+                    "id": broker.id(),
+                    "host": broker.host(),
+                    "port": broker.port(),
+                    "is_controller": false, // Would need additional API call to determine
+                    "rack": null
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(brokers)
+    }
+
+    // Aggregate capacity view of a cluster: throughput over the last hour from offsets,
+    // disk usage from retained messages, sampled message sizes and replication factor.
+    #[tracing::instrument(skip(self, config), fields(cluster_id = %cluster_id))]
+    pub async fn generate_capacity_report(
+        &self,
+        cluster_id: &str,
+        config: &crate::config::Config,
+    ) -> Result<KafkaCapacityReport, AppError> {
+        let cluster = self.get_cluster(cluster_id, config).await?;
+        let mut client_config = self.build_client_config(&cluster);
+        client_config.set("client.id", "mayyam-capacity-report");
+        client_config.set("group.id", "mayyam-capacity-report");
+        client_config.set("enable.auto.commit", "false");
+
+        let consumer: BaseConsumer = client_config.create().map_err(|e| {
+            AppError::ExternalService(format!("Failed to create Kafka consumer: {}", e))
+        })?;
+        let admin: AdminClient<_> = self.build_client_config(&cluster).create().map_err(|e| {
+            AppError::ExternalService(format!("Failed to create Kafka admin client: {}", e))
+        })?;
+
+        let timeout = Duration::from_secs(10);
+        let metadata = consumer.fetch_metadata(None, timeout).map_err(|e| {
+            KAFKA_OPERATION_ERRORS
+                .with_label_values(&[cluster_id, "capacity_report"])
+                .inc();
+            AppError::Kafka(format!("Failed to fetch cluster metadata: {}", e))
+        })?;
+
+        let hour_ago_ms = chrono::Utc::now().timestamp_millis() - 3_600_000;
+        let mut topics = Vec::new();
+
+        for topic in metadata
+            .topics()
+            .iter()
+            .filter(|t| !t.name().starts_with("__"))
+        {
+            let mut since_hour_ago = TopicPartitionList::new();
+            for p in topic.partitions() {
+                since_hour_ago
+                    .add_partition_offset(topic.name(), p.id(), Offset::Offset(hour_ago_ms))
+                    .map_err(|e| AppError::Kafka(e.to_string()))?;
+            }
+            let hour_ago_offsets = consumer
+                .offsets_for_times(since_hour_ago, timeout)
+                .map_err(|e| {
+                    AppError::Kafka(format!(
+                        "Failed to look up offsets for topic '{}': {}",
+                        topic.name(),
+                        e
+                    ))
+                })?;
+
+            let mut messages_last_hour = 0i64;
+            let mut retained_per_partition = Vec::new();
+            let mut busiest: Option<(i32, i64, i64)> = None;
+
+            for p in topic.partitions() {
+                let (low, high) = consumer
+                    .fetch_watermarks(topic.name(), p.id(), timeout)
+                    .map_err(|e| {
+                        AppError::Kafka(format!(
+                            "Failed to fetch watermarks for {}/{}: {}",
+                            topic.name(),
+                            p.id(),
+                            e
+                        ))
+                    })?;
+
+                // No offset at the timestamp means nothing was written in the last hour
+                let hour_ago = match hour_ago_offsets
+                    .find_partition(topic.name(), p.id())
+                    .map(|e| e.offset())
+                {
+                    Some(Offset::Offset(o)) => o.max(low),
+                    _ => high,
+                };
+                messages_last_hour += (high - hour_ago).max(0);
+
+                let retained = (high - low).max(0);
+                retained_per_partition.push(retained);
+                if retained > busiest.map(|(_, l, h)| h - l).unwrap_or(0) {
+                    busiest = Some((p.id(), low, high));
+                }
+            }
+
+            let avg_message_size = busiest
+                .and_then(|(partition, low, high)| {
+                    Self::sample_message_size(&consumer, topic.name(), partition, low, high)
+                })
+                .unwrap_or(DEFAULT_MESSAGE_SIZE_BYTES);
+
+            let replication_factor = topic
+                .partitions()
+                .first()
+                .map(|p| p.replicas().len())
+                .unwrap_or(1)
+                .max(1);
+            let retained_messages: i64 = retained_per_partition.iter().sum();
+            let largest_retained = retained_per_partition.iter().copied().max().unwrap_or(0);
+
+            topics.push(TopicCapacity {
+                name: topic.name().to_string(),
+                partitions: topic.partitions().len(),
+                replication_factor,
+                messages_last_hour,
+                estimated_disk_usage_bytes: (retained_messages as f64
+                    * avg_message_size
+                    * replication_factor as f64) as u64,
+                retention_bytes: None,
+                largest_partition_bytes: (largest_retained as f64 * avg_message_size) as u64,
+            });
+        }
+
+        let opts = AdminOptions::new().request_timeout(Some(timeout));
+        let specifiers: Vec<ResourceSpecifier> = topics
+            .iter()
+            .map(|t| ResourceSpecifier::Topic(&t.name))
+            .collect();
+        let mut retention_by_topic = HashMap::new();
+        match admin.describe_configs(&specifiers, &opts).await {
+            Ok(results) => {
+                for resource in results.into_iter().flatten() {
+                    let retention = resource
+                        .get("retention.bytes")
+                        .and_then(|entry| entry.value.as_deref())
+                        .and_then(|v| v.parse::<i64>().ok());
+                    if let OwnedResourceSpecifier::Topic(name) = resource.specifier {
+                        retention_by_topic.insert(name, retention);
+                    }
+                }
+            }
+            Err(e) => warn!("Failed to describe topic configs for capacity report: {}", e),
+        }
+        for topic in topics.iter_mut() {
+            topic.retention_bytes = retention_by_topic.get(&topic.name).copied().flatten();
+        }
+
+        Ok(build_capacity_report(metadata.brokers().len(), &topics))
+    }
+
+    // Low/high watermarks of every partition of every non-internal topic
+    pub async fn fetch_partition_watermarks(
+        &self,
+        cluster_id: &str,
+        config: &crate::config::Config,
+    ) -> Result<Vec<PartitionWatermarks>, AppError> {
+        let cluster = self.get_cluster(cluster_id, config).await?;
+        let mut client_config = self.build_client_config(&cluster);
+        client_config.set("client.id", "mayyam-topic-monitor");
+        client_config.set("group.id", "mayyam-topic-monitor");
+        client_config.set("enable.auto.commit", "false");
+        let consumer: BaseConsumer = client_config.create().map_err(|e| {
+            AppError::ExternalService(format!("Failed to create Kafka consumer: {}", e))
+        })?;
+
+        let timeout = Duration::from_secs(10);
+        let metadata = consumer
+            .fetch_metadata(None, timeout)
+            .map_err(|e| AppError::Kafka(format!("Failed to fetch cluster metadata: {}", e)))?;
+
+        let mut watermarks = Vec::new();
+        for topic in metadata
+            .topics()
+            .iter()
+            .filter(|t| !t.name().starts_with("__"))
+        {
+            for p in topic.partitions() {
+                let (low, high) = consumer
+                    .fetch_watermarks(topic.name(), p.id(), timeout)
+                    .map_err(|e| {
+                        AppError::Kafka(format!(
+                            "Failed to fetch watermarks for {}/{}: {}",
+                            topic.name(),
+                            p.id(),
+                            e
+                        ))
+                    })?;
+                watermarks.push(PartitionWatermarks {
+                    topic: topic.name().to_string(),
+                    partition: p.id(),
+                    offsets: PartitionOffsets {
+                        earliest: low,
+                        latest: high,
+                    },
+                });
+            }
+        }
+        Ok(watermarks)
+    }
+
+    // Compares high watermarks of a topic on a source cluster with its mirror on a
+    // target cluster. Offsets are not preserved across clusters by MirrorMaker 2, so
+    // the per-partition difference is an approximation of messages not yet replicated.
+    pub async fn get_replication_lag(
+        &self,
+        source_cluster_id: &str,
+        target_cluster_id: &str,
+        topic_name: &str,
+        config: &crate::config::Config,
+    ) -> Result<ReplicationLagReport, AppError> {
+        if source_cluster_id == target_cluster_id {
+            return Err(AppError::BadRequest(
+                "Source and target clusters must differ".to_string(),
+            ));
+        }
+        let replication = &config.kafka.replication;
+        let target_topic = format!(
+            "{}{}",
+            replication.target_topic_prefix.as_deref().unwrap_or(""),
+            topic_name
+        );
+
+        let source_cluster = self.get_cluster(source_cluster_id, config).await?;
+        let target_cluster = self.get_cluster(target_cluster_id, config).await?;
+        let source_latest = self
+            .fetch_topic_high_watermarks(&source_cluster, topic_name)?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "Topic {} not found on cluster {}",
+                    topic_name, source_cluster_id
+                ))
+            })?;
+        let target_latest = self
+            .fetch_topic_high_watermarks(&target_cluster, &target_topic)?
+            .unwrap_or_default();
+        if target_latest.is_empty() {
+            warn!(
+                "Replica topic {} not found on cluster {}",
+                target_topic, target_cluster_id
+            );
+        }
+
+        let mut partitions: Vec<PartitionReplicationLag> = source_latest
+            .iter()
+            .map(|(&partition, &source)| {
+                let target = target_latest.get(&partition).copied().unwrap_or(0);
+                let lag = (source - target).max(0);
+                PartitionReplicationLag {
+                    partition,
+                    source_latest: source,
+                    target_latest: target,
+                    lag,
+                    exceeds_threshold: lag > replication.max_acceptable_lag_messages
+                        || !target_latest.contains_key(&partition),
+                }
+            })
+            .collect();
+        partitions.sort_by_key(|p| p.partition);
+
+        Ok(ReplicationLagReport {
+            topic: topic_name.to_string(),
+            target_topic,
+            total_lag: partitions.iter().map(|p| p.lag).sum(),
+            is_healthy: !partitions.iter().any(|p| p.exceeds_threshold),
+            partitions,
+            last_checked: chrono::Utc::now(),
+        })
+    }
+
+    // High watermark per partition, or None if the topic does not exist on the cluster
+    fn fetch_topic_high_watermarks(
+        &self,
+        cluster: &KafkaClusterConfig,
+        topic_name: &str,
+    ) -> Result<Option<HashMap<i32, i64>>, AppError> {
+        let mut client_config = self.build_client_config(cluster);
+        client_config.set("client.id", "mayyam-replication-monitor");
+        client_config.set("group.id", "mayyam-replication-monitor");
+        client_config.set("enable.auto.commit", "false");
+        let consumer: BaseConsumer = client_config.create().map_err(|e| {
+            AppError::ExternalService(format!("Failed to create Kafka consumer: {}", e))
+        })?;
+
+        let timeout = Duration::from_secs(10);
+        let metadata = consumer
+            .fetch_metadata(Some(topic_name), timeout)
+            .map_err(|e| AppError::Kafka(format!("Failed to fetch topic metadata: {}", e)))?;
+        let topic = match metadata.topics().iter().find(|t| t.name() == topic_name) {
+            Some(t) if t.error().is_none() && !t.partitions().is_empty() => t,
+            _ => return Ok(None),
+        };
+
+        let mut watermarks = HashMap::new();
+        for p in topic.partitions() {
+            let (_, high) = consumer
+                .fetch_watermarks(topic_name, p.id(), timeout)
+                .map_err(|e| {
+                    AppError::Kafka(format!(
+                        "Failed to fetch watermarks for {}/{}: {}",
+                        topic_name,
+                        p.id(),
+                        e
+                    ))
+                })?;
+            watermarks.insert(p.id(), high);
+        }
+        Ok(Some(watermarks))
+    }
+
+    // Average message size of a topic, sampled from its fullest partition
+    pub async fn estimate_message_size(
+        &self,
+        cluster_id: &str,
+        topic_name: &str,
+        config: &crate::config::Config,
+    ) -> Result<f64, AppError> {
+        let cluster = self.get_cluster(cluster_id, config).await?;
+        let mut client_config = self.build_client_config(&cluster);
+        client_config.set("client.id", "mayyam-topic-monitor");
+        client_config.set("group.id", "mayyam-topic-monitor");
+        client_config.set("enable.auto.commit", "false");
+        let consumer: BaseConsumer = client_config.create().map_err(|e| {
+            AppError::ExternalService(format!("Failed to create Kafka consumer: {}", e))
+        })?;
+
+        let timeout = Duration::from_secs(10);
+        let metadata = consumer
+            .fetch_metadata(Some(topic_name), timeout)
+            .map_err(|e| AppError::Kafka(format!("Failed to fetch topic metadata: {}", e)))?;
+        let mut fullest: Option<(i32, i64, i64)> = None;
+        for topic in metadata.topics() {
+            for p in topic.partitions() {
+                if let Ok((low, high)) = consumer.fetch_watermarks(topic_name, p.id(), timeout) {
+                    if high - low > fullest.map(|(_, l, h)| h - l).unwrap_or(0) {
+                        fullest = Some((p.id(), low, high));
+                    }
+                }
+            }
+        }
+
+        Ok(fullest
+            .and_then(|(partition, low, high)| {
+                Self::sample_message_size(&consumer, topic_name, partition, low, high)
+            })
+            .unwrap_or(DEFAULT_MESSAGE_SIZE_BYTES))
+    }
+
+    // Average key + payload size over the most recent messages of a partition
+    fn sample_message_size(
+        consumer: &BaseConsumer,
+        topic: &str,
+        partition: i32,
+        low: i64,
+        high: i64,
+    ) -> Option<f64> {
+        let start = (high - CAPACITY_SAMPLE_MESSAGES).max(low);
+        let mut tpl = TopicPartitionList::new();
+        tpl.add_partition_offset(topic, partition, Offset::Offset(start)).ok()?;
+        consumer.assign(&tpl).ok()?;
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let (mut total, mut count) = (0usize, 0usize);
+        while count < (high - start) as usize && Instant::now() < deadline {
+            match consumer.poll(Duration::from_millis(500)) {
+                Some(Ok(msg)) => {
+                    total += msg.payload().map(|p| p.len()).unwrap_or(0)
+                        + msg.key().map(|k| k.len()).unwrap_or(0);
+                    count += 1;
+                }
+                Some(Err(e)) => {
+                    warn!("Failed to sample messages from {}/{}: {}", topic, partition, e);
+                    break;
+                }
+                None => {}
+            }
+        }
+        let _ = consumer.unassign();
+
+        if count == 0 {
+            None
+        } else {
+            Some(total as f64 / count as f64)
+        }
+    }
+
+    // Spreads the replicas of the selected topics (all non-internal topics by
+    // default) evenly over target_broker_ids, keeping existing placements where
+    // the balance allows so that as little data as possible has to move.
+    #[tracing::instrument(skip(self, config), fields(cluster_id = %cluster_id))]
+    pub async fn generate_reassignment_plan(
+        &self,
+        cluster_id: &str,
+        topics: Option<Vec<String>>,
+        target_broker_ids: Vec<i32>,
+        config: &crate::config::Config,
+    ) -> Result<ReassignmentPlan, AppError> {
+        let mut targets = target_broker_ids;
+        targets.sort_unstable();
+        targets.dedup();
+        if targets.is_empty() {
+            return Err(AppError::Validation(
+                "At least one target broker is required".to_string(),
+            ));
+        }
+
+        let cluster = self.get_cluster(cluster_id, config).await?;
+        let mut client_config = self.build_client_config(&cluster);
+        client_config.set("client.id", "mayyam-reassignment");
+        client_config.set("group.id", "mayyam-reassignment");
+        client_config.set("enable.auto.commit", "false");
+
+        let consumer: BaseConsumer = client_config.create().map_err(|e| {
+            AppError::ExternalService(format!("Failed to create Kafka consumer: {}", e))
+        })?;
+
+        let timeout = Duration::from_secs(10);
+        let metadata = consumer.fetch_metadata(None, timeout).map_err(|e| {
+            KAFKA_OPERATION_ERRORS
+                .with_label_values(&[cluster_id, "reassignment_plan"])
+                .inc();
+            AppError::Kafka(format!("Failed to fetch cluster metadata: {}", e))
+        })?;
+
+        let known_brokers: Vec<i32> = metadata.brokers().iter().map(|b| b.id()).collect();
+        if let Some(unknown) = targets.iter().find(|id| !known_brokers.contains(id)) {
+            return Err(AppError::Validation(format!(
+                "Broker {} is not part of cluster {}",
+                unknown, cluster_id
+            )));
+        }
+
+        let selected: Vec<_> = match &topics {
+            Some(names) => {
+                let mut selected = Vec::new();
+                for name in names {
+                    let topic = metadata
+                        .topics()
+                        .iter()
+                        .find(|t| t.name() == name)
+                        .ok_or_else(|| AppError::NotFound(format!("Topic {} not found", name)))?;
+                    selected.push(topic);
+                }
+                selected
+            }
+            None => metadata
+                .topics()
+                .iter()
+                .filter(|t| !t.name().starts_with("__"))
+                .collect(),
+        };
+
+        let mut partitions = Vec::new();
+        for topic in &selected {
+            for p in topic.partitions() {
+                if p.replicas().len() > targets.len() {
+                    return Err(AppError::Validation(format!(
+                        "{}/{} has {} replicas but only {} target brokers were given",
+                        topic.name(),
+                        p.id(),
+                        p.replicas().len(),
+                        targets.len()
+                    )));
+                }
+                partitions.push((topic.name().to_string(), p.id(), p.replicas().to_vec()));
+            }
+        }
+
+        let reassignments = build_balanced_assignment(&partitions, &targets);
+
+        // Every replica placed on a new broker copies the whole partition
+        let mut estimated_data_move_bytes = 0u64;
+        let mut message_sizes: HashMap<String, f64> = HashMap::new();
+        for r in &reassignments {
+            let added = r
+                .new_replicas
+                .iter()
+                .filter(|b| !r.current_replicas.contains(b))
+                .count() as u64;
+            if added == 0 {
+                continue;
+            }
+
+            let (low, high) = consumer
+                .fetch_watermarks(&r.topic, r.partition, timeout)
+                .map_err(|e| {
+                    AppError::Kafka(format!(
+                        "Failed to fetch watermarks for {}/{}: {}",
+                        r.topic, r.partition, e
+                    ))
+                })?;
+            let avg_message_size = match message_sizes.get(&r.topic) {
+                Some(size) => *size,
+                None => {
+                    let size =
+                        Self::sample_message_size(&consumer, &r.topic, r.partition, low, high)
+                            .unwrap_or(DEFAULT_MESSAGE_SIZE_BYTES);
+                    message_sizes.insert(r.topic.clone(), size);
+                    size
+                }
+            };
+            estimated_data_move_bytes +=
+                ((high - low).max(0) as f64 * avg_message_size) as u64 * added;
+        }
 
-        Ok(brokers)
+        info!(
+            "Reassignment plan for cluster {} moves {} of {} partitions",
+            cluster_id,
+            reassignments.len(),
+            partitions.len()
+        );
+
+        Ok(ReassignmentPlan {
+            reassignment_json: to_reassignment_json(&reassignments),
+            reassignments,
+            estimated_data_move_bytes,
+        })
     }
 
     // Validate cluster update request
@@ -1822,6 +3755,9 @@ impl KafkaService {
         request: &MessageBackupRequest,
         config: &crate::config::Config,
     ) -> Result<MessageBackupResponse, AppError> {
+        let _lock = self
+            .lock(&format!("kafka_backup:{}:{}", cluster_id, request.topic))
+            .await?;
         let cluster_config = self.get_cluster(cluster_id, config).await?;
 
         let mut client_config = self.build_client_config(&cluster_config);
@@ -2014,6 +3950,12 @@ impl KafkaService {
         request: &MessageRestoreRequest,
         config: &crate::config::Config,
     ) -> Result<MessageRestoreResponse, AppError> {
+        let _lock = self
+            .lock(&format!(
+                "kafka_write:{}:{}",
+                cluster_id, request.target_topic
+            ))
+            .await?;
         let cluster_config = self.get_cluster(cluster_id, config).await?;
 
         let client_config = self.build_client_config(&cluster_config);
@@ -2132,6 +4074,25 @@ impl KafkaService {
         request: &MessageMigrationRequest,
         config: &crate::config::Config,
     ) -> Result<MessageMigrationResponse, AppError> {
+        let pipeline = request
+            .transform_messages
+            .as_ref()
+            .map(MessageTransformPipeline::new)
+            .transpose()?;
+        let _lock = self
+            .lock(&format!(
+                "kafka_write:{}:{}",
+                request.target_cluster_id, request.target_topic
+            ))
+            .await?;
+        if request.source_cluster_id == request.target_cluster_id
+            && request.source_topic == request.target_topic
+        {
+            return Err(AppError::Validation(
+                "Source and target topic must differ; migrating a topic onto itself re-reads its own copies"
+                    .to_string(),
+            ));
+        }
         let source_cluster_config = self.get_cluster(&request.source_cluster_id, config).await?;
         let target_cluster_config = self.get_cluster(&request.target_cluster_id, config).await?;
 
@@ -2207,42 +4168,22 @@ impl KafkaService {
                             }
                         }
 
-                        // Transform message if needed
-                        let target_key = if request
-                            .transform_messages
-                            .as_ref()
-                            .and_then(|t| t.key_prefix.as_ref())
-                            .is_some()
-                        {
-                            let prefix = request
-                                .transform_messages
-                                .as_ref()
-                                .unwrap()
-                                .key_prefix
-                                .as_ref()
-                                .unwrap();
-                            msg.key()
-                                .map(|k| format!("{}{}", prefix, String::from_utf8_lossy(k)))
-                        } else {
-                            msg.key().map(|k| String::from_utf8_lossy(k).to_string())
+                        let raw = to_raw_message(&msg);
+                        let raw = match &pipeline {
+                            Some(pipeline) => match pipeline.transform_raw(raw) {
+                                Ok(transformed) => transformed,
+                                Err(e) => {
+                                    warn!(
+                                        "Skipping message at offset {} that failed to transform: {}",
+                                        msg.offset(),
+                                        e
+                                    );
+                                    continue;
+                                }
+                            },
+                            None => raw,
                         };
-
-                        let payload = msg.payload().unwrap_or(&[]);
-                        let mut record = FutureRecord::to(&request.target_topic).payload(payload);
-
-                        if let Some(key) = &target_key {
-                            record = record.key(key.as_bytes());
-                        }
-
-                        // Add transformation headers if specified
-                        if let Some(transform) = &request.transform_messages {
-                            if let Some(headers) = transform.header_additions.as_ref() {
-                                // TODO: Add header support when available in rdkafka
-                                // for (key, value) in headers {
-                                //     record = record.header(key.as_str(), value.as_bytes());
-                                // }
-                            }
-                        }
+                        let record = to_future_record(&request.target_topic, &raw);
 
                         // Send to target topic
                         match target_producer.send(record, Duration::from_secs(10)).await {
@@ -2275,6 +4216,312 @@ impl KafkaService {
         })
     }
 
+    /// Produce a transformed copy of every message currently in the topic onto
+    /// `request.target_topic` in the same cluster. Each partition is read only up to its high
+    /// watermark at the start. Writing back to the source topic is rejected, since it would
+    /// leave the originals interleaved with their copies.
+    pub async fn transform_topic(
+        &self,
+        cluster_id: &str,
+        topic_name: &str,
+        request: &TopicTransformRequest,
+        config: &crate::config::Config,
+    ) -> Result<TopicTransformResponse, AppError> {
+        if request.target_topic == topic_name {
+            return Err(AppError::Validation(
+                "target_topic must differ from the source topic".to_string(),
+            ));
+        }
+        let pipeline = MessageTransformPipeline::new(&request.transform)?;
+        let target_topic = request.target_topic.as_str();
+        let _lock = self
+            .lock(&format!("kafka_write:{}:{}", cluster_id, target_topic))
+            .await?;
+        let cluster = self.get_cluster(cluster_id, config).await?;
+
+        let mut consumer_config = self.build_client_config(&cluster);
+        consumer_config.set("group.id", format!("mayyam-transform-{}", Uuid::new_v4()));
+        consumer_config.set("client.id", "mayyam-transform-consumer");
+        consumer_config.set("enable.auto.commit", "false");
+        let consumer: StreamConsumer = consumer_config
+            .create()
+            .map_err(|e| AppError::Kafka(format!("Failed to create transform consumer: {}", e)))?;
+
+        let mut producer_config = self.build_client_config(&cluster);
+        producer_config.set("client.id", "mayyam-transform-producer");
+        let producer: FutureProducer = producer_config
+            .create()
+            .map_err(|e| AppError::Kafka(format!("Failed to create transform producer: {}", e)))?;
+
+        let timeout = Duration::from_secs(10);
+        let partitions = match &request.partitions {
+            Some(partitions) => partitions.clone(),
+            None => {
+                let metadata = consumer
+                    .fetch_metadata(Some(topic_name), timeout)
+                    .map_err(|e| {
+                        AppError::Kafka(format!("Failed to fetch topic metadata: {}", e))
+                    })?;
+                let topic = metadata
+                    .topics()
+                    .iter()
+                    .find(|t| t.name() == topic_name)
+                    .ok_or_else(|| AppError::NotFound(format!("Topic {} not found", topic_name)))?;
+                topic.partitions().iter().map(|p| p.id()).collect()
+            }
+        };
+
+        let mut messages_transformed = 0u64;
+        let mut messages_failed = 0u64;
+        for &partition in &partitions {
+            let (low, high) = consumer
+                .fetch_watermarks(topic_name, partition, timeout)
+                .map_err(|e| {
+                    AppError::Kafka(format!(
+                        "Failed to fetch watermarks for partition {}: {}",
+                        partition, e
+                    ))
+                })?;
+            if high <= low {
+                continue;
+            }
+
+            let mut assignment = TopicPartitionList::new();
+            assignment
+                .add_partition_offset(topic_name, partition, Offset::Offset(low))
+                .map_err(|e| AppError::Kafka(format!("Invalid start offset: {}", e)))?;
+            consumer
+                .assign(&assignment)
+                .map_err(|e| AppError::Kafka(format!("Failed to assign partition: {}", e)))?;
+
+            loop {
+                let msg = match tokio::time::timeout(timeout, consumer.recv()).await {
+                    Ok(Ok(msg)) => msg,
+                    Ok(Err(e)) => {
+                        KAFKA_OPERATION_ERRORS
+                            .with_label_values(&[cluster_id, "transform"])
+                            .inc();
+                        return Err(AppError::Kafka(format!(
+                            "Error reading message for transform: {}",
+                            e
+                        )));
+                    }
+                    Err(_) => {
+                        warn!(
+                            "No more messages on {}[{}] before offset {}",
+                            topic_name, partition, high
+                        );
+                        break;
+                    }
+                };
+                if msg.offset() >= high {
+                    break;
+                }
+
+                match pipeline.transform_raw(to_raw_message(&msg)) {
+                    Ok(transformed) => {
+                        let record = to_future_record(target_topic, &transformed);
+                        match producer.send(record, timeout).await {
+                            Ok(_) => {
+                                messages_transformed += 1;
+                                KAFKA_MESSAGES_PRODUCED
+                                    .with_label_values(&[cluster_id, target_topic])
+                                    .inc();
+                            }
+                            Err((e, _)) => {
+                                KAFKA_OPERATION_ERRORS
+                                    .with_label_values(&[cluster_id, "transform"])
+                                    .inc();
+                                warn!(
+                                    "Failed to produce transformed message from offset {}: {}",
+                                    msg.offset(),
+                                    e
+                                );
+                                messages_failed += 1;
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        warn!(
+                            "Skipping message at {}[{}] offset {} that failed to transform: {}",
+                            topic_name,
+                            partition,
+                            msg.offset(),
+                            e
+                        );
+                        messages_failed += 1;
+                    }
+                }
+
+                if msg.offset() + 1 >= high {
+                    break;
+                }
+            }
+        }
+
+        info!(
+            "Transformed {} messages from {} into {} ({} failed)",
+            messages_transformed, topic_name, target_topic, messages_failed
+        );
+
+        Ok(TopicTransformResponse {
+            topic: topic_name.to_string(),
+            target_topic: target_topic.to_string(),
+            messages_transformed,
+            messages_failed,
+            partitions_transformed: partitions,
+        })
+    }
+
+    /// Re-produce messages in `[start_offset, end_offset)` of one partition, tagging each
+    /// with an `x-replayed-from-offset` header
+    pub async fn replay_messages(
+        &self,
+        cluster_id: &str,
+        topic_name: &str,
+        request: &MessageReplayRequest,
+        config: &crate::config::Config,
+    ) -> Result<ReplayResult, AppError> {
+        let target_topic = request
+            .target_topic
+            .clone()
+            .unwrap_or_else(|| topic_name.to_string());
+        if target_topic == topic_name && !request.allow_self_replay {
+            return Err(AppError::BadRequest(
+                "Replaying onto the source topic requires allow_self_replay".to_string(),
+            ));
+        }
+        if request.start_offset < 0 || request.end_offset <= request.start_offset {
+            return Err(AppError::BadRequest(
+                "end_offset must be greater than a non-negative start_offset".to_string(),
+            ));
+        }
+        if request.rate_limit_per_sec == Some(0) {
+            return Err(AppError::BadRequest(
+                "rate_limit_per_sec must be greater than zero".to_string(),
+            ));
+        }
+
+        let cluster = self.get_cluster(cluster_id, config).await?;
+
+        let mut consumer_config = self.build_client_config(&cluster);
+        consumer_config.set("group.id", format!("mayyam-replay-{}", Uuid::new_v4()));
+        consumer_config.set("client.id", "mayyam-replay-consumer");
+        consumer_config.set("enable.auto.commit", "false");
+        let consumer: StreamConsumer = consumer_config
+            .create()
+            .map_err(|e| AppError::Kafka(format!("Failed to create replay consumer: {}", e)))?;
+
+        let mut producer_config = self.build_client_config(&cluster);
+        producer_config.set("client.id", "mayyam-replay-producer");
+        let producer: FutureProducer = producer_config
+            .create()
+            .map_err(|e| AppError::Kafka(format!("Failed to create replay producer: {}", e)))?;
+
+        // Assigning directly avoids a group rebalance before the seek takes effect
+        let mut assignment = TopicPartitionList::new();
+        assignment
+            .add_partition_offset(
+                topic_name,
+                request.partition,
+                Offset::Offset(request.start_offset),
+            )
+            .map_err(|e| AppError::Kafka(format!("Invalid replay start offset: {}", e)))?;
+        consumer
+            .assign(&assignment)
+            .map_err(|e| AppError::Kafka(format!("Failed to assign partition: {}", e)))?;
+
+        let mut ticker = request
+            .rate_limit_per_sec
+            .map(|rate| tokio::time::interval(Duration::from_secs_f64(1.0 / rate as f64)));
+
+        let start_time = Instant::now();
+        let mut messages_replayed = 0u64;
+
+        loop {
+            let msg = match tokio::time::timeout(Duration::from_secs(10), consumer.recv()).await {
+                Ok(Ok(msg)) => msg,
+                Ok(Err(e)) => {
+                    KAFKA_OPERATION_ERRORS
+                        .with_label_values(&[cluster_id, "replay"])
+                        .inc();
+                    return Err(AppError::Kafka(format!(
+                        "Error reading message for replay: {}",
+                        e
+                    )));
+                }
+                Err(_) => {
+                    // Compaction or retention can leave the range short of end_offset
+                    warn!(
+                        "No more messages on {}[{}] before offset {}",
+                        topic_name, request.partition, request.end_offset
+                    );
+                    break;
+                }
+            };
+
+            if msg.offset() >= request.end_offset {
+                break;
+            }
+
+            if let Some(ticker) = ticker.as_mut() {
+                ticker.tick().await;
+            }
+
+            let replayed_from = msg.offset().to_string();
+            let mut headers = OwnedHeaders::new();
+            if let Some(original) = msg.headers() {
+                for header in original.iter() {
+                    headers = headers.insert(header);
+                }
+            }
+            headers = headers.insert(Header {
+                key: "x-replayed-from-offset",
+                value: Some(replayed_from.as_bytes()),
+            });
+
+            let mut record = FutureRecord::to(&target_topic)
+                .payload(msg.payload().unwrap_or(&[]))
+                .headers(headers);
+            if let Some(key) = msg.key() {
+                record = record.key(key);
+            }
+
+            producer
+                .send(record, Duration::from_secs(10))
+                .await
+                .map_err(|(e, _)| {
+                    KAFKA_OPERATION_ERRORS
+                        .with_label_values(&[cluster_id, "replay"])
+                        .inc();
+                    AppError::Kafka(format!(
+                        "Failed to replay message at offset {}: {}",
+                        replayed_from, e
+                    ))
+                })?;
+
+            messages_replayed += 1;
+            KAFKA_MESSAGES_PRODUCED
+                .with_label_values(&[cluster_id, &target_topic])
+                .inc();
+
+            if msg.offset() + 1 >= request.end_offset {
+                break;
+            }
+        }
+
+        info!(
+            "Replayed {} messages from {}[{}] to {}",
+            messages_replayed, topic_name, request.partition, target_topic
+        );
+
+        Ok(ReplayResult {
+            messages_replayed,
+            duration_ms: start_time.elapsed().as_millis() as u64,
+            target_topic,
+        })
+    }
+
     /// Wait for consumer group to drain all messages from topics
     pub async fn wait_for_queue_drain(
         &self,
@@ -2366,6 +4613,452 @@ impl KafkaService {
     }
 }
 
+fn purge_lock_key(cluster_id: &str, topic_name: &str) -> String {
+    format!("kafka_purge:{}:{}", cluster_id, topic_name)
+}
+
+fn uses_tls(cluster: &KafkaClusterConfig) -> bool {
+    let protocol = cluster.security_protocol.to_ascii_uppercase();
+    protocol == "SSL" || protocol == "SASL_SSL"
+}
+
+fn broker_addresses(metadata: &rdkafka::metadata::Metadata) -> Vec<(String, u16)> {
+    metadata
+        .brokers()
+        .iter()
+        .filter_map(|b| Some((b.host().to_string(), u16::try_from(b.port()).ok()?)))
+        .collect()
+}
+
+async fn probe_broker_certs(
+    cluster_id: &str,
+    addresses: &[(String, u16)],
+) -> Vec<BrokerCertExpiry> {
+    let probes = addresses
+        .iter()
+        .map(|(host, port)| read_broker_certificate(host, *port));
+    let results = futures::future::join_all(probes).await;
+    let mut certs = Vec::new();
+    for (result, (host, port)) in results.into_iter().zip(addresses) {
+        match result {
+            Ok(cert) => certs.push(cert),
+            Err(e) => {
+                KAFKA_OPERATION_ERRORS
+                    .with_label_values(&[cluster_id, "cert_check"])
+                    .inc();
+                warn!(
+                    "Could not read TLS certificate from broker {}:{}: {}",
+                    host, port, e
+                );
+            }
+        }
+    }
+    certs
+}
+
+async fn read_broker_certificate(host: &str, port: u16) -> Result<BrokerCertExpiry, AppError> {
+    // A verified handshake tells us whether clients will accept the chain; the certificate
+    // itself is then read without verification, since expired and self-signed ones are
+    // exactly what this check has to report on
+    let trust_error = broker_tls_handshake(host, port, true).await.err();
+    let tls = broker_tls_handshake(host, port, false).await?;
+
+    let der = tls
+        .get_ref()
+        .peer_certificate()
+        .map_err(|e| AppError::ExternalService(format!("Failed to read certificate: {}", e)))?
+        .ok_or_else(|| AppError::ExternalService("Broker presented no certificate".to_string()))?
+        .to_der()
+        .map_err(|e| AppError::ExternalService(format!("Failed to encode certificate: {}", e)))?;
+    let (_, cert) = x509_parser::parse_x509_certificate(&der)
+        .map_err(|e| AppError::ExternalService(format!("Failed to parse certificate: {}", e)))?;
+
+    let common_name = cert
+        .subject()
+        .iter_common_name()
+        .next()
+        .and_then(|cn| cn.as_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let not_after = chrono::DateTime::from_timestamp(cert.validity().not_after.timestamp(), 0)
+        .ok_or_else(|| {
+            AppError::ExternalService("Certificate has an invalid expiry".to_string())
+        })?;
+    let days_until_expiry = (not_after - chrono::Utc::now()).num_days();
+
+    Ok(BrokerCertExpiry {
+        broker_host: host.to_string(),
+        broker_port: port,
+        common_name,
+        not_after,
+        days_until_expiry,
+        is_expired: not_after <= chrono::Utc::now(),
+        is_trusted: trust_error.is_none(),
+        trust_error: trust_error.map(|e| e.to_string()),
+    })
+}
+
+async fn broker_tls_handshake(
+    host: &str,
+    port: u16,
+    verify: bool,
+) -> Result<tokio_native_tls::TlsStream<tokio::net::TcpStream>, AppError> {
+    let connector = native_tls::TlsConnector::builder()
+        .danger_accept_invalid_certs(!verify)
+        .danger_accept_invalid_hostnames(!verify)
+        .build()
+        .map_err(|e| AppError::Internal(format!("Failed to build TLS connector: {}", e)))?;
+    let connector = tokio_native_tls::TlsConnector::from(connector);
+
+    let connect = tokio::net::TcpStream::connect((host, port));
+    let tcp = tokio::time::timeout(TLS_PROBE_TIMEOUT, connect)
+        .await
+        .map_err(|_| AppError::ExternalService("Timed out connecting to broker".to_string()))?
+        .map_err(|e| AppError::ExternalService(format!("Failed to connect: {}", e)))?;
+    tokio::time::timeout(TLS_PROBE_TIMEOUT, connector.connect(host, tcp))
+        .await
+        .map_err(|_| AppError::ExternalService("Timed out during TLS handshake".to_string()))?
+        .map_err(|e| AppError::ExternalService(format!("TLS handshake failed: {}", e)))
+}
+
+// Connection settings shared by every client talking to a configured cluster, including the
+// audit sink's producer
+pub(crate) fn build_client_config(cluster: &KafkaClusterConfig) -> ClientConfig {
+    let mut client_config = ClientConfig::new();
+
+    // Set bootstrap servers (allow environment variable override for Docker/container environments)
+    let bootstrap_servers = if let Ok(env_brokers) = std::env::var("KAFKA_BROKERS") {
+        env_brokers
+    } else {
+        cluster.bootstrap_servers.join(",")
+    };
+    client_config.set("bootstrap.servers", &bootstrap_servers);
+
+    // Set security settings if present
+    if let (Some(username), Some(password)) = (&cluster.sasl_username, &cluster.sasl_password) {
+        client_config.set("sasl.username", username);
+        client_config.set("sasl.password", password);
+
+        if let Some(mechanism) = &cluster.sasl_mechanism {
+            client_config.set("sasl.mechanism", mechanism);
+        }
+
+        client_config.set("security.protocol", &cluster.security_protocol);
+    } else {
+        client_config.set("security.protocol", &cluster.security_protocol);
+    }
+
+    // Common settings for resilience in a web context
+    client_config.set("request.timeout.ms", "10000");
+    client_config.set("message.timeout.ms", "15000");
+    client_config.set("socket.timeout.ms", "10000");
+    client_config.set("metadata.request.timeout.ms", "10000");
+    client_config.set("message.send.max.retries", "3");
+    client_config.set("retry.backoff.ms", "500");
+
+    client_config
+}
+
+fn to_raw_message<M: Message>(msg: &M) -> RawMessage {
+    RawMessage {
+        key: msg.key().map(|k| k.to_vec()),
+        value: msg.payload().map(|p| p.to_vec()),
+        headers: msg
+            .headers()
+            .map(|hdrs| {
+                hdrs.iter()
+                    .map(|h| (h.key.to_string(), h.value.map(|v| v.to_vec())))
+                    .collect()
+            })
+            .unwrap_or_default(),
+    }
+}
+
+// A missing key or value is left unset on the record, so tombstones are produced as tombstones
+fn to_future_record<'a>(topic: &'a str, message: &'a RawMessage) -> FutureRecord<'a, [u8], [u8]> {
+    let headers = message
+        .headers
+        .iter()
+        .fold(OwnedHeaders::new(), |owned, (key, value)| {
+            owned.insert(Header {
+                key,
+                value: value.as_deref(),
+            })
+        });
+    let mut record = FutureRecord::to(topic).headers(headers);
+    if let Some(key) = &message.key {
+        record = record.key(key.as_slice());
+    }
+    if let Some(value) = &message.value {
+        record = record.payload(value.as_slice());
+    }
+    record
+}
+
+async fn describe_topic_config(
+    admin: &AdminClient<DefaultClientContext>,
+    topic_name: &str,
+) -> Result<ConfigResource, AppError> {
+    let opts = AdminOptions::new().request_timeout(Some(Duration::from_secs(30)));
+    let results = admin
+        .describe_configs(&[ResourceSpecifier::Topic(topic_name)], &opts)
+        .await
+        .map_err(|e| {
+            AppError::ExternalService(format!("Failed to describe topic configs: {}", e))
+        })?;
+    results
+        .into_iter()
+        .next()
+        .ok_or_else(|| AppError::NotFound(format!("No configs returned for topic {}", topic_name)))?
+        .map_err(|e| {
+            AppError::ExternalService(format!(
+                "Failed to describe configs of topic {}: {:?}",
+                topic_name, e
+            ))
+        })
+}
+
+// Values set on the topic itself, as opposed to broker or default values
+fn topic_overrides(resource: &ConfigResource) -> HashMap<String, String> {
+    resource
+        .entries
+        .iter()
+        .filter(|entry| matches!(entry.source, ConfigSource::DynamicTopic) && !entry.is_sensitive)
+        .filter_map(|entry| Some((entry.name.clone(), entry.value.clone()?)))
+        .collect()
+}
+
+// AlterConfigs replaces every override of the topic, so `configs` must be the full set
+async fn alter_topic_config(
+    admin: &AdminClient<DefaultClientContext>,
+    topic_name: &str,
+    configs: &HashMap<String, String>,
+) -> Result<(), AppError> {
+    let mut alter = AlterConfig::new(ResourceSpecifier::Topic(topic_name));
+    for (key, value) in configs {
+        alter = alter.set(key, value);
+    }
+    let opts = AdminOptions::new().request_timeout(Some(Duration::from_secs(30)));
+    let results = admin
+        .alter_configs(&[&alter], &opts)
+        .await
+        .map_err(|e| AppError::ExternalService(format!("Failed to alter topic configs: {}", e)))?;
+    if let Some(Err((_, err))) = results.first() {
+        return Err(AppError::ExternalService(format!(
+            "Failed to alter configs of topic {}: {:?}",
+            topic_name, err
+        )));
+    }
+    Ok(())
+}
+
+// Roll per-topic figures up into a report. A topic counts as near retention once its
+// largest partition reaches RETENTION_WARNING_RATIO of retention.bytes.
+pub fn build_capacity_report(broker_count: usize, topics: &[TopicCapacity]) -> KafkaCapacityReport {
+    let total_partitions: usize = topics.iter().map(|t| t.partitions).sum();
+    let messages_last_hour: i64 = topics.iter().map(|t| t.messages_last_hour).sum();
+    let estimated_disk_usage_bytes: u64 =
+        topics.iter().map(|t| t.estimated_disk_usage_bytes).sum();
+
+    let topics_near_retention: Vec<String> = topics
+        .iter()
+        .filter(|t| match t.retention_bytes {
+            Some(limit) if limit > 0 => {
+                t.largest_partition_bytes as f64 >= limit as f64 * RETENTION_WARNING_RATIO
+            }
+            _ => false,
+        })
+        .map(|t| t.name.clone())
+        .collect();
+
+    let mut recommendations = Vec::new();
+    if broker_count < 3 {
+        recommendations.push(format!(
+            "Cluster has {} broker(s); run at least 3 to tolerate a broker failure",
+            broker_count
+        ));
+    }
+    if broker_count > 0 && total_partitions / broker_count > MAX_PARTITIONS_PER_BROKER {
+        recommendations.push(format!(
+            "{} partitions per broker exceeds the recommended {}; add brokers or consolidate topics",
+            total_partitions / broker_count,
+            MAX_PARTITIONS_PER_BROKER
+        ));
+    }
+    for topic in topics.iter().filter(|t| t.replication_factor < 2) {
+        recommendations.push(format!(
+            "Topic '{}' has replication factor {}; increase it to avoid data loss",
+            topic.name, topic.replication_factor
+        ));
+    }
+    for name in &topics_near_retention {
+        recommendations.push(format!(
+            "Topic '{}' is close to retention.bytes; raise the limit or add partitions",
+            name
+        ));
+    }
+
+    KafkaCapacityReport {
+        broker_count,
+        total_topics: topics.len(),
+        total_partitions,
+        estimated_messages_per_second: messages_last_hour as f64 / 3600.0,
+        estimated_disk_usage_bytes,
+        topics_near_retention,
+        recommendations,
+    }
+}
+
+// Places replicas so the replica count per target broker differs by at most
+// one. Replicas on brokers outside the target set are first moved to the least
+// loaded targets, then single replicas are shifted from the busiest to the
+// idlest broker until balanced, preferring followers so leaders stay put.
+// Only partitions whose replica list changes are returned.
+pub fn build_balanced_assignment(
+    partitions: &[(String, i32, Vec<i32>)],
+    target_broker_ids: &[i32],
+) -> Vec<PartitionReassignment> {
+    if target_broker_ids.is_empty() {
+        return Vec::new();
+    }
+
+    let mut load: HashMap<i32, usize> = target_broker_ids.iter().map(|b| (*b, 0)).collect();
+    let mut assignments: Vec<Vec<i32>> = partitions
+        .iter()
+        .map(|(_, _, current)| {
+            let kept: Vec<i32> = current
+                .iter()
+                .copied()
+                .filter(|b| load.contains_key(b))
+                .collect();
+            for b in &kept {
+                *load.get_mut(b).unwrap() += 1;
+            }
+            kept
+        })
+        .collect();
+
+    for ((_, _, current), assigned) in partitions.iter().zip(assignments.iter_mut()) {
+        while assigned.len() < current.len() {
+            let Some(broker) = target_broker_ids
+                .iter()
+                .filter(|b| !assigned.contains(b))
+                .min_by_key(|b| (load[*b], **b))
+                .copied()
+            else {
+                break;
+            };
+            *load.get_mut(&broker).unwrap() += 1;
+            assigned.push(broker);
+        }
+    }
+
+    loop {
+        let busiest = *target_broker_ids
+            .iter()
+            .max_by_key(|b| (load[*b], **b))
+            .unwrap();
+        let idlest = *target_broker_ids
+            .iter()
+            .min_by_key(|b| (load[*b], **b))
+            .unwrap();
+        if load[&busiest] <= load[&idlest] + 1 {
+            break;
+        }
+
+        let movable = |a: &Vec<i32>, follower_only: bool| {
+            !a.contains(&idlest)
+                && a.iter()
+                    .position(|b| *b == busiest)
+                    .map_or(false, |pos| pos > 0 || !follower_only)
+        };
+        let Some(assigned) = assignments
+            .iter()
+            .position(|a| movable(a, true))
+            .or_else(|| assignments.iter().position(|a| movable(a, false)))
+            .map(|i| &mut assignments[i])
+        else {
+            break;
+        };
+
+        for b in assigned.iter_mut() {
+            if *b == busiest {
+                *b = idlest;
+            }
+        }
+        *load.get_mut(&busiest).unwrap() -= 1;
+        *load.get_mut(&idlest).unwrap() += 1;
+    }
+
+    partitions
+        .iter()
+        .zip(assignments)
+        .filter(|((_, _, current), assigned)| current != assigned)
+        .map(
+            |((topic, partition, current), assigned)| PartitionReassignment {
+                topic: topic.clone(),
+                partition: *partition,
+                current_replicas: current.clone(),
+                new_replicas: assigned,
+            },
+        )
+        .collect()
+}
+
+/// Decode a member assignment blob from the consumer protocol
+/// (version: i16, [topic: string, [partition: i32]], user_data: bytes).
+/// Malformed or truncated input yields whatever assignments were read before the error.
+pub fn decode_member_assignment(bytes: &[u8]) -> Vec<ConsumerGroupAssignment> {
+    fn read_i16(buf: &[u8], pos: &mut usize) -> Option<i16> {
+        let v = buf.get(*pos..*pos + 2)?;
+        *pos += 2;
+        Some(i16::from_be_bytes([v[0], v[1]]))
+    }
+    fn read_i32(buf: &[u8], pos: &mut usize) -> Option<i32> {
+        let v = buf.get(*pos..*pos + 4)?;
+        *pos += 4;
+        Some(i32::from_be_bytes([v[0], v[1], v[2], v[3]]))
+    }
+
+    let mut assignments = Vec::new();
+    let mut pos = 0usize;
+    if read_i16(bytes, &mut pos).is_none() {
+        return assignments;
+    }
+    let topic_count = match read_i32(bytes, &mut pos) {
+        Some(n) if n > 0 => n,
+        _ => return assignments,
+    };
+
+    for _ in 0..topic_count {
+        let name_len = match read_i16(bytes, &mut pos) {
+            Some(n) if n >= 0 => n as usize,
+            _ => break,
+        };
+        let topic = match bytes.get(pos..pos + name_len) {
+            Some(raw) => String::from_utf8_lossy(raw).to_string(),
+            None => break,
+        };
+        pos += name_len;
+
+        let partition_count = match read_i32(bytes, &mut pos) {
+            Some(n) if n >= 0 => n,
+            _ => break,
+        };
+        for _ in 0..partition_count {
+            match read_i32(bytes, &mut pos) {
+                Some(partition) => assignments.push(ConsumerGroupAssignment {
+                    topic: topic.clone(),
+                    partition,
+                }),
+                None => return assignments,
+            }
+        }
+    }
+
+    assignments
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClusterUpdateRequest {
     pub name: Option<String>,
@@ -2397,6 +5090,18 @@ pub struct BrokerStatus {
     pub rack: Option<String>,
 }
 
+fn compile_search_regex(
+    field: &str,
+    pattern: Option<&str>,
+) -> Result<Option<regex::Regex>, AppError> {
+    pattern
+        .map(|p| {
+            regex::Regex::new(p)
+                .map_err(|e| AppError::Validation(format!("Invalid {}: {}", field, e)))
+        })
+        .transpose()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2474,4 +5179,78 @@ mod tests {
             assert!(msg.contains("Invalid security protocol"));
         }
     }
+
+    #[test]
+    fn test_decode_member_assignment() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&0i16.to_be_bytes());
+        bytes.extend_from_slice(&1i32.to_be_bytes());
+        bytes.extend_from_slice(&(6i16).to_be_bytes());
+        bytes.extend_from_slice(b"orders");
+        bytes.extend_from_slice(&2i32.to_be_bytes());
+        bytes.extend_from_slice(&0i32.to_be_bytes());
+        bytes.extend_from_slice(&3i32.to_be_bytes());
+        bytes.extend_from_slice(&(-1i32).to_be_bytes()); // empty user data
+
+        let assignments = decode_member_assignment(&bytes);
+        assert_eq!(assignments.len(), 2);
+        assert_eq!(assignments[0].topic, "orders");
+        assert_eq!(assignments[0].partition, 0);
+        assert_eq!(assignments[1].partition, 3);
+    }
+
+    #[test]
+    fn test_build_balanced_assignment() {
+        let partitions = vec![
+            ("orders".to_string(), 0, vec![1, 2]),
+            ("orders".to_string(), 1, vec![2, 1]),
+            ("orders".to_string(), 2, vec![1, 2]),
+        ];
+        let plan = build_balanced_assignment(&partitions, &[1, 2, 3]);
+
+        let mut load: HashMap<i32, usize> = HashMap::new();
+        for (topic, partition, current) in &partitions {
+            let replicas = plan
+                .iter()
+                .find(|r| &r.topic == topic && r.partition == *partition)
+                .map(|r| r.new_replicas.clone())
+                .unwrap_or_else(|| current.clone());
+            assert_eq!(replicas.len(), current.len());
+            for b in replicas {
+                *load.entry(b).or_default() += 1;
+            }
+        }
+        assert_eq!(load.get(&1), Some(&2));
+        assert_eq!(load.get(&2), Some(&2));
+        assert_eq!(load.get(&3), Some(&2));
+        // Only the moves needed to fill broker 3 are planned, leaders stay put
+        assert_eq!(plan.len(), 2);
+        assert!(plan
+            .iter()
+            .all(|r| r.new_replicas[0] == r.current_replicas[0]));
+    }
+
+    #[test]
+    fn test_decode_truncated_member_assignment() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&0i16.to_be_bytes());
+        bytes.extend_from_slice(&1i32.to_be_bytes());
+        bytes.extend_from_slice(&(6i16).to_be_bytes());
+        bytes.extend_from_slice(b"ord");
+
+        assert!(decode_member_assignment(&bytes).is_empty());
+    }
+
+    #[test]
+    fn test_compile_search_regex() {
+        assert!(compile_search_regex("key_regex", None).unwrap().is_none());
+        let re = compile_search_regex("key_regex", Some("^order-\\d+$"))
+            .unwrap()
+            .unwrap();
+        assert!(re.is_match("order-42"));
+        assert!(matches!(
+            compile_search_regex("value_regex", Some("(unclosed")),
+            Err(AppError::Validation(_))
+        ));
+    }
 }
@@ -0,0 +1,286 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use std::time::{Duration, Instant};
+
+use futures::stream::{FuturesUnordered, StreamExt};
+use rdkafka::config::ClientConfig;
+use rdkafka::consumer::{Consumer, StreamConsumer};
+use rdkafka::error::KafkaError;
+use rdkafka::message::Message;
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use rdkafka::types::RDKafkaErrorCode;
+use serde::Serialize;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::config::KafkaClusterConfig;
+use crate::errors::AppError;
+
+// Bounds memory per producer while keeping librdkafka's batching effective
+const MAX_IN_FLIGHT_PER_PRODUCER: usize = 1000;
+const CONSUMER_ASSIGNMENT_TIMEOUT: Duration = Duration::from_secs(15);
+// Lets consumers catch up on messages produced just before the deadline
+const CONSUMER_DRAIN_GRACE: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone)]
+pub struct BenchmarkConfig {
+    pub cluster: KafkaClusterConfig,
+    pub topic: String,
+    pub producers: usize,
+    pub consumers: usize,
+    pub message_size: usize,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkResult {
+    pub producer_msgs_per_sec: f64,
+    pub consumer_msgs_per_sec: f64,
+    pub producer_mb_per_sec: f64,
+    pub consumer_mb_per_sec: f64,
+    pub p99_produce_latency_ms: f64,
+    pub error_count: u64,
+}
+
+#[derive(Default)]
+struct ProducerStats {
+    messages: u64,
+    errors: u64,
+    latencies_ms: Vec<f64>,
+}
+
+#[derive(Default)]
+struct ConsumerStats {
+    messages: u64,
+    bytes: u64,
+    errors: u64,
+}
+
+pub struct KafkaBenchmarkService;
+
+impl KafkaBenchmarkService {
+    pub async fn run(config: BenchmarkConfig) -> Result<BenchmarkResult, AppError> {
+        if config.producers == 0 && config.consumers == 0 {
+            return Err(AppError::Validation(
+                "At least one producer or consumer is required".to_string(),
+            ));
+        }
+        if config.message_size == 0 || config.duration.is_zero() {
+            return Err(AppError::Validation(
+                "message_size and duration must be greater than zero".to_string(),
+            ));
+        }
+
+        // Consumers share one fresh group so partitions are split between them and
+        // only messages produced during the run are counted
+        let group_id = format!("mayyam-benchmark-{}", Uuid::new_v4());
+        let mut consumers = Vec::with_capacity(config.consumers);
+        for i in 0..config.consumers {
+            let mut client_config = base_client_config(&config.cluster);
+            client_config
+                .set("group.id", &group_id)
+                .set("client.id", format!("mayyam-benchmark-consumer-{}", i))
+                .set("auto.offset.reset", "latest")
+                .set("enable.auto.commit", "false");
+            let consumer: StreamConsumer = client_config.create().map_err(|e| {
+                AppError::Kafka(format!("Failed to create benchmark consumer: {}", e))
+            })?;
+            consumer.subscribe(&[&config.topic]).map_err(|e| {
+                AppError::Kafka(format!("Failed to subscribe to {}: {}", config.topic, e))
+            })?;
+            consumers.push(consumer);
+        }
+        wait_for_assignment(&consumers).await;
+
+        let started = Instant::now();
+        let deadline = started + config.duration;
+
+        let consumer_tasks: Vec<_> = consumers
+            .into_iter()
+            .map(|consumer| tokio::spawn(consume_until(consumer, deadline + CONSUMER_DRAIN_GRACE)))
+            .collect();
+
+        let payload = vec![b'x'; config.message_size];
+        let mut producer_tasks = Vec::with_capacity(config.producers);
+        for i in 0..config.producers {
+            let mut client_config = base_client_config(&config.cluster);
+            client_config
+                .set("client.id", format!("mayyam-benchmark-producer-{}", i))
+                .set("linger.ms", "5");
+            let producer: FutureProducer = client_config.create().map_err(|e| {
+                AppError::Kafka(format!("Failed to create benchmark producer: {}", e))
+            })?;
+            producer_tasks.push(tokio::spawn(produce_until(
+                producer,
+                config.topic.clone(),
+                payload.clone(),
+                deadline,
+            )));
+        }
+
+        let mut produced = ProducerStats::default();
+        for task in producer_tasks {
+            let stats = task
+                .await
+                .map_err(|e| AppError::Internal(format!("Producer task failed: {}", e)))?;
+            produced.messages += stats.messages;
+            produced.errors += stats.errors;
+            produced.latencies_ms.extend(stats.latencies_ms);
+        }
+        let produce_elapsed = started.elapsed().as_secs_f64();
+
+        let mut consumed = ConsumerStats::default();
+        for task in consumer_tasks {
+            let stats = task
+                .await
+                .map_err(|e| AppError::Internal(format!("Consumer task failed: {}", e)))?;
+            consumed.messages += stats.messages;
+            consumed.bytes += stats.bytes;
+            consumed.errors += stats.errors;
+        }
+        let consume_elapsed = started.elapsed().as_secs_f64();
+
+        let produced_bytes = produced.messages as f64 * config.message_size as f64;
+        let result = BenchmarkResult {
+            producer_msgs_per_sec: produced.messages as f64 / produce_elapsed,
+            consumer_msgs_per_sec: consumed.messages as f64 / consume_elapsed,
+            producer_mb_per_sec: produced_bytes / produce_elapsed / 1_048_576.0,
+            consumer_mb_per_sec: consumed.bytes as f64 / consume_elapsed / 1_048_576.0,
+            p99_produce_latency_ms: percentile(&mut produced.latencies_ms, 0.99),
+            error_count: produced.errors + consumed.errors,
+        };
+        info!(
+            topic = %config.topic,
+            produced = produced.messages,
+            consumed = consumed.messages,
+            errors = result.error_count,
+            "Kafka benchmark finished"
+        );
+        Ok(result)
+    }
+}
+
+fn base_client_config(cluster: &KafkaClusterConfig) -> ClientConfig {
+    let mut client_config = ClientConfig::new();
+    client_config.set("bootstrap.servers", cluster.bootstrap_servers.join(","));
+    if let (Some(username), Some(password)) = (&cluster.sasl_username, &cluster.sasl_password) {
+        client_config.set("sasl.username", username);
+        client_config.set("sasl.password", password);
+        if let Some(mechanism) = &cluster.sasl_mechanism {
+            client_config.set("sasl.mechanism", mechanism);
+        }
+    }
+    client_config.set("security.protocol", &cluster.security_protocol);
+    client_config
+}
+
+// With auto.offset.reset=latest, anything produced before the group is assigned is missed
+async fn wait_for_assignment(consumers: &[StreamConsumer]) {
+    let give_up = Instant::now() + CONSUMER_ASSIGNMENT_TIMEOUT;
+    for consumer in consumers {
+        loop {
+            // Polling drives the rebalance; a message arriving here is not yet counted
+            let _ = tokio::time::timeout(Duration::from_millis(200), consumer.recv()).await;
+            let assigned = consumer.assignment().map(|a| a.count() > 0).unwrap_or(false);
+            if assigned || Instant::now() >= give_up {
+                break;
+            }
+        }
+    }
+    // More consumers than partitions leaves some idle, which is expected
+    if Instant::now() >= give_up {
+        warn!("Not every benchmark consumer received a partition assignment");
+    }
+}
+
+async fn produce_until(
+    producer: FutureProducer,
+    topic: String,
+    payload: Vec<u8>,
+    deadline: Instant,
+) -> ProducerStats {
+    let mut stats = ProducerStats::default();
+    let mut in_flight = FuturesUnordered::new();
+
+    while Instant::now() < deadline {
+        if in_flight.len() >= MAX_IN_FLIGHT_PER_PRODUCER {
+            if let Some(outcome) = in_flight.next().await {
+                record_delivery(&mut stats, outcome);
+            }
+        }
+
+        let record = FutureRecord::<(), _>::to(&topic).payload(&payload);
+        match producer.send_result(record) {
+            Ok(delivery) => {
+                let sent_at = Instant::now();
+                in_flight.push(async move {
+                    let delivered = matches!(delivery.await, Ok(Ok(_)));
+                    (delivered, sent_at.elapsed())
+                });
+            }
+            Err((KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull), _)) => {
+                // Local queue is full; wait for a delivery before sending more
+                match in_flight.next().await {
+                    Some(outcome) => record_delivery(&mut stats, outcome),
+                    None => tokio::time::sleep(Duration::from_millis(10)).await,
+                }
+            }
+            Err(_) => stats.errors += 1,
+        }
+    }
+
+    while let Some(outcome) = in_flight.next().await {
+        record_delivery(&mut stats, outcome);
+    }
+    stats
+}
+
+fn record_delivery(stats: &mut ProducerStats, (delivered, latency): (bool, Duration)) {
+    if delivered {
+        stats.messages += 1;
+        stats.latencies_ms.push(latency.as_secs_f64() * 1000.0);
+    } else {
+        stats.errors += 1;
+    }
+}
+
+async fn consume_until(consumer: StreamConsumer, deadline: Instant) -> ConsumerStats {
+    let mut stats = ConsumerStats::default();
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        match tokio::time::timeout(remaining, consumer.recv()).await {
+            Ok(Ok(message)) => {
+                stats.messages += 1;
+                stats.bytes += message.payload_len() as u64;
+            }
+            Ok(Err(_)) => stats.errors += 1,
+            Err(_) => break,
+        }
+    }
+    stats
+}
+
+fn percentile(values: &mut [f64], p: f64) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let index = ((values.len() as f64 * p).ceil() as usize).saturating_sub(1);
+    values[index.min(values.len() - 1)]
+}
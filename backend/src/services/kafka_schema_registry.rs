@@ -0,0 +1,603 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use reqwest::{Client as HttpClient, Method, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tracing::info;
+
+use crate::config::Config;
+use crate::errors::AppError;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
+use crate::services::kafka::KafkaService;
+
+const SCHEMA_REGISTRY_CONTENT_TYPE: &str = "application/vnd.schemaregistry.v1+json";
+
+// Confluent error codes for a subject or version that doesn't exist yet
+const SUBJECT_NOT_FOUND: i64 = 40401;
+const VERSION_NOT_FOUND: i64 = 40402;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CompatibilityLevel {
+    Backward,
+    BackwardTransitive,
+    Forward,
+    ForwardTransitive,
+    Full,
+    FullTransitive,
+    None,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompatibilityCheckResult {
+    pub is_compatible: bool,
+    pub incompatible_details: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegisteredSchema {
+    pub id: i32,
+    pub schema: String,
+    pub schema_type: String,
+}
+
+// Client for a Confluent-compatible Schema Registry. Topics map to their value
+// subject (`<topic>-value`) following the default TopicNameStrategy.
+pub struct SchemaRegistryClient {
+    http_client: HttpClient,
+    base_url: String,
+}
+
+impl SchemaRegistryClient {
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            http_client: HttpClient::new(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub fn value_subject(topic: &str) -> String {
+        format!("{}-value", topic)
+    }
+
+    // Tests an Avro schema against the latest registered version of the topic's
+    // subject. The registry always evaluates the subject's configured level, so
+    // the check is refused when that differs from `compatibility_level` rather
+    // than silently answering for another level.
+    pub async fn check_compatibility(
+        &self,
+        topic: &str,
+        schema_json: &str,
+        compatibility_level: CompatibilityLevel,
+    ) -> Result<CompatibilityCheckResult, AppError> {
+        serde_json::from_str::<Value>(schema_json)
+            .map_err(|e| AppError::Validation(format!("Schema is not valid JSON: {}", e)))?;
+
+        if compatibility_level == CompatibilityLevel::None {
+            return Ok(CompatibilityCheckResult {
+                is_compatible: true,
+                incompatible_details: None,
+            });
+        }
+
+        let configured = self.get_compatibility_level(topic).await?;
+        if configured != compatibility_level {
+            return Err(AppError::BadRequest(format!(
+                "Subject {} uses {:?} compatibility; set it to {:?} before checking",
+                Self::value_subject(topic),
+                configured,
+                compatibility_level
+            )));
+        }
+
+        let path = format!(
+            "compatibility/subjects/{}/versions/latest?verbose=true",
+            Self::value_subject(topic)
+        );
+        let body = json!({ "schema": schema_json, "schemaType": "AVRO" });
+        match self.request(Method::POST, &path, Some(&body)).await {
+            Ok(response) => {
+                let is_compatible = response["is_compatible"].as_bool().unwrap_or(false);
+                let messages: Vec<String> = response["messages"]
+                    .as_array()
+                    .map(|messages| {
+                        messages
+                            .iter()
+                            .filter_map(|m| m.as_str().map(String::from))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                Ok(CompatibilityCheckResult {
+                    is_compatible,
+                    incompatible_details: if is_compatible || messages.is_empty() {
+                        None
+                    } else {
+                        Some(messages)
+                    },
+                })
+            }
+            // Nothing registered yet, so any schema is compatible
+            Err(RegistryError::Api { error_code, .. })
+                if error_code == SUBJECT_NOT_FOUND || error_code == VERSION_NOT_FOUND =>
+            {
+                Ok(CompatibilityCheckResult {
+                    is_compatible: true,
+                    incompatible_details: None,
+                })
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    // The subject's own level, or the registry's global default when unset
+    pub async fn get_compatibility_level(
+        &self,
+        topic: &str,
+    ) -> Result<CompatibilityLevel, AppError> {
+        let path = format!("config/{}?defaultToGlobal=true", Self::value_subject(topic));
+        let response = match self.request(Method::GET, &path, None).await {
+            Ok(response) => response,
+            // Registries without defaultToGlobal report a missing subject config
+            Err(RegistryError::Api { status, .. }) if status == StatusCode::NOT_FOUND => {
+                self.request(Method::GET, "config", None).await?
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        serde_json::from_value(response["compatibilityLevel"].clone()).map_err(|e| {
+            AppError::ExternalService(format!(
+                "Unexpected compatibility level from schema registry: {}",
+                e
+            ))
+        })
+    }
+
+    pub async fn set_compatibility_level(
+        &self,
+        topic: &str,
+        level: CompatibilityLevel,
+    ) -> Result<CompatibilityLevel, AppError> {
+        let subject = Self::value_subject(topic);
+        info!(
+            "Setting compatibility of subject {} to {:?}",
+            subject, level
+        );
+        let response = self
+            .request(
+                Method::PUT,
+                &format!("config/{}", subject),
+                Some(&json!({ "compatibility": level })),
+            )
+            .await?;
+
+        serde_json::from_value(response["compatibility"].clone()).map_err(|e| {
+            AppError::ExternalService(format!(
+                "Unexpected compatibility level from schema registry: {}",
+                e
+            ))
+        })
+    }
+
+    pub async fn list_subjects(&self) -> Result<Vec<String>, AppError> {
+        let response = self.request(Method::GET, "subjects", None).await?;
+        parse_response(response, "subject list")
+    }
+
+    pub async fn list_versions(&self, subject: &str) -> Result<Vec<i32>, AppError> {
+        let response = self
+            .request(Method::GET, &format!("subjects/{}/versions", subject), None)
+            .await
+            .map_err(|e| e.into_app_error(subject))?;
+        parse_response(response, "version list")
+    }
+
+    pub async fn get_schema_by_version(
+        &self,
+        subject: &str,
+        version: i32,
+    ) -> Result<RegisteredSchema, AppError> {
+        let response = self
+            .request(
+                Method::GET,
+                &format!("subjects/{}/versions/{}", subject, version),
+                None,
+            )
+            .await
+            .map_err(|e| e.into_app_error(subject))?;
+
+        let id = response["id"].as_i64().ok_or_else(|| {
+            AppError::ExternalService(
+                "Schema registry response is missing the schema id".to_string(),
+            )
+        })?;
+        let schema = response["schema"].as_str().ok_or_else(|| {
+            AppError::ExternalService("Schema registry response is missing the schema".to_string())
+        })?;
+        Ok(RegisteredSchema {
+            id: id as i32,
+            schema: schema.to_string(),
+            // The registry leaves schemaType out for Avro, its default
+            schema_type: response["schemaType"]
+                .as_str()
+                .unwrap_or("AVRO")
+                .to_string(),
+        })
+    }
+
+    // Soft delete; the registry keeps the schema id so existing messages still decode
+    pub async fn delete_subject_version(
+        &self,
+        subject: &str,
+        version: i32,
+    ) -> Result<i32, AppError> {
+        info!("Deleting version {} of subject {}", version, subject);
+        let response = self
+            .request(
+                Method::DELETE,
+                &format!("subjects/{}/versions/{}", subject, version),
+                None,
+            )
+            .await
+            .map_err(|e| e.into_app_error(subject))?;
+        parse_response(response, "deleted version")
+    }
+
+    // Soft deletes every version and returns the ones removed
+    pub async fn delete_subject(&self, subject: &str) -> Result<Vec<i32>, AppError> {
+        info!("Deleting subject {}", subject);
+        let response = self
+            .request(Method::DELETE, &format!("subjects/{}", subject), None)
+            .await
+            .map_err(|e| e.into_app_error(subject))?;
+        parse_response(response, "deleted versions")
+    }
+
+    async fn request(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<Value, RegistryError> {
+        let mut builder = self
+            .http_client
+            .request(method, format!("{}/{}", self.base_url, path))
+            .header(reqwest::header::ACCEPT, SCHEMA_REGISTRY_CONTENT_TYPE);
+        if let Some(body) = body {
+            builder = builder
+                .header(reqwest::header::CONTENT_TYPE, SCHEMA_REGISTRY_CONTENT_TYPE)
+                .body(body.to_string());
+        }
+
+        let response = builder.send().await.map_err(RegistryError::Http)?;
+        let status = response.status();
+        let payload: Value = response.json().await.unwrap_or(Value::Null);
+        if status.is_success() {
+            return Ok(payload);
+        }
+        Err(RegistryError::Api {
+            status,
+            error_code: payload["error_code"].as_i64().unwrap_or_default(),
+            message: payload["message"]
+                .as_str()
+                .map(String::from)
+                .unwrap_or_else(|| payload.to_string()),
+        })
+    }
+}
+
+fn parse_response<T: serde::de::DeserializeOwned>(
+    response: Value,
+    what: &str,
+) -> Result<T, AppError> {
+    serde_json::from_value(response).map_err(|e| {
+        AppError::ExternalService(format!("Unexpected {} from schema registry: {}", what, e))
+    })
+}
+
+// Keeps the registry's error code around so callers can tell "not registered
+// yet" apart from real failures before it is flattened into an AppError.
+enum RegistryError {
+    Http(reqwest::Error),
+    Api {
+        status: StatusCode,
+        error_code: i64,
+        message: String,
+    },
+}
+
+impl RegistryError {
+    // Missing subjects and versions become NotFound so the API can answer 404
+    fn into_app_error(self, subject: &str) -> AppError {
+        match self {
+            RegistryError::Api {
+                error_code,
+                message,
+                ..
+            } if error_code == SUBJECT_NOT_FOUND || error_code == VERSION_NOT_FOUND => {
+                AppError::NotFound(format!("Subject {}: {}", subject, message))
+            }
+            e => e.into(),
+        }
+    }
+}
+
+impl From<RegistryError> for AppError {
+    fn from(err: RegistryError) -> Self {
+        match err {
+            RegistryError::Http(e) => e.into(),
+            RegistryError::Api {
+                status,
+                error_code,
+                message,
+            } => AppError::ExternalService(format!(
+                "Schema registry returned {} (error {}): {}",
+                status, error_code, message
+            )),
+        }
+    }
+}
+
+// Resolves the registry configured on a Kafka cluster and runs subject
+// operations against it, auditing the destructive ones.
+pub struct SchemaRegistryService {
+    kafka_service: Arc<KafkaService>,
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+}
+
+impl SchemaRegistryService {
+    pub fn new(kafka_service: Arc<KafkaService>) -> Self {
+        Self {
+            kafka_service,
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
+    }
+
+    pub async fn client_for_cluster(
+        &self,
+        cluster_id: &str,
+        config: &Config,
+    ) -> Result<SchemaRegistryClient, AppError> {
+        let cluster = self.kafka_service.get_cluster(cluster_id, config).await?;
+        let url = cluster.schema_registry_url.ok_or_else(|| {
+            AppError::BadRequest(format!(
+                "Kafka cluster {} has no schema registry configured",
+                cluster_id
+            ))
+        })?;
+        Ok(SchemaRegistryClient::new(&url))
+    }
+
+    pub async fn list_subjects(
+        &self,
+        cluster_id: &str,
+        config: &Config,
+    ) -> Result<Vec<String>, AppError> {
+        self.client_for_cluster(cluster_id, config)
+            .await?
+            .list_subjects()
+            .await
+    }
+
+    pub async fn list_versions(
+        &self,
+        cluster_id: &str,
+        subject: &str,
+        config: &Config,
+    ) -> Result<Vec<i32>, AppError> {
+        self.client_for_cluster(cluster_id, config)
+            .await?
+            .list_versions(subject)
+            .await
+    }
+
+    pub async fn get_schema_by_version(
+        &self,
+        cluster_id: &str,
+        subject: &str,
+        version: i32,
+        config: &Config,
+    ) -> Result<RegisteredSchema, AppError> {
+        self.client_for_cluster(cluster_id, config)
+            .await?
+            .get_schema_by_version(subject, version)
+            .await
+    }
+
+    pub async fn delete_subject_version(
+        &self,
+        cluster_id: &str,
+        subject: &str,
+        version: i32,
+        config: &Config,
+        audit_ctx: &AuditContext,
+    ) -> Result<i32, AppError> {
+        let deleted = self
+            .client_for_cluster(cluster_id, config)
+            .await?
+            .delete_subject_version(subject, version)
+            .await?;
+
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::DELETE, "SchemaSubject", subject)
+                .with_payload(json!({ "cluster_id": cluster_id, "version": deleted })),
+        )
+        .await;
+        Ok(deleted)
+    }
+
+    pub async fn delete_subject(
+        &self,
+        cluster_id: &str,
+        subject: &str,
+        config: &Config,
+        audit_ctx: &AuditContext,
+    ) -> Result<Vec<i32>, AppError> {
+        let deleted = self
+            .client_for_cluster(cluster_id, config)
+            .await?
+            .delete_subject(subject)
+            .await?;
+
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::DELETE, "SchemaSubject", subject)
+                .with_payload(json!({ "cluster_id": cluster_id, "versions": deleted })),
+        )
+        .await;
+        Ok(deleted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use httpmock::prelude::*;
+
+    #[tokio::test]
+    async fn lists_subjects_and_versions() {
+        let server = MockServer::start_async().await;
+        let subjects = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/subjects");
+                then.status(200)
+                    .json_body(json!(["orders-value", "payments-value"]));
+            })
+            .await;
+        let versions = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/subjects/orders-value/versions");
+                then.status(200).json_body(json!([1, 2, 3]));
+            })
+            .await;
+
+        let client = SchemaRegistryClient::new(&server.base_url());
+        assert_eq!(
+            client.list_subjects().await.unwrap(),
+            vec!["orders-value".to_string(), "payments-value".to_string()]
+        );
+        assert_eq!(
+            client.list_versions("orders-value").await.unwrap(),
+            vec![1, 2, 3]
+        );
+        subjects.assert_async().await;
+        versions.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn schema_type_defaults_to_avro() {
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/subjects/orders-value/versions/2");
+                then.status(200).json_body(json!({
+                    "subject": "orders-value",
+                    "version": 2,
+                    "id": 17,
+                    "schema": "{\"type\":\"string\"}"
+                }));
+            })
+            .await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/subjects/events-value/versions/1");
+                then.status(200).json_body(json!({
+                    "subject": "events-value",
+                    "version": 1,
+                    "id": 18,
+                    "schemaType": "PROTOBUF",
+                    "schema": "syntax = \"proto3\";"
+                }));
+            })
+            .await;
+
+        let client = SchemaRegistryClient::new(&server.base_url());
+        let avro = client
+            .get_schema_by_version("orders-value", 2)
+            .await
+            .unwrap();
+        assert_eq!(
+            avro,
+            RegisteredSchema {
+                id: 17,
+                schema: "{\"type\":\"string\"}".to_string(),
+                schema_type: "AVRO".to_string(),
+            }
+        );
+        let proto = client
+            .get_schema_by_version("events-value", 1)
+            .await
+            .unwrap();
+        assert_eq!(proto.schema_type, "PROTOBUF");
+    }
+
+    #[tokio::test]
+    async fn deletes_versions_and_subjects() {
+        let server = MockServer::start_async().await;
+        let delete_version = server
+            .mock_async(|when, then| {
+                when.method(DELETE)
+                    .path("/subjects/orders-value/versions/1");
+                then.status(200).json_body(json!(1));
+            })
+            .await;
+        let delete_subject = server
+            .mock_async(|when, then| {
+                when.method(DELETE).path("/subjects/orders-value");
+                then.status(200).json_body(json!([2, 3]));
+            })
+            .await;
+
+        let client = SchemaRegistryClient::new(&server.base_url());
+        assert_eq!(
+            client
+                .delete_subject_version("orders-value", 1)
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            client.delete_subject("orders-value").await.unwrap(),
+            vec![2, 3]
+        );
+        delete_version.assert_async().await;
+        delete_subject.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn missing_subject_is_not_found() {
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/subjects/unknown-value/versions");
+                then.status(404).json_body(json!({
+                    "error_code": 40401,
+                    "message": "Subject 'unknown-value' not found."
+                }));
+            })
+            .await;
+
+        let client = SchemaRegistryClient::new(&server.base_url());
+        let err = client.list_versions("unknown-value").await.unwrap_err();
+        assert!(matches!(err, AppError::NotFound(_)));
+    }
+}
@@ -0,0 +1,218 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Periodically samples partition high watermarks of every known Kafka cluster and
+// stores the per-partition produce rate derived from consecutive samples.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use tracing::{debug, warn};
+
+use crate::config::reloader::SharedConfig;
+use crate::config::{Config, KafkaTopicMonitorConfig};
+use crate::errors::AppError;
+use crate::models::kafka_topic_throughput::KafkaTopicThroughputSample;
+use crate::repositories::kafka_topic_throughput::KafkaTopicThroughputRepository;
+use crate::services::kafka::KafkaService;
+
+// Average message sizes change slowly; resampling them every round would mean
+// consuming from every topic each interval
+const MESSAGE_SIZE_TTL: Duration = Duration::from_secs(3600);
+
+// (cluster_id, topic, partition)
+type PartitionKey = (String, String, i32);
+
+#[derive(Debug)]
+pub struct KafkaTopicMonitor {
+    kafka_service: Arc<KafkaService>,
+    repo: Arc<KafkaTopicThroughputRepository>,
+    // Last high watermark seen per partition and when it was read
+    previous: Mutex<HashMap<PartitionKey, (i64, Instant)>>,
+    message_sizes: Mutex<HashMap<(String, String), (f64, Instant)>>,
+}
+
+impl KafkaTopicMonitor {
+    pub fn new(
+        kafka_service: Arc<KafkaService>,
+        repo: Arc<KafkaTopicThroughputRepository>,
+    ) -> Self {
+        Self {
+            kafka_service,
+            repo,
+            previous: Mutex::new(HashMap::new()),
+            message_sizes: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // Reads the current watermarks of a cluster and stores one sample per partition
+    // seen in the previous round. The first round for a partition only records the
+    // baseline, so it returns no samples for it.
+    pub async fn sample_cluster(
+        &self,
+        cluster_id: &str,
+        config: &Config,
+    ) -> Result<Vec<KafkaTopicThroughputSample>, AppError> {
+        let watermarks = self
+            .kafka_service
+            .fetch_partition_watermarks(cluster_id, config)
+            .await?;
+        let now = Instant::now();
+        let timestamp = Utc::now();
+
+        let mut rates: Vec<(String, i32, f64)> = Vec::new();
+        {
+            let mut previous = self.previous.lock().unwrap();
+            for w in &watermarks {
+                let key = (cluster_id.to_string(), w.topic.clone(), w.partition);
+                let high = w.offsets.latest;
+                if let Some((prev_high, prev_at)) = previous.insert(key, (high, now)) {
+                    let elapsed = now.duration_since(prev_at).as_secs_f64();
+                    if elapsed > 0.0 {
+                        // Watermarks only move backwards when a topic is recreated
+                        let delta = (high - prev_high).max(0) as f64;
+                        rates.push((w.topic.clone(), w.partition, delta / elapsed));
+                    }
+                }
+            }
+        }
+
+        let mut samples = Vec::with_capacity(rates.len());
+        for (topic_name, partition, messages_per_sec) in rates {
+            let message_size = self
+                .message_size(cluster_id, &topic_name, messages_per_sec, config)
+                .await;
+            samples.push(KafkaTopicThroughputSample {
+                topic_name,
+                partition,
+                cluster_id: cluster_id.to_string(),
+                timestamp,
+                messages_per_sec,
+                bytes_per_sec_estimate: messages_per_sec * message_size,
+            });
+        }
+
+        self.repo.insert_samples(&samples).await?;
+        Ok(samples)
+    }
+
+    pub async fn get_topic_throughput_history(
+        &self,
+        cluster_id: &str,
+        topic_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        partition: Option<i32>,
+    ) -> Result<Vec<KafkaTopicThroughputSample>, AppError> {
+        if start_time > end_time {
+            return Err(AppError::BadRequest(
+                "start_time must not be after end_time".to_string(),
+            ));
+        }
+        self.repo
+            .find_history(cluster_id, topic_name, partition, start_time, end_time)
+            .await
+    }
+
+    // Samples every stored and configured cluster on the configured interval.
+    // The config is re-read each round so hot-reloaded clusters are picked up.
+    pub fn start(self: &Arc<Self>, config: SharedConfig, monitor: KafkaTopicMonitorConfig) {
+        if !monitor.enabled {
+            return;
+        }
+        let service = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(Duration::from_secs(monitor.interval_secs.max(1)));
+            loop {
+                interval.tick().await;
+                let config = config.snapshot();
+                for cluster_id in service.cluster_ids(&config).await {
+                    match service.sample_cluster(&cluster_id, &config).await {
+                        Ok(samples) => debug!(
+                            "Kafka cluster {}: stored {} throughput samples",
+                            cluster_id,
+                            samples.len()
+                        ),
+                        Err(e) => warn!(
+                            "Failed to sample topic throughput for Kafka cluster {}: {}",
+                            cluster_id, e
+                        ),
+                    }
+                }
+                let cutoff = Utc::now() - chrono::Duration::days(monitor.retention_days.max(1));
+                if let Err(e) = service.repo.delete_older_than(cutoff).await {
+                    warn!("Failed to prune Kafka throughput samples: {}", e);
+                }
+            }
+        });
+    }
+
+    async fn cluster_ids(&self, config: &Config) -> Vec<String> {
+        let mut ids: Vec<String> = match self.kafka_service.list_clusters().await {
+            Ok(clusters) => clusters
+                .iter()
+                .filter_map(|c| c.get("id").and_then(|id| id.as_str()).map(str::to_string))
+                .collect(),
+            Err(e) => {
+                warn!("Failed to list stored Kafka clusters: {}", e);
+                Vec::new()
+            }
+        };
+        ids.extend(config.kafka.clusters.iter().map(|c| c.name.clone()));
+        ids
+    }
+
+    async fn message_size(
+        &self,
+        cluster_id: &str,
+        topic_name: &str,
+        messages_per_sec: f64,
+        config: &Config,
+    ) -> f64 {
+        let key = (cluster_id.to_string(), topic_name.to_string());
+        let cached = self.message_sizes.lock().unwrap().get(&key).copied();
+        if let Some((size, sampled_at)) = cached {
+            if sampled_at.elapsed() < MESSAGE_SIZE_TTL {
+                return size;
+            }
+        }
+        // Idle topics contribute no bytes either way; skip the sampling cost
+        if messages_per_sec == 0.0 {
+            return 0.0;
+        }
+        match self
+            .kafka_service
+            .estimate_message_size(cluster_id, topic_name, config)
+            .await
+        {
+            Ok(size) => {
+                self.message_sizes
+                    .lock()
+                    .unwrap()
+                    .insert(key, (size, Instant::now()));
+                size
+            }
+            Err(e) => {
+                warn!(
+                    "Failed to sample message size for {}/{}: {}",
+                    cluster_id, topic_name, e
+                );
+                0.0
+            }
+        }
+    }
+}
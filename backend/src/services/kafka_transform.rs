@@ -0,0 +1,466 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use jaq_interpret::{Ctx, Filter, FilterT, ParseCtx, RcIter, Val};
+use serde_json::{Map, Number, Value};
+
+use crate::errors::AppError;
+use crate::services::kafka::{KafkaMessage, MessageTransformation};
+
+/// A consumed message as raw bytes. `value` is `None` for tombstones.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawMessage {
+    pub key: Option<Vec<u8>>,
+    pub value: Option<Vec<u8>>,
+    pub headers: Vec<(String, Option<Vec<u8>>)>,
+}
+
+/// Applies a `MessageTransformation` to messages. Building the pipeline parses the jq filter
+/// and schema once, so a migration can reuse it for every message.
+pub struct MessageTransformPipeline<'a> {
+    transform: &'a MessageTransformation,
+    jq_filter: Option<Filter>,
+    value_schema: Option<Value>,
+}
+
+impl<'a> MessageTransformPipeline<'a> {
+    pub fn new(transform: &'a MessageTransformation) -> Result<Self, AppError> {
+        let jq_filter = transform
+            .jq_filter
+            .as_deref()
+            .map(compile_jq_filter)
+            .transpose()?;
+        let value_schema = transform
+            .value_schema_coercion
+            .as_deref()
+            .map(|schema| {
+                serde_json::from_str(schema)
+                    .map_err(|e| AppError::BadRequest(format!("Invalid value schema: {}", e)))
+            })
+            .transpose()?;
+
+        Ok(Self {
+            transform,
+            jq_filter,
+            value_schema,
+        })
+    }
+
+    /// One-off transformation of a single message
+    pub fn apply(
+        transform: &MessageTransformation,
+        message: &KafkaMessage,
+    ) -> Result<KafkaMessage, AppError> {
+        MessageTransformPipeline::new(transform)?.transform(message)
+    }
+
+    // Headers are removed before additions so a header can be replaced; the jq filter runs
+    // before the schema coercion so the filter's output is what gets coerced.
+    pub fn transform(&self, message: &KafkaMessage) -> Result<KafkaMessage, AppError> {
+        let mut headers = message.headers.clone().unwrap_or_default();
+        if let Some(removals) = &self.transform.header_removals {
+            headers.retain(|(key, _)| !removals.contains(key));
+        }
+        if let Some(additions) = &self.transform.header_additions {
+            headers.extend(additions.iter().cloned());
+        }
+
+        let key = match &self.transform.key_prefix {
+            Some(prefix) => message.key.as_ref().map(|k| format!("{}{}", prefix, k)),
+            None => message.key.clone(),
+        };
+
+        let value = if self.jq_filter.is_some() || self.value_schema.is_some() {
+            let mut json: Value = serde_json::from_str(&message.value).map_err(|e| {
+                AppError::BadRequest(format!("Message value is not valid JSON: {}", e))
+            })?;
+            if let Some(filter) = &self.jq_filter {
+                json = run_jq_filter(filter, json)?;
+            }
+            if let Some(schema) = &self.value_schema {
+                json = coerce_to_schema(json, schema, "$")?;
+            }
+            json.to_string()
+        } else {
+            message.value.clone()
+        };
+
+        Ok(KafkaMessage {
+            key,
+            value,
+            headers: if headers.is_empty() && message.headers.is_none() {
+                None
+            } else {
+                Some(headers)
+            },
+        })
+    }
+
+    /// Byte-level variant of `transform` used when copying messages between topics. The value
+    /// is only decoded when a jq filter or schema coercion has to read it, so binary payloads
+    /// pass through untouched, and tombstones are forwarded as tombstones.
+    pub fn transform_raw(&self, message: RawMessage) -> Result<RawMessage, AppError> {
+        let mut headers = message.headers;
+        if let Some(removals) = &self.transform.header_removals {
+            headers.retain(|(key, _)| !removals.contains(key));
+        }
+        if let Some(additions) = &self.transform.header_additions {
+            headers.extend(
+                additions
+                    .iter()
+                    .map(|(k, v)| (k.clone(), Some(v.as_bytes().to_vec()))),
+            );
+        }
+
+        let key = match &self.transform.key_prefix {
+            Some(prefix) => message.key.map(|k| [prefix.as_bytes(), &k].concat()),
+            None => message.key,
+        };
+
+        let value = match message.value {
+            Some(value) if self.jq_filter.is_some() || self.value_schema.is_some() => {
+                let mut json: Value = serde_json::from_slice(&value).map_err(|e| {
+                    AppError::BadRequest(format!("Message value is not valid JSON: {}", e))
+                })?;
+                if let Some(filter) = &self.jq_filter {
+                    json = run_jq_filter(filter, json)?;
+                }
+                if let Some(schema) = &self.value_schema {
+                    json = coerce_to_schema(json, schema, "$")?;
+                }
+                Some(json.to_string().into_bytes())
+            }
+            value => value,
+        };
+
+        Ok(RawMessage {
+            key,
+            value,
+            headers,
+        })
+    }
+}
+
+fn compile_jq_filter(filter: &str) -> Result<Filter, AppError> {
+    let mut defs = ParseCtx::new(Vec::new());
+    defs.insert_natives(jaq_core::core());
+    defs.insert_defs(jaq_std::std());
+
+    let (parsed, errs) = jaq_parse::parse(filter, jaq_parse::main());
+    let parsed = match parsed {
+        Some(parsed) if errs.is_empty() => parsed,
+        _ => {
+            let reasons: Vec<String> = errs.iter().map(|e| e.to_string()).collect();
+            return Err(AppError::BadRequest(format!(
+                "Invalid jq filter: {}",
+                reasons.join("; ")
+            )));
+        }
+    };
+
+    let compiled = defs.compile(parsed);
+    if !defs.errs.is_empty() {
+        let reasons: Vec<String> = defs.errs.iter().map(|(e, _)| e.to_string()).collect();
+        return Err(AppError::BadRequest(format!(
+            "Invalid jq filter: {}",
+            reasons.join("; ")
+        )));
+    }
+    Ok(compiled)
+}
+
+// A message maps to exactly one message, so the filter must yield exactly one output
+fn run_jq_filter(filter: &Filter, input: Value) -> Result<Value, AppError> {
+    let inputs = RcIter::new(core::iter::empty());
+    let mut outputs = filter.run((Ctx::new([], &inputs), Val::from(input)));
+
+    let first = outputs
+        .next()
+        .ok_or_else(|| AppError::BadRequest("jq filter produced no output".to_string()))?
+        .map_err(|e| AppError::BadRequest(format!("jq filter failed: {}", e)))?;
+    if outputs.next().is_some() {
+        return Err(AppError::BadRequest(
+            "jq filter produced more than one output".to_string(),
+        ));
+    }
+    Ok(Value::from(first))
+}
+
+// Best-effort coercion: converts scalars to the declared `type` (e.g. "42" to 42), fills
+// property defaults, drops properties when `additionalProperties` is false, and recurses
+// into `properties` and `items`. Fails when a value cannot be converted or a required
+// property is missing.
+fn coerce_to_schema(value: Value, schema: &Value, path: &str) -> Result<Value, AppError> {
+    let Some(schema) = schema.as_object() else {
+        return Ok(value);
+    };
+
+    let types: Vec<&str> = match schema.get("type") {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(ts)) => ts.iter().filter_map(|t| t.as_str()).collect(),
+        _ => Vec::new(),
+    };
+    let value = if types.is_empty() || types.iter().any(|t| matches_type(&value, t)) {
+        value
+    } else {
+        types
+            .iter()
+            .find_map(|t| convert_scalar(&value, t))
+            .ok_or_else(|| {
+                AppError::BadRequest(format!(
+                    "Cannot coerce value at {} to {}",
+                    path,
+                    types.join(" | ")
+                ))
+            })?
+    };
+
+    match value {
+        Value::Object(mut map) => {
+            if let Some(Value::Object(properties)) = schema.get("properties") {
+                for (key, property_schema) in properties {
+                    match map.remove(key) {
+                        Some(v) => {
+                            let coerced =
+                                coerce_to_schema(v, property_schema, &format!("{}.{}", path, key))?;
+                            map.insert(key.clone(), coerced);
+                        }
+                        None => {
+                            if let Some(default) = property_schema.get("default") {
+                                map.insert(key.clone(), default.clone());
+                            }
+                        }
+                    }
+                }
+                if schema.get("additionalProperties") == Some(&Value::Bool(false)) {
+                    map.retain(|key, _| properties.contains_key(key));
+                }
+            }
+            if let Some(Value::Array(required)) = schema.get("required") {
+                if let Some(missing) = required
+                    .iter()
+                    .filter_map(|r| r.as_str())
+                    .find(|r| !map.contains_key(*r))
+                {
+                    return Err(AppError::BadRequest(format!(
+                        "Missing required property {}.{}",
+                        path, missing
+                    )));
+                }
+            }
+            Ok(Value::Object(map))
+        }
+        Value::Array(items) => match schema.get("items") {
+            Some(item_schema) => items
+                .into_iter()
+                .enumerate()
+                .map(|(i, v)| coerce_to_schema(v, item_schema, &format!("{}[{}]", path, i)))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array),
+            None => Ok(Value::Array(items)),
+        },
+        other => Ok(other),
+    }
+}
+
+fn matches_type(value: &Value, schema_type: &str) -> bool {
+    match schema_type {
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "null" => value.is_null(),
+        _ => false,
+    }
+}
+
+fn convert_scalar(value: &Value, schema_type: &str) -> Option<Value> {
+    match (schema_type, value) {
+        ("string", Value::Number(n)) => Some(Value::String(n.to_string())),
+        ("string", Value::Bool(b)) => Some(Value::String(b.to_string())),
+        ("number", Value::String(s)) => s
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .and_then(Number::from_f64)
+            .map(Value::Number),
+        ("integer", Value::String(s)) => s.trim().parse::<i64>().ok().map(Value::from),
+        ("integer", Value::Number(n)) => n
+            .as_f64()
+            .filter(|f| f.fract() == 0.0 && f.abs() < i64::MAX as f64)
+            .map(|f| Value::from(f as i64)),
+        ("boolean", Value::String(s)) => match s.trim() {
+            "true" => Some(Value::Bool(true)),
+            "false" => Some(Value::Bool(false)),
+            _ => None,
+        },
+        ("object", Value::Null) => Some(Value::Object(Map::new())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transformation() -> MessageTransformation {
+        MessageTransformation {
+            key_prefix: None,
+            header_additions: None,
+            value_transformation: None,
+            jq_filter: None,
+            header_removals: None,
+            value_schema_coercion: None,
+        }
+    }
+
+    fn message(value: &str) -> KafkaMessage {
+        KafkaMessage {
+            key: Some("order-1".to_string()),
+            value: value.to_string(),
+            headers: Some(vec![
+                ("trace-id".to_string(), "abc".to_string()),
+                ("source".to_string(), "legacy".to_string()),
+            ]),
+        }
+    }
+
+    #[test]
+    fn test_key_prefix_and_header_changes() {
+        let transform = MessageTransformation {
+            key_prefix: Some("eu-".to_string()),
+            header_additions: Some(vec![("source".to_string(), "migrated".to_string())]),
+            header_removals: Some(vec!["source".to_string()]),
+            ..transformation()
+        };
+
+        let result = MessageTransformPipeline::apply(&transform, &message("not json")).unwrap();
+
+        assert_eq!(result.key.as_deref(), Some("eu-order-1"));
+        assert_eq!(result.value, "not json");
+        assert_eq!(
+            result.headers.unwrap(),
+            vec![
+                ("trace-id".to_string(), "abc".to_string()),
+                ("source".to_string(), "migrated".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_raw_transform_keeps_binary_values_and_tombstones() {
+        let transform = MessageTransformation {
+            key_prefix: Some("eu-".to_string()),
+            ..transformation()
+        };
+        let pipeline = MessageTransformPipeline::new(&transform).unwrap();
+        let binary = vec![0xff, 0x00, 0xfe];
+
+        let result = pipeline
+            .transform_raw(RawMessage {
+                key: Some(b"k".to_vec()),
+                value: Some(binary.clone()),
+                headers: vec![("bin".to_string(), Some(vec![0x80]))],
+            })
+            .unwrap();
+        assert_eq!(result.key, Some(b"eu-k".to_vec()));
+        assert_eq!(result.value, Some(binary));
+        assert_eq!(result.headers, vec![("bin".to_string(), Some(vec![0x80]))]);
+
+        let transform = MessageTransformation {
+            jq_filter: Some(".".to_string()),
+            ..transformation()
+        };
+        let pipeline = MessageTransformPipeline::new(&transform).unwrap();
+        let tombstone = pipeline
+            .transform_raw(RawMessage {
+                key: Some(b"k".to_vec()),
+                value: None,
+                headers: Vec::new(),
+            })
+            .unwrap();
+        assert_eq!(tombstone.value, None);
+    }
+
+    #[test]
+    fn test_jq_filter_rewrites_value() {
+        let transform = MessageTransformation {
+            jq_filter: Some("{id: .order_id, total: (.amount * 2)}".to_string()),
+            ..transformation()
+        };
+
+        let result =
+            MessageTransformPipeline::apply(&transform, &message(r#"{"order_id":7,"amount":5}"#))
+                .unwrap();
+
+        let value: Value = serde_json::from_str(&result.value).unwrap();
+        assert_eq!(value, serde_json::json!({"id": 7, "total": 10}));
+    }
+
+    #[test]
+    fn test_jq_filter_requires_single_output() {
+        let transform = MessageTransformation {
+            jq_filter: Some(".[]".to_string()),
+            ..transformation()
+        };
+
+        assert!(MessageTransformPipeline::apply(&transform, &message("[1,2]")).is_err());
+        assert!(MessageTransformPipeline::apply(&transform, &message("[]")).is_err());
+    }
+
+    #[test]
+    fn test_schema_coercion() {
+        let schema = r#"{
+            "type": "object",
+            "properties": {
+                "id": {"type": "integer"},
+                "price": {"type": "number"},
+                "active": {"type": "boolean"},
+                "sku": {"type": "string"},
+                "currency": {"type": "string", "default": "USD"}
+            },
+            "required": ["id"],
+            "additionalProperties": false
+        }"#;
+        let transform = MessageTransformation {
+            value_schema_coercion: Some(schema.to_string()),
+            ..transformation()
+        };
+
+        let result = MessageTransformPipeline::apply(
+            &transform,
+            &message(r#"{"id":"42","price":"9.5","active":"true","sku":1001,"extra":1}"#),
+        )
+        .unwrap();
+
+        let value: Value = serde_json::from_str(&result.value).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "id": 42,
+                "price": 9.5,
+                "active": true,
+                "sku": "1001",
+                "currency": "USD"
+            })
+        );
+
+        let err = MessageTransformPipeline::apply(&transform, &message(r#"{"id":"abc"}"#));
+        assert!(err.is_err());
+        let err = MessageTransformPipeline::apply(&transform, &message(r#"{"price":1}"#));
+        assert!(err.is_err());
+    }
+}
@@ -0,0 +1,477 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use k8s_openapi::api::admissionregistration::v1::{
+    MutatingWebhookConfiguration, ValidatingWebhookConfiguration, WebhookClientConfig,
+};
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::core::v1::{ConfigMap, Pod, Service};
+use kube::api::{Api, ApiResource, DynamicObject, GroupVersionKind, ListParams};
+use kube::ResourceExt;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::time::Duration;
+use tracing::{info, instrument, warn};
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::client::ClientFactory;
+
+// Same as the API server's default webhook timeout
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+const SIMULATOR_USERNAME: &str = "system:serviceaccount:mayyam:admission-simulator";
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookKind {
+    Validating,
+    Mutating,
+}
+
+/// Identifies a webhook registered in the cluster. The simulator only calls endpoints
+/// resolved from these registrations, never caller-supplied URLs.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookRef {
+    pub kind: WebhookKind,
+    /// Name of the Validating/MutatingWebhookConfiguration
+    pub configuration: String,
+    /// Name of the webhook entry inside the configuration
+    pub webhook: String,
+}
+
+impl std::fmt::Display for WebhookRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.configuration, self.webhook)
+    }
+}
+
+struct ResolvedWebhook {
+    url: String,
+    http: reqwest::Client,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AdmissionOperation {
+    Create,
+    Update,
+    Delete,
+    Connect,
+}
+
+impl AdmissionOperation {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AdmissionOperation::Create => "CREATE",
+            AdmissionOperation::Update => "UPDATE",
+            AdmissionOperation::Delete => "DELETE",
+            AdmissionOperation::Connect => "CONNECT",
+        }
+    }
+}
+
+// RFC 6902 operation returned by mutating webhooks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatchOp {
+    pub op: String,
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AdmissionSimulationResult {
+    pub allowed: bool,
+    pub reason: Option<String>,
+    pub warnings: Vec<String>,
+    pub patch: Option<Vec<PatchOp>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AdmissionBatchEntry {
+    pub kind: String,
+    pub name: String,
+    pub result: Option<AdmissionSimulationResult>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AdmissionBatchResult {
+    pub namespace: String,
+    pub total: usize,
+    pub allowed: usize,
+    pub denied: usize,
+    pub errors: usize,
+    pub entries: Vec<AdmissionBatchEntry>,
+}
+
+pub struct AdmissionWebhookSimulator;
+
+impl AdmissionWebhookSimulator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Send `resource` to the registered webhook wrapped in an `admission.k8s.io/v1`
+    /// AdmissionReview, flagged as a dry run, and report the webhook's decision.
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "test_admission_policy", webhook = %webhook))]
+    pub async fn test_policy(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        webhook: &WebhookRef,
+        resource: Value,
+        operation: AdmissionOperation,
+    ) -> Result<AdmissionSimulationResult, AppError> {
+        let ar = api_resource_for(&resource)?;
+        let client = ClientFactory::get_client(cluster_config).await?;
+        let target = resolve_webhook(&client, webhook).await?;
+        self.send_review(&target, &ar, resource, operation).await
+    }
+
+    /// Run every workload, Service and ConfigMap currently in `namespace` through the webhook.
+    /// Failures for individual resources are reported per entry instead of aborting the batch.
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "test_admission_policy_batch", webhook = %webhook))]
+    pub async fn test_policy_batch(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        webhook: &WebhookRef,
+        namespace: &str,
+        operation: AdmissionOperation,
+    ) -> Result<AdmissionBatchResult, AppError> {
+        let client = ClientFactory::get_client(cluster_config).await?;
+        let target = resolve_webhook(&client, webhook).await?;
+        let api_resources = [
+            ApiResource::erase::<Pod>(&()),
+            ApiResource::erase::<Deployment>(&()),
+            ApiResource::erase::<StatefulSet>(&()),
+            ApiResource::erase::<DaemonSet>(&()),
+            ApiResource::erase::<Service>(&()),
+            ApiResource::erase::<ConfigMap>(&()),
+        ];
+
+        let mut entries = Vec::new();
+        for ar in &api_resources {
+            let api: Api<DynamicObject> = Api::namespaced_with(client.clone(), namespace, ar);
+            let objects = api.list(&ListParams::default()).await.map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to list {} in namespace '{}': {}",
+                    ar.plural, namespace, e
+                ))
+            })?;
+
+            for object in objects {
+                let name = object.name_any();
+                // List items come back without apiVersion/kind, which webhooks rely on
+                let mut resource = serde_json::to_value(&object)?;
+                resource["apiVersion"] = json!(ar.api_version);
+                resource["kind"] = json!(ar.kind);
+
+                let entry = match self.send_review(&target, ar, resource, operation).await {
+                    Ok(result) => AdmissionBatchEntry {
+                        kind: ar.kind.clone(),
+                        name,
+                        result: Some(result),
+                        error: None,
+                    },
+                    Err(e) => {
+                        warn!(
+                            "Admission simulation failed for {} {}: {}",
+                            ar.kind, name, e
+                        );
+                        AdmissionBatchEntry {
+                            kind: ar.kind.clone(),
+                            name,
+                            result: None,
+                            error: Some(e.to_string()),
+                        }
+                    }
+                };
+                entries.push(entry);
+            }
+        }
+
+        let allowed = entries
+            .iter()
+            .filter(|e| e.result.as_ref().map_or(false, |r| r.allowed))
+            .count();
+        let errors = entries.iter().filter(|e| e.error.is_some()).count();
+        info!(
+            "Admission simulation for namespace {}: {} resources, {} allowed, {} errors",
+            namespace,
+            entries.len(),
+            allowed,
+            errors
+        );
+
+        Ok(AdmissionBatchResult {
+            namespace: namespace.to_string(),
+            total: entries.len(),
+            allowed,
+            denied: entries.len() - allowed - errors,
+            errors,
+            entries,
+        })
+    }
+
+    async fn send_review(
+        &self,
+        target: &ResolvedWebhook,
+        ar: &ApiResource,
+        resource: Value,
+        operation: AdmissionOperation,
+    ) -> Result<AdmissionSimulationResult, AppError> {
+        let uid = Uuid::new_v4().to_string();
+        let review = admission_review(&uid, ar, resource, operation);
+
+        let webhook_url = target.url.as_str();
+        let response = target
+            .http
+            .post(webhook_url)
+            .json(&review)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!("Failed to call webhook {}: {}", webhook_url, e))
+            })?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(AppError::ExternalService(format!(
+                "Webhook {} returned {}: {}",
+                webhook_url, status, body
+            )));
+        }
+        let body: Value = response.json().await.map_err(|e| {
+            AppError::ExternalService(format!(
+                "Webhook {} returned an invalid AdmissionReview: {}",
+                webhook_url, e
+            ))
+        })?;
+
+        parse_admission_response(&uid, &body)
+    }
+}
+
+impl Default for AdmissionWebhookSimulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Look up the webhook's endpoint and CA bundle from its registration in the cluster.
+async fn resolve_webhook(
+    client: &kube::Client,
+    webhook: &WebhookRef,
+) -> Result<ResolvedWebhook, AppError> {
+    let not_found = || {
+        AppError::NotFound(format!(
+            "Webhook '{}' not found in {:?} webhook configuration '{}'",
+            webhook.webhook, webhook.kind, webhook.configuration
+        ))
+    };
+    let map_err = |e: kube::Error| match e {
+        kube::Error::Api(ref ae) if ae.code == 404 => not_found(),
+        e => AppError::ExternalService(format!(
+            "Failed to read webhook configuration '{}': {}",
+            webhook.configuration, e
+        )),
+    };
+
+    let client_config = match webhook.kind {
+        WebhookKind::Validating => {
+            let api: Api<ValidatingWebhookConfiguration> = Api::all(client.clone());
+            api.get(&webhook.configuration)
+                .await
+                .map_err(map_err)?
+                .webhooks
+                .unwrap_or_default()
+                .into_iter()
+                .find(|w| w.name == webhook.webhook)
+                .map(|w| w.client_config)
+        }
+        WebhookKind::Mutating => {
+            let api: Api<MutatingWebhookConfiguration> = Api::all(client.clone());
+            api.get(&webhook.configuration)
+                .await
+                .map_err(map_err)?
+                .webhooks
+                .unwrap_or_default()
+                .into_iter()
+                .find(|w| w.name == webhook.webhook)
+                .map(|w| w.client_config)
+        }
+    }
+    .ok_or_else(not_found)?;
+
+    let url = webhook_url_for(&client_config).ok_or_else(|| {
+        AppError::Validation(format!("Webhook '{}' has no url or service", webhook))
+    })?;
+
+    let mut builder = reqwest::Client::builder().timeout(WEBHOOK_TIMEOUT);
+    if let Some(ca_bundle) = client_config.ca_bundle.as_ref() {
+        let cert = reqwest::Certificate::from_pem(&ca_bundle.0).map_err(|e| {
+            AppError::ExternalService(format!(
+                "Webhook '{}' has an invalid caBundle: {}",
+                webhook, e
+            ))
+        })?;
+        builder = builder.add_root_certificate(cert);
+    }
+    let http = builder.build().map_err(|e| {
+        AppError::Internal(format!(
+            "Failed to build HTTP client for webhook '{}': {}",
+            webhook, e
+        ))
+    })?;
+
+    Ok(ResolvedWebhook { url, http })
+}
+
+fn webhook_url_for(client_config: &WebhookClientConfig) -> Option<String> {
+    if let Some(url) = client_config.url.as_ref() {
+        return Some(url.clone());
+    }
+    let service = client_config.service.as_ref()?;
+    Some(format!(
+        "https://{}.{}.svc:{}{}",
+        service.name,
+        service.namespace,
+        service.port.unwrap_or(443),
+        service.path.as_deref().unwrap_or("")
+    ))
+}
+
+fn api_resource_for(resource: &Value) -> Result<ApiResource, AppError> {
+    let api_version = resource
+        .get("apiVersion")
+        .and_then(Value::as_str)
+        .ok_or_else(|| AppError::Validation("Resource is missing apiVersion".to_string()))?;
+    let kind = resource
+        .get("kind")
+        .and_then(Value::as_str)
+        .ok_or_else(|| AppError::Validation("Resource is missing kind".to_string()))?;
+    let (group, version) = api_version.split_once('/').unwrap_or(("", api_version));
+    Ok(ApiResource::from_gvk(&GroupVersionKind::gvk(
+        group, version, kind,
+    )))
+}
+
+fn admission_review(
+    uid: &str,
+    ar: &ApiResource,
+    resource: Value,
+    operation: AdmissionOperation,
+) -> Value {
+    let name = resource
+        .pointer("/metadata/name")
+        .cloned()
+        .unwrap_or(Value::Null);
+    let namespace = resource
+        .pointer("/metadata/namespace")
+        .cloned()
+        .unwrap_or(Value::Null);
+    // UPDATE is simulated as a no-op update; DELETE only carries the existing object
+    let (object, old_object) = match operation {
+        AdmissionOperation::Create | AdmissionOperation::Connect => (resource, Value::Null),
+        AdmissionOperation::Update => (resource.clone(), resource),
+        AdmissionOperation::Delete => (Value::Null, resource),
+    };
+    let kind = json!({
+        "group": ar.group,
+        "version": ar.version,
+        "kind": ar.kind,
+    });
+    let gvr = json!({
+        "group": ar.group,
+        "version": ar.version,
+        "resource": ar.plural,
+    });
+
+    json!({
+        "apiVersion": "admission.k8s.io/v1",
+        "kind": "AdmissionReview",
+        "request": {
+            "uid": uid,
+            "kind": kind,
+            "resource": gvr,
+            "requestKind": kind,
+            "requestResource": gvr,
+            "name": name,
+            "namespace": namespace,
+            "operation": operation.as_str(),
+            "userInfo": { "username": SIMULATOR_USERNAME },
+            "object": object,
+            "oldObject": old_object,
+            "dryRun": true,
+        }
+    })
+}
+
+fn parse_admission_response(
+    uid: &str,
+    review: &Value,
+) -> Result<AdmissionSimulationResult, AppError> {
+    let response = review
+        .get("response")
+        .ok_or_else(|| AppError::ExternalService("AdmissionReview has no response".to_string()))?;
+    if response.get("uid").and_then(Value::as_str) != Some(uid) {
+        return Err(AppError::ExternalService(format!(
+            "AdmissionReview response uid does not match request uid {}",
+            uid
+        )));
+    }
+
+    let allowed = response
+        .get("allowed")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    let reason = response
+        .pointer("/status/message")
+        .or_else(|| response.pointer("/status/reason"))
+        .and_then(Value::as_str)
+        .map(|s| s.to_string());
+    let warnings = response
+        .get("warnings")
+        .and_then(Value::as_array)
+        .map(|w| {
+            w.iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    let patch = match response.get("patch").and_then(Value::as_str) {
+        Some(encoded) => {
+            let decoded = BASE64.decode(encoded).map_err(|e| {
+                AppError::ExternalService(format!("Webhook patch is not valid base64: {}", e))
+            })?;
+            let ops: Vec<PatchOp> = serde_json::from_slice(&decoded).map_err(|e| {
+                AppError::ExternalService(format!("Webhook patch is not a JSON patch: {}", e))
+            })?;
+            Some(ops)
+        }
+        None => None,
+    };
+
+    Ok(AdmissionSimulationResult {
+        allowed,
+        reason,
+        warnings,
+        patch,
+    })
+}
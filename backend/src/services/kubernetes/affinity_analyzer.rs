@@ -0,0 +1,229 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use k8s_openapi::api::core::v1::{Node, NodeSelectorRequirement, NodeSelectorTerm, Pod, PodSpec};
+use kube::api::{Api, ListParams};
+use kube::ResourceExt;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use tracing::{info, instrument};
+
+use crate::errors::AppError;
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::client::ClientFactory;
+use crate::services::kubernetes::scheduling_analyzer::{blocks_scheduling, tolerates};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AffinityComplianceReport {
+    pub required_match_nodes: Vec<String>,
+    // Nodes matching at least one preferred term, with the summed term weights
+    pub preferred_match_nodes: Vec<(String, i32)>,
+    pub violating_nodes: Vec<String>,
+    pub current_node: Option<String>,
+    pub is_correctly_placed: bool,
+}
+
+pub struct AffinityAnalyzer;
+
+impl AffinityAnalyzer {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Evaluate the pod's node affinity (and nodeSelector, which is equally binding)
+    /// against the labels of every node in the cluster.
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "get_node_affinity_compliance", resource_type = "Pod"))]
+    pub async fn get_node_affinity_compliance(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        pod_name: &str,
+    ) -> Result<AffinityComplianceReport, AppError> {
+        let client = ClientFactory::get_client(cluster_config).await?;
+        let pod = Api::<Pod>::namespaced(client.clone(), namespace)
+            .get(pod_name)
+            .await
+            .map_err(|e| AppError::NotFound(format!("Pod {} not found: {}", pod_name, e)))?;
+        let spec = pod.spec.unwrap_or_default();
+        let nodes = list_nodes(client).await?;
+
+        let mut required_match_nodes = Vec::new();
+        let mut preferred_match_nodes = Vec::new();
+        let mut violating_nodes = Vec::new();
+        for node in &nodes {
+            let name = node.name_any();
+            if matches_required(&spec, node) {
+                required_match_nodes.push(name.clone());
+            } else {
+                violating_nodes.push(name.clone());
+            }
+            let score = preferred_score(&spec, node);
+            if score > 0 {
+                preferred_match_nodes.push((name, score));
+            }
+        }
+        preferred_match_nodes.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        let current_node = spec.node_name.clone();
+        let is_correctly_placed = current_node
+            .as_ref()
+            .map_or(false, |n| required_match_nodes.contains(n));
+        info!(
+            %pod_name,
+            required_matches = required_match_nodes.len(),
+            violations = violating_nodes.len(),
+            is_correctly_placed,
+            "Node affinity compliance evaluated"
+        );
+
+        Ok(AffinityComplianceReport {
+            required_match_nodes,
+            preferred_match_nodes,
+            violating_nodes,
+            current_node,
+            is_correctly_placed,
+        })
+    }
+
+    /// Nodes that accept new pods and satisfy the spec's required node affinity,
+    /// nodeSelector and tolerations. Resource fit is not considered.
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "find_schedulable_nodes", resource_type = "Node"))]
+    pub async fn find_schedulable_nodes(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        pod_spec: PodSpec,
+    ) -> Result<Vec<String>, AppError> {
+        let client = ClientFactory::get_client(cluster_config).await?;
+        let nodes = list_nodes(client).await?;
+        let tolerations = pod_spec.tolerations.clone().unwrap_or_default();
+
+        Ok(nodes
+            .iter()
+            .filter(|node| {
+                let node_spec = node.spec.clone().unwrap_or_default();
+                node_spec.unschedulable != Some(true)
+                    && matches_required(&pod_spec, node)
+                    && node_spec
+                        .taints
+                        .unwrap_or_default()
+                        .iter()
+                        .filter(|taint| blocks_scheduling(taint))
+                        .all(|taint| tolerations.iter().any(|t| tolerates(t, taint)))
+            })
+            .map(|node| node.name_any())
+            .collect())
+    }
+}
+
+impl Default for AffinityAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+async fn list_nodes(client: kube::Client) -> Result<Vec<Node>, AppError> {
+    Ok(Api::<Node>::all(client)
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| AppError::Kubernetes(e.to_string()))?
+        .items)
+}
+
+fn matches_required(spec: &PodSpec, node: &Node) -> bool {
+    let labels = node.labels();
+    let selector_matches = spec.node_selector.as_ref().map_or(true, |selector| {
+        selector.iter().all(|(k, v)| labels.get(k) == Some(v))
+    });
+
+    // Terms are ORed; a required affinity without terms matches no node
+    let affinity_matches = spec
+        .affinity
+        .as_ref()
+        .and_then(|a| a.node_affinity.as_ref())
+        .and_then(|na| {
+            na.required_during_scheduling_ignored_during_execution
+                .as_ref()
+        })
+        .map_or(true, |required| {
+            required
+                .node_selector_terms
+                .iter()
+                .any(|term| term_matches(term, node))
+        });
+
+    selector_matches && affinity_matches
+}
+
+fn preferred_score(spec: &PodSpec, node: &Node) -> i32 {
+    spec.affinity
+        .as_ref()
+        .and_then(|a| a.node_affinity.as_ref())
+        .and_then(|na| {
+            na.preferred_during_scheduling_ignored_during_execution
+                .as_ref()
+        })
+        .map_or(0, |preferred| {
+            preferred
+                .iter()
+                .filter(|p| term_matches(&p.preference, node))
+                .map(|p| p.weight)
+                .sum()
+        })
+}
+
+// Expressions and fields within a term are ANDed; an empty term matches nothing
+fn term_matches(term: &NodeSelectorTerm, node: &Node) -> bool {
+    let expressions = term.match_expressions.as_deref().unwrap_or_default();
+    let fields = term.match_fields.as_deref().unwrap_or_default();
+    if expressions.is_empty() && fields.is_empty() {
+        return false;
+    }
+
+    let labels = node.labels();
+    let mut node_fields = BTreeMap::new();
+    node_fields.insert("metadata.name".to_string(), node.name_any());
+
+    expressions
+        .iter()
+        .all(|req| requirement_matches(req, labels))
+        && fields
+            .iter()
+            .all(|req| requirement_matches(req, &node_fields))
+}
+
+fn requirement_matches(req: &NodeSelectorRequirement, labels: &BTreeMap<String, String>) -> bool {
+    let values = req.values.as_deref().unwrap_or_default();
+    let value = labels.get(&req.key);
+    match req.operator.as_str() {
+        "In" => value.map_or(false, |v| values.contains(v)),
+        "NotIn" => value.map_or(true, |v| !values.contains(v)),
+        "Exists" => value.is_some(),
+        "DoesNotExist" => value.is_none(),
+        "Gt" | "Lt" => {
+            let (Some(actual), Some(bound)) = (
+                value.and_then(|v| v.parse::<i64>().ok()),
+                values.first().and_then(|v| v.parse::<i64>().ok()),
+            ) else {
+                return false;
+            };
+            if req.operator == "Gt" {
+                actual > bound
+            } else {
+                actual < bound
+            }
+        }
+        _ => false,
+    }
+}
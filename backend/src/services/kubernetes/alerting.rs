@@ -0,0 +1,283 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use chrono::{DateTime, Duration, Utc};
+use k8s_openapi::api::core::v1::Event;
+use kube::api::ListParams;
+use kube::Api;
+use sea_orm::ActiveValue::Set;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+use crate::config::KubernetesAlertingConfig;
+use crate::errors::AppError;
+use crate::models::alert_firing::{ActiveModel as AlertFiringActiveModel, Model as AlertFiring};
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::models::InsightSeverity;
+use crate::repositories::alert_firing::AlertFiringRepository;
+use crate::repositories::cluster::ClusterRepository;
+use crate::services::kubernetes::client::ClientFactory;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertRule {
+    pub name: String,
+    // Event reason to match, e.g. OOMKilling, BackOff, Failed
+    pub event_reason: String,
+    pub event_count_threshold: u32,
+    pub window_secs: u64,
+    pub severity: InsightSeverity,
+    #[serde(default)]
+    pub notify_webhook: Option<String>,
+}
+
+pub struct AlertingService {
+    repo: Arc<AlertFiringRepository>,
+    http_client: reqwest::Client,
+}
+
+impl AlertingService {
+    pub fn new(repo: Arc<AlertFiringRepository>) -> Self {
+        Self {
+            repo,
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    /// Count events per involved object for each rule's reason within its window. Objects over
+    /// the threshold fire once until they drop back under it, at which point the alert resolves.
+    /// `namespace` of `None` evaluates events across all namespaces.
+    pub async fn evaluate_rules(
+        &self,
+        cluster_id: Uuid,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: Option<&str>,
+        rules: &[AlertRule],
+    ) -> Result<Vec<AlertFiring>, AppError> {
+        let client = ClientFactory::get_client(cluster_config).await?;
+        let api: Api<Event> = match namespace {
+            Some(ns) => Api::namespaced(client, ns),
+            None => Api::all(client),
+        };
+        let events = api
+            .list(&ListParams::default())
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?
+            .items;
+        let namespace_key = namespace.unwrap_or("*");
+        let now = Utc::now();
+
+        let mut fired = Vec::new();
+        for rule in rules {
+            let window_start = now - Duration::seconds(rule.window_secs as i64);
+            let counts = count_events(&events, &rule.event_reason, window_start);
+
+            let open = self
+                .repo
+                .find_open(cluster_id, namespace_key, &rule.name)
+                .await?;
+            let resolved: Vec<Uuid> = open
+                .iter()
+                .filter(|f| {
+                    counts
+                        .get(&f.involved_object)
+                        .map_or(true, |c| *c < rule.event_count_threshold)
+                })
+                .map(|f| f.id)
+                .collect();
+            self.repo.resolve(resolved).await?;
+
+            for (involved_object, count) in counts {
+                if count < rule.event_count_threshold
+                    || open.iter().any(|f| f.involved_object == involved_object)
+                {
+                    continue;
+                }
+                let firing = self
+                    .repo
+                    .create(AlertFiringActiveModel {
+                        id: Set(Uuid::new_v4()),
+                        cluster_id: Set(cluster_id),
+                        namespace: Set(namespace_key.to_string()),
+                        rule_name: Set(rule.name.clone()),
+                        event_reason: Set(rule.event_reason.clone()),
+                        severity: Set(format!("{:?}", rule.severity).to_lowercase()),
+                        involved_object: Set(involved_object),
+                        event_count: Set(count as i32),
+                        fired_at: Set(now),
+                        resolved_at: Set(None),
+                    })
+                    .await?;
+                info!(
+                    "Alert '{}' fired for {} ({} {} events in {}s)",
+                    rule.name, firing.involved_object, count, rule.event_reason, rule.window_secs
+                );
+                if let Some(webhook) = &rule.notify_webhook {
+                    self.notify(webhook, rule, &firing).await;
+                }
+                fired.push(firing);
+            }
+        }
+        Ok(fired)
+    }
+
+    pub async fn list_firings(
+        &self,
+        cluster_id: Uuid,
+        open_only: bool,
+        limit: u64,
+    ) -> Result<Vec<AlertFiring>, AppError> {
+        self.repo.list(cluster_id, open_only, limit).await
+    }
+
+    /// Evaluate the configured rules against every registered Kubernetes cluster on an interval.
+    pub fn start(
+        self: &Arc<Self>,
+        clusters: Arc<ClusterRepository>,
+        config: KubernetesAlertingConfig,
+    ) {
+        if !config.enabled || config.rules.is_empty() {
+            return;
+        }
+        let service = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(std::time::Duration::from_secs(config.interval_secs.max(1)));
+            loop {
+                interval.tick().await;
+                let clusters = match clusters.find_by_type("kubernetes").await {
+                    Ok(clusters) => clusters,
+                    Err(e) => {
+                        error!("Failed to list clusters for alert evaluation: {}", e);
+                        continue;
+                    }
+                };
+                for cluster in clusters {
+                    let cluster_config: KubernetesClusterConfig =
+                        match parse_cluster_config(&cluster.config) {
+                            Ok(cfg) => cfg,
+                            Err(e) => {
+                                warn!("Skipping alerting for cluster {}: {}", cluster.name, e);
+                                continue;
+                            }
+                        };
+                    let namespaces: Vec<Option<&str>> = if config.namespaces.is_empty() {
+                        vec![None]
+                    } else {
+                        config
+                            .namespaces
+                            .iter()
+                            .map(|ns| Some(ns.as_str()))
+                            .collect()
+                    };
+                    for namespace in namespaces {
+                        match service
+                            .evaluate_rules(cluster.id, &cluster_config, namespace, &config.rules)
+                            .await
+                        {
+                            Ok(fired) => {
+                                debug!("Cluster {}: {} alerts fired", cluster.name, fired.len())
+                            }
+                            Err(e) => warn!(
+                                "Alert evaluation failed for cluster {}: {}",
+                                cluster.name, e
+                            ),
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    async fn notify(&self, webhook: &str, rule: &AlertRule, firing: &AlertFiring) {
+        let payload = serde_json::json!({
+            "rule": rule.name,
+            "severity": rule.severity,
+            "event_reason": rule.event_reason,
+            "cluster_id": firing.cluster_id,
+            "namespace": firing.namespace,
+            "involved_object": firing.involved_object,
+            "event_count": firing.event_count,
+            "window_secs": rule.window_secs,
+            "fired_at": firing.fired_at,
+        });
+        let result = self.http_client.post(webhook).json(&payload).send().await;
+        match result.and_then(|r| r.error_for_status()) {
+            Ok(_) => {}
+            Err(e) => warn!("Failed to notify webhook for alert '{}': {}", rule.name, e),
+        }
+    }
+}
+
+// Clusters registered without explicit settings fall back to the default kubeconfig
+fn parse_cluster_config(
+    value: &serde_json::Value,
+) -> Result<KubernetesClusterConfig, serde_json::Error> {
+    if value.is_null() {
+        serde_json::from_value(serde_json::json!({}))
+    } else {
+        serde_json::from_value(value.clone())
+    }
+}
+
+// Occurrences of `reason` per involved object ("Kind/namespace/name") since `window_start`.
+// An event's count only covers the window when the event was first seen inside it; older
+// aggregated events contribute a single occurrence for their most recent sighting.
+fn count_events(
+    events: &[Event],
+    reason: &str,
+    window_start: DateTime<Utc>,
+) -> HashMap<String, u32> {
+    let mut counts = HashMap::new();
+    for event in events {
+        if event.reason.as_deref() != Some(reason) {
+            continue;
+        }
+        let last_seen = event
+            .series
+            .as_ref()
+            .and_then(|s| s.last_observed_time.as_ref().map(|t| t.0))
+            .or_else(|| event.last_timestamp.as_ref().map(|t| t.0))
+            .or_else(|| event.event_time.as_ref().map(|t| t.0))
+            .or_else(|| event.metadata.creation_timestamp.as_ref().map(|t| t.0));
+        if !last_seen.map_or(false, |t| t >= window_start) {
+            continue;
+        }
+        let first_seen = event.first_timestamp.as_ref().map(|t| t.0);
+        let occurrences = if first_seen.map_or(false, |t| t >= window_start) {
+            event
+                .series
+                .as_ref()
+                .and_then(|s| s.count)
+                .or(event.count)
+                .unwrap_or(1)
+                .max(1) as u32
+        } else {
+            1
+        };
+
+        let object = &event.involved_object;
+        let key = format!(
+            "{}/{}/{}",
+            object.kind.as_deref().unwrap_or("Unknown"),
+            object.namespace.as_deref().unwrap_or(""),
+            object.name.as_deref().unwrap_or("")
+        );
+        *counts.entry(key).or_insert(0) += occurrences;
+    }
+    counts
+}
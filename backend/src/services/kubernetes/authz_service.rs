@@ -22,6 +22,7 @@ use k8s_openapi::api::authorization::v1::{
 };
 use kube::api::PostParams;
 use kube::Api;
+use tracing::instrument;
 
 pub struct AuthorizationService;
 
@@ -56,6 +57,7 @@ impl AuthorizationService {
         }
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = namespace.as_deref().unwrap_or_default(), operation = "can", resource_type = "SelfSubjectAccessReview"))]
     pub async fn can(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -0,0 +1,202 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use chrono::{DateTime, Duration, Utc};
+use futures::future::join_all;
+use k8s_openapi::api::core::v1::Event;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use crate::errors::AppError;
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::pod::{PodInfo, PodService};
+
+// How much worse than the baseline the canary's error rate may be before rolling back
+const ERROR_RATE_TOLERANCE: f64 = 0.05;
+// Extra restarts per pod the canary may accumulate over the baseline
+const RESTARTS_PER_POD_TOLERANCE: f64 = 1.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CanaryRecommendation {
+    Promote,
+    Rollback,
+    Continue,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CanaryAnalysisResult {
+    pub baseline_error_rate: f64,
+    pub canary_error_rate: f64,
+    pub baseline_restart_count: i32,
+    pub canary_restart_count: i32,
+    pub baseline_pod_count: usize,
+    pub canary_pod_count: usize,
+    pub baseline_last_error_at: Option<DateTime<Utc>>,
+    pub canary_last_error_at: Option<DateTime<Utc>>,
+    pub recommendation: CanaryRecommendation,
+}
+
+struct GroupStats {
+    pod_count: usize,
+    erroring_pods: usize,
+    restart_count: i32,
+    last_error_at: Option<DateTime<Utc>>,
+    all_ready: bool,
+}
+
+impl GroupStats {
+    fn error_rate(&self) -> f64 {
+        if self.pod_count == 0 {
+            0.0
+        } else {
+            self.erroring_pods as f64 / self.pod_count as f64
+        }
+    }
+
+    fn restarts_per_pod(&self) -> f64 {
+        if self.pod_count == 0 {
+            0.0
+        } else {
+            self.restart_count as f64 / self.pod_count as f64
+        }
+    }
+}
+
+pub struct DeploymentCanaryAnalyzer {
+    pod_service: Arc<PodService>,
+}
+
+impl DeploymentCanaryAnalyzer {
+    pub fn new(pod_service: Arc<PodService>) -> Self {
+        Self { pod_service }
+    }
+
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "analyze", resource_type = "Pod"))]
+    pub async fn analyze(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        baseline_selector: &str,
+        canary_selector: &str,
+        metric_window_secs: u64,
+    ) -> Result<CanaryAnalysisResult, AppError> {
+        let window_start = Utc::now() - Duration::seconds(metric_window_secs as i64);
+
+        let (baseline, canary) = tokio::try_join!(
+            self.collect_group(cluster_config, namespace, baseline_selector, window_start),
+            self.collect_group(cluster_config, namespace, canary_selector, window_start),
+        )?;
+        if canary.pod_count == 0 {
+            return Err(AppError::NotFound(format!(
+                "No pods match canary selector '{}' in namespace {}",
+                canary_selector, namespace
+            )));
+        }
+
+        let recommendation = recommend(&baseline, &canary);
+        info!(
+            %namespace,
+            baseline_error_rate = baseline.error_rate(),
+            canary_error_rate = canary.error_rate(),
+            ?recommendation,
+            "Canary analysis complete"
+        );
+
+        Ok(CanaryAnalysisResult {
+            baseline_error_rate: baseline.error_rate(),
+            canary_error_rate: canary.error_rate(),
+            baseline_restart_count: baseline.restart_count,
+            canary_restart_count: canary.restart_count,
+            baseline_pod_count: baseline.pod_count,
+            canary_pod_count: canary.pod_count,
+            baseline_last_error_at: baseline.last_error_at,
+            canary_last_error_at: canary.last_error_at,
+            recommendation,
+        })
+    }
+
+    // A pod counts as erroring when it has failed or emitted a Warning event within the window
+    async fn collect_group(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        selector: &str,
+        window_start: DateTime<Utc>,
+    ) -> Result<GroupStats, AppError> {
+        let pods = self
+            .pod_service
+            .list_pods_by_selector(cluster_config, namespace, selector)
+            .await?;
+
+        let events = join_all(pods.iter().map(|pod| {
+            self.pod_service
+                .get_pod_events(cluster_config, namespace, &pod.name)
+        }))
+        .await;
+
+        let mut stats = GroupStats {
+            pod_count: pods.len(),
+            erroring_pods: 0,
+            restart_count: 0,
+            last_error_at: None,
+            all_ready: true,
+        };
+        for (pod, pod_events) in pods.iter().zip(events) {
+            stats.restart_count += pod.restart_count;
+            stats.all_ready &= is_ready(pod);
+
+            // The pod may have been deleted between listing and fetching events
+            let last_warning = pod_events
+                .unwrap_or_default()
+                .iter()
+                .filter(|e| e.type_.as_deref() == Some("Warning"))
+                .filter_map(event_time)
+                .filter(|t| *t >= window_start)
+                .max();
+            if last_warning.is_some() || pod.status == "Failed" {
+                stats.erroring_pods += 1;
+            }
+            stats.last_error_at = stats.last_error_at.max(last_warning);
+        }
+        Ok(stats)
+    }
+}
+
+fn is_ready(pod: &PodInfo) -> bool {
+    pod.status == "Running" && pod.containers.iter().all(|c| c.ready)
+}
+
+fn event_time(event: &Event) -> Option<DateTime<Utc>> {
+    event
+        .last_timestamp
+        .as_ref()
+        .map(|t| t.0)
+        .or_else(|| event.event_time.as_ref().map(|t| t.0))
+        .or_else(|| event.first_timestamp.as_ref().map(|t| t.0))
+}
+
+fn recommend(baseline: &GroupStats, canary: &GroupStats) -> CanaryRecommendation {
+    if canary.error_rate() > baseline.error_rate() + ERROR_RATE_TOLERANCE
+        || canary.restarts_per_pod() > baseline.restarts_per_pod() + RESTARTS_PER_POD_TOLERANCE
+    {
+        CanaryRecommendation::Rollback
+    } else if !canary.all_ready {
+        // Still rolling out; judge again once every canary pod is serving
+        CanaryRecommendation::Continue
+    } else {
+        CanaryRecommendation::Promote
+    }
+}
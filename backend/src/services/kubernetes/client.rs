@@ -23,7 +23,9 @@ use once_cell::sync::Lazy;
 use secrecy::Secret;
 use sha2::{Digest, Sha256};
 use std::collections::hash_map::DefaultHasher;
+use serde::Serialize;
 use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tracing::{debug, info};
 
@@ -47,11 +49,62 @@ fn b64_if_needed(data_opt: &Option<String>) -> Option<String> {
     }
 }
 
-fn fingerprint(cfg: &KubernetesClusterConfig) -> u64 {
+#[derive(Debug, Clone, Serialize)]
+pub struct ContextInfo {
+    pub context_name: String,
+    pub cluster_name: String,
+    pub user: String,
+    pub namespace: Option<String>,
+    pub is_current: bool,
+}
+
+// Kubeconfig paths are often entered as ~/.kube/config
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), std::env::var_os("HOME")) {
+        (Ok(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+fn read_kubeconfig(path: &Path) -> Result<Kubeconfig, AppError> {
+    let path = expand_home(path);
+    Kubeconfig::read_from(&path).map_err(|e| {
+        AppError::BadRequest(format!(
+            "Failed to read kubeconfig from {}: {}",
+            path.display(),
+            e
+        ))
+    })
+}
+
+/// List every context in a kubeconfig so a user can pick one for `kube_context`.
+pub fn discover_contexts_from_kubeconfig(path: &Path) -> Result<Vec<ContextInfo>, AppError> {
+    let kubeconfig = read_kubeconfig(path)?;
+    let current = kubeconfig.current_context.clone();
+    let mut contexts: Vec<ContextInfo> = kubeconfig
+        .contexts
+        .into_iter()
+        .map(|named| {
+            let context = named.context.unwrap_or_default();
+            ContextInfo {
+                is_current: current.as_deref() == Some(named.name.as_str()),
+                context_name: named.name,
+                cluster_name: context.cluster,
+                user: context.user,
+                namespace: context.namespace,
+            }
+        })
+        .collect();
+    contexts.sort_by(|a, b| a.context_name.cmp(&b.context_name));
+    Ok(contexts)
+}
+
+pub(crate) fn fingerprint(cfg: &KubernetesClusterConfig) -> u64 {
     let mut hasher = DefaultHasher::new();
     cfg.kube_config_path.hash(&mut hasher);
     cfg.kube_context.hash(&mut hasher);
     cfg.api_server_url.hash(&mut hasher);
+    cfg.use_in_cluster_config.hash(&mut hasher);
     // Don't include raw secret materials; include their hashes
     if let Some(t) = &cfg.token {
         let mut sha = Sha256::new();
@@ -86,13 +139,50 @@ impl ClientFactory {
         Ok(client)
     }
 
+    /// Drop every cached client, e.g. after kubeconfig files or cluster credentials change.
+    pub fn invalidate_cache() {
+        let dropped = CLIENT_CACHE.len();
+        CLIENT_CACHE.clear();
+        info!(target: "mayyam::k8s::client", dropped, "Cleared Kubernetes client cache");
+    }
+
     async fn build_client(cluster_config: &KubernetesClusterConfig) -> Result<Client, AppError> {
+        // 0) Explicit in-cluster mode: the pod's service account, no kubeconfig involved
+        if cluster_config.use_in_cluster_config {
+            debug!(target: "mayyam::k8s::client", "Building client from in-cluster service account");
+            let cfg = KubeConfig::incluster().map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to load in-cluster Kubernetes config: {}",
+                    e
+                ))
+            })?;
+            return Client::try_from(cfg).map_err(|e| {
+                AppError::ExternalService(format!("Failed to create Kubernetes client: {}", e))
+            });
+        }
+
+        // A bearer token alone says nothing about which API server to send it to
+        if cluster_config.token.is_some()
+            && cluster_config.api_server_url.is_none()
+            && cluster_config.kube_config_path.is_none()
+        {
+            return Err(AppError::BadRequest(
+                "A service account token requires api_server_url".to_string(),
+            ));
+        }
+
         // 1) If kubeconfig path provided, honor it with optional context
         if let Some(path) = &cluster_config.kube_config_path {
             debug!(target: "mayyam::k8s::client", kubeconfig_path = %path, ctx = ?cluster_config.kube_context, "Building client from kubeconfig path");
-            let kubeconfig = Kubeconfig::read_from(path).map_err(|e| {
-                AppError::ExternalService(format!("Failed to read kubeconfig from path: {}", e))
-            })?;
+            let kubeconfig = read_kubeconfig(Path::new(path))?;
+            if let Some(context) = &cluster_config.kube_context {
+                if !kubeconfig.contexts.iter().any(|c| &c.name == context) {
+                    return Err(AppError::BadRequest(format!(
+                        "Context '{}' not found in kubeconfig {}",
+                        context, path
+                    )));
+                }
+            }
             let cfg = KubeConfig::from_custom_kubeconfig(
                 kubeconfig,
                 &KubeConfigOptions {
@@ -0,0 +1,144 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::AppError;
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::client::ClientFactory;
+use serde::Serialize;
+use std::collections::HashSet;
+use tracing::instrument;
+
+// How many upcoming minor releases the report looks ahead
+const LOOKAHEAD_MINOR_VERSIONS: u32 = 2;
+
+// (group, version, deprecated in 1.x, no longer served from 1.x, replacement).
+// Group/versions are listed once they stop being served as a whole, which
+// can lag behind the removal of individual kinds within them.
+#[rustfmt::skip]
+const DEPRECATION_TABLE: &[(&str, &str, u32, u32, Option<&str>)] = &[
+    ("extensions", "v1beta1", 14, 22, Some("networking.k8s.io/v1")),
+    ("networking.k8s.io", "v1beta1", 19, 22, Some("networking.k8s.io/v1")),
+    ("apiextensions.k8s.io", "v1beta1", 16, 22, Some("apiextensions.k8s.io/v1")),
+    ("admissionregistration.k8s.io", "v1beta1", 16, 22, Some("admissionregistration.k8s.io/v1")),
+    ("apiregistration.k8s.io", "v1beta1", 19, 22, Some("apiregistration.k8s.io/v1")),
+    ("authentication.k8s.io", "v1beta1", 19, 22, Some("authentication.k8s.io/v1")),
+    ("authorization.k8s.io", "v1beta1", 19, 22, Some("authorization.k8s.io/v1")),
+    ("certificates.k8s.io", "v1beta1", 19, 22, Some("certificates.k8s.io/v1")),
+    ("coordination.k8s.io", "v1beta1", 19, 22, Some("coordination.k8s.io/v1")),
+    ("rbac.authorization.k8s.io", "v1beta1", 17, 22, Some("rbac.authorization.k8s.io/v1")),
+    ("scheduling.k8s.io", "v1beta1", 17, 22, Some("scheduling.k8s.io/v1")),
+    ("batch", "v1beta1", 21, 25, Some("batch/v1")),
+    ("discovery.k8s.io", "v1beta1", 21, 25, Some("discovery.k8s.io/v1")),
+    ("events.k8s.io", "v1beta1", 19, 25, Some("events.k8s.io/v1")),
+    ("node.k8s.io", "v1beta1", 20, 25, Some("node.k8s.io/v1")),
+    ("policy", "v1beta1", 21, 25, Some("policy/v1")),
+    ("autoscaling", "v2beta1", 22, 25, Some("autoscaling/v2")),
+    ("autoscaling", "v2beta2", 23, 26, Some("autoscaling/v2")),
+    ("flowcontrol.apiserver.k8s.io", "v1beta1", 23, 26, Some("flowcontrol.apiserver.k8s.io/v1")),
+    ("storage.k8s.io", "v1beta1", 19, 27, Some("storage.k8s.io/v1")),
+    ("flowcontrol.apiserver.k8s.io", "v1beta2", 26, 29, Some("flowcontrol.apiserver.k8s.io/v1")),
+    ("flowcontrol.apiserver.k8s.io", "v1beta3", 29, 32, Some("flowcontrol.apiserver.k8s.io/v1")),
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiDeprecation {
+    pub api_group: String,
+    pub version: String,
+    pub replacement_api: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiDeprecationReport {
+    pub current_version: String,
+    pub next_versions: Vec<String>,
+    // Served APIs that are deprecated but survive the next minor releases
+    pub deprecated_apis: Vec<ApiDeprecation>,
+    // Served APIs that stop being served within the next minor releases
+    pub removed_apis: Vec<ApiDeprecation>,
+}
+
+pub struct ClusterVersionService;
+
+impl ClusterVersionService {
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "get_api_deprecations"))]
+    pub async fn get_api_deprecations(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+    ) -> Result<ApiDeprecationReport, AppError> {
+        let client = ClientFactory::get_client(cluster_config).await?;
+
+        let info = client
+            .apiserver_version()
+            .await
+            .map_err(|e| AppError::Kubernetes(format!("Failed to get server version: {}", e)))?;
+        let major = parse_version_number(&info.major)?;
+        let minor = parse_version_number(&info.minor)?;
+
+        let groups = client
+            .list_api_groups()
+            .await
+            .map_err(|e| AppError::Kubernetes(format!("Failed to list API groups: {}", e)))?;
+        let served: HashSet<(String, String)> = groups
+            .groups
+            .iter()
+            .flat_map(|group| {
+                group
+                    .versions
+                    .iter()
+                    .map(move |v| (group.name.clone(), v.version.clone()))
+            })
+            .collect();
+
+        let horizon = minor + LOOKAHEAD_MINOR_VERSIONS;
+        let mut deprecated_apis = Vec::new();
+        let mut removed_apis = Vec::new();
+        for &(group, version, deprecated_in, removed_in, replacement) in DEPRECATION_TABLE {
+            // Entries removed at or before the current release only show up as
+            // served when the version was later reintroduced, so skip them.
+            if removed_in <= minor || !served.contains(&(group.to_string(), version.to_string())) {
+                continue;
+            }
+            let deprecation = ApiDeprecation {
+                api_group: group.to_string(),
+                version: version.to_string(),
+                replacement_api: replacement.map(String::from),
+            };
+            if removed_in <= horizon {
+                removed_apis.push(deprecation);
+            } else if deprecated_in <= horizon {
+                deprecated_apis.push(deprecation);
+            }
+        }
+
+        Ok(ApiDeprecationReport {
+            current_version: info.git_version,
+            next_versions: (1..=LOOKAHEAD_MINOR_VERSIONS)
+                .map(|offset| format!("{}.{}", major, minor + offset))
+                .collect(),
+            deprecated_apis,
+            removed_apis,
+        })
+    }
+}
+
+// Managed distributions report minors like "27+"
+fn parse_version_number(raw: &str) -> Result<u32, AppError> {
+    let digits: String = raw.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().map_err(|_| {
+        AppError::Kubernetes(format!(
+            "Unrecognized Kubernetes version component '{}'",
+            raw
+        ))
+    })
+}
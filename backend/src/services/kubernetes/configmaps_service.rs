@@ -14,15 +14,22 @@
 
 
 use crate::errors::AppError;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
 use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
 use crate::services::kubernetes::client::ClientFactory;
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use k8s_openapi::api::core::v1::ConfigMap;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
 use kube::{
     api::{Api, DeleteParams, ListParams, Patch, PatchParams},
     ResourceExt,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::instrument;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ConfigMapInfo {
@@ -33,11 +40,80 @@ pub struct ConfigMapInfo {
     pub annotations: Option<BTreeMap<String, String>>,
 }
 
-pub struct ConfigMapsService;
+// Key-level comparison of a proposed data map against what is in the cluster.
+// Also used for Secrets, where values are compared in their encoded form.
+// Keys that only exist in the cluster are not reported: the server-side apply
+// only touches the keys in the manifest, so it never removes them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConfigMapDiff {
+    pub is_new: bool,
+    pub added_keys: Vec<String>,
+    pub modified_keys: Vec<String>,
+    pub unchanged_keys: Vec<String>,
+    pub type_change: Option<TypeChange>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypeChange {
+    pub from: String,
+    pub to: String,
+}
+
+impl ConfigMapDiff {
+    pub fn has_changes(&self) -> bool {
+        self.is_new
+            || !self.added_keys.is_empty()
+            || !self.modified_keys.is_empty()
+            || self.type_change.is_some()
+    }
+}
+
+pub(crate) fn diff_data_maps(
+    current: Option<&BTreeMap<String, String>>,
+    proposed: &BTreeMap<String, String>,
+) -> ConfigMapDiff {
+    let Some(current) = current else {
+        return ConfigMapDiff {
+            is_new: true,
+            added_keys: proposed.keys().cloned().collect(),
+            ..Default::default()
+        };
+    };
+    let mut diff = ConfigMapDiff::default();
+    for (key, value) in proposed {
+        match current.get(key) {
+            None => diff.added_keys.push(key.clone()),
+            Some(existing) if existing != value => diff.modified_keys.push(key.clone()),
+            Some(_) => diff.unchanged_keys.push(key.clone()),
+        }
+    }
+    diff
+}
+
+// data and binaryData share one key space, so they are diffed as a single map
+// with binary values base64-encoded
+fn combined_data(cm: &ConfigMap) -> BTreeMap<String, String> {
+    let mut out = cm.data.clone().unwrap_or_default();
+    for (k, v) in cm.binary_data.iter().flatten() {
+        out.insert(k.clone(), BASE64.encode(&v.0));
+    }
+    out
+}
+
+pub struct ConfigMapsService {
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+}
 
 impl ConfigMapsService {
     pub fn new() -> Self {
-        Self
+        Self {
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
     }
 
     async fn api(
@@ -53,16 +129,18 @@ impl ConfigMapsService {
         Ok(api)
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "list", resource_type = "ConfigMap"))]
     pub async fn list(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
         label_selector: Option<String>,
         field_selector: Option<String>,
-        limit: Option<u32>,
+        page_size: Option<usize>,
         continue_token: Option<String>,
-    ) -> Result<Vec<ConfigMapInfo>, AppError> {
+    ) -> Result<PaginatedResponse<ConfigMapInfo>, AppError> {
         let api = Self::api(cluster_config, namespace).await?;
+        let page = PageRequest::new(page_size, continue_token);
         let mut lp = ListParams::default();
         if let Some(ls) = label_selector {
             lp = lp.labels(&ls);
@@ -70,16 +148,11 @@ impl ConfigMapsService {
         if let Some(fs) = field_selector {
             lp = lp.fields(&fs);
         }
-        if let Some(l) = limit {
-            lp = lp.limit(l);
-        }
-        if let Some(ct) = continue_token {
-            lp = lp.continue_token(&ct);
-        }
         let cms = api
-            .list(&lp)
+            .list(&page.list_params(lp))
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        let list_meta = cms.metadata.clone();
         let mut out = Vec::new();
         for cm in cms {
             out.push(ConfigMapInfo {
@@ -96,9 +169,10 @@ impl ConfigMapsService {
                 annotations: cm.metadata.annotations.clone(),
             });
         }
-        Ok(out)
+        Ok(page.finish(list_meta, out))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "get", resource_type = "ConfigMap"))]
     pub async fn get(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -111,6 +185,7 @@ impl ConfigMapsService {
             .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "upsert", resource_type = "ConfigMap"))]
     pub async fn upsert(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -137,16 +212,77 @@ impl ConfigMapsService {
         Ok(res)
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "diff", resource_type = "ConfigMap"))]
+    pub async fn diff_configmap(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        proposed: &ConfigMap,
+    ) -> Result<ConfigMapDiff, AppError> {
+        let name =
+            proposed.metadata.name.as_deref().ok_or_else(|| {
+                AppError::Validation("ConfigMap must have metadata.name".to_string())
+            })?;
+        let api = Self::api(cluster_config, namespace).await?;
+        let current = api
+            .get_opt(name)
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        let current_data = current.as_ref().map(combined_data);
+        Ok(diff_data_maps(
+            current_data.as_ref(),
+            &combined_data(proposed),
+        ))
+    }
+
+    // Applies a full manifest, including binaryData, as the "mayyam" field manager
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "apply", resource_type = "ConfigMap"))]
+    pub async fn apply(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        proposed: &ConfigMap,
+    ) -> Result<ConfigMap, AppError> {
+        let name =
+            proposed.metadata.name.as_deref().ok_or_else(|| {
+                AppError::Validation("ConfigMap must have metadata.name".to_string())
+            })?;
+        let api = Self::api(cluster_config, namespace).await?;
+        let patch = ConfigMap {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                labels: proposed.metadata.labels.clone(),
+                annotations: proposed.metadata.annotations.clone(),
+                ..Default::default()
+            },
+            data: proposed.data.clone(),
+            binary_data: proposed.binary_data.clone(),
+            ..Default::default()
+        };
+        let params = PatchParams::apply("mayyam").force();
+        api.patch(name, &params, &Patch::Apply(&patch))
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))
+    }
+
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "delete", resource_type = "ConfigMap"))]
     pub async fn delete(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let api = Self::api(cluster_config, namespace).await?;
         api.delete(name, &DeleteParams::default())
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::DELETE, "ConfigMap", name)
+                .with_namespace(namespace),
+        )
+        .await;
         Ok(())
     }
 }
@@ -15,38 +15,61 @@
 use crate::errors::AppError;
 use crate::models::cluster::KubernetesClusterConfig;
 use crate::services::kubernetes::client::ClientFactory;
-use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::{
+    CustomResourceDefinition, CustomResourceDefinitionVersion,
+};
 use kube::{
     api::{Api, DynamicObject, GroupVersionKind, ListParams},
     discovery::{ApiGroup, ApiResource, Discovery, Scope},
 };
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use tracing::instrument;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrdScope {
+    Namespaced,
+    Cluster,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CrdInfo {
+    pub name: String,
+    pub group: String,
+    pub kind: String,
+    pub plural: String,
+    pub versions: Vec<String>,
+    pub scope: CrdScope,
+    pub established: bool,
+    // openAPIV3Schema of the highest-priority served version
+    pub schema: Option<Value>,
+}
 
 pub struct CrdsService;
 
 impl CrdsService {
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "list_crds", resource_type = "CustomResourceDefinition"))]
     pub async fn list_crds(
         &self,
         cluster_config: &KubernetesClusterConfig,
-    ) -> Result<Vec<Value>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<CrdInfo>, AppError> {
+        let page = PageRequest::new(page_size, continue_token);
         let client = ClientFactory::get_client(cluster_config).await?;
         let crds: Api<CustomResourceDefinition> = Api::all(client);
 
         let crd_list = crds
-            .list(&ListParams::default())
+            .list(&page.list_params(ListParams::default()))
             .await
             .map_err(|e| AppError::ExternalService(format!("Failed to list CRDs: {}", e)))?;
 
-        let mut formatted_crds = Vec::new();
-        for crd in crd_list {
-            if let Ok(value) = serde_json::to_value(&crd) {
-                formatted_crds.push(value);
-            }
-        }
-
-        Ok(formatted_crds)
+        let infos = crd_list.items.iter().map(to_crd_info).collect();
+        Ok(page.finish(crd_list.metadata, infos))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "get_crd_details", resource_type = "CustomResourceDefinition"))]
     pub async fn get_crd_details(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -63,7 +86,64 @@ impl CrdsService {
             .map_err(|e| AppError::Internal(format!("Failed to serialize CRD details: {}", e)))
     }
 
+    // Lists instances of an installed CRD, addressed by the CRD's name
+    // (e.g. "certificates.cert-manager.io") rather than group/version/plural.
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = namespace.as_deref().unwrap_or_default(), operation = "list_crd_resources", resource_type = %crd_name))]
+    pub async fn list_crd_resources(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        crd_name: &str,
+        namespace: Option<String>,
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<Value>, AppError> {
+        let page = PageRequest::new(page_size, continue_token);
+        let client = ClientFactory::get_client(cluster_config).await?;
+        let crds: Api<CustomResourceDefinition> = Api::all(client.clone());
+
+        let crd = crds.get_opt(crd_name).await.map_err(|e| {
+            AppError::ExternalService(format!("Failed to get CRD {}: {}", crd_name, e))
+        })?;
+        let crd = crd.ok_or_else(|| AppError::NotFound(format!("CRD {} not found", crd_name)))?;
+
+        let version = latest_served_version(&crd)
+            .map(|v| v.name.clone())
+            .ok_or_else(|| {
+                AppError::BadRequest(format!("CRD {} has no served versions", crd_name))
+            })?;
+        let ar = ApiResource {
+            group: crd.spec.group.clone(),
+            api_version: format!("{}/{}", crd.spec.group, version),
+            version,
+            kind: crd.spec.names.kind.clone(),
+            plural: crd.spec.names.plural.clone(),
+        };
+
+        let api: Api<DynamicObject> = match namespace.as_deref() {
+            Some(ns) if crd.spec.scope == "Namespaced" => Api::namespaced_with(client, ns, &ar),
+            _ => Api::all_with(client, &ar),
+        };
+
+        let list = api
+            .list(&page.list_params(ListParams::default()))
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!("Failed to list {} resources: {}", crd_name, e))
+            })?;
+        let list_meta = list.metadata.clone();
+
+        let mut items = Vec::new();
+        for item in list {
+            if let Ok(value) = serde_json::to_value(&item) {
+                items.push(value);
+            }
+        }
+
+        Ok(page.finish(list_meta, items))
+    }
+
     /// Generic fallback for dynamically dealing with custom resources based on their GroupVersionKind
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = namespace.unwrap_or_default(), operation = "list_custom_resources", resource_type = %plural))]
     pub async fn list_custom_resources(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -71,7 +151,10 @@ impl CrdsService {
         version: &str,
         plural: &str,
         namespace: Option<&str>,
-    ) -> Result<Vec<Value>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<Value>, AppError> {
+        let page = PageRequest::new(page_size, continue_token);
         let client = ClientFactory::get_client(cluster_config).await?;
         let discovery = Discovery::new(client.clone())
             .run()
@@ -107,9 +190,13 @@ impl CrdsService {
             _ => Api::all_with(client.clone(), &ar),
         };
 
-        let list = api.list(&ListParams::default()).await.map_err(|e| {
-            AppError::ExternalService(format!("Failed to list CustomResources: {}", e))
-        })?;
+        let list = api
+            .list(&page.list_params(ListParams::default()))
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!("Failed to list CustomResources: {}", e))
+            })?;
+        let list_meta = list.metadata.clone();
 
         let mut items = Vec::new();
         for item in list {
@@ -118,6 +205,70 @@ impl CrdsService {
             }
         }
 
-        Ok(items)
+        Ok(page.finish(list_meta, items))
+    }
+}
+
+fn to_crd_info(crd: &CustomResourceDefinition) -> CrdInfo {
+    let established = crd
+        .status
+        .as_ref()
+        .and_then(|status| status.conditions.as_ref())
+        .map(|conditions| {
+            conditions
+                .iter()
+                .any(|c| c.type_ == "Established" && c.status == "True")
+        })
+        .unwrap_or(false);
+
+    CrdInfo {
+        name: crd.metadata.name.clone().unwrap_or_default(),
+        group: crd.spec.group.clone(),
+        kind: crd.spec.names.kind.clone(),
+        plural: crd.spec.names.plural.clone(),
+        versions: crd.spec.versions.iter().map(|v| v.name.clone()).collect(),
+        scope: if crd.spec.scope == "Namespaced" {
+            CrdScope::Namespaced
+        } else {
+            CrdScope::Cluster
+        },
+        established,
+        schema: latest_served_version(crd)
+            .and_then(|v| v.schema.as_ref())
+            .and_then(|schema| schema.open_api_v3_schema.as_ref())
+            .and_then(|schema| serde_json::to_value(schema).ok()),
     }
 }
+
+// Picks the served version Kubernetes would rank highest: GA over beta over
+// alpha, then the higher major and minor number (v2 > v1beta2 > v1beta1 > v1alpha1).
+fn latest_served_version(
+    crd: &CustomResourceDefinition,
+) -> Option<&CustomResourceDefinitionVersion> {
+    crd.spec
+        .versions
+        .iter()
+        .filter(|v| v.served)
+        .max_by_key(|v| version_priority(&v.name))
+}
+
+fn version_priority(version: &str) -> (u8, u32, u32) {
+    let rest = match version.strip_prefix('v') {
+        Some(rest) => rest,
+        None => return (0, 0, 0),
+    };
+    let major_len = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+    let major = rest[..major_len].parse().unwrap_or(0);
+    let (stability, minor) = match &rest[major_len..] {
+        "" => (3, 0),
+        suffix => match suffix
+            .strip_prefix("beta")
+            .map(|n| (2, n))
+            .or_else(|| suffix.strip_prefix("alpha").map(|n| (1, n)))
+        {
+            Some((stability, n)) => (stability, n.parse().unwrap_or(0)),
+            None => (0, 0),
+        },
+    };
+    (stability, major, minor)
+}
@@ -24,10 +24,15 @@ use kube::{Api, Client, ResourceExt};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::collections::BTreeMap;
+use tracing::instrument;
 
 use crate::errors::AppError;
 use crate::models::cluster::KubernetesClusterConfig;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
+use std::sync::Arc;
 // Use the PodInfo and convert_kube_pod_to_pod_info from the pods module
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
 use crate::services::kubernetes::pod::{convert_kube_pod_to_pod_info, PodInfo};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -43,7 +48,9 @@ pub struct DaemonSetInfo {
     pub images: Vec<String>,
 }
 
-pub struct DaemonSetsService;
+pub struct DaemonSetsService {
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+}
 
 fn label_selector_to_string(selector: &LabelSelector) -> Option<String> {
     let mut parts = Vec::new();
@@ -82,7 +89,14 @@ fn label_selector_to_string(selector: &LabelSelector) -> Option<String> {
 
 impl DaemonSetsService {
     pub fn new() -> Self {
-        DaemonSetsService {}
+        Self {
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
     }
 
     async fn get_kube_client(cluster_config: &KubernetesClusterConfig) -> Result<Client, AppError> {
@@ -120,20 +134,25 @@ impl DaemonSetsService {
         })
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "list_daemon_sets", resource_type = "DaemonSet"))]
     pub async fn list_daemon_sets(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
-    ) -> Result<Vec<DaemonSetInfo>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<DaemonSetInfo>, AppError> {
+        let page = PageRequest::new(page_size, continue_token);
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<DaemonSet> = Api::namespaced(client, namespace);
-        let lp = ListParams::default();
+        let lp = page.list_params(ListParams::default());
         let ds_list = api.list(&lp).await.map_err(|e| {
             AppError::ExternalService(format!(
                 "Failed to list daemon sets in namespace \'{}\': {}",
                 namespace, e
             ))
         })?;
+        let list_meta = ds_list.metadata.clone();
 
         let mut infos = Vec::new();
         for ds in ds_list {
@@ -181,9 +200,10 @@ impl DaemonSetsService {
                 images,
             });
         }
-        Ok(infos)
+        Ok(page.finish(list_meta, infos))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "get_daemon_set_details", resource_type = "DaemonSet"))]
     pub async fn get_daemon_set_details(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -200,6 +220,7 @@ impl DaemonSetsService {
         })
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "get_pods_for_daemon_set", resource_type = "Pod"))]
     pub async fn get_pods_for_daemon_set(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -242,11 +263,13 @@ impl DaemonSetsService {
         Ok(pod_infos)
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "delete_daemon_set", resource_type = "DaemonSet"))]
     pub async fn delete_daemon_set(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<DaemonSet> = Api::namespaced(client, namespace);
@@ -258,14 +281,22 @@ impl DaemonSetsService {
                     name, namespace, e
                 ))
             })?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::DELETE, "DaemonSet", name)
+                .with_namespace(namespace),
+        )
+        .await;
         Ok(())
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "restart_daemon_set", resource_type = "DaemonSet"))]
     pub async fn restart_daemon_set(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<DaemonSet> = Api::namespaced(client, namespace);
@@ -294,14 +325,22 @@ impl DaemonSetsService {
                     name, namespace, e
                 ))
             })?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::RESTART, "DaemonSet", name)
+                .with_namespace(namespace),
+        )
+        .await;
         Ok(())
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "delete_all_pods_for_daemon_set", resource_type = "Pod"))]
     pub async fn delete_all_pods_for_daemon_set(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
         daemon_set_name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let client = Self::get_kube_client(cluster_config).await?;
         let ds_api: Api<DaemonSet> = Api::namespaced(client.clone(), namespace);
@@ -342,6 +381,17 @@ impl DaemonSetsService {
                     })?;
             }
         }
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(
+                audit_ctx,
+                AuditOperation::DELETE_PODS,
+                "DaemonSet",
+                daemon_set_name,
+            )
+            .with_namespace(namespace),
+        )
+        .await;
         // If no selector, or selector doesn't match any pods, this is a no-op for pod deletion.
         Ok(())
     }
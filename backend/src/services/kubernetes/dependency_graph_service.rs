@@ -0,0 +1,340 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::AppError;
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::client::ClientFactory;
+use crate::services::kubernetes::garbage_collection_service::References;
+use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet};
+use k8s_openapi::api::core::v1::{
+    ConfigMap, Endpoints, PersistentVolume, PersistentVolumeClaim, Pod, Secret, Service,
+};
+use k8s_openapi::api::networking::v1::Ingress;
+use kube::api::ListParams;
+use kube::{Api, Client, ResourceExt};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+use tracing::instrument;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphNode {
+    pub id: String,
+    pub resource_type: String,
+    pub name: String,
+    pub status: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct GraphEdge {
+    pub from_id: String,
+    pub to_id: String,
+    pub relationship: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourceGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+#[derive(Default)]
+struct GraphBuilder {
+    nodes: BTreeMap<String, GraphNode>,
+    edges: Vec<GraphEdge>,
+    seen_edges: HashSet<GraphEdge>,
+}
+
+impl GraphBuilder {
+    fn node(&mut self, resource_type: &str, name: &str, status: impl Into<String>) -> String {
+        let id = node_id(resource_type, name);
+        self.nodes.entry(id.clone()).or_insert_with(|| GraphNode {
+            id: id.clone(),
+            resource_type: resource_type.to_string(),
+            name: name.to_string(),
+            status: status.into(),
+        });
+        id
+    }
+
+    fn edge(&mut self, from_id: &str, to_id: &str, relationship: &str) {
+        let edge = GraphEdge {
+            from_id: from_id.to_string(),
+            to_id: to_id.to_string(),
+            relationship: relationship.to_string(),
+        };
+        if self.seen_edges.insert(edge.clone()) {
+            self.edges.push(edge);
+        }
+    }
+
+    fn build(self) -> ResourceGraph {
+        ResourceGraph {
+            nodes: self.nodes.into_values().collect(),
+            edges: self.edges,
+        }
+    }
+}
+
+fn node_id(resource_type: &str, name: &str) -> String {
+    format!("{}/{}", resource_type, name)
+}
+
+pub struct DependencyGraphService;
+
+impl DependencyGraphService {
+    // Resources that exist are added with their status before any edges are drawn, so a
+    // reference to something that does not exist shows up as a "Missing" node.
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "build_dependency_graph"))]
+    pub async fn build_graph(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+    ) -> Result<ResourceGraph, AppError> {
+        let client = ClientFactory::get_client(cluster_config).await?;
+        let deployments: Vec<Deployment> = list(&client, namespace, "Deployments").await?;
+        let replica_sets: Vec<ReplicaSet> = list(&client, namespace, "ReplicaSets").await?;
+        let pods: Vec<Pod> = list(&client, namespace, "Pods").await?;
+        let services: Vec<Service> = list(&client, namespace, "Services").await?;
+        let endpoints: Vec<Endpoints> = list(&client, namespace, "Endpoints").await?;
+        let ingresses: Vec<Ingress> = list(&client, namespace, "Ingresses").await?;
+        let configmaps: Vec<ConfigMap> = list(&client, namespace, "ConfigMaps").await?;
+        let secrets: Vec<Secret> = list(&client, namespace, "Secrets").await?;
+        let pvcs: Vec<PersistentVolumeClaim> =
+            list(&client, namespace, "PersistentVolumeClaims").await?;
+
+        let mut graph = GraphBuilder::default();
+
+        for deployment in &deployments {
+            let status = deployment.status.as_ref();
+            let desired = deployment
+                .spec
+                .as_ref()
+                .and_then(|s| s.replicas)
+                .unwrap_or(1);
+            let available = status.and_then(|s| s.available_replicas).unwrap_or(0);
+            let state = if available >= desired {
+                "Available"
+            } else {
+                "Unavailable"
+            };
+            graph.node("Deployment", &deployment.name_any(), state);
+        }
+        for rs in &replica_sets {
+            let desired = rs.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+            let ready = rs
+                .status
+                .as_ref()
+                .and_then(|s| s.ready_replicas)
+                .unwrap_or(0);
+            let state = match desired {
+                0 => "ScaledDown",
+                _ if ready >= desired => "Ready",
+                _ => "NotReady",
+            };
+            graph.node("ReplicaSet", &rs.name_any(), state);
+        }
+        for pod in &pods {
+            let phase = pod
+                .status
+                .as_ref()
+                .and_then(|s| s.phase.clone())
+                .unwrap_or_else(|| "Unknown".to_string());
+            graph.node("Pod", &pod.name_any(), phase);
+        }
+        for service in &services {
+            let service_type = service
+                .spec
+                .as_ref()
+                .and_then(|s| s.type_.clone())
+                .unwrap_or_else(|| "ClusterIP".to_string());
+            graph.node("Service", &service.name_any(), service_type);
+        }
+        for ep in &endpoints {
+            let ready = ep
+                .subsets
+                .iter()
+                .flatten()
+                .any(|s| s.addresses.as_ref().map_or(false, |a| !a.is_empty()));
+            let state = if ready { "Ready" } else { "NoReadyAddresses" };
+            graph.node("Endpoints", &ep.name_any(), state);
+        }
+        for ingress in &ingresses {
+            let has_address = ingress
+                .status
+                .as_ref()
+                .and_then(|s| s.load_balancer.as_ref())
+                .and_then(|lb| lb.ingress.as_ref())
+                .map_or(false, |i| !i.is_empty());
+            let state = if has_address { "Ready" } else { "Pending" };
+            graph.node("Ingress", &ingress.name_any(), state);
+        }
+        for configmap in &configmaps {
+            graph.node("ConfigMap", &configmap.name_any(), "Present");
+        }
+        for secret in &secrets {
+            graph.node("Secret", &secret.name_any(), "Present");
+        }
+        for pvc in &pvcs {
+            let phase = pvc
+                .status
+                .as_ref()
+                .and_then(|s| s.phase.clone())
+                .unwrap_or_else(|| "Unknown".to_string());
+            graph.node("PersistentVolumeClaim", &pvc.name_any(), phase);
+        }
+
+        // Deployment -> ReplicaSet -> Pod through owner references
+        for rs in &replica_sets {
+            let rs_id = node_id("ReplicaSet", &rs.name_any());
+            for owner in rs
+                .owner_references()
+                .iter()
+                .filter(|o| o.kind == "Deployment")
+            {
+                let owner_id = graph.node("Deployment", &owner.name, "Missing");
+                graph.edge(&owner_id, &rs_id, "owns");
+            }
+        }
+        for pod in &pods {
+            let pod_id = node_id("Pod", &pod.name_any());
+            for owner in pod
+                .owner_references()
+                .iter()
+                .filter(|o| o.kind == "ReplicaSet")
+            {
+                let owner_id = graph.node("ReplicaSet", &owner.name, "Missing");
+                graph.edge(&owner_id, &pod_id, "owns");
+            }
+
+            let Some(spec) = pod.spec.as_ref() else {
+                continue;
+            };
+            let mut references = References::default();
+            references.collect(spec);
+            for name in &references.configmaps {
+                let configmap_id = graph.node("ConfigMap", name, "Missing");
+                graph.edge(&pod_id, &configmap_id, "uses");
+            }
+            for name in &references.secrets {
+                let secret_id = graph.node("Secret", name, "Missing");
+                graph.edge(&pod_id, &secret_id, "uses");
+            }
+            let claims = spec
+                .volumes
+                .iter()
+                .flatten()
+                .filter_map(|v| v.persistent_volume_claim.as_ref());
+            for claim in claims {
+                let pvc_id = graph.node("PersistentVolumeClaim", &claim.claim_name, "Missing");
+                graph.edge(&pod_id, &pvc_id, "mounts");
+            }
+        }
+
+        // Service -> Endpoints -> Pod; Endpoints share the Service's name
+        for service in &services {
+            let name = service.name_any();
+            if endpoints.iter().any(|ep| ep.name_any() == name) {
+                graph.edge(
+                    &node_id("Service", &name),
+                    &node_id("Endpoints", &name),
+                    "routes_to",
+                );
+            }
+        }
+        for ep in &endpoints {
+            let ep_id = node_id("Endpoints", &ep.name_any());
+            let addresses = ep.subsets.iter().flatten().flat_map(|s| {
+                s.addresses
+                    .iter()
+                    .flatten()
+                    .chain(s.not_ready_addresses.iter().flatten())
+            });
+            for address in addresses {
+                let Some(target) = address.target_ref.as_ref() else {
+                    continue;
+                };
+                if let (Some("Pod"), Some(pod_name)) = (target.kind.as_deref(), &target.name) {
+                    let pod_id = graph.node("Pod", pod_name, "Missing");
+                    graph.edge(&ep_id, &pod_id, "targets");
+                }
+            }
+        }
+
+        // Ingress -> Service from the default backend and every rule path
+        for ingress in &ingresses {
+            let ingress_id = node_id("Ingress", &ingress.name_any());
+            let Some(spec) = ingress.spec.as_ref() else {
+                continue;
+            };
+            let rule_backends = spec
+                .rules
+                .iter()
+                .flatten()
+                .filter_map(|r| r.http.as_ref())
+                .flat_map(|http| http.paths.iter().map(|p| &p.backend));
+            for backend in spec.default_backend.iter().chain(rule_backends) {
+                if let Some(service) = backend.service.as_ref() {
+                    let service_id = graph.node("Service", &service.name, "Missing");
+                    graph.edge(&ingress_id, &service_id, "routes_to");
+                }
+            }
+        }
+
+        // PVC -> PV; PersistentVolumes are cluster scoped so only the bound ones are fetched
+        let pv_api: Api<PersistentVolume> = Api::all(client.clone());
+        for pvc in &pvcs {
+            let Some(volume_name) = pvc.spec.as_ref().and_then(|s| s.volume_name.as_ref()) else {
+                continue;
+            };
+            let pv_status = pv_api
+                .get_opt(volume_name)
+                .await
+                .map_err(|e| {
+                    AppError::Kubernetes(format!(
+                        "Failed to get PersistentVolume {}: {}",
+                        volume_name, e
+                    ))
+                })?
+                .map(|pv| {
+                    pv.status
+                        .and_then(|s| s.phase)
+                        .unwrap_or_else(|| "Unknown".to_string())
+                })
+                .unwrap_or_else(|| "Missing".to_string());
+            let pv_id = graph.node("PersistentVolume", volume_name, pv_status);
+            graph.edge(
+                &node_id("PersistentVolumeClaim", &pvc.name_any()),
+                &pv_id,
+                "bound_to",
+            );
+        }
+
+        Ok(graph.build())
+    }
+}
+
+async fn list<K>(client: &Client, namespace: &str, kind: &str) -> Result<Vec<K>, AppError>
+where
+    K: kube::Resource<Scope = k8s_openapi::NamespaceResourceScope>
+        + Clone
+        + serde::de::DeserializeOwned
+        + std::fmt::Debug,
+    K::DynamicType: Default,
+{
+    Api::<K>::namespaced(client.clone(), namespace)
+        .list(&ListParams::default())
+        .await
+        .map(|list| list.items)
+        .map_err(|e| AppError::Kubernetes(format!("Failed to list {}: {}", kind, e)))
+}
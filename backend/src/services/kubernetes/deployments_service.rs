@@ -22,11 +22,16 @@ use kube::{Api, Client, ResourceExt}; // Added ResourceExt
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::collections::BTreeMap;
+use tracing::instrument;
 
 use crate::errors::AppError;
 use crate::models::cluster::KubernetesClusterConfig;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
+use std::sync::Arc;
 // Use the PodInfo and convert_kube_pod_to_pod_info from the pod module
 use crate::services::kubernetes::client::ClientFactory;
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
 use crate::services::kubernetes::pod::PodInfo;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -40,25 +45,42 @@ pub struct DeploymentInfo {
     pub images: Vec<String>,
 }
 
-pub struct DeploymentsService;
+pub struct DeploymentsService {
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+}
 
 impl DeploymentsService {
     pub fn new() -> Self {
-        DeploymentsService {}
+        Self {
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
     }
 
     async fn get_kube_client(cluster_config: &KubernetesClusterConfig) -> Result<Client, AppError> {
         ClientFactory::get_client(cluster_config).await
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "list_deployments", resource_type = "Deployment"))]
     pub async fn list_deployments(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
-    ) -> Result<Vec<DeploymentInfo>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<DeploymentInfo>, AppError> {
+        let page = PageRequest::new(page_size, continue_token);
         let client = Self::get_kube_client(cluster_config).await?;
-        let api: Api<Deployment> = Api::namespaced(client, namespace);
-        let lp = ListParams::default();
+        let api: Api<Deployment> = if namespace.is_empty() || namespace == "all" {
+            Api::all(client)
+        } else {
+            Api::namespaced(client, namespace)
+        };
+        let lp = page.list_params(ListParams::default());
         let deployment_list = api.list(&lp).await.map_err(|e| {
             AppError::ExternalService(format!(
                 "Failed to list deployments in namespace '{}': {}",
@@ -66,64 +88,138 @@ impl DeploymentsService {
             ))
         })?;
 
-        let mut infos = Vec::new();
-        for d in deployment_list {
-            let name = d.name_any();
-            let replicas = d.spec.as_ref().and_then(|s| s.replicas).unwrap_or(0);
-            let available_replicas = d
-                .status
-                .as_ref()
-                .and_then(|s| s.available_replicas)
-                .unwrap_or(0);
-            let updated_replicas = d
-                .status
-                .as_ref()
-                .and_then(|s| s.updated_replicas)
-                .unwrap_or(0);
-
-            let age = d.metadata.creation_timestamp.as_ref().map_or_else(
-                || "Unknown".to_string(),
-                |ts| {
-                    let creation_time = ts.0;
-                    let duration = Utc::now().signed_duration_since(creation_time);
-                    if duration.num_days() > 0 {
-                        format!("{}d", duration.num_days())
-                    } else if duration.num_hours() > 0 {
-                        format!("{}h", duration.num_hours())
-                    } else if duration.num_minutes() > 0 {
-                        format!("{}m", duration.num_minutes())
-                    } else {
-                        format!("{}s", duration.num_seconds())
-                    }
-                },
-            );
-
-            let images = d
-                .spec
-                .as_ref()
-                .and_then(|s| s.template.spec.as_ref())
-                .map(|pod_spec| {
-                    pod_spec
-                        .containers
-                        .iter()
-                        .filter_map(|c| c.image.clone())
-                        .collect::<Vec<String>>()
+        let infos = deployment_list
+            .iter()
+            .map(|d| Self::to_deployment_info(d, namespace))
+            .collect();
+        Ok(page.finish(deployment_list.metadata, infos))
+    }
+
+    // Lists deployments across all namespaces whose pod template runs the image.
+    // `image_name` matches the full repository or its last path segments, so
+    // "nginx" matches "docker.io/library/nginx:1.25".
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "find_deployments_by_image", resource_type = "Deployment"))]
+    pub async fn find_deployments_by_image(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        image_name: &str,
+        image_tag: Option<&str>,
+    ) -> Result<Vec<DeploymentInfo>, AppError> {
+        let client = Self::get_kube_client(cluster_config).await?;
+        let api: Api<Deployment> = Api::all(client);
+        let deployment_list = api.list(&ListParams::default()).await.map_err(|e| {
+            AppError::ExternalService(format!(
+                "Failed to list deployments across namespaces: {}",
+                e
+            ))
+        })?;
+
+        Ok(deployment_list
+            .iter()
+            .map(|d| Self::to_deployment_info(d, ""))
+            .filter(|info| {
+                info.images.iter().any(|image| {
+                    let (repository, tag) = split_image_reference(image);
+                    repository_matches(repository, image_name)
+                        && image_tag.map_or(true, |wanted| tag == wanted)
                 })
-                .unwrap_or_default();
-
-            infos.push(DeploymentInfo {
-                name,
-                namespace: namespace.to_string(),
-                replicas,
-                available_replicas,
-                updated_replicas,
-                age,
-                images,
-            });
+            })
+            .collect())
+    }
+
+    // Maps each namespace running the app to the image tags of its deployments.
+    // More than one namespace key with differing tags indicates version drift.
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "get_deployment_version_matrix", resource_type = "Deployment"))]
+    pub async fn get_deployment_version_matrix(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        app_label_key: &str,
+        app_label_value: &str,
+    ) -> Result<BTreeMap<String, Vec<String>>, AppError> {
+        let client = Self::get_kube_client(cluster_config).await?;
+        let api: Api<Deployment> = Api::all(client);
+        let lp = ListParams::default().labels(&format!("{}={}", app_label_key, app_label_value));
+        let deployment_list = api.list(&lp).await.map_err(|e| {
+            AppError::ExternalService(format!(
+                "Failed to list deployments labelled {}={}: {}",
+                app_label_key, app_label_value, e
+            ))
+        })?;
+
+        let mut matrix: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for d in deployment_list.iter() {
+            let info = Self::to_deployment_info(d, "");
+            let tags = matrix.entry(info.namespace).or_default();
+            for image in &info.images {
+                let (_, tag) = split_image_reference(image);
+                if !tags.iter().any(|t| t == tag) {
+                    tags.push(tag.to_string());
+                }
+            }
+        }
+        for tags in matrix.values_mut() {
+            tags.sort();
+        }
+
+        Ok(matrix)
+    }
+
+    fn to_deployment_info(d: &Deployment, namespace: &str) -> DeploymentInfo {
+        let name = d.name_any();
+        let replicas = d.spec.as_ref().and_then(|s| s.replicas).unwrap_or(0);
+        let available_replicas = d
+            .status
+            .as_ref()
+            .and_then(|s| s.available_replicas)
+            .unwrap_or(0);
+        let updated_replicas = d
+            .status
+            .as_ref()
+            .and_then(|s| s.updated_replicas)
+            .unwrap_or(0);
+
+        let age = d.metadata.creation_timestamp.as_ref().map_or_else(
+            || "Unknown".to_string(),
+            |ts| {
+                let creation_time = ts.0;
+                let duration = Utc::now().signed_duration_since(creation_time);
+                if duration.num_days() > 0 {
+                    format!("{}d", duration.num_days())
+                } else if duration.num_hours() > 0 {
+                    format!("{}h", duration.num_hours())
+                } else if duration.num_minutes() > 0 {
+                    format!("{}m", duration.num_minutes())
+                } else {
+                    format!("{}s", duration.num_seconds())
+                }
+            },
+        );
+
+        let images = d
+            .spec
+            .as_ref()
+            .and_then(|s| s.template.spec.as_ref())
+            .map(|pod_spec| {
+                pod_spec
+                    .containers
+                    .iter()
+                    .filter_map(|c| c.image.clone())
+                    .collect::<Vec<String>>()
+            })
+            .unwrap_or_default();
+
+        DeploymentInfo {
+            name,
+            namespace: d.namespace().unwrap_or_else(|| namespace.to_string()),
+            replicas,
+            available_replicas,
+            updated_replicas,
+            age,
+            images,
         }
-        Ok(infos)
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "get_deployment_details", resource_type = "Deployment"))]
     pub async fn get_deployment_details(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -140,11 +236,13 @@ impl DeploymentsService {
         })
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "delete_deployment", resource_type = "Deployment"))]
     pub async fn delete_deployment(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<Deployment> = Api::namespaced(client, namespace);
@@ -156,15 +254,23 @@ impl DeploymentsService {
                     name, namespace, e
                 ))
             })?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::DELETE, "Deployment", name)
+                .with_namespace(namespace),
+        )
+        .await;
         Ok(())
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "scale_deployment", resource_type = "Deployment"))]
     pub async fn scale_deployment(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
         name: &str,
         replicas: i32,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<Deployment> = Api::namespaced(client, namespace);
@@ -179,14 +285,23 @@ impl DeploymentsService {
                     name, namespace, e
                 ))
             })?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::SCALE, "Deployment", name)
+                .with_namespace(namespace)
+                .with_payload(json!({ "replicas": replicas })),
+        )
+        .await;
         Ok(())
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "restart_deployment", resource_type = "Deployment"))]
     pub async fn restart_deployment(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<Deployment> = Api::namespaced(client, namespace);
@@ -215,9 +330,16 @@ impl DeploymentsService {
                     name, namespace, e
                 ))
             })?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::RESTART, "Deployment", name)
+                .with_namespace(namespace),
+        )
+        .await;
         Ok(())
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "get_pods_for_deployment", resource_type = "Pod"))]
     pub async fn get_pods_for_deployment(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -273,11 +395,13 @@ impl DeploymentsService {
         Ok(pod_infos)
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "delete_all_pods_for_deployment", resource_type = "Pod"))]
     pub async fn delete_all_pods_for_deployment(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
         deployment_name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let client = Self::get_kube_client(cluster_config).await?;
         let deployment_api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
@@ -341,6 +465,38 @@ impl DeploymentsService {
             // Consider adding a small delay or logging here if needed
         }
 
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(
+                audit_ctx,
+                AuditOperation::DELETE_PODS,
+                "Deployment",
+                deployment_name,
+            )
+            .with_namespace(namespace),
+        )
+        .await;
         Ok(())
     }
 }
+
+// Splits "registry/repo:tag@digest" into the repository and tag, defaulting
+// the tag to "latest" the way the container runtime does
+fn split_image_reference(image: &str) -> (&str, &str) {
+    let without_digest = image.split('@').next().unwrap_or(image);
+    let name_start = without_digest.rfind('/').map_or(0, |i| i + 1);
+    match without_digest[name_start..].rfind(':') {
+        Some(i) => (
+            &without_digest[..name_start + i],
+            &without_digest[name_start + i + 1..],
+        ),
+        None => (without_digest, "latest"),
+    }
+}
+
+fn repository_matches(repository: &str, image_name: &str) -> bool {
+    repository == image_name
+        || repository
+            .strip_suffix(image_name)
+            .map_or(false, |prefix| prefix.ends_with('/'))
+}
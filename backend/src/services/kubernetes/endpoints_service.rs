@@ -15,17 +15,57 @@
 
 use crate::errors::AppError;
 use crate::models::cluster::KubernetesClusterConfig;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
+use std::sync::Arc;
 use crate::services::kubernetes::client::ClientFactory;
-use k8s_openapi::api::core::v1::Endpoints;
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
+use futures::future::join_all;
+use k8s_openapi::api::core::v1::{Endpoints, Pod};
 use k8s_openapi::api::discovery::v1::EndpointSlice;
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
 use kube::api::{DeleteParams, ListParams, Patch, PatchParams};
 use kube::Api;
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tracing::instrument;
 
-pub struct EndpointsService;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndpointAddress {
+    pub ip: String,
+    pub port: i32,
+    pub pod_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndpointHealthReport {
+    pub service_name: String,
+    pub healthy_endpoints: Vec<EndpointAddress>,
+    pub unhealthy_endpoints: Vec<(EndpointAddress, String)>, // Address and failure reason
+    pub health_rate: f64,
+}
+
+// HTTP readiness check taken from the backing pod's readinessProbe
+struct HttpCheck {
+    url: String,
+}
+
+pub struct EndpointsService {
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+}
 
 impl EndpointsService {
     pub fn new() -> Self {
-        Self
+        Self {
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
     }
 
     async fn endpoints_api(
@@ -52,32 +92,41 @@ impl EndpointsService {
         })
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "list_endpoints", resource_type = "Endpoints"))]
     pub async fn list_endpoints(
         &self,
         cluster: &KubernetesClusterConfig,
         namespace: &str,
-    ) -> Result<Vec<Endpoints>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<Endpoints>, AppError> {
         let api = Self::endpoints_api(cluster, namespace).await?;
+        let page = PageRequest::new(page_size, continue_token);
         let list = api
-            .list(&ListParams::default())
+            .list(&page.list_params(ListParams::default()))
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
-        Ok(list.items)
+        Ok(page.finish(list.metadata, list.items))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "list_endpoint_slices", resource_type = "EndpointSlice"))]
     pub async fn list_endpoint_slices(
         &self,
         cluster: &KubernetesClusterConfig,
         namespace: &str,
-    ) -> Result<Vec<EndpointSlice>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<EndpointSlice>, AppError> {
         let api = Self::endpoint_slice_api(cluster, namespace).await?;
+        let page = PageRequest::new(page_size, continue_token);
         let list = api
-            .list(&ListParams::default())
+            .list(&page.list_params(ListParams::default()))
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
-        Ok(list.items)
+        Ok(page.finish(list.metadata, list.items))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "get_endpoints", resource_type = "Endpoints"))]
     pub async fn get_endpoints(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -91,6 +140,7 @@ impl EndpointsService {
             .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "upsert_endpoints", resource_type = "Endpoints"))]
     pub async fn upsert_endpoints(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -112,17 +162,200 @@ impl EndpointsService {
         .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "delete_endpoints", resource_type = "Endpoints"))]
     pub async fn delete_endpoints(
         &self,
         cluster: &KubernetesClusterConfig,
         namespace: &str,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let api: Api<Endpoints> =
             Api::namespaced(ClientFactory::get_client(cluster).await?, namespace);
         api.delete(name, &DeleteParams::default())
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::DELETE, "Endpoints", name)
+                .with_namespace(namespace),
+        )
+        .await;
         Ok(())
     }
+    // Dials every ready address of the service and, when the backing pod declares an
+    // httpGet readinessProbe, also requests that path. Addresses Kubernetes already
+    // marks not ready are reported unhealthy without being probed.
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "health_check_endpoints", resource_type = "Endpoints"))]
+    pub async fn health_check_endpoints(
+        &self,
+        cluster: &KubernetesClusterConfig,
+        namespace: &str,
+        service_name: &str,
+        timeout_secs: u64,
+    ) -> Result<EndpointHealthReport, AppError> {
+        let client = ClientFactory::get_client(cluster).await?;
+        let endpoints_api: Api<Endpoints> = Api::namespaced(client.clone(), namespace);
+        let pods_api: Api<Pod> = Api::namespaced(client, namespace);
+        let endpoints = endpoints_api
+            .get(service_name)
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+
+        let timeout = Duration::from_secs(timeout_secs.max(1));
+        let http_client = reqwest::Client::builder()
+            .timeout(timeout)
+            .danger_accept_invalid_certs(true)
+            .build()
+            .map_err(|e| AppError::Internal(format!("Failed to build HTTP client: {}", e)))?;
+
+        let mut targets = Vec::new();
+        let mut unhealthy_endpoints = Vec::new();
+        for subset in endpoints.subsets.unwrap_or_default() {
+            let ports = subset.ports.unwrap_or_default();
+            for address in subset.addresses.unwrap_or_default() {
+                let pod = match pod_name(&address) {
+                    Some(name) => pods_api.get_opt(&name).await.ok().flatten(),
+                    None => None,
+                };
+                for port in &ports {
+                    let http_check = pod
+                        .as_ref()
+                        .and_then(|pod| readiness_http_check(pod, &address.ip, port.port));
+                    targets.push((
+                        EndpointAddress {
+                            ip: address.ip.clone(),
+                            port: port.port,
+                            pod_name: pod_name(&address),
+                        },
+                        http_check,
+                    ));
+                }
+            }
+            for address in subset.not_ready_addresses.unwrap_or_default() {
+                for port in &ports {
+                    unhealthy_endpoints.push((
+                        EndpointAddress {
+                            ip: address.ip.clone(),
+                            port: port.port,
+                            pod_name: pod_name(&address),
+                        },
+                        "Marked not ready by Kubernetes".to_string(),
+                    ));
+                }
+            }
+        }
+
+        let results = join_all(targets.into_iter().map(|(address, http_check)| {
+            let http_client = http_client.clone();
+            async move {
+                let result = probe_endpoint(&http_client, &address, http_check, timeout).await;
+                (address, result)
+            }
+        }))
+        .await;
+
+        let mut healthy_endpoints = Vec::new();
+        for (address, result) in results {
+            match result {
+                Ok(()) => healthy_endpoints.push(address),
+                Err(reason) => unhealthy_endpoints.push((address, reason)),
+            }
+        }
+
+        let total = healthy_endpoints.len() + unhealthy_endpoints.len();
+        let health_rate = if total == 0 {
+            0.0
+        } else {
+            healthy_endpoints.len() as f64 / total as f64
+        };
+
+        Ok(EndpointHealthReport {
+            service_name: service_name.to_string(),
+            healthy_endpoints,
+            unhealthy_endpoints,
+            health_rate,
+        })
+    }
+}
+
+fn pod_name(address: &k8s_openapi::api::core::v1::EndpointAddress) -> Option<String> {
+    address
+        .target_ref
+        .as_ref()
+        .filter(|r| r.kind.as_deref() == Some("Pod"))
+        .and_then(|r| r.name.clone())
+}
+
+// Picks the container serving the endpoint port and resolves its probe port, which may be
+// named or differ from the service port
+fn readiness_http_check(pod: &Pod, ip: &str, port: i32) -> Option<HttpCheck> {
+    let containers = &pod.spec.as_ref()?.containers;
+    let container = containers
+        .iter()
+        .find(|c| {
+            c.ports.as_ref().map_or(false, |ports| {
+                ports.iter().any(|p| p.container_port == port)
+            })
+        })
+        .or_else(|| containers.first().filter(|_| containers.len() == 1))?;
+    let http_get = container.readiness_probe.as_ref()?.http_get.as_ref()?;
+
+    let probe_port = match &http_get.port {
+        IntOrString::Int(p) => *p,
+        IntOrString::String(name) => {
+            container
+                .ports
+                .as_ref()?
+                .iter()
+                .find(|p| p.name.as_deref() == Some(name.as_str()))?
+                .container_port
+        }
+    };
+    let scheme = match http_get.scheme.as_deref() {
+        Some("HTTPS") => "https",
+        _ => "http",
+    };
+    let path = http_get.path.as_deref().unwrap_or("/");
+    Some(HttpCheck {
+        url: format!("{}://{}{}", scheme, socket_addr(ip, probe_port)?, path),
+    })
+}
+
+// SocketAddr writes IPv6 addresses as [ip]:port, as both connect and URLs expect
+fn socket_addr(ip: &str, port: i32) -> Option<SocketAddr> {
+    Some(SocketAddr::new(ip.parse().ok()?, u16::try_from(port).ok()?))
+}
+
+async fn probe_endpoint(
+    http_client: &reqwest::Client,
+    address: &EndpointAddress,
+    http_check: Option<HttpCheck>,
+    timeout: Duration,
+) -> Result<(), String> {
+    let target = socket_addr(&address.ip, address.port).ok_or_else(|| {
+        format!(
+            "Invalid endpoint address {} port {}",
+            address.ip, address.port
+        )
+    })?;
+    match tokio::time::timeout(timeout, TcpStream::connect(target)).await {
+        Ok(Ok(_)) => {}
+        Ok(Err(e)) => return Err(format!("TCP connect to {} failed: {}", target, e)),
+        Err(_) => return Err(format!("TCP connect to {} timed out", target)),
+    }
+
+    if let Some(check) = http_check {
+        let response = http_client
+            .get(&check.url)
+            .send()
+            .await
+            .map_err(|e| format!("GET {} failed: {}", check.url, e))?;
+        // Same success range kubelet uses for httpGet probes
+        let status = response.status().as_u16();
+        if !(200..400).contains(&status) {
+            return Err(format!("GET {} returned {}", check.url, status));
+        }
+    }
+    Ok(())
 }
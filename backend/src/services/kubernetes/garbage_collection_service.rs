@@ -0,0 +1,384 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::AppError;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
+use crate::services::kubernetes::client::ClientFactory;
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::core::v1::{ConfigMap, PersistentVolumeClaim, Pod, PodSpec, Secret};
+use k8s_openapi::api::networking::v1::Ingress;
+use kube::api::{DeleteParams, ListParams};
+use kube::{Api, Client, ResourceExt};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+// Created by Kubernetes in every namespace
+const KUBE_ROOT_CA_CONFIGMAP: &str = "kube-root-ca.crt";
+const TLS_SECRET_TYPE: &str = "kubernetes.io/tls";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrphanedResourceReport {
+    pub namespace: String,
+    pub orphaned_configmaps: Vec<String>,
+    pub orphaned_pvcs: Vec<String>,
+    pub orphaned_secrets: Vec<String>,
+    // Size of the orphaned ConfigMap and Secret payloads held by the API server
+    pub estimated_memory_freed_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GarbageCollectionResult {
+    pub dry_run: bool,
+    pub deleted: Vec<String>,
+    // Listed in the report but referenced again since it was generated
+    pub skipped: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+// ConfigMaps and Secrets a pod spec refers to through volumes, env and image pull secrets,
+// plus the PVCs it mounts
+#[derive(Default)]
+pub(crate) struct References {
+    pub(crate) configmaps: HashSet<String>,
+    pub(crate) secrets: HashSet<String>,
+    pub(crate) persistent_volume_claims: HashSet<String>,
+}
+
+impl References {
+    pub(crate) fn collect(&mut self, spec: &PodSpec) {
+        for volume in spec.volumes.iter().flatten() {
+            if let Some(name) = volume.config_map.as_ref().and_then(|c| c.name.clone()) {
+                self.configmaps.insert(name);
+            }
+            if let Some(name) = volume.secret.as_ref().and_then(|s| s.secret_name.clone()) {
+                self.secrets.insert(name);
+            }
+            if let Some(claim) = volume.persistent_volume_claim.as_ref() {
+                self.persistent_volume_claims
+                    .insert(claim.claim_name.clone());
+            }
+            let sources = volume.projected.as_ref().and_then(|p| p.sources.as_ref());
+            for source in sources.into_iter().flatten() {
+                if let Some(name) = source.config_map.as_ref().and_then(|c| c.name.clone()) {
+                    self.configmaps.insert(name);
+                }
+                if let Some(name) = source.secret.as_ref().and_then(|s| s.name.clone()) {
+                    self.secrets.insert(name);
+                }
+            }
+        }
+
+        let containers = spec
+            .containers
+            .iter()
+            .chain(spec.init_containers.iter().flatten());
+        for container in containers {
+            for env in container.env.iter().flatten() {
+                let value_from = env.value_from.as_ref();
+                if let Some(name) = value_from
+                    .and_then(|v| v.config_map_key_ref.as_ref())
+                    .and_then(|r| r.name.clone())
+                {
+                    self.configmaps.insert(name);
+                }
+                if let Some(name) = value_from
+                    .and_then(|v| v.secret_key_ref.as_ref())
+                    .and_then(|r| r.name.clone())
+                {
+                    self.secrets.insert(name);
+                }
+            }
+            for env_from in container.env_from.iter().flatten() {
+                if let Some(name) = env_from
+                    .config_map_ref
+                    .as_ref()
+                    .and_then(|r| r.name.clone())
+                {
+                    self.configmaps.insert(name);
+                }
+                if let Some(name) = env_from.secret_ref.as_ref().and_then(|r| r.name.clone()) {
+                    self.secrets.insert(name);
+                }
+            }
+        }
+
+        for pull_secret in spec.image_pull_secrets.iter().flatten() {
+            if let Some(name) = pull_secret.name.clone() {
+                self.secrets.insert(name);
+            }
+        }
+    }
+}
+
+pub struct GarbageCollectionService {
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+}
+
+impl GarbageCollectionService {
+    pub fn new() -> Self {
+        Self {
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
+    }
+
+    // ConfigMaps no pod or workload template uses; PVCs that are not bound and no pod mounts;
+    // TLS Secrets no Ingress, pod or workload template uses. Workload templates are checked
+    // even when scaled to zero. Resources with an owner are left to the owner's lifecycle.
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "find_orphaned_resources"))]
+    pub async fn find_orphaned_resources(
+        &self,
+        cluster: &KubernetesClusterConfig,
+        namespace: &str,
+    ) -> Result<OrphanedResourceReport, AppError> {
+        let client = ClientFactory::get_client(cluster).await?;
+        let pods = list::<Pod>(&client, namespace).await?;
+        let deployments = list::<Deployment>(&client, namespace).await?;
+        let stateful_sets = list::<StatefulSet>(&client, namespace).await?;
+        let daemon_sets = list::<DaemonSet>(&client, namespace).await?;
+        let jobs = list::<Job>(&client, namespace).await?;
+        let cron_jobs = list::<CronJob>(&client, namespace).await?;
+        let ingresses = list::<Ingress>(&client, namespace).await?;
+
+        let mut references = References::default();
+        let pod_specs = pods
+            .iter()
+            .filter_map(|p| p.spec.as_ref())
+            .chain(
+                deployments
+                    .iter()
+                    .filter_map(|d| d.spec.as_ref()?.template.spec.as_ref()),
+            )
+            .chain(
+                stateful_sets
+                    .iter()
+                    .filter_map(|s| s.spec.as_ref()?.template.spec.as_ref()),
+            )
+            .chain(
+                daemon_sets
+                    .iter()
+                    .filter_map(|d| d.spec.as_ref()?.template.spec.as_ref()),
+            )
+            .chain(
+                jobs.iter()
+                    .filter_map(|j| j.spec.as_ref()?.template.spec.as_ref()),
+            )
+            .chain(cron_jobs.iter().filter_map(|c| {
+                c.spec
+                    .as_ref()?
+                    .job_template
+                    .spec
+                    .as_ref()?
+                    .template
+                    .spec
+                    .as_ref()
+            }));
+        for spec in pod_specs {
+            references.collect(spec);
+        }
+        let ingress_secrets: HashSet<String> = ingresses
+            .iter()
+            .filter_map(|i| i.spec.as_ref()?.tls.as_ref())
+            .flatten()
+            .filter_map(|tls| tls.secret_name.clone())
+            .collect();
+
+        let mut estimated_memory_freed_bytes = 0u64;
+
+        let mut orphaned_configmaps = Vec::new();
+        for configmap in list::<ConfigMap>(&client, namespace).await? {
+            let name = configmap.name_any();
+            if name == KUBE_ROOT_CA_CONFIGMAP
+                || !configmap.owner_references().is_empty()
+                || references.configmaps.contains(&name)
+            {
+                continue;
+            }
+            let data_bytes: usize = configmap.data.iter().flatten().map(|(_, v)| v.len()).sum();
+            let binary_bytes: usize = configmap
+                .binary_data
+                .iter()
+                .flatten()
+                .map(|(_, v)| v.0.len())
+                .sum();
+            estimated_memory_freed_bytes += (data_bytes + binary_bytes) as u64;
+            orphaned_configmaps.push(name);
+        }
+
+        let orphaned_pvcs = list::<PersistentVolumeClaim>(&client, namespace)
+            .await?
+            .into_iter()
+            .filter(|pvc| pvc.owner_references().is_empty())
+            .filter(|pvc| pvc.status.as_ref().and_then(|s| s.phase.as_deref()) != Some("Bound"))
+            .filter(|pvc| {
+                !references
+                    .persistent_volume_claims
+                    .contains(&pvc.name_any())
+            })
+            .map(|pvc| pvc.name_any())
+            .collect();
+
+        let mut orphaned_secrets = Vec::new();
+        for secret in list::<Secret>(&client, namespace).await? {
+            let name = secret.name_any();
+            if secret.type_.as_deref() != Some(TLS_SECRET_TYPE)
+                || !secret.owner_references().is_empty()
+                || ingress_secrets.contains(&name)
+                || references.secrets.contains(&name)
+            {
+                continue;
+            }
+            let data_bytes: usize = secret.data.iter().flatten().map(|(_, v)| v.0.len()).sum();
+            estimated_memory_freed_bytes += data_bytes as u64;
+            orphaned_secrets.push(name);
+        }
+
+        Ok(OrphanedResourceReport {
+            namespace: namespace.to_string(),
+            orphaned_configmaps,
+            orphaned_pvcs,
+            orphaned_secrets,
+            estimated_memory_freed_bytes,
+        })
+    }
+
+    // Deletes what the report lists, but only resources that are still orphaned now, so a
+    // report that went stale cannot remove something that came back into use.
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %report.namespace, operation = "cleanup_orphaned_resources", dry_run))]
+    pub async fn cleanup(
+        &self,
+        cluster: &KubernetesClusterConfig,
+        report: &OrphanedResourceReport,
+        dry_run: bool,
+        audit_ctx: &AuditContext,
+    ) -> Result<GarbageCollectionResult, AppError> {
+        let namespace = report.namespace.as_str();
+        let current = self.find_orphaned_resources(cluster, namespace).await?;
+        let client = ClientFactory::get_client(cluster).await?;
+
+        let mut result = GarbageCollectionResult {
+            dry_run,
+            deleted: Vec::new(),
+            skipped: Vec::new(),
+            errors: Vec::new(),
+        };
+        let targets = [
+            (
+                "ConfigMap",
+                &report.orphaned_configmaps,
+                &current.orphaned_configmaps,
+            ),
+            (
+                "PersistentVolumeClaim",
+                &report.orphaned_pvcs,
+                &current.orphaned_pvcs,
+            ),
+            (
+                "Secret",
+                &report.orphaned_secrets,
+                &current.orphaned_secrets,
+            ),
+        ];
+        for (kind, requested, still_orphaned) in targets {
+            for name in requested {
+                let resource = format!("{}/{}", kind, name);
+                if !still_orphaned.contains(name) {
+                    result.skipped.push(resource);
+                    continue;
+                }
+                if dry_run {
+                    result.deleted.push(resource);
+                    continue;
+                }
+
+                let deleted = match kind {
+                    "ConfigMap" => delete::<ConfigMap>(&client, namespace, name).await,
+                    "PersistentVolumeClaim" => {
+                        delete::<PersistentVolumeClaim>(&client, namespace, name).await
+                    }
+                    _ => delete::<Secret>(&client, namespace, name).await,
+                };
+                match deleted {
+                    Ok(()) => {
+                        emit_if_configured(
+                            &self.audit_emitter,
+                            AuditEvent::new(audit_ctx, AuditOperation::DELETE, kind, name)
+                                .with_namespace(namespace),
+                        )
+                        .await;
+                        result.deleted.push(resource);
+                    }
+                    Err(e) => {
+                        warn!(%resource, error = %e, "Failed to delete orphaned resource");
+                        result.errors.push(format!("{}: {}", resource, e));
+                    }
+                }
+            }
+        }
+
+        info!(
+            deleted = result.deleted.len(),
+            skipped = result.skipped.len(),
+            errors = result.errors.len(),
+            dry_run,
+            "Orphaned resource cleanup complete"
+        );
+        Ok(result)
+    }
+}
+
+impl Default for GarbageCollectionService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+async fn list<K>(client: &Client, namespace: &str) -> Result<Vec<K>, AppError>
+where
+    K: kube::Resource<Scope = k8s_openapi::NamespaceResourceScope>
+        + Clone
+        + serde::de::DeserializeOwned
+        + std::fmt::Debug,
+    K::DynamicType: Default,
+{
+    Api::<K>::namespaced(client.clone(), namespace)
+        .list(&ListParams::default())
+        .await
+        .map(|list| list.items)
+        .map_err(|e| AppError::Kubernetes(e.to_string()))
+}
+
+async fn delete<K>(client: &Client, namespace: &str, name: &str) -> Result<(), AppError>
+where
+    K: kube::Resource<Scope = k8s_openapi::NamespaceResourceScope>
+        + Clone
+        + serde::de::DeserializeOwned
+        + std::fmt::Debug,
+    K::DynamicType: Default,
+{
+    Api::<K>::namespaced(client.clone(), namespace)
+        .delete(name, &DeleteParams::default())
+        .await
+        .map(|_| ())
+        .map_err(|e| AppError::Kubernetes(e.to_string()))
+}
@@ -14,17 +14,31 @@
 
 
 use crate::errors::AppError;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
 use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
 use crate::services::kubernetes::client::ClientFactory;
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
 use k8s_openapi::api::autoscaling::v2::HorizontalPodAutoscaler;
 use kube::api::{DeleteParams, ListParams, Patch, PatchParams};
 use kube::Api;
+use std::sync::Arc;
+use tracing::instrument;
 
-pub struct HorizontalPodAutoscalerService;
+pub struct HorizontalPodAutoscalerService {
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+}
 
 impl HorizontalPodAutoscalerService {
     pub fn new() -> Self {
-        Self
+        Self {
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
     }
 
     async fn api(
@@ -39,19 +53,24 @@ impl HorizontalPodAutoscalerService {
         })
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "list", resource_type = "HorizontalPodAutoscaler"))]
     pub async fn list(
         &self,
         cluster: &KubernetesClusterConfig,
         namespace: &str,
-    ) -> Result<Vec<HorizontalPodAutoscaler>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<HorizontalPodAutoscaler>, AppError> {
         let api = Self::api(cluster, namespace).await?;
+        let page = PageRequest::new(page_size, continue_token);
         let list = api
-            .list(&ListParams::default())
+            .list(&page.list_params(ListParams::default()))
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
-        Ok(list.items)
+        Ok(page.finish(list.metadata, list.items))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "get", resource_type = "HorizontalPodAutoscaler"))]
     pub async fn get(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -65,6 +84,7 @@ impl HorizontalPodAutoscalerService {
             .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "upsert", resource_type = "HorizontalPodAutoscaler"))]
     pub async fn upsert(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -85,17 +105,30 @@ impl HorizontalPodAutoscalerService {
         .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "delete", resource_type = "HorizontalPodAutoscaler"))]
     pub async fn delete(
         &self,
         cluster: &KubernetesClusterConfig,
         namespace: &str,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let api: Api<HorizontalPodAutoscaler> =
             Api::namespaced(ClientFactory::get_client(cluster).await?, namespace);
         api.delete(name, &DeleteParams::default())
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(
+                audit_ctx,
+                AuditOperation::DELETE,
+                "HorizontalPodAutoscaler",
+                name,
+            )
+            .with_namespace(namespace),
+        )
+        .await;
         Ok(())
     }
 }
@@ -14,17 +14,55 @@
 
 
 use crate::errors::AppError;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
 use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
 use crate::services::kubernetes::client::ClientFactory;
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
 use k8s_openapi::api::batch::v1::Job;
-use kube::api::{DeleteParams, ListParams, Patch, PatchParams, PostParams};
-use kube::{Api, Client};
+use k8s_openapi::api::core::v1::Pod;
+use kube::api::{DeleteParams, ListParams, LogParams, Patch, PatchParams, PostParams};
+use kube::{Api, Client, ResourceExt};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{info, instrument, warn};
 
-pub struct JobsService;
+const JOB_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const FAILED_POD_LOG_LINES: i64 = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    Complete,
+    Failed,
+    TimedOut,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobCompletionResult {
+    pub job_name: String,
+    pub status: JobStatus,
+    pub duration_secs: u64,
+    pub failed_pods: Vec<String>,
+    // Collected from the failed pods when the job fails
+    pub logs: Option<String>,
+}
+
+pub struct JobsService {
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+}
 
 impl JobsService {
     pub fn new() -> Self {
-        Self
+        Self {
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
     }
 
     async fn api(cluster: &KubernetesClusterConfig, namespace: &str) -> Result<Api<Job>, AppError> {
@@ -36,20 +74,24 @@ impl JobsService {
         })
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "list", resource_type = "Job"))]
     pub async fn list(
         &self,
         cluster: &KubernetesClusterConfig,
         namespace: &str,
-    ) -> Result<Vec<Job>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<Job>, AppError> {
         let api = Self::api(cluster, namespace).await?;
-        let lp = ListParams::default();
+        let page = PageRequest::new(page_size, continue_token);
         let list = api
-            .list(&lp)
+            .list(&page.list_params(ListParams::default()))
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
-        Ok(list.items)
+        Ok(page.finish(list.metadata, list.items))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "get", resource_type = "Job"))]
     pub async fn get(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -62,6 +104,7 @@ impl JobsService {
             .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "upsert", resource_type = "Job"))]
     pub async fn upsert(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -82,16 +125,126 @@ impl JobsService {
         .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "delete", resource_type = "Job"))]
     pub async fn delete(
         &self,
         cluster: &KubernetesClusterConfig,
         namespace: &str,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let api: Api<Job> = Api::namespaced(ClientFactory::get_client(cluster).await?, namespace);
         api.delete(name, &DeleteParams::default())
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::DELETE, "Job", name)
+                .with_namespace(namespace),
+        )
+        .await;
         Ok(())
     }
+
+    // Creates the job and blocks until it reports Complete or Failed or the
+    // timeout elapses, e.g. for migration jobs driven from CI
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "run_job_and_wait", resource_type = "Job"))]
+    pub async fn run_job_and_wait(
+        &self,
+        cluster: &KubernetesClusterConfig,
+        namespace: &str,
+        job_spec: Job,
+        timeout_secs: u64,
+        delete_on_completion: bool,
+    ) -> Result<JobCompletionResult, AppError> {
+        let client = ClientFactory::get_client(cluster).await?;
+        let api: Api<Job> = Api::namespaced(client.clone(), namespace);
+        let started = Instant::now();
+
+        let job = api
+            .create(&PostParams::default(), &job_spec)
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        let job_name = job.name_any();
+        info!(
+            "Started job {}/{}, waiting up to {}s",
+            namespace, job_name, timeout_secs
+        );
+
+        let deadline = started + Duration::from_secs(timeout_secs);
+        let status = loop {
+            let current = api
+                .get(&job_name)
+                .await
+                .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+            if let Some(status) = Self::finished_status(&current) {
+                break status;
+            }
+            if Instant::now() >= deadline {
+                break JobStatus::TimedOut;
+            }
+            tokio::time::sleep(JOB_POLL_INTERVAL).await;
+        };
+
+        let pods: Api<Pod> = Api::namespaced(client, namespace);
+        let lp = ListParams::default().labels(&format!("job-name={}", job_name));
+        let failed_pods: Vec<String> = pods
+            .list(&lp)
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?
+            .items
+            .iter()
+            .filter(|p| p.status.as_ref().and_then(|s| s.phase.as_deref()) == Some("Failed"))
+            .map(|p| p.name_any())
+            .collect();
+
+        let logs = if status == JobStatus::Failed {
+            Some(Self::collect_logs(&pods, &failed_pods).await)
+        } else {
+            None
+        };
+
+        if delete_on_completion {
+            // Background propagation removes the job's pods along with it
+            if let Err(e) = api.delete(&job_name, &DeleteParams::background()).await {
+                warn!("Failed to delete job {}/{}: {}", namespace, job_name, e);
+            }
+        }
+
+        Ok(JobCompletionResult {
+            job_name,
+            status,
+            duration_secs: started.elapsed().as_secs(),
+            failed_pods,
+            logs,
+        })
+    }
+
+    fn finished_status(job: &Job) -> Option<JobStatus> {
+        let conditions = job.status.as_ref()?.conditions.as_ref()?;
+        conditions
+            .iter()
+            .filter(|c| c.status == "True")
+            .find_map(|c| match c.type_.as_str() {
+                "Complete" => Some(JobStatus::Complete),
+                "Failed" => Some(JobStatus::Failed),
+                _ => None,
+            })
+    }
+
+    async fn collect_logs(pods: &Api<Pod>, pod_names: &[String]) -> String {
+        let lp = LogParams {
+            tail_lines: Some(FAILED_POD_LOG_LINES),
+            ..LogParams::default()
+        };
+        let mut logs = Vec::new();
+        for name in pod_names {
+            let output = match pods.logs(name, &lp).await {
+                Ok(output) => output,
+                Err(e) => format!("<failed to fetch logs: {}>", e),
+            };
+            logs.push(format!("==> {} <==\n{}", name, output));
+        }
+        logs.join("\n")
+    }
 }
@@ -0,0 +1,169 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use std::collections::BTreeMap;
+
+use kube::api::{Api, DynamicObject, GroupVersionKind, Patch, PatchParams};
+use kube::discovery::{Discovery, Scope};
+use kube::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tracing::{debug, info, instrument};
+
+use crate::errors::AppError;
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::client::ClientFactory;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceRef {
+    pub api_version: String, // e.g. "v1", "apps/v1"
+    pub kind: String,
+    pub namespace: Option<String>,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LabelApplyResult {
+    pub applied: Vec<ResourceRef>,
+    pub skipped: Vec<ResourceRef>,
+    pub failed: Vec<(ResourceRef, String)>,
+}
+
+pub struct LabelService;
+
+impl LabelService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Merge-patch labels and annotations onto each resource. With `overwrite = false`,
+    /// resources already carrying any of the label keys are left untouched.
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "apply_labels", resource_type = "Resource"))]
+    pub async fn apply_labels(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        resource_refs: Vec<ResourceRef>,
+        labels: BTreeMap<String, String>,
+        annotations: BTreeMap<String, String>,
+        overwrite: bool,
+    ) -> Result<LabelApplyResult, AppError> {
+        if labels.is_empty() && annotations.is_empty() {
+            return Err(AppError::BadRequest(
+                "At least one label or annotation is required".to_string(),
+            ));
+        }
+
+        let client = ClientFactory::get_client(cluster_config).await?;
+        let discovery = Discovery::new(client.clone())
+            .run()
+            .await
+            .map_err(|e| AppError::ExternalService(format!("Discovery failed: {}", e)))?;
+
+        let mut metadata = serde_json::Map::new();
+        if !labels.is_empty() {
+            metadata.insert("labels".to_string(), json!(labels));
+        }
+        if !annotations.is_empty() {
+            metadata.insert("annotations".to_string(), json!(annotations));
+        }
+        let patch = json!({ "metadata": metadata });
+
+        let mut result = LabelApplyResult::default();
+        for resource in resource_refs {
+            let api = match Self::dynamic_api(&client, &discovery, &resource) {
+                Ok(api) => api,
+                Err(e) => {
+                    result.failed.push((resource, e.to_string()));
+                    continue;
+                }
+            };
+
+            if !overwrite {
+                match api.get(&resource.name).await {
+                    Ok(existing) => {
+                        let has_label = existing
+                            .metadata
+                            .labels
+                            .as_ref()
+                            .map(|current| labels.keys().any(|k| current.contains_key(k)))
+                            .unwrap_or(false);
+                        if has_label {
+                            debug!(
+                                kind = %resource.kind,
+                                name = %resource.name,
+                                "Skipping resource that already has a requested label"
+                            );
+                            result.skipped.push(resource);
+                            continue;
+                        }
+                    }
+                    Err(e) => {
+                        result.failed.push((resource, e.to_string()));
+                        continue;
+                    }
+                }
+            }
+
+            match api
+                .patch(&resource.name, &PatchParams::default(), &Patch::Merge(&patch))
+                .await
+            {
+                Ok(_) => result.applied.push(resource),
+                Err(e) => result.failed.push((resource, e.to_string())),
+            }
+        }
+
+        info!(
+            applied = result.applied.len(),
+            skipped = result.skipped.len(),
+            failed = result.failed.len(),
+            "Applied labels to resources"
+        );
+        Ok(result)
+    }
+
+    fn dynamic_api(
+        client: &Client,
+        discovery: &Discovery,
+        resource: &ResourceRef,
+    ) -> Result<Api<DynamicObject>, AppError> {
+        let (group, version) = match resource.api_version.split_once('/') {
+            Some((group, version)) => (group, version),
+            None => ("", resource.api_version.as_str()),
+        };
+        let gvk = GroupVersionKind::gvk(group, version, &resource.kind);
+        let (ar, caps) = discovery.resolve_gvk(&gvk).ok_or_else(|| {
+            AppError::NotFound(format!(
+                "Resource kind {} not found in {}",
+                resource.kind, resource.api_version
+            ))
+        })?;
+
+        match (caps.scope, resource.namespace.as_deref()) {
+            (Scope::Namespaced, Some(ns)) => Ok(Api::namespaced_with(client.clone(), ns, &ar)),
+            (Scope::Namespaced, None) => Err(AppError::BadRequest(format!(
+                "{} {} is namespaced but no namespace was given",
+                resource.kind, resource.name
+            ))),
+            (Scope::Cluster, _) => Ok(Api::all_with(client.clone(), &ar)),
+        }
+    }
+}
+
+impl Default for LabelService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
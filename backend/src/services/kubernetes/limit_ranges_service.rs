@@ -14,17 +14,88 @@
 
 
 use crate::errors::AppError;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
 use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
 use crate::services::kubernetes::client::ClientFactory;
-use k8s_openapi::api::core::v1::LimitRange;
-use kube::api::{DeleteParams, ListParams, Patch, PatchParams};
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
+use k8s_openapi::api::core::v1::{LimitRange, LimitRangeItem, LimitRangeSpec};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use kube::api::{DeleteParams, ListParams, ObjectMeta, Patch, PatchParams};
 use kube::Api;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::instrument;
 
-pub struct LimitRangesService;
+pub const DEFAULT_LIMIT_RANGE_NAME: &str = "default-limits";
+
+// Container defaults applied to pods that do not set their own requests or limits
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DefaultResourceLimits {
+    pub cpu_default_request: String,
+    pub cpu_default_limit: String,
+    pub memory_default_request: String,
+    pub memory_default_limit: String,
+    pub cpu_max: Option<String>,
+    pub memory_max: Option<String>,
+}
+
+impl DefaultResourceLimits {
+    fn to_limit_range(&self, namespace: &str) -> LimitRange {
+        let quantities = |cpu: &str, memory: &str| {
+            BTreeMap::from([
+                ("cpu".to_string(), Quantity(cpu.to_string())),
+                ("memory".to_string(), Quantity(memory.to_string())),
+            ])
+        };
+        let mut max = BTreeMap::new();
+        if let Some(cpu) = &self.cpu_max {
+            max.insert("cpu".to_string(), Quantity(cpu.clone()));
+        }
+        if let Some(memory) = &self.memory_max {
+            max.insert("memory".to_string(), Quantity(memory.clone()));
+        }
+
+        LimitRange {
+            metadata: ObjectMeta {
+                name: Some(DEFAULT_LIMIT_RANGE_NAME.to_string()),
+                namespace: Some(namespace.to_string()),
+                ..Default::default()
+            },
+            spec: Some(LimitRangeSpec {
+                limits: vec![LimitRangeItem {
+                    type_: "Container".to_string(),
+                    default: Some(quantities(
+                        &self.cpu_default_limit,
+                        &self.memory_default_limit,
+                    )),
+                    default_request: Some(quantities(
+                        &self.cpu_default_request,
+                        &self.memory_default_request,
+                    )),
+                    max: (!max.is_empty()).then_some(max),
+                    ..Default::default()
+                }],
+            }),
+        }
+    }
+}
+
+pub struct LimitRangesService {
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+}
 
 impl LimitRangesService {
     pub fn new() -> Self {
-        Self
+        Self {
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
     }
 
     async fn api(
@@ -39,19 +110,24 @@ impl LimitRangesService {
         })
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "list", resource_type = "LimitRange"))]
     pub async fn list(
         &self,
         cluster: &KubernetesClusterConfig,
         namespace: &str,
-    ) -> Result<Vec<LimitRange>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<LimitRange>, AppError> {
         let api = Self::api(cluster, namespace).await?;
+        let page = PageRequest::new(page_size, continue_token);
         let list = api
-            .list(&ListParams::default())
+            .list(&page.list_params(ListParams::default()))
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
-        Ok(list.items)
+        Ok(page.finish(list.metadata, list.items))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "get", resource_type = "LimitRange"))]
     pub async fn get(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -65,6 +141,7 @@ impl LimitRangesService {
             .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "upsert", resource_type = "LimitRange"))]
     pub async fn upsert(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -86,17 +163,36 @@ impl LimitRangesService {
         .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "delete", resource_type = "LimitRange"))]
     pub async fn delete(
         &self,
         cluster: &KubernetesClusterConfig,
         namespace: &str,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let api: Api<LimitRange> =
             Api::namespaced(ClientFactory::get_client(cluster).await?, namespace);
         api.delete(name, &DeleteParams::default())
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::DELETE, "LimitRange", name)
+                .with_namespace(namespace),
+        )
+        .await;
         Ok(())
     }
+    // Creates or updates the `default-limits` LimitRange of the namespace
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "apply_default_limit_range", resource_type = "LimitRange"))]
+    pub async fn apply_default_limit_range(
+        &self,
+        cluster: &KubernetesClusterConfig,
+        namespace: &str,
+        defaults: DefaultResourceLimits,
+    ) -> Result<LimitRange, AppError> {
+        self.upsert(cluster, namespace, &defaults.to_limit_range(namespace))
+            .await
+    }
 }
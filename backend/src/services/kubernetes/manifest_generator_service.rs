@@ -0,0 +1,266 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use std::sync::Arc;
+
+use k8s_openapi::api::apps::v1::Deployment;
+use kube::api::PostParams;
+use kube::{Api, Client};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, instrument};
+
+use crate::errors::AppError;
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::client::ClientFactory;
+use crate::services::llm::interface::LlmRequestBuilder;
+use crate::services::llm::manager::UnifiedLlmManager;
+
+const MAX_DESCRIPTION_LEN: usize = 4000;
+
+const DEPLOYMENT_SYSTEM_PROMPT: &str = "You are a Kubernetes expert. Produce a single valid \
+Kubernetes Deployment manifest (apiVersion: apps/v1, kind: Deployment) in YAML that matches the \
+user's description. Always include metadata.name, spec.selector.matchLabels, matching pod \
+template labels, resource requests and limits, and readiness/liveness probes where sensible. \
+Respond with the YAML only, without explanations.";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedManifest {
+    pub yaml: String,
+    pub resource_type: String,
+    pub validation_passed: bool,
+    pub warnings: Vec<String>,
+}
+
+pub struct ManifestGeneratorService {
+    llm_manager: Arc<UnifiedLlmManager>,
+}
+
+impl ManifestGeneratorService {
+    pub fn new(llm_manager: Arc<UnifiedLlmManager>) -> Self {
+        Self { llm_manager }
+    }
+
+    async fn get_kube_client(cluster_config: &KubernetesClusterConfig) -> Result<Client, AppError> {
+        ClientFactory::get_client(cluster_config).await
+    }
+
+    /// Ask the LLM for a Deployment manifest matching `description` and validate the result.
+    pub async fn generate_deployment(&self, description: &str) -> Result<GeneratedManifest, AppError> {
+        let description = description.trim();
+        if description.is_empty() {
+            return Err(AppError::BadRequest(
+                "Manifest description must not be empty".to_string(),
+            ));
+        }
+        if description.len() > MAX_DESCRIPTION_LEN {
+            return Err(AppError::BadRequest(format!(
+                "Manifest description too long (max {} chars)",
+                MAX_DESCRIPTION_LEN
+            )));
+        }
+
+        let request = LlmRequestBuilder::new()
+            .system_prompt(DEPLOYMENT_SYSTEM_PROMPT)
+            .prompt(format!("Description:\n{}", description))
+            .temperature(0.2)
+            .build();
+
+        let response = self.llm_manager.generate_smart(request).await?;
+        debug!(
+            target: "mayyam::k8s::manifest_generator",
+            provider = %response.provider_info.name,
+            "Received manifest generation response"
+        );
+
+        let yaml = extract_yaml(&response.response.content);
+        Ok(validate_deployment_yaml(&yaml))
+    }
+
+    /// Submit the manifest to the API server with `dryRun=All` so admission and schema
+    /// validation run without persisting anything.
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "dry_apply_deployment", resource_type = "Deployment"))]
+    pub async fn dry_apply_deployment(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        manifest: &mut GeneratedManifest,
+    ) -> Result<(), AppError> {
+        let deployment: Deployment = serde_yaml::from_str(&manifest.yaml).map_err(|e| {
+            AppError::Validation(format!("Generated manifest is not a valid Deployment: {}", e))
+        })?;
+
+        let client = Self::get_kube_client(cluster_config).await?;
+        let api: Api<Deployment> = Api::namespaced(client, namespace);
+        let pp = PostParams {
+            dry_run: true,
+            ..Default::default()
+        };
+
+        match api.create(&pp, &deployment).await {
+            Ok(_) => {
+                info!(
+                    target: "mayyam::k8s::manifest_generator",
+                    %namespace,
+                    "Generated deployment passed server-side dry run"
+                );
+            }
+            Err(e) => {
+                manifest.validation_passed = false;
+                manifest
+                    .warnings
+                    .push(format!("Server-side dry run rejected the manifest: {}", e));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Pull the YAML body out of an LLM response, dropping surrounding prose and markdown fences.
+pub fn extract_yaml(content: &str) -> String {
+    let trimmed = content.trim();
+    if let Some(start) = trimmed.find("```") {
+        let after_fence = &trimmed[start + 3..];
+        // Skip the optional language tag on the opening fence
+        let body_start = after_fence.find('\n').map(|i| i + 1).unwrap_or(0);
+        let body = &after_fence[body_start..];
+        let body = match body.find("```") {
+            Some(end) => &body[..end],
+            None => body,
+        };
+        return body.trim().to_string();
+    }
+    trimmed.to_string()
+}
+
+/// Validate a Deployment manifest by round-tripping it through the k8s-openapi types.
+pub fn validate_deployment_yaml(yaml: &str) -> GeneratedManifest {
+    let mut warnings = Vec::new();
+
+    let value: serde_yaml::Value = match serde_yaml::from_str(yaml) {
+        Ok(v) => v,
+        Err(e) => {
+            warnings.push(format!("Response is not valid YAML: {}", e));
+            return GeneratedManifest {
+                yaml: yaml.to_string(),
+                resource_type: "Unknown".to_string(),
+                validation_passed: false,
+                warnings,
+            };
+        }
+    };
+
+    let resource_type = value
+        .get("kind")
+        .and_then(|k| k.as_str())
+        .unwrap_or("Unknown")
+        .to_string();
+
+    if resource_type != "Deployment" {
+        warnings.push(format!(
+            "Expected kind 'Deployment' but got '{}'",
+            resource_type
+        ));
+        return GeneratedManifest {
+            yaml: yaml.to_string(),
+            resource_type,
+            validation_passed: false,
+            warnings,
+        };
+    }
+
+    let deployment: Deployment = match serde_yaml::from_value(value) {
+        Ok(d) => d,
+        Err(e) => {
+            warnings.push(format!("Manifest does not match the Deployment schema: {}", e));
+            return GeneratedManifest {
+                yaml: yaml.to_string(),
+                resource_type,
+                validation_passed: false,
+                warnings,
+            };
+        }
+    };
+
+    let mut validation_passed = true;
+    if deployment.metadata.name.is_none() {
+        validation_passed = false;
+        warnings.push("metadata.name is missing".to_string());
+    }
+
+    match deployment.spec.as_ref() {
+        None => {
+            validation_passed = false;
+            warnings.push("spec is missing".to_string());
+        }
+        Some(spec) => {
+            let selector = spec.selector.match_labels.clone().unwrap_or_default();
+            let template_labels = spec
+                .template
+                .metadata
+                .as_ref()
+                .and_then(|m| m.labels.clone())
+                .unwrap_or_default();
+            let selector_matches = !selector.is_empty()
+                && selector
+                    .iter()
+                    .all(|(k, v)| template_labels.get(k) == Some(v));
+            if !selector_matches {
+                validation_passed = false;
+                warnings.push(
+                    "spec.selector.matchLabels does not match the pod template labels".to_string(),
+                );
+            }
+
+            let containers = spec
+                .template
+                .spec
+                .as_ref()
+                .map(|s| s.containers.clone())
+                .unwrap_or_default();
+            if containers.is_empty() {
+                validation_passed = false;
+                warnings.push("Pod template has no containers".to_string());
+            }
+            for c in &containers {
+                match c.image.as_deref() {
+                    None => warnings.push(format!("Container '{}' has no image", c.name)),
+                    Some(image) if image.ends_with(":latest") || !image.contains(':') => {
+                        warnings.push(format!(
+                            "Container '{}' uses an unpinned image tag ('{}')",
+                            c.name, image
+                        ));
+                    }
+                    _ => {}
+                }
+                if c.resources.is_none() {
+                    warnings.push(format!(
+                        "Container '{}' has no resource requests or limits",
+                        c.name
+                    ));
+                }
+                if c.readiness_probe.is_none() {
+                    warnings.push(format!("Container '{}' has no readiness probe", c.name));
+                }
+            }
+        }
+    }
+
+    GeneratedManifest {
+        yaml: yaml.to_string(),
+        resource_type,
+        validation_passed,
+        warnings,
+    }
+}
@@ -13,11 +13,14 @@
 // limitations under the License.
 
 
+use std::cmp::Reverse;
+
 use chrono::{DateTime, Utc};
 use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
-use kube::{api::ListParams, Api};
-use serde::Serialize;
-use tracing::{debug, error};
+use kube::api::{ApiResource, DynamicObject, GroupVersionKind};
+use kube::{api::ListParams, Api, ResourceExt};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, error, instrument};
 
 use crate::{
     errors::AppError, models::cluster::KubernetesClusterConfig,
@@ -101,6 +104,31 @@ impl ClusterMetricsOverview {
     }
 }
 
+#[derive(Debug, Serialize, Clone)]
+pub struct ContainerResourceUsage {
+    pub name: String,
+    pub cpu_usage_millicores: i64,
+    pub memory_usage_bytes: i64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PodResourceUsage {
+    pub pod_name: String,
+    pub namespace: String,
+    pub cpu_usage_millicores: i64,
+    pub memory_usage_bytes: i64,
+    pub containers: Vec<ContainerResourceUsage>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResourceSortKey {
+    Cpu,
+    Memory,
+}
+
+const METRICS_API_GROUP: &str = "metrics.k8s.io";
+
 pub struct MetricsService;
 
 impl MetricsService {
@@ -108,6 +136,7 @@ impl MetricsService {
         Self
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = namespace.unwrap_or_default(), operation = "get_cluster_metrics", resource_type = "NodeMetrics"))]
     pub async fn get_cluster_metrics(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -205,6 +234,79 @@ impl MetricsService {
         Ok(metrics)
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "get_pod_resource_usage", resource_type = "PodMetrics"))]
+    pub async fn get_pod_resource_usage(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        pod_name: &str,
+    ) -> Result<PodResourceUsage, AppError> {
+        let client = ClientFactory::get_client(cluster_config).await?;
+        let api = pod_metrics_api(client.clone(), Some(namespace));
+        match api.get(pod_name).await {
+            Ok(obj) => Ok(pod_usage_from_metrics(&obj)),
+            Err(e) => {
+                Self::ensure_metrics_api(&client).await?;
+                Err(match e {
+                    kube::Error::Api(ae) if ae.code == 404 => AppError::NotFound(format!(
+                        "No metrics for pod {}/{} (pod missing or not yet scraped)",
+                        namespace, pod_name
+                    )),
+                    other => AppError::Kubernetes(other.to_string()),
+                })
+            }
+        }
+    }
+
+    /// Pods in `namespace` (or all namespaces for "" / "all") ordered by descending usage.
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "get_top_pods", resource_type = "PodMetrics"))]
+    pub async fn get_top_pods(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        sort_by: ResourceSortKey,
+        limit: usize,
+    ) -> Result<Vec<PodResourceUsage>, AppError> {
+        let client = ClientFactory::get_client(cluster_config).await?;
+        let namespace = if namespace.is_empty() || namespace == "all" {
+            None
+        } else {
+            Some(namespace)
+        };
+        let api = pod_metrics_api(client.clone(), namespace);
+        let list = match api.list(&ListParams::default()).await {
+            Ok(list) => list,
+            Err(e) => {
+                Self::ensure_metrics_api(&client).await?;
+                return Err(AppError::Kubernetes(e.to_string()));
+            }
+        };
+
+        let mut pods: Vec<PodResourceUsage> =
+            list.items.iter().map(pod_usage_from_metrics).collect();
+        match sort_by {
+            ResourceSortKey::Cpu => pods.sort_by_key(|p| Reverse(p.cpu_usage_millicores)),
+            ResourceSortKey::Memory => pods.sort_by_key(|p| Reverse(p.memory_usage_bytes)),
+        }
+        pods.truncate(limit);
+        Ok(pods)
+    }
+
+    // Distinguishes "metrics-server missing" from ordinary API errors
+    async fn ensure_metrics_api(client: &kube::Client) -> Result<(), AppError> {
+        let groups = client
+            .list_api_groups()
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        if groups.groups.iter().any(|g| g.name == METRICS_API_GROUP) {
+            Ok(())
+        } else {
+            Err(AppError::ExternalService(
+                "metrics.k8s.io API is not available; is metrics-server installed?".to_string(),
+            ))
+        }
+    }
+
     async fn collect_node_metrics(
         &self,
         _client: &kube::Client,
@@ -223,7 +325,60 @@ impl MetricsService {
     }
 }
 
-fn parse_cpu_quantity(quantity: &Quantity) -> Option<f64> {
+fn pod_metrics_api(client: kube::Client, namespace: Option<&str>) -> Api<DynamicObject> {
+    let gvk = GroupVersionKind::gvk(METRICS_API_GROUP, "v1beta1", "PodMetrics");
+    let resource = ApiResource::from_gvk_with_plural(&gvk, "pods");
+    match namespace {
+        Some(ns) => Api::namespaced_with(client, ns, &resource),
+        None => Api::all_with(client, &resource),
+    }
+}
+
+fn pod_usage_from_metrics(obj: &DynamicObject) -> PodResourceUsage {
+    let containers: Vec<ContainerResourceUsage> = obj
+        .data
+        .get("containers")
+        .and_then(|c| c.as_array())
+        .map(|containers| {
+            containers
+                .iter()
+                .map(|c| {
+                    let usage_of = |key: &str| {
+                        c.get("usage")
+                            .and_then(|u| u.get(key))
+                            .and_then(|v| v.as_str())
+                            .map(|s| Quantity(s.to_string()))
+                    };
+                    ContainerResourceUsage {
+                        name: c
+                            .get("name")
+                            .and_then(|n| n.as_str())
+                            .unwrap_or_default()
+                            .to_string(),
+                        cpu_usage_millicores: usage_of("cpu")
+                            .and_then(|q| parse_cpu_quantity(&q))
+                            .map(|cores| (cores * 1000.0).round() as i64)
+                            .unwrap_or(0),
+                        memory_usage_bytes: usage_of("memory")
+                            .and_then(|q| parse_memory_quantity(&q))
+                            .map(|bytes| bytes as i64)
+                            .unwrap_or(0),
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    PodResourceUsage {
+        pod_name: obj.name_any(),
+        namespace: obj.namespace().unwrap_or_default(),
+        cpu_usage_millicores: containers.iter().map(|c| c.cpu_usage_millicores).sum(),
+        memory_usage_bytes: containers.iter().map(|c| c.memory_usage_bytes).sum(),
+        containers,
+    }
+}
+
+pub(crate) fn parse_cpu_quantity(quantity: &Quantity) -> Option<f64> {
     let raw = quantity.0.as_str().trim();
     if raw.is_empty() {
         return None;
@@ -239,7 +394,7 @@ fn parse_cpu_quantity(quantity: &Quantity) -> Option<f64> {
     }
 }
 
-fn parse_memory_quantity(quantity: &Quantity) -> Option<f64> {
+pub(crate) fn parse_memory_quantity(quantity: &Quantity) -> Option<f64> {
     parse_resource_quantity(quantity.0.as_str())
 }
 
@@ -20,6 +20,7 @@ pub mod deployments_service;
 pub mod metrics_service;
 pub mod namespaces_service;
 pub mod nodes_service;
+pub mod pagination;
 pub mod persistent_volume_claims_service;
 pub mod persistent_volumes_service;
 pub mod pod; // Changed from pod_service
@@ -28,45 +29,75 @@ pub mod services_service;
 pub mod stateful_sets_service;
 
 // Phase 2 services
+pub mod admission_simulator;
+pub mod affinity_analyzer;
+pub mod alerting;
 pub mod authz_service;
+pub mod canary_analyzer;
+pub mod cluster_version_service;
 pub mod cronjobs_service;
+pub mod dependency_graph_service;
 pub mod endpoints_service;
+pub mod garbage_collection_service;
 pub mod hpa_service;
 pub mod ingress_service;
 pub mod jobs_service;
+pub mod label_service;
 pub mod limit_ranges_service;
+pub mod manifest_generator_service;
+pub mod multi_cluster_aggregator;
 pub mod network_policies_service;
 pub mod nodes_ops_service;
 pub mod pdb_service;
+pub mod pod_status_broadcaster;
+pub mod prometheus_rule_service;
 pub mod rbac_service;
+pub mod scheduling_analyzer;
+pub mod secret_rotation_service;
 pub mod resource_quotas_service;
 pub mod service_accounts_service;
 pub mod replica_sets_service;
 pub mod storage_classes_service;
+pub mod workload_cost_estimator;
 pub mod crds_service;
 
 pub mod prelude {
+    pub use super::admission_simulator::AdmissionWebhookSimulator;
+    pub use super::affinity_analyzer::AffinityAnalyzer;
+    pub use super::alerting::AlertingService;
     pub use super::authz_service::AuthorizationService;
+    pub use super::canary_analyzer::DeploymentCanaryAnalyzer;
+    pub use super::cluster_version_service::ClusterVersionService;
     pub use super::cronjobs_service::CronJobsService;
     pub use super::daemon_sets::DaemonSetsService;
+    pub use super::dependency_graph_service::DependencyGraphService;
     pub use super::deployments_service::DeploymentsService;
     pub use super::endpoints_service::EndpointsService;
+    pub use super::garbage_collection_service::GarbageCollectionService;
     pub use super::hpa_service::HorizontalPodAutoscalerService;
     pub use super::ingress_service::IngressService;
     pub use super::jobs_service::JobsService;
+    pub use super::label_service::LabelService;
     pub use super::limit_ranges_service::LimitRangesService;
+    pub use super::manifest_generator_service::ManifestGeneratorService;
     pub use super::metrics_service::MetricsService;
+    pub use super::multi_cluster_aggregator::MultiClusterAggregator;
     pub use super::namespaces_service::NamespacesService;
     pub use super::network_policies_service::NetworkPoliciesService;
     pub use super::nodes_ops_service::NodeOpsService;
     pub use super::nodes_service::NodesService;
+    pub use super::pagination::{PaginatedResponse, PaginationQuery};
     pub use super::pdb_service::PodDisruptionBudgetsService;
     pub use super::persistent_volume_claims_service::PersistentVolumeClaimsService;
     pub use super::persistent_volumes_service::PersistentVolumesService;
     pub use super::pod::PodService; // Changed from pod_service
+    pub use super::prometheus_rule_service::PrometheusRuleService;
     pub use super::rbac_service::RbacService;
     pub use super::resource_quotas_service::ResourceQuotasService;
+    pub use super::scheduling_analyzer::SchedulingAnalyzer;
+    pub use super::secret_rotation_service::SecretRotationService;
     pub use super::service_accounts_service::ServiceAccountsService;
     pub use super::services_service::ServicesService;
     pub use super::stateful_sets_service::StatefulSetsService;
+    pub use super::workload_cost_estimator::WorkloadCostEstimator;
 }
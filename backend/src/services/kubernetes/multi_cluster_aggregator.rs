@@ -0,0 +1,181 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::AppError;
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::client::ClientFactory;
+use crate::services::kubernetes::deployments_service::DeploymentsService;
+use crate::services::kubernetes::nodes_service::NodesService;
+use crate::services::kubernetes::pagination::{PaginatedResponse, MAX_PAGE_SIZE};
+use crate::services::kubernetes::pod::{PodInfo, PodService};
+use serde::Serialize;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::task::JoinSet;
+use tracing::instrument;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ClusterSummary {
+    pub cluster_name: String,
+    pub total_pods: usize,
+    pub total_deployments: usize,
+    pub total_nodes: usize,
+    pub unhealthy_pods: usize,
+    // Empty when the version could not be fetched
+    pub cluster_version: String,
+    // One entry per failed call; the matching counts are left at zero
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MultiClusterSummary {
+    pub clusters: Vec<ClusterSummary>,
+}
+
+pub struct MultiClusterAggregator {
+    pod_service: Arc<PodService>,
+    deployments_service: Arc<DeploymentsService>,
+    nodes_service: Arc<NodesService>,
+}
+
+impl MultiClusterAggregator {
+    pub fn new(
+        pod_service: Arc<PodService>,
+        deployments_service: Arc<DeploymentsService>,
+        nodes_service: Arc<NodesService>,
+    ) -> Self {
+        Self {
+            pod_service,
+            deployments_service,
+            nodes_service,
+        }
+    }
+
+    // Summaries are returned in the order of `cluster_configs`. A failing cluster only
+    // records its errors; the others are still summarised.
+    #[instrument(skip_all, fields(cluster_count = cluster_configs.len(), operation = "aggregate_resource_counts"))]
+    pub async fn aggregate_resource_counts(
+        &self,
+        cluster_configs: Vec<KubernetesClusterConfig>,
+    ) -> Result<MultiClusterSummary, AppError> {
+        let mut tasks = JoinSet::new();
+        for (index, cluster) in cluster_configs.into_iter().enumerate() {
+            let pod_service = self.pod_service.clone();
+            let deployments_service = self.deployments_service.clone();
+            let nodes_service = self.nodes_service.clone();
+            tasks.spawn(async move {
+                let summary =
+                    summarize_cluster(&cluster, &pod_service, &deployments_service, &nodes_service)
+                        .await;
+                (index, summary)
+            });
+        }
+
+        let mut summaries = Vec::with_capacity(tasks.len());
+        while let Some(joined) = tasks.join_next().await {
+            let (index, summary) = joined
+                .map_err(|e| AppError::Internal(format!("Cluster summary task failed: {}", e)))?;
+            summaries.push((index, summary));
+        }
+        summaries.sort_by_key(|(index, _)| *index);
+
+        Ok(MultiClusterSummary {
+            clusters: summaries.into_iter().map(|(_, summary)| summary).collect(),
+        })
+    }
+}
+
+async fn summarize_cluster(
+    cluster: &KubernetesClusterConfig,
+    pod_service: &PodService,
+    deployments_service: &DeploymentsService,
+    nodes_service: &NodesService,
+) -> ClusterSummary {
+    let (pods, deployments, nodes, version) = tokio::join!(
+        list_all_pages(move |token| {
+            pod_service.list_pods(cluster, "all", Some(MAX_PAGE_SIZE), token)
+        }),
+        list_all_pages(move |token| {
+            deployments_service.list_deployments(cluster, "all", Some(MAX_PAGE_SIZE), token)
+        }),
+        list_all_pages(move |token| nodes_service.list_nodes(cluster, Some(MAX_PAGE_SIZE), token)),
+        cluster_version(cluster),
+    );
+
+    let mut summary = ClusterSummary {
+        cluster_name: cluster.trace_name().to_string(),
+        total_pods: 0,
+        total_deployments: 0,
+        total_nodes: 0,
+        unhealthy_pods: 0,
+        cluster_version: String::new(),
+        errors: Vec::new(),
+    };
+    match pods {
+        Ok(pods) => {
+            summary.total_pods = pods.len();
+            summary.unhealthy_pods = pods.iter().filter(|p| is_unhealthy(p)).count();
+        }
+        Err(e) => summary.errors.push(format!("pods: {}", e)),
+    }
+    match deployments {
+        Ok(deployments) => summary.total_deployments = deployments.len(),
+        Err(e) => summary.errors.push(format!("deployments: {}", e)),
+    }
+    match nodes {
+        Ok(nodes) => summary.total_nodes = nodes.len(),
+        Err(e) => summary.errors.push(format!("nodes: {}", e)),
+    }
+    match version {
+        Ok(version) => summary.cluster_version = version,
+        Err(e) => summary.errors.push(format!("version: {}", e)),
+    }
+    summary
+}
+
+// Follows continue tokens until the list is exhausted
+async fn list_all_pages<T, F, Fut>(mut fetch: F) -> Result<Vec<T>, AppError>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<PaginatedResponse<T>, AppError>>,
+{
+    let mut items = Vec::new();
+    let mut token = None;
+    loop {
+        let page = fetch(token).await?;
+        items.extend(page.items);
+        match page.continue_token {
+            Some(next) => token = Some(next),
+            None => return Ok(items),
+        }
+    }
+}
+
+async fn cluster_version(cluster: &KubernetesClusterConfig) -> Result<String, AppError> {
+    let client = ClientFactory::get_client(cluster).await?;
+    let info = client
+        .apiserver_version()
+        .await
+        .map_err(|e| AppError::Kubernetes(format!("Failed to get server version: {}", e)))?;
+    Ok(info.git_version)
+}
+
+// Completed pods are healthy; running pods must have every container ready
+fn is_unhealthy(pod: &PodInfo) -> bool {
+    match pod.status.as_str() {
+        "Succeeded" => false,
+        "Running" => pod.containers.iter().any(|c| !c.ready),
+        _ => true,
+    }
+}
@@ -22,6 +22,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::errors::AppError;
 use crate::models::cluster::KubernetesClusterConfig;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
+use crate::services::kubernetes::limit_ranges_service::{DefaultResourceLimits, LimitRangesService};
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
+use std::sync::Arc;
+use tracing::instrument;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct NamespaceInfo {
@@ -30,28 +36,42 @@ pub struct NamespaceInfo {
     pub age: String,
 }
 
-pub struct NamespacesService;
+pub struct NamespacesService {
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+}
 
 impl NamespacesService {
     pub fn new() -> Self {
-        NamespacesService {}
+        Self {
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
     }
 
     async fn get_kube_client(cluster_config: &KubernetesClusterConfig) -> Result<Client, AppError> {
         ClientFactory::get_client(cluster_config).await
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "list_namespaces", resource_type = "Namespace"))]
     pub async fn list_namespaces(
         &self,
         cluster_config: &KubernetesClusterConfig,
-    ) -> Result<Vec<NamespaceInfo>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<NamespaceInfo>, AppError> {
+        let page = PageRequest::new(page_size, continue_token);
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<Namespace> = Api::all(client);
-        let lp = ListParams::default();
+        let lp = page.list_params(ListParams::default());
         let ns_list = api
             .list(&lp)
             .await
             .map_err(|e| AppError::ExternalService(format!("Failed to list namespaces: {}", e)))?;
+        let list_meta = ns_list.metadata.clone();
 
         let mut infos = Vec::new();
         for ns in ns_list {
@@ -81,9 +101,10 @@ impl NamespacesService {
 
             infos.push(NamespaceInfo { name, status, age });
         }
-        Ok(infos)
+        Ok(page.finish(list_meta, infos))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %name, operation = "get_namespace_details", resource_type = "Namespace"))]
     pub async fn get_namespace_details(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -96,6 +117,7 @@ impl NamespacesService {
         })
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %name, operation = "create_namespace", resource_type = "Namespace"))]
     pub async fn create_namespace(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -117,10 +139,43 @@ impl NamespacesService {
         })
     }
 
+    // Kubernetes has no multi-object transactions, so a namespace whose LimitRange cannot
+    // be applied is deleted again rather than left without limits
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %name, operation = "create_namespace_with_defaults", resource_type = "Namespace"))]
+    pub async fn create_namespace_with_defaults(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        name: &str,
+        labels: Option<std::collections::BTreeMap<String, String>>,
+        default_limits: Option<DefaultResourceLimits>,
+    ) -> Result<Namespace, AppError> {
+        let ns = self.create_namespace(cluster_config, name, labels).await?;
+        let Some(default_limits) = default_limits else {
+            return Ok(ns);
+        };
+
+        if let Err(e) = LimitRangesService::new()
+            .apply_default_limit_range(cluster_config, name, default_limits)
+            .await
+        {
+            let api: Api<Namespace> = Api::all(Self::get_kube_client(cluster_config).await?);
+            if let Err(delete_err) = api.delete(name, &DeleteParams::default()).await {
+                return Err(AppError::ExternalService(format!(
+                    "Failed to apply default limits to namespace '{}' ({}) and to roll it back: {}",
+                    name, e, delete_err
+                )));
+            }
+            return Err(e);
+        }
+        Ok(ns)
+    }
+
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %name, operation = "delete_namespace", resource_type = "Namespace"))]
     pub async fn delete_namespace(
         &self,
         cluster_config: &KubernetesClusterConfig,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<Namespace> = Api::all(client);
@@ -129,6 +184,11 @@ impl NamespacesService {
             .map_err(|e| {
                 AppError::ExternalService(format!("Failed to delete namespace '{}': {}", name, e))
             })?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::DELETE, "Namespace", name),
+        )
+        .await;
         Ok(())
     }
 }
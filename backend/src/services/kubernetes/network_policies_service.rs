@@ -14,17 +14,43 @@
 
 
 use crate::errors::AppError;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
 use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
 use crate::services::kubernetes::client::ClientFactory;
-use k8s_openapi::api::networking::v1::NetworkPolicy;
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
+use std::collections::{BTreeMap, HashSet};
+
+use k8s_openapi::api::core::v1::Namespace;
+use k8s_openapi::api::networking::v1::{
+    NetworkPolicy, NetworkPolicyEgressRule, NetworkPolicyIngressRule, NetworkPolicyPeer,
+    NetworkPolicySpec,
+};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta};
 use kube::api::{DeleteParams, ListParams, Patch, PatchParams};
-use kube::Api;
+use kube::{Api, ResourceExt};
+use std::sync::Arc;
+use tracing::instrument;
+
+const ISOLATION_POLICY_NAME: &str = "mayyam-namespace-isolation";
+const DEFAULT_DENY_POLICY_NAME: &str = "default-deny-all";
+// Set automatically on every namespace since Kubernetes 1.21
+const NAMESPACE_NAME_LABEL: &str = "kubernetes.io/metadata.name";
 
-pub struct NetworkPoliciesService;
+pub struct NetworkPoliciesService {
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+}
 
 impl NetworkPoliciesService {
     pub fn new() -> Self {
-        Self
+        Self {
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
     }
 
     async fn api(
@@ -39,19 +65,24 @@ impl NetworkPoliciesService {
         })
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "list", resource_type = "NetworkPolicy"))]
     pub async fn list(
         &self,
         cluster: &KubernetesClusterConfig,
         namespace: &str,
-    ) -> Result<Vec<NetworkPolicy>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<NetworkPolicy>, AppError> {
         let api = Self::api(cluster, namespace).await?;
+        let page = PageRequest::new(page_size, continue_token);
         let list = api
-            .list(&ListParams::default())
+            .list(&page.list_params(ListParams::default()))
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
-        Ok(list.items)
+        Ok(page.finish(list.metadata, list.items))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "get", resource_type = "NetworkPolicy"))]
     pub async fn get(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -65,6 +96,7 @@ impl NetworkPoliciesService {
             .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "upsert", resource_type = "NetworkPolicy"))]
     pub async fn upsert(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -85,17 +117,168 @@ impl NetworkPoliciesService {
         .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "delete", resource_type = "NetworkPolicy"))]
     pub async fn delete(
         &self,
         cluster: &KubernetesClusterConfig,
         namespace: &str,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let api: Api<NetworkPolicy> =
             Api::namespaced(ClientFactory::get_client(cluster).await?, namespace);
         api.delete(name, &DeleteParams::default())
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::DELETE, "NetworkPolicy", name)
+                .with_namespace(namespace),
+        )
+        .await;
         Ok(())
     }
+
+    /// Apply a policy that denies all ingress and egress for pods in `namespace` except
+    /// traffic from/to the listed namespaces. Egress rules must include kube-system if
+    /// pods need cluster DNS.
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "create_namespace_isolation_policy", resource_type = "NetworkPolicy"))]
+    pub async fn create_namespace_isolation_policy(
+        &self,
+        cluster: &KubernetesClusterConfig,
+        namespace: &str,
+        allow_ingress_from_namespaces: Vec<String>,
+        allow_egress_to_namespaces: Vec<String>,
+    ) -> Result<NetworkPolicy, AppError> {
+        let ingress = if allow_ingress_from_namespaces.is_empty() {
+            Vec::new()
+        } else {
+            vec![NetworkPolicyIngressRule {
+                from: Some(namespace_peers(&allow_ingress_from_namespaces)),
+                ..Default::default()
+            }]
+        };
+        let egress = if allow_egress_to_namespaces.is_empty() {
+            Vec::new()
+        } else {
+            vec![NetworkPolicyEgressRule {
+                to: Some(namespace_peers(&allow_egress_to_namespaces)),
+                ..Default::default()
+            }]
+        };
+
+        let policy = NetworkPolicy {
+            metadata: policy_metadata(ISOLATION_POLICY_NAME, namespace),
+            spec: Some(NetworkPolicySpec {
+                pod_selector: LabelSelector::default(),
+                policy_types: Some(vec!["Ingress".to_string(), "Egress".to_string()]),
+                ingress: Some(ingress),
+                egress: Some(egress),
+            }),
+            ..Default::default()
+        };
+        self.upsert(cluster, namespace, &policy).await
+    }
+
+    /// Apply the standard deny-all ingress policy to `namespace`.
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "create_default_deny_all", resource_type = "NetworkPolicy"))]
+    pub async fn create_default_deny_all(
+        &self,
+        cluster: &KubernetesClusterConfig,
+        namespace: &str,
+    ) -> Result<NetworkPolicy, AppError> {
+        let policy = NetworkPolicy {
+            metadata: policy_metadata(DEFAULT_DENY_POLICY_NAME, namespace),
+            spec: Some(NetworkPolicySpec {
+                pod_selector: LabelSelector::default(),
+                policy_types: Some(vec!["Ingress".to_string()]),
+                ingress: None,
+                egress: None,
+            }),
+            ..Default::default()
+        };
+        self.upsert(cluster, namespace, &policy).await
+    }
+
+    /// Namespaces without any policy that denies all ingress to every pod.
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), operation = "list_unenforced_namespaces", resource_type = "Namespace"))]
+    pub async fn list_unenforced_namespaces(
+        &self,
+        cluster: &KubernetesClusterConfig,
+    ) -> Result<Vec<String>, AppError> {
+        let client = ClientFactory::get_client(cluster).await?;
+        let namespaces: Api<Namespace> = Api::all(client.clone());
+        let policies: Api<NetworkPolicy> = Api::all(client);
+
+        let enforced: HashSet<String> = policies
+            .list(&ListParams::default())
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?
+            .items
+            .iter()
+            .filter(|p| is_default_deny_ingress(p))
+            .filter_map(|p| p.namespace())
+            .collect();
+
+        let mut unenforced: Vec<String> = namespaces
+            .list(&ListParams::default())
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?
+            .items
+            .iter()
+            .map(|ns| ns.name_any())
+            .filter(|name| !enforced.contains(name))
+            .collect();
+        unenforced.sort();
+        Ok(unenforced)
+    }
+}
+
+fn policy_metadata(name: &str, namespace: &str) -> ObjectMeta {
+    ObjectMeta {
+        name: Some(name.to_string()),
+        namespace: Some(namespace.to_string()),
+        labels: Some(BTreeMap::from([(
+            "app.kubernetes.io/managed-by".to_string(),
+            "mayyam".to_string(),
+        )])),
+        ..Default::default()
+    }
+}
+
+fn namespace_peers(namespaces: &[String]) -> Vec<NetworkPolicyPeer> {
+    namespaces
+        .iter()
+        .map(|ns| NetworkPolicyPeer {
+            namespace_selector: Some(LabelSelector {
+                match_labels: Some(BTreeMap::from([(
+                    NAMESPACE_NAME_LABEL.to_string(),
+                    ns.clone(),
+                )])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+        .collect()
+}
+
+// A default deny selects every pod, covers ingress, and allows nothing in
+pub fn is_default_deny_ingress(policy: &NetworkPolicy) -> bool {
+    let spec = match policy.spec.as_ref() {
+        Some(spec) => spec,
+        None => return false,
+    };
+    let selects_all = spec.pod_selector.match_labels.as_ref().map_or(true, |l| l.is_empty())
+        && spec
+            .pod_selector
+            .match_expressions
+            .as_ref()
+            .map_or(true, |e| e.is_empty());
+    // With no policyTypes, Ingress is always implied
+    let covers_ingress = spec
+        .policy_types
+        .as_ref()
+        .map_or(true, |types| types.iter().any(|t| t == "Ingress"));
+    let allows_nothing = spec.ingress.as_ref().map_or(true, |rules| rules.is_empty());
+    selects_all && covers_ingress && allows_nothing
 }
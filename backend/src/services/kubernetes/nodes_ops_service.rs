@@ -20,6 +20,7 @@ use k8s_openapi::api::core::v1::Node;
 use kube::api::{Patch, PatchParams};
 use kube::Api;
 use serde_json::json;
+use tracing::instrument;
 
 pub struct NodeOpsService;
 
@@ -33,6 +34,7 @@ impl NodeOpsService {
         Ok(Api::all(client))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), operation = "cordon", resource_type = "Node"))]
     pub async fn cordon(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -46,6 +48,7 @@ impl NodeOpsService {
             .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), operation = "uncordon", resource_type = "Node"))]
     pub async fn uncordon(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -59,6 +62,7 @@ impl NodeOpsService {
             .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), operation = "add_taint", resource_type = "Node"))]
     pub async fn add_taint(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -76,6 +80,7 @@ impl NodeOpsService {
             .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), operation = "remove_taint", resource_type = "Node"))]
     pub async fn remove_taint(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -14,15 +14,19 @@
 
 
 use chrono::Utc;
-use k8s_openapi::api::core::v1::Node;
-use kube::api::ListParams;
+use k8s_openapi::api::core::v1::{Node, Taint};
+use kube::api::{ListParams, Patch, PatchParams};
 use kube::config::{Config as KubeConfig, KubeConfigOptions, Kubeconfig};
 use kube::{Api, Client, ResourceExt};
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::collections::BTreeMap;
+use std::str::FromStr;
+use tracing::{info, instrument};
 
 use crate::errors::AppError;
 use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct NodeCondition {
@@ -49,6 +53,46 @@ pub struct NodeInfo {
     // pub conditions: Vec<NodeCondition>, // Can be too verbose for list view
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TaintEffect {
+    NoSchedule,
+    PreferNoSchedule,
+    NoExecute,
+}
+
+impl TaintEffect {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaintEffect::NoSchedule => "NoSchedule",
+            TaintEffect::PreferNoSchedule => "PreferNoSchedule",
+            TaintEffect::NoExecute => "NoExecute",
+        }
+    }
+}
+
+impl FromStr for TaintEffect {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NoSchedule" => Ok(TaintEffect::NoSchedule),
+            "PreferNoSchedule" => Ok(TaintEffect::PreferNoSchedule),
+            "NoExecute" => Ok(TaintEffect::NoExecute),
+            other => Err(format!(
+                "Invalid taint effect '{}', expected NoSchedule, PreferNoSchedule or NoExecute",
+                other
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeTaint {
+    pub key: String,
+    pub value: Option<String>,
+    pub effect: TaintEffect,
+}
+
 pub struct NodesService;
 
 impl NodesService {
@@ -135,17 +179,22 @@ impl NodesService {
         roles
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "list_nodes", resource_type = "Node"))]
     pub async fn list_nodes(
         &self,
         cluster_config: &KubernetesClusterConfig,
-    ) -> Result<Vec<NodeInfo>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<NodeInfo>, AppError> {
+        let page = PageRequest::new(page_size, continue_token);
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<Node> = Api::all(client);
-        let lp = ListParams::default();
+        let lp = page.list_params(ListParams::default());
         let node_list = api
             .list(&lp)
             .await
             .map_err(|e| AppError::ExternalService(format!("Failed to list nodes: {}", e)))?;
+        let list_meta = node_list.metadata.clone();
 
         let mut infos = Vec::new();
         for n in node_list {
@@ -208,9 +257,10 @@ impl NodesService {
                 container_runtime_version,
             });
         }
-        Ok(infos)
+        Ok(page.finish(list_meta, infos))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "get_node_details", resource_type = "Node"))]
     pub async fn get_node_details(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -222,4 +272,149 @@ impl NodesService {
             .await
             .map_err(|e| AppError::ExternalService(format!("Failed to get node '{}': {}", name, e)))
     }
+
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "add_node_label", resource_type = "Node", node_name = %node_name))]
+    pub async fn add_node_label(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        node_name: &str,
+        key: &str,
+        value: &str,
+    ) -> Result<Node, AppError> {
+        let api = Self::node_api(cluster_config).await?;
+        Self::get_existing_node(&api, node_name).await?;
+        let patch = json!({ "metadata": { "labels": { key: value } } });
+        let node = Self::merge_patch_node(&api, node_name, &patch).await?;
+        info!("Set label {}={} on node {}", key, value, node_name);
+        Ok(node)
+    }
+
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "remove_node_label", resource_type = "Node", node_name = %node_name))]
+    pub async fn remove_node_label(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        node_name: &str,
+        key: &str,
+    ) -> Result<Node, AppError> {
+        let api = Self::node_api(cluster_config).await?;
+        let node = Self::get_existing_node(&api, node_name).await?;
+        if !node.labels().contains_key(key) {
+            return Err(AppError::NotFound(format!(
+                "Node '{}' has no label '{}'",
+                node_name, key
+            )));
+        }
+        // A null value removes the key in a JSON merge patch
+        let patch = json!({ "metadata": { "labels": { key: null } } });
+        let node = Self::merge_patch_node(&api, node_name, &patch).await?;
+        info!("Removed label {} from node {}", key, node_name);
+        Ok(node)
+    }
+
+    // Replaces an existing taint with the same key and effect, like `kubectl taint --overwrite`
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "add_node_taint", resource_type = "Node", node_name = %node_name))]
+    pub async fn add_node_taint(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        node_name: &str,
+        taint: NodeTaint,
+    ) -> Result<Node, AppError> {
+        let api = Self::node_api(cluster_config).await?;
+        let node = Self::get_existing_node(&api, node_name).await?;
+
+        let mut taints: Vec<Taint> = node
+            .spec
+            .as_ref()
+            .and_then(|s| s.taints.clone())
+            .unwrap_or_default();
+        taints.retain(|t| !(t.key == taint.key && t.effect == taint.effect.as_str()));
+        taints.push(Taint {
+            key: taint.key.clone(),
+            value: taint.value.clone(),
+            effect: taint.effect.as_str().to_string(),
+            time_added: None,
+        });
+
+        let node = Self::patch_taints(&api, &node, &taints).await?;
+        info!(
+            "Added taint {}:{} to node {}",
+            taint.key,
+            taint.effect.as_str(),
+            node_name
+        );
+        Ok(node)
+    }
+
+    // Removes the taint for every effect, like `kubectl taint <node> <key>-`
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "remove_node_taint", resource_type = "Node", node_name = %node_name))]
+    pub async fn remove_node_taint(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        node_name: &str,
+        taint_key: &str,
+    ) -> Result<Node, AppError> {
+        let api = Self::node_api(cluster_config).await?;
+        let node = Self::get_existing_node(&api, node_name).await?;
+
+        let taints: Vec<Taint> = node
+            .spec
+            .as_ref()
+            .and_then(|s| s.taints.clone())
+            .unwrap_or_default();
+        let remaining: Vec<Taint> = taints
+            .iter()
+            .filter(|t| t.key != taint_key)
+            .cloned()
+            .collect();
+        if remaining.len() == taints.len() {
+            return Err(AppError::NotFound(format!(
+                "Node '{}' has no taint '{}'",
+                node_name, taint_key
+            )));
+        }
+
+        let node = Self::patch_taints(&api, &node, &remaining).await?;
+        info!("Removed taint {} from node {}", taint_key, node_name);
+        Ok(node)
+    }
+
+    async fn node_api(cluster_config: &KubernetesClusterConfig) -> Result<Api<Node>, AppError> {
+        let client = Self::get_kube_client(cluster_config).await?;
+        Ok(Api::all(client))
+    }
+
+    async fn get_existing_node(api: &Api<Node>, node_name: &str) -> Result<Node, AppError> {
+        api.get_opt(node_name)
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!("Failed to get node '{}': {}", node_name, e))
+            })?
+            .ok_or_else(|| AppError::NotFound(format!("Node '{}' not found", node_name)))
+    }
+
+    async fn merge_patch_node(
+        api: &Api<Node>,
+        node_name: &str,
+        patch: &serde_json::Value,
+    ) -> Result<Node, AppError> {
+        api.patch(node_name, &PatchParams::default(), &Patch::Merge(patch))
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!("Failed to patch node '{}': {}", node_name, e))
+            })
+    }
+
+    // A merge patch replaces the whole taint list, so the resourceVersion guards against
+    // overwriting a concurrent change
+    async fn patch_taints(
+        api: &Api<Node>,
+        node: &Node,
+        taints: &[Taint],
+    ) -> Result<Node, AppError> {
+        let patch = json!({
+            "metadata": { "resourceVersion": node.resource_version() },
+            "spec": { "taints": taints }
+        });
+        Self::merge_patch_node(api, &node.name_any(), &patch).await
+    }
 }
@@ -0,0 +1,103 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ListMeta;
+use kube::api::ListParams;
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_PAGE_SIZE: usize = 50;
+pub const MAX_PAGE_SIZE: usize = 1000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaginatedResponse<T> {
+    pub items: Vec<T>,
+    // Exact when the API server reports remainingItemCount; it omits it for
+    // label/field-selected lists, in which case this only counts pages seen so far
+    pub total: usize,
+    pub page: usize,
+    pub page_size: usize,
+    // Pass back to fetch the next page; None on the last page
+    pub continue_token: Option<String>,
+}
+
+// Query parameters accepted by the list endpoints
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PaginationQuery {
+    pub page_size: Option<usize>,
+    pub continue_token: Option<String>,
+}
+
+/// One page of a Kubernetes list call. The continue token handed to clients is the API
+/// server's token prefixed with the page number it leads to ("3:eyJ2Ijo..."), so page
+/// numbers survive the round trip without any server-side state.
+#[derive(Debug, Clone)]
+pub struct PageRequest {
+    page: usize,
+    page_size: usize,
+    continue_token: Option<String>,
+}
+
+impl PageRequest {
+    pub fn new(page_size: Option<usize>, continue_token: Option<String>) -> Self {
+        let page_size = page_size
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE);
+        let (page, continue_token) = match continue_token.filter(|t| !t.is_empty()) {
+            Some(token) => match token.split_once(':') {
+                Some((page, rest)) if page.parse::<usize>().is_ok() => {
+                    (page.parse().unwrap_or(1), Some(rest.to_string()))
+                }
+                // Raw API server token; the page number is unknown
+                _ => (0, Some(token)),
+            },
+            None => (1, None),
+        };
+        Self {
+            page,
+            page_size,
+            continue_token,
+        }
+    }
+
+    pub fn list_params(&self, lp: ListParams) -> ListParams {
+        let lp = lp.limit(self.page_size as u32);
+        match &self.continue_token {
+            Some(token) => lp.continue_token(token),
+            None => lp,
+        }
+    }
+
+    pub fn finish<T>(self, list_meta: ListMeta, items: Vec<T>) -> PaginatedResponse<T> {
+        let remaining = list_meta
+            .remaining_item_count
+            .map(|c| c.max(0) as usize)
+            .unwrap_or(0);
+        let total = self.page.saturating_sub(1) * self.page_size + items.len() + remaining;
+        let continue_token = list_meta.continue_.filter(|t| !t.is_empty()).map(|t| {
+            if self.page == 0 {
+                t
+            } else {
+                format!("{}:{}", self.page + 1, t)
+            }
+        });
+        PaginatedResponse {
+            items,
+            total,
+            page: self.page,
+            page_size: self.page_size,
+            continue_token,
+        }
+    }
+}
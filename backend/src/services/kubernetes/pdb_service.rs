@@ -14,17 +14,60 @@
 
 
 use crate::errors::AppError;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
 use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
 use crate::services::kubernetes::client::ClientFactory;
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
+use k8s_openapi::api::apps::v1::Deployment;
+use k8s_openapi::api::core::v1::Pod;
 use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
 use kube::api::{DeleteParams, ListParams, Patch, PatchParams};
-use kube::Api;
+use kube::{Api, ResourceExt};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use tracing::instrument;
 
-pub struct PodDisruptionBudgetsService;
+/// A budget that currently allows no voluntary disruptions
+#[derive(Debug, Clone, Serialize)]
+pub struct PdbViolation {
+    pub namespace: String,
+    pub pdb_name: String,
+    pub selector: Option<String>,
+    pub min_available: Option<String>,
+    pub current_healthy: i32,
+    pub desired_healthy: i32,
+    pub disruptions_allowed: i32,
+    pub blocking_deployments: Vec<String>,
+}
+
+/// A budget that draining a node would exceed
+#[derive(Debug, Clone, Serialize)]
+pub struct PdbDrainImpact {
+    pub namespace: String,
+    pub pdb_name: String,
+    pub pods_on_node: Vec<String>,
+    pub disruptions_allowed: i32,
+    pub excess_evictions: i32,
+}
+
+pub struct PodDisruptionBudgetsService {
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+}
 
 impl PodDisruptionBudgetsService {
     pub fn new() -> Self {
-        Self
+        Self {
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
     }
 
     async fn api(
@@ -39,19 +82,24 @@ impl PodDisruptionBudgetsService {
         })
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "list", resource_type = "PodDisruptionBudget"))]
     pub async fn list(
         &self,
         cluster: &KubernetesClusterConfig,
         namespace: &str,
-    ) -> Result<Vec<PodDisruptionBudget>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<PodDisruptionBudget>, AppError> {
         let api = Self::api(cluster, namespace).await?;
+        let page = PageRequest::new(page_size, continue_token);
         let list = api
-            .list(&ListParams::default())
+            .list(&page.list_params(ListParams::default()))
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
-        Ok(list.items)
+        Ok(page.finish(list.metadata, list.items))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "get", resource_type = "PodDisruptionBudget"))]
     pub async fn get(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -65,6 +113,7 @@ impl PodDisruptionBudgetsService {
             .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "upsert", resource_type = "PodDisruptionBudget"))]
     pub async fn upsert(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -85,17 +134,210 @@ impl PodDisruptionBudgetsService {
         .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "delete", resource_type = "PodDisruptionBudget"))]
     pub async fn delete(
         &self,
         cluster: &KubernetesClusterConfig,
         namespace: &str,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let api: Api<PodDisruptionBudget> =
             Api::namespaced(ClientFactory::get_client(cluster).await?, namespace);
         api.delete(name, &DeleteParams::default())
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(
+                audit_ctx,
+                AuditOperation::DELETE,
+                "PodDisruptionBudget",
+                name,
+            )
+            .with_namespace(namespace),
+        )
+        .await;
         Ok(())
     }
+
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "detect_violations", resource_type = "PodDisruptionBudget"))]
+    pub async fn detect_pdb_violations(
+        &self,
+        cluster: &KubernetesClusterConfig,
+        namespace: &str,
+    ) -> Result<Vec<PdbViolation>, AppError> {
+        let client = ClientFactory::get_client(cluster).await?;
+        let pdbs = Self::api(cluster, namespace)
+            .await?
+            .list(&ListParams::default())
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+
+        let mut deployments_by_ns: HashMap<String, Vec<Deployment>> = HashMap::new();
+        let mut violations = Vec::new();
+        for pdb in pdbs.items {
+            let Some(status) = pdb.status.as_ref() else {
+                continue;
+            };
+            if status.disruptions_allowed > 0 || status.current_healthy > status.desired_healthy {
+                continue;
+            }
+
+            let pdb_ns = pdb.namespace().unwrap_or_default();
+            if !deployments_by_ns.contains_key(&pdb_ns) {
+                let deployments = Api::<Deployment>::namespaced(client.clone(), &pdb_ns)
+                    .list(&ListParams::default())
+                    .await
+                    .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+                deployments_by_ns.insert(pdb_ns.clone(), deployments.items);
+            }
+
+            let spec = pdb.spec.as_ref();
+            let selector = spec.and_then(|s| s.selector.as_ref());
+            let blocking_deployments = deployments_by_ns[&pdb_ns]
+                .iter()
+                .filter(|d| {
+                    let labels = d
+                        .spec
+                        .as_ref()
+                        .and_then(|s| s.template.metadata.as_ref())
+                        .and_then(|m| m.labels.as_ref());
+                    selector.map_or(false, |sel| {
+                        selector_matches(sel, labels.unwrap_or(&BTreeMap::new()))
+                    })
+                })
+                .map(|d| d.name_any())
+                .collect();
+
+            violations.push(PdbViolation {
+                namespace: pdb_ns,
+                pdb_name: pdb.name_any(),
+                selector: selector.and_then(selector_to_string),
+                min_available: spec
+                    .and_then(|s| s.min_available.as_ref())
+                    .map(int_or_string),
+                current_healthy: status.current_healthy,
+                desired_healthy: status.desired_healthy,
+                disruptions_allowed: status.disruptions_allowed,
+                blocking_deployments,
+            });
+        }
+        Ok(violations)
+    }
+
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), node = %node_name, operation = "simulate_drain", resource_type = "PodDisruptionBudget"))]
+    pub async fn simulate_node_drain_pdb_impact(
+        &self,
+        cluster: &KubernetesClusterConfig,
+        node_name: &str,
+    ) -> Result<Vec<PdbDrainImpact>, AppError> {
+        let client = ClientFactory::get_client(cluster).await?;
+        let pods = Api::<Pod>::all(client.clone())
+            .list(&ListParams::default().fields(&format!("spec.nodeName={}", node_name)))
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        // Drain skips DaemonSet pods, and only healthy pods count against a budget
+        let evicted: Vec<Pod> = pods
+            .items
+            .into_iter()
+            .filter(|p| !p.owner_references().iter().any(|o| o.kind == "DaemonSet") && is_ready(p))
+            .collect();
+        if evicted.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let pdbs = Api::<PodDisruptionBudget>::all(client)
+            .list(&ListParams::default())
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+
+        let mut impacts = Vec::new();
+        for pdb in pdbs.items {
+            let Some(selector) = pdb.spec.as_ref().and_then(|s| s.selector.as_ref()) else {
+                continue;
+            };
+            let pdb_ns = pdb.namespace().unwrap_or_default();
+            let pods_on_node: Vec<String> = evicted
+                .iter()
+                .filter(|p| p.namespace().as_deref() == Some(pdb_ns.as_str()))
+                .filter(|p| selector_matches(selector, p.labels()))
+                .map(|p| p.name_any())
+                .collect();
+            let disruptions_allowed = pdb.status.as_ref().map_or(0, |s| s.disruptions_allowed);
+            let excess_evictions = pods_on_node.len() as i32 - disruptions_allowed;
+            if excess_evictions > 0 {
+                impacts.push(PdbDrainImpact {
+                    namespace: pdb_ns,
+                    pdb_name: pdb.name_any(),
+                    pods_on_node,
+                    disruptions_allowed,
+                    excess_evictions,
+                });
+            }
+        }
+        Ok(impacts)
+    }
+}
+
+// In policy/v1 an empty selector matches every pod in the namespace
+fn selector_matches(selector: &LabelSelector, labels: &BTreeMap<String, String>) -> bool {
+    let labels_match = selector
+        .match_labels
+        .iter()
+        .flatten()
+        .all(|(k, v)| labels.get(k) == Some(v));
+    let expressions_match = selector.match_expressions.iter().flatten().all(|expr| {
+        let values = expr.values.as_deref().unwrap_or_default();
+        let value = labels.get(&expr.key);
+        match expr.operator.as_str() {
+            "In" => value.map_or(false, |v| values.contains(v)),
+            "NotIn" => value.map_or(true, |v| !values.contains(v)),
+            "Exists" => value.is_some(),
+            "DoesNotExist" => value.is_none(),
+            _ => false,
+        }
+    });
+    labels_match && expressions_match
+}
+
+fn selector_to_string(selector: &LabelSelector) -> Option<String> {
+    let mut parts: Vec<String> = selector
+        .match_labels
+        .iter()
+        .flatten()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect();
+    for expr in selector.match_expressions.iter().flatten() {
+        let values = expr.values.as_deref().unwrap_or_default().join(",");
+        parts.push(match expr.operator.as_str() {
+            "In" => format!("{} in ({})", expr.key, values),
+            "NotIn" => format!("{} notin ({})", expr.key, values),
+            "DoesNotExist" => format!("!{}", expr.key),
+            _ => expr.key.clone(),
+        });
+    }
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(","))
+    }
+}
+
+fn int_or_string(value: &IntOrString) -> String {
+    match value {
+        IntOrString::Int(i) => i.to_string(),
+        IntOrString::String(s) => s.clone(),
+    }
+}
+
+fn is_ready(pod: &Pod) -> bool {
+    pod.status
+        .as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .map_or(false, |conditions| {
+            conditions
+                .iter()
+                .any(|c| c.type_ == "Ready" && c.status == "True")
+        })
 }
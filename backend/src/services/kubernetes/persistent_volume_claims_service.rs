@@ -20,9 +20,11 @@ use kube::api::ListParams;
 use kube::config::{Config as KubeConfig, KubeConfigOptions, Kubeconfig};
 use kube::{Api, Client, ResourceExt};
 use serde::{Deserialize, Serialize};
+use tracing::instrument;
 
 use crate::errors::AppError;
 use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PersistentVolumeClaimInfo {
@@ -78,20 +80,25 @@ impl PersistentVolumeClaimsService {
         })
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "list_persistent_volume_claims", resource_type = "PersistentVolumeClaim"))]
     pub async fn list_persistent_volume_claims(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
-    ) -> Result<Vec<PersistentVolumeClaimInfo>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<PersistentVolumeClaimInfo>, AppError> {
+        let page = PageRequest::new(page_size, continue_token);
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<PersistentVolumeClaim> = Api::namespaced(client, namespace);
-        let lp = ListParams::default();
+        let lp = page.list_params(ListParams::default());
         let pvc_list = api.list(&lp).await.map_err(|e| {
             AppError::ExternalService(format!(
                 "Failed to list PVCs in namespace '{}': {}",
                 namespace, e
             ))
         })?;
+        let list_meta = pvc_list.metadata.clone();
 
         let mut infos = Vec::new();
         for pvc in pvc_list {
@@ -150,9 +157,10 @@ impl PersistentVolumeClaimsService {
                 age,
             });
         }
-        Ok(infos)
+        Ok(page.finish(list_meta, infos))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "get_persistent_volume_claim_details", resource_type = "PersistentVolumeClaim"))]
     pub async fn get_persistent_volume_claim_details(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -14,15 +14,25 @@
 
 
 use chrono::Utc;
-use k8s_openapi::api::core::v1::PersistentVolume;
+use k8s_openapi::api::core::v1::{
+    PersistentVolume, PersistentVolumeClaim, PersistentVolumeClaimSpec, ResourceRequirements,
+};
 use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
-use kube::api::ListParams;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kube::api::{ListParams, Patch, PatchParams, PostParams};
 use kube::config::{Config as KubeConfig, KubeConfigOptions, Kubeconfig};
 use kube::{Api, Client, ResourceExt};
 use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::{info, instrument};
 
 use crate::errors::AppError;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
 use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PersistentVolumeInfo {
@@ -37,11 +47,37 @@ pub struct PersistentVolumeInfo {
     pub age: String,
 }
 
-pub struct PersistentVolumesService;
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum ReclaimPolicy {
+    Retain,
+    Recycle,
+    Delete,
+}
+
+impl ReclaimPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReclaimPolicy::Retain => "Retain",
+            ReclaimPolicy::Recycle => "Recycle",
+            ReclaimPolicy::Delete => "Delete",
+        }
+    }
+}
+
+pub struct PersistentVolumesService {
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+}
 
 impl PersistentVolumesService {
     pub fn new() -> Self {
-        PersistentVolumesService {}
+        PersistentVolumesService {
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
     }
 
     async fn get_kube_client(cluster_config: &KubernetesClusterConfig) -> Result<Client, AppError> {
@@ -79,93 +115,28 @@ impl PersistentVolumesService {
         })
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "list_persistent_volumes", resource_type = "PersistentVolume"))]
     pub async fn list_persistent_volumes(
         &self,
         cluster_config: &KubernetesClusterConfig,
-    ) -> Result<Vec<PersistentVolumeInfo>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<PersistentVolumeInfo>, AppError> {
+        let page = PageRequest::new(page_size, continue_token);
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<PersistentVolume> = Api::all(client);
-        let lp = ListParams::default();
+        let lp = page.list_params(ListParams::default());
         let pv_list = api
             .list(&lp)
             .await
             .map_err(|e| AppError::ExternalService(format!("Failed to list PVs: {}", e)))?;
+        let list_meta = pv_list.metadata.clone();
 
-        let mut infos = Vec::new();
-        for pv in pv_list {
-            let name = pv.name_any();
-            let spec = pv.spec.as_ref();
-            let status = pv.status.as_ref();
-
-            let capacity = spec
-                .and_then(|s| s.capacity.as_ref())
-                .and_then(|cap_map| cap_map.get("storage"))
-                .map(|q: &Quantity| q.0.clone());
-
-            let access_modes = spec
-                .and_then(|s| s.access_modes.as_ref())
-                .map_or_else(Vec::new, |modes| modes.clone());
-
-            let reclaim_policy = spec
-                .and_then(|s| s.persistent_volume_reclaim_policy.as_ref())
-                .cloned()
-                .unwrap_or_else(|| "Unknown".to_string());
-
-            let pv_status = status
-                .and_then(|s| s.phase.clone())
-                .unwrap_or_else(|| "Unknown".to_string());
-            let reason = status
-                .and_then(|s| s.reason.clone())
-                .unwrap_or_else(|| "".to_string());
-
-            let claim_ref = spec.and_then(|s| s.claim_ref.as_ref());
-            let claim = claim_ref.map_or_else(
-                || "-".to_string(),
-                |cr| {
-                    format!(
-                        "{}/{}",
-                        cr.namespace.as_deref().unwrap_or(""),
-                        cr.name.as_deref().unwrap_or("")
-                    )
-                },
-            );
-
-            let storage_class = spec
-                .and_then(|s| s.storage_class_name.clone())
-                .unwrap_or_else(|| "-".to_string());
-
-            let age = pv.metadata.creation_timestamp.as_ref().map_or_else(
-                || "Unknown".to_string(),
-                |ts| {
-                    let creation_time = ts.0;
-                    let duration = Utc::now().signed_duration_since(creation_time);
-                    if duration.num_days() > 0 {
-                        format!("{}d", duration.num_days())
-                    } else if duration.num_hours() > 0 {
-                        format!("{}h", duration.num_hours())
-                    } else if duration.num_minutes() > 0 {
-                        format!("{}m", duration.num_minutes())
-                    } else {
-                        format!("{}s", duration.num_seconds())
-                    }
-                },
-            );
-
-            infos.push(PersistentVolumeInfo {
-                name,
-                capacity,
-                access_modes,
-                reclaim_policy,
-                status: pv_status,
-                claim,
-                storage_class,
-                reason,
-                age,
-            });
-        }
-        Ok(infos)
+        let infos = pv_list.into_iter().map(|pv| pv_info(&pv)).collect();
+        Ok(page.finish(list_meta, infos))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "get_persistent_volume_details", resource_type = "PersistentVolume"))]
     pub async fn get_persistent_volume_details(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -177,4 +148,251 @@ impl PersistentVolumesService {
             .await
             .map_err(|e| AppError::ExternalService(format!("Failed to get PV '{}': {}", name, e)))
     }
+
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "set_reclaim_policy", resource_type = "PersistentVolume", pv_name = %pv_name))]
+    pub async fn set_reclaim_policy(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        pv_name: &str,
+        policy: ReclaimPolicy,
+        audit_ctx: &AuditContext,
+    ) -> Result<(), AppError> {
+        let client = Self::get_kube_client(cluster_config).await?;
+        let api: Api<PersistentVolume> = Api::all(client);
+        let patch = json!({
+            "spec": { "persistentVolumeReclaimPolicy": policy.as_str() }
+        });
+        api.patch(pv_name, &PatchParams::default(), &Patch::Merge(&patch))
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to set reclaim policy of PV '{}': {}",
+                    pv_name, e
+                ))
+            })?;
+        info!(
+            "Set reclaim policy of PV {} to {}",
+            pv_name,
+            policy.as_str()
+        );
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(
+                audit_ctx,
+                AuditOperation::UPDATE,
+                "PersistentVolume",
+                pv_name,
+            )
+            .with_payload(json!({ "reclaim_policy": policy.as_str() })),
+        )
+        .await;
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "list_released_pvs", resource_type = "PersistentVolume"))]
+    pub async fn list_released_pvs(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+    ) -> Result<Vec<PersistentVolumeInfo>, AppError> {
+        let client = Self::get_kube_client(cluster_config).await?;
+        let api: Api<PersistentVolume> = Api::all(client);
+        // status.phase is not a supported field selector for PVs
+        let pv_list = api
+            .list(&ListParams::default())
+            .await
+            .map_err(|e| AppError::ExternalService(format!("Failed to list PVs: {}", e)))?;
+
+        Ok(pv_list
+            .iter()
+            .filter(|pv| pv.status.as_ref().and_then(|s| s.phase.as_deref()) == Some("Released"))
+            .map(pv_info)
+            .collect())
+    }
+
+    // A Released PV keeps the claimRef of its deleted PVC and never binds again.
+    // The claimRef is pointed at the new PVC before it exists, without a uid, so the
+    // PV is reserved for that claim and no other PVC can bind it in between.
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "rebind_pv_to_pvc", resource_type = "PersistentVolume", pv_name = %pv_name, %namespace, %pvc_name))]
+    pub async fn rebind_pv_to_pvc(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        pv_name: &str,
+        pvc_name: &str,
+        namespace: &str,
+        audit_ctx: &AuditContext,
+    ) -> Result<PersistentVolumeClaim, AppError> {
+        let client = Self::get_kube_client(cluster_config).await?;
+        let pv_api: Api<PersistentVolume> = Api::all(client.clone());
+        let pvc_api: Api<PersistentVolumeClaim> = Api::namespaced(client, namespace);
+
+        let pv = pv_api.get(pv_name).await.map_err(|e| {
+            AppError::ExternalService(format!("Failed to get PV '{}': {}", pv_name, e))
+        })?;
+        let phase = pv
+            .status
+            .as_ref()
+            .and_then(|s| s.phase.clone())
+            .unwrap_or_default();
+        if phase != "Released" && phase != "Available" {
+            return Err(AppError::Conflict(format!(
+                "PV '{}' is {}, only Released or Available PVs can be rebound",
+                pv_name, phase
+            )));
+        }
+        if pvc_api
+            .get_opt(pvc_name)
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!("Failed to get PVC '{}': {}", pvc_name, e))
+            })?
+            .is_some()
+        {
+            return Err(AppError::Conflict(format!(
+                "PVC '{}' already exists in namespace '{}'",
+                pvc_name, namespace
+            )));
+        }
+
+        let spec = pv.spec.clone().unwrap_or_default();
+        let pvc = PersistentVolumeClaim {
+            metadata: ObjectMeta {
+                name: Some(pvc_name.to_string()),
+                namespace: Some(namespace.to_string()),
+                ..Default::default()
+            },
+            spec: Some(PersistentVolumeClaimSpec {
+                access_modes: spec.access_modes.clone(),
+                resources: spec.capacity.as_ref().and_then(|capacity| {
+                    capacity.get("storage").map(|storage| ResourceRequirements {
+                        requests: Some(BTreeMap::from([("storage".to_string(), storage.clone())])),
+                        ..Default::default()
+                    })
+                }),
+                // An empty class keeps the default StorageClass from being applied
+                storage_class_name: Some(spec.storage_class_name.clone().unwrap_or_default()),
+                volume_mode: spec.volume_mode.clone(),
+                volume_name: Some(pv_name.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        // uid and resourceVersion still belong to the old claim and must be dropped
+        let patch = json!({
+            "spec": {
+                "claimRef": {
+                    "apiVersion": "v1",
+                    "kind": "PersistentVolumeClaim",
+                    "namespace": namespace,
+                    "name": pvc_name,
+                    "uid": null,
+                    "resourceVersion": null,
+                }
+            }
+        });
+        pv_api
+            .patch(pv_name, &PatchParams::default(), &Patch::Merge(&patch))
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to reserve PV '{}' for the new claim: {}",
+                    pv_name, e
+                ))
+            })?;
+
+        let created = pvc_api
+            .create(&PostParams::default(), &pvc)
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to create PVC '{}' in namespace '{}': {}",
+                    pvc_name, namespace, e
+                ))
+            })?;
+        info!("Rebound PV {} to PVC {}/{}", pv_name, namespace, pvc_name);
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(
+                audit_ctx,
+                AuditOperation::UPDATE,
+                "PersistentVolume",
+                pv_name,
+            )
+            .with_payload(json!({ "claim": { "namespace": namespace, "name": pvc_name } })),
+        )
+        .await;
+        Ok(created)
+    }
+}
+
+fn pv_info(pv: &PersistentVolume) -> PersistentVolumeInfo {
+    let name = pv.name_any();
+    let spec = pv.spec.as_ref();
+    let status = pv.status.as_ref();
+
+    let capacity = spec
+        .and_then(|s| s.capacity.as_ref())
+        .and_then(|cap_map| cap_map.get("storage"))
+        .map(|q: &Quantity| q.0.clone());
+
+    let access_modes = spec
+        .and_then(|s| s.access_modes.as_ref())
+        .map_or_else(Vec::new, |modes| modes.clone());
+
+    let reclaim_policy = spec
+        .and_then(|s| s.persistent_volume_reclaim_policy.as_ref())
+        .cloned()
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let pv_status = status
+        .and_then(|s| s.phase.clone())
+        .unwrap_or_else(|| "Unknown".to_string());
+    let reason = status
+        .and_then(|s| s.reason.clone())
+        .unwrap_or_else(|| "".to_string());
+
+    let claim_ref = spec.and_then(|s| s.claim_ref.as_ref());
+    let claim = claim_ref.map_or_else(
+        || "-".to_string(),
+        |cr| {
+            format!(
+                "{}/{}",
+                cr.namespace.as_deref().unwrap_or(""),
+                cr.name.as_deref().unwrap_or("")
+            )
+        },
+    );
+
+    let storage_class = spec
+        .and_then(|s| s.storage_class_name.clone())
+        .unwrap_or_else(|| "-".to_string());
+
+    let age = pv.metadata.creation_timestamp.as_ref().map_or_else(
+        || "Unknown".to_string(),
+        |ts| {
+            let creation_time = ts.0;
+            let duration = Utc::now().signed_duration_since(creation_time);
+            if duration.num_days() > 0 {
+                format!("{}d", duration.num_days())
+            } else if duration.num_hours() > 0 {
+                format!("{}h", duration.num_hours())
+            } else if duration.num_minutes() > 0 {
+                format!("{}m", duration.num_minutes())
+            } else {
+                format!("{}s", duration.num_seconds())
+            }
+        },
+    );
+
+    PersistentVolumeInfo {
+        name,
+        capacity,
+        access_modes,
+        reclaim_policy,
+        status: pv_status,
+        claim,
+        storage_class,
+        reason,
+        age,
+    }
 }
@@ -13,19 +13,23 @@
 // limitations under the License.
 
 
-use chrono::Utc;
-use k8s_openapi::api::core::v1::{Event, Pod, PodSpec, PodStatus};
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::core::v1::{EphemeralContainer, Event, Pod, PodSpec, PodStatus};
 use kube::{
-    api::{DeleteParams, ListParams, LogParams, ObjectMeta},
+    api::{DeleteParams, ListParams, LogParams, ObjectMeta, Patch, PatchParams},
     Api, Client, ResourceExt,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, instrument};
 
 use crate::services::kubernetes::client::ClientFactory;
 use crate::{errors::AppError, models::cluster::KubernetesClusterConfig};
-use kube::api::AttachParams;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
+use std::sync::Arc;
+use kube::api::{AttachParams, Portforwarder};
 use tokio::io::AsyncReadExt;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -59,6 +63,37 @@ pub struct ExecResult {
     pub stderr: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EphemeralContainerSpec {
+    pub image: String,
+    pub name: String,
+    #[serde(default)]
+    pub command: Vec<String>,
+    // Shares the process namespace of this container, so its processes are visible
+    pub target_container_name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EphemeralContainerStatus {
+    pub name: String,
+    pub state: String,
+    pub started_at: Option<DateTime<Utc>>,
+}
+
+// How long to wait for an injected ephemeral container to start
+const EPHEMERAL_CONTAINER_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+const EPHEMERAL_CONTAINER_START_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(120);
+
+// Waiting reasons that will not resolve on their own
+const EPHEMERAL_CONTAINER_FAILURE_REASONS: &[&str] = &[
+    "ErrImagePull",
+    "ImagePullBackOff",
+    "InvalidImageName",
+    "CreateContainerError",
+    "CreateContainerConfigError",
+    "RunContainerError",
+];
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ContainerInfo {
     pub name: String,
@@ -168,23 +203,36 @@ pub fn convert_kube_pod_to_pod_info(pod: &Pod, current_namespace: &str) -> PodIn
 }
 
 #[derive(Clone)]
-pub struct PodService;
+pub struct PodService {
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+}
 
 impl PodService {
     pub fn new() -> Self {
-        PodService
+        Self {
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
     }
 
     async fn get_kube_client(cluster_config: &KubernetesClusterConfig) -> Result<Client, AppError> {
         ClientFactory::get_client(cluster_config).await
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "list_pods", resource_type = "Pod"))]
     pub async fn list_pods(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
-    ) -> Result<Vec<PodInfo>, AppError> {
-        debug!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, "Listing pods");
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<PodInfo>, AppError> {
+        debug!(target: "mayyam::services::kubernetes::pod", "Listing pods");
+        let page = PageRequest::new(page_size, continue_token);
         let client = Self::get_kube_client(cluster_config).await?;
 
         let api: Api<Pod> = if namespace.is_empty() || namespace == "all" {
@@ -192,10 +240,10 @@ impl PodService {
         } else {
             Api::namespaced(client, namespace)
         };
-        let lp = ListParams::default();
+        let lp = page.list_params(ListParams::default());
         match api.list(&lp).await {
             Ok(pod_list) => {
-                info!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, count = pod_list.items.len(), "Successfully listed pods");
+                info!(target: "mayyam::services::kubernetes::pod", count = pod_list.items.len(), "Successfully listed pods");
                 let actual_namespace = if namespace.is_empty() || namespace == "all" {
                     ""
                 } else {
@@ -205,53 +253,75 @@ impl PodService {
                     .iter()
                     .map(|p| convert_kube_pod_to_pod_info(p, actual_namespace))
                     .collect();
-                Ok(pod_infos)
+                Ok(page.finish(pod_list.metadata, pod_infos))
             }
             Err(e) => {
-                error!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, error = %e, "Failed to list pods");
+                error!(target: "mayyam::services::kubernetes::pod", error = %e, "Failed to list pods");
                 Err(AppError::Kubernetes(e.to_string()))
             }
         }
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "list_pods_by_selector", resource_type = "Pod"))]
+    pub async fn list_pods_by_selector(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        label_selector: &str,
+    ) -> Result<Vec<PodInfo>, AppError> {
+        debug!(target: "mayyam::services::kubernetes::pod", %label_selector, "Listing pods by selector");
+        let client = Self::get_kube_client(cluster_config).await?;
+        let api: Api<Pod> = Api::namespaced(client, namespace);
+        let pod_list = api
+            .list(&ListParams::default().labels(label_selector))
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        Ok(pod_list
+            .iter()
+            .map(|p| convert_kube_pod_to_pod_info(p, namespace))
+            .collect())
+    }
+
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "get_pod_details", resource_type = "Pod"))]
     pub async fn get_pod_details(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
         pod_name: &str,
     ) -> Result<PodDetail, AppError> {
-        debug!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, %pod_name, "Getting pod details");
+        debug!(target: "mayyam::services::kubernetes::pod", %pod_name, "Getting pod details");
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<Pod> = Api::namespaced(client, namespace);
         match api.get(pod_name).await {
             Ok(pod) => {
-                info!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, %pod_name, "Successfully retrieved pod details");
+                info!(target: "mayyam::services::kubernetes::pod", %pod_name, "Successfully retrieved pod details");
                 Ok(PodDetail::from(pod))
             }
             Err(e) => {
-                error!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, %pod_name, error = %e, "Failed to get pod details");
+                error!(target: "mayyam::services::kubernetes::pod", %pod_name, error = %e, "Failed to get pod details");
                 Err(AppError::Kubernetes(e.to_string()))
             }
         }
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "get_pod_events", resource_type = "Event"))]
     pub async fn get_pod_events(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
         pod_name: &str,
     ) -> Result<Vec<Event>, AppError> {
-        debug!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, %pod_name, "Getting pod events");
+        debug!(target: "mayyam::services::kubernetes::pod", %pod_name, "Getting pod events");
         let client = Self::get_kube_client(cluster_config).await?;
 
         let pod_api: Api<Pod> = Api::namespaced(client.clone(), namespace);
         let pod_object = pod_api.get(pod_name).await.map_err(|e| {
-            error!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, %pod_name, error = %e, "Failed to retrieve pod to get its UID for events");
+            error!(target: "mayyam::services::kubernetes::pod", %pod_name, error = %e, "Failed to retrieve pod to get its UID for events");
             AppError::NotFound(format!("Could not retrieve pod '{}' to get its UID: {}", pod_name, e))
         })?;
 
         let pod_uid = pod_object.metadata.uid.ok_or_else(|| {
-            error!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, %pod_name, "Pod is missing UID, cannot fetch events.");
+            error!(target: "mayyam::services::kubernetes::pod", %pod_name, "Pod is missing UID, cannot fetch events.");
             AppError::Internal(format!("Pod '{}' in namespace '{}' does not have a UID, cannot fetch events.", pod_name, namespace))
         })?;
 
@@ -262,16 +332,17 @@ impl PodService {
 
         match event_api.list(&lp).await {
             Ok(event_list) => {
-                info!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, %pod_name, count = event_list.items.len(), "Successfully fetched pod events");
+                info!(target: "mayyam::services::kubernetes::pod", %pod_name, count = event_list.items.len(), "Successfully fetched pod events");
                 Ok(event_list.items)
             }
             Err(e) => {
-                error!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, %pod_name, error = %e, "Error fetching pod events");
+                error!(target: "mayyam::services::kubernetes::pod", %pod_name, error = %e, "Error fetching pod events");
                 Err(AppError::Kubernetes(e.to_string()))
             }
         }
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "get_pod_logs", resource_type = "Pod"))]
     pub async fn get_pod_logs(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -281,7 +352,7 @@ impl PodService {
         previous: bool,
         tail_lines: Option<i64>,
     ) -> Result<String, AppError> {
-        debug!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, %pod_name, "Getting pod logs");
+        debug!(target: "mayyam::services::kubernetes::pod", %pod_name, "Getting pod logs");
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<Pod> = Api::namespaced(client, namespace);
         let mut lp = LogParams::default();
@@ -293,38 +364,47 @@ impl PodService {
 
         match api.logs(pod_name, &lp).await {
             Ok(logs) => {
-                info!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, %pod_name, "Successfully fetched pod logs");
+                info!(target: "mayyam::services::kubernetes::pod", %pod_name, "Successfully fetched pod logs");
                 Ok(logs)
             }
             Err(e) => {
-                error!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, %pod_name, error = %e, "Error fetching pod logs");
+                error!(target: "mayyam::services::kubernetes::pod", %pod_name, error = %e, "Error fetching pod logs");
                 Err(AppError::Kubernetes(e.to_string()))
             }
         }
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "delete_pod", resource_type = "Pod"))]
     pub async fn delete_pod(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
         pod_name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
-        debug!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, %pod_name, "Deleting pod");
+        debug!(target: "mayyam::services::kubernetes::pod", %pod_name, "Deleting pod");
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<Pod> = Api::namespaced(client, namespace);
         let dp = DeleteParams::default();
         match api.delete(pod_name, &dp).await {
             Ok(_) => {
-                info!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, %pod_name, "Successfully deleted pod");
+                info!(target: "mayyam::services::kubernetes::pod", %pod_name, "Successfully deleted pod");
+                emit_if_configured(
+                    &self.audit_emitter,
+                    AuditEvent::new(audit_ctx, AuditOperation::DELETE, "Pod", pod_name)
+                        .with_namespace(namespace),
+                )
+                .await;
                 Ok(())
             }
             Err(e) => {
-                error!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, %pod_name, error = %e, "Error deleting pod");
+                error!(target: "mayyam::services::kubernetes::pod", %pod_name, error = %e, "Error deleting pod");
                 Err(AppError::Kubernetes(e.to_string()))
             }
         }
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "exec_command", resource_type = "Pod"))]
     pub async fn exec_command(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -370,6 +450,135 @@ impl PodService {
         })
     }
 
+    // Adds a debug container through the ephemeralcontainers subresource (Kubernetes 1.23+)
+    // and waits until it is running. Ephemeral containers cannot be removed once added.
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "inject_ephemeral_container", resource_type = "Pod"))]
+    pub async fn inject_ephemeral_container(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        pod_name: &str,
+        container_spec: EphemeralContainerSpec,
+    ) -> Result<EphemeralContainerStatus, AppError> {
+        let client = Self::get_kube_client(cluster_config).await?;
+        let api: Api<Pod> = Api::namespaced(client, namespace);
+
+        let pod = api
+            .get(pod_name)
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        // A merge patch replaces the whole list, so the existing containers must be kept
+        let mut containers = pod
+            .spec
+            .and_then(|s| s.ephemeral_containers)
+            .unwrap_or_default();
+        if containers.iter().any(|c| c.name == container_spec.name) {
+            return Err(AppError::Conflict(format!(
+                "Ephemeral container {} already exists in pod {}",
+                container_spec.name, pod_name
+            )));
+        }
+        containers.push(EphemeralContainer {
+            name: container_spec.name.clone(),
+            image: Some(container_spec.image.clone()),
+            command: (!container_spec.command.is_empty()).then(|| container_spec.command.clone()),
+            target_container_name: container_spec.target_container_name.clone(),
+            stdin: Some(true),
+            tty: Some(true),
+            ..Default::default()
+        });
+
+        let patch = serde_json::json!({ "spec": { "ephemeralContainers": containers } });
+        api.patch_ephemeral_containers(pod_name, &PatchParams::default(), &Patch::Merge(&patch))
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        info!(target: "mayyam::services::kubernetes::pod", %pod_name, container = %container_spec.name, image = %container_spec.image, "Injected ephemeral container");
+
+        let deadline = std::time::Instant::now() + EPHEMERAL_CONTAINER_START_TIMEOUT;
+        loop {
+            let pod = api
+                .get(pod_name)
+                .await
+                .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+            let state = pod
+                .status
+                .and_then(|s| s.ephemeral_container_statuses)
+                .unwrap_or_default()
+                .into_iter()
+                .find(|s| s.name == container_spec.name)
+                .and_then(|s| s.state);
+
+            if let Some(state) = state {
+                if let Some(running) = state.running {
+                    return Ok(EphemeralContainerStatus {
+                        name: container_spec.name,
+                        state: "Running".to_string(),
+                        started_at: running.started_at.map(|t| t.0),
+                    });
+                }
+                if let Some(terminated) = state.terminated {
+                    if terminated.exit_code != 0 {
+                        return Err(AppError::Kubernetes(format!(
+                            "Ephemeral container {} exited with code {}: {}",
+                            container_spec.name,
+                            terminated.exit_code,
+                            terminated.reason.unwrap_or_default()
+                        )));
+                    }
+                    return Ok(EphemeralContainerStatus {
+                        name: container_spec.name,
+                        state: "Terminated".to_string(),
+                        started_at: terminated.started_at.map(|t| t.0),
+                    });
+                }
+                if let Some(waiting) = state.waiting {
+                    let reason = waiting.reason.unwrap_or_default();
+                    if EPHEMERAL_CONTAINER_FAILURE_REASONS.contains(&reason.as_str()) {
+                        return Err(AppError::Kubernetes(format!(
+                            "Ephemeral container {} failed to start: {} {}",
+                            container_spec.name,
+                            reason,
+                            waiting.message.unwrap_or_default()
+                        )));
+                    }
+                }
+            }
+
+            if std::time::Instant::now() >= deadline {
+                return Err(AppError::Kubernetes(format!(
+                    "Ephemeral container {} did not start within {}s",
+                    container_spec.name,
+                    EPHEMERAL_CONTAINER_START_TIMEOUT.as_secs()
+                )));
+            }
+            tokio::time::sleep(EPHEMERAL_CONTAINER_POLL_INTERVAL).await;
+        }
+    }
+
+    // Opens a port-forward tunnel to a single pod port. The caller owns the forwarder and
+    // must take the stream for `pod_port` from it.
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "port_forward", resource_type = "Pod"))]
+    pub async fn port_forward(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        pod_name: &str,
+        pod_port: u16,
+    ) -> Result<Portforwarder, AppError> {
+        let client = Self::get_kube_client(cluster_config).await?;
+        let api: Api<Pod> = Api::namespaced(client, namespace);
+
+        let forwarder = api.portforward(pod_name, &[pod_port]).await.map_err(|e| {
+            AppError::Kubernetes(format!(
+                "Failed to open port-forward to {}/{}:{}: {}",
+                namespace, pod_name, pod_port, e
+            ))
+        })?;
+        info!(%pod_name, pod_port, "Opened port-forward tunnel");
+        Ok(forwarder)
+    }
+
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "stream_pod_logs", resource_type = "Pod"))]
     pub async fn stream_pod_logs(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -381,7 +590,7 @@ impl PodService {
     ) -> Result<impl futures::Stream<Item = Result<bytes::Bytes, kube::Error>>, AppError> {
         use futures::StreamExt;
         use futures_util::TryStreamExt;
-        debug!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, %pod_name, "Streaming pod logs");
+        debug!(target: "mayyam::services::kubernetes::pod", %pod_name, "Streaming pod logs");
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<Pod> = Api::namespaced(client, namespace);
         
@@ -394,7 +603,7 @@ impl PodService {
         lp.follow = true; // Essential for streaming
 
         let log_reader = api.log_stream(pod_name, &lp).await.map_err(|e| {
-            error!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, %pod_name, error = %e, "Error streaming pod logs");
+            error!(target: "mayyam::services::kubernetes::pod", %pod_name, error = %e, "Error streaming pod logs");
             AppError::Kubernetes(e.to_string())
         })?;
 
@@ -412,24 +621,26 @@ impl PodService {
         Ok(stream)
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "watch_pods", resource_type = "Pod"))]
     pub async fn watch_pods(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
     ) -> Result<impl futures::Stream<Item = Result<kube::runtime::watcher::Event<Pod>, kube::runtime::watcher::Error>>, AppError> {
-        debug!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, "Watching pods");
+        debug!(target: "mayyam::services::kubernetes::pod", "Watching pods");
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<Pod> = Api::namespaced(client, namespace);
         let watcher = kube::runtime::watcher(api, kube::runtime::watcher::Config::default());
         Ok(watcher)
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "watch_events", resource_type = "Event"))]
     pub async fn watch_events(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
     ) -> Result<impl futures::Stream<Item = Result<kube::runtime::watcher::Event<Event>, kube::runtime::watcher::Error>>, AppError> {
-        debug!(target: "mayyam::services::kubernetes::pod", cluster_name = cluster_config.api_server_url.as_deref().unwrap_or("unknown"), %namespace, "Watching events");
+        debug!(target: "mayyam::services::kubernetes::pod", "Watching events");
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<Event> = Api::namespaced(client, namespace);
         let watcher = kube::runtime::watcher(api, kube::runtime::watcher::Config::default());
@@ -0,0 +1,213 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_graphql::SimpleObject;
+use chrono::Utc;
+use futures::StreamExt;
+use k8s_openapi::api::core::v1::Pod;
+use kube::api::{WatchEvent, WatchParams};
+use kube::{Api, ResourceExt};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use tracing::{debug, info, warn};
+
+use crate::errors::AppError;
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::client::ClientFactory;
+
+const CHANNEL_CAPACITY: usize = 256;
+// Kubernetes caps watch requests server-side; reconnect a little before that
+const WATCH_TIMEOUT_SECS: u32 = 290;
+
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct PodStatusEvent {
+    pub pod_name: String,
+    pub namespace: String,
+    pub old_status: Option<String>,
+    pub new_status: String,
+    pub timestamp: String,
+}
+
+type WatchKey = (String, String); // (cluster_id, namespace)
+
+// Fans out pod status changes to subscribers. A single watch is kept per cluster and
+// namespace and shut down once its last subscriber is gone.
+pub struct PodStatusBroadcaster {
+    channels: Mutex<HashMap<WatchKey, broadcast::Sender<PodStatusEvent>>>,
+}
+
+impl PodStatusBroadcaster {
+    pub fn new() -> Self {
+        Self {
+            channels: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub async fn subscribe(
+        self: &Arc<Self>,
+        cluster_id: &str,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+    ) -> Result<broadcast::Receiver<PodStatusEvent>, AppError> {
+        let key = (cluster_id.to_string(), namespace.to_string());
+        if let Some(tx) = self.channels.lock().unwrap().get(&key) {
+            return Ok(tx.subscribe());
+        }
+
+        let client = ClientFactory::get_client(cluster_config).await?;
+        let api: Api<Pod> = Api::namespaced(client, namespace);
+
+        let (tx, rx) = {
+            let mut channels = self.channels.lock().unwrap();
+            // Another subscriber may have started the watch while the client was built
+            if let Some(tx) = channels.get(&key) {
+                return Ok(tx.subscribe());
+            }
+            let (tx, rx) = broadcast::channel(CHANNEL_CAPACITY);
+            channels.insert(key.clone(), tx.clone());
+            (tx, rx)
+        };
+
+        info!(%cluster_id, %namespace, "Starting pod status watch");
+        let this = Arc::clone(self);
+        tokio::spawn(async move { this.run_watch(key, api, tx).await });
+        Ok(rx)
+    }
+
+    async fn run_watch(
+        self: Arc<Self>,
+        key: WatchKey,
+        api: Api<Pod>,
+        tx: broadcast::Sender<PodStatusEvent>,
+    ) {
+        let namespace = key.1.clone();
+        let mut statuses: HashMap<String, String> = HashMap::new();
+        // "0" replays current pods as Added events, giving subscribers the initial state
+        let mut resource_version = "0".to_string();
+
+        'watch: loop {
+            let wp = WatchParams::default().timeout(WATCH_TIMEOUT_SECS);
+            let stream = match api.watch(&wp, &resource_version).await {
+                Ok(stream) => stream,
+                Err(e) => {
+                    warn!(%namespace, "Failed to start pod watch: {}", e);
+                    break;
+                }
+            };
+            futures::pin_mut!(stream);
+
+            while let Some(event) = stream.next().await {
+                let event = match event {
+                    Ok(event) => event,
+                    Err(e) => {
+                        warn!(%namespace, "Pod watch stream error: {}", e);
+                        break;
+                    }
+                };
+
+                let change = match event {
+                    WatchEvent::Added(pod) | WatchEvent::Modified(pod) => {
+                        if let Some(rv) = pod.resource_version() {
+                            resource_version = rv;
+                        }
+                        let name = pod.name_any();
+                        let new_status = pod_status(&pod);
+                        let old_status = statuses.insert(name.clone(), new_status.clone());
+                        if old_status.as_deref() == Some(new_status.as_str()) {
+                            None
+                        } else {
+                            Some((name, old_status, new_status))
+                        }
+                    }
+                    WatchEvent::Deleted(pod) => {
+                        if let Some(rv) = pod.resource_version() {
+                            resource_version = rv;
+                        }
+                        let name = pod.name_any();
+                        let old_status = statuses.remove(&name);
+                        Some((name, old_status, "Deleted".to_string()))
+                    }
+                    WatchEvent::Bookmark(bookmark) => {
+                        resource_version = bookmark.metadata.resource_version;
+                        None
+                    }
+                    WatchEvent::Error(e) => {
+                        // 410 Gone: our resource version expired, relist from scratch
+                        if e.code == 410 {
+                            resource_version = "0".to_string();
+                        }
+                        debug!(%namespace, "Pod watch returned error: {}", e.message);
+                        break;
+                    }
+                };
+
+                if let Some((pod_name, old_status, new_status)) = change {
+                    let event = PodStatusEvent {
+                        pod_name,
+                        namespace: namespace.clone(),
+                        old_status,
+                        new_status,
+                        timestamp: Utc::now().to_rfc3339(),
+                    };
+                    if tx.send(event).is_err() {
+                        break 'watch;
+                    }
+                }
+            }
+
+            if tx.receiver_count() == 0 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+
+        self.channels.lock().unwrap().remove(&key);
+        info!(cluster_id = %key.0, %namespace, "Stopped pod status watch");
+    }
+}
+
+impl Default for PodStatusBroadcaster {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Container waiting reasons (CrashLoopBackOff, ImagePullBackOff, ...) are more useful
+// than the pod phase, which stays Pending or Running while they happen.
+fn pod_status(pod: &Pod) -> String {
+    if pod.metadata.deletion_timestamp.is_some() {
+        return "Terminating".to_string();
+    }
+
+    let status = match pod.status.as_ref() {
+        Some(status) => status,
+        None => return "Unknown".to_string(),
+    };
+
+    let waiting_reason = status
+        .container_statuses
+        .iter()
+        .flatten()
+        .filter_map(|cs| cs.state.as_ref()?.waiting.as_ref()?.reason.clone())
+        .next();
+
+    waiting_reason
+        .or_else(|| status.phase.clone())
+        .unwrap_or_else(|| "Unknown".to_string())
+}
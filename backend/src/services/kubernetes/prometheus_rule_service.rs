@@ -0,0 +1,493 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use k8s_openapi::api::core::v1::ConfigMap;
+use kube::{
+    api::{Api, ListParams, Patch, PatchParams},
+    ResourceExt,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+use tracing::{instrument, warn};
+
+use crate::errors::AppError;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
+use crate::services::kubernetes::client::ClientFactory;
+
+// Label marking a ConfigMap as holding Prometheus rule files, one per data key
+pub const PROMETHEUS_RULE_LABEL: &str = "prometheus_rule";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrometheusRuleEntry {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alert: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record: Option<String>,
+    pub expr: String,
+    #[serde(default, rename = "for", skip_serializing_if = "Option::is_none")]
+    pub for_duration: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub annotations: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrometheusRuleGroup {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval: Option<String>,
+    #[serde(default)]
+    pub rules: Vec<PrometheusRuleEntry>,
+}
+
+// Same layout as a Prometheus `rule_files` entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RuleFile {
+    #[serde(default)]
+    groups: Vec<PrometheusRuleGroup>,
+}
+
+// One rule file stored under a ConfigMap data key
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrometheusRule {
+    pub configmap_name: String,
+    pub namespace: String,
+    pub data_key: String,
+    pub groups: Vec<PrometheusRuleGroup>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleValidationError {
+    pub group: String,
+    pub rule: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleValidationResult {
+    pub valid: bool,
+    pub group_count: usize,
+    pub rule_count: usize,
+    pub errors: Vec<RuleValidationError>,
+}
+
+pub struct PrometheusRuleService {
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+}
+
+impl PrometheusRuleService {
+    pub fn new() -> Self {
+        Self {
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
+    }
+
+    async fn api(
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+    ) -> Result<Api<ConfigMap>, AppError> {
+        let client = ClientFactory::get_client(cluster_config).await?;
+        let api = if namespace.is_empty() || namespace == "all" {
+            Api::all(client)
+        } else {
+            Api::namespaced(client, namespace)
+        };
+        Ok(api)
+    }
+
+    // Data keys that are not valid rule files are skipped with a warning so one
+    // broken ConfigMap doesn't hide the rest
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "list_alert_rules", resource_type = "ConfigMap"))]
+    pub async fn list_alert_rules(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+    ) -> Result<Vec<PrometheusRule>, AppError> {
+        let api = Self::api(cluster_config, namespace).await?;
+        let lp = ListParams::default().labels(&format!("{}=true", PROMETHEUS_RULE_LABEL));
+        let cms = api
+            .list(&lp)
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+
+        let mut rules = Vec::new();
+        for cm in cms {
+            let configmap_name = cm.name_any();
+            let cm_namespace = cm.namespace().unwrap_or_else(|| namespace.to_string());
+            for (data_key, content) in cm.data.unwrap_or_default() {
+                match serde_yaml::from_str::<RuleFile>(&content) {
+                    Ok(file) => rules.push(PrometheusRule {
+                        configmap_name: configmap_name.clone(),
+                        namespace: cm_namespace.clone(),
+                        data_key,
+                        groups: file.groups,
+                    }),
+                    Err(e) => warn!(
+                        "Skipping {} in ConfigMap {}/{}: not a rule file: {}",
+                        data_key, cm_namespace, configmap_name, e
+                    ),
+                }
+            }
+        }
+        Ok(rules)
+    }
+
+    // Structural checks plus a PromQL parse of every expression. Only YAML that
+    // can't be read as a rule file is an error; rule problems go in the result.
+    pub fn validate_alert_rule(rule_yaml: &str) -> Result<RuleValidationResult, AppError> {
+        let file = parse_rule_file(rule_yaml)?;
+        Ok(validate_groups(&file.groups))
+    }
+
+    // Writes the rule file into a labelled ConfigMap, refusing anything that
+    // fails validation
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "apply_alert_rule", resource_type = "ConfigMap"))]
+    pub async fn apply_alert_rule(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        name: &str,
+        data_key: &str,
+        rule_yaml: &str,
+        audit_ctx: &AuditContext,
+    ) -> Result<PrometheusRule, AppError> {
+        let file = parse_rule_file(rule_yaml)?;
+        let validation = validate_groups(&file.groups);
+        if !validation.valid {
+            let messages: Vec<String> = validation
+                .errors
+                .iter()
+                .map(|e| match &e.rule {
+                    Some(rule) => format!("{}/{}: {}", e.group, rule, e.message),
+                    None => format!("{}: {}", e.group, e.message),
+                })
+                .collect();
+            return Err(AppError::Validation(format!(
+                "Rule file failed validation: {}",
+                messages.join("; ")
+            )));
+        }
+
+        let api = Self::api(cluster_config, namespace).await?;
+        let patch = serde_json::json!({
+            "apiVersion": "v1",
+            "kind": "ConfigMap",
+            "metadata": {
+                "name": name,
+                "labels": { PROMETHEUS_RULE_LABEL: "true" },
+            },
+            "data": { data_key: rule_yaml },
+        });
+        let params = PatchParams::apply("mayyam").force();
+        api.patch(name, &params, &Patch::Apply(&patch))
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::UPDATE, "ConfigMap", name)
+                .with_namespace(namespace)
+                .with_payload(serde_json::json!({
+                    "data_key": data_key,
+                    "group_count": validation.group_count,
+                    "rule_count": validation.rule_count,
+                })),
+        )
+        .await;
+
+        Ok(PrometheusRule {
+            configmap_name: name.to_string(),
+            namespace: namespace.to_string(),
+            data_key: data_key.to_string(),
+            groups: file.groups,
+        })
+    }
+
+    // The ConfigMap is named after the file stem and the file name becomes the data key
+    pub async fn apply_alert_rule_from_file(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        path: &Path,
+        audit_ctx: &AuditContext,
+    ) -> Result<PrometheusRule, AppError> {
+        let rule_yaml = tokio::fs::read_to_string(path).await.map_err(|e| {
+            AppError::BadRequest(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        let data_key = path.file_name().and_then(|n| n.to_str()).ok_or_else(|| {
+            AppError::BadRequest(format!("Invalid rule file path: {}", path.display()))
+        })?;
+        let name = configmap_name_for(path).ok_or_else(|| {
+            AppError::BadRequest(format!(
+                "Cannot derive a ConfigMap name from {}",
+                path.display()
+            ))
+        })?;
+
+        self.apply_alert_rule(
+            cluster_config,
+            namespace,
+            &name,
+            data_key,
+            &rule_yaml,
+            audit_ctx,
+        )
+        .await
+    }
+}
+
+fn parse_rule_file(rule_yaml: &str) -> Result<RuleFile, AppError> {
+    serde_yaml::from_str(rule_yaml)
+        .map_err(|e| AppError::Validation(format!("Invalid rule file YAML: {}", e)))
+}
+
+fn validate_groups(groups: &[PrometheusRuleGroup]) -> RuleValidationResult {
+    let mut errors = Vec::new();
+    let mut group_names = HashSet::new();
+    let mut rule_count = 0;
+    if groups.is_empty() {
+        errors.push(RuleValidationError {
+            group: String::new(),
+            rule: None,
+            message: "Rule file has no groups".to_string(),
+        });
+    }
+
+    for group in groups {
+        if group.name.trim().is_empty() {
+            errors.push(RuleValidationError {
+                group: group.name.clone(),
+                rule: None,
+                message: "Group name must not be empty".to_string(),
+            });
+        } else if !group_names.insert(group.name.as_str()) {
+            errors.push(RuleValidationError {
+                group: group.name.clone(),
+                rule: None,
+                message: "Duplicate group name".to_string(),
+            });
+        }
+
+        for rule in &group.rules {
+            rule_count += 1;
+            let rule_name = rule.alert.clone().or_else(|| rule.record.clone());
+            let mut push = |message: String| {
+                errors.push(RuleValidationError {
+                    group: group.name.clone(),
+                    rule: rule_name.clone(),
+                    message,
+                })
+            };
+
+            match (&rule.alert, &rule.record) {
+                (Some(_), Some(_)) => {
+                    push("Rule must set only one of 'alert' or 'record'".to_string())
+                }
+                (None, None) => push("Rule must set 'alert' or 'record'".to_string()),
+                (None, Some(_)) if rule.for_duration.is_some() => {
+                    push("Recording rules do not support 'for'".to_string())
+                }
+                _ => {}
+            }
+
+            if rule.expr.trim().is_empty() {
+                push("Expression must not be empty".to_string());
+            } else if let Err(e) = promql_parser::parser::parse(&rule.expr) {
+                push(format!("Invalid PromQL expression: {}", e));
+            }
+        }
+    }
+
+    RuleValidationResult {
+        valid: errors.is_empty(),
+        group_count: groups.len(),
+        rule_count,
+        errors,
+    }
+}
+
+// Lowercases the file stem and replaces anything outside the DNS-1123 subdomain
+// alphabet, e.g. "API_Alerts.rules.yaml" becomes "api-alerts.rules"
+fn configmap_name_for(path: &Path) -> Option<String> {
+    let stem = path.file_stem()?.to_str()?;
+    let name: String = stem
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let name = name.trim_matches(|c| c == '-' || c == '.');
+    if name.is_empty() {
+        None
+    } else {
+        Some(name.chars().take(253).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(rule_yaml: &str) -> Vec<String> {
+        let file = parse_rule_file(rule_yaml).unwrap();
+        validate_groups(&file.groups)
+            .errors
+            .into_iter()
+            .map(|e| e.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_rule_file() {
+        let file = parse_rule_file(
+            r#"
+groups:
+  - name: api
+    rules:
+      - record: job:http_requests:rate5m
+        expr: sum by (job) (rate(http_requests_total[5m]))
+      - alert: HighErrorRate
+        expr: job:http_errors:rate5m > 0.05
+        for: 10m
+        labels:
+          severity: page
+"#,
+        )
+        .unwrap();
+        let result = validate_groups(&file.groups);
+        assert!(result.valid, "{:?}", result.errors);
+        assert_eq!(result.group_count, 1);
+        assert_eq!(result.rule_count, 2);
+        assert_eq!(file.groups[0].rules[1].for_duration.as_deref(), Some("10m"));
+    }
+
+    #[test]
+    fn test_rule_must_set_exactly_one_of_alert_and_record() {
+        let errors = messages(
+            r#"
+groups:
+  - name: api
+    rules:
+      - alert: Both
+        record: both
+        expr: up
+      - expr: up
+"#,
+        );
+        assert_eq!(
+            errors,
+            vec![
+                "Rule must set only one of 'alert' or 'record'",
+                "Rule must set 'alert' or 'record'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_recording_rule_rejects_for() {
+        let errors = messages(
+            r#"
+groups:
+  - name: api
+    rules:
+      - record: job:up:sum
+        expr: sum(up)
+        for: 5m
+"#,
+        );
+        assert_eq!(errors, vec!["Recording rules do not support 'for'"]);
+    }
+
+    #[test]
+    fn test_duplicate_and_empty_groups() {
+        let errors = messages(
+            r#"
+groups:
+  - name: api
+    rules: []
+  - name: api
+    rules: []
+  - name: " "
+    rules: []
+"#,
+        );
+        assert_eq!(
+            errors,
+            vec!["Duplicate group name", "Group name must not be empty"]
+        );
+        assert_eq!(messages("groups: []"), vec!["Rule file has no groups"]);
+    }
+
+    #[test]
+    fn test_invalid_promql() {
+        let errors = messages(
+            r#"
+groups:
+  - name: api
+    rules:
+      - alert: Broken
+        expr: sum(rate(http_requests_total[5m])
+      - alert: Empty
+        expr: " "
+"#,
+        );
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].starts_with("Invalid PromQL expression"));
+        assert_eq!(errors[1], "Expression must not be empty");
+    }
+
+    #[test]
+    fn test_parse_rule_file_rejects_invalid_yaml() {
+        assert!(matches!(
+            parse_rule_file("groups: [name: api"),
+            Err(AppError::Validation(_))
+        ));
+        assert!(matches!(
+            parse_rule_file("groups:\n  - rules: []\n"),
+            Err(AppError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_configmap_name_for() {
+        assert_eq!(
+            configmap_name_for(Path::new("rules/API_Alerts.rules.yaml")).as_deref(),
+            Some("api-alerts.rules")
+        );
+        assert_eq!(
+            configmap_name_for(Path::new("_node alerts_.yml")).as_deref(),
+            Some("node-alerts")
+        );
+        assert_eq!(configmap_name_for(Path::new("__.yaml")), None);
+        let long = format!("{}.yaml", "a".repeat(300));
+        assert_eq!(
+            configmap_name_for(Path::new(&long)).map(|n| n.len()),
+            Some(253)
+        );
+    }
+}
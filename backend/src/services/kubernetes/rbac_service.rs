@@ -15,16 +15,150 @@
 
 use crate::errors::AppError;
 use crate::models::cluster::KubernetesClusterConfig;
-use crate::services::kubernetes::client::ClientFactory;
-use k8s_openapi::api::rbac::v1::{ClusterRole, ClusterRoleBinding, Role, RoleBinding};
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use crate::services::kubernetes::client::{fingerprint, ClientFactory};
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
+use k8s_openapi::api::rbac::v1::{
+    ClusterRole, ClusterRoleBinding, PolicyRule, Role, RoleBinding, RoleRef, Subject,
+};
 use kube::api::{DeleteParams, ListParams, Patch, PatchParams};
 use kube::Api;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
 
-pub struct RbacService;
+const ROLE_GRAPH_TTL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum RbacSubject {
+    ServiceAccount { namespace: String, name: String },
+    User { name: String },
+    Group { name: String },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourcePermission {
+    // None means the permission applies cluster-wide
+    pub namespace: Option<String>,
+    pub api_group: String,
+    pub resource: String,
+    pub verbs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PermissionSummary {
+    pub subject: RbacSubject,
+    pub permissions: Vec<ResourcePermission>,
+    pub bindings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PolicyRuleDelta {
+    pub api_group: String,
+    // Resource type, "resource[name]" for rules restricted by resourceNames, or a
+    // non-resource URL such as "/healthz"
+    pub resource: String,
+    pub added_verbs: Vec<String>,
+    pub removed_verbs: Vec<String>,
+}
+
+// Differences between a source and a target cluster, expressed as the changes
+// promoting the source's RBAC to the target would make
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RbacDiff {
+    pub added_roles: Vec<String>,
+    pub removed_roles: Vec<String>,
+    pub modified_roles: Vec<(String, Vec<PolicyRuleDelta>)>,
+    pub added_bindings: Vec<String>,
+    pub removed_bindings: Vec<String>,
+    // Bindings present in both clusters whose roleRef or subjects differ
+    pub modified_bindings: Vec<String>,
+}
+
+impl RbacDiff {
+    pub fn has_changes(&self) -> bool {
+        !(self.added_roles.is_empty()
+            && self.removed_roles.is_empty()
+            && self.modified_roles.is_empty()
+            && self.added_bindings.is_empty()
+            && self.removed_bindings.is_empty()
+            && self.modified_bindings.is_empty())
+    }
+}
+
+impl fmt::Display for RbacDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.has_changes() {
+            return writeln!(f, "No RBAC differences");
+        }
+        for role in &self.added_roles {
+            writeln!(f, "+ {}", role)?;
+        }
+        for role in &self.removed_roles {
+            writeln!(f, "- {}", role)?;
+        }
+        for (role, deltas) in &self.modified_roles {
+            writeln!(f, "~ {}", role)?;
+            for delta in deltas {
+                let group = if delta.api_group.is_empty() {
+                    "core"
+                } else {
+                    delta.api_group.as_str()
+                };
+                write!(f, "    {} ({}):", delta.resource, group)?;
+                for verb in &delta.added_verbs {
+                    write!(f, " +{}", verb)?;
+                }
+                for verb in &delta.removed_verbs {
+                    write!(f, " -{}", verb)?;
+                }
+                writeln!(f)?;
+            }
+        }
+        for binding in &self.added_bindings {
+            writeln!(f, "+ {}", binding)?;
+        }
+        for binding in &self.removed_bindings {
+            writeln!(f, "- {}", binding)?;
+        }
+        for binding in &self.modified_bindings {
+            writeln!(f, "~ {}", binding)?;
+        }
+        Ok(())
+    }
+}
+
+// Snapshot of every RBAC object in a cluster, refreshed at most once per ROLE_GRAPH_TTL
+struct RoleGraph {
+    roles: Vec<Role>,
+    role_bindings: Vec<RoleBinding>,
+    cluster_roles: Vec<ClusterRole>,
+    cluster_role_bindings: Vec<ClusterRoleBinding>,
+    fetched_at: Instant,
+}
+
+pub struct RbacService {
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+    role_graph_cache: Mutex<HashMap<u64, Arc<RoleGraph>>>,
+}
 
 impl RbacService {
     pub fn new() -> Self {
-        Self
+        Self {
+            audit_emitter: None,
+            role_graph_cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
     }
 
     // Roles
@@ -39,18 +173,23 @@ impl RbacService {
             Api::namespaced(client, namespace)
         })
     }
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "list_roles", resource_type = "Role"))]
     pub async fn list_roles(
         &self,
         cluster: &KubernetesClusterConfig,
         namespace: &str,
-    ) -> Result<Vec<Role>, AppError> {
-        let api = Self::roles_api(cluster, namespace).await?;
-        Ok(api
-            .list(&ListParams::default())
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<Role>, AppError> {
+        let api: Api<Role> = Self::roles_api(cluster, namespace).await?;
+        let page = PageRequest::new(page_size, continue_token);
+        let list = api
+            .list(&page.list_params(ListParams::default()))
             .await
-            .map_err(|e| AppError::Kubernetes(e.to_string()))?
-            .items)
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        Ok(page.finish(list.metadata, list.items))
     }
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "get_role", resource_type = "Role"))]
     pub async fn get_role(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -62,6 +201,7 @@ impl RbacService {
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "upsert_role", resource_type = "Role"))]
     pub async fn upsert_role(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -81,16 +221,24 @@ impl RbacService {
         .await
         .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "delete_role", resource_type = "Role"))]
     pub async fn delete_role(
         &self,
         cluster: &KubernetesClusterConfig,
         namespace: &str,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let api: Api<Role> = Api::namespaced(ClientFactory::get_client(cluster).await?, namespace);
         api.delete(name, &DeleteParams::default())
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::DELETE, "Role", name)
+                .with_namespace(namespace),
+        )
+        .await;
         Ok(())
     }
 
@@ -106,18 +254,23 @@ impl RbacService {
             Api::namespaced(client, namespace)
         })
     }
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "list_role_bindings", resource_type = "RoleBinding"))]
     pub async fn list_role_bindings(
         &self,
         cluster: &KubernetesClusterConfig,
         namespace: &str,
-    ) -> Result<Vec<RoleBinding>, AppError> {
-        let api = Self::role_bindings_api(cluster, namespace).await?;
-        Ok(api
-            .list(&ListParams::default())
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<RoleBinding>, AppError> {
+        let api: Api<RoleBinding> = Self::role_bindings_api(cluster, namespace).await?;
+        let page = PageRequest::new(page_size, continue_token);
+        let list = api
+            .list(&page.list_params(ListParams::default()))
             .await
-            .map_err(|e| AppError::Kubernetes(e.to_string()))?
-            .items)
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        Ok(page.finish(list.metadata, list.items))
     }
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "get_role_binding", resource_type = "RoleBinding"))]
     pub async fn get_role_binding(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -130,6 +283,7 @@ impl RbacService {
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "upsert_role_binding", resource_type = "RoleBinding"))]
     pub async fn upsert_role_binding(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -150,17 +304,25 @@ impl RbacService {
         .await
         .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), namespace = %namespace, operation = "delete_role_binding", resource_type = "RoleBinding"))]
     pub async fn delete_role_binding(
         &self,
         cluster: &KubernetesClusterConfig,
         namespace: &str,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let api: Api<RoleBinding> =
             Api::namespaced(ClientFactory::get_client(cluster).await?, namespace);
         api.delete(name, &DeleteParams::default())
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::DELETE, "RoleBinding", name)
+                .with_namespace(namespace),
+        )
+        .await;
         Ok(())
     }
 
@@ -171,17 +333,22 @@ impl RbacService {
         let client = ClientFactory::get_client(cluster).await?;
         Ok(Api::all(client))
     }
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), operation = "list_cluster_roles", resource_type = "ClusterRole"))]
     pub async fn list_cluster_roles(
         &self,
         cluster: &KubernetesClusterConfig,
-    ) -> Result<Vec<ClusterRole>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<ClusterRole>, AppError> {
         let api: Api<ClusterRole> = Self::cluster_roles_api(cluster).await?;
-        Ok(api
-            .list(&ListParams::default())
+        let page = PageRequest::new(page_size, continue_token);
+        let list = api
+            .list(&page.list_params(ListParams::default()))
             .await
-            .map_err(|e| AppError::Kubernetes(e.to_string()))?
-            .items)
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        Ok(page.finish(list.metadata, list.items))
     }
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), operation = "get_cluster_role", resource_type = "ClusterRole"))]
     pub async fn get_cluster_role(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -192,6 +359,7 @@ impl RbacService {
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), operation = "upsert_cluster_role", resource_type = "ClusterRole"))]
     pub async fn upsert_cluster_role(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -210,15 +378,22 @@ impl RbacService {
         .await
         .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), operation = "delete_cluster_role", resource_type = "ClusterRole"))]
     pub async fn delete_cluster_role(
         &self,
         cluster: &KubernetesClusterConfig,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let api = Self::cluster_roles_api(cluster).await?;
         api.delete(name, &DeleteParams::default())
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::DELETE, "ClusterRole", name),
+        )
+        .await;
         Ok(())
     }
 
@@ -229,17 +404,22 @@ impl RbacService {
         let client = ClientFactory::get_client(cluster).await?;
         Ok(Api::all(client))
     }
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), operation = "list_cluster_role_bindings", resource_type = "ClusterRoleBinding"))]
     pub async fn list_cluster_role_bindings(
         &self,
         cluster: &KubernetesClusterConfig,
-    ) -> Result<Vec<ClusterRoleBinding>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<ClusterRoleBinding>, AppError> {
         let api: Api<ClusterRoleBinding> = Self::cluster_role_bindings_api(cluster).await?;
-        Ok(api
-            .list(&ListParams::default())
+        let page = PageRequest::new(page_size, continue_token);
+        let list = api
+            .list(&page.list_params(ListParams::default()))
             .await
-            .map_err(|e| AppError::Kubernetes(e.to_string()))?
-            .items)
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        Ok(page.finish(list.metadata, list.items))
     }
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), operation = "get_cluster_role_binding", resource_type = "ClusterRoleBinding"))]
     pub async fn get_cluster_role_binding(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -250,6 +430,7 @@ impl RbacService {
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), operation = "upsert_cluster_role_binding", resource_type = "ClusterRoleBinding"))]
     pub async fn upsert_cluster_role_binding(
         &self,
         cluster: &KubernetesClusterConfig,
@@ -267,15 +448,386 @@ impl RbacService {
         .await
         .map_err(|e| AppError::Kubernetes(e.to_string()))
     }
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), operation = "delete_cluster_role_binding", resource_type = "ClusterRoleBinding"))]
     pub async fn delete_cluster_role_binding(
         &self,
         cluster: &KubernetesClusterConfig,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let api = Self::cluster_role_bindings_api(cluster).await?;
         api.delete(name, &DeleteParams::default())
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::DELETE, "ClusterRoleBinding", name),
+        )
+        .await;
         Ok(())
     }
+
+    // Subject permissions
+    async fn role_graph(
+        &self,
+        cluster: &KubernetesClusterConfig,
+    ) -> Result<Arc<RoleGraph>, AppError> {
+        let key = fingerprint(cluster);
+        if let Some(graph) = self.role_graph_cache.lock().unwrap().get(&key) {
+            if graph.fetched_at.elapsed() < ROLE_GRAPH_TTL {
+                return Ok(graph.clone());
+            }
+        }
+
+        // The graph needs every binding, so bypass the paged list methods.
+        let (roles, role_bindings, cluster_roles, cluster_role_bindings) = tokio::try_join!(
+            list_all(Self::roles_api(cluster, "all")),
+            list_all(Self::role_bindings_api(cluster, "all")),
+            list_all(Self::cluster_roles_api(cluster)),
+            list_all(Self::cluster_role_bindings_api(cluster)),
+        )?;
+        let graph = Arc::new(RoleGraph {
+            roles,
+            role_bindings,
+            cluster_roles,
+            cluster_role_bindings,
+            fetched_at: Instant::now(),
+        });
+        self.role_graph_cache
+            .lock()
+            .unwrap()
+            .insert(key, graph.clone());
+        Ok(graph)
+    }
+
+    #[instrument(skip_all, fields(cluster_name = %cluster.trace_name(), operation = "get_subject_permissions", resource_type = "RoleBinding"))]
+    pub async fn get_subject_permissions(
+        &self,
+        cluster: &KubernetesClusterConfig,
+        subject: RbacSubject,
+    ) -> Result<PermissionSummary, AppError> {
+        let graph = self.role_graph(cluster).await?;
+        let mut grants: BTreeMap<(Option<String>, String, String), BTreeSet<String>> =
+            BTreeMap::new();
+        let mut bindings = Vec::new();
+
+        for binding in &graph.cluster_role_bindings {
+            if !binding_matches(&subject, binding.subjects.as_deref(), None) {
+                continue;
+            }
+            let name = binding.metadata.name.clone().unwrap_or_default();
+            // ClusterRoleBindings can only reference ClusterRoles
+            if let Some(rules) = cluster_role_rules(&graph, &binding.role_ref.name) {
+                add_grants(&mut grants, None, rules);
+            }
+            bindings.push(format!("ClusterRoleBinding/{}", name));
+        }
+
+        for binding in &graph.role_bindings {
+            let namespace = binding.metadata.namespace.clone().unwrap_or_default();
+            if !binding_matches(&subject, binding.subjects.as_deref(), Some(&namespace)) {
+                continue;
+            }
+            let name = binding.metadata.name.clone().unwrap_or_default();
+            if let Some(rules) = role_ref_rules(&graph, &binding.role_ref, &namespace) {
+                add_grants(&mut grants, Some(namespace.clone()), rules);
+            }
+            bindings.push(format!("RoleBinding/{}/{}", namespace, name));
+        }
+
+        let permissions = grants
+            .into_iter()
+            .map(|((namespace, api_group, resource), verbs)| ResourcePermission {
+                namespace,
+                api_group,
+                resource,
+                verbs: verbs.into_iter().collect(),
+            })
+            .collect();
+
+        Ok(PermissionSummary {
+            subject,
+            permissions,
+            bindings,
+        })
+    }
+
+    // Namespace only narrows Roles and RoleBindings; cluster-scoped objects are always compared
+    #[instrument(skip_all, fields(source_cluster = %source.trace_name(), target_cluster = %target.trace_name(), operation = "diff_rbac", resource_type = "Role"))]
+    pub async fn diff_rbac(
+        &self,
+        source: &KubernetesClusterConfig,
+        target: &KubernetesClusterConfig,
+        namespace: Option<String>,
+    ) -> Result<RbacDiff, AppError> {
+        let (source_graph, target_graph) =
+            tokio::try_join!(self.role_graph(source), self.role_graph(target))?;
+        let namespace = namespace.as_deref();
+
+        let source_roles = role_rules(&source_graph, namespace);
+        let target_roles = role_rules(&target_graph, namespace);
+        let mut diff = RbacDiff::default();
+        for (name, rules) in &source_roles {
+            match target_roles.get(name) {
+                None => diff.added_roles.push(name.clone()),
+                Some(target_rules) => {
+                    let deltas = rule_deltas(rules, target_rules);
+                    if !deltas.is_empty() {
+                        diff.modified_roles.push((name.clone(), deltas));
+                    }
+                }
+            }
+        }
+        diff.removed_roles = target_roles
+            .keys()
+            .filter(|name| !source_roles.contains_key(*name))
+            .cloned()
+            .collect();
+
+        let source_bindings = binding_specs(&source_graph, namespace);
+        let target_bindings = binding_specs(&target_graph, namespace);
+        for (name, spec) in &source_bindings {
+            match target_bindings.get(name) {
+                None => diff.added_bindings.push(name.clone()),
+                Some(target_spec) if target_spec != spec => {
+                    diff.modified_bindings.push(name.clone())
+                }
+                Some(_) => {}
+            }
+        }
+        diff.removed_bindings = target_bindings
+            .keys()
+            .filter(|name| !source_bindings.contains_key(*name))
+            .cloned()
+            .collect();
+
+        Ok(diff)
+    }
+}
+
+type FlattenedRules = BTreeMap<(String, String), BTreeSet<String>>;
+
+fn in_namespace(metadata_namespace: Option<&str>, namespace: Option<&str>) -> bool {
+    namespace.map_or(true, |ns| metadata_namespace == Some(ns))
+}
+
+fn role_rules(graph: &RoleGraph, namespace: Option<&str>) -> BTreeMap<String, FlattenedRules> {
+    let roles = graph
+        .roles
+        .iter()
+        .filter(|r| in_namespace(r.metadata.namespace.as_deref(), namespace))
+        .map(|r| {
+            let name = format!(
+                "Role/{}/{}",
+                r.metadata.namespace.as_deref().unwrap_or_default(),
+                r.metadata.name.as_deref().unwrap_or_default()
+            );
+            (name, flatten_rules(r.rules.as_deref().unwrap_or_default()))
+        });
+    let cluster_roles = graph.cluster_roles.iter().map(|r| {
+        let name = format!(
+            "ClusterRole/{}",
+            r.metadata.name.as_deref().unwrap_or_default()
+        );
+        (name, flatten_rules(r.rules.as_deref().unwrap_or_default()))
+    });
+    roles.chain(cluster_roles).collect()
+}
+
+// Expands rules to one entry per (apiGroup, resource) so that equivalent rules
+// written differently compare equal
+fn flatten_rules(rules: &[PolicyRule]) -> FlattenedRules {
+    let mut flattened = FlattenedRules::new();
+    for rule in rules {
+        let default_groups = vec![String::new()];
+        let api_groups = rule.api_groups.as_ref().unwrap_or(&default_groups);
+        let mut resources = Vec::new();
+        for resource in rule.resources.as_deref().unwrap_or_default() {
+            match rule.resource_names.as_deref() {
+                Some(names) if !names.is_empty() => {
+                    resources.extend(names.iter().map(|n| format!("{}[{}]", resource, n)))
+                }
+                _ => resources.push(resource.clone()),
+            }
+        }
+        for api_group in api_groups {
+            for resource in &resources {
+                flattened
+                    .entry((api_group.clone(), resource.clone()))
+                    .or_default()
+                    .extend(rule.verbs.iter().cloned());
+            }
+        }
+        for url in rule.non_resource_urls.as_deref().unwrap_or_default() {
+            flattened
+                .entry((String::new(), url.clone()))
+                .or_default()
+                .extend(rule.verbs.iter().cloned());
+        }
+    }
+    flattened
+}
+
+fn rule_deltas(source: &FlattenedRules, target: &FlattenedRules) -> Vec<PolicyRuleDelta> {
+    let empty = BTreeSet::new();
+    let keys: BTreeSet<&(String, String)> = source.keys().chain(target.keys()).collect();
+    keys.into_iter()
+        .filter_map(|key| {
+            let source_verbs = source.get(key).unwrap_or(&empty);
+            let target_verbs = target.get(key).unwrap_or(&empty);
+            let added_verbs: Vec<String> = source_verbs.difference(target_verbs).cloned().collect();
+            let removed_verbs: Vec<String> =
+                target_verbs.difference(source_verbs).cloned().collect();
+            if added_verbs.is_empty() && removed_verbs.is_empty() {
+                return None;
+            }
+            Some(PolicyRuleDelta {
+                api_group: key.0.clone(),
+                resource: key.1.clone(),
+                added_verbs,
+                removed_verbs,
+            })
+        })
+        .collect()
+}
+
+// Bindings are compared by roleRef and the set of subjects they grant it to
+fn binding_specs(
+    graph: &RoleGraph,
+    namespace: Option<&str>,
+) -> BTreeMap<String, (String, BTreeSet<String>)> {
+    let subject_set = |subjects: Option<&[Subject]>| -> BTreeSet<String> {
+        subjects
+            .unwrap_or_default()
+            .iter()
+            .map(|s| {
+                format!(
+                    "{}/{}/{}",
+                    s.kind,
+                    s.namespace.as_deref().unwrap_or_default(),
+                    s.name
+                )
+            })
+            .collect()
+    };
+    let role_bindings = graph
+        .role_bindings
+        .iter()
+        .filter(|b| in_namespace(b.metadata.namespace.as_deref(), namespace))
+        .map(|b| {
+            let name = format!(
+                "RoleBinding/{}/{}",
+                b.metadata.namespace.as_deref().unwrap_or_default(),
+                b.metadata.name.as_deref().unwrap_or_default()
+            );
+            let role_ref = format!("{}/{}", b.role_ref.kind, b.role_ref.name);
+            (name, (role_ref, subject_set(b.subjects.as_deref())))
+        });
+    let cluster_role_bindings = graph.cluster_role_bindings.iter().map(|b| {
+        let name = format!(
+            "ClusterRoleBinding/{}",
+            b.metadata.name.as_deref().unwrap_or_default()
+        );
+        let role_ref = format!("{}/{}", b.role_ref.kind, b.role_ref.name);
+        (name, (role_ref, subject_set(b.subjects.as_deref())))
+    });
+    role_bindings.chain(cluster_role_bindings).collect()
+}
+
+async fn list_all<K>(
+    api: impl std::future::Future<Output = Result<Api<K>, AppError>>,
+) -> Result<Vec<K>, AppError>
+where
+    K: Clone + DeserializeOwned + std::fmt::Debug,
+{
+    Ok(api
+        .await?
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| AppError::Kubernetes(e.to_string()))?
+        .items)
+}
+
+// Groups Kubernetes adds implicitly to authenticated identities
+fn implicit_groups(subject: &RbacSubject) -> Vec<String> {
+    match subject {
+        RbacSubject::ServiceAccount { namespace, .. } => vec![
+            "system:authenticated".to_string(),
+            "system:serviceaccounts".to_string(),
+            format!("system:serviceaccounts:{}", namespace),
+        ],
+        RbacSubject::User { .. } => vec!["system:authenticated".to_string()],
+        RbacSubject::Group { .. } => Vec::new(),
+    }
+}
+
+fn binding_matches(
+    subject: &RbacSubject,
+    binding_subjects: Option<&[Subject]>,
+    binding_namespace: Option<&str>,
+) -> bool {
+    let groups = implicit_groups(subject);
+    binding_subjects.unwrap_or_default().iter().any(|s| {
+        match (s.kind.as_str(), subject) {
+            ("ServiceAccount", RbacSubject::ServiceAccount { namespace, name }) => {
+                // A RoleBinding subject without a namespace refers to the binding's namespace
+                let sa_namespace = s.namespace.as_deref().or(binding_namespace);
+                s.name == *name && sa_namespace == Some(namespace.as_str())
+            }
+            ("User", RbacSubject::User { name }) => s.name == *name,
+            ("Group", RbacSubject::Group { name }) => s.name == *name,
+            ("Group", _) => groups.contains(&s.name),
+            _ => false,
+        }
+    })
+}
+
+fn cluster_role_rules<'a>(graph: &'a RoleGraph, name: &str) -> Option<&'a [PolicyRule]> {
+    graph
+        .cluster_roles
+        .iter()
+        .find(|r| r.metadata.name.as_deref() == Some(name))
+        .and_then(|r| r.rules.as_deref())
+}
+
+fn role_ref_rules<'a>(
+    graph: &'a RoleGraph,
+    role_ref: &RoleRef,
+    namespace: &str,
+) -> Option<&'a [PolicyRule]> {
+    match role_ref.kind.as_str() {
+        "ClusterRole" => cluster_role_rules(graph, &role_ref.name),
+        "Role" => graph
+            .roles
+            .iter()
+            .find(|r| {
+                r.metadata.name.as_deref() == Some(role_ref.name.as_str())
+                    && r.metadata.namespace.as_deref() == Some(namespace)
+            })
+            .and_then(|r| r.rules.as_deref()),
+        _ => None,
+    }
+}
+
+fn add_grants(
+    grants: &mut BTreeMap<(Option<String>, String, String), BTreeSet<String>>,
+    namespace: Option<String>,
+    rules: &[PolicyRule],
+) {
+    for rule in rules {
+        // Non-resource URL rules (e.g. /healthz) don't map to a resource type
+        let Some(resources) = rule.resources.as_ref() else {
+            continue;
+        };
+        let default_groups = vec![String::new()];
+        let api_groups = rule.api_groups.as_ref().unwrap_or(&default_groups);
+        for api_group in api_groups {
+            for resource in resources {
+                grants
+                    .entry((namespace.clone(), api_group.clone(), resource.clone()))
+                    .or_default()
+                    .extend(rule.verbs.iter().cloned());
+            }
+        }
+    }
 }
@@ -15,18 +15,24 @@
 use crate::errors::AppError;
 use crate::models::cluster::KubernetesClusterConfig;
 use crate::services::kubernetes::client::ClientFactory;
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
 use k8s_openapi::api::apps::v1::ReplicaSet;
 use kube::{api::ListParams, Api};
 use serde_json::Value;
+use tracing::instrument;
 
 pub struct ReplicaSetsService;
 
 impl ReplicaSetsService {
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace_name, operation = "list_replica_sets", resource_type = "ReplicaSet"))]
     pub async fn list_replica_sets(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace_name: &str,
-    ) -> Result<Vec<Value>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<Value>, AppError> {
+        let page = PageRequest::new(page_size, continue_token);
         let client = ClientFactory::get_client(cluster_config).await?;
         let replica_sets: Api<ReplicaSet> = if namespace_name.is_empty() {
             Api::all(client)
@@ -35,9 +41,10 @@ impl ReplicaSetsService {
         };
 
         let rs_list = replica_sets
-            .list(&ListParams::default())
+            .list(&page.list_params(ListParams::default()))
             .await
             .map_err(|e| AppError::ExternalService(format!("Failed to list ReplicaSets: {}", e)))?;
+        let list_meta = rs_list.metadata.clone();
 
         let mut formatted_rs = Vec::new();
         for rs in rs_list {
@@ -46,9 +53,10 @@ impl ReplicaSetsService {
             }
         }
 
-        Ok(formatted_rs)
+        Ok(page.finish(list_meta, formatted_rs))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace_name, operation = "get_replica_set_details", resource_type = "ReplicaSet"))]
     pub async fn get_replica_set_details(
         &self,
         cluster_config: &KubernetesClusterConfig,
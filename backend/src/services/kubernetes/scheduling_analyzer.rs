@@ -0,0 +1,394 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use k8s_openapi::api::core::v1::{Container, Node, Pod, Taint, Toleration};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use kube::api::{Api, ListParams};
+use kube::ResourceExt;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use crate::errors::AppError;
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::client::ClientFactory;
+use crate::services::kubernetes::metrics_service::{parse_cpu_quantity, parse_memory_quantity};
+use crate::services::kubernetes::pod::PodService;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SchedulingBlocker {
+    // Latest FailedScheduling message(s) reported by the scheduler
+    SchedulerEvent {
+        message: String,
+    },
+    NodeUnschedulable {
+        nodes: Vec<String>,
+    },
+    NodeSelectorMismatch {
+        nodes: Vec<String>,
+    },
+    UntoleratedTaint {
+        taint: String,
+        nodes: Vec<String>,
+    },
+    InsufficientCpu {
+        requested_millicores: i64,
+        nodes: Vec<String>,
+    },
+    InsufficientMemory {
+        requested_bytes: i64,
+        nodes: Vec<String>,
+    },
+    // Preemption of lower-priority pods is held back by a budget with no disruptions left
+    DisruptionBudgetBlocksPreemption {
+        pdb: String,
+    },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SchedulingAnalysis {
+    pub reasons: Vec<SchedulingBlocker>,
+    pub candidate_nodes: Vec<String>,
+    pub recommended_action: String,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct Requests {
+    pub(crate) cpu_cores: f64,
+    pub(crate) memory_bytes: f64,
+}
+
+pub struct SchedulingAnalyzer {
+    pod_service: Arc<PodService>,
+}
+
+impl SchedulingAnalyzer {
+    pub fn new(pod_service: Arc<PodService>) -> Self {
+        Self { pod_service }
+    }
+
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "analyze_pending_pod", resource_type = "Pod"))]
+    pub async fn analyze_pending_pod(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        pod_name: &str,
+    ) -> Result<SchedulingAnalysis, AppError> {
+        let client = ClientFactory::get_client(cluster_config).await?;
+
+        let pod = Api::<Pod>::namespaced(client.clone(), namespace)
+            .get(pod_name)
+            .await
+            .map_err(|e| AppError::NotFound(format!("Pod {} not found: {}", pod_name, e)))?;
+        let phase = pod
+            .status
+            .as_ref()
+            .and_then(|s| s.phase.clone())
+            .unwrap_or_default();
+        let assigned_node = pod.spec.as_ref().and_then(|s| s.node_name.clone());
+        if phase != "Pending" || assigned_node.is_some() {
+            return Err(AppError::Validation(format!(
+                "Pod {} is not waiting to be scheduled (phase {})",
+                pod_name, phase
+            )));
+        }
+        let spec = pod.spec.clone().unwrap_or_default();
+        let requested = pod_requests(&pod);
+
+        let events = self
+            .pod_service
+            .get_pod_events(cluster_config, namespace, pod_name)
+            .await?;
+        let mut failed_scheduling: Vec<_> = events
+            .iter()
+            .filter(|e| e.reason.as_deref() == Some("FailedScheduling"))
+            .filter_map(|e| {
+                let at = e
+                    .last_timestamp
+                    .as_ref()
+                    .map(|t| t.0)
+                    .or_else(|| e.event_time.as_ref().map(|t| t.0));
+                Some((at, e.message.clone()?))
+            })
+            .collect();
+        failed_scheduling.sort_by(|a, b| b.0.cmp(&a.0));
+        let mut seen = HashSet::new();
+        let scheduler_messages: Vec<String> = failed_scheduling
+            .into_iter()
+            .map(|(_, message)| message)
+            .filter(|message| seen.insert(message.clone()))
+            .collect();
+
+        let nodes = Api::<Node>::all(client.clone())
+            .list(&ListParams::default())
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?
+            .items;
+
+        // What is already reserved on each node by pods that still hold resources
+        let running = Api::<Pod>::all(client.clone())
+            .list(&ListParams::default().fields("status.phase!=Succeeded,status.phase!=Failed"))
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?
+            .items;
+        let mut reserved: HashMap<String, Requests> = HashMap::new();
+        for p in &running {
+            if let Some(node_name) = p.spec.as_ref().and_then(|s| s.node_name.clone()) {
+                let r = pod_requests(p);
+                let entry = reserved.entry(node_name).or_default();
+                entry.cpu_cores += r.cpu_cores;
+                entry.memory_bytes += r.memory_bytes;
+            }
+        }
+
+        let tolerations = spec.tolerations.clone().unwrap_or_default();
+        let node_selector = spec.node_selector.clone().unwrap_or_default();
+        let mut unschedulable = Vec::new();
+        let mut selector_mismatch = Vec::new();
+        let mut tainted: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        let mut short_cpu = Vec::new();
+        let mut short_memory = Vec::new();
+        let mut candidate_nodes = Vec::new();
+
+        for node in &nodes {
+            let name = node.name_any();
+            let mut fits = true;
+
+            let node_spec = node.spec.clone().unwrap_or_default();
+            if node_spec.unschedulable == Some(true) {
+                unschedulable.push(name.clone());
+                fits = false;
+            }
+
+            let labels = node.labels();
+            if !node_selector.iter().all(|(k, v)| labels.get(k) == Some(v)) {
+                selector_mismatch.push(name.clone());
+                fits = false;
+            }
+
+            for taint in node_spec.taints.unwrap_or_default() {
+                if blocks_scheduling(&taint) && !tolerations.iter().any(|t| tolerates(t, &taint)) {
+                    tainted
+                        .entry(format_taint(&taint))
+                        .or_default()
+                        .push(name.clone());
+                    fits = false;
+                }
+            }
+
+            let allocatable = node
+                .status
+                .as_ref()
+                .and_then(|s| s.allocatable.clone())
+                .unwrap_or_default();
+            let used = reserved.get(&name).copied().unwrap_or_default();
+            let free_cpu = allocatable
+                .get("cpu")
+                .and_then(parse_cpu_quantity)
+                .unwrap_or(0.0)
+                - used.cpu_cores;
+            let free_memory = allocatable
+                .get("memory")
+                .and_then(parse_memory_quantity)
+                .unwrap_or(0.0)
+                - used.memory_bytes;
+            if requested.cpu_cores > free_cpu {
+                short_cpu.push(name.clone());
+                fits = false;
+            }
+            if requested.memory_bytes > free_memory {
+                short_memory.push(name.clone());
+                fits = false;
+            }
+
+            if fits {
+                candidate_nodes.push(name);
+            }
+        }
+
+        let mut reasons: Vec<SchedulingBlocker> = scheduler_messages
+            .into_iter()
+            .map(|message| SchedulingBlocker::SchedulerEvent { message })
+            .collect();
+        if !unschedulable.is_empty() {
+            reasons.push(SchedulingBlocker::NodeUnschedulable {
+                nodes: unschedulable,
+            });
+        }
+        if !selector_mismatch.is_empty() {
+            reasons.push(SchedulingBlocker::NodeSelectorMismatch {
+                nodes: selector_mismatch,
+            });
+        }
+        for (taint, nodes) in tainted {
+            reasons.push(SchedulingBlocker::UntoleratedTaint { taint, nodes });
+        }
+        if !short_cpu.is_empty() {
+            reasons.push(SchedulingBlocker::InsufficientCpu {
+                requested_millicores: (requested.cpu_cores * 1000.0).round() as i64,
+                nodes: short_cpu,
+            });
+        }
+        if !short_memory.is_empty() {
+            reasons.push(SchedulingBlocker::InsufficientMemory {
+                requested_bytes: requested.memory_bytes.round() as i64,
+                nodes: short_memory,
+            });
+        }
+
+        // Only a pod with priority can preempt, so budgets matter only then
+        if candidate_nodes.is_empty() && spec.priority.unwrap_or(0) > 0 {
+            let pdbs = Api::<PodDisruptionBudget>::all(client)
+                .list(&ListParams::default())
+                .await
+                .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+            for pdb in pdbs.items {
+                if pdb.status.as_ref().map(|s| s.disruptions_allowed) == Some(0) {
+                    reasons.push(SchedulingBlocker::DisruptionBudgetBlocksPreemption {
+                        pdb: format!("{}/{}", pdb.namespace().unwrap_or_default(), pdb.name_any()),
+                    });
+                }
+            }
+        }
+
+        let recommended_action = recommend(&reasons, &candidate_nodes, nodes.len());
+        info!(
+            %pod_name,
+            blockers = reasons.len(),
+            candidates = candidate_nodes.len(),
+            "Scheduling analysis complete"
+        );
+
+        Ok(SchedulingAnalysis {
+            reasons,
+            candidate_nodes,
+            recommended_action,
+        })
+    }
+}
+
+// Effective request: app containers run together, init containers one at a time
+pub(crate) fn pod_requests(pod: &Pod) -> Requests {
+    let Some(spec) = pod.spec.as_ref() else {
+        return Requests::default();
+    };
+    let containers = spec.containers.iter().fold(Requests::default(), |acc, c| {
+        let r = container_requests(c);
+        Requests {
+            cpu_cores: acc.cpu_cores + r.cpu_cores,
+            memory_bytes: acc.memory_bytes + r.memory_bytes,
+        }
+    });
+    spec.init_containers
+        .iter()
+        .flatten()
+        .map(container_requests)
+        .fold(containers, |acc, r| Requests {
+            cpu_cores: acc.cpu_cores.max(r.cpu_cores),
+            memory_bytes: acc.memory_bytes.max(r.memory_bytes),
+        })
+}
+
+fn container_requests(container: &Container) -> Requests {
+    let requests = container
+        .resources
+        .as_ref()
+        .and_then(|r| r.requests.as_ref());
+    Requests {
+        cpu_cores: requests
+            .and_then(|r| r.get("cpu"))
+            .and_then(parse_cpu_quantity)
+            .unwrap_or(0.0),
+        memory_bytes: requests
+            .and_then(|r| r.get("memory"))
+            .and_then(parse_memory_quantity)
+            .unwrap_or(0.0),
+    }
+}
+
+pub(crate) fn blocks_scheduling(taint: &Taint) -> bool {
+    taint.effect == "NoSchedule" || taint.effect == "NoExecute"
+}
+
+pub(crate) fn tolerates(toleration: &Toleration, taint: &Taint) -> bool {
+    if let Some(effect) = toleration.effect.as_deref() {
+        if !effect.is_empty() && effect != taint.effect {
+            return false;
+        }
+    }
+    match toleration.key.as_deref() {
+        // An empty key with Exists tolerates every taint
+        None | Some("") => toleration.operator.as_deref() == Some("Exists"),
+        Some(key) if key != taint.key => false,
+        Some(_) => match toleration.operator.as_deref() {
+            Some("Exists") => true,
+            _ => toleration.value.as_deref().unwrap_or("") == taint.value.as_deref().unwrap_or(""),
+        },
+    }
+}
+
+fn format_taint(taint: &Taint) -> String {
+    match taint.value.as_deref() {
+        Some(value) if !value.is_empty() => format!("{}={}:{}", taint.key, value, taint.effect),
+        _ => format!("{}:{}", taint.key, taint.effect),
+    }
+}
+
+fn recommend(
+    reasons: &[SchedulingBlocker],
+    candidate_nodes: &[String],
+    node_count: usize,
+) -> String {
+    if node_count == 0 {
+        return "The cluster has no nodes; add nodes or check the node group".to_string();
+    }
+    if !candidate_nodes.is_empty() {
+        return "Some nodes satisfy requests, taints and node selectors; check node affinity, topology spread constraints and volume binding in the scheduler events".to_string();
+    }
+
+    let mut actions = Vec::new();
+    for reason in reasons {
+        let action = match reason {
+            SchedulingBlocker::NodeUnschedulable { .. } => {
+                "uncordon nodes that are done with maintenance"
+            }
+            SchedulingBlocker::NodeSelectorMismatch { .. } => {
+                "fix the pod's nodeSelector or label matching nodes"
+            }
+            SchedulingBlocker::UntoleratedTaint { .. } => {
+                "add tolerations for the node taints or schedule onto untainted nodes"
+            }
+            SchedulingBlocker::InsufficientCpu { .. }
+            | SchedulingBlocker::InsufficientMemory { .. } => {
+                "lower the pod's resource requests or add node capacity"
+            }
+            SchedulingBlocker::DisruptionBudgetBlocksPreemption { .. } => {
+                "relax the listed PodDisruptionBudgets so lower-priority pods can be preempted"
+            }
+            SchedulingBlocker::SchedulerEvent { .. } => continue,
+        };
+        if !actions.contains(&action) {
+            actions.push(action);
+        }
+    }
+    if actions.is_empty() {
+        "No blocking constraint found; review the scheduler events for the pod".to_string()
+    } else {
+        let mut text = actions.join("; ");
+        text[..1].make_ascii_uppercase();
+        text
+    }
+}
@@ -0,0 +1,546 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::AppError;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
+use crate::services::kubernetes::client::ClientFactory;
+use crate::services::kubernetes::garbage_collection_service::References;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use chrono::Utc;
+use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet, StatefulSet};
+use k8s_openapi::api::core::v1::{Pod, PodSpec, Secret};
+use k8s_openapi::ByteString;
+use kube::api::{ListParams, Patch, PatchParams, PostParams};
+use kube::{Api, Client, ResourceExt};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{info, instrument};
+
+const ROLLOUT_TIMEOUT: Duration = Duration::from_secs(600);
+const ROLLOUT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const ROTATED_FROM_ANNOTATION: &str = "mayyam.io/rotated-from";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RestartStrategy {
+    // Update the Secret in place, then restart the workloads using it one at a time
+    RollingRestart,
+    // Copy the Secret under a new name and roll each workload over to the copy, leaving
+    // the original untouched for running pods and rollback
+    BlueGreen,
+    // Update the Secret and only report the pods that still need a restart
+    Manual,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RotationResult {
+    pub secret_updated: bool,
+    // Pods replaced by the restarts
+    pub pods_restarted: Vec<String>,
+    // Pods using the secret that were not restarted: all of them for the manual strategy,
+    // otherwise pods not managed by a Deployment or StatefulSet
+    pub pods_pending_restart: Vec<String>,
+    // Secret the workloads now reference, set for the blue/green strategy
+    pub new_secret_name: Option<String>,
+    // Workload whose restart or rollout failed, as "Kind/name". The secret is already
+    // rotated at that point and later workloads are left in pods_pending_restart.
+    pub failed_workload: Option<String>,
+    pub error: Option<String>,
+    // Leftovers the caller has to deal with, e.g. Secrets that are not cleaned up
+    pub notes: Vec<String>,
+    pub duration_secs: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Workload {
+    Deployment(String),
+    StatefulSet(String),
+}
+
+impl Workload {
+    fn kind(&self) -> &'static str {
+        match self {
+            Workload::Deployment(_) => "Deployment",
+            Workload::StatefulSet(_) => "StatefulSet",
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            Workload::Deployment(name) | Workload::StatefulSet(name) => name,
+        }
+    }
+}
+
+pub struct SecretRotationService {
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+}
+
+impl SecretRotationService {
+    pub fn new() -> Self {
+        Self {
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
+    }
+
+    /// Set `key` of the Secret to `new_value` and restart the pods that use it. Workloads
+    /// are restarted one at a time and each must finish rolling out before the next one
+    /// starts; a rollout that doesn't become healthy stops the rotation and is reported
+    /// through `failed_workload` and `error`.
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "rotate_secret", resource_type = "Secret"))]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn rotate_secret(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        secret_name: &str,
+        key: &str,
+        new_value: &[u8],
+        restart_strategy: RestartStrategy,
+        audit_ctx: &AuditContext,
+    ) -> Result<RotationResult, AppError> {
+        let started = Instant::now();
+        let client = ClientFactory::get_client(cluster_config).await?;
+        let secrets: Api<Secret> = Api::namespaced(client.clone(), namespace);
+        let secret = secrets
+            .get_opt(secret_name)
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?
+            .ok_or_else(|| {
+                AppError::NotFound(format!("Secret {}/{} not found", namespace, secret_name))
+            })?;
+
+        let unchanged = secret
+            .data
+            .as_ref()
+            .and_then(|d| d.get(key))
+            .map_or(false, |v| v.0 == new_value);
+        if unchanged {
+            return Ok(RotationResult {
+                secret_updated: false,
+                pods_restarted: Vec::new(),
+                pods_pending_restart: Vec::new(),
+                new_secret_name: None,
+                failed_workload: None,
+                error: None,
+                notes: Vec::new(),
+                duration_secs: started.elapsed().as_secs(),
+            });
+        }
+
+        let (workloads, unmanaged_pods) =
+            find_workloads_using_secret(&client, namespace, secret_name).await?;
+
+        let mut new_secret_name = None;
+        if restart_strategy == RestartStrategy::BlueGreen {
+            let name = create_rotated_copy(&secrets, &secret, key, new_value).await?;
+            new_secret_name = Some(name);
+        } else {
+            let patch = json!({ "data": { key: BASE64.encode(new_value) } });
+            secrets
+                .patch(secret_name, &PatchParams::default(), &Patch::Merge(&patch))
+                .await
+                .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        }
+        info!(
+            "Rotated key {} of secret {}/{} ({:?})",
+            key, namespace, secret_name, restart_strategy
+        );
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::ROTATE, "Secret", secret_name)
+                .with_namespace(namespace),
+        )
+        .await;
+
+        // The secret is already rotated from here on, so failures are reported in the
+        // result instead of returned, leaving the caller a record of what was changed
+        let mut pods_restarted = Vec::new();
+        let mut pods_pending_restart = unmanaged_pods.clone();
+        let mut failed_workload = None;
+        let mut error = None;
+        for (workload, pods) in workloads {
+            if restart_strategy == RestartStrategy::Manual || failed_workload.is_some() {
+                pods_pending_restart.extend(pods);
+                continue;
+            }
+            let rolled_out = async {
+                match &new_secret_name {
+                    Some(new_name) => {
+                        repoint_workload(&client, namespace, &workload, secret_name, new_name)
+                            .await?
+                    }
+                    None => restart_workload(&client, namespace, &workload).await?,
+                }
+                emit_if_configured(
+                    &self.audit_emitter,
+                    AuditEvent::new(
+                        audit_ctx,
+                        AuditOperation::RESTART,
+                        workload.kind(),
+                        workload.name(),
+                    )
+                    .with_namespace(namespace),
+                )
+                .await;
+                wait_for_rollout(&client, namespace, &workload).await
+            }
+            .await;
+            match rolled_out {
+                Ok(()) => pods_restarted.extend(pods),
+                Err(e) => {
+                    failed_workload = Some(format!("{}/{}", workload.kind(), workload.name()));
+                    error = Some(e.to_string());
+                    pods_pending_restart.extend(pods);
+                }
+            }
+        }
+
+        let mut notes = Vec::new();
+        if let Some(new_name) = &new_secret_name {
+            notes.push(format!(
+                "Secret {} still holds the previous value and is not deleted; remove it once rollback is no longer needed",
+                secret_name
+            ));
+            if failed_workload.is_some() {
+                notes.push(format!(
+                    "Secret {} is not cleaned up after the failure; workloads switched before it reference the copy",
+                    new_name
+                ));
+            }
+            if !unmanaged_pods.is_empty() {
+                notes.push(format!(
+                    "Pods not managed by a Deployment or StatefulSet still reference {} and must be recreated against {}",
+                    secret_name, new_name
+                ));
+            }
+        }
+
+        Ok(RotationResult {
+            secret_updated: true,
+            pods_restarted,
+            pods_pending_restart,
+            new_secret_name,
+            failed_workload,
+            error,
+            notes,
+            duration_secs: started.elapsed().as_secs(),
+        })
+    }
+}
+
+// Groups the pods referencing the secret by the Deployment or StatefulSet managing them.
+// Pods without such a controller are returned separately.
+async fn find_workloads_using_secret(
+    client: &Client,
+    namespace: &str,
+    secret_name: &str,
+) -> Result<(BTreeMap<Workload, Vec<String>>, Vec<String>), AppError> {
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let replica_sets: Api<ReplicaSet> = Api::namespaced(client.clone(), namespace);
+    let (pods, replica_sets) = tokio::try_join!(
+        pods.list(&ListParams::default()),
+        replica_sets.list(&ListParams::default()),
+    )
+    .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+
+    let deployment_of: HashMap<String, String> = replica_sets
+        .items
+        .iter()
+        .filter_map(|rs| {
+            let owner = controller_of(rs.owner_references(), "Deployment")?;
+            Some((rs.name_any(), owner))
+        })
+        .collect();
+
+    let mut workloads: BTreeMap<Workload, Vec<String>> = BTreeMap::new();
+    let mut unmanaged = Vec::new();
+    for pod in &pods.items {
+        let Some(spec) = pod.spec.as_ref() else {
+            continue;
+        };
+        let mut references = References::default();
+        references.collect(spec);
+        if !references.secrets.contains(secret_name) {
+            continue;
+        }
+
+        let owners = pod.owner_references();
+        let workload = if let Some(sts) = controller_of(owners, "StatefulSet") {
+            Some(Workload::StatefulSet(sts))
+        } else {
+            controller_of(owners, "ReplicaSet")
+                .and_then(|rs| deployment_of.get(&rs).cloned())
+                .map(Workload::Deployment)
+        };
+        match workload {
+            Some(workload) => workloads.entry(workload).or_default().push(pod.name_any()),
+            None => unmanaged.push(pod.name_any()),
+        }
+    }
+    Ok((workloads, unmanaged))
+}
+
+fn controller_of(
+    owners: &[k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference],
+    kind: &str,
+) -> Option<String> {
+    owners
+        .iter()
+        .find(|o| o.controller == Some(true) && o.kind == kind)
+        .map(|o| o.name.clone())
+}
+
+async fn create_rotated_copy(
+    secrets: &Api<Secret>,
+    secret: &Secret,
+    key: &str,
+    new_value: &[u8],
+) -> Result<String, AppError> {
+    let old_name = secret.name_any();
+    let new_name = format!("{}-{}", old_name, Utc::now().format("%Y%m%d%H%M%S"));
+    let mut data = secret.data.clone().unwrap_or_default();
+    data.insert(key.to_string(), ByteString(new_value.to_vec()));
+    let mut annotations = BTreeMap::new();
+    annotations.insert(ROTATED_FROM_ANNOTATION.to_string(), old_name);
+
+    let mut copy = Secret {
+        data: Some(data),
+        type_: secret.type_.clone(),
+        ..Default::default()
+    };
+    copy.metadata.name = Some(new_name.clone());
+    copy.metadata.labels = secret.metadata.labels.clone();
+    copy.metadata.annotations = Some(annotations);
+    secrets
+        .create(&PostParams::default(), &copy)
+        .await
+        .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+    Ok(new_name)
+}
+
+// Same annotation `kubectl rollout restart` sets
+async fn restart_workload(
+    client: &Client,
+    namespace: &str,
+    workload: &Workload,
+) -> Result<(), AppError> {
+    let patch = json!({
+        "spec": {
+            "template": {
+                "metadata": {
+                    "annotations": {
+                        "kubectl.kubernetes.io/restartedAt": Utc::now().to_rfc3339()
+                    }
+                }
+            }
+        }
+    });
+    let params = PatchParams::default();
+    let result = match workload {
+        Workload::Deployment(name) => Api::<Deployment>::namespaced(client.clone(), namespace)
+            .patch(name, &params, &Patch::Merge(&patch))
+            .await
+            .map(|_| ()),
+        Workload::StatefulSet(name) => Api::<StatefulSet>::namespaced(client.clone(), namespace)
+            .patch(name, &params, &Patch::Merge(&patch))
+            .await
+            .map(|_| ()),
+    };
+    result.map_err(|e| {
+        AppError::Kubernetes(format!(
+            "Failed to restart {} {}: {}",
+            workload.kind(),
+            workload.name(),
+            e
+        ))
+    })
+}
+
+// Changing the pod template triggers the rollout, so no restart annotation is needed
+async fn repoint_workload(
+    client: &Client,
+    namespace: &str,
+    workload: &Workload,
+    from: &str,
+    to: &str,
+) -> Result<(), AppError> {
+    let params = PostParams::default();
+    let result = match workload {
+        Workload::Deployment(name) => {
+            let api = Api::<Deployment>::namespaced(client.clone(), namespace);
+            match api.get(name).await {
+                Ok(mut deployment) => {
+                    let spec = deployment
+                        .spec
+                        .as_mut()
+                        .and_then(|s| s.template.spec.as_mut());
+                    if let Some(spec) = spec {
+                        repoint_secret(spec, from, to);
+                    }
+                    api.replace(name, &params, &deployment).await.map(|_| ())
+                }
+                Err(e) => Err(e),
+            }
+        }
+        Workload::StatefulSet(name) => {
+            let api = Api::<StatefulSet>::namespaced(client.clone(), namespace);
+            match api.get(name).await {
+                Ok(mut stateful_set) => {
+                    let spec = stateful_set
+                        .spec
+                        .as_mut()
+                        .and_then(|s| s.template.spec.as_mut());
+                    if let Some(spec) = spec {
+                        repoint_secret(spec, from, to);
+                    }
+                    api.replace(name, &params, &stateful_set).await.map(|_| ())
+                }
+                Err(e) => Err(e),
+            }
+        }
+    };
+    result.map_err(|e| {
+        AppError::Kubernetes(format!(
+            "Failed to switch {} {} to secret {}: {}",
+            workload.kind(),
+            workload.name(),
+            to,
+            e
+        ))
+    })
+}
+
+// Rewrites every reference that References::collect recognises
+fn repoint_secret(spec: &mut PodSpec, from: &str, to: &str) {
+    let rename = |name: &mut Option<String>| {
+        if name.as_deref() == Some(from) {
+            *name = Some(to.to_string());
+        }
+    };
+
+    for volume in spec.volumes.iter_mut().flatten() {
+        if let Some(secret) = volume.secret.as_mut() {
+            rename(&mut secret.secret_name);
+        }
+        let sources = volume.projected.as_mut().and_then(|p| p.sources.as_mut());
+        for source in sources.into_iter().flatten() {
+            if let Some(secret) = source.secret.as_mut() {
+                rename(&mut secret.name);
+            }
+        }
+    }
+
+    let containers = spec
+        .containers
+        .iter_mut()
+        .chain(spec.init_containers.iter_mut().flatten());
+    for container in containers {
+        for env in container.env.iter_mut().flatten() {
+            let key_ref = env
+                .value_from
+                .as_mut()
+                .and_then(|v| v.secret_key_ref.as_mut());
+            if let Some(key_ref) = key_ref {
+                rename(&mut key_ref.name);
+            }
+        }
+        for env_from in container.env_from.iter_mut().flatten() {
+            if let Some(secret_ref) = env_from.secret_ref.as_mut() {
+                rename(&mut secret_ref.name);
+            }
+        }
+    }
+
+    for pull_secret in spec.image_pull_secrets.iter_mut().flatten() {
+        rename(&mut pull_secret.name);
+    }
+}
+
+async fn wait_for_rollout(
+    client: &Client,
+    namespace: &str,
+    workload: &Workload,
+) -> Result<(), AppError> {
+    let deadline = Instant::now() + ROLLOUT_TIMEOUT;
+    loop {
+        let rolled_out = match workload {
+            Workload::Deployment(name) => Api::<Deployment>::namespaced(client.clone(), namespace)
+                .get(name)
+                .await
+                .map(|d| deployment_rolled_out(&d)),
+            Workload::StatefulSet(name) => {
+                Api::<StatefulSet>::namespaced(client.clone(), namespace)
+                    .get(name)
+                    .await
+                    .map(|s| stateful_set_rolled_out(&s))
+            }
+        }
+        .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        if rolled_out {
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            return Err(AppError::Kubernetes(format!(
+                "{} {} did not become healthy within {}s; remaining workloads were not restarted",
+                workload.kind(),
+                workload.name(),
+                ROLLOUT_TIMEOUT.as_secs()
+            )));
+        }
+        tokio::time::sleep(ROLLOUT_POLL_INTERVAL).await;
+    }
+}
+
+// Mirrors `kubectl rollout status`: the controller has seen the change, every replica runs
+// the new template and no old replicas are left
+fn deployment_rolled_out(deployment: &Deployment) -> bool {
+    let Some(status) = deployment.status.as_ref() else {
+        return false;
+    };
+    let desired = deployment
+        .spec
+        .as_ref()
+        .and_then(|s| s.replicas)
+        .unwrap_or(1);
+    status.observed_generation.unwrap_or(0) >= deployment.metadata.generation.unwrap_or(0)
+        && status.updated_replicas.unwrap_or(0) == desired
+        && status.available_replicas.unwrap_or(0) == desired
+        && status.replicas.unwrap_or(0) == desired
+}
+
+fn stateful_set_rolled_out(stateful_set: &StatefulSet) -> bool {
+    let Some(status) = stateful_set.status.as_ref() else {
+        return false;
+    };
+    let desired = stateful_set
+        .spec
+        .as_ref()
+        .and_then(|s| s.replicas)
+        .unwrap_or(1);
+    status.observed_generation.unwrap_or(0) >= stateful_set.metadata.generation.unwrap_or(0)
+        && status.updated_replicas.unwrap_or(0) == desired
+        && status.ready_replicas.unwrap_or(0) == desired
+        && status.current_revision == status.update_revision
+}
@@ -14,16 +14,23 @@
 
 
 use crate::errors::AppError;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
 use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
 use crate::services::kubernetes::client::ClientFactory;
+use crate::services::kubernetes::configmaps_service::{diff_data_maps, ConfigMapDiff, TypeChange};
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use k8s_openapi::api::core::v1::Secret;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
 use kube::{
     api::{Api, DeleteParams, ListParams, Patch, PatchParams},
     ResourceExt,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::instrument;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SecretInfo {
@@ -35,11 +42,20 @@ pub struct SecretInfo {
     pub annotations: Option<BTreeMap<String, String>>,
 }
 
-pub struct SecretsService;
+pub struct SecretsService {
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+}
 
 impl SecretsService {
     pub fn new() -> Self {
-        Self
+        Self {
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
     }
 
     async fn api(
@@ -55,16 +71,18 @@ impl SecretsService {
         Ok(api)
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "list", resource_type = "Secret"))]
     pub async fn list(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
         label_selector: Option<String>,
         field_selector: Option<String>,
-        limit: Option<u32>,
+        page_size: Option<usize>,
         continue_token: Option<String>,
-    ) -> Result<Vec<SecretInfo>, AppError> {
+    ) -> Result<PaginatedResponse<SecretInfo>, AppError> {
         let api = Self::api(cluster_config, namespace).await?;
+        let page = PageRequest::new(page_size, continue_token);
         let mut lp = ListParams::default();
         if let Some(ls) = label_selector {
             lp = lp.labels(&ls);
@@ -72,18 +90,13 @@ impl SecretsService {
         if let Some(fs) = field_selector {
             lp = lp.fields(&fs);
         }
-        if let Some(l) = limit {
-            lp = lp.limit(l);
-        }
-        if let Some(ct) = continue_token {
-            lp = lp.continue_token(&ct);
-        }
         let items = api
-            .list(&lp)
+            .list(&page.list_params(lp))
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
 
-        Ok(items
+        let secrets = items
+            .items
             .into_iter()
             .map(|s| SecretInfo {
                 name: s.name_any(),
@@ -99,9 +112,11 @@ impl SecretsService {
                 labels: s.metadata.labels.clone(),
                 annotations: s.metadata.annotations.clone(),
             })
-            .collect())
+            .collect();
+        Ok(page.finish(items.metadata, secrets))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "get_redacted", resource_type = "Secret"))]
     pub async fn get_redacted(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -123,6 +138,7 @@ impl SecretsService {
         Ok(s)
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "upsert_plaintext", resource_type = "Secret"))]
     pub async fn upsert_plaintext(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -154,16 +170,101 @@ impl SecretsService {
         Ok(res)
     }
 
+    // Values are compared base64-encoded and never returned, only key names
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "diff", resource_type = "Secret"))]
+    pub async fn diff_secret(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        proposed: &Secret,
+    ) -> Result<ConfigMapDiff, AppError> {
+        let name =
+            proposed.metadata.name.as_deref().ok_or_else(|| {
+                AppError::Validation("Secret must have metadata.name".to_string())
+            })?;
+        let api = Self::api(cluster_config, namespace).await?;
+        let current = api
+            .get_opt(name)
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        let current_data = current.as_ref().map(encoded_data);
+        let mut diff = diff_data_maps(current_data.as_ref(), &encoded_data(proposed));
+        // type is immutable, so a change here means the apply will be rejected
+        if let (Some(current), Some(to)) = (current.as_ref(), proposed.type_.as_deref()) {
+            let from = current.type_.as_deref().unwrap_or("Opaque");
+            if from != to {
+                diff.type_change = Some(TypeChange {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                });
+            }
+        }
+        Ok(diff)
+    }
+
+    // Applies a manifest as-is: data stays base64 bytes, stringData stays plaintext
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "apply", resource_type = "Secret"))]
+    pub async fn apply(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        proposed: &Secret,
+    ) -> Result<Secret, AppError> {
+        let name =
+            proposed.metadata.name.as_deref().ok_or_else(|| {
+                AppError::Validation("Secret must have metadata.name".to_string())
+            })?;
+        let api = Self::api(cluster_config, namespace).await?;
+        let patch = Secret {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                labels: proposed.metadata.labels.clone(),
+                annotations: proposed.metadata.annotations.clone(),
+                ..Default::default()
+            },
+            type_: proposed.type_.clone(),
+            data: proposed.data.clone(),
+            string_data: proposed.string_data.clone(),
+            ..Default::default()
+        };
+        let params = PatchParams::apply("mayyam").force();
+        api.patch(name, &params, &Patch::Apply(&patch))
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))
+    }
+
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "delete", resource_type = "Secret"))]
     pub async fn delete(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let api = Self::api(cluster_config, namespace).await?;
         api.delete(name, &DeleteParams::default())
             .await
             .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::DELETE, "Secret", name)
+                .with_namespace(namespace),
+        )
+        .await;
         Ok(())
     }
 }
+
+// stringData entries win over data, matching how the API server merges them
+fn encoded_data(secret: &Secret) -> BTreeMap<String, String> {
+    let mut out: BTreeMap<String, String> = secret
+        .data
+        .iter()
+        .flatten()
+        .map(|(k, v)| (k.clone(), BASE64.encode(&v.0)))
+        .collect();
+    for (k, v) in secret.string_data.iter().flatten() {
+        out.insert(k.clone(), BASE64.encode(v.as_bytes()));
+    }
+    out
+}
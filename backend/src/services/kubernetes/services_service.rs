@@ -14,14 +14,18 @@
 
 
 use chrono::Utc;
-use k8s_openapi::api::core::v1::Service;
+use k8s_openapi::api::core::v1::{Service, ServiceSpec};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
 use kube::api::ListParams;
 use kube::config::{Config as KubeConfig, KubeConfigOptions, Kubeconfig};
 use kube::{Api, Client, ResourceExt};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tracing::instrument;
 
 use crate::errors::AppError;
 use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ServicePortInfo {
@@ -44,6 +48,28 @@ pub struct ServiceInfo {
     // pub selector: Option<std::collections::BTreeMap<String, String>>, // Too detailed for list view
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LoadBalancerInfo {
+    pub service_name: String,
+    pub namespace: String,
+    pub external_ips: Vec<String>,
+    pub hostnames: Vec<String>,
+    pub ports: Vec<ServicePortInfo>,
+    pub age: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DnsCheckResult {
+    pub hostname: String,
+    pub resolved_ips: Vec<String>,
+    pub expected_ips: Vec<String>,
+    pub resolves_to_expected: bool,
+    pub error: Option<String>,
+}
+
+// Cloud resolvers can be slow for freshly provisioned load balancers
+const DNS_LOOKUP_TIMEOUT: Duration = Duration::from_secs(5);
+
 pub struct ServicesService;
 
 impl ServicesService {
@@ -86,20 +112,25 @@ impl ServicesService {
         })
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "list_services", resource_type = "Service"))]
     pub async fn list_services(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
-    ) -> Result<Vec<ServiceInfo>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<ServiceInfo>, AppError> {
+        let page = PageRequest::new(page_size, continue_token);
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<Service> = Api::namespaced(client, namespace);
-        let lp = ListParams::default();
+        let lp = page.list_params(ListParams::default());
         let service_list = api.list(&lp).await.map_err(|e| {
             AppError::ExternalService(format!(
                 "Failed to list services in namespace '{}': {}",
                 namespace, e
             ))
         })?;
+        let list_meta = service_list.metadata.clone();
 
         let mut infos = Vec::new();
         for s in service_list {
@@ -107,35 +138,8 @@ impl ServicesService {
             let spec = s.spec.as_ref();
             let status = s.status.as_ref();
 
-            let age = s.metadata.creation_timestamp.as_ref().map_or_else(
-                || "Unknown".to_string(),
-                |ts| {
-                    let creation_time = ts.0;
-                    let duration = Utc::now().signed_duration_since(creation_time);
-                    if duration.num_days() > 0 {
-                        format!("{}d", duration.num_days())
-                    } else if duration.num_hours() > 0 {
-                        format!("{}h", duration.num_hours())
-                    } else if duration.num_minutes() > 0 {
-                        format!("{}m", duration.num_minutes())
-                    } else {
-                        format!("{}s", duration.num_seconds())
-                    }
-                },
-            );
-
-            let ports_info = spec.and_then(|s_spec| s_spec.ports.as_ref()).map_or_else(Vec::new, |k8s_ports| {
-                k8s_ports.iter().map(|p| ServicePortInfo {
-                    name: p.name.clone(),
-                    port: p.port,
-                    target_port: p.target_port.as_ref().map(|tp| match tp {
-                        k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::Int(i) => i.to_string(),
-                        k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::String(s) => s.clone(),
-                    }),
-                    protocol: p.protocol.clone(),
-                    node_port: p.node_port,
-                }).collect()
-            });
+            let age = Self::service_age(&s);
+            let ports_info = Self::port_infos(spec);
 
             let external_ips = spec
                 .and_then(|s_spec| s_spec.external_ips.clone())
@@ -155,9 +159,141 @@ impl ServicesService {
                 age,
             });
         }
+        Ok(page.finish(list_meta, infos))
+    }
+
+    // LoadBalancer services with the addresses the cloud provider assigned.
+    // Services still waiting for an address are included with empty lists.
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "get_loadbalancer_addresses", resource_type = "Service"))]
+    pub async fn get_loadbalancer_addresses(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+    ) -> Result<Vec<LoadBalancerInfo>, AppError> {
+        let client = Self::get_kube_client(cluster_config).await?;
+        let api: Api<Service> = if namespace.is_empty() || namespace == "all" {
+            Api::all(client)
+        } else {
+            Api::namespaced(client, namespace)
+        };
+        let service_list = api.list(&ListParams::default()).await.map_err(|e| {
+            AppError::ExternalService(format!(
+                "Failed to list services in namespace '{}': {}",
+                namespace, e
+            ))
+        })?;
+
+        let infos = service_list
+            .iter()
+            .filter(|s| {
+                s.spec.as_ref().and_then(|spec| spec.type_.as_deref()) == Some("LoadBalancer")
+            })
+            .map(|s| {
+                let ingress = s
+                    .status
+                    .as_ref()
+                    .and_then(|status| status.load_balancer.as_ref())
+                    .and_then(|lb| lb.ingress.as_ref());
+                LoadBalancerInfo {
+                    service_name: s.name_any(),
+                    namespace: s.namespace().unwrap_or_else(|| namespace.to_string()),
+                    external_ips: ingress
+                        .map(|ingress| ingress.iter().filter_map(|i| i.ip.clone()).collect())
+                        .unwrap_or_default(),
+                    hostnames: ingress
+                        .map(|ingress| ingress.iter().filter_map(|i| i.hostname.clone()).collect())
+                        .unwrap_or_default(),
+                    ports: Self::port_infos(s.spec.as_ref()),
+                    age: Self::service_age(s),
+                }
+            })
+            .collect();
         Ok(infos)
     }
 
+    // Resolves each load balancer hostname. When the provider also reported
+    // IPs, the hostname must resolve to at least one of them; providers like
+    // AWS only hand out a hostname, in which case resolving at all is enough.
+    pub async fn check_dns_resolution(info: &LoadBalancerInfo) -> Vec<DnsCheckResult> {
+        let mut results = Vec::with_capacity(info.hostnames.len());
+        for hostname in &info.hostnames {
+            let lookup = tokio::time::timeout(
+                DNS_LOOKUP_TIMEOUT,
+                tokio::net::lookup_host((hostname.as_str(), 0)),
+            )
+            .await;
+            let (resolved_ips, error) = match lookup {
+                Ok(Ok(addrs)) => {
+                    let mut ips: Vec<String> = addrs.map(|a| a.ip().to_string()).collect();
+                    ips.sort();
+                    ips.dedup();
+                    (ips, None)
+                }
+                Ok(Err(e)) => (Vec::new(), Some(e.to_string())),
+                Err(_) => (
+                    Vec::new(),
+                    Some(format!(
+                        "DNS lookup timed out after {}s",
+                        DNS_LOOKUP_TIMEOUT.as_secs()
+                    )),
+                ),
+            };
+
+            let resolves_to_expected = if info.external_ips.is_empty() {
+                !resolved_ips.is_empty()
+            } else {
+                resolved_ips.iter().any(|ip| info.external_ips.contains(ip))
+            };
+            results.push(DnsCheckResult {
+                hostname: hostname.clone(),
+                resolved_ips,
+                expected_ips: info.external_ips.clone(),
+                resolves_to_expected,
+                error,
+            });
+        }
+        results
+    }
+
+    fn service_age(service: &Service) -> String {
+        service.metadata.creation_timestamp.as_ref().map_or_else(
+            || "Unknown".to_string(),
+            |ts| {
+                let creation_time = ts.0;
+                let duration = Utc::now().signed_duration_since(creation_time);
+                if duration.num_days() > 0 {
+                    format!("{}d", duration.num_days())
+                } else if duration.num_hours() > 0 {
+                    format!("{}h", duration.num_hours())
+                } else if duration.num_minutes() > 0 {
+                    format!("{}m", duration.num_minutes())
+                } else {
+                    format!("{}s", duration.num_seconds())
+                }
+            },
+        )
+    }
+
+    fn port_infos(spec: Option<&ServiceSpec>) -> Vec<ServicePortInfo> {
+        spec.and_then(|s_spec| s_spec.ports.as_ref())
+            .map_or_else(Vec::new, |k8s_ports| {
+                k8s_ports
+                    .iter()
+                    .map(|p| ServicePortInfo {
+                        name: p.name.clone(),
+                        port: p.port,
+                        target_port: p.target_port.as_ref().map(|tp| match tp {
+                            IntOrString::Int(i) => i.to_string(),
+                            IntOrString::String(s) => s.clone(),
+                        }),
+                        protocol: p.protocol.clone(),
+                        node_port: p.node_port,
+                    })
+                    .collect()
+            })
+    }
+
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "get_service_details", resource_type = "Service"))]
     pub async fn get_service_details(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -24,9 +24,14 @@ use kube::{Api, Client, ResourceExt};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::collections::BTreeMap;
+use tracing::instrument;
 
 use crate::errors::AppError;
 use crate::models::cluster::KubernetesClusterConfig;
+use crate::models::audit_event::{AuditContext, AuditEvent, AuditOperation};
+use crate::services::analytics::audit_event_emitter::{emit_if_configured, AuditEventEmitter};
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
+use std::sync::Arc;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StatefulSetInfo {
@@ -75,11 +80,20 @@ fn label_selector_to_string(selector: &LabelSelector) -> Option<String> {
     }
 }
 
-pub struct StatefulSetsService;
+pub struct StatefulSetsService {
+    audit_emitter: Option<Arc<AuditEventEmitter>>,
+}
 
 impl StatefulSetsService {
     pub fn new() -> Self {
-        StatefulSetsService {}
+        Self {
+            audit_emitter: None,
+        }
+    }
+
+    pub fn with_audit_emitter(mut self, audit_emitter: Arc<AuditEventEmitter>) -> Self {
+        self.audit_emitter = Some(audit_emitter);
+        self
     }
 
     async fn get_kube_client(cluster_config: &KubernetesClusterConfig) -> Result<Client, AppError> {
@@ -118,20 +132,25 @@ impl StatefulSetsService {
         })
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "list_stateful_sets", resource_type = "StatefulSet"))]
     pub async fn list_stateful_sets(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
-    ) -> Result<Vec<StatefulSetInfo>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<StatefulSetInfo>, AppError> {
+        let page = PageRequest::new(page_size, continue_token);
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<StatefulSet> = Api::namespaced(client, namespace);
-        let lp = ListParams::default();
+        let lp = page.list_params(ListParams::default());
         let sts_list = api.list(&lp).await.map_err(|e| {
             AppError::ExternalService(format!(
                 "Failed to list stateful sets in namespace '{}': {}",
                 namespace, e
             ))
         })?;
+        let list_meta = sts_list.metadata.clone();
 
         let mut infos = Vec::new();
         for sts in sts_list {
@@ -178,9 +197,10 @@ impl StatefulSetsService {
                 images,
             });
         }
-        Ok(infos)
+        Ok(page.finish(list_meta, infos))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "get_stateful_set_details", resource_type = "StatefulSet"))]
     pub async fn get_stateful_set_details(
         &self,
         cluster_config: &KubernetesClusterConfig,
@@ -197,11 +217,13 @@ impl StatefulSetsService {
         })
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "delete_stateful_set", resource_type = "StatefulSet"))]
     pub async fn delete_stateful_set(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<StatefulSet> = Api::namespaced(client, namespace);
@@ -213,15 +235,23 @@ impl StatefulSetsService {
                     name, namespace, e
                 ))
             })?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::DELETE, "StatefulSet", name)
+                .with_namespace(namespace),
+        )
+        .await;
         Ok(())
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "scale_stateful_set", resource_type = "StatefulSet"))]
     pub async fn scale_stateful_set(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
         name: &str,
         replicas: i32,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<StatefulSet> = Api::namespaced(client, namespace);
@@ -236,14 +266,23 @@ impl StatefulSetsService {
                     name, namespace, e
                 ))
             })?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::SCALE, "StatefulSet", name)
+                .with_namespace(namespace)
+                .with_payload(json!({ "replicas": replicas })),
+        )
+        .await;
         Ok(())
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "restart_stateful_set", resource_type = "StatefulSet"))]
     pub async fn restart_stateful_set(
         &self,
         cluster_config: &KubernetesClusterConfig,
         namespace: &str,
         name: &str,
+        audit_ctx: &AuditContext,
     ) -> Result<(), AppError> {
         let client = Self::get_kube_client(cluster_config).await?;
         let api: Api<StatefulSet> = Api::namespaced(client, namespace);
@@ -272,9 +311,16 @@ impl StatefulSetsService {
                     name, namespace, e
                 ))
             })?;
+        emit_if_configured(
+            &self.audit_emitter,
+            AuditEvent::new(audit_ctx, AuditOperation::RESTART, "StatefulSet", name)
+                .with_namespace(namespace),
+        )
+        .await;
         Ok(())
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "get_pods_for_stateful_set", resource_type = "Pod"))]
     pub async fn get_pods_for_stateful_set(
         &self,
         cluster_config: &KubernetesClusterConfig,
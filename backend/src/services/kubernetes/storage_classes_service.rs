@@ -15,24 +15,31 @@
 use crate::errors::AppError;
 use crate::models::cluster::KubernetesClusterConfig;
 use crate::services::kubernetes::client::ClientFactory;
+use crate::services::kubernetes::pagination::{PageRequest, PaginatedResponse};
 use k8s_openapi::api::storage::v1::StorageClass;
 use kube::{api::ListParams, Api};
 use serde_json::Value;
+use tracing::instrument;
 
 pub struct StorageClassesService;
 
 impl StorageClassesService {
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "list_storage_classes", resource_type = "StorageClass"))]
     pub async fn list_storage_classes(
         &self,
         cluster_config: &KubernetesClusterConfig,
-    ) -> Result<Vec<Value>, AppError> {
+        page_size: Option<usize>,
+        continue_token: Option<String>,
+    ) -> Result<PaginatedResponse<Value>, AppError> {
+        let page = PageRequest::new(page_size, continue_token);
         let client = ClientFactory::get_client(cluster_config).await?;
         let storage_classes: Api<StorageClass> = Api::all(client);
 
         let sc_list = storage_classes
-            .list(&ListParams::default())
+            .list(&page.list_params(ListParams::default()))
             .await
             .map_err(|e| AppError::ExternalService(format!("Failed to list StorageClasses: {}", e)))?;
+        let list_meta = sc_list.metadata.clone();
 
         let mut formatted_sc = Vec::new();
         for sc in sc_list {
@@ -41,9 +48,10 @@ impl StorageClassesService {
             }
         }
 
-        Ok(formatted_sc)
+        Ok(page.finish(list_meta, formatted_sc))
     }
 
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), operation = "get_storage_class_details", resource_type = "StorageClass"))]
     pub async fn get_storage_class_details(
         &self,
         cluster_config: &KubernetesClusterConfig,
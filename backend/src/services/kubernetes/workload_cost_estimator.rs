@@ -0,0 +1,294 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet};
+use k8s_openapi::api::core::v1::{Node, Pod};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+use kube::api::{Api, ListParams};
+use kube::ResourceExt;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use tracing::{info, instrument};
+
+use crate::errors::AppError;
+use crate::models::cluster::KubernetesClusterConfig;
+use crate::services::kubernetes::client::ClientFactory;
+use crate::services::kubernetes::metrics_service::{parse_cpu_quantity, parse_memory_quantity};
+use crate::services::kubernetes::scheduling_analyzer::{pod_requests, Requests};
+
+// Cloud providers bill 730 hours in an average month
+const HOURS_PER_MONTH: f64 = 730.0;
+const BYTES_PER_GB: f64 = 1_073_741_824.0;
+
+// Allocated figures are per hour of runtime; cost_per_replica is hourly.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkloadCostEstimate {
+    pub deployment_name: String,
+    pub cpu_hours_allocated: f64,
+    pub memory_gb_hours_allocated: f64,
+    pub estimated_hourly_cost: f64,
+    pub estimated_monthly_cost: f64,
+    pub cost_per_replica: f64,
+}
+
+pub struct WorkloadCostEstimator;
+
+impl WorkloadCostEstimator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    // Each scheduled pod is charged the share of its node's hourly cost taken by
+    // its larger request ratio, so a memory-heavy pod on a small node pays for
+    // the CPU it leaves unusable. Pods not yet on a node are not charged.
+    #[instrument(skip_all, fields(cluster_name = %cluster_config.trace_name(), namespace = %namespace, operation = "estimate_deployment_cost", resource_type = "Deployment"))]
+    pub async fn estimate_deployment_cost(
+        &self,
+        cluster_config: &KubernetesClusterConfig,
+        namespace: &str,
+        deployment_name: &str,
+        node_cost_per_cpu_hour: f64,
+        node_cost_per_gb_memory_hour: f64,
+    ) -> Result<WorkloadCostEstimate, AppError> {
+        let valid = |cost: f64| cost.is_finite() && cost >= 0.0;
+        if !valid(node_cost_per_cpu_hour) || !valid(node_cost_per_gb_memory_hour) {
+            return Err(AppError::Validation(
+                "Node costs must be finite and not negative".to_string(),
+            ));
+        }
+
+        let client = ClientFactory::get_client(cluster_config).await?;
+        let deployments: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+        let deployment = deployments
+            .get(deployment_name)
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        let deployment_uid = deployment.metadata.uid.clone().unwrap_or_default();
+
+        // matchLabels only narrows the listing; ownership decides which pods count,
+        // since other workloads can carry the same labels
+        let selector = deployment
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.selector.match_labels.as_ref())
+            .map(|labels| {
+                labels
+                    .iter()
+                    .map(|(k, v)| format!("{}={}", k, v))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .unwrap_or_default();
+        let list_params = if selector.is_empty() {
+            ListParams::default()
+        } else {
+            ListParams::default().labels(&selector)
+        };
+
+        let replica_sets: Api<ReplicaSet> = Api::namespaced(client.clone(), namespace);
+        let owned_replica_sets: HashSet<String> = replica_sets
+            .list(&list_params)
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?
+            .into_iter()
+            .filter(|rs| is_controlled_by(rs.owner_references(), "Deployment", &deployment_uid))
+            .filter_map(|rs| rs.metadata.uid)
+            .collect();
+
+        let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+        let pod_list = pods
+            .list(&list_params)
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?;
+        let scheduled: Vec<(String, Pod)> = pod_list
+            .into_iter()
+            .filter(|p| {
+                p.owner_references().iter().any(|o| {
+                    o.controller == Some(true)
+                        && o.kind == "ReplicaSet"
+                        && owned_replica_sets.contains(&o.uid)
+                })
+            })
+            .filter(|p| {
+                let phase = p.status.as_ref().and_then(|s| s.phase.as_deref());
+                phase != Some("Succeeded") && phase != Some("Failed")
+            })
+            .filter_map(|p| {
+                let node = p.spec.as_ref().and_then(|s| s.node_name.clone())?;
+                Some((node, p))
+            })
+            .collect();
+
+        let nodes: Api<Node> = Api::all(client);
+        let node_capacity: HashMap<String, (f64, f64)> = nodes
+            .list(&ListParams::default())
+            .await
+            .map_err(|e| AppError::Kubernetes(e.to_string()))?
+            .into_iter()
+            .map(|node| {
+                let allocatable = node
+                    .status
+                    .as_ref()
+                    .and_then(|s| s.allocatable.clone())
+                    .unwrap_or_default();
+                let cpu = allocatable
+                    .get("cpu")
+                    .and_then(parse_cpu_quantity)
+                    .unwrap_or(0.0);
+                let memory = allocatable
+                    .get("memory")
+                    .and_then(parse_memory_quantity)
+                    .unwrap_or(0.0);
+                (node.name_any(), (cpu, memory))
+            })
+            .collect();
+
+        let priced: Vec<(Requests, Option<(f64, f64)>)> = scheduled
+            .iter()
+            .map(|(node_name, pod)| (pod_requests(pod), node_capacity.get(node_name).copied()))
+            .collect();
+        let estimate = price_pods(
+            deployment_name,
+            &priced,
+            node_cost_per_cpu_hour,
+            node_cost_per_gb_memory_hour,
+        );
+
+        info!(
+            deployment = %deployment_name,
+            replicas = scheduled.len(),
+            hourly_cost = estimate.estimated_hourly_cost,
+            "Estimated deployment cost"
+        );
+        Ok(estimate)
+    }
+}
+
+fn is_controlled_by(owners: &[OwnerReference], kind: &str, uid: &str) -> bool {
+    owners
+        .iter()
+        .any(|o| o.controller == Some(true) && o.kind == kind && o.uid == uid)
+}
+
+// Prices pods from their requests and the allocatable (cores, bytes) of the node
+// each one runs on, None when the node is unknown
+fn price_pods(
+    deployment_name: &str,
+    pods: &[(Requests, Option<(f64, f64)>)],
+    node_cost_per_cpu_hour: f64,
+    node_cost_per_gb_memory_hour: f64,
+) -> WorkloadCostEstimate {
+    let mut cpu_hours_allocated = 0.0;
+    let mut memory_gb_hours_allocated = 0.0;
+    let mut estimated_hourly_cost = 0.0;
+    for (requests, node) in pods {
+        let memory_gb = requests.memory_bytes / BYTES_PER_GB;
+        cpu_hours_allocated += requests.cpu_cores;
+        memory_gb_hours_allocated += memory_gb;
+
+        estimated_hourly_cost += match *node {
+            Some((node_cpu, node_memory)) if node_cpu > 0.0 && node_memory > 0.0 => {
+                let node_hourly_cost = node_cpu * node_cost_per_cpu_hour
+                    + (node_memory / BYTES_PER_GB) * node_cost_per_gb_memory_hour;
+                let share = (requests.cpu_cores / node_cpu)
+                    .max(requests.memory_bytes / node_memory)
+                    .min(1.0);
+                share * node_hourly_cost
+            }
+            // Node gone or reporting no capacity: price the requests directly
+            _ => {
+                requests.cpu_cores * node_cost_per_cpu_hour
+                    + memory_gb * node_cost_per_gb_memory_hour
+            }
+        };
+    }
+
+    let cost_per_replica = if pods.is_empty() {
+        0.0
+    } else {
+        estimated_hourly_cost / pods.len() as f64
+    };
+
+    WorkloadCostEstimate {
+        deployment_name: deployment_name.to_string(),
+        cpu_hours_allocated,
+        memory_gb_hours_allocated,
+        estimated_hourly_cost,
+        estimated_monthly_cost: estimated_hourly_cost * HOURS_PER_MONTH,
+        cost_per_replica,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GB: f64 = BYTES_PER_GB;
+
+    fn requests(cpu_cores: f64, memory_gb: f64) -> Requests {
+        Requests {
+            cpu_cores,
+            memory_bytes: memory_gb * GB,
+        }
+    }
+
+    #[test]
+    fn test_pod_pays_for_its_larger_share_of_the_node() {
+        // 4 cores at 0.05 and 16 GB at 0.01 make the node cost 0.36/h. The pod takes
+        // 1/4 of the CPU but 1/2 of the memory, so it pays half.
+        let estimate = price_pods(
+            "api",
+            &[(requests(1.0, 8.0), Some((4.0, 16.0 * GB)))],
+            0.05,
+            0.01,
+        );
+        assert!((estimate.estimated_hourly_cost - 0.18).abs() < 1e-9);
+        assert!((estimate.estimated_monthly_cost - 0.18 * HOURS_PER_MONTH).abs() < 1e-9);
+        assert_eq!(estimate.cpu_hours_allocated, 1.0);
+        assert_eq!(estimate.memory_gb_hours_allocated, 8.0);
+    }
+
+    #[test]
+    fn test_share_is_capped_at_the_whole_node() {
+        let estimate = price_pods(
+            "api",
+            &[(requests(8.0, 1.0), Some((4.0, 16.0 * GB)))],
+            0.05,
+            0.01,
+        );
+        assert!((estimate.estimated_hourly_cost - 0.36).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_unknown_node_prices_requests_directly() {
+        let estimate = price_pods(
+            "api",
+            &[
+                (requests(2.0, 4.0), None),
+                (requests(2.0, 4.0), Some((0.0, 0.0))),
+            ],
+            0.05,
+            0.01,
+        );
+        assert!((estimate.estimated_hourly_cost - 0.28).abs() < 1e-9);
+        assert!((estimate.cost_per_replica - 0.14).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_no_pods_cost_nothing() {
+        let estimate = price_pods("api", &[], 0.05, 0.01);
+        assert_eq!(estimate.estimated_hourly_cost, 0.0);
+        assert_eq!(estimate.cost_per_replica, 0.0);
+    }
+}
@@ -21,8 +21,14 @@ use std::time::Duration;
 
 use crate::errors::AppError;
 use crate::models::llm_provider::LlmProviderModel;
+use crate::models::llm_session::{LlmMessage, LlmSession};
 use crate::repositories::llm_provider::LlmProviderRepository;
+use crate::repositories::llm_session_repository::LlmSessionRepository;
 use crate::repositories::prompt_template::PromptTemplateRepository;
+use crate::utils::template::render_template;
+
+// Tokens held back from the context window for the model's reply
+const RESPONSE_TOKEN_RESERVE: usize = 1024;
 
 #[derive(Debug, Clone)]
 pub struct LlmRequest {
@@ -46,6 +52,7 @@ pub struct LlmResponse {
 pub struct LlmIntegrationService {
     llm_provider_repo: Arc<LlmProviderRepository>,
     prompt_template_repo: Arc<PromptTemplateRepository>,
+    session_repo: Arc<LlmSessionRepository>,
     http_client: Client,
 }
 
@@ -53,10 +60,12 @@ impl LlmIntegrationService {
     pub fn new(
         llm_provider_repo: Arc<LlmProviderRepository>,
         prompt_template_repo: Arc<PromptTemplateRepository>,
+        session_repo: Arc<LlmSessionRepository>,
     ) -> Self {
         Self {
             llm_provider_repo,
             prompt_template_repo,
+            session_repo,
             http_client: Client::builder()
                 .timeout(Duration::from_secs(60)) // Increased timeout for LLM API calls
                 .pool_max_idle_per_host(8)
@@ -99,7 +108,10 @@ impl LlmIntegrationService {
     ) -> Result<String, AppError> {
         let template = self.prompt_template_repo.find_by_id(&template_id).await?;
 
-        self.render_template(&template.prompt_template, variables)
+        Ok(render_template(
+            &template.prompt_template,
+            &variables.unwrap_or(Value::Null),
+        ))
     }
 
     pub async fn generate_with_template(
@@ -128,35 +140,103 @@ impl LlmIntegrationService {
         self.generate_response(provider_id, request).await
     }
 
-    fn render_template(
+    pub async fn create_session(
         &self,
-        template: &str,
-        variables: Option<Value>,
-    ) -> Result<String, AppError> {
-        let mut rendered = template.to_string();
-
-        if let Some(vars) = variables {
-            if let Value::Object(map) = vars {
-                for (key, value) in map {
-                    let placeholder = format!("{{{{{}}}}}", key); // {{variable_name}}
-                    let replacement = match value {
-                        Value::String(s) => s,
-                        Value::Number(n) => n.to_string(),
-                        Value::Bool(b) => b.to_string(),
-                        Value::Array(arr) => serde_json::to_string(&arr).map_err(|e| {
-                            AppError::BadRequest(format!("Invalid array variable: {}", e))
-                        })?,
-                        Value::Object(obj) => serde_json::to_string(&obj).map_err(|e| {
-                            AppError::BadRequest(format!("Invalid object variable: {}", e))
-                        })?,
-                        Value::Null => "null".to_string(),
-                    };
-                    rendered = rendered.replace(&placeholder, &replacement);
-                }
-            }
+        user_id: &str,
+        provider_id: uuid::Uuid,
+        context: Option<String>,
+    ) -> Result<LlmSession, AppError> {
+        self.session_repo
+            .create(user_id, provider_id, context)
+            .await
+    }
+
+    pub async fn get_session(&self, session_id: uuid::Uuid) -> Result<LlmSession, AppError> {
+        self.session_repo
+            .find_by_id(session_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("LLM session {} not found", session_id)))
+    }
+
+    pub async fn delete_session(&self, session_id: uuid::Uuid) -> Result<(), AppError> {
+        if !self.session_repo.delete(session_id).await? {
+            return Err(AppError::NotFound(format!(
+                "LLM session {} not found",
+                session_id
+            )));
         }
+        Ok(())
+    }
 
-        Ok(rendered)
+    pub async fn chat_with_history(
+        &self,
+        session_id: uuid::Uuid,
+        new_message: String,
+    ) -> Result<LlmResponse, AppError> {
+        let mut session = self.get_session(session_id).await?;
+        let provider = self
+            .llm_provider_repo
+            .find_by_id(session.provider_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("LLM provider not found".to_string()))?;
+
+        session.messages.push(LlmMessage::user(new_message));
+
+        // Drop the oldest turns until the transcript fits, always keeping the new message
+        let budget = context_window_tokens(&provider.model_name)
+            .saturating_sub(RESPONSE_TOKEN_RESERVE)
+            .saturating_sub(session.context.as_deref().map_or(0, estimate_tokens));
+        let mut used: usize = session
+            .messages
+            .iter()
+            .map(|m| estimate_tokens(&m.content))
+            .sum();
+        let mut trimmed = 0;
+        while used > budget && session.messages.len() - trimmed > 1 {
+            used -= estimate_tokens(&session.messages[trimmed].content);
+            trimmed += 1;
+        }
+        if trimmed > 0 {
+            tracing::info!(
+                "Trimmed {} messages from LLM session {} to fit the context window",
+                trimmed,
+                session_id
+            );
+            session.messages.drain(..trimmed);
+        }
+
+        // Providers take a single prompt, so the history is rendered as a transcript
+        let prompt = session
+            .messages
+            .iter()
+            .map(|m| {
+                let speaker = if m.role == "assistant" {
+                    "Assistant"
+                } else {
+                    "User"
+                };
+                format!("{}: {}", speaker, m.content)
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        let request = LlmRequest {
+            prompt: format!("{}\n\nAssistant:", prompt),
+            system_prompt: session.context.clone(),
+            temperature: None,
+            max_tokens: None,
+            variables: None,
+        };
+        let response = self.generate_response(session.provider_id, request).await?;
+
+        session
+            .messages
+            .push(LlmMessage::assistant(response.content.clone()));
+        self.session_repo
+            .save_messages(session_id, &session.messages)
+            .await?;
+
+        Ok(response)
     }
 
     async fn call_openai(
@@ -843,3 +923,24 @@ impl LlmIntegrationService {
         })
     }
 }
+
+// Rough estimate of ~4 characters per token, good enough for trimming history
+fn estimate_tokens(text: &str) -> usize {
+    text.len() / 4 + 1
+}
+
+fn context_window_tokens(model_name: &str) -> usize {
+    let model = model_name.to_lowercase();
+    if model.contains("claude") || model.contains("gemini") {
+        200_000
+    } else if model.contains("gpt-4o") || model.contains("gpt-4-turbo") || model.contains("gpt-4.1")
+    {
+        128_000
+    } else if model.contains("deepseek") {
+        64_000
+    } else if model.contains("gpt-3.5") {
+        16_384
+    } else {
+        8_192
+    }
+}
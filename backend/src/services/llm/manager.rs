@@ -16,6 +16,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use crate::errors::AppError;
 use crate::repositories::llm_provider::LlmProviderRepository;
@@ -77,6 +78,18 @@ pub struct ProviderInfo {
     pub capabilities: crate::services::llm::interface::ProviderCapabilities,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderHealthStatus {
+    pub provider_name: String,
+    pub model: String,
+    pub is_healthy: bool,
+    pub latency_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+const HEALTH_CHECK_PROMPT: &str = "Say 'OK'";
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(30);
+
 impl UnifiedLlmManager {
     pub fn new(provider_repo: Arc<LlmProviderRepository>, model_repo: Arc<LlmProviderModelRepository>) -> Self {
         Self {
@@ -269,6 +282,63 @@ impl UnifiedLlmManager {
         Ok(costs)
     }
 
+    /// Send a minimal completion to every registered provider and report its health
+    pub async fn health_check_all_providers(&self) -> HashMap<String, ProviderHealthStatus> {
+        let checks = self.providers.iter().map(|(name, provider)| async move {
+            let request = UnifiedLlmRequest {
+                prompt: HEALTH_CHECK_PROMPT.to_string(),
+                max_tokens: Some(5),
+                temperature: Some(0.0),
+                ..Default::default()
+            };
+            // Providers are keyed by "provider_id:model_name"
+            let configured_model = name.split_once(':').map(|(_, m)| m).unwrap_or_default();
+
+            let started = Instant::now();
+            let result =
+                tokio::time::timeout(HEALTH_CHECK_TIMEOUT, provider.generate(request)).await;
+            let latency_ms = started.elapsed().as_millis() as u64;
+
+            let status = match result {
+                Ok(Ok(response)) => ProviderHealthStatus {
+                    provider_name: provider.provider_name().to_string(),
+                    model: response.model,
+                    is_healthy: true,
+                    latency_ms: Some(latency_ms),
+                    error: None,
+                },
+                Ok(Err(e)) => ProviderHealthStatus {
+                    provider_name: provider.provider_name().to_string(),
+                    model: configured_model.to_string(),
+                    is_healthy: false,
+                    latency_ms: None,
+                    error: Some(e.to_string()),
+                },
+                Err(_) => ProviderHealthStatus {
+                    provider_name: provider.provider_name().to_string(),
+                    model: configured_model.to_string(),
+                    is_healthy: false,
+                    latency_ms: None,
+                    error: Some(format!(
+                        "Health check timed out after {}s",
+                        HEALTH_CHECK_TIMEOUT.as_secs()
+                    )),
+                },
+            };
+            (name.clone(), status)
+        });
+
+        futures::future::join_all(checks).await.into_iter().collect()
+    }
+
+    /// Approximate the token count of `text` for a provider. None of the providers expose
+    /// a tokenization endpoint, so this uses the ~4 tokens per 3 English words rule of thumb.
+    pub fn estimate_tokens(&self, provider_name: &str, text: &str) -> Result<usize, AppError> {
+        self.get_provider(provider_name)?;
+        let words = text.split_whitespace().count();
+        Ok((words * 4 + 2) / 3)
+    }
+
     /// Create a request builder
     pub fn request_builder() -> LlmRequestBuilder {
         LlmRequestBuilder::new()
@@ -0,0 +1,112 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::config::Config;
+use crate::errors::AppError;
+use crate::services::kafka::{ConsumeOptions, KafkaMessage, KafkaService, KafkaTopic};
+
+// Operations common to Kafka and GCP Pub/Sub. Kafka message keys map to Pub/Sub
+// ordering keys and headers to message attributes.
+#[async_trait]
+pub trait MessageBrokerAdapter: Send + Sync {
+    async fn list_topics(&self) -> Result<Vec<String>, AppError>;
+
+    async fn create_topic(&self, topic: &str) -> Result<(), AppError>;
+
+    /// Returns the broker's identifier for the published message.
+    async fn publish(&self, topic: &str, message: &KafkaMessage) -> Result<String, AppError>;
+
+    /// `source` is the topic for Kafka and the subscription for Pub/Sub, which
+    /// ignores `options.group_id` and `options.from_beginning`.
+    async fn consume(
+        &self,
+        source: &str,
+        options: &ConsumeOptions,
+    ) -> Result<Vec<KafkaMessage>, AppError>;
+}
+
+// Binds a KafkaService to one cluster
+pub struct KafkaBrokerAdapter {
+    kafka_service: Arc<KafkaService>,
+    cluster_id: String,
+    config: Config,
+}
+
+impl KafkaBrokerAdapter {
+    pub fn new(kafka_service: Arc<KafkaService>, cluster_id: &str, config: Config) -> Self {
+        Self {
+            kafka_service,
+            cluster_id: cluster_id.to_string(),
+            config,
+        }
+    }
+}
+
+#[async_trait]
+impl MessageBrokerAdapter for KafkaBrokerAdapter {
+    async fn list_topics(&self) -> Result<Vec<String>, AppError> {
+        let topics = self
+            .kafka_service
+            .list_topics(&self.cluster_id, &self.config)
+            .await?;
+        Ok(topics
+            .iter()
+            .filter_map(|t| t["name"].as_str().map(|name| name.to_string()))
+            .collect())
+    }
+
+    async fn create_topic(&self, topic: &str) -> Result<(), AppError> {
+        let topic = KafkaTopic {
+            name: topic.to_string(),
+            partitions: 1,
+            replication_factor: 1,
+            configs: None,
+        };
+        self.kafka_service
+            .create_topic(&self.cluster_id, &topic, &self.config)
+            .await?;
+        Ok(())
+    }
+
+    async fn publish(&self, topic: &str, message: &KafkaMessage) -> Result<String, AppError> {
+        let delivery = self
+            .kafka_service
+            .produce_message(&self.cluster_id, topic, message, &self.config)
+            .await?;
+        Ok(format!("{}:{}", delivery["partition"], delivery["offset"]))
+    }
+
+    async fn consume(
+        &self,
+        source: &str,
+        options: &ConsumeOptions,
+    ) -> Result<Vec<KafkaMessage>, AppError> {
+        let messages = self
+            .kafka_service
+            .consume_messages(&self.cluster_id, source, options, &self.config)
+            .await?;
+        Ok(messages
+            .into_iter()
+            .map(|m| KafkaMessage {
+                key: m["key"].as_str().map(|k| k.to_string()),
+                value: m["value"].as_str().unwrap_or_default().to_string(),
+                headers: serde_json::from_value(m["headers"].clone()).ok(),
+            })
+            .collect())
+    }
+}
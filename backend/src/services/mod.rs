@@ -14,19 +14,31 @@
 
 
 pub mod ai_analysis_service;
+pub mod aurora_cluster_service;
 pub mod aws;
 pub mod aws_account;
 pub mod aws_cost_analytics;
 pub mod aws_dataplane;
 pub mod budget_service;
 pub mod cost_categories;
+pub mod cost_optimization_advisor;
 pub mod database;
 pub mod explain_plan_service;
+pub mod gcp;
 pub mod kafka;
+pub mod kafka_benchmark;
+pub mod kafka_schema_registry;
+pub mod kafka_topic_monitor;
+pub mod kafka_transform;
+pub mod message_broker;
 pub mod mysql_performance_service;
+pub mod notifications;
+pub mod opensearch;
+pub mod pubsub;
 pub mod query_fingerprinting_service;
 pub mod resource_cost_enrichment;
 pub mod slow_query_ingestion_service;
+pub mod task_queue;
 pub mod user;
 
 pub mod analytics;
@@ -43,3 +55,4 @@ pub mod kubernetes;
 pub mod chaos_service;
 pub mod chaos_audit_service;
 pub mod chaos_metrics_service;
+pub mod chaos_scheduler;
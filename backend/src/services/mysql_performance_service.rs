@@ -13,6 +13,7 @@
 // limitations under the License.
 
 
+use crate::errors::AppError;
 use crate::models::mysql_performance_snapshot::MySQLPerformanceSnapshot;
 use crate::repositories::mysql_performance_repository::MySQLPerformanceRepository;
 use crate::repositories::aurora_cluster_repository::AuroraClusterRepository;
@@ -21,10 +22,14 @@ use chrono::{NaiveDateTime, Duration};
 use serde_json;
 use std::collections::HashMap;
 
+// Relative change (percent) beyond which a metric counts as improved or regressed
+const DEFAULT_REGRESSION_THRESHOLD_PERCENT: f64 = 10.0;
+
 #[derive(Clone)]
 pub struct MySQLPerformanceService {
     performance_repo: MySQLPerformanceRepository,
     cluster_repo: AuroraClusterRepository,
+    regression_threshold_percent: f64,
 }
 
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
@@ -51,6 +56,8 @@ pub struct WorkloadMetrics {
     pub insert_commands: i64,
     pub update_commands: i64,
     pub delete_commands: i64,
+    #[serde(default)]
+    pub handler_read_rnd_next: i64,
 }
 
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
@@ -71,6 +78,31 @@ pub struct ReplicationMetrics {
     pub replication_errors: Vec<String>,
 }
 
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct MetricDelta {
+    pub metric: String,
+    pub before: f64,
+    pub after: f64,
+    pub delta: f64,
+    pub percent_change: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Healthy,
+    Degraded,
+    Critical,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PerformanceSnapshotDiff {
+    pub improved_metrics: Vec<MetricDelta>,
+    pub regressed_metrics: Vec<MetricDelta>,
+    pub unchanged_metrics: Vec<MetricDelta>,
+    pub overall_health: HealthStatus,
+}
+
 #[derive(Debug, Clone)]
 pub struct HealthCheckResult {
     pub overall_score: f64,
@@ -87,9 +119,15 @@ impl MySQLPerformanceService {
         Self {
             performance_repo,
             cluster_repo,
+            regression_threshold_percent: DEFAULT_REGRESSION_THRESHOLD_PERCENT,
         }
     }
 
+    pub fn with_regression_threshold(mut self, threshold_percent: f64) -> Self {
+        self.regression_threshold_percent = threshold_percent;
+        self
+    }
+
     pub async fn capture_performance_snapshot(
         &self,
         cluster_id: Uuid,
@@ -119,6 +157,7 @@ impl MySQLPerformanceService {
             innodb_flushes: 0, // TODO: calculate
             temp_tables_disk: 0, // TODO: calculate
             temp_tables_memory: 0, // TODO: calculate
+            handler_read_rnd_next: metrics.workload.handler_read_rnd_next,
             replication_lag: metrics.replication.seconds_behind_master.map(|x| x as f64),
             health_score: health_check.overall_score.to_string(),
             top_issues: serde_json::to_value(health_check.issues)
@@ -386,4 +425,77 @@ impl MySQLPerformanceService {
 
         Ok(summary)
     }
-}
\ No newline at end of file
+
+    /// Compare two snapshots, treating `snapshot_id_a` as the baseline.
+    pub async fn compare_snapshots(
+        &self,
+        snapshot_id_a: Uuid,
+        snapshot_id_b: Uuid,
+    ) -> Result<PerformanceSnapshotDiff, AppError> {
+        let before = self.performance_repo.find_by_id(snapshot_id_a).await?
+            .ok_or_else(|| AppError::NotFound(format!("Performance snapshot not found: {}", snapshot_id_a)))?;
+        let after = self.performance_repo.find_by_id(snapshot_id_b).await?
+            .ok_or_else(|| AppError::NotFound(format!("Performance snapshot not found: {}", snapshot_id_b)))?;
+
+        Ok(diff_snapshots(&before, &after, self.regression_threshold_percent))
+    }
+}
+
+pub fn diff_snapshots(
+    before: &MySQLPerformanceSnapshot,
+    after: &MySQLPerformanceSnapshot,
+    threshold_percent: f64,
+) -> PerformanceSnapshotDiff {
+    // (metric, before, after, higher_is_better)
+    let metrics = [
+        ("threads_running", before.threads_running as f64, after.threads_running as f64, false),
+        ("queries_per_second", before.qps, after.qps, true),
+        ("slow_queries", before.slow_queries_total as f64, after.slow_queries_total as f64, false),
+        ("innodb_buffer_pool_hit_rate", before.innodb_buffer_pool_usage, after.innodb_buffer_pool_usage, true),
+        ("handler_read_rnd_next", before.handler_read_rnd_next as f64, after.handler_read_rnd_next as f64, false),
+    ];
+
+    let mut improved_metrics = Vec::new();
+    let mut regressed_metrics = Vec::new();
+    let mut unchanged_metrics = Vec::new();
+
+    for (metric, before_value, after_value, higher_is_better) in metrics {
+        let delta = after_value - before_value;
+        let percent_change = if before_value != 0.0 {
+            delta / before_value.abs() * 100.0
+        } else if after_value != 0.0 {
+            100.0 * after_value.signum()
+        } else {
+            0.0
+        };
+
+        let metric_delta = MetricDelta {
+            metric: metric.to_string(),
+            before: before_value,
+            after: after_value,
+            delta,
+            percent_change,
+        };
+
+        if percent_change.abs() <= threshold_percent {
+            unchanged_metrics.push(metric_delta);
+        } else if (delta > 0.0) == higher_is_better {
+            improved_metrics.push(metric_delta);
+        } else {
+            regressed_metrics.push(metric_delta);
+        }
+    }
+
+    let overall_health = match regressed_metrics.len() {
+        0 => HealthStatus::Healthy,
+        1 | 2 => HealthStatus::Degraded,
+        _ => HealthStatus::Critical,
+    };
+
+    PerformanceSnapshotDiff {
+        improved_metrics,
+        regressed_metrics,
+        unchanged_metrics,
+        overall_health,
+    }
+}
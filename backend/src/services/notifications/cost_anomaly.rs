@@ -0,0 +1,171 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use crate::models::aws_cost_anomalies::CostAnomalyModel as CostAnomaly;
+use crate::services::notifications::email::EmailNotifier;
+use crate::utils::template::{render_html_template, render_template};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+
+// Overridable through prompt templates in the email_notification category
+const HTML_TEMPLATE_NAME: &str = "cost_anomaly_html";
+const TEXT_TEMPLATE_NAME: &str = "cost_anomaly_text";
+// Anomaly detection waits on delivery, so a slow webhook must not hold it up
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+const DEFAULT_HTML_TEMPLATE: &str = "<h2>Cost {{anomaly_type}} detected for {{service_name}}</h2>\
+<p>Account <b>{{account_id}}</b>, severity <b>{{severity}}</b>.</p>\
+<p>Baseline: {{baseline_cost}}<br/>Actual: {{actual_cost}}<br/>Change: {{percentage_change}}%</p>\
+<p>{{description}}</p>";
+
+const DEFAULT_TEXT_TEMPLATE: &str = "Cost {{anomaly_type}} detected for {{service_name}}\n\
+Account: {{account_id}}\nSeverity: {{severity}}\n\
+Baseline: {{baseline_cost}}\nActual: {{actual_cost}}\nChange: {{percentage_change}}%\n\
+{{description}}";
+
+// Fans a detected cost anomaly out to the configured webhook and email recipients.
+// Delivery failures are logged and never fail anomaly detection.
+pub struct CostAnomalyNotifier {
+    http_client: reqwest::Client,
+    webhook_url: Option<String>,
+    email: Option<(Arc<EmailNotifier>, Vec<String>)>,
+}
+
+impl CostAnomalyNotifier {
+    pub fn new(webhook_url: Option<String>) -> Self {
+        Self {
+            http_client: reqwest::Client::builder()
+                .timeout(WEBHOOK_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
+            webhook_url,
+            email: None,
+        }
+    }
+
+    pub fn with_email(mut self, notifier: Arc<EmailNotifier>, recipients: Vec<String>) -> Self {
+        if !recipients.is_empty() {
+            self.email = Some((notifier, recipients));
+        }
+        self
+    }
+
+    pub async fn notify(&self, anomaly: &CostAnomaly) {
+        let variables = template_variables(anomaly);
+        if let Some(webhook) = &self.webhook_url {
+            self.notify_webhook(webhook, anomaly).await;
+        }
+        if let Some((notifier, recipients)) = &self.email {
+            self.notify_email(notifier, recipients, anomaly, &variables)
+                .await;
+        }
+    }
+
+    async fn notify_webhook(&self, webhook: &str, anomaly: &CostAnomaly) {
+        let result = self.http_client.post(webhook).json(anomaly).send().await;
+        if let Err(e) = result.and_then(|r| r.error_for_status()) {
+            warn!(
+                "Failed to notify webhook for cost anomaly {}: {}",
+                anomaly.id, e
+            );
+        }
+    }
+
+    async fn notify_email(
+        &self,
+        notifier: &EmailNotifier,
+        recipients: &[String],
+        anomaly: &CostAnomaly,
+        variables: &Value,
+    ) {
+        let html = self
+            .render(
+                notifier,
+                HTML_TEMPLATE_NAME,
+                DEFAULT_HTML_TEMPLATE,
+                variables,
+                true,
+            )
+            .await;
+        let text = self
+            .render(
+                notifier,
+                TEXT_TEMPLATE_NAME,
+                DEFAULT_TEXT_TEMPLATE,
+                variables,
+                false,
+            )
+            .await;
+        let subject = format!(
+            "[{}] Cost {} for {} in account {}",
+            anomaly.severity, anomaly.anomaly_type, anomaly.service_name, anomaly.account_id
+        );
+        if let Err(e) = notifier
+            .send_email(
+                recipients.to_vec(),
+                &subject,
+                &html,
+                &text,
+                notifier.source_email(),
+            )
+            .await
+        {
+            warn!("Failed to email cost anomaly {}: {}", anomaly.id, e);
+        }
+    }
+
+    async fn render(
+        &self,
+        notifier: &EmailNotifier,
+        name: &str,
+        default_template: &str,
+        variables: &Value,
+        html: bool,
+    ) -> String {
+        let render = if html {
+            render_html_template
+        } else {
+            render_template
+        };
+        match notifier.find_named_template(name).await {
+            Ok(Some(template)) => render(&template, variables),
+            Ok(None) => render(default_template, variables),
+            Err(e) => {
+                warn!(
+                    "Failed to load email template '{}', using default: {}",
+                    name, e
+                );
+                render(default_template, variables)
+            }
+        }
+    }
+}
+
+fn template_variables(anomaly: &CostAnomaly) -> Value {
+    let fmt = |v: Option<f64>| v.map(|v| format!("{:.2}", v)).unwrap_or_default();
+    json!({
+        "account_id": anomaly.account_id,
+        "service_name": anomaly.service_name,
+        "anomaly_type": anomaly.anomaly_type,
+        "severity": anomaly.severity,
+        "detected_date": anomaly.detected_date,
+        "baseline_cost": fmt(anomaly.baseline_cost),
+        "actual_cost": fmt(Some(anomaly.actual_cost)),
+        "percentage_change": fmt(anomaly.percentage_change),
+        "description": anomaly.description.clone().unwrap_or_default(),
+    })
+}
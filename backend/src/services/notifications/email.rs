@@ -0,0 +1,120 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use crate::config::SesConfig;
+use crate::errors::AppError;
+use crate::repositories::prompt_template::PromptTemplateRepository;
+use aws_config::{BehaviorVersion, Region};
+use aws_sdk_sesv2::types::{Body, Content, Destination, EmailContent, Message};
+use std::sync::Arc;
+use tracing::info;
+
+// Prompt templates in this category are used as email bodies, looked up by name
+pub const EMAIL_TEMPLATE_CATEGORY: &str = "email_notification";
+
+pub struct EmailNotifier {
+    client: aws_sdk_sesv2::Client,
+    source_email: String,
+    template_repo: Option<Arc<PromptTemplateRepository>>,
+}
+
+impl EmailNotifier {
+    pub async fn new(config: &SesConfig) -> Self {
+        let sdk_config = aws_config::defaults(BehaviorVersion::latest())
+            .region(Region::new(config.region.clone()))
+            .load()
+            .await;
+        Self {
+            client: aws_sdk_sesv2::Client::new(&sdk_config),
+            source_email: config.source_email.clone(),
+            template_repo: None,
+        }
+    }
+
+    pub fn with_template_repo(mut self, template_repo: Arc<PromptTemplateRepository>) -> Self {
+        self.template_repo = Some(template_repo);
+        self
+    }
+
+    pub fn source_email(&self) -> &str {
+        &self.source_email
+    }
+
+    /// Returns the SES message id.
+    pub async fn send_email(
+        &self,
+        to: Vec<String>,
+        subject: &str,
+        body_html: &str,
+        body_text: &str,
+        from: &str,
+    ) -> Result<String, AppError> {
+        if to.is_empty() {
+            return Err(AppError::Validation(
+                "At least one recipient is required".to_string(),
+            ));
+        }
+        let recipients = to.len();
+        let content = |data: &str| {
+            Content::builder()
+                .data(data)
+                .charset("UTF-8")
+                .build()
+                .map_err(|e| AppError::Internal(format!("Failed to build email content: {}", e)))
+        };
+        let message = Message::builder()
+            .subject(content(subject)?)
+            .body(
+                Body::builder()
+                    .html(content(body_html)?)
+                    .text(content(body_text)?)
+                    .build(),
+            )
+            .build();
+
+        let output = self
+            .client
+            .send_email()
+            .from_email_address(from)
+            .destination(Destination::builder().set_to_addresses(Some(to)).build())
+            .content(EmailContent::builder().simple(message).build())
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!("Failed to send email via SES: {}", e))
+            })?;
+
+        let message_id = output.message_id().unwrap_or_default().to_string();
+        info!(
+            "Sent email '{}' to {} recipients (message id {})",
+            subject, recipients, message_id
+        );
+        Ok(message_id)
+    }
+
+    // The active email template with this name, or None when no template
+    // repository is attached or the template does not exist
+    pub async fn find_named_template(&self, name: &str) -> Result<Option<String>, AppError> {
+        let Some(repo) = &self.template_repo else {
+            return Ok(None);
+        };
+        let template = repo
+            .find_by_category(EMAIL_TEMPLATE_CATEGORY)
+            .await?
+            .into_iter()
+            .find(|t| t.is_active && t.name == name);
+        Ok(template.map(|t| t.prompt_template))
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use chrono::Utc;
+use reqwest::{Client as HttpClient, Method, StatusCode};
+use serde::Serialize;
+use serde_json::{json, Value};
+use tracing::{info, warn};
+
+use crate::config::OpenSearchConfig;
+use crate::errors::AppError;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RolloverResult {
+    pub old_index: String,
+    pub new_index: String,
+    pub rolled_over: bool,
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReindexResult {
+    pub source_index: String,
+    pub target_index: String,
+    pub documents_reindexed: u64,
+    pub took_ms: u64,
+    pub failures: Vec<Value>,
+}
+
+pub struct OpenSearchService {
+    http_client: HttpClient,
+}
+
+impl OpenSearchService {
+    pub fn new() -> Self {
+        Self {
+            http_client: HttpClient::new(),
+        }
+    }
+
+    pub async fn get_index_mapping(
+        &self,
+        config: &OpenSearchConfig,
+        index_name: &str,
+    ) -> Result<Value, AppError> {
+        let response = self
+            .request(config, Method::GET, &format!("{}/_mapping", index_name), None)
+            .await?;
+        // The response is keyed by the concrete index name, which differs when querying an alias
+        Ok(response
+            .as_object()
+            .and_then(|indices| indices.values().next())
+            .and_then(|index| index.get("mappings"))
+            .cloned()
+            .unwrap_or(response))
+    }
+
+    pub async fn put_index_mapping(
+        &self,
+        config: &OpenSearchConfig,
+        index_name: &str,
+        mapping_json: &Value,
+    ) -> Result<Value, AppError> {
+        info!("Updating mapping for OpenSearch index {} on {}", index_name, config.name);
+        self.request(
+            config,
+            Method::PUT,
+            &format!("{}/_mapping", index_name),
+            Some(mapping_json),
+        )
+        .await
+    }
+
+    pub async fn create_alias(
+        &self,
+        config: &OpenSearchConfig,
+        index_name: &str,
+        alias_name: &str,
+        filter: Option<Value>,
+    ) -> Result<Value, AppError> {
+        let mut add = json!({ "index": index_name, "alias": alias_name });
+        if let Some(filter) = filter {
+            add["filter"] = filter;
+        }
+        info!("Creating alias {} -> {} on {}", alias_name, index_name, config.name);
+        self.request(
+            config,
+            Method::POST,
+            "_aliases",
+            Some(&json!({ "actions": [{ "add": add }] })),
+        )
+        .await
+    }
+
+    pub async fn rollover_alias(
+        &self,
+        config: &OpenSearchConfig,
+        alias_name: &str,
+        max_docs: Option<u64>,
+        max_size_bytes: Option<u64>,
+        dry_run: bool,
+    ) -> Result<RolloverResult, AppError> {
+        let mut conditions = serde_json::Map::new();
+        if let Some(max_docs) = max_docs {
+            conditions.insert("max_docs".to_string(), json!(max_docs));
+        }
+        if let Some(max_size) = max_size_bytes {
+            conditions.insert("max_size".to_string(), json!(format!("{}b", max_size)));
+        }
+        // Without conditions OpenSearch rolls over unconditionally
+        let body = if conditions.is_empty() {
+            json!({})
+        } else {
+            json!({ "conditions": conditions })
+        };
+
+        let mut path = format!("{}/_rollover", alias_name);
+        if dry_run {
+            path.push_str("?dry_run=true");
+        }
+        let response = self
+            .request(config, Method::POST, &path, Some(&body))
+            .await?;
+
+        let result = RolloverResult {
+            old_index: response["old_index"].as_str().unwrap_or_default().to_string(),
+            new_index: response["new_index"].as_str().unwrap_or_default().to_string(),
+            rolled_over: response["rolled_over"].as_bool().unwrap_or(false),
+            dry_run: response["dry_run"].as_bool().unwrap_or(dry_run),
+        };
+        info!(
+            "Rollover of alias {} on {}: {} -> {} (rolled_over={}, dry_run={})",
+            alias_name,
+            config.name,
+            result.old_index,
+            result.new_index,
+            result.rolled_over,
+            result.dry_run
+        );
+        Ok(result)
+    }
+
+    /// Copy an index into a new index created with `target_mapping`, since most mapping
+    /// changes cannot be applied to an existing index. The source index is left untouched.
+    pub async fn reindex_with_mapping_change(
+        &self,
+        config: &OpenSearchConfig,
+        source: &str,
+        target_mapping: Value,
+    ) -> Result<ReindexResult, AppError> {
+        let target = format!("{}-reindexed-{}", source, Utc::now().format("%Y%m%d%H%M%S"));
+
+        self.request(
+            config,
+            Method::PUT,
+            &target,
+            Some(&json!({ "mappings": target_mapping })),
+        )
+        .await?;
+
+        let response = self
+            .request(
+                config,
+                Method::POST,
+                "_reindex?wait_for_completion=true",
+                Some(&json!({
+                    "source": { "index": source },
+                    "dest": { "index": target }
+                })),
+            )
+            .await?;
+
+        let failures = response["failures"].as_array().cloned().unwrap_or_default();
+        if !failures.is_empty() {
+            warn!(
+                "Reindex {} -> {} on {} finished with {} failures",
+                source,
+                target,
+                config.name,
+                failures.len()
+            );
+        }
+
+        Ok(ReindexResult {
+            source_index: source.to_string(),
+            target_index: target,
+            documents_reindexed: response["created"].as_u64().unwrap_or(0)
+                + response["updated"].as_u64().unwrap_or(0),
+            took_ms: response["took"].as_u64().unwrap_or(0),
+            failures,
+        })
+    }
+
+    // Tries each configured host in turn until one accepts the connection
+    async fn request(
+        &self,
+        config: &OpenSearchConfig,
+        method: Method,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<Value, AppError> {
+        let mut last_error = None;
+        for host in &config.hosts {
+            let base = if host.starts_with("http://") || host.starts_with("https://") {
+                host.trim_end_matches('/').to_string()
+            } else {
+                format!("http://{}", host.trim_end_matches('/'))
+            };
+            let mut builder = self
+                .http_client
+                .request(method.clone(), format!("{}/{}", base, path))
+                .basic_auth(&config.username, Some(&config.password));
+            if let Some(body) = body {
+                builder = builder.json(body);
+            }
+
+            let response = match builder.send().await {
+                Ok(response) => response,
+                Err(e) if e.is_connect() || e.is_timeout() => {
+                    warn!("OpenSearch host {} unreachable: {}", host, e);
+                    last_error = Some(e);
+                    continue;
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            let status = response.status();
+            let payload: Value = response.json().await.unwrap_or(Value::Null);
+            if status.is_success() {
+                return Ok(payload);
+            }
+            let reason = payload["error"]["reason"]
+                .as_str()
+                .map(|r| r.to_string())
+                .unwrap_or_else(|| payload.to_string());
+            return Err(match status {
+                StatusCode::NOT_FOUND => AppError::NotFound(reason),
+                StatusCode::BAD_REQUEST => AppError::BadRequest(reason),
+                _ => AppError::ExternalService(format!(
+                    "OpenSearch returned {}: {}",
+                    status, reason
+                )),
+            });
+        }
+
+        Err(match last_error {
+            Some(e) => AppError::ExternalService(format!(
+                "No reachable OpenSearch host for {}: {}",
+                config.name, e
+            )),
+            None => AppError::Config(format!("OpenSearch cluster {} has no hosts", config.name)),
+        })
+    }
+}
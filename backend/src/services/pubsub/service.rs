@@ -0,0 +1,242 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use async_trait::async_trait;
+use google_cloud_googleapis::pubsub::v1::PubsubMessage;
+use google_cloud_pubsub::client::google_cloud_auth::credentials::CredentialsFile;
+use google_cloud_pubsub::client::{Client, ClientConfig};
+use google_cloud_pubsub::subscription::SubscriptionConfig;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+use tracing::{debug, error};
+
+use crate::config::GcpPubSubConfig;
+use crate::errors::AppError;
+use crate::services::kafka::{ConsumeOptions, KafkaMessage};
+use crate::services::message_broker::MessageBrokerAdapter;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PubSubSubscription {
+    pub name: String,
+    pub topic: String,
+    pub ack_deadline_seconds: i32,
+}
+
+pub struct GcpPubSubService {
+    client: Client,
+    project_id: String,
+}
+
+impl GcpPubSubService {
+    pub async fn new(config: &GcpPubSubConfig) -> Result<Self, AppError> {
+        let client_config = match &config.credentials_file {
+            Some(path) => {
+                let credentials =
+                    CredentialsFile::new_from_file(path.clone())
+                        .await
+                        .map_err(|e| {
+                            AppError::Config(format!(
+                                "Failed to read GCP credentials file {}: {}",
+                                path, e
+                            ))
+                        })?;
+                ClientConfig::default().with_credentials(credentials).await
+            }
+            None => ClientConfig::default().with_auth().await,
+        }
+        .map_err(|e| AppError::Auth(format!("Failed to authenticate with GCP: {}", e)))?;
+
+        let client = Client::new(ClientConfig {
+            project_id: Some(config.project_id.clone()),
+            ..client_config
+        })
+        .await
+        .map_err(|e| {
+            AppError::ExternalService(format!("Failed to create Pub/Sub client: {}", e))
+        })?;
+
+        Ok(Self {
+            client,
+            project_id: config.project_id.clone(),
+        })
+    }
+
+    pub fn project_id(&self) -> &str {
+        &self.project_id
+    }
+
+    pub async fn list_subscriptions(&self) -> Result<Vec<PubSubSubscription>, AppError> {
+        let subscriptions = self.client.get_subscriptions(None).await.map_err(|e| {
+            AppError::ExternalService(format!("Failed to list Pub/Sub subscriptions: {}", e))
+        })?;
+
+        let mut result = Vec::with_capacity(subscriptions.len());
+        for subscription in subscriptions {
+            let (topic, config) = subscription.config(None).await.map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to get Pub/Sub subscription {}: {}",
+                    subscription.id(),
+                    e
+                ))
+            })?;
+            result.push(PubSubSubscription {
+                name: subscription.id(),
+                topic: short_name(&topic).to_string(),
+                ack_deadline_seconds: config.ack_deadline_seconds,
+            });
+        }
+        Ok(result)
+    }
+
+    pub async fn create_subscription(
+        &self,
+        subscription: &str,
+        topic: &str,
+        ack_deadline_seconds: i32,
+    ) -> Result<(), AppError> {
+        let config = SubscriptionConfig {
+            ack_deadline_seconds,
+            ..Default::default()
+        };
+        self.client
+            .create_subscription(subscription, topic, config, None)
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "Failed to create Pub/Sub subscription {}: {}",
+                    subscription, e
+                ))
+            })?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl MessageBrokerAdapter for GcpPubSubService {
+    async fn list_topics(&self) -> Result<Vec<String>, AppError> {
+        let topics = self.client.topics(None, None).await.map_err(|e| {
+            AppError::ExternalService(format!("Failed to list Pub/Sub topics: {}", e))
+        })?;
+        Ok(topics.iter().map(|t| short_name(t).to_string()).collect())
+    }
+
+    async fn create_topic(&self, topic: &str) -> Result<(), AppError> {
+        let pubsub_topic = self.client.topic(topic);
+        let exists = pubsub_topic.exists(None).await.map_err(|e| {
+            AppError::ExternalService(format!("Failed to check Pub/Sub topic {}: {}", topic, e))
+        })?;
+        if exists {
+            return Err(AppError::Conflict(format!(
+                "Pub/Sub topic {} already exists",
+                topic
+            )));
+        }
+        pubsub_topic.create(None, None).await.map_err(|e| {
+            AppError::ExternalService(format!("Failed to create Pub/Sub topic {}: {}", topic, e))
+        })
+    }
+
+    async fn publish(&self, topic: &str, message: &KafkaMessage) -> Result<String, AppError> {
+        let mut publisher = self.client.topic(topic).new_publisher(None);
+        let awaiter = publisher.publish(to_pubsub_message(message)).await;
+        let result = awaiter.get().await;
+        publisher.shutdown().await;
+
+        result.map_err(|e| {
+            AppError::ExternalService(format!(
+                "Failed to publish to Pub/Sub topic {}: {}",
+                topic, e
+            ))
+        })
+    }
+
+    async fn consume(
+        &self,
+        source: &str,
+        options: &ConsumeOptions,
+    ) -> Result<Vec<KafkaMessage>, AppError> {
+        let subscription = self.client.subscription(source);
+        let max_messages = options.max_messages.unwrap_or(10) as usize;
+        let deadline = Instant::now() + Duration::from_millis(options.timeout_ms.unwrap_or(5000));
+        let mut messages = Vec::new();
+
+        // Pull blocks until messages are available, so each call is bounded by
+        // the remaining time
+        while messages.len() < max_messages {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+            let batch_size = (max_messages - messages.len()) as i32;
+            let received =
+                match tokio::time::timeout(remaining, subscription.pull(batch_size, None)).await {
+                    Ok(result) => result.map_err(|e| {
+                        AppError::ExternalService(format!(
+                            "Failed to pull from Pub/Sub subscription {}: {}",
+                            source, e
+                        ))
+                    })?,
+                    Err(_) => break,
+                };
+
+            for received_message in received {
+                messages.push(from_pubsub_message(&received_message.message));
+                if let Err(e) = received_message.ack().await {
+                    error!("Failed to ack Pub/Sub message: {:?}", e);
+                }
+            }
+        }
+
+        debug!(
+            "Pulled {} messages from Pub/Sub subscription {}",
+            messages.len(),
+            source
+        );
+        Ok(messages)
+    }
+}
+
+// The API returns fully qualified names, e.g. projects/{project}/topics/{topic}
+fn short_name(name: &str) -> &str {
+    name.rsplit('/').next().unwrap_or(name)
+}
+
+fn to_pubsub_message(message: &KafkaMessage) -> PubsubMessage {
+    PubsubMessage {
+        data: message.value.clone().into_bytes(),
+        attributes: message
+            .headers
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .collect(),
+        ordering_key: message.key.clone().unwrap_or_default(),
+        ..Default::default()
+    }
+}
+
+fn from_pubsub_message(message: &PubsubMessage) -> KafkaMessage {
+    KafkaMessage {
+        key: Some(message.ordering_key.clone()).filter(|k| !k.is_empty()),
+        value: String::from_utf8_lossy(&message.data).to_string(),
+        headers: Some(
+            message
+                .attributes
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect(),
+        ),
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use serde::Deserialize;
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::models::async_task::{
+    Model as AsyncTask, STATUS_CANCELLED, STATUS_CANCELLING, STATUS_COMPLETED, STATUS_FAILED,
+};
+use crate::repositories::async_task::AsyncTaskRepository;
+use crate::services::aws_account::AwsAccountService;
+
+pub const TASK_TYPE_AWS_SYNC: &str = "aws_sync";
+
+// Fallback poll interval in case a wake-up is missed, e.g. tasks enqueued by another instance
+const IDLE_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const CANCEL_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+// Running tasks are heartbeated well within the lease; a task whose heartbeat is older than
+// the lease belongs to a worker that died and is failed by whichever instance notices first
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+const TASK_LEASE: Duration = Duration::from_secs(90);
+
+#[derive(Debug, Deserialize)]
+struct AwsSyncPayload {
+    aws_account_id: Uuid,
+    sync_id: Uuid,
+}
+
+pub struct TaskQueue {
+    repo: Arc<AsyncTaskRepository>,
+    aws_account_service: Arc<AwsAccountService>,
+    wake: Notify,
+}
+
+impl TaskQueue {
+    pub fn new(
+        repo: Arc<AsyncTaskRepository>,
+        aws_account_service: Arc<AwsAccountService>,
+    ) -> Self {
+        Self {
+            repo,
+            aws_account_service,
+            wake: Notify::new(),
+        }
+    }
+
+    pub async fn enqueue(
+        &self,
+        task_type: &str,
+        payload: serde_json::Value,
+    ) -> Result<AsyncTask, AppError> {
+        let task = self.repo.create(task_type, payload).await?;
+        info!("Queued {} task {}", task.task_type, task.id);
+        self.wake.notify_one();
+        Ok(task)
+    }
+
+    pub async fn enqueue_aws_sync(&self, aws_account_id: Uuid) -> Result<AsyncTask, AppError> {
+        self.enqueue(
+            TASK_TYPE_AWS_SYNC,
+            json!({ "aws_account_id": aws_account_id, "sync_id": Uuid::new_v4() }),
+        )
+        .await
+    }
+
+    pub async fn get(&self, task_id: Uuid) -> Result<AsyncTask, AppError> {
+        self.repo
+            .get(task_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Task {} not found", task_id)))
+    }
+
+    pub async fn cancel(&self, task_id: Uuid) -> Result<AsyncTask, AppError> {
+        let task = self
+            .repo
+            .request_cancel(task_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Task {} not found", task_id)))?;
+        if task.status != STATUS_CANCELLING && task.status != STATUS_CANCELLED {
+            return Err(AppError::BadRequest(format!(
+                "Task {} is already {}",
+                task_id, task.status
+            )));
+        }
+        Ok(task)
+    }
+
+    /// Spawn the worker loop. Tasks are processed one at a time, oldest first.
+    pub fn start_worker(self: &Arc<Self>) {
+        let queue = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut last_reap: Option<std::time::Instant> = None;
+            loop {
+                if last_reap.map_or(true, |t| t.elapsed() >= TASK_LEASE) {
+                    queue.fail_abandoned().await;
+                    last_reap = Some(std::time::Instant::now());
+                }
+
+                match queue.repo.claim_next().await {
+                    Ok(Some(task)) => queue.process(task).await,
+                    Ok(None) => {
+                        let _ = tokio::time::timeout(IDLE_POLL_INTERVAL, queue.wake.notified())
+                            .await;
+                    }
+                    Err(e) => {
+                        error!("Failed to poll task queue: {}", e);
+                        tokio::time::sleep(IDLE_POLL_INTERVAL).await;
+                    }
+                }
+            }
+        });
+    }
+
+    async fn fail_abandoned(&self) {
+        let lease = chrono::Duration::seconds(TASK_LEASE.as_secs() as i64);
+        match self.repo.fail_interrupted(lease).await {
+            Ok(0) => {}
+            Ok(n) => warn!("Marked {} abandoned tasks as failed", n),
+            Err(e) => error!("Failed to clean up abandoned tasks: {}", e),
+        }
+    }
+
+    async fn process(&self, task: AsyncTask) {
+        info!("Processing {} task {}", task.task_type, task.id);
+
+        // Dropping the work future on cancellation stops it at its next await point
+        let outcome = tokio::select! {
+            result = self.run(&task) => Some(result),
+            _ = self.wait_for_cancel(task.id) => None,
+            _ = self.heartbeat(task.id) => None,
+        };
+
+        let (status, result) = match outcome {
+            Some(Ok(value)) => (STATUS_COMPLETED, value),
+            Some(Err(e)) => {
+                error!("Task {} failed: {}", task.id, e);
+                (STATUS_FAILED, json!({ "error": e.to_string() }))
+            }
+            None => {
+                info!("Task {} cancelled", task.id);
+                (STATUS_CANCELLED, json!({ "message": "Cancelled by user" }))
+            }
+        };
+        if let Err(e) = self.repo.finish(task.id, status, result).await {
+            error!("Failed to record result for task {}: {}", task.id, e);
+        }
+    }
+
+    async fn run(&self, task: &AsyncTask) -> Result<serde_json::Value, AppError> {
+        match task.task_type.as_str() {
+            TASK_TYPE_AWS_SYNC => {
+                let payload: AwsSyncPayload = serde_json::from_value(task.payload.clone())?;
+                let response = self
+                    .aws_account_service
+                    .sync_account_resources(payload.aws_account_id, payload.sync_id)
+                    .await?;
+                Ok(serde_json::to_value(response)?)
+            }
+            other => Err(AppError::BadRequest(format!("Unknown task type: {}", other))),
+        }
+    }
+
+    // Never returns; keeps the task's lease alive for as long as it is being processed
+    async fn heartbeat(&self, task_id: Uuid) {
+        let mut interval = tokio::time::interval(HEARTBEAT_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = self.repo.heartbeat(task_id).await {
+                warn!("Failed to renew lease on task {}: {}", task_id, e);
+            }
+        }
+    }
+
+    async fn wait_for_cancel(&self, task_id: Uuid) {
+        let mut interval = tokio::time::interval(CANCEL_CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Ok(Some(task)) = self.repo.get(task_id).await {
+                if task.status == STATUS_CANCELLING {
+                    return;
+                }
+            }
+        }
+    }
+}
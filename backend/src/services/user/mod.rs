@@ -0,0 +1,225 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+pub mod oidc;
+
+use std::sync::Arc;
+use tracing;
+use uuid::Uuid;
+
+use crate::errors::AppError;
+use crate::models::user::{CreateUserDto, LoginUserDto, Model as UserModel, UpdateUserDto};
+use crate::repositories::user::UserRepository;
+use oidc::OidcClaims;
+
+pub struct UserService {
+    user_repository: Arc<UserRepository>,
+}
+
+impl UserService {
+    pub fn new(user_repository: Arc<UserRepository>) -> Self {
+        Self { user_repository }
+    }
+
+    pub async fn get_user_by_id(&self, id: Uuid) -> Result<Option<UserModel>, AppError> {
+        tracing::debug!("Fetching user by ID: {}", id);
+        self.user_repository.find_by_id(id).await
+    }
+
+    pub async fn get_user_by_username(
+        &self,
+        username: &str,
+    ) -> Result<Option<UserModel>, AppError> {
+        tracing::debug!("Fetching user by username: {}", username);
+        self.user_repository.find_by_username(username).await
+    }
+
+    pub async fn create_user(&self, user_data: &CreateUserDto) -> Result<UserModel, AppError> {
+        if user_data.password.len() < 8 {
+            tracing::warn!(
+                "Unable to create user: {}",
+                "Password must be at least 8 characters long"
+            );
+            return Err(AppError::Validation(
+                "Password must be at least 8 characters long".to_string(),
+            ));
+        }
+
+        tracing::info!("Creating user: {:?}", &user_data.username);
+        self.user_repository.create(user_data).await
+    }
+
+    pub async fn authenticate_user(
+        &self,
+        login_data: &LoginUserDto,
+    ) -> Result<Option<UserModel>, AppError> {
+        tracing::info!("Authenticating user: {:?}", &login_data.username);
+        self.user_repository.verify_credentials(login_data).await
+    }
+
+    pub async fn update_user(
+        &self,
+        id: Uuid,
+        user_data: &UpdateUserDto,
+    ) -> Result<UserModel, AppError> {
+        tracing::debug!("Updating user: {:?}", &user_data.first_name);
+
+        if let Some(password) = &user_data.password {
+            if password.len() < 8 {
+                tracing::warn!(
+                    "Unable to update user: {}",
+                    "Password must be at least 8 characters long"
+                );
+                return Err(AppError::Validation(
+                    "Password must be at least 8 characters long".to_string(),
+                ));
+            }
+        }
+
+        self.user_repository.update(id, user_data).await
+    }
+
+    pub async fn list_users(
+        &self,
+        page: u64,
+        page_size: u64,
+    ) -> Result<(Vec<UserModel>, u64), AppError> {
+        let offset = page * page_size;
+        let users = self.user_repository.list_users(page_size, offset).await?;
+        let total = self.user_repository.count_users().await?;
+
+        Ok((users, total))
+    }
+
+    // Matches an OIDC identity to a local user by (issuer, subject). An unlinked
+    // local account is only linked by email when the provider has verified that
+    // address; otherwise the user is created on first login. The provider's current
+    // groups are added to the user's local roles for this login only, so a group
+    // removed at the provider no longer grants its role.
+    pub async fn find_or_create_oidc_user(
+        &self,
+        claims: &OidcClaims,
+    ) -> Result<UserModel, AppError> {
+        let linked = self
+            .user_repository
+            .find_by_oidc_identity(&claims.issuer, &claims.subject)
+            .await?;
+        let user = match linked {
+            Some(user) => user,
+            None => self.link_or_create_oidc_user(claims).await?,
+        };
+
+        if !user.active {
+            return Err(AppError::Auth(format!(
+                "User {} is disabled",
+                user.username
+            )));
+        }
+
+        let roles = merge_roles(&user.roles, &claims.groups);
+        self.user_repository
+            .record_external_login(user.id, &claims.issuer, &claims.subject, &roles)
+            .await
+    }
+
+    async fn link_or_create_oidc_user(&self, claims: &OidcClaims) -> Result<UserModel, AppError> {
+        let email = claims.email.clone().ok_or_else(|| {
+            AppError::Auth("OIDC provider did not return an email claim".to_string())
+        })?;
+
+        match self.user_repository.find_by_email(&email).await? {
+            Some(user) => {
+                // Anyone who can claim an unverified address at the provider would
+                // otherwise take over the local account
+                if !claims.email_verified {
+                    return Err(AppError::Auth(format!(
+                        "Email {} is not verified by the identity provider",
+                        email
+                    )));
+                }
+                if user.oidc_subject.is_some() {
+                    return Err(AppError::Auth(format!(
+                        "User {} is linked to a different identity provider account",
+                        user.username
+                    )));
+                }
+                tracing::info!(
+                    "Linking user {} to OIDC subject {} of {}",
+                    user.username,
+                    claims.subject,
+                    claims.issuer
+                );
+                Ok(user)
+            }
+            None => {
+                let username = claims
+                    .preferred_username
+                    .clone()
+                    .or_else(|| email.split('@').next().map(str::to_string))
+                    .unwrap_or_else(|| claims.subject.clone());
+                if self
+                    .user_repository
+                    .find_by_username(&username)
+                    .await?
+                    .is_some()
+                {
+                    return Err(AppError::Auth(format!(
+                        "Username {} is already taken by another account",
+                        username
+                    )));
+                }
+                tracing::info!(
+                    "Creating user {} from OIDC subject {}",
+                    username,
+                    claims.subject
+                );
+
+                // Local password login stays unusable for provider-managed users
+                let user_data = CreateUserDto {
+                    username,
+                    email: email.clone(),
+                    password: format!("{}{}", Uuid::new_v4(), Uuid::new_v4()),
+                    first_name: claims.given_name.clone(),
+                    last_name: claims.family_name.clone(),
+                    is_admin: None,
+                    permissions: None,
+                };
+                self.user_repository.create(&user_data).await
+            }
+        }
+    }
+
+    pub async fn delete_user(&self, id: Uuid) -> Result<(), AppError> {
+        tracing::warn!("Deleting user: {}", id);
+        self.user_repository.delete(id).await
+    }
+}
+
+// Provider groups add to the roles granted locally; they never remove them.
+// The result is not stored, it is recomputed from the current groups on every login.
+fn merge_roles(existing: &str, groups: &[String]) -> Vec<String> {
+    let mut roles: Vec<String> = existing
+        .split(',')
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+        .map(str::to_string)
+        .collect();
+    for group in groups {
+        if !roles.contains(group) {
+            roles.push(group.clone());
+        }
+    }
+    roles
+}
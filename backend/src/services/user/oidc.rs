@@ -0,0 +1,265 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD as BASE64_URL, Engine as _};
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use openidconnect::core::{CoreClient, CoreIdToken, CoreProviderMetadata, CoreResponseType};
+use openidconnect::reqwest::async_http_client;
+use openidconnect::{
+    AuthenticationFlow, AuthorizationCode, ClientId, ClientSecret, CsrfToken, IssuerUrl, Nonce,
+    OAuth2TokenResponse, RedirectUrl, RefreshToken, Scope, TokenResponse,
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tracing::{debug, info};
+
+use crate::config::AuthConfig;
+use crate::errors::AppError;
+
+// How long a login attempt may take between redirect and callback
+pub const PENDING_LOGIN_TTL: Duration = Duration::from_secs(600);
+
+// Carries the state and nonce of a login attempt from the redirect to the callback
+pub const OIDC_STATE_COOKIE: &str = "mayyam_oidc_state";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OidcTokens {
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    pub id_token: Option<String>,
+    pub expires_in: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OidcClaims {
+    pub issuer: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub email_verified: bool,
+    pub preferred_username: Option<String>,
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+    pub groups: Vec<String>,
+    pub tokens: OidcTokens,
+}
+
+// `groups` is not a standard claim, so it is read from the already verified
+// ID token payload rather than through the typed claims
+#[derive(Debug, Default, Deserialize)]
+struct GroupsClaim {
+    #[serde(default)]
+    groups: Vec<String>,
+}
+
+// The login state is kept in a signed cookie rather than in memory, so the callback
+// can land on any instance and nothing accumulates for abandoned logins
+#[derive(Debug, Serialize, Deserialize)]
+struct LoginState {
+    state: String,
+    nonce: String,
+    exp: i64,
+}
+
+pub struct OidcLoginRedirect {
+    pub url: String,
+    pub state_cookie: String,
+    pub secure_cookie: bool,
+}
+
+pub struct OidcProvider {
+    client: CoreClient,
+    state_key: Vec<u8>,
+    secure_cookie: bool,
+}
+
+impl OidcProvider {
+    // Runs OpenID discovery against the configured issuer
+    pub async fn discover(config: &AuthConfig) -> Result<Self, AppError> {
+        let required = |value: &Option<String>, name: &str| {
+            value
+                .clone()
+                .filter(|v| !v.is_empty())
+                .ok_or_else(|| AppError::Config(format!("auth.{} is required for OIDC", name)))
+        };
+        let issuer_url = required(&config.oidc_issuer_url, "oidc_issuer_url")?;
+        let client_id = required(&config.oidc_client_id, "oidc_client_id")?;
+        let redirect_uri = required(&config.oidc_redirect_uri, "oidc_redirect_uri")?;
+
+        let issuer = IssuerUrl::new(issuer_url.clone())
+            .map_err(|e| AppError::Config(format!("Invalid OIDC issuer URL: {}", e)))?;
+        let secure_cookie = redirect_uri.starts_with("https://");
+        let redirect = RedirectUrl::new(redirect_uri)
+            .map_err(|e| AppError::Config(format!("Invalid OIDC redirect URI: {}", e)))?;
+
+        let metadata = CoreProviderMetadata::discover_async(issuer, async_http_client)
+            .await
+            .map_err(|e| {
+                AppError::ExternalService(format!(
+                    "OIDC discovery failed for {}: {}",
+                    issuer_url, e
+                ))
+            })?;
+
+        let client = CoreClient::from_provider_metadata(
+            metadata,
+            ClientId::new(client_id),
+            config
+                .oidc_client_secret
+                .clone()
+                .filter(|s| !s.is_empty())
+                .map(ClientSecret::new),
+        )
+        .set_redirect_uri(redirect);
+
+        info!("OIDC provider discovered at {}", issuer_url);
+        Ok(Self {
+            client,
+            state_key: config.jwt_secret.as_bytes().to_vec(),
+            secure_cookie,
+        })
+    }
+
+    // Builds the provider login URL. The state and nonce go into a signed cookie
+    // so the callback can be matched to this login attempt.
+    pub fn get_authorization_url(&self) -> Result<OidcLoginRedirect, AppError> {
+        let (url, state, nonce) = self
+            .client
+            .authorize_url(
+                AuthenticationFlow::<CoreResponseType>::AuthorizationCode,
+                CsrfToken::new_random,
+                Nonce::new_random,
+            )
+            .add_scope(Scope::new("email".to_string()))
+            .add_scope(Scope::new("profile".to_string()))
+            .url();
+
+        let login_state = LoginState {
+            state: state.secret().clone(),
+            nonce: nonce.secret().clone(),
+            exp: chrono::Utc::now().timestamp() + PENDING_LOGIN_TTL.as_secs() as i64,
+        };
+        let state_cookie = encode(
+            &Header::new(Algorithm::HS256),
+            &login_state,
+            &EncodingKey::from_secret(&self.state_key),
+        )
+        .map_err(|e| AppError::Internal(format!("Failed to sign OIDC login state: {}", e)))?;
+
+        Ok(OidcLoginRedirect {
+            url: url.to_string(),
+            state_cookie,
+            secure_cookie: self.secure_cookie,
+        })
+    }
+
+    pub async fn exchange_code_for_token(
+        &self,
+        code: &str,
+        state: &str,
+        state_cookie: Option<&str>,
+    ) -> Result<OidcClaims, AppError> {
+        // Signature and expiry are checked by the decode
+        let login_state = state_cookie
+            .and_then(|cookie| {
+                decode::<LoginState>(
+                    cookie,
+                    &DecodingKey::from_secret(&self.state_key),
+                    &Validation::new(Algorithm::HS256),
+                )
+                .ok()
+            })
+            .map(|data| data.claims)
+            .filter(|login| login.state == state)
+            .ok_or_else(|| AppError::Auth("Unknown or expired OIDC login state".to_string()))?;
+        let nonce = Nonce::new(login_state.nonce);
+
+        let response = self
+            .client
+            .exchange_code(AuthorizationCode::new(code.to_string()))
+            .request_async(async_http_client)
+            .await
+            .map_err(|e| AppError::Auth(format!("OIDC code exchange failed: {}", e)))?;
+
+        let id_token = response
+            .id_token()
+            .ok_or_else(|| AppError::Auth("OIDC provider returned no ID token".to_string()))?;
+        let claims = id_token
+            .claims(&self.client.id_token_verifier(), &nonce)
+            .map_err(|e| AppError::Auth(format!("Invalid OIDC ID token: {}", e)))?;
+        let raw_id_token = serialize_id_token(id_token)?;
+
+        debug!("OIDC login for subject {}", claims.subject().as_str());
+        Ok(OidcClaims {
+            issuer: claims.issuer().as_str().to_string(),
+            subject: claims.subject().as_str().to_string(),
+            email: claims.email().map(|e| e.as_str().to_string()),
+            email_verified: claims.email_verified() == Some(true),
+            preferred_username: claims.preferred_username().map(|u| u.as_str().to_string()),
+            given_name: claims
+                .given_name()
+                .and_then(|n| n.get(None))
+                .map(|n| n.as_str().to_string()),
+            family_name: claims
+                .family_name()
+                .and_then(|n| n.get(None))
+                .map(|n| n.as_str().to_string()),
+            groups: groups_from_id_token(&raw_id_token),
+            tokens: OidcTokens {
+                access_token: response.access_token().secret().clone(),
+                refresh_token: response.refresh_token().map(|t| t.secret().clone()),
+                id_token: Some(raw_id_token),
+                expires_in: response.expires_in().map(|d| d.as_secs()),
+            },
+        })
+    }
+
+    pub async fn refresh_access_token(&self, refresh_token: &str) -> Result<OidcTokens, AppError> {
+        let response = self
+            .client
+            .exchange_refresh_token(&RefreshToken::new(refresh_token.to_string()))
+            .request_async(async_http_client)
+            .await
+            .map_err(|e| AppError::Auth(format!("OIDC token refresh failed: {}", e)))?;
+
+        Ok(OidcTokens {
+            access_token: response.access_token().secret().clone(),
+            // Providers without rotation keep the original refresh token valid
+            refresh_token: response
+                .refresh_token()
+                .map(|t| t.secret().clone())
+                .or_else(|| Some(refresh_token.to_string())),
+            id_token: response.id_token().map(serialize_id_token).transpose()?,
+            expires_in: response.expires_in().map(|d| d.as_secs()),
+        })
+    }
+}
+
+fn serialize_id_token(id_token: &CoreIdToken) -> Result<String, AppError> {
+    match serde_json::to_value(id_token)? {
+        serde_json::Value::String(jwt) => Ok(jwt),
+        _ => Err(AppError::Internal(
+            "Unexpected ID token encoding".to_string(),
+        )),
+    }
+}
+
+fn groups_from_id_token(jwt: &str) -> Vec<String> {
+    jwt.split('.')
+        .nth(1)
+        .and_then(|payload| BASE64_URL.decode(payload).ok())
+        .and_then(|bytes| serde_json::from_slice::<GroupsClaim>(&bytes).ok())
+        .unwrap_or_default()
+        .groups
+}
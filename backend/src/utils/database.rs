@@ -16,13 +16,15 @@
 use crate::config::Config;
 use crate::errors::AppError;
 use crate::models::database::Model as DatabaseConnectionModel;
+use crate::models::database::{ConnectionStats, ConnectionTestResult};
 use aes_gcm::{
     aead::{Aead, KeyInit},
     Aes256Gcm, Nonce,
 };
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use sea_orm::{ConnectionTrait, DbBackend, Statement};
-use sea_orm::{Database, DatabaseConnection, DbErr};
+use sea_orm::{Database, DatabaseConnection, DbErr, RuntimeErr, SqlxMySqlConnector};
+use sqlx::mysql::{MySqlConnectOptions, MySqlPoolOptions, MySqlSslMode};
 use tracing::{error, info};
 
 /// Connect to the database using configuration settings
@@ -69,17 +71,117 @@ pub async fn connect_to_specific_postgres(
 pub async fn connect_to_specific_mysql(
     config: &crate::config::MySQLConfig,
 ) -> Result<DatabaseConnection, DbErr> {
-    let database_url = format!(
-        "mysql://{}:{}@{}:{}/{}",
-        config.username, config.password, config.host, config.port, config.database
-    );
+    let options = mysql_connect_options(config).map_err(|e| DbErr::Custom(e.to_string()))?;
 
     info!("Connecting to MySQL database: {}", config.name);
-    info!(database_url);
-    info!("OOOOOH : {}", config.name);
-    let conn = Database::connect(database_url).await?;
+    let pool = MySqlPoolOptions::new()
+        .connect_with(options)
+        .await
+        .map_err(|e| DbErr::Conn(RuntimeErr::SqlxError(e)))?;
 
-    Ok(conn)
+    Ok(SqlxMySqlConnector::from_sqlx_mysql_pool(pool))
+}
+
+/// Build sqlx connect options for a configured MySQL server, including TLS settings
+pub fn mysql_connect_options(
+    config: &crate::config::MySQLConfig,
+) -> Result<MySqlConnectOptions, AppError> {
+    let mut options = MySqlConnectOptions::new()
+        .host(&config.host)
+        .port(config.port)
+        .username(&config.username)
+        .password(&config.password)
+        .database(&config.database);
+
+    if let Some(ssl_mode) = config.ssl_mode.as_deref() {
+        let mode = match ssl_mode {
+            "disabled" => MySqlSslMode::Disabled,
+            "required" => MySqlSslMode::Required,
+            "verify_ca" => MySqlSslMode::VerifyCa,
+            "verify_identity" => MySqlSslMode::VerifyIdentity,
+            other => {
+                return Err(AppError::Config(format!(
+                    "Unsupported MySQL ssl_mode '{}'",
+                    other
+                )))
+            }
+        };
+        options = options.ssl_mode(mode);
+    }
+    if let Some(ca) = &config.ssl_ca {
+        options = options.ssl_ca(ca);
+    }
+    if let Some(cert) = &config.ssl_cert {
+        options = options.ssl_client_cert(cert);
+    }
+    if let Some(key) = &config.ssl_key {
+        options = options.ssl_client_key(key);
+    }
+
+    Ok(options)
+}
+
+/// Connect to a configured MySQL server and report version, connection and TLS details
+pub async fn test_connection(
+    config: &crate::config::MySQLConfig,
+) -> Result<ConnectionTestResult, AppError> {
+    config.validate_ssl().map_err(AppError::Config)?;
+    let options = mysql_connect_options(config)?;
+
+    let start_time = std::time::Instant::now();
+    let pool = MySqlPoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await
+        .map_err(|e| {
+            AppError::ExternalService(format!(
+                "Failed to connect to MySQL '{}': {}",
+                config.name, e
+            ))
+        })?;
+    let latency_ms = start_time.elapsed().as_millis() as u64;
+
+    let query_err =
+        |e: sqlx::Error| AppError::ExternalService(format!("MySQL query failed: {}", e));
+    let server_info_sql =
+        "SELECT VERSION(), @@character_set_server, CAST(@@max_connections AS SIGNED)";
+    let (server_version, server_encoding, max_connections): (String, String, i64) =
+        sqlx::query_as(server_info_sql)
+            .fetch_one(&pool)
+            .await
+            .map_err(query_err)?;
+    let threads_connected: Option<(String, String)> =
+        sqlx::query_as("SHOW GLOBAL STATUS LIKE 'Threads_connected'")
+            .fetch_optional(&pool)
+            .await
+            .map_err(query_err)?;
+    let ssl_cipher: Option<(String, String)> =
+        sqlx::query_as("SHOW SESSION STATUS LIKE 'Ssl_cipher'")
+            .fetch_optional(&pool)
+            .await
+            .map_err(query_err)?;
+    pool.close().await;
+
+    let ssl_in_use = ssl_cipher.map_or(false, |(_, cipher)| !cipher.is_empty());
+    Ok(ConnectionTestResult {
+        success: true,
+        message: format!(
+            "Successfully connected to MySQL database {}{}",
+            config.name,
+            if ssl_in_use { " over TLS" } else { "" }
+        ),
+        latency_ms: Some(latency_ms),
+        version_info: Some(server_version.clone()),
+        connection_stats: Some(ConnectionStats {
+            max_connections: max_connections as i32,
+            current_connections: threads_connected
+                .and_then(|(_, value)| value.parse().ok())
+                .unwrap_or(0),
+            ssl_in_use,
+            server_encoding,
+            server_version,
+        }),
+    })
 }
 
 /// Decrypt a password using the application's encryption key
@@ -510,6 +612,7 @@ pub async fn ensure_aws_accounts_table(db: &DatabaseConnection) -> Result<(), Db
             sso_profile VARCHAR(100),
             web_identity_token_file VARCHAR(255),
             session_name VARCHAR(100),
+            anomaly_detection_config JSONB,
             last_synced_at TIMESTAMPTZ,
             created_at TIMESTAMPTZ NOT NULL,
             updated_at TIMESTAMPTZ NOT NULL
@@ -561,6 +664,12 @@ pub async fn ensure_aws_accounts_table(db: &DatabaseConnection) -> Result<(), Db
                           WHERE table_name = 'aws_accounts' AND column_name = 'session_name') THEN
                 ALTER TABLE aws_accounts ADD COLUMN session_name VARCHAR(100);
             END IF;
+
+            -- Add anomaly_detection_config column if missing
+            IF NOT EXISTS (SELECT 1 FROM information_schema.columns 
+                          WHERE table_name = 'aws_accounts' AND column_name = 'anomaly_detection_config') THEN
+                ALTER TABLE aws_accounts ADD COLUMN anomaly_detection_config JSONB;
+            END IF;
         END $$;
     "#;
 
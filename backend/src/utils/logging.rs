@@ -13,11 +13,14 @@
 // limitations under the License.
 
 
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::Resource;
 use std::env;
 use tracing_appender::rolling::{RollingFileAppender, Rotation};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
-pub fn init_logger() {
+pub fn init_logger(otel_endpoint: Option<&str>) {
     // Check if we're running in debug or release mode
     let is_debug = cfg!(debug_assertions);
 
@@ -41,10 +44,24 @@ pub fn init_logger() {
         .or_else(|_| EnvFilter::try_new(&format!("mayyam={},actix_web=info", log_level)))
         .unwrap();
 
+    // Export spans to an OpenTelemetry collector when an endpoint is configured.
+    // The subscriber isn't up yet, so a failure can only go to stderr.
+    let otel_layer = otel_endpoint.and_then(|endpoint| match build_otel_tracer(endpoint) {
+        Ok(tracer) => Some(tracing_opentelemetry::layer().with_tracer(tracer)),
+        Err(e) => {
+            eprintln!(
+                "Failed to initialize OpenTelemetry exporter for {}: {}",
+                endpoint, e
+            );
+            None
+        }
+    });
+
     // Initialize the tracing subscriber with both console and file outputs
     tracing_subscriber::registry()
         .with(tracing_subscriber::fmt::layer().with_writer(std::io::stdout))
         .with(tracing_subscriber::fmt::layer().with_writer(non_blocking))
+        .with(otel_layer)
         .with(filter)
         .init();
 
@@ -55,3 +72,25 @@ pub fn init_logger() {
         GUARD = Some(_guard);
     }
 }
+
+fn build_otel_tracer(
+    endpoint: &str,
+) -> Result<opentelemetry_sdk::trace::Tracer, opentelemetry::trace::TraceError> {
+    opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(endpoint),
+        )
+        .with_trace_config(
+            opentelemetry_sdk::trace::config()
+                .with_resource(Resource::new(vec![KeyValue::new("service.name", "mayyam")])),
+        )
+        .install_batch(opentelemetry_sdk::runtime::Tokio)
+}
+
+// Flushes spans still buffered by the OpenTelemetry batch exporter
+pub fn shutdown_tracing() {
+    opentelemetry::global::shutdown_tracer_provider();
+}
@@ -0,0 +1,258 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+// Runner for numbered SQL migrations (`001_*.sql`) against user-managed target databases.
+// Separate from `utils::migrations`, which applies Mayyam's own embedded schema.
+
+use async_trait::async_trait;
+use regex::Regex;
+use sea_orm::{
+    ConnectionTrait, DatabaseConnection, DbBackend, Statement, TransactionTrait,
+};
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+use crate::errors::AppError;
+
+#[derive(Debug, Clone)]
+pub struct MigrationFile {
+    pub version: i64,
+    pub name: String,
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Default)]
+pub struct MigrationReport {
+    pub last_applied_version: Option<i64>,
+    pub applied: Vec<String>,
+    pub dry_run: bool,
+    // False when a dry run only listed the pending migrations
+    pub executed: bool,
+}
+
+/// Discover `NNN_description.sql` files in `dir`, ordered by version.
+pub fn discover_migrations(dir: &Path) -> Result<Vec<MigrationFile>, AppError> {
+    let pattern = Regex::new(r"^(\d+)_.+\.sql$").expect("valid migration filename regex");
+    let entries = std::fs::read_dir(dir).map_err(|e| {
+        AppError::Config(format!(
+            "Failed to read migrations directory '{}': {}",
+            dir.display(),
+            e
+        ))
+    })?;
+
+    let mut migrations = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        let caps = match pattern.captures(&name) {
+            Some(caps) => caps,
+            None => continue,
+        };
+        let version: i64 = caps[1].parse().map_err(|_| {
+            AppError::Validation(format!("Invalid migration version in '{}'", name))
+        })?;
+        migrations.push(MigrationFile {
+            version,
+            name,
+            path: entry.path(),
+        });
+    }
+
+    migrations.sort_by_key(|m| m.version);
+    for pair in migrations.windows(2) {
+        if pair[0].version == pair[1].version {
+            return Err(AppError::Validation(format!(
+                "Duplicate migration version {} ('{}' and '{}')",
+                pair[0].version, pair[0].name, pair[1].name
+            )));
+        }
+    }
+
+    Ok(migrations)
+}
+
+#[async_trait]
+pub trait MigrationRunner: Send + Sync {
+    fn connection(&self) -> &DatabaseConnection;
+
+    fn backend(&self) -> DbBackend;
+
+    /// DDL creating the `schema_migrations` tracking table if missing.
+    fn create_table_sql(&self) -> &'static str;
+
+    /// Parameterised insert of (version, name) into `schema_migrations`.
+    fn record_sql(&self) -> &'static str;
+
+    /// Whether DDL can be rolled back, which a dry run relies on.
+    fn transactional_ddl(&self) -> bool {
+        true
+    }
+
+    async fn ensure_migrations_table(&self) -> Result<(), AppError> {
+        self.connection()
+            .execute(Statement::from_string(
+                self.backend(),
+                self.create_table_sql().to_string(),
+            ))
+            .await?;
+        Ok(())
+    }
+
+    async fn last_applied_version(&self) -> Result<Option<i64>, AppError> {
+        let row = self
+            .connection()
+            .query_one(Statement::from_string(
+                self.backend(),
+                "SELECT MAX(version) AS version FROM schema_migrations".to_string(),
+            ))
+            .await?;
+        Ok(row.and_then(|r| r.try_get::<Option<i64>>("", "version").ok().flatten()))
+    }
+
+    /// Apply every migration newer than the last applied version inside one transaction.
+    /// With `dry_run` the transaction is rolled back after all statements succeed, or,
+    /// without transactional DDL, the pending migrations are listed but not executed.
+    async fn run(
+        &self,
+        migrations: &[MigrationFile],
+        dry_run: bool,
+    ) -> Result<MigrationReport, AppError> {
+        self.ensure_migrations_table().await?;
+        let last_applied_version = self.last_applied_version().await?;
+
+        let pending: Vec<&MigrationFile> = migrations
+            .iter()
+            .filter(|m| last_applied_version.map_or(true, |v| m.version > v))
+            .collect();
+
+        let mut report = MigrationReport {
+            last_applied_version,
+            applied: Vec::new(),
+            dry_run,
+            executed: false,
+        };
+        if pending.is_empty() {
+            return Ok(report);
+        }
+        if dry_run && !self.transactional_ddl() {
+            report.applied = pending.iter().map(|m| m.name.clone()).collect();
+            return Ok(report);
+        }
+        report.executed = true;
+
+        let txn = self.connection().begin().await?;
+        for migration in pending {
+            info!("Applying migration {}", migration.name);
+            let sql = tokio::fs::read_to_string(&migration.path).await?;
+            txn.execute_unprepared(&sql).await.map_err(|e| {
+                AppError::Database(sea_orm::DbErr::Custom(format!(
+                    "Migration '{}' failed: {}",
+                    migration.name, e
+                )))
+            })?;
+            txn.execute(Statement::from_sql_and_values(
+                self.backend(),
+                self.record_sql(),
+                [migration.version.into(), migration.name.clone().into()],
+            ))
+            .await?;
+            report.applied.push(migration.name.clone());
+        }
+
+        if dry_run {
+            txn.rollback().await?;
+        } else {
+            txn.commit().await?;
+        }
+
+        Ok(report)
+    }
+}
+
+pub struct PostgresMigrationRunner {
+    db: DatabaseConnection,
+}
+
+impl PostgresMigrationRunner {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl MigrationRunner for PostgresMigrationRunner {
+    fn connection(&self) -> &DatabaseConnection {
+        &self.db
+    }
+
+    fn backend(&self) -> DbBackend {
+        DbBackend::Postgres
+    }
+
+    fn create_table_sql(&self) -> &'static str {
+        r#"
+        CREATE TABLE IF NOT EXISTS schema_migrations (
+            version BIGINT PRIMARY KEY,
+            name VARCHAR(255) NOT NULL,
+            applied_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        )
+        "#
+    }
+
+    fn record_sql(&self) -> &'static str {
+        "INSERT INTO schema_migrations (version, name) VALUES ($1, $2)"
+    }
+}
+
+pub struct MySqlMigrationRunner {
+    db: DatabaseConnection,
+}
+
+impl MySqlMigrationRunner {
+    pub fn new(db: DatabaseConnection) -> Self {
+        warn!("MySQL commits DDL implicitly; schema changes are not rolled back on failure");
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl MigrationRunner for MySqlMigrationRunner {
+    fn connection(&self) -> &DatabaseConnection {
+        &self.db
+    }
+
+    fn backend(&self) -> DbBackend {
+        DbBackend::MySql
+    }
+
+    fn create_table_sql(&self) -> &'static str {
+        r#"
+        CREATE TABLE IF NOT EXISTS schema_migrations (
+            version BIGINT PRIMARY KEY,
+            name VARCHAR(255) NOT NULL,
+            applied_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#
+    }
+
+    fn record_sql(&self) -> &'static str {
+        "INSERT INTO schema_migrations (version, name) VALUES (?, ?)"
+    }
+
+    fn transactional_ddl(&self) -> bool {
+        false
+    }
+}
@@ -15,9 +15,11 @@
 
 pub mod database;
 pub mod migrations;
+pub mod migration_runner;
 pub mod database_ext;
 pub mod encryption;
 pub mod html_generator;
 pub mod logging;
 pub mod retry;
+pub mod template;
 pub mod time_conversion;
@@ -0,0 +1,88 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use serde_json::Value;
+
+// {{variable}} substitution shared by prompt templates and notification bodies.
+// Strings are inserted as-is, null as "null" and other values as JSON; unknown
+// placeholders are left in place.
+pub fn render_template(template: &str, variables: &Value) -> String {
+    render_with(template, variables, |s| s.to_string())
+}
+
+// Same as render_template, with every substituted value HTML-escaped so variables
+// can't inject markup into the template
+pub fn render_html_template(template: &str, variables: &Value) -> String {
+    render_with(template, variables, html_escape)
+}
+
+fn render_with(template: &str, variables: &Value, escape: impl Fn(&str) -> String) -> String {
+    let mut rendered = template.to_string();
+    if let Value::Object(map) = variables {
+        for (key, value) in map {
+            let replacement = match value {
+                Value::String(s) => escape(s),
+                other => escape(&other.to_string()),
+            };
+            rendered = rendered.replace(&format!("{{{{{}}}}}", key), &replacement);
+        }
+    }
+    rendered
+}
+
+pub fn html_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn render_template_substitutes_known_variables() {
+        let rendered = render_template(
+            "{{service}} cost rose {{pct}}% for {{account}} {{tags}} {{unknown}}",
+            &json!({"service": "Amazon EC2", "pct": 62.5, "account": null, "tags": ["a"]}),
+        );
+        assert_eq!(
+            rendered,
+            "Amazon EC2 cost rose 62.5% for null [\"a\"] {{unknown}}"
+        );
+    }
+
+    #[test]
+    fn render_html_template_escapes_values_only() {
+        let rendered = render_html_template(
+            "<p>{{description}}</p>",
+            &json!({"description": "<script>alert('x')</script> & \"more\""}),
+        );
+        assert_eq!(
+            rendered,
+            "<p>&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt; &amp; &quot;more&quot;</p>"
+        );
+    }
+}
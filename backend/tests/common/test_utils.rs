@@ -114,6 +114,7 @@ pub mod factories {
             sso_profile: None,
             web_identity_token_file: None,
             session_name: None,
+            anomaly_detection_config: None,
         }
     }
 
@@ -135,6 +136,7 @@ pub mod factories {
             sso_profile: None,
             web_identity_token_file: None,
             session_name: None,
+            anomaly_detection_config: None,
             last_synced_at: None,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
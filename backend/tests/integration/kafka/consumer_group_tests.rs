@@ -143,6 +143,7 @@ async fn test_consumer_groups_api() {
         )))
         .header("Authorization", format!("Bearer {}", harness.auth_token()))
         .json(&json!({
+            "topic": topic_name,
             "partitions": [
                 {
                     "partition": 0,
@@ -106,6 +106,7 @@ mod aws_account_service_tests {
             sso_profile: None,
             web_identity_token_file: None,
             session_name: None,
+            anomaly_detection_config: None,
         };
 
         let result = service.create_account(create_dto).await;
@@ -239,6 +240,7 @@ mod aws_account_service_tests {
             sso_profile: None,
             web_identity_token_file: None,
             session_name: None,
+            anomaly_detection_config: None,
         };
 
         let updated = service
@@ -37,6 +37,7 @@ fn valid_cluster_config() -> KafkaClusterConfig {
         sasl_password: None,
         sasl_mechanism: None,
         security_protocol: "PLAINTEXT".to_string(),
+        schema_registry_url: None,
     }
 }
 
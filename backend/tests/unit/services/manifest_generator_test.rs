@@ -0,0 +1,86 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use mayyam::services::kubernetes::manifest_generator_service::{
+    extract_yaml, validate_deployment_yaml,
+};
+
+const VALID_DEPLOYMENT: &str = r#"apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: web
+spec:
+  replicas: 2
+  selector:
+    matchLabels:
+      app: web
+  template:
+    metadata:
+      labels:
+        app: web
+    spec:
+      containers:
+        - name: web
+          image: nginx:1.25
+          resources:
+            limits:
+              cpu: 500m
+          readinessProbe:
+            httpGet:
+              path: /
+              port: 80
+"#;
+
+#[test]
+fn extract_yaml_strips_markdown_fences() {
+    let content = format!("Here you go:\n```yaml\n{}```\nEnjoy!", VALID_DEPLOYMENT);
+
+    let yaml = extract_yaml(&content);
+
+    assert!(yaml.starts_with("apiVersion: apps/v1"));
+    assert!(!yaml.contains("```"));
+}
+
+#[test]
+fn valid_deployment_passes_without_warnings() {
+    let manifest = validate_deployment_yaml(VALID_DEPLOYMENT);
+
+    assert!(manifest.validation_passed);
+    assert_eq!(manifest.resource_type, "Deployment");
+    assert!(manifest.warnings.is_empty());
+}
+
+#[test]
+fn mismatched_selector_fails_validation() {
+    let yaml = VALID_DEPLOYMENT.replace("      app: web\n  template", "      app: api\n  template");
+
+    let manifest = validate_deployment_yaml(&yaml);
+
+    assert!(!manifest.validation_passed);
+    assert!(manifest
+        .warnings
+        .iter()
+        .any(|w| w.contains("matchLabels")));
+}
+
+#[test]
+fn non_deployment_kind_is_rejected() {
+    let yaml = "apiVersion: v1\nkind: Service\nmetadata:\n  name: web\n";
+
+    let manifest = validate_deployment_yaml(yaml);
+
+    assert!(!manifest.validation_passed);
+    assert_eq!(manifest.resource_type, "Service");
+}
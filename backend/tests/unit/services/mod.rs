@@ -16,3 +16,5 @@
 pub mod aws_account_service_test;
 pub mod kafka_service_validation_test;
 pub mod kinesis_service_validation_test;
+pub mod manifest_generator_test;
+pub mod vpc_flow_log_analyzer_test;
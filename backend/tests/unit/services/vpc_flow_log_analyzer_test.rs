@@ -0,0 +1,76 @@
+// Copyright (c) 2025 Rajan Panneer Selvam
+//
+// Licensed under the Business Source License 1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.mariadb.com/bsl11
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use mayyam::services::aws::aws_data_plane::vpc_flow_log_analyzer::{
+    parse_flow_log_record, summarize_flow_logs,
+};
+
+const ACCEPTED: &str =
+    "2 123456789012 eni-0a1b2c3d 10.0.1.5 10.0.2.9 49152 443 6 10 8400 1700000000 1700000060 ACCEPT OK";
+const REJECTED: &str =
+    "2 123456789012 eni-0a1b2c3d 203.0.113.7 10.0.2.9 51515 22 6 3 180 1700000000 1700000060 REJECT OK";
+
+#[test]
+fn parses_default_format_record() {
+    let record = parse_flow_log_record(ACCEPTED).expect("record should parse");
+    assert_eq!(record.src_addr, "10.0.1.5");
+    assert_eq!(record.dst_port, Some(443));
+    assert_eq!(record.protocol, "TCP");
+    assert_eq!(record.bytes, 8400);
+    assert!(record.accepted);
+    assert!(record.vpc_id.is_none());
+}
+
+#[test]
+fn skips_header_and_nodata_records() {
+    assert!(parse_flow_log_record(
+        "version account-id interface-id srcaddr dstaddr srcport dstport protocol packets bytes start end action log-status"
+    )
+    .is_none());
+    assert!(parse_flow_log_record(
+        "2 123456789012 eni-0a1b2c3d - - - - - - - 1700000000 1700000060 - NODATA"
+    )
+    .is_none());
+}
+
+#[test]
+fn summarizes_accepted_and_rejected_traffic() {
+    let records: Vec<_> = [ACCEPTED, ACCEPTED, REJECTED]
+        .iter()
+        .filter_map(|l| parse_flow_log_record(l))
+        .collect();
+
+    let summary = summarize_flow_logs(&records, "vpc-123");
+    assert_eq!(summary.top_source_ips[0], ("10.0.1.5".to_string(), 2));
+    assert_eq!(summary.top_destination_ports[0], (443, 2));
+    assert_eq!(summary.rejected_connection_count, 1);
+    assert_eq!(summary.accepted_bytes, 16800);
+    assert_eq!(summary.rejected_bytes, 180);
+    assert_eq!(summary.protocol_breakdown.get("TCP"), Some(&3));
+}
+
+#[test]
+fn ignores_records_from_other_vpcs() {
+    let other = format!("{} vpc-other", ACCEPTED);
+    let own = format!("{} vpc-123", REJECTED);
+    let records: Vec<_> = [other.as_str(), own.as_str()]
+        .iter()
+        .filter_map(|l| parse_flow_log_record(l))
+        .collect();
+
+    let summary = summarize_flow_logs(&records, "vpc-123");
+    assert_eq!(summary.accepted_bytes, 0);
+    assert_eq!(summary.rejected_connection_count, 1);
+}
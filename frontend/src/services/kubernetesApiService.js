@@ -35,6 +35,26 @@ const handleResponse = async (response) => {
     return response.json();
 };
 
+// List endpoints return one page at a time ({ items, continue_token, ... }).
+// The views below still expect the full list, so follow the continue tokens.
+const fetchAllPages = async (url) => {
+    const items = [];
+    let continueToken = null;
+    do {
+        const separator = url.includes('?') ? '&' : '?';
+        const pageUrl = continueToken
+            ? `${url}${separator}continue_token=${encodeURIComponent(continueToken)}`
+            : url;
+        const page = await handleResponse(await fetchWithAuth(pageUrl));
+        if (!page || !Array.isArray(page.items)) {
+            return page;
+        }
+        items.push(...page.items);
+        continueToken = page.continue_token;
+    } while (continueToken);
+    return items;
+};
+
 // --- Deployments ---
 export const getDeployments = async (clusterId, namespace) => {
     let url;
@@ -43,8 +63,7 @@ export const getDeployments = async (clusterId, namespace) => {
     } else {
         url = `${API_BASE_URL}/clusters/${clusterId}/deployments`; // For "All Namespaces"
     }
-    const response = await fetchWithAuth(url);
-    return handleResponse(response);
+    return fetchAllPages(url);
 };
 
 export const getPodsForDeployment = async (clusterId, namespace, deploymentName) => {
@@ -61,8 +80,7 @@ export const getServices = async (clusterId, namespace) => {
         // This will point to an "all services" endpoint. Backend support needed.
         url = `${API_BASE_URL}/clusters/${clusterId}/services`;
     }
-    const response = await fetchWithAuth(url);
-    return handleResponse(response);
+    return fetchAllPages(url);
 };
 
 // --- DaemonSets ---
@@ -74,8 +92,7 @@ export const getDaemonSets = async (clusterId, namespace) => {
         // This will point to an "all daemonsets" endpoint. Backend support needed.
         url = `${API_BASE_URL}/clusters/${clusterId}/daemonsets`;
     }
-    const response = await fetchWithAuth(url);
-    return handleResponse(response);
+    return fetchAllPages(url);
 };
 
 export const getPodsForDaemonSet = async (clusterId, namespace, daemonSetName) => {
@@ -93,8 +110,7 @@ export const getStatefulSets = async (clusterId, namespace) => {
         // This will point to an "all statefulsets" endpoint. Backend support needed.
         url = `${API_BASE_URL}/clusters/${clusterId}/statefulsets`;
     }
-    const response = await fetchWithAuth(url);
-    return handleResponse(response);
+    return fetchAllPages(url);
 };
 
 export const getPodsForStatefulSet = async (clusterId, namespace, statefulSetName) => {
@@ -111,26 +127,22 @@ export const getPVCs = async (clusterId, namespace) => {
         // This will point to an "all PVCs" endpoint. Backend support needed.
         url = `${API_BASE_URL}/clusters/${clusterId}/persistentvolumeclaims`;
     }
-    const response = await fetchWithAuth(url);
-    return handleResponse(response);
+    return fetchAllPages(url);
 };
 
 // --- PersistentVolumes (PVs) ---
 export const getPVs = async (clusterId) => { // PVs are not namespaced
-    const response = await fetchWithAuth(`${API_BASE_URL}/clusters/${clusterId}/persistentvolumes`);
-    return handleResponse(response);
+    return fetchAllPages(`${API_BASE_URL}/clusters/${clusterId}/persistentvolumes`);
 };
 
 // --- Nodes ---
 export const getNodes = async (clusterId) => { // Nodes are not namespaced
-    const response = await fetchWithAuth(`${API_BASE_URL}/clusters/${clusterId}/nodes`);
-    return handleResponse(response);
+    return fetchAllPages(`${API_BASE_URL}/clusters/${clusterId}/nodes`);
 };
 
 // --- Namespaces --- (Listing all namespaces is not namespaced itself)
 export const getNamespaces = async (clusterId) => {
-    const response = await fetchWithAuth(`${API_BASE_URL}/clusters/${clusterId}/namespaces`);
-    return handleResponse(response);
+    return fetchAllPages(`${API_BASE_URL}/clusters/${clusterId}/namespaces`);
 };
 
 // --- Pods ---
@@ -139,8 +151,7 @@ export const getNamespaces = async (clusterId) => {
 export const getPods = async (clusterId, namespace) => {
     const namespaceSegment = !namespace || namespace === "" ? "all" : encodeURIComponent(namespace);
     const url = `${API_BASE_URL}/clusters/${clusterId}/namespaces/${namespaceSegment}/pods`;
-    const response = await fetchWithAuth(url);
-    return handleResponse(response);
+    return fetchAllPages(url);
 };
 
 // Function to get specific pod details
@@ -198,8 +209,7 @@ export const getClusterMetrics = async (clusterId, namespace = null) => {
 export const getConfigMaps = async (clusterId, namespace) => {
     const namespaceSegment = !namespace || namespace === "" ? "all" : encodeURIComponent(namespace);
     const url = `${API_BASE_URL}/clusters/${clusterId}/namespaces/${namespaceSegment}/configmaps`;
-    const response = await fetchWithAuth(url);
-    return handleResponse(response);
+    return fetchAllPages(url);
 };
 
 export const getConfigMap = async (clusterId, namespace, name) => {
@@ -230,8 +240,7 @@ export const deleteConfigMap = async (clusterId, namespace, name) => {
 export const getSecrets = async (clusterId, namespace) => {
     const namespaceSegment = !namespace || namespace === "" ? "all" : encodeURIComponent(namespace);
     const url = `${API_BASE_URL}/clusters/${clusterId}/namespaces/${namespaceSegment}/secrets`;
-    const response = await fetchWithAuth(url);
-    return handleResponse(response);
+    return fetchAllPages(url);
 };
 
 export const getSecret = async (clusterId, namespace, name) => {